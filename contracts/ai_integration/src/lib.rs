@@ -7,6 +7,8 @@ use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, String,
     Symbol, Vec,
 };
+use common::interface::{self, InterfaceVersion};
+use common::storage_ttl::{self, StorageKeySpec};
 
 const ADMIN: Symbol = symbol_short!("ADMIN");
 const INITIALIZED: Symbol = symbol_short!("INIT");
@@ -17,6 +19,13 @@ const REQUEST_KEY: Symbol = symbol_short!("REQ");
 const RESULT_KEY: Symbol = symbol_short!("RES");
 const FLAGGED_KEY: Symbol = symbol_short!("FLAGGED");
 
+/// Cross-contract interface version reported by `get_interface_version`
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: interface::AI_INTEGRATION,
+    major: 1,
+    minor: 0,
+};
+
 const MAX_BPS: u32 = 10_000;
 
 #[contracterror]
@@ -130,6 +139,11 @@ impl AiIntegrationContract {
         Ok(())
     }
 
+    /// Version of the interface this contract exposes to other contracts.
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
     pub fn get_admin(env: Env) -> Result<Address, AiIntegrationError> {
         Self::require_initialized(&env)?;
         env.storage()
@@ -488,4 +502,16 @@ impl AiIntegrationContract {
 
         Ok(())
     }
+
+    /// Extends the TTL of the entries described by `keys_spec`, inspecting at
+    /// most `limit` keys. Permissionless: it only keeps existing state alive.
+    pub fn extend_storage(env: Env, keys_spec: Vec<StorageKeySpec>, limit: u32) -> u32 {
+        storage_ttl::extend_storage(
+            &env,
+            &keys_spec,
+            limit,
+            storage_ttl::DEFAULT_TTL_THRESHOLD,
+            storage_ttl::DEFAULT_TTL_EXTEND_TO,
+        )
+    }
 }
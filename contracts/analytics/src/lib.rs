@@ -6,6 +6,8 @@ pub mod homomorphic;
 mod test;
 
 use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec};
+use common::interface::{self, InterfaceVersion};
+use common::storage_ttl::{self, StorageKeySpec};
 
 use crate::aggregation::Aggregator;
 use crate::differential_privacy::DifferentialPrivacy;
@@ -19,6 +21,13 @@ const METRIC: Symbol = symbol_short!("METRIC");
 const PUB_KEY: Symbol = symbol_short!("PUB_KEY");
 const PRIV_KEY: Symbol = symbol_short!("PRIV_KEY");
 
+/// Cross-contract interface version reported by `get_interface_version`
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: interface::ANALYTICS,
+    major: 1,
+    minor: 0,
+};
+
 // ── Types ──────────────────────────────────────────────────────────────────────
 
 #[contracttype]
@@ -79,6 +88,11 @@ impl AnalyticsContract {
         Ok(())
     }
 
+    /// Version of the interface this contract exposes to other contracts.
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
     pub fn get_admin(env: Env) -> Address {
         env.storage().instance().get(&ADMIN).unwrap()
     }
@@ -185,4 +199,16 @@ impl AnalyticsContract {
         }
         out
     }
+
+    /// Extends the TTL of the entries described by `keys_spec`, inspecting at
+    /// most `limit` keys. Permissionless: it only keeps existing state alive.
+    pub fn extend_storage(env: Env, keys_spec: Vec<StorageKeySpec>, limit: u32) -> u32 {
+        storage_ttl::extend_storage(
+            &env,
+            &keys_spec,
+            limit,
+            storage_ttl::DEFAULT_TTL_THRESHOLD,
+            storage_ttl::DEFAULT_TTL_EXTEND_TO,
+        )
+    }
 }
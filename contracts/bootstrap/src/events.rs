@@ -0,0 +1,29 @@
+//! Events emitted by the bootstrap contract.
+
+use soroban_sdk::{symbol_short, Address, Env};
+use common::event_redaction::publish;
+
+use crate::{DeploymentManifest, SuiteContracts};
+
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SuiteDeployedEvent {
+    pub contracts: SuiteContracts,
+    pub governance: Address,
+    pub deployer: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event once the suite is initialized and wired.
+pub fn publish_suite_deployed(env: &Env, manifest: &DeploymentManifest) {
+    publish(
+        env,
+        (symbol_short!("SUITE_DEP"), manifest.governance.clone()),
+        SuiteDeployedEvent {
+            contracts: manifest.contracts.clone(),
+            governance: manifest.governance.clone(),
+            deployer: manifest.deployer.clone(),
+            timestamp: manifest.deployed_at,
+        },
+    );
+}
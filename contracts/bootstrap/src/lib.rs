@@ -0,0 +1,287 @@
+//! # Suite Bootstrap Contract
+//!
+//! Brings up identity, vision_records, zk_verifier and orchestrator in a
+//! single invocation and records what was deployed.
+//!
+//! ## Sequence
+//! ```text
+//! deploy (deploy_suite only)
+//!   → check each contract's get_interface_version()
+//!   → zk_verifier.initialize(bootstrap)
+//!   → vision_records.initialize(bootstrap)
+//!   → vision_records.set_registration_verifier(bootstrap, zk_verifier)
+//!   → identity.initialize(governance)
+//!   → identity.set_zk_verifier(governance, zk_verifier)
+//!   → orchestrator.initialize(governance)
+//!   → orchestrator.register_participant(governance, …) for records, identity, zk
+//!   → vision_records / zk_verifier .propose_admin(bootstrap, governance)
+//! ```
+//! Governance must authorize the invocation (identity wiring runs as its
+//! owner) and completes the handover by calling `accept_admin` on
+//! vision_records and zk_verifier. A bootstrap instance runs once.
+#![no_std]
+
+pub mod events;
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, vec, Address, Bytes,
+    BytesN, Env, IntoVal, Symbol, Val, Vec,
+};
+use common::interface::{self, InterfaceRange, InterfaceVersion};
+use common::transaction::ContractType;
+
+// ── Storage keys ──────────────────────────────────────────────────────────────
+
+const MANIFEST: Symbol = symbol_short!("MANIFEST");
+
+/// Bumped when the manifest layout or bootstrap sequence changes.
+pub const MANIFEST_VERSION: u32 = 1;
+
+/// Interface major version of each suite contract this bootstrap wires
+const SUITE_INTERFACE_MAJOR: u32 = 1;
+
+/// Cross-contract interface version reported by `get_interface_version`
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: symbol_short!("bootstrap"),
+    major: 1,
+    minor: 0,
+};
+
+// ── Types ─────────────────────────────────────────────────────────────────────
+
+/// Wasm hashes of the suite contracts
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SuiteWasm {
+    pub identity: BytesN<32>,
+    pub vision_records: BytesN<32>,
+    pub zk_verifier: BytesN<32>,
+    pub orchestrator: BytesN<32>,
+}
+
+/// Contract ids of the suite contracts
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SuiteContracts {
+    pub identity: Address,
+    pub vision_records: Address,
+    pub zk_verifier: Address,
+    pub orchestrator: Address,
+}
+
+/// On-chain record of a suite deployment
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeploymentManifest {
+    pub version: u32,
+    pub contracts: SuiteContracts,
+    /// As deployed by `deploy_suite`, or as declared to `adopt_suite`
+    pub wasm: SuiteWasm,
+    pub governance: Address,
+    pub deployer: Address,
+    /// Contracts whose admin was proposed to governance; each handover
+    /// completes when governance calls `accept_admin` there
+    pub admin_handover: Vec<Address>,
+    pub deployed_at: u64,
+    pub ledger: u32,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum BootstrapError {
+    AlreadyBootstrapped = 1,
+    /// The same address was given for two suite contracts
+    DuplicateContract = 2,
+    /// A suite contract reports an interface version this bootstrap cannot wire
+    IncompatibleInterface = 3,
+}
+
+// ── Contract ──────────────────────────────────────────────────────────────────
+
+#[contract]
+pub struct BootstrapContract;
+
+#[contractimpl]
+impl BootstrapContract {
+    /// Deploy the suite from uploaded wasm, initialize and wire it, and start
+    /// the admin handover to `governance`. Contract ids are derived from
+    /// `salt`, so a given bootstrap and salt always yield the same ids.
+    pub fn deploy_suite(
+        env: Env,
+        deployer: Address,
+        governance: Address,
+        wasm: SuiteWasm,
+        salt: BytesN<32>,
+    ) -> Result<DeploymentManifest, BootstrapError> {
+        deployer.require_auth();
+        governance.require_auth();
+        Self::require_fresh(&env)?;
+
+        let contracts = SuiteContracts {
+            identity: Self::deploy(&env, &salt, 0, &wasm.identity),
+            vision_records: Self::deploy(&env, &salt, 1, &wasm.vision_records),
+            zk_verifier: Self::deploy(&env, &salt, 2, &wasm.zk_verifier),
+            orchestrator: Self::deploy(&env, &salt, 3, &wasm.orchestrator),
+        };
+        Self::wire(&env, deployer, governance, contracts, wasm)
+    }
+
+    /// Initialize and wire suite contracts that were deployed separately but
+    /// not yet initialized. `wasm` is recorded in the manifest as declared.
+    pub fn adopt_suite(
+        env: Env,
+        deployer: Address,
+        governance: Address,
+        contracts: SuiteContracts,
+        wasm: SuiteWasm,
+    ) -> Result<DeploymentManifest, BootstrapError> {
+        deployer.require_auth();
+        governance.require_auth();
+        Self::require_fresh(&env)?;
+
+        let mut seen: Vec<Address> = Vec::new(&env);
+        for address in [
+            &contracts.identity,
+            &contracts.vision_records,
+            &contracts.zk_verifier,
+            &contracts.orchestrator,
+        ] {
+            if seen.contains(address) {
+                return Err(BootstrapError::DuplicateContract);
+            }
+            seen.push_back(address.clone());
+        }
+
+        Self::wire(&env, deployer, governance, contracts, wasm)
+    }
+
+    /// Version of the interface this contract exposes to other contracts.
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
+    /// The manifest of the suite this bootstrap brought up, if any.
+    pub fn get_manifest(env: Env) -> Option<DeploymentManifest> {
+        env.storage().instance().get(&MANIFEST)
+    }
+
+    // ── Internal helpers ──────────────────────────────────────────────────────
+
+    fn require_fresh(env: &Env) -> Result<(), BootstrapError> {
+        if env.storage().instance().has(&MANIFEST) {
+            return Err(BootstrapError::AlreadyBootstrapped);
+        }
+        Ok(())
+    }
+
+    fn deploy(env: &Env, salt: &BytesN<32>, index: u8, wasm_hash: &BytesN<32>) -> Address {
+        let mut seed = Bytes::from_array(env, &salt.to_array());
+        seed.push_back(index);
+        let contract_salt: BytesN<32> = env.crypto().sha256(&seed).into();
+        env.deployer()
+            .with_current_contract(contract_salt)
+            .deploy_v2(wasm_hash.clone(), ())
+    }
+
+    fn require_interfaces(env: &Env, contracts: &SuiteContracts) -> Result<(), BootstrapError> {
+        for (contract, name) in [
+            (&contracts.identity, interface::IDENTITY),
+            (&contracts.vision_records, interface::VISION_RECORDS),
+            (&contracts.zk_verifier, interface::ZK_VERIFIER),
+            (&contracts.orchestrator, interface::ORCHESTRATOR),
+        ] {
+            let range = InterfaceRange {
+                interface: name,
+                major: SUITE_INTERFACE_MAJOR,
+                min_minor: 0,
+            };
+            if !interface::is_compatible(env, contract, &range) {
+                return Err(BootstrapError::IncompatibleInterface);
+            }
+        }
+        Ok(())
+    }
+
+    fn call(env: &Env, contract: &Address, function: &str, args: Vec<Val>) {
+        let _: Val = env.invoke_contract(contract, &Symbol::new(env, function), args);
+    }
+
+    fn wire(
+        env: &Env,
+        deployer: Address,
+        governance: Address,
+        contracts: SuiteContracts,
+        wasm: SuiteWasm,
+    ) -> Result<DeploymentManifest, BootstrapError> {
+        Self::require_interfaces(env, &contracts)?;
+
+        let this = env.current_contract_address();
+        let no_timeout_config: Option<Val> = None;
+
+        Self::call(env, &contracts.zk_verifier, "initialize", vec![env, this.into_val(env)]);
+        Self::call(env, &contracts.vision_records, "initialize", vec![env, this.into_val(env)]);
+        Self::call(
+            env,
+            &contracts.vision_records,
+            "set_registration_verifier",
+            vec![env, this.into_val(env), contracts.zk_verifier.into_val(env)],
+        );
+        Self::call(env, &contracts.identity, "initialize", vec![env, governance.into_val(env)]);
+        Self::call(
+            env,
+            &contracts.identity,
+            "set_zk_verifier",
+            vec![env, governance.into_val(env), contracts.zk_verifier.into_val(env)],
+        );
+        Self::call(
+            env,
+            &contracts.orchestrator,
+            "initialize",
+            vec![env, governance.into_val(env), no_timeout_config.into_val(env)],
+        );
+        for (contract_type, contract) in [
+            (ContractType::VisionRecords, &contracts.vision_records),
+            (ContractType::Identity, &contracts.identity),
+            (ContractType::ZkVerifier, &contracts.zk_verifier),
+        ] {
+            Self::call(
+                env,
+                &contracts.orchestrator,
+                "register_participant",
+                vec![
+                    env,
+                    governance.into_val(env),
+                    contract_type.into_val(env),
+                    contract.into_val(env),
+                ],
+            );
+        }
+
+        let mut admin_handover = Vec::new(env);
+        for contract in [&contracts.vision_records, &contracts.zk_verifier] {
+            Self::call(
+                env,
+                contract,
+                "propose_admin",
+                vec![env, this.into_val(env), governance.into_val(env)],
+            );
+            admin_handover.push_back(contract.clone());
+        }
+
+        let manifest = DeploymentManifest {
+            version: MANIFEST_VERSION,
+            contracts,
+            wasm,
+            governance,
+            deployer,
+            admin_handover,
+            deployed_at: env.ledger().timestamp(),
+            ledger: env.ledger().sequence(),
+        };
+        env.storage().instance().set(&MANIFEST, &manifest);
+        events::publish_suite_deployed(env, &manifest);
+
+        Ok(manifest)
+    }
+}
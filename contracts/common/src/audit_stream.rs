@@ -0,0 +1,180 @@
+//! # Global Audit Stream
+//!
+//! Append-only log of privileged actions. Every appended entry receives the
+//! next value of a per-contract sequence counter that starts at 1 and never
+//! repeats, so an external auditor reading ranges with [`get_audit_range`]
+//! can detect missing entries simply by looking for gaps in `seq`.
+//!
+//! Entries are deliberately compact — who did what, to whom and when — and
+//! are not a replacement for contract-specific audit trails.
+//!
+//! ```ignore
+//! audit_stream::append(&env, &caller, symbol_short!("SET_POL"), None);
+//! let page = audit_stream::get_audit_range(&env, 1, 50);
+//! ```
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+// ── Storage keys ─────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum AuditStreamKey {
+    /// Sequence number of the most recently appended entry.
+    LastSeq,
+    Entry(u64),
+}
+
+// ── TTL constants (mirror common convention) ─────────────────────────────────
+
+const TTL_THRESHOLD: u32 = 5_184_000;
+const TTL_EXTEND_TO: u32 = 10_368_000;
+
+/// Maximum number of sequence numbers a single range read may span.
+pub const MAX_AUDIT_RANGE: u64 = 100;
+
+// ── Types ────────────────────────────────────────────────────────────────────
+
+/// A single privileged action recorded in the audit stream.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditStreamEntry {
+    pub seq: u64,
+    pub actor: Address,
+    pub action: Symbol,
+    pub subject: Option<Address>,
+    pub timestamp: u64,
+}
+
+// ── Public API ────────────────────────────────────────────────────────────────
+
+/// Returns the sequence number of the latest entry, or 0 if none exist.
+pub fn last_sequence(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&AuditStreamKey::LastSeq)
+        .unwrap_or(0)
+}
+
+/// Appends an entry for `action` performed by `actor` and returns its
+/// sequence number.
+pub fn append(env: &Env, actor: &Address, action: Symbol, subject: Option<Address>) -> u64 {
+    let seq = last_sequence(env).saturating_add(1);
+    let entry = AuditStreamEntry {
+        seq,
+        actor: actor.clone(),
+        action,
+        subject,
+        timestamp: env.ledger().timestamp(),
+    };
+
+    let key = AuditStreamKey::Entry(seq);
+    env.storage().persistent().set(&key, &entry);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+    env.storage()
+        .persistent()
+        .set(&AuditStreamKey::LastSeq, &seq);
+    env.storage()
+        .persistent()
+        .extend_ttl(&AuditStreamKey::LastSeq, TTL_THRESHOLD, TTL_EXTEND_TO);
+
+    seq
+}
+
+/// Returns the entry with sequence number `seq`, if it is still stored.
+pub fn get_entry(env: &Env, seq: u64) -> Option<AuditStreamEntry> {
+    env.storage().persistent().get(&AuditStreamKey::Entry(seq))
+}
+
+/// Returns the stored entries with `from_seq <= seq <= to_seq`, in order.
+///
+/// The range is clamped to the latest sequence number and to at most
+/// [`MAX_AUDIT_RANGE`] entries; callers page by resuming from the last
+/// returned `seq + 1`. Entries that are no longer stored are omitted, which
+/// shows up as a gap in the returned sequence numbers.
+pub fn get_audit_range(env: &Env, from_seq: u64, to_seq: u64) -> Vec<AuditStreamEntry> {
+    let mut entries = Vec::new(env);
+    let from = from_seq.max(1);
+    let last = last_sequence(env).min(to_seq);
+    if from > last {
+        return entries;
+    }
+
+    let end = last.min(from.saturating_add(MAX_AUDIT_RANGE - 1));
+    let mut seq = from;
+    while seq <= end {
+        if let Some(entry) = get_entry(env, seq) {
+            entries.push_back(entry);
+        }
+        seq += 1;
+    }
+    entries
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl, symbol_short, testutils::Address as _, Env};
+
+    #[contract]
+    pub struct TestContract;
+
+    #[contractimpl]
+    impl TestContract {}
+
+    fn with_contract_env<F: FnOnce(&Env)>(f: F) {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, TestContract);
+        env.as_contract(&contract_id, || {
+            f(&env);
+        });
+    }
+
+    #[test]
+    fn sequence_numbers_start_at_one_and_increase() {
+        with_contract_env(|env| {
+            let admin = Address::generate(env);
+            assert_eq!(last_sequence(env), 0);
+            assert_eq!(append(env, &admin, symbol_short!("INIT"), None), 1);
+            assert_eq!(
+                append(env, &admin, symbol_short!("GRANT"), Some(admin.clone())),
+                2
+            );
+            assert_eq!(last_sequence(env), 2);
+            assert_eq!(get_entry(env, 2).unwrap().subject, Some(admin));
+        });
+    }
+
+    #[test]
+    fn range_is_clamped_to_latest_sequence() {
+        with_contract_env(|env| {
+            let admin = Address::generate(env);
+            for _ in 0..5 {
+                append(env, &admin, symbol_short!("SET"), None);
+            }
+            let range = get_audit_range(env, 2, 50);
+            assert_eq!(range.len(), 4);
+            assert_eq!(range.get(0).unwrap().seq, 2);
+            assert_eq!(range.get(3).unwrap().seq, 5);
+            assert!(get_audit_range(env, 6, 10).is_empty());
+            assert!(get_audit_range(env, 4, 3).is_empty());
+        });
+    }
+
+    #[test]
+    fn range_is_capped_per_call() {
+        with_contract_env(|env| {
+            let admin = Address::generate(env);
+            for _ in 0..(MAX_AUDIT_RANGE + 5) {
+                append(env, &admin, symbol_short!("SET"), None);
+            }
+            let range = get_audit_range(env, 1, u64::MAX);
+            assert_eq!(range.len() as u64, MAX_AUDIT_RANGE);
+        });
+    }
+}
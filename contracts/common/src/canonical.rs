@@ -0,0 +1,209 @@
+//! Canonical byte encoding for signing and hashing contract types.
+//!
+//! Every encoding has the same layout:
+//!
+//! ```text
+//! "TEYE" | version: u8 | tag_len: u8 | tag | XDR(value)
+//! ```
+//!
+//! XDR of a `Val` is already deterministic (maps are key-ordered), so the
+//! prefix is what this module adds: the version lets the layout change
+//! without old signatures verifying against new bytes, and the domain tag
+//! stops a signature or hash made for one purpose from being replayed as
+//! another.
+
+use soroban_sdk::{
+    xdr::{FromXdr, ToXdr},
+    Bytes, BytesN, Env, IntoVal, TryFromVal, Val,
+};
+
+const MAGIC: &[u8; 4] = b"TEYE";
+
+/// Current encoding version. Bump when the layout changes.
+pub const CANONICAL_VERSION: u8 = 1;
+
+/// Purpose an encoding is produced for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Domain {
+    /// Payloads signed by a user, e.g. record e-signatures
+    Signature,
+    /// Receipts issued for admin actions or finished transactions
+    Receipt,
+    /// Hash commitments revealed later
+    Commitment,
+    /// Digests of on-chain state compared off-chain
+    StateDigest,
+    /// Links in a record's chain of custody
+    Custody,
+}
+
+impl Domain {
+    pub fn tag(&self) -> &'static [u8] {
+        match self {
+            Domain::Signature => b"teye.signature",
+            Domain::Receipt => b"teye.receipt",
+            Domain::Commitment => b"teye.commitment",
+            Domain::StateDigest => b"teye.state_digest",
+            Domain::Custody => b"teye.custody",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CanonicalError {
+    /// Input does not start with the canonical magic bytes
+    BadPrefix,
+    /// Encoded with a version this code does not understand
+    UnsupportedVersion,
+    /// Encoded for a different domain
+    DomainMismatch,
+    /// The XDR payload does not decode to the requested type
+    Malformed,
+}
+
+fn header(env: &Env, domain: Domain) -> Bytes {
+    let tag = domain.tag();
+    let mut out = Bytes::from_slice(env, MAGIC);
+    out.push_back(CANONICAL_VERSION);
+    out.push_back(tag.len() as u8);
+    out.extend_from_slice(tag);
+    out
+}
+
+/// Canonical bytes of `value` for `domain`.
+pub fn encode<T>(env: &Env, domain: Domain, value: &T) -> Bytes
+where
+    T: IntoVal<Env, Val> + Clone,
+{
+    let mut out = header(env, domain);
+    out.append(&value.clone().to_xdr(env));
+    out
+}
+
+/// SHA-256 of the canonical bytes of `value` for `domain`.
+pub fn hash<T>(env: &Env, domain: Domain, value: &T) -> BytesN<32>
+where
+    T: IntoVal<Env, Val> + Clone,
+{
+    env.crypto().sha256(&encode(env, domain, value)).into()
+}
+
+/// Decodes bytes produced by [`encode`] for the same `domain`.
+pub fn decode<T>(env: &Env, domain: Domain, bytes: &Bytes) -> Result<T, CanonicalError>
+where
+    T: TryFromVal<Env, Val>,
+{
+    let expected = header(env, domain);
+    let magic_len = MAGIC.len() as u32;
+    if bytes.len() < magic_len || bytes.slice(..magic_len) != expected.slice(..magic_len) {
+        return Err(CanonicalError::BadPrefix);
+    }
+    if bytes.get(magic_len) != Some(CANONICAL_VERSION) {
+        return Err(CanonicalError::UnsupportedVersion);
+    }
+    let header_len = expected.len();
+    if bytes.len() < header_len || bytes.slice(..header_len) != expected {
+        return Err(CanonicalError::DomainMismatch);
+    }
+    T::from_xdr(env, &bytes.slice(header_len..)).map_err(|_| CanonicalError::Malformed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contracttype, testutils::Address as _, Address, Map, String};
+
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    struct Sample {
+        owner: Address,
+        amount: u64,
+        note: String,
+    }
+
+    fn sample(env: &Env) -> Sample {
+        Sample {
+            owner: Address::generate(env),
+            amount: 42,
+            note: String::from_str(env, "canonical"),
+        }
+    }
+
+    #[test]
+    fn struct_round_trips() {
+        let env = Env::default();
+        let value = sample(&env);
+        let bytes = encode(&env, Domain::Signature, &value);
+        let decoded: Sample = decode(&env, Domain::Signature, &bytes).unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn scalar_round_trips() {
+        let env = Env::default();
+        let bytes = encode(&env, Domain::Commitment, &u64::MAX);
+        assert_eq!(decode::<u64>(&env, Domain::Commitment, &bytes), Ok(u64::MAX));
+    }
+
+    #[test]
+    fn encoding_is_deterministic() {
+        let env = Env::default();
+        let value = sample(&env);
+        assert_eq!(
+            encode(&env, Domain::Receipt, &value),
+            encode(&env, Domain::Receipt, &value.clone())
+        );
+
+        // Maps encode in key order regardless of insertion order.
+        let mut a: Map<u32, u32> = Map::new(&env);
+        a.set(2, 20);
+        a.set(1, 10);
+        let mut b: Map<u32, u32> = Map::new(&env);
+        b.set(1, 10);
+        b.set(2, 20);
+        assert_eq!(hash(&env, Domain::StateDigest, &a), hash(&env, Domain::StateDigest, &b));
+    }
+
+    #[test]
+    fn domains_are_separated() {
+        let env = Env::default();
+        let value = sample(&env);
+        assert_ne!(
+            hash(&env, Domain::Signature, &value),
+            hash(&env, Domain::Receipt, &value)
+        );
+
+        let bytes = encode(&env, Domain::Signature, &value);
+        assert_eq!(
+            decode::<Sample>(&env, Domain::Receipt, &bytes),
+            Err(CanonicalError::DomainMismatch)
+        );
+    }
+
+    #[test]
+    fn rejects_foreign_or_future_bytes() {
+        let env = Env::default();
+        let raw = 7u64.to_xdr(&env);
+        assert_eq!(
+            decode::<u64>(&env, Domain::Commitment, &raw),
+            Err(CanonicalError::BadPrefix)
+        );
+
+        let mut future = encode(&env, Domain::Commitment, &7u64);
+        future.set(MAGIC.len() as u32, CANONICAL_VERSION + 1);
+        assert_eq!(
+            decode::<u64>(&env, Domain::Commitment, &future),
+            Err(CanonicalError::UnsupportedVersion)
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_payload_type() {
+        let env = Env::default();
+        let bytes = encode(&env, Domain::Signature, &String::from_str(&env, "not a number"));
+        assert_eq!(
+            decode::<u64>(&env, Domain::Signature, &bytes),
+            Err(CanonicalError::Malformed)
+        );
+    }
+}
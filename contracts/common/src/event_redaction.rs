@@ -0,0 +1,160 @@
+#![allow(deprecated)] // events().publish migration tracked separately
+
+//! Deployment-wide control over how much event payloads reveal.
+//!
+//! Contracts route their events through [`publish`], which consults the
+//! level stored in instance storage:
+//!
+//! | Level     | Topics                          | Data                     |
+//! |-----------|---------------------------------|--------------------------|
+//! | `Full`    | as emitted                      | as emitted               |
+//! | `Hashed`  | event name, sha256 of topics    | sha256 of the data       |
+//! | `Minimal` | event name only                 | none                     |
+//!
+//! `Hashed` lets an indexer that already knows the parties confirm an event
+//! by recomputing the hashes, without exposing addresses to everyone else.
+//! The event name is always the first topic and is never redacted.
+
+use soroban_sdk::{
+    contracttype, symbol_short, xdr::ToXdr, BytesN, Env, IntoVal, Symbol, Val, Vec,
+};
+
+const EVT_RDCT: Symbol = symbol_short!("EVT_RDCT");
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventRedaction {
+    Full,
+    Hashed,
+    Minimal,
+}
+
+/// Sets the redaction level for the current contract.
+///
+/// Callers are responsible for enforcing admin authorization.
+pub fn set_level(env: &Env, level: EventRedaction) {
+    env.storage().instance().set(&EVT_RDCT, &level);
+}
+
+/// Returns the configured level, defaulting to [`EventRedaction::Full`].
+pub fn get_level(env: &Env) -> EventRedaction {
+    env.storage()
+        .instance()
+        .get(&EVT_RDCT)
+        .unwrap_or(EventRedaction::Full)
+}
+
+/// Publishes an event at the configured redaction level.
+///
+/// `topics` must start with the event name.
+pub fn publish<T, D>(env: &Env, topics: T, data: D)
+where
+    T: IntoVal<Env, Vec<Val>>,
+    D: IntoVal<Env, Val>,
+{
+    let topics: Vec<Val> = topics.into_val(env);
+    match get_level(env) {
+        EventRedaction::Full => env.events().publish(topics, data),
+        EventRedaction::Hashed => {
+            let name = topics.first();
+            let topics_hash: BytesN<32> = env.crypto().sha256(&topics.to_xdr(env)).into();
+            let data_hash: BytesN<32> = env.crypto().sha256(&data.to_xdr(env)).into();
+            let mut redacted: Vec<Val> = Vec::new(env);
+            if let Some(name) = name {
+                redacted.push_back(name);
+            }
+            redacted.push_back(topics_hash.into_val(env));
+            env.events().publish(redacted, data_hash);
+        }
+        EventRedaction::Minimal => {
+            let mut redacted: Vec<Val> = Vec::new(env);
+            if let Some(name) = topics.first() {
+                redacted.push_back(name);
+            }
+            env.events().publish(redacted, ());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{
+        contract, testutils::Address as _, testutils::Events as _, xdr::ContractEventBody, Address,
+        TryFromVal,
+    };
+
+    #[contract]
+    struct DummyContract;
+
+    fn last_event(env: &Env) -> (Vec<Val>, Val) {
+        let all = env.events().all();
+        let event = all.events().last().unwrap().clone();
+        let ContractEventBody::V0(body) = event.body;
+        let mut topics = Vec::new(env);
+        for topic in body.topics.iter() {
+            topics.push_back(Val::try_from_val(env, topic).unwrap());
+        }
+        (topics, Val::try_from_val(env, &body.data).unwrap())
+    }
+
+    #[test]
+    fn default_level_is_full() {
+        let env = Env::default();
+        let contract_id = env.register(DummyContract, ());
+        env.as_contract(&contract_id, || {
+            assert_eq!(get_level(&env), EventRedaction::Full);
+            set_level(&env, EventRedaction::Minimal);
+            assert_eq!(get_level(&env), EventRedaction::Minimal);
+        });
+    }
+
+    #[test]
+    fn full_publishes_unchanged() {
+        let env = Env::default();
+        let contract_id = env.register(DummyContract, ());
+        let patient = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            publish(&env, (symbol_short!("REC_ADD"), patient.clone()), 7u64);
+        });
+        let (topics, data) = last_event(&env);
+        assert_eq!(topics, (symbol_short!("REC_ADD"), patient).into_val(&env));
+        assert_eq!(u64::try_from_val(&env, &data).unwrap(), 7);
+    }
+
+    #[test]
+    fn hashed_hides_topics_and_data() {
+        let env = Env::default();
+        let contract_id = env.register(DummyContract, ());
+        let patient = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            set_level(&env, EventRedaction::Hashed);
+            publish(&env, (symbol_short!("REC_ADD"), patient.clone()), 7u64);
+        });
+        let (topics, data) = last_event(&env);
+
+        let full: Vec<Val> = (symbol_short!("REC_ADD"), patient).into_val(&env);
+        let expected_topics: BytesN<32> = env.crypto().sha256(&full.to_xdr(&env)).into();
+        let expected_data: BytesN<32> = env.crypto().sha256(&7u64.to_xdr(&env)).into();
+        assert_eq!(topics.len(), 2);
+        assert_eq!(
+            topics,
+            (symbol_short!("REC_ADD"), expected_topics).into_val(&env)
+        );
+        assert_eq!(BytesN::<32>::try_from_val(&env, &data).unwrap(), expected_data);
+    }
+
+    #[test]
+    fn minimal_keeps_only_the_name() {
+        let env = Env::default();
+        let contract_id = env.register(DummyContract, ());
+        let patient = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            set_level(&env, EventRedaction::Minimal);
+            publish(&env, (symbol_short!("REC_ADD"), patient), 7u64);
+        });
+        let (topics, data) = last_event(&env);
+        assert_eq!(topics, (symbol_short!("REC_ADD"),).into_val(&env));
+        assert!(data.is_void());
+    }
+}
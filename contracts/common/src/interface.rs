@@ -0,0 +1,77 @@
+//! Inter-contract interface versioning.
+//!
+//! Every suite contract exposes `get_interface_version()`. A contract that
+//! wires another in (a verifier, a participant) states the range it was built
+//! against and refuses counterparts outside it. The major version changes on
+//! breaking changes to a contract's cross-contract surface; the minor version
+//! on additive ones.
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+/// Interface names of the suite contracts
+pub const AI_INTEGRATION: Symbol = symbol_short!("ai_intg");
+pub const ANALYTICS: Symbol = symbol_short!("analytics");
+pub const CROSS_CHAIN: Symbol = symbol_short!("x_chain");
+pub const EMR_BRIDGE: Symbol = symbol_short!("emr_brdg");
+pub const FHIR: Symbol = symbol_short!("fhir");
+pub const IDENTITY: Symbol = symbol_short!("identity");
+pub const KEY_MANAGER: Symbol = symbol_short!("key_mgr");
+pub const METERING: Symbol = symbol_short!("metering");
+pub const ORCHESTRATOR: Symbol = symbol_short!("orch");
+pub const STAKING: Symbol = symbol_short!("staking");
+pub const STATE_CHANNEL: Symbol = symbol_short!("st_chan");
+pub const TREASURY: Symbol = symbol_short!("treasury");
+pub const VISION_RECORDS: Symbol = symbol_short!("vis_rec");
+pub const ZK_VERIFIER: Symbol = symbol_short!("zk_vfy");
+pub const ZK_VOTING: Symbol = symbol_short!("zk_vote");
+
+/// Version of a contract's cross-contract interface
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InterfaceVersion {
+    /// Which interface this is, e.g. `zk_vfy`; versions of different
+    /// interfaces are never compatible with each other
+    pub interface: Symbol,
+    pub major: u32,
+    pub minor: u32,
+}
+
+/// Versions a caller can work with: the same interface and major version,
+/// at least `min_minor`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InterfaceRange {
+    pub interface: Symbol,
+    pub major: u32,
+    pub min_minor: u32,
+}
+
+impl InterfaceRange {
+    pub fn accepts(&self, version: &InterfaceVersion) -> bool {
+        version.interface == self.interface
+            && version.major == self.major
+            && version.minor >= self.min_minor
+    }
+}
+
+/// Asks `contract` for its interface version. Returns `None` if the contract
+/// does not expose one or answers with something else.
+pub fn query(env: &Env, contract: &Address) -> Option<InterfaceVersion> {
+    match env.try_invoke_contract::<InterfaceVersion, soroban_sdk::Error>(
+        contract,
+        &Symbol::new(env, "get_interface_version"),
+        Vec::new(env),
+    ) {
+        Ok(Ok(version)) => Some(version),
+        _ => None,
+    }
+}
+
+/// True if `contract` reports a version inside `range`. Contracts that
+/// report no version are treated as incompatible.
+pub fn is_compatible(env: &Env, contract: &Address, range: &InterfaceRange) -> bool {
+    match query(env, contract) {
+        Some(version) => range.accepts(&version),
+        None => false,
+    }
+}
@@ -0,0 +1,175 @@
+//! Internal consistency self-checks.
+//!
+//! Contracts expose `run_invariant_checks(limit)` to walk their id-keyed
+//! state in slices of at most `limit` ids, resuming where the previous call
+//! stopped and wrapping around at the end. Each broken invariant is
+//! published as an `INV_FAIL` event and listed in the returned
+//! [`InvariantReport`], which makes a full pass after an upgrade or data
+//! migration a matter of calling until `wrapped` is set.
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::event_redaction;
+
+/// Instance-storage key of the next id to check
+const INV_CURSOR: Symbol = symbol_short!("INV_CUR");
+
+/// Most ids checked per call, whatever `limit` asks for
+pub const MAX_INVARIANT_SCAN: u32 = 100;
+
+/// Most violations listed in one report; further ones are still published
+pub const MAX_REPORTED_VIOLATIONS: u32 = 50;
+
+// ── Types ────────────────────────────────────────────────────────────────────
+
+/// The state entry a violation was found in
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InvariantSubject {
+    Id(u64),
+    IdPair(u64, u64),
+    AddressPair(Address, Address),
+    Resource(String),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvariantViolation {
+    /// Short name of the invariant, e.g. `idx_dang`
+    pub check: Symbol,
+    pub subject: InvariantSubject,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvariantReport {
+    /// First and last id covered by this call (`to < from` when nothing
+    /// was scanned)
+    pub from: u64,
+    pub to: u64,
+    /// Set when this call reached the last id, so the next starts over
+    pub wrapped: bool,
+    /// Total violations found, including any beyond the listed ones
+    pub violation_count: u32,
+    pub violations: Vec<InvariantViolation>,
+}
+
+// ── Public API ────────────────────────────────────────────────────────────────
+
+/// One call's worth of checking over ids `1..=last_id`.
+pub struct InvariantScan<'a> {
+    env: &'a Env,
+    from: u64,
+    to: u64,
+    last_id: u64,
+    violation_count: u32,
+    violations: Vec<InvariantViolation>,
+}
+
+impl<'a> InvariantScan<'a> {
+    /// Picks up at the stored cursor and covers at most `limit` ids.
+    pub fn start(env: &'a Env, last_id: u64, limit: u32) -> Self {
+        let limit = limit.min(MAX_INVARIANT_SCAN) as u64;
+        let cursor: u64 = env.storage().instance().get(&INV_CURSOR).unwrap_or(1);
+        let from = if cursor == 0 || cursor > last_id { 1 } else { cursor };
+        let to = if limit == 0 || last_id == 0 {
+            from.saturating_sub(1)
+        } else {
+            from.saturating_add(limit - 1).min(last_id)
+        };
+        Self {
+            env,
+            from,
+            to,
+            last_id,
+            violation_count: 0,
+            violations: Vec::new(env),
+        }
+    }
+
+    /// Ids to check in this call
+    pub fn ids(&self) -> core::ops::RangeInclusive<u64> {
+        self.from..=self.to
+    }
+
+    /// Records and publishes a broken invariant.
+    pub fn flag(&mut self, check: Symbol, subject: InvariantSubject) {
+        let violation = InvariantViolation { check, subject };
+        event_redaction::publish(
+            self.env,
+            (symbol_short!("INV_FAIL"), violation.check.clone()),
+            violation.subject.clone(),
+        );
+        self.violation_count = self.violation_count.saturating_add(1);
+        if self.violations.len() < MAX_REPORTED_VIOLATIONS {
+            self.violations.push_back(violation);
+        }
+    }
+
+    /// Stores where the next call resumes and returns the report.
+    pub fn finish(self) -> InvariantReport {
+        let wrapped = self.to >= self.last_id;
+        let next = if wrapped { 1 } else { self.to.saturating_add(1) };
+        self.env.storage().instance().set(&INV_CURSOR, &next);
+        InvariantReport {
+            from: self.from,
+            to: self.to,
+            wrapped,
+            violation_count: self.violation_count,
+            violations: self.violations,
+        }
+    }
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl};
+
+    #[contract]
+    pub struct TestContract;
+
+    #[contractimpl]
+    impl TestContract {}
+
+    #[test]
+    fn scans_resume_and_wrap() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, TestContract);
+        env.as_contract(&contract_id, || {
+            let scan = InvariantScan::start(&env, 5, 2);
+            assert_eq!(scan.ids(), 1..=2);
+            assert!(!scan.finish().wrapped);
+
+            let scan = InvariantScan::start(&env, 5, 2);
+            assert_eq!(scan.ids(), 3..=4);
+            scan.finish();
+
+            let report = InvariantScan::start(&env, 5, 2).finish();
+            assert_eq!((report.from, report.to), (5, 5));
+            assert!(report.wrapped);
+
+            let scan = InvariantScan::start(&env, 5, 1_000);
+            assert_eq!(scan.ids(), 1..=5);
+        });
+    }
+
+    #[test]
+    fn violations_are_capped_in_the_report() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, TestContract);
+        env.as_contract(&contract_id, || {
+            let mut scan = InvariantScan::start(&env, 0, 10);
+            assert!(scan.ids().is_empty());
+            for id in 0..(MAX_REPORTED_VIOLATIONS as u64 + 5) {
+                scan.flag(symbol_short!("test"), InvariantSubject::Id(id));
+            }
+            let report = scan.finish();
+            assert_eq!(report.violation_count, MAX_REPORTED_VIOLATIONS + 5);
+            assert_eq!(report.violations.len(), MAX_REPORTED_VIOLATIONS);
+            assert!(report.wrapped);
+        });
+    }
+}
@@ -8,6 +8,11 @@
 //!   and rollback support.
 //! - [`versioned_storage`] — lazy-migration storage layer built on top of
 //!   the migration framework.
+//! - [`interface`] — cross-contract interface versions and compatibility checks.
+//! - [`invariant`] — resumable internal-consistency self-checks.
+//! - [`state_proof`] — entry proofs for checking mirrored state against ledger
+//!   state proofs.
+//! - [`optometry`] — fixed-point Snellen / logMAR / decimal acuity conversions.
 //!
 //! Contract-specific errors can extend the range starting at code **100** and
 //! above, ensuring no collisions with the common set.
@@ -22,8 +27,13 @@ use soroban_sdk::contracterror;
 
 #[allow(clippy::enum_variant_names)]
 pub mod admin_tiers;
+pub mod audit_stream;
+pub mod canonical;
 pub mod concurrency;
 pub mod conflict_resolver;
+pub mod event_redaction;
+pub mod interface;
+pub mod invariant;
 #[cfg(feature = "std")]
 pub mod consent;
 pub mod keys;
@@ -31,6 +41,7 @@ pub mod meta_tx;
 pub mod metering;
 pub mod multisig;
 pub mod nonce;
+pub mod optometry;
 pub mod pausable;
 pub mod policy_dsl;
 pub mod progressive_auth;
@@ -38,6 +49,8 @@ pub mod rate_limit;
 pub mod reentrancy_guard;
 pub mod session;
 pub mod risk_engine;
+pub mod state_proof;
+pub mod storage_ttl;
 pub mod vector_clock;
 pub mod whitelist;
 pub mod transaction;
@@ -45,7 +58,9 @@ pub mod transaction;
 pub mod credential_types;
 
 pub use admin_tiers::*;
+pub use audit_stream::AuditStreamEntry;
 pub use concurrency::*;
+pub use event_redaction::EventRedaction;
 #[cfg(feature = "std")]
 pub use consent::*;
 pub use keys::*;
@@ -57,6 +72,7 @@ pub use rate_limit::*;
 pub use reentrancy_guard::*;
 pub use session::*;
 pub use risk_engine::*;
+pub use storage_ttl::*;
 pub use vector_clock::*;
 pub use whitelist::*;
 pub use credential_types::*;
@@ -1,9 +1,11 @@
-use soroban_sdk::{Bytes, BytesN, Env};
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env};
 
 /// Builds a canonical message for a grant access meta-transaction.
 ///
 /// Message format: "grant_access" || patient_pubkey(32) || grantee_id(32)
 ///                 || level(4 BE) || expires_at(8 BE) || nonce(8 BE)
+///                 || xdr(patient) || xdr(grantee)
+#[allow(clippy::too_many_arguments)]
 pub fn build_grant_message(
     env: &Env,
     patient_pubkey: &BytesN<32>,
@@ -11,6 +13,8 @@ pub fn build_grant_message(
     level: u32,
     expires_at: u64,
     nonce: u64,
+    patient: &Address,
+    grantee: &Address,
 ) -> Bytes {
     let mut msg = Bytes::new(env);
     msg.append(&Bytes::from_slice(env, b"grant_access"));
@@ -19,6 +23,8 @@ pub fn build_grant_message(
     msg.append(&Bytes::from_slice(env, &level.to_be_bytes()));
     msg.append(&Bytes::from_slice(env, &expires_at.to_be_bytes()));
     msg.append(&Bytes::from_slice(env, &nonce.to_be_bytes()));
+    msg.append(&patient.clone().to_xdr(env));
+    msg.append(&grantee.clone().to_xdr(env));
     msg
 }
 
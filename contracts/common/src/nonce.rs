@@ -18,8 +18,18 @@
 //! nonce::validate_and_increment_nonce(&env, &request.sender, request.nonce)?;
 //! // ... rest of logic
 //! ```
+//!
+//! ## Signature-authorized calls
+//!
+//! Relayed and sponsored calls carry a nonce chosen by the signer rather than
+//! a strictly sequential one. [`consume_nonce`] keeps a per-user registry of
+//! consumed nonces, each bound to the hash of the payload that was signed, so
+//! a signed message can be submitted at most once and in any order:
+//! ```ignore
+//! nonce::consume_nonce(&env, &signer, signed.nonce, &payload_hash)?;
+//! ```
 
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, BytesN, Env};
 
 use crate::CommonError;
 
@@ -29,6 +39,8 @@ use crate::CommonError;
 #[derive(Clone, Debug, Eq, PartialEq)]
 enum NonceKey {
     Nonce(Address),
+    /// Payload hash a signature-authorized nonce was consumed with.
+    Consumed(Address, u64),
 }
 
 // ── TTL constants (mirror common convention) ─────────────────────────────────
@@ -98,6 +110,38 @@ pub fn validate_and_increment_nonce(
     Ok(())
 }
 
+/// Consume `nonce` for a signature-authorized call made on behalf of `user`.
+///
+/// Unlike [`validate_and_increment_nonce`], nonces need not be sequential:
+/// any nonce is accepted once per user. The hash of the signed payload is
+/// recorded against the nonce so auditors can tie it back to the call.
+///
+/// # Errors
+/// - [`CommonError::InvalidNonce`] — `nonce` was already consumed for `user`.
+pub fn consume_nonce(
+    env: &Env,
+    user: &Address,
+    nonce: u64,
+    payload_hash: &BytesN<32>,
+) -> Result<(), CommonError> {
+    let key = NonceKey::Consumed(user.clone(), nonce);
+    if env.storage().persistent().has(&key) {
+        return Err(CommonError::InvalidNonce);
+    }
+    env.storage().persistent().set(&key, payload_hash);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Ok(())
+}
+
+/// Return the payload hash `nonce` was consumed with, if it has been used.
+pub fn consumed_payload(env: &Env, user: &Address, nonce: u64) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&NonceKey::Consumed(user.clone(), nonce))
+}
+
 // ── Tests ─────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -204,6 +248,35 @@ mod tests {
         });
     }
 
+    #[test]
+    fn consume_nonce_accepts_any_order_once() {
+        with_contract_env(|env| {
+            let user = Address::generate(env);
+            let payload = BytesN::from_array(env, &[1u8; 32]);
+            consume_nonce(env, &user, 42, &payload).unwrap();
+            consume_nonce(env, &user, 7, &payload).unwrap();
+            assert_eq!(
+                consume_nonce(env, &user, 42, &payload).unwrap_err(),
+                CommonError::InvalidNonce
+            );
+            assert_eq!(consumed_payload(env, &user, 42), Some(payload));
+            assert_eq!(consumed_payload(env, &user, 8), None);
+        });
+    }
+
+    #[test]
+    fn consumed_nonces_do_not_touch_sequential_counter() {
+        with_contract_env(|env| {
+            let user = Address::generate(env);
+            let other = Address::generate(env);
+            let payload = BytesN::from_array(env, &[2u8; 32]);
+            consume_nonce(env, &user, 0, &payload).unwrap();
+            consume_nonce(env, &other, 0, &payload).unwrap();
+            assert_eq!(current_nonce(env, &user), 0);
+            validate_and_increment_nonce(env, &user, 0).unwrap();
+        });
+    }
+
     #[test]
     fn error_codes_are_stable() {
         with_contract_env(|env| {
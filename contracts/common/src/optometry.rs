@@ -0,0 +1,514 @@
+//! # Visual Acuity Normalization
+//!
+//! Conversions between the three notations clinicians record visual acuity
+//! in — Snellen fractions (`20/40`, `6/12`), logMAR and decimal acuity — so
+//! that every contract compares acuity on the same scale. Categorical
+//! entries (counting fingers, hand motion, light perception) are recognized
+//! by [`is_categorical_acuity`] but have no place on that scale.
+//!
+//! Contracts cannot use floating point, so values are fixed-point integers:
+//!
+//! | Notation | Representation                          | Example        |
+//! |----------|-----------------------------------------|----------------|
+//! | logMAR   | `i32` thousandths ([`LOGMAR_SCALE`])    | `301` = 0.301  |
+//! | decimal  | `u32` thousandths ([`DECIMAL_SCALE`])   | `500` = 0.5    |
+//! | Snellen  | [`Snellen`] numerator / denominator     | `20/40`        |
+//!
+//! ## Precision
+//!
+//! Every conversion is computed internally with 18 decimal digits and
+//! rounded once, half away from zero, to the nearest thousandth of the
+//! target unit. Consequently:
+//!
+//! * any conversion *into* logMAR is within ±0.0005 logMAR of the exact value;
+//! * `logmar → decimal → logmar` returns the original value exactly whenever
+//!   the decimal acuity is at least 0.5 (every step of the logMAR grid maps to
+//!   a distinct thousandth of decimal acuity in that range);
+//! * `logmar → snellen → logmar` with a numerator of 20 stays within one
+//!   rounding step of the Snellen denominator.
+//!
+//! Snellen chart lines are nominal (20/25 is 0.097 logMAR, charted as 0.1), so
+//! progression should be compared in logMAR, or in whole chart lines via
+//! [`chart_line`].
+
+use soroban_sdk::{contracttype, String};
+
+// ── Constants ────────────────────────────────────────────────────────────────
+
+/// Fixed-point scale of logMAR values (thousandths).
+pub const LOGMAR_SCALE: i32 = 1_000;
+/// Fixed-point scale of decimal acuity values (thousandths).
+pub const DECIMAL_SCALE: u32 = 1_000;
+/// Best acuity accepted: logMAR -0.5 (20/6.3).
+pub const MIN_LOGMAR: i32 = -500;
+/// Worst acuity accepted: logMAR 3.0 (roughly hand motion).
+pub const MAX_LOGMAR: i32 = 3_000;
+/// Offset added to logMAR before it is encoded as a non-negative field element.
+pub const ZK_LOGMAR_OFFSET: i32 = -MIN_LOGMAR;
+
+/// Internal precision: 18 decimal digits.
+const SCALE: i128 = 1_000_000_000_000_000_000;
+const LN2: i128 = 693_147_180_559_945_309;
+const LN10: i128 = 2_302_585_092_994_045_684;
+
+/// Longest acuity string [`parse_acuity`] will look at.
+const MAX_ACUITY_LEN: usize = 16;
+/// Notations for acuity too poor to read a chart, matched case-insensitively.
+const CATEGORICAL_ACUITY: [&str; 10] = [
+    "CF",
+    "HM",
+    "LP",
+    "PL",
+    "NLP",
+    "counting fingers",
+    "hand motion",
+    "hand movements",
+    "light perception",
+    "no light perception",
+];
+/// Longest entry in [`CATEGORICAL_ACUITY`].
+const MAX_CATEGORY_LEN: usize = 19;
+
+// ── Types ────────────────────────────────────────────────────────────────────
+
+/// A Snellen fraction: test distance over the distance at which a normal eye
+/// reads the same line.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Snellen {
+    pub numerator: u32,
+    pub denominator: u32,
+}
+
+// ── Conversions ──────────────────────────────────────────────────────────────
+
+/// Converts a Snellen fraction to logMAR thousandths.
+///
+/// Returns `None` for a zero numerator or denominator, or when the result is
+/// outside [`MIN_LOGMAR`]..=[`MAX_LOGMAR`].
+pub fn snellen_to_logmar(snellen: &Snellen) -> Option<i32> {
+    if snellen.numerator == 0 || snellen.denominator == 0 {
+        return None;
+    }
+    let ln = ln_ratio(snellen.denominator as i128, snellen.numerator as i128);
+    in_range(round_div(ln * LOGMAR_SCALE as i128, LN10))
+}
+
+/// Converts a Snellen fraction to decimal acuity thousandths.
+pub fn snellen_to_decimal(snellen: &Snellen) -> Option<u32> {
+    // Range-checks the fraction the same way as the logMAR conversion.
+    snellen_to_logmar(snellen)?;
+    let decimal = round_div(
+        snellen.numerator as i128 * DECIMAL_SCALE as i128,
+        snellen.denominator as i128,
+    );
+    u32::try_from(decimal).ok()
+}
+
+/// Converts decimal acuity thousandths to logMAR thousandths.
+pub fn decimal_to_logmar(decimal: u32) -> Option<i32> {
+    if decimal == 0 {
+        return None;
+    }
+    let ln = ln_ratio(DECIMAL_SCALE as i128, decimal as i128);
+    in_range(round_div(ln * LOGMAR_SCALE as i128, LN10))
+}
+
+/// Converts logMAR thousandths to decimal acuity thousandths.
+pub fn logmar_to_decimal(logmar: i32) -> Option<u32> {
+    if !(MIN_LOGMAR..=MAX_LOGMAR).contains(&logmar) {
+        return None;
+    }
+    let value = pow10_milli(-logmar);
+    u32::try_from(round_div(value * DECIMAL_SCALE as i128, SCALE)).ok()
+}
+
+/// Converts logMAR thousandths to a Snellen fraction with the given
+/// numerator (20 for feet, 6 for metres).
+pub fn logmar_to_snellen(logmar: i32, numerator: u32) -> Option<Snellen> {
+    if numerator == 0 || !(MIN_LOGMAR..=MAX_LOGMAR).contains(&logmar) {
+        return None;
+    }
+    let denominator = round_div(pow10_milli(logmar) * numerator as i128, SCALE);
+    let denominator = u32::try_from(denominator).ok().filter(|d| *d > 0)?;
+    Some(Snellen {
+        numerator,
+        denominator,
+    })
+}
+
+/// Rounds logMAR thousandths to the nearest chart line (0.1 logMAR steps).
+///
+/// The difference between two chart lines is the number of lines gained or
+/// lost; positive values mean worse acuity.
+pub fn chart_line(logmar: i32) -> i32 {
+    round_div(logmar as i128, 100) as i32
+}
+
+/// Encodes logMAR thousandths as a 32-byte big-endian field element for use
+/// as a zk public input. The value is shifted by [`ZK_LOGMAR_OFFSET`] so it
+/// is never negative.
+pub fn logmar_to_field_bytes(logmar: i32) -> Option<[u8; 32]> {
+    if !(MIN_LOGMAR..=MAX_LOGMAR).contains(&logmar) {
+        return None;
+    }
+    let shifted = (logmar + ZK_LOGMAR_OFFSET) as u32;
+    let mut out = [0u8; 32];
+    out[28..].copy_from_slice(&shifted.to_be_bytes());
+    Some(out)
+}
+
+// ── Parsing ──────────────────────────────────────────────────────────────────
+
+/// Parses a recorded acuity and returns it in logMAR thousandths.
+///
+/// Accepts Snellen fractions with whole-number parts (`"20/40"`, `"6/12"`)
+/// and decimal acuity with up to three fractional digits (`"0.5"`, `"1.25"`).
+/// Anything else — including chart-line suffixes like `"20/40 -2"` — is
+/// rejected.
+pub fn parse_acuity(value: &String) -> Option<i32> {
+    let len = value.len() as usize;
+    if len == 0 || len > MAX_ACUITY_LEN {
+        return None;
+    }
+    let mut buf = [0u8; MAX_ACUITY_LEN];
+    value.copy_into_slice(&mut buf[..len]);
+    let text = &buf[..len];
+
+    if let Some(slash) = text.iter().position(|b| *b == b'/') {
+        let snellen = Snellen {
+            numerator: parse_uint(&text[..slash])?,
+            denominator: parse_uint(&text[slash + 1..])?,
+        };
+        return snellen_to_logmar(&snellen);
+    }
+    decimal_to_logmar(parse_thousandths(text)?)
+}
+
+/// Returns `true` for categorical acuity such as counting fingers (`"CF"`),
+/// hand motion (`"HM"`), light perception (`"LP"`) or no light perception
+/// (`"NLP"`). These have no logMAR value, so [`parse_acuity`] rejects them
+/// and callers comparing acuity should leave them out.
+pub fn is_categorical_acuity(value: &String) -> bool {
+    let len = value.len() as usize;
+    if len == 0 || len > MAX_CATEGORY_LEN {
+        return false;
+    }
+    let mut buf = [0u8; MAX_CATEGORY_LEN];
+    value.copy_into_slice(&mut buf[..len]);
+    let text = &buf[..len];
+    CATEGORICAL_ACUITY
+        .iter()
+        .any(|category| category.as_bytes().eq_ignore_ascii_case(text))
+}
+
+fn parse_uint(digits: &[u8]) -> Option<u32> {
+    if digits.is_empty() {
+        return None;
+    }
+    let mut value: u32 = 0;
+    for b in digits {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        value = value.checked_mul(10)?.checked_add((b - b'0') as u32)?;
+    }
+    Some(value)
+}
+
+fn parse_thousandths(text: &[u8]) -> Option<u32> {
+    let (whole, frac) = match text.iter().position(|b| *b == b'.') {
+        Some(dot) => (&text[..dot], &text[dot + 1..]),
+        None => (text, &text[..0]),
+    };
+    if frac.len() > 3 || (whole.is_empty() && frac.is_empty()) {
+        return None;
+    }
+    let whole = if whole.is_empty() {
+        0
+    } else {
+        parse_uint(whole)?
+    };
+    let mut frac_value = if frac.is_empty() {
+        0
+    } else {
+        parse_uint(frac)?
+    };
+    for _ in frac.len()..3 {
+        frac_value *= 10;
+    }
+    whole.checked_mul(DECIMAL_SCALE)?.checked_add(frac_value)
+}
+
+// ── Fixed-point math ─────────────────────────────────────────────────────────
+
+fn in_range(logmar: i128) -> Option<i32> {
+    let logmar = i32::try_from(logmar).ok()?;
+    (MIN_LOGMAR..=MAX_LOGMAR)
+        .contains(&logmar)
+        .then_some(logmar)
+}
+
+/// Divides rounding half away from zero. `den` must be positive.
+fn round_div(num: i128, den: i128) -> i128 {
+    if num >= 0 {
+        (num + den / 2) / den
+    } else {
+        -((-num + den / 2) / den)
+    }
+}
+
+/// Natural log of `num / den` scaled by [`SCALE`]. Both inputs must be
+/// positive and below 2^32.
+fn ln_ratio(num: i128, den: i128) -> i128 {
+    let (mut n, mut d, mut k) = (num, den, 0i128);
+    // Normalise the ratio into [1, 2), tracking the power of two removed.
+    while n >= 2 * d {
+        d *= 2;
+        k += 1;
+    }
+    while n < d {
+        n *= 2;
+        k -= 1;
+    }
+    // ln(z) = 2·atanh(u) with u = (z - 1) / (z + 1) ≤ 1/3.
+    let u = (n - d) * SCALE / (n + d);
+    let u2 = u * u / SCALE;
+    let mut term = u;
+    let mut sum = 0i128;
+    let mut i = 1i128;
+    while term != 0 {
+        sum += term / i;
+        term = term * u2 / SCALE;
+        i += 2;
+    }
+    k * LN2 + 2 * sum
+}
+
+/// `10^(milli / 1000)` scaled by [`SCALE`], for |milli| ≤ [`MAX_LOGMAR`].
+fn pow10_milli(milli: i32) -> i128 {
+    let whole = milli.div_euclid(LOGMAR_SCALE);
+    let frac = milli.rem_euclid(LOGMAR_SCALE) as i128;
+
+    // e^y with y = frac·ln10 / 1000 ∈ [0, ln10).
+    let y = frac * LN10 / LOGMAR_SCALE as i128;
+    let mut sum = SCALE;
+    let mut term = SCALE;
+    let mut n = 1i128;
+    loop {
+        term = term * y / (n * SCALE);
+        if term == 0 {
+            break;
+        }
+        sum += term;
+        n += 1;
+    }
+
+    let mut value = sum;
+    if whole >= 0 {
+        for _ in 0..whole {
+            value *= 10;
+        }
+    } else {
+        for _ in 0..-whole {
+            value = round_div(value, 10);
+        }
+    }
+    value
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::Env;
+
+    fn snellen(numerator: u32, denominator: u32) -> Snellen {
+        Snellen {
+            numerator,
+            denominator,
+        }
+    }
+
+    #[test]
+    fn standard_chart_lines_convert_to_logmar() {
+        let cases = [
+            (10, -301),
+            (16, -97),
+            (20, 0),
+            (25, 97),
+            (32, 204),
+            (40, 301),
+            (50, 398),
+            (63, 498),
+            (80, 602),
+            (100, 699),
+            (160, 903),
+            (200, 1000),
+            (400, 1301),
+        ];
+        for (denominator, logmar) in cases {
+            assert_eq!(
+                snellen_to_logmar(&snellen(20, denominator)),
+                Some(logmar),
+                "20/{}",
+                denominator
+            );
+        }
+        assert_eq!(snellen_to_logmar(&snellen(6, 12)), Some(301));
+        assert_eq!(snellen_to_logmar(&snellen(6, 60)), Some(1000));
+    }
+
+    #[test]
+    fn decimal_and_logmar_are_inverse() {
+        assert_eq!(decimal_to_logmar(1_000), Some(0));
+        assert_eq!(decimal_to_logmar(500), Some(301));
+        assert_eq!(decimal_to_logmar(100), Some(1000));
+        assert_eq!(decimal_to_logmar(2_000), Some(-301));
+        assert_eq!(logmar_to_decimal(0), Some(1_000));
+        assert_eq!(logmar_to_decimal(301), Some(500));
+        assert_eq!(logmar_to_decimal(1000), Some(100));
+        assert_eq!(logmar_to_decimal(-301), Some(2_000));
+        assert_eq!(logmar_to_decimal(3000), Some(1));
+    }
+
+    #[test]
+    fn logmar_decimal_round_trip_is_exact_for_good_acuity() {
+        for logmar in MIN_LOGMAR..=301 {
+            let decimal = logmar_to_decimal(logmar).unwrap();
+            assert_eq!(decimal_to_logmar(decimal), Some(logmar), "{}", logmar);
+        }
+    }
+
+    #[test]
+    fn logmar_decimal_round_trip_stays_within_rounding_everywhere() {
+        for logmar in MIN_LOGMAR..=MAX_LOGMAR {
+            let decimal = logmar_to_decimal(logmar).unwrap();
+            assert!(decimal > 0);
+            let back = decimal_to_logmar(decimal).unwrap();
+            // Half a thousandth of decimal acuity, expressed in logMAR.
+            let tolerance = 1 + 435 / (2 * decimal as i32 - 1);
+            assert!((back - logmar).abs() <= tolerance, "{}", logmar);
+        }
+    }
+
+    #[test]
+    fn snellen_conversion_is_monotonic() {
+        let mut previous = snellen_to_logmar(&snellen(20, 7)).unwrap();
+        for denominator in 8..=2_000 {
+            let logmar = snellen_to_logmar(&snellen(20, denominator)).unwrap();
+            assert!(logmar >= previous, "20/{}", denominator);
+            previous = logmar;
+        }
+    }
+
+    #[test]
+    fn logmar_to_snellen_recovers_standard_denominators() {
+        for (logmar, denominator) in [
+            (0, 20),
+            (97, 25),
+            (301, 40),
+            (398, 50),
+            (699, 100),
+            (1000, 200),
+        ] {
+            assert_eq!(
+                logmar_to_snellen(logmar, 20),
+                Some(snellen(20, denominator))
+            );
+        }
+        // 20/6 is past MIN_LOGMAR, so start where the denominator is 8.
+        for logmar in -400..=MAX_LOGMAR {
+            let s = logmar_to_snellen(logmar, 20).unwrap();
+            let back = snellen_to_logmar(&s).unwrap();
+            let tolerance = 1 + 435 / (2 * s.denominator as i32 - 1);
+            assert!((back - logmar).abs() <= tolerance, "{}", logmar);
+        }
+    }
+
+    #[test]
+    fn snellen_to_decimal_is_the_ratio() {
+        assert_eq!(snellen_to_decimal(&snellen(20, 40)), Some(500));
+        assert_eq!(snellen_to_decimal(&snellen(6, 9)), Some(667));
+        assert_eq!(snellen_to_decimal(&snellen(20, 15)), Some(1_333));
+    }
+
+    #[test]
+    fn out_of_range_values_are_rejected() {
+        assert_eq!(snellen_to_logmar(&snellen(0, 20)), None);
+        assert_eq!(snellen_to_logmar(&snellen(20, 0)), None);
+        assert_eq!(snellen_to_logmar(&snellen(20, 30_000)), None);
+        assert_eq!(snellen_to_logmar(&snellen(20, 1)), None);
+        assert_eq!(decimal_to_logmar(0), None);
+        assert_eq!(logmar_to_decimal(MAX_LOGMAR + 1), None);
+        assert_eq!(logmar_to_decimal(MIN_LOGMAR - 1), None);
+        assert_eq!(logmar_to_snellen(0, 0), None);
+    }
+
+    #[test]
+    fn chart_lines_round_to_tenths() {
+        assert_eq!(chart_line(97), 1);
+        assert_eq!(chart_line(204), 2);
+        assert_eq!(chart_line(-97), -1);
+        assert_eq!(chart_line(49), 0);
+        assert_eq!(chart_line(50), 1);
+        assert_eq!(chart_line(-50), -1);
+    }
+
+    #[test]
+    fn field_encoding_is_offset_big_endian() {
+        let bytes = logmar_to_field_bytes(MIN_LOGMAR).unwrap();
+        assert_eq!(bytes, [0u8; 32]);
+        let bytes = logmar_to_field_bytes(301).unwrap();
+        assert_eq!(&bytes[28..], &801u32.to_be_bytes());
+        assert!(bytes[..28].iter().all(|b| *b == 0));
+        assert_eq!(logmar_to_field_bytes(MAX_LOGMAR + 1), None);
+    }
+
+    #[test]
+    fn parses_snellen_and_decimal_notation() {
+        let env = Env::default();
+        let parse = |s: &str| parse_acuity(&String::from_str(&env, s));
+        assert_eq!(parse("20/20"), Some(0));
+        assert_eq!(parse("20/40"), Some(301));
+        assert_eq!(parse("6/12"), Some(301));
+        assert_eq!(parse("0.5"), Some(301));
+        assert_eq!(parse(".5"), Some(301));
+        assert_eq!(parse("1"), Some(0));
+        assert_eq!(parse("1.000"), Some(0));
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("20/"), None);
+        assert_eq!(parse("/20"), None);
+        assert_eq!(parse("0.5000"), None);
+        assert_eq!(parse("CF"), None);
+        assert_eq!(parse("20/40 -2"), None);
+        assert_eq!(parse("20/20/20"), None);
+    }
+
+    #[test]
+    fn recognizes_categorical_acuity() {
+        let env = Env::default();
+        let categorical = |s: &str| is_categorical_acuity(&String::from_str(&env, s));
+        for value in [
+            "CF",
+            "hm",
+            "LP",
+            "NLP",
+            "Counting Fingers",
+            "no light perception",
+        ] {
+            assert!(categorical(value), "{}", value);
+        }
+        for value in [
+            "",
+            "20/20",
+            "0.5",
+            "CF 2ft",
+            "fingers",
+            "no light perception!",
+        ] {
+            assert!(!categorical(value), "{}", value);
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! # Entry Proofs
+//!
+//! Shared implementation behind each contract's `prove_entry(key_spec)`
+//! entrypoint. An [`EntryProof`] carries a stored value together with the
+//! exact key and durability it lives under and the ledger it was read in, so
+//! an off-chain mirror can look the same contract-data entry up in a Stellar
+//! ledger state proof and check that the value it was handed is the one the
+//! network holds.
+//!
+//! Nothing here is trusted on its own: the proof only tells the verifier
+//! which ledger entry to check and what hash to expect.
+
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Val};
+
+use crate::storage_ttl::StorageKeySpec;
+
+// ── Types ────────────────────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EntryDurability {
+    Persistent,
+    Temporary,
+}
+
+/// A stored value and what is needed to match it against ledger state.
+///
+/// `key_xdr` is the `ScVal` key of the contract-data entry owned by
+/// `contract`; `value_xdr` is the `ScVal` stored there when `ledger_sequence`
+/// closed. `entry_hash` is SHA-256 over `value_xdr`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EntryProof {
+    pub contract: Address,
+    pub durability: EntryDurability,
+    pub key_xdr: Bytes,
+    pub value_xdr: Bytes,
+    pub entry_hash: BytesN<32>,
+    pub ledger_sequence: u32,
+    pub ledger_timestamp: u64,
+}
+
+// ── Public API ────────────────────────────────────────────────────────────────
+
+/// Builds a proof for the single persistent entry `spec` names, or `None`
+/// if it is absent. Only specs that name exactly one key are provable:
+/// `Symbol`, `Address`, `AddressPair` and an `IdRange` whose bounds are
+/// equal. Instance storage is part of the contract instance entry and is
+/// not proven entry by entry.
+pub fn prove_entry(env: &Env, spec: &StorageKeySpec) -> Option<EntryProof> {
+    match spec.clone() {
+        StorageKeySpec::Symbol(key) => prove(env, key),
+        StorageKeySpec::Address(prefix, addr) => prove(env, (prefix, addr)),
+        StorageKeySpec::AddressPair(prefix, a, b) => prove(env, (prefix, a, b)),
+        StorageKeySpec::IdRange(prefix, start, end) if start == end => prove(env, (prefix, start)),
+        _ => None,
+    }
+}
+
+fn prove<K>(env: &Env, key: K) -> Option<EntryProof>
+where
+    K: IntoVal<Env, Val> + Clone,
+{
+    let value: Val = env.storage().persistent().get(&key)?;
+    let value_xdr = value.to_xdr(env);
+    Some(EntryProof {
+        contract: env.current_contract_address(),
+        durability: EntryDurability::Persistent,
+        key_xdr: key.to_xdr(env),
+        entry_hash: env.crypto().sha256(&value_xdr).into(),
+        value_xdr,
+        ledger_sequence: env.ledger().sequence(),
+        ledger_timestamp: env.ledger().timestamp(),
+    })
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl, symbol_short, testutils::Address as _, vec, Vec};
+
+    #[contract]
+    pub struct TestContract;
+
+    #[contractimpl]
+    impl TestContract {}
+
+    fn with_contract_env<F: FnOnce(&Env)>(f: F) {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, TestContract);
+        env.as_contract(&contract_id, || f(&env));
+    }
+
+    #[test]
+    fn proves_present_entries() {
+        with_contract_env(|env| {
+            let patient = Address::generate(env);
+            let key = (symbol_short!("PAT_REC"), patient.clone());
+            let ids: Vec<u64> = vec![env, 1, 2, 3];
+            env.storage().persistent().set(&key, &ids);
+
+            let proof = prove_entry(
+                env,
+                &StorageKeySpec::Address(symbol_short!("PAT_REC"), patient),
+            )
+            .unwrap();
+            assert_eq!(proof.contract, env.current_contract_address());
+            assert_eq!(proof.durability, EntryDurability::Persistent);
+            assert_eq!(proof.key_xdr, key.to_xdr(env));
+            let ids_xdr = ids.to_xdr(env);
+            assert_eq!(proof.value_xdr, ids_xdr);
+            let expected: BytesN<32> = env.crypto().sha256(&ids_xdr).into();
+            assert_eq!(proof.entry_hash, expected);
+            assert_eq!(proof.ledger_sequence, env.ledger().sequence());
+        });
+    }
+
+    #[test]
+    fn single_id_range_is_provable() {
+        with_contract_env(|env| {
+            env.storage()
+                .persistent()
+                .set(&(symbol_short!("RECORD"), 7u64), &true);
+            let spec = StorageKeySpec::IdRange(symbol_short!("RECORD"), 7, 7);
+            assert!(prove_entry(env, &spec).is_some());
+
+            let spec = StorageKeySpec::IdRange(symbol_short!("RECORD"), 7, 8);
+            assert!(prove_entry(env, &spec).is_none());
+        });
+    }
+
+    #[test]
+    fn absent_and_multi_entry_specs_are_not_provable() {
+        with_contract_env(|env| {
+            let owner = Address::generate(env);
+            assert!(prove_entry(env, &StorageKeySpec::Symbol(symbol_short!("NONE"))).is_none());
+            assert!(prove_entry(env, &StorageKeySpec::Instance).is_none());
+            assert!(prove_entry(
+                env,
+                &StorageKeySpec::AddressIndex(symbol_short!("IDX"), owner, symbol_short!("ITEM"))
+            )
+            .is_none());
+        });
+    }
+}
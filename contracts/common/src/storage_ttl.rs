@@ -0,0 +1,226 @@
+//! # Storage TTL Maintenance
+//!
+//! Shared implementation behind each contract's `extend_storage(keys_spec,
+//! limit)` entrypoint. Callers describe the entries to keep alive with
+//! [`StorageKeySpec`] values that mirror the key shapes used across the
+//! workspace (`(Symbol, u64)`, `(Symbol, Address)`, …), and the contract
+//! bumps the TTL of every matching entry that currently exists.
+//!
+//! Every inspected key — present or not — counts towards `limit`, so the
+//! cost of a single call is bounded regardless of the spec.
+
+use soroban_sdk::{contracttype, Address, Env, Symbol, Vec};
+
+// ── TTL constants (mirror common convention) ─────────────────────────────────
+
+pub const DEFAULT_TTL_THRESHOLD: u32 = 5_184_000;
+pub const DEFAULT_TTL_EXTEND_TO: u32 = 10_368_000;
+
+// ── Types ────────────────────────────────────────────────────────────────────
+
+/// Describes a set of storage entries whose TTL should be extended.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StorageKeySpec {
+    /// The contract instance and its instance storage.
+    Instance,
+    /// A persistent key that is a bare symbol.
+    Symbol(Symbol),
+    /// Persistent `(prefix, id)` keys for every id in `[start, end]`.
+    IdRange(Symbol, u64, u64),
+    /// A persistent `(prefix, address)` key.
+    Address(Symbol, Address),
+    /// A persistent `(prefix, address, address)` key.
+    AddressPair(Symbol, Address, Address),
+    /// The `Vec<u64>` index stored at `(index_prefix, owner)` and every
+    /// `(item_prefix, id)` entry it lists.
+    AddressIndex(Symbol, Address, Symbol),
+}
+
+// ── Public API ────────────────────────────────────────────────────────────────
+
+/// Extends the TTL of the entries described by `specs`, inspecting at most
+/// `limit` keys. Returns the number of entries that were extended.
+pub fn extend_storage(
+    env: &Env,
+    specs: &Vec<StorageKeySpec>,
+    limit: u32,
+    threshold: u32,
+    extend_to: u32,
+) -> u32 {
+    let mut budget = limit;
+    let mut extended = 0u32;
+
+    for spec in specs.iter() {
+        if budget == 0 {
+            break;
+        }
+        match spec {
+            StorageKeySpec::Instance => {
+                budget -= 1;
+                env.storage().instance().extend_ttl(threshold, extend_to);
+                extended += 1;
+            }
+            StorageKeySpec::Symbol(key) => {
+                budget -= 1;
+                if bump(env, &key, threshold, extend_to) {
+                    extended += 1;
+                }
+            }
+            StorageKeySpec::IdRange(prefix, start, end) => {
+                let mut id = start;
+                while id <= end && budget > 0 {
+                    budget -= 1;
+                    if bump(env, &(prefix.clone(), id), threshold, extend_to) {
+                        extended += 1;
+                    }
+                    id = match id.checked_add(1) {
+                        Some(next) => next,
+                        None => break,
+                    };
+                }
+            }
+            StorageKeySpec::Address(prefix, addr) => {
+                budget -= 1;
+                if bump(env, &(prefix, addr), threshold, extend_to) {
+                    extended += 1;
+                }
+            }
+            StorageKeySpec::AddressPair(prefix, a, b) => {
+                budget -= 1;
+                if bump(env, &(prefix, a, b), threshold, extend_to) {
+                    extended += 1;
+                }
+            }
+            StorageKeySpec::AddressIndex(index_prefix, owner, item_prefix) => {
+                budget -= 1;
+                let index_key = (index_prefix, owner);
+                let ids: Option<Vec<u64>> = env.storage().persistent().get(&index_key);
+                let Some(ids) = ids else { continue };
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&index_key, threshold, extend_to);
+                extended += 1;
+
+                for id in ids.iter() {
+                    if budget == 0 {
+                        break;
+                    }
+                    budget -= 1;
+                    if bump(env, &(item_prefix.clone(), id), threshold, extend_to) {
+                        extended += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    extended
+}
+
+fn bump<K>(env: &Env, key: &K, threshold: u32, extend_to: u32) -> bool
+where
+    K: soroban_sdk::IntoVal<Env, soroban_sdk::Val>,
+{
+    if !env.storage().persistent().has(key) {
+        return false;
+    }
+    env.storage()
+        .persistent()
+        .extend_ttl(key, threshold, extend_to);
+    true
+}
+
+// ── Tests ─────────────────────────────────────────────────────────────────────
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{contract, contractimpl, symbol_short, testutils::Address as _, vec, Env};
+
+    #[contract]
+    pub struct TestContract;
+
+    #[contractimpl]
+    impl TestContract {}
+
+    fn with_contract_env<F: FnOnce(&Env)>(f: F) {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, TestContract);
+        env.as_contract(&contract_id, || {
+            f(&env);
+        });
+    }
+
+    fn extend(env: &Env, specs: &Vec<StorageKeySpec>, limit: u32) -> u32 {
+        extend_storage(
+            env,
+            specs,
+            limit,
+            DEFAULT_TTL_THRESHOLD,
+            DEFAULT_TTL_EXTEND_TO,
+        )
+    }
+
+    #[test]
+    fn missing_keys_are_skipped() {
+        with_contract_env(|env| {
+            let user = Address::generate(env);
+            let specs = vec![
+                env,
+                StorageKeySpec::Address(symbol_short!("USER"), user),
+                StorageKeySpec::Symbol(symbol_short!("NONE")),
+            ];
+            assert_eq!(extend(env, &specs, 10), 0);
+        });
+    }
+
+    #[test]
+    fn id_range_extends_existing_entries_within_limit() {
+        with_contract_env(|env| {
+            for id in 1u64..=5 {
+                env.storage()
+                    .persistent()
+                    .set(&(symbol_short!("RECORD"), id), &id);
+            }
+            let specs = vec![env, StorageKeySpec::IdRange(symbol_short!("RECORD"), 1, 10)];
+            assert_eq!(extend(env, &specs, 3), 3);
+            assert_eq!(extend(env, &specs, 100), 5);
+        });
+    }
+
+    #[test]
+    fn address_index_follows_listed_ids() {
+        with_contract_env(|env| {
+            let patient = Address::generate(env);
+            let ids = vec![env, 2u64, 4u64];
+            env.storage()
+                .persistent()
+                .set(&(symbol_short!("PAT_REC"), patient.clone()), &ids);
+            for id in ids.iter() {
+                env.storage()
+                    .persistent()
+                    .set(&(symbol_short!("RECORD"), id), &id);
+            }
+
+            let specs = vec![
+                env,
+                StorageKeySpec::AddressIndex(
+                    symbol_short!("PAT_REC"),
+                    patient,
+                    symbol_short!("RECORD"),
+                ),
+            ];
+            // Index entry plus both records.
+            assert_eq!(extend(env, &specs, 10), 3);
+        });
+    }
+
+    #[test]
+    fn zero_limit_does_nothing() {
+        with_contract_env(|env| {
+            let specs = vec![env, StorageKeySpec::Instance];
+            assert_eq!(extend(env, &specs, 0), 0);
+        });
+    }
+}
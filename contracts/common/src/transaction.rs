@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Symbol, String, Vec, Env, symbol_short};
+use soroban_sdk::{contracttype, Address, BytesN, Symbol, String, Val, Vec, Env, symbol_short};
 
 /// Transaction phases for two-phase commit protocol
 #[contracttype]
@@ -66,6 +66,24 @@ pub struct TransactionOperation {
     pub error: Option<String>,
 }
 
+/// Typed value of a transaction metadata tag
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TagValue {
+    Text(String),
+    Number(u64),
+    Hash(BytesN<32>),
+    Flag(bool),
+}
+
+/// Key/value metadata attached to a transaction
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionTag {
+    pub key: Symbol,
+    pub value: TagValue,
+}
+
 /// Transaction log entry for tracking orchestrated transactions
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -88,8 +106,8 @@ pub struct TransactionLog {
     pub timeout_seconds: u64,
     /// Error message if transaction failed
     pub error: Option<String>,
-    /// Metadata for transaction
-    pub metadata: Vec<String>,
+    /// Metadata tags for transaction
+    pub metadata: Vec<TransactionTag>,
 }
 
 /// Deadlock detection information
@@ -106,6 +124,27 @@ pub struct DeadlockInfo {
     pub detected_at: u64,
 }
 
+/// Inverse of a prepared operation, declared by the participant itself so
+/// rollback does not depend on a `rollback_*` naming convention
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct Compensation {
+    /// Function to invoke on the participant contract
+    pub function: Symbol,
+    /// Arguments for the compensating call
+    pub args: Vec<Val>,
+}
+
+/// Returned by participant prepare hooks that register a compensation
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct PreparedOperation {
+    /// Participant-side identifier of the provisional write (e.g. record id)
+    pub id: u64,
+    /// How to undo the provisional write
+    pub compensation: Compensation,
+}
+
 /// Rollback information for failed operations
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -209,6 +248,10 @@ pub enum TransactionError {
     ContractCallFailed = 1010,
     /// Resource already locked
     ResourceLocked = 1011,
+    /// Function is not on the target contract's allowlist
+    FunctionNotAllowed = 1012,
+    /// Participant reports an interface version the orchestrator cannot call
+    IncompatibleInterface = 1013,
 }
 
 /// Helper functions for transaction management
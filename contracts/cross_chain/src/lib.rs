@@ -11,13 +11,22 @@ pub use relay::StateRootAnchor;
 
 use soroban_sdk::{
     contract, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, String,
-    Symbol,
+    Symbol, Vec,
 };
+use common::interface::{self, InterfaceVersion};
+use common::storage_ttl::{self, StorageKeySpec};
 
 /// Storage keys
 const ADMIN: Symbol = symbol_short!("ADMIN");
 const INITIALIZED: Symbol = symbol_short!("INIT");
 
+/// Cross-contract interface version reported by `get_interface_version`
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: interface::CROSS_CHAIN,
+    major: 1,
+    minor: 0,
+};
+
 /// TTL constants for persistent storage (in ledgers)
 const TTL_THRESHOLD: u32 = 17_280; // ~1 day
 const TTL_EXTEND_TO: u32 = 518_400; // ~30 days
@@ -65,6 +74,11 @@ impl CrossChainContract {
         Ok(())
     }
 
+    /// Version of the interface this contract exposes to other contracts.
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
     /// Add a trusted relayer allowed to submit cross-chain messages
     pub fn add_relayer(env: Env, caller: Address, relayer: Address) -> Result<(), CrossChainError> {
         caller.require_auth();
@@ -246,6 +260,12 @@ impl CrossChainContract {
     pub fn get_latest_root(env: Env, chain_id: Symbol) -> Option<StateRootAnchor> {
         relay::get_latest_root(&env, chain_id)
     }
+
+    /// Extends the TTL of the entries described by `keys_spec`, inspecting at
+    /// most `limit` keys. Permissionless: it only keeps existing state alive.
+    pub fn extend_storage(env: Env, keys_spec: Vec<StorageKeySpec>, limit: u32) -> u32 {
+        storage_ttl::extend_storage(&env, &keys_spec, limit, TTL_THRESHOLD, TTL_EXTEND_TO)
+    }
 }
 
 #[cfg(test)]
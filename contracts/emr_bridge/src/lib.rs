@@ -8,6 +8,8 @@ pub mod types;
 mod test;
 
 use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String, Symbol, Vec};
+use common::interface::{self, InterfaceVersion};
+use common::storage_ttl::{self, StorageKeySpec};
 use types::{
     DataExchangeRecord, DataFormat, EmrProvider, EmrSystem, ExchangeDirection, FieldMapping,
     ProviderStatus, SyncStatus, SyncVerification,
@@ -17,6 +19,13 @@ use types::{
 const ADMIN: Symbol = symbol_short!("ADMIN");
 const INITIALIZED: Symbol = symbol_short!("INIT");
 
+/// Cross-contract interface version reported by `get_interface_version`
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: interface::EMR_BRIDGE,
+    major: 1,
+    minor: 0,
+};
+
 /// TTL constants for persistent storage (in ledgers)
 const TTL_THRESHOLD: u32 = 17_280; // ~1 day
 const TTL_EXTEND_TO: u32 = 518_400; // ~30 days
@@ -67,6 +76,11 @@ impl EmrBridgeContract {
         Ok(())
     }
 
+    /// Version of the interface this contract exposes to other contracts.
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
     /// Get the admin address
     pub fn get_admin(env: Env) -> Result<Address, EmrBridgeError> {
         env.storage()
@@ -546,4 +560,10 @@ impl EmrBridgeContract {
         }
         Ok(())
     }
+
+    /// Extends the TTL of the entries described by `keys_spec`, inspecting at
+    /// most `limit` keys. Permissionless: it only keeps existing state alive.
+    pub fn extend_storage(env: Env, keys_spec: Vec<StorageKeySpec>, limit: u32) -> u32 {
+        storage_ttl::extend_storage(&env, &keys_spec, limit, TTL_THRESHOLD, TTL_EXTEND_TO)
+    }
 }
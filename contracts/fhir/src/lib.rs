@@ -4,14 +4,28 @@
 mod test;
 mod types;
 
-use soroban_sdk::{contract, contractimpl, Env, String};
+use soroban_sdk::{contract, contractimpl, Env, String, Vec};
+use common::interface::{self, InterfaceVersion};
+use common::storage_ttl::{self, StorageKeySpec};
 use types::{Gender, Observation, ObservationStatus, Patient};
 
+/// Cross-contract interface version reported by `get_interface_version`
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: interface::FHIR,
+    major: 1,
+    minor: 0,
+};
+
 #[contract]
 pub struct FhirContract;
 
 #[contractimpl]
 impl FhirContract {
+    /// Version of the interface this contract exposes to other contracts.
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
     /// Creates a FHIR Patient resource.
     pub fn create_patient(
         _env: Env,
@@ -68,4 +82,16 @@ impl FhirContract {
             && !observation.code_system.is_empty()
             && !observation.subject_id.is_empty()
     }
+
+    /// Extends the TTL of the entries described by `keys_spec`, inspecting at
+    /// most `limit` keys. Permissionless: it only keeps existing state alive.
+    pub fn extend_storage(env: Env, keys_spec: Vec<StorageKeySpec>, limit: u32) -> u32 {
+        storage_ttl::extend_storage(
+            &env,
+            &keys_spec,
+            limit,
+            storage_ttl::DEFAULT_TTL_THRESHOLD,
+            storage_ttl::DEFAULT_TTL_EXTEND_TO,
+        )
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{
     types::{Gender, ObservationStatus},
-    FhirContract, FhirContractClient,
+    FhirContract, FhirContractClient, INTERFACE_VERSION,
 };
 use soroban_sdk::{Env, String};
 
@@ -64,3 +64,14 @@ fn test_observation_creation_and_validation() {
     let is_valid = client.validate_observation(&observation);
     assert!(is_valid);
 }
+
+#[test]
+fn test_reports_interface_version() {
+    let env = Env::default();
+    let contract_id = env.register(FhirContract, ());
+    let client = FhirContractClient::new(&env, &contract_id);
+
+    let version = client.get_interface_version();
+    assert_eq!(version, INTERFACE_VERSION);
+    assert_eq!(version.interface, common::interface::FHIR);
+}
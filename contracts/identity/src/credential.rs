@@ -9,6 +9,7 @@
 
 #![allow(deprecated)]
 
+use common::interface::{self, InterfaceRange};
 use soroban_sdk::{symbol_short, Address, BytesN, Env, Symbol, Vec};
 
 // Re-use the proof type definitions from the zk_verifier crate.
@@ -20,6 +21,13 @@ use zk_verifier::{AccessRequest, ZkVerifierContractClient};
 
 const ZK_VERIFIER: Symbol = symbol_short!("ZK_VER");
 
+/// zk_verifier interface versions this contract can call
+pub const ZK_VERIFIER_INTERFACE: InterfaceRange = InterfaceRange {
+    interface: interface::ZK_VERIFIER,
+    major: 1,
+    min_minor: 0,
+};
+
 // ── Errors ───────────────────────────────────────────────────────────────────
 
 #[soroban_sdk::contracterror]
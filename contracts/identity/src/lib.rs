@@ -7,6 +7,8 @@ pub mod recovery;
 use credential::CredentialError;
 use recovery::{RecoveryError, RecoveryRequest};
 use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec, String};
+use common::interface::{self, InterfaceVersion};
+use common::storage_ttl::{self, StorageKeySpec};
 
 /// Preparation data for guardian addition
 #[contracttype]
@@ -41,6 +43,13 @@ const ADMIN: Symbol = symbol_short!("ADMIN");
 const INITIALIZED: Symbol = symbol_short!("INIT");
 const HOLDER_BIND_PREFIX: &str = "HLD_BIND";
 
+/// Cross-contract interface version reported by `get_interface_version`
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: interface::IDENTITY,
+    major: 1,
+    minor: 0,
+};
+
 /// Re-export credential error for downstream consumers.
 pub use credential::CredentialError as CredentialVerificationError;
 
@@ -394,10 +403,18 @@ impl IdentityContract {
     ) -> Result<(), RecoveryError> {
         caller.require_auth();
         Self::require_active_owner(&env, &caller)?;
+        if !interface::is_compatible(&env, &verifier_id, &credential::ZK_VERIFIER_INTERFACE) {
+            return Err(RecoveryError::IncompatibleInterface);
+        }
         credential::set_zk_verifier(&env, &verifier_id);
         Ok(())
     }
 
+    /// Version of the interface this contract exposes to other contracts.
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
     /// Get the stored `zk_verifier` contract address.
     pub fn get_zk_verifier(env: Env) -> Option<Address> {
         credential::get_zk_verifier(&env)
@@ -532,4 +549,19 @@ impl IdentityContract {
         }
         Ok(())
     }
+
+    /// Extends the TTL of the entries described by `keys_spec`, inspecting at
+    /// most `limit` keys. Permissionless: it only keeps existing state alive.
+    pub fn extend_storage(env: Env, keys_spec: Vec<StorageKeySpec>, limit: u32) -> u32 {
+        storage_ttl::extend_storage(
+            &env,
+            &keys_spec,
+            limit,
+            storage_ttl::DEFAULT_TTL_THRESHOLD,
+            storage_ttl::DEFAULT_TTL_EXTEND_TO,
+        )
+    }
 }
+
+#[cfg(test)]
+mod test_properties;
@@ -39,6 +39,8 @@ pub enum RecoveryError {
     InsufficientApprovals = 13,
     CooldownNotExpired = 14,
     OwnerDeactivated = 15,
+    /// The contract offered as zk_verifier reports an unsupported interface version
+    IncompatibleInterface = 16,
 }
 
 // ── Types ────────────────────────────────────────────────────────────────────
@@ -0,0 +1,95 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::arithmetic_side_effects
+)]
+//! Property tests for recovery threshold math.
+//!
+//! Invariants tested:
+//! - A threshold is accepted iff `1 <= threshold <= guardians`
+//! - Recovery executes iff approvals reach the threshold and the cooldown
+//!   has passed; otherwise ownership is unchanged
+
+extern crate std;
+
+use proptest::prelude::*;
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env};
+
+use crate::recovery::RecoveryError;
+use crate::{IdentityContract, IdentityContractClient};
+
+const COOLDOWN: u64 = 172_800;
+
+fn setup(
+    guardians: u32,
+) -> (
+    Env,
+    IdentityContractClient<'static>,
+    Address,
+    std::vec::Vec<Address>,
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(IdentityContract, ());
+    let client = IdentityContractClient::new(&env, &contract_id);
+    let owner = Address::generate(&env);
+    client.initialize(&owner);
+
+    let guardians: std::vec::Vec<Address> =
+        (0..guardians).map(|_| Address::generate(&env)).collect();
+    for guardian in &guardians {
+        client.add_guardian(&owner, guardian);
+    }
+    (env, client, owner, guardians)
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn prop_threshold_bounded_by_guardian_count(guardians in 0u32..=5, threshold in 0u32..=8) {
+        let (_env, client, owner, _) = setup(guardians);
+        let result = client.try_set_recovery_threshold(&owner, &threshold);
+        if threshold >= 1 && threshold <= guardians {
+            prop_assert!(result.is_ok());
+            prop_assert_eq!(client.get_recovery_threshold(&owner), threshold);
+        } else {
+            prop_assert_eq!(result, Err(Ok(RecoveryError::InvalidThreshold)));
+        }
+    }
+
+    #[test]
+    fn prop_execute_requires_threshold_and_cooldown(
+        guardians in 3u32..=5,
+        threshold_seed in any::<u32>(),
+        approvals_seed in any::<u32>(),
+        elapsed in 0u64..=2 * COOLDOWN,
+    ) {
+        let threshold = 1 + threshold_seed % guardians;
+        let approvals = 1 + approvals_seed % guardians;
+        let (env, client, owner, guardians) = setup(guardians);
+        client.set_recovery_threshold(&owner, &threshold);
+        env.ledger().set_timestamp(1_000);
+
+        let new_address = Address::generate(&env);
+        client.initiate_recovery(&guardians[0], &owner, &new_address);
+        for guardian in guardians.iter().take(approvals as usize).skip(1) {
+            client.approve_recovery(guardian, &owner);
+        }
+
+        env.ledger().set_timestamp(1_000 + elapsed);
+        let result = client.try_execute_recovery(&guardians[0], &owner);
+        if approvals < threshold {
+            prop_assert_eq!(result, Err(Ok(RecoveryError::InsufficientApprovals)));
+        } else if elapsed < COOLDOWN {
+            prop_assert_eq!(result, Err(Ok(RecoveryError::CooldownNotExpired)));
+        } else {
+            prop_assert_eq!(result, Ok(Ok(new_address.clone())));
+        }
+
+        let executed = approvals >= threshold && elapsed >= COOLDOWN;
+        prop_assert_eq!(client.is_owner_active(&new_address), executed);
+        prop_assert_eq!(client.is_owner_active(&owner), !executed);
+    }
+}
@@ -342,3 +342,18 @@ fn test_zk_credential_verifier_not_set() {
         "Error should be VerifierNotSet"
     );
 }
+
+#[test]
+fn test_set_zk_verifier_rejects_other_interface() {
+    let (env, client, owner) = setup();
+    // Another identity contract reports a version, but of the wrong interface.
+    let other_id = env.register(IdentityContract, ());
+
+    let result = client.try_set_zk_verifier(&owner, &other_id);
+    assert_eq!(result.unwrap_err(), Ok(RecoveryError::IncompatibleInterface));
+    assert_eq!(client.get_zk_verifier(), None);
+
+    let zk_id = env.register(ZkVerifierContract, ());
+    client.set_zk_verifier(&owner, &zk_id);
+    assert_eq!(client.get_zk_verifier(), Some(zk_id));
+}
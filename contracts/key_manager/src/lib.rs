@@ -11,6 +11,9 @@ use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env,
     Symbol, Vec,
 };
+use common::audit_stream::{self, AuditStreamEntry};
+use common::interface::{self, InterfaceVersion};
+use common::storage_ttl::{self, StorageKeySpec};
 
 use identity::IdentityContractClient;
 
@@ -28,6 +31,13 @@ const KEY_VER: Symbol = symbol_short!("KEY_VER");
 const RECOVERY: Symbol = symbol_short!("RECOV");
 const AUDIT: Symbol = symbol_short!("AUDIT");
 
+/// Cross-contract interface version reported by `get_interface_version`
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: interface::KEY_MANAGER,
+    major: 1,
+    minor: 0,
+};
+
 const RECOVERY_COOLDOWN: u64 = 86_400; // 24 hours
 
 #[contracttype]
@@ -155,6 +165,11 @@ impl KeyManagerContract {
         env.storage().instance().set(&IDENTITY, &identity_contract);
     }
 
+    /// Version of the interface this contract exposes to other contracts.
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
     pub fn set_identity_contract(
         env: Env,
         caller: Address,
@@ -162,6 +177,7 @@ impl KeyManagerContract {
     ) -> Result<(), ContractError> {
         Self::require_admin(&env, &caller)?;
         env.storage().instance().set(&IDENTITY, &identity_contract);
+        audit_stream::append(&env, &caller, symbol_short!("ID_SET"), Some(identity_contract));
         Ok(())
     }
 
@@ -495,6 +511,12 @@ impl KeyManagerContract {
         env.storage().instance().get(&AUDIT_TAIL)
     }
 
+    /// Returns global audit stream entries with sequence numbers in
+    /// `[from_seq, to_seq]`.
+    pub fn get_audit_range(env: Env, from_seq: u64, to_seq: u64) -> Vec<AuditStreamEntry> {
+        audit_stream::get_audit_range(&env, from_seq, to_seq)
+    }
+
     fn require_admin(env: &Env, caller: &Address) -> Result<(), ContractError> {
         caller.require_auth();
         let admin: Address = env
@@ -670,6 +692,7 @@ impl KeyManagerContract {
             .unwrap_or(BytesN::from_array(env, &[0u8; 32]));
 
         let entry_hash = Self::hash_audit(env, &prev_hash, details);
+        audit_stream::append(env, &actor, action.clone(), None);
 
         let entry = AuditEntry {
             seq,
@@ -710,4 +733,16 @@ impl KeyManagerContract {
         data.extend_from_array(&request.approvals.len().to_be_bytes());
         env.crypto().sha256(&data).into()
     }
+
+    /// Extends the TTL of the entries described by `keys_spec`, inspecting at
+    /// most `limit` keys. Permissionless: it only keeps existing state alive.
+    pub fn extend_storage(env: Env, keys_spec: Vec<StorageKeySpec>, limit: u32) -> u32 {
+        storage_ttl::extend_storage(
+            &env,
+            &keys_spec,
+            limit,
+            storage_ttl::DEFAULT_TTL_THRESHOLD,
+            storage_ttl::DEFAULT_TTL_EXTEND_TO,
+        )
+    }
 }
@@ -138,3 +138,25 @@ fn test_hierarchy_validation() {
     );
     assert!(matches!(err, Err(Ok(ContractError::InvalidHierarchy))));
 }
+
+#[test]
+fn test_global_audit_range_tracks_key_actions() {
+    let (env, client, _identity, admin) = setup();
+
+    let policy = KeyPolicy {
+        max_uses: 0,
+        not_before: 0,
+        not_after: 0,
+        allowed_ops: Vec::new(&env),
+    };
+    let key_bytes = BytesN::from_array(&env, &[3u8; 32]);
+    let key_id = client.create_master_key(&admin, &KeyType::Signing, &policy, &0u64, &key_bytes);
+    client.rotate_key(&admin, &key_id);
+
+    let entries = client.get_audit_range(&1, &10);
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries.get(0).unwrap().seq, 1);
+    assert_eq!(entries.get(0).unwrap().action, symbol_short!("KEY_NEW"));
+    assert_eq!(entries.get(1).unwrap().seq, 2);
+    assert_eq!(entries.get(1).unwrap().action, symbol_short!("KEY_ROT"));
+}
@@ -40,6 +40,8 @@ use quota::{QuotaError, QuotaUsage, TenantQuota};
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env, Symbol, Vec,
 };
+use teye_common::interface::{self, InterfaceVersion};
+use teye_common::storage_ttl::{self, StorageKeySpec};
 
 // ── Storage keys ──────────────────────────────────────────────────────────────
 
@@ -50,6 +52,13 @@ const TENANT_LIST: Symbol = symbol_short!("TEN_LST");
 const PARENT_KEY: Symbol = symbol_short!("PARENT");
 const GAS_COSTS: Symbol = symbol_short!("GAS_CST");
 
+/// Cross-contract interface version reported by `get_interface_version`
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: interface::METERING,
+    major: 1,
+    minor: 0,
+};
+
 /// Percentage of total quota consumed before a `QuotaAlertEvent` fires.
 const ALERT_THRESHOLD_PCT: u64 = 80;
 
@@ -210,6 +219,11 @@ impl MeteringContract {
         Ok(())
     }
 
+    /// Version of the interface this contract exposes to other contracts.
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
     // ── Admin helpers ─────────────────────────────────────────────────────────
 
     fn require_admin(env: &Env, caller: &Address) -> Result<(), MeteringError> {
@@ -745,6 +759,12 @@ impl MeteringContract {
             .get(&TENANT_LIST)
             .unwrap_or(Vec::new(&env))
     }
+
+    /// Extends the TTL of the entries described by `keys_spec`, inspecting at
+    /// most `limit` keys. Permissionless: it only keeps existing state alive.
+    pub fn extend_storage(env: Env, keys_spec: Vec<StorageKeySpec>, limit: u32) -> u32 {
+        storage_ttl::extend_storage(&env, &keys_spec, limit, TTL_THRESHOLD, TTL_EXTEND_TO)
+    }
 }
 
 // ── Tests ─────────────────────────────────────────────────────────────────────
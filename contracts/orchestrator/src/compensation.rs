@@ -0,0 +1,30 @@
+use soroban_sdk::{symbol_short, Env, Symbol};
+use common::transaction::Compensation;
+
+/// Storage prefix for compensations registered during prepare
+const COMPENSATION: Symbol = symbol_short!("COMP");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Records the inverse a participant declared when preparing `operation_id`
+pub fn register(env: &Env, transaction_id: u64, operation_id: u64, compensation: &Compensation) {
+    let key = (COMPENSATION, transaction_id, operation_id);
+    env.storage().persistent().set(&key, compensation);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+pub fn get(env: &Env, transaction_id: u64, operation_id: u64) -> Option<Compensation> {
+    env.storage()
+        .persistent()
+        .get(&(COMPENSATION, transaction_id, operation_id))
+}
+
+/// Drops a compensation once its operation is committed or undone
+pub fn clear(env: &Env, transaction_id: u64, operation_id: u64) {
+    env.storage()
+        .persistent()
+        .remove(&(COMPENSATION, transaction_id, operation_id));
+}
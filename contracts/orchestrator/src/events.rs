@@ -1,6 +1,9 @@
 use soroban_sdk::{Env, symbol_short, Address, String, Vec};
+use common::event_redaction;
 use common::transaction::{TransactionLog, TransactionPhase, DeadlockInfo, ContractType};
 
+use crate::sla::SlaBreach;
+
 /// Event publisher for orchestrator events.
 /// All symbol_short! values must be ≤9 characters.
 pub struct EventPublisher;
@@ -8,15 +11,44 @@ pub struct EventPublisher;
 impl EventPublisher {
     /// Publish transaction started event
     pub fn transaction_started(env: &Env, log: &TransactionLog) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("TX_START"), log.transaction_id),
             (log.initiator.clone(), log.created_at, log.timeout_seconds),
         );
     }
 
+    /// Publish function added to a contract's allowlist
+    pub fn function_allowed(env: &Env, contract_type: &ContractType, function_name: &String, admin: &Address) {
+        event_redaction::publish(
+            env,
+            (symbol_short!("FN_ALLOW"), contract_type.clone()),
+            (function_name.clone(), admin.clone(), env.ledger().timestamp()),
+        );
+    }
+
+    /// Publish function removed from a contract's allowlist
+    pub fn function_disallowed(env: &Env, contract_type: &ContractType, function_name: &String, admin: &Address) {
+        event_redaction::publish(
+            env,
+            (symbol_short!("FN_DISALW"), contract_type.clone()),
+            (function_name.clone(), admin.clone(), env.ledger().timestamp()),
+        );
+    }
+
+    /// Publish participant contract registered
+    pub fn participant_registered(env: &Env, contract_type: &ContractType, address: &Address, admin: &Address) {
+        event_redaction::publish(
+            env,
+            (symbol_short!("PART_REG"), contract_type.clone()),
+            (address.clone(), admin.clone(), env.ledger().timestamp()),
+        );
+    }
+
     /// Publish transaction prepared event
     pub fn transaction_prepared(env: &Env, log: &TransactionLog) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("TX_PREP"), log.transaction_id),
             (log.updated_at, log.operations.len() as u32),
         );
@@ -24,7 +56,8 @@ impl EventPublisher {
 
     /// Publish transaction committed event
     pub fn transaction_committed(env: &Env, log: &TransactionLog) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("TX_COMIT"), log.transaction_id),
             (log.updated_at, log.operations.len() as u32),
         );
@@ -32,7 +65,8 @@ impl EventPublisher {
 
     /// Publish transaction rolled back event
     pub fn transaction_rolled_back(env: &Env, log: &TransactionLog) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("TX_RBACK"), log.transaction_id),
             (log.updated_at, log.phase.clone(), log.error.clone()),
         );
@@ -40,7 +74,8 @@ impl EventPublisher {
 
     /// Publish transaction timed out event
     pub fn transaction_timed_out(env: &Env, log: &TransactionLog) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("TX_TMOUT"), log.transaction_id),
             (log.updated_at, log.created_at + log.timeout_seconds),
         );
@@ -48,7 +83,8 @@ impl EventPublisher {
 
     /// Publish operation prepared event
     pub fn operation_prepared(env: &Env, transaction_id: u64, operation_id: u64, contract_type: &ContractType) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("OP_PREP"), transaction_id, operation_id),
             (contract_type.clone(), env.ledger().timestamp()),
         );
@@ -56,7 +92,8 @@ impl EventPublisher {
 
     /// Publish operation committed event
     pub fn operation_committed(env: &Env, transaction_id: u64, operation_id: u64, contract_type: &ContractType) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("OP_COMIT"), transaction_id, operation_id),
             (contract_type.clone(), env.ledger().timestamp()),
         );
@@ -64,7 +101,8 @@ impl EventPublisher {
 
     /// Publish operation failed event
     pub fn operation_failed(env: &Env, transaction_id: u64, operation_id: u64, contract_type: &ContractType, error: &String) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("OP_FAIL"), transaction_id, operation_id),
             (contract_type.clone(), error.clone(), env.ledger().timestamp()),
         );
@@ -72,7 +110,8 @@ impl EventPublisher {
 
     /// Publish operation rolled back event
     pub fn operation_rolled_back(env: &Env, transaction_id: u64, operation_id: u64, contract_type: &ContractType) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("OP_RBACK"), transaction_id, operation_id),
             (contract_type.clone(), env.ledger().timestamp()),
         );
@@ -80,7 +119,8 @@ impl EventPublisher {
 
     /// Publish rollback failed event
     pub fn rollback_failed(env: &Env, transaction_id: u64, operation_id: u64, contract_type: &ContractType, error: &String) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("RB_FAIL"), transaction_id, operation_id),
             (contract_type.clone(), error.clone(), env.ledger().timestamp()),
         );
@@ -88,7 +128,8 @@ impl EventPublisher {
 
     /// Publish deadlock detected event
     pub fn deadlock_detected(env: &Env, deadlock_info: &DeadlockInfo) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("DEADLOCK"), deadlock_info.transaction_id),
             (deadlock_info.conflicting_transactions.clone(), deadlock_info.conflicting_resources.clone(), deadlock_info.detected_at),
         );
@@ -96,7 +137,8 @@ impl EventPublisher {
 
     /// Publish resource locked event
     pub fn resource_locked(env: &Env, transaction_id: u64, resource: &String, contract_address: &Address) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("RES_LOCK"), transaction_id),
             (resource.clone(), contract_address.clone(), env.ledger().timestamp()),
         );
@@ -104,7 +146,8 @@ impl EventPublisher {
 
     /// Publish resource unlocked event
     pub fn resource_unlocked(env: &Env, transaction_id: u64, resource: &String) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("RES_UNLK"), transaction_id),
             (resource.clone(), env.ledger().timestamp()),
         );
@@ -112,7 +155,8 @@ impl EventPublisher {
 
     /// Publish phase transition event
     pub fn phase_transition(env: &Env, transaction_id: u64, from_phase: &TransactionPhase, to_phase: &TransactionPhase) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("PH_TRANS"), transaction_id),
             (from_phase.clone(), to_phase.clone(), env.ledger().timestamp()),
         );
@@ -120,7 +164,8 @@ impl EventPublisher {
 
     /// Publish performance metrics event
     pub fn performance_metrics(env: &Env, transaction_id: u64, prepare_time: u64, commit_time: u64, total_time: u64) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("PERF"), transaction_id),
             (prepare_time, commit_time, total_time),
         );
@@ -128,7 +173,8 @@ impl EventPublisher {
 
     /// Publish gas consumption event
     pub fn gas_consumption(env: &Env, transaction_id: u64, operation_id: u64, gas_used: u64) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("GAS"), transaction_id, operation_id),
             (gas_used, env.ledger().timestamp()),
         );
@@ -136,7 +182,8 @@ impl EventPublisher {
 
     /// Publish health check event
     pub fn health_check(env: &Env, active_transactions: u64, locked_resources: u64, pending_timeouts: u64) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("HEALTH"),),
             (active_transactions, locked_resources, pending_timeouts, env.ledger().timestamp()),
         );
@@ -144,7 +191,8 @@ impl EventPublisher {
 
     /// Publish audit trail event
     pub fn audit_trail(env: &Env, transaction_id: u64, action: &String, actor: &Address, details: Vec<String>) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("AUDIT"), transaction_id, action.clone()),
             (actor.clone(), details, env.ledger().timestamp()),
         );
@@ -152,7 +200,8 @@ impl EventPublisher {
 
     /// Publish security event
     pub fn security_event(env: &Env, event_type: &String, severity: &String, details: Vec<String>) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("SECURITY"), event_type.clone(), severity.clone()),
             (details, env.ledger().timestamp()),
         );
@@ -160,9 +209,19 @@ impl EventPublisher {
 
     /// Publish monitoring event
     pub fn monitoring_event(env: &Env, metric_name: &String, metric_value: u64, threshold: Option<u64>) {
-        env.events().publish(
+        event_redaction::publish(
+            env,
             (symbol_short!("MONITOR"), metric_name.clone()),
             (metric_value, threshold, env.ledger().timestamp()),
         );
     }
+
+    /// Publish a workflow SLA breach
+    pub fn sla_breached(env: &Env, breach: &SlaBreach) {
+        event_redaction::publish(
+            env,
+            (symbol_short!("SLA_BRCH"), breach.transaction_id),
+            breach.clone(),
+        );
+    }
 }
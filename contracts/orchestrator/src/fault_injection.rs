@@ -0,0 +1,88 @@
+//! Deterministic fault injection for exercising failure paths in tests.
+//!
+//! Only compiled for tests or with the `testutils` feature. A test arms a
+//! fault for a participant and phase; the next matching call fails as if the
+//! participant had rejected it, without a hand-written failing mock contract.
+
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+use common::transaction::TransactionOperation;
+
+const FAULTS: Symbol = symbol_short!("FAULTS");
+
+/// Fires on every matching call until cleared
+pub const ALWAYS: u32 = u32::MAX;
+
+/// Two-phase commit step a fault applies to
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FaultPhase {
+    Prepare,
+    Commit,
+    Rollback,
+}
+
+/// An armed fault
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Fault {
+    pub phase: FaultPhase,
+    pub contract_address: Address,
+    /// Restrict to a single operation; `None` matches any operation on the contract
+    pub operation_id: Option<u64>,
+    /// Failures left before the fault disarms itself, or [`ALWAYS`]
+    pub remaining: u32,
+}
+
+pub fn get_faults(env: &Env) -> Vec<Fault> {
+    env.storage().instance().get(&FAULTS).unwrap_or(Vec::new(env))
+}
+
+/// Makes the next `times` matching calls fail
+pub fn inject(
+    env: &Env,
+    phase: FaultPhase,
+    contract_address: &Address,
+    operation_id: Option<u64>,
+    times: u32,
+) {
+    let mut faults = get_faults(env);
+    faults.push_back(Fault {
+        phase,
+        contract_address: contract_address.clone(),
+        operation_id,
+        remaining: times,
+    });
+    env.storage().instance().set(&FAULTS, &faults);
+}
+
+/// Disarms every fault
+pub fn clear(env: &Env) {
+    env.storage().instance().remove(&FAULTS);
+}
+
+/// Returns true if an armed fault matches, consuming one of its failures.
+pub fn trip(env: &Env, phase: FaultPhase, operation: &TransactionOperation) -> bool {
+    let faults = get_faults(env);
+    let mut remaining = Vec::new(env);
+    let mut tripped = false;
+    for mut fault in faults.iter() {
+        let matches = !tripped
+            && fault.remaining > 0
+            && fault.phase == phase
+            && fault.contract_address == operation.contract_address
+            && fault.operation_id.map_or(true, |id| id == operation.operation_id);
+        if matches {
+            tripped = true;
+            if fault.remaining != ALWAYS {
+                fault.remaining = fault.remaining.saturating_sub(1);
+            }
+        }
+        if fault.remaining > 0 {
+            remaining.push_back(fault);
+        }
+    }
+    if tripped {
+        env.storage().instance().set(&FAULTS, &remaining);
+    }
+    tripped
+}
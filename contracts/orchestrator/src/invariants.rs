@@ -0,0 +1,72 @@
+use soroban_sdk::{symbol_short, Env, String, Vec};
+use common::invariant::{InvariantReport, InvariantScan, InvariantSubject};
+use common::transaction::{
+    get_transaction_log, TransactionPhase, TransactionStatus, ACTIVE_TRANSACTIONS, RESOURCE_LOCKS,
+    TRANSACTION_COUNTER,
+};
+
+use crate::compensation;
+
+/// Checks transaction logs `1..=TX_CTR` a slice at a time:
+///
+/// - `tx_phase`: a finished phase on a transaction still marked active
+/// - `op_uncmt`: a prepared operation left uncommitted in a completed transaction
+/// - `cmp_orph`: a compensation outliving its transaction
+///
+/// Every call also checks the (small, instance-stored) tracking lists:
+///
+/// - `lck_orph`: a resource lock held by a transaction that is not active
+/// - `act_dang`: an active-list entry with no transaction log
+pub fn run(env: &Env, limit: u32) -> InvariantReport {
+    let last_id: u64 = env.storage().instance().get(&TRANSACTION_COUNTER).unwrap_or(0);
+    let mut scan = InvariantScan::start(env, last_id, limit);
+
+    for transaction_id in scan.ids() {
+        let log = match get_transaction_log(env, transaction_id) {
+            Some(log) => log,
+            None => continue,
+        };
+        let finished = matches!(
+            log.phase,
+            TransactionPhase::Committed | TransactionPhase::RolledBack | TransactionPhase::TimedOut
+        );
+        if finished && log.status == TransactionStatus::Active {
+            scan.flag(symbol_short!("tx_phase"), InvariantSubject::Id(transaction_id));
+        }
+
+        for operation in log.operations.iter() {
+            if !operation.prepared || operation.committed {
+                continue;
+            }
+            let subject = InvariantSubject::IdPair(transaction_id, operation.operation_id);
+            if log.status == TransactionStatus::Completed {
+                scan.flag(symbol_short!("op_uncmt"), subject);
+            } else if log.status != TransactionStatus::Active
+                && compensation::get(env, transaction_id, operation.operation_id).is_some()
+            {
+                scan.flag(symbol_short!("cmp_orph"), subject);
+            }
+        }
+    }
+
+    let locks: Vec<(String, u64)> = env.storage().instance().get(&RESOURCE_LOCKS)
+        .unwrap_or(Vec::new(env));
+    for (resource, transaction_id) in locks.iter() {
+        let live = get_transaction_log(env, transaction_id)
+            .map(|log| log.status == TransactionStatus::Active)
+            .unwrap_or(false);
+        if !live {
+            scan.flag(symbol_short!("lck_orph"), InvariantSubject::Resource(resource));
+        }
+    }
+
+    let active: Vec<u64> = env.storage().instance().get(&ACTIVE_TRANSACTIONS)
+        .unwrap_or(Vec::new(env));
+    for transaction_id in active.iter() {
+        if get_transaction_log(env, transaction_id).is_none() {
+            scan.flag(symbol_short!("act_dang"), InvariantSubject::Id(transaction_id));
+        }
+    }
+
+    scan.finish()
+}
@@ -2,14 +2,27 @@
 
 pub mod transaction;
 pub mod rollback;
+pub mod compensation;
 pub mod deadlock;
+pub mod invariants;
 pub mod events;
 pub mod errors;
 pub mod validation;
+pub mod maintenance;
+pub mod receipt;
+pub mod registry;
+pub mod sla;
+pub mod tags;
+#[cfg(any(test, feature = "testutils"))]
+pub mod fault_injection;
 
 use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String, Vec, Symbol};
+use common::event_redaction::{self, EventRedaction};
+use common::interface::{self, InterfaceVersion};
+use common::invariant::InvariantReport;
+use common::storage_ttl::{self, StorageKeySpec};
 use common::transaction::{
-    TransactionLog, TransactionPhase, TransactionStatus, TransactionOperation, TransactionError,
+    ContractType, TagValue, TransactionLog, TransactionTag, TransactionPhase, TransactionStatus, TransactionOperation, TransactionError,
     TransactionTimeoutConfig, generate_transaction_id, get_transaction_log, set_transaction_log,
     is_transaction_expired, get_default_timeout_config,
     TRANSACTION_COUNTER, TIMEOUT_CONFIG, ACTIVE_TRANSACTIONS, RESOURCE_LOCKS,
@@ -19,11 +32,21 @@ use transaction::TransactionManager;
 use rollback::RollbackManager;
 use deadlock::DeadlockDetector;
 use events::EventPublisher;
+use maintenance::FootprintTargets;
+use receipt::{ReceiptBuilder, TransactionReceipt};
+use sla::{SlaStats, WorkflowSla};
 
 /// Storage keys for the orchestrator contract
 const ADMIN: Symbol = symbol_short!("ADMIN");
 const INITIALIZED: Symbol = symbol_short!("INIT");
 
+/// Cross-contract interface version reported by `get_interface_version`
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: interface::ORCHESTRATOR,
+    major: 1,
+    minor: 0,
+};
+
 /// Main orchestrator contract for cross-contract atomic transactions
 #[contract]
 pub struct OrchestratorContract;
@@ -54,7 +77,7 @@ impl OrchestratorContract {
         initiator: Address,
         operations: Vec<TransactionOperation>,
         timeout_seconds: Option<u64>,
-        metadata: Vec<String>,
+        metadata: Vec<TransactionTag>,
     ) -> Result<u64, TransactionError> {
         Self::require_initialized(&env)?;
         
@@ -69,6 +92,18 @@ impl OrchestratorContract {
         if timeout > config.max_timeout {
             return Err(TransactionError::InvalidInput);
         }
+        validation::validate_metadata(&metadata)?;
+
+        for operation in operations.iter() {
+            validation::validate_transaction_operation(
+                &env,
+                operation.operation_id,
+                &operation.contract_type,
+                &operation.function_name,
+                &operation.parameters,
+                &operation.locked_resources,
+            )?;
+        }
 
         // Check for potential deadlocks before starting
         let deadlock_detector = DeadlockDetector::new(&env);
@@ -92,6 +127,8 @@ impl OrchestratorContract {
 
         // Store transaction log
         set_transaction_log(&env, &log);
+        tags::index_transaction(&env, transaction_id, &log.metadata);
+        sla::observe(&env, &log);
 
         // Acquire resource locks
         Self::acquire_resource_locks(&env, &transaction_id, &operations)?;
@@ -111,6 +148,7 @@ impl OrchestratorContract {
                         log.updated_at = env.ledger().timestamp();
                         
                         set_transaction_log(&env, &log);
+                        sla::observe(&env, &log);
                         Self::release_resource_locks(&env, transaction_id)?;
                         
                         EventPublisher::transaction_committed(&env, &log);
@@ -130,6 +168,7 @@ impl OrchestratorContract {
                         log.updated_at = env.ledger().timestamp();
                         
                         set_transaction_log(&env, &log);
+                        sla::observe(&env, &log);
                         Self::release_resource_locks(&env, transaction_id)?;
                         
                         EventPublisher::transaction_rolled_back(&env, &log);
@@ -151,6 +190,7 @@ impl OrchestratorContract {
                 log.updated_at = env.ledger().timestamp();
                 
                 set_transaction_log(&env, &log);
+                sla::observe(&env, &log);
                 Self::release_resource_locks(&env, transaction_id)?;
                 
                 EventPublisher::transaction_rolled_back(&env, &log);
@@ -167,6 +207,22 @@ impl OrchestratorContract {
             .ok_or(TransactionError::TransactionNotFound)
     }
 
+    /// Hash-chained summary of a finished transaction, suitable for anchoring
+    /// on another chain or presenting off-chain.
+    pub fn get_transaction_receipt(
+        env: Env,
+        transaction_id: u64,
+    ) -> Result<TransactionReceipt, TransactionError> {
+        Self::require_initialized(&env)?;
+
+        let log = get_transaction_log(&env, transaction_id)
+            .ok_or(TransactionError::TransactionNotFound)?;
+        if !ReceiptBuilder::is_finished(&log) {
+            return Err(TransactionError::InvalidPhase);
+        }
+        Ok(ReceiptBuilder::new(&env).build(&log))
+    }
+
     /// Get all active transactions
     pub fn get_active_transactions(env: Env) -> Result<Vec<u64>, TransactionError> {
         Self::require_initialized(&env)?;
@@ -196,6 +252,7 @@ impl OrchestratorContract {
         log.updated_at = env.ledger().timestamp();
         
         set_transaction_log(&env, &log);
+        sla::observe(&env, &log);
         Self::release_resource_locks(&env, transaction_id)?;
         
         EventPublisher::transaction_rolled_back(&env, &log);
@@ -224,11 +281,14 @@ impl OrchestratorContract {
                         updated_log.error = Some(String::from_str(&env, "Transaction timed out"));
 
                         set_transaction_log(&env, &updated_log);
+                        sla::observe(&env, &updated_log);
                         let _ = Self::release_resource_locks(&env, transaction_id);
 
                         timed_out.push_back(transaction_id);
                         EventPublisher::transaction_timed_out(&env, &updated_log);
                     }
+                } else if log.status == TransactionStatus::Active {
+                    sla::check_overdue(&env, &log);
                 }
             }
         }
@@ -236,6 +296,43 @@ impl OrchestratorContract {
         Ok(timed_out)
     }
 
+    /// Define or replace the phase limits for a tagged workflow (admin only)
+    pub fn set_workflow_sla(env: Env, admin: Address, sla: WorkflowSla) -> Result<(), TransactionError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        Self::require_initialized(&env)?;
+
+        sla::set_sla(&env, &sla)
+    }
+
+    /// Stop tracking a workflow against its SLA (admin only). Compliance totals are kept.
+    pub fn remove_workflow_sla(env: Env, admin: Address, workflow: String) -> Result<(), TransactionError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        Self::require_initialized(&env)?;
+
+        sla::remove_sla(&env, &workflow);
+        Ok(())
+    }
+
+    /// Get the SLA defined for a workflow, if any
+    pub fn get_workflow_sla(env: Env, workflow: String) -> Option<WorkflowSla> {
+        sla::get_sla(&env, &workflow)
+    }
+
+    /// Finished and breaching transaction counts for a workflow
+    pub fn get_sla_stats(env: Env, workflow: String) -> SlaStats {
+        sla::get_stats(&env, &workflow)
+    }
+
+    /// Check the next `limit` transaction logs (at most 100), plus the lock
+    /// and active lists, for consistency. Resumes where the last call
+    /// stopped; violations are also published as `INV_FAIL` events.
+    pub fn run_invariant_checks(env: Env, limit: u32) -> Result<InvariantReport, TransactionError> {
+        Self::require_initialized(&env)?;
+        Ok(invariants::run(&env, limit))
+    }
+
     /// Update timeout configuration (admin only)
     pub fn update_timeout_config(env: Env, admin: Address, config: TransactionTimeoutConfig) -> Result<(), TransactionError> {
         Self::require_admin(&env, &admin)?;
@@ -258,6 +355,103 @@ impl OrchestratorContract {
         Ok(config)
     }
 
+    /// Set how much orchestrator events reveal (admin only)
+    pub fn set_event_redaction(env: Env, admin: Address, level: EventRedaction) -> Result<(), TransactionError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        Self::require_initialized(&env)?;
+
+        event_redaction::set_level(&env, level);
+        Ok(())
+    }
+
+    /// Get the current event redaction level
+    pub fn get_event_redaction(env: Env) -> EventRedaction {
+        event_redaction::get_level(&env)
+    }
+
+    /// Allow orchestrated operations to call `function_name` on `contract_type` (admin only).
+    /// Once a contract type has an allowlist, operations targeting unlisted functions are rejected.
+    pub fn allow_function(
+        env: Env,
+        admin: Address,
+        contract_type: ContractType,
+        function_name: String,
+    ) -> Result<(), TransactionError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        Self::require_initialized(&env)?;
+        validation::validate_function_name(&function_name)?;
+
+        if registry::allow_function(&env, &contract_type, &function_name)? {
+            EventPublisher::function_allowed(&env, &contract_type, &function_name, &admin);
+        }
+        Ok(())
+    }
+
+    /// Remove `function_name` from the allowlist for `contract_type` (admin only)
+    pub fn disallow_function(
+        env: Env,
+        admin: Address,
+        contract_type: ContractType,
+        function_name: String,
+    ) -> Result<(), TransactionError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        Self::require_initialized(&env)?;
+
+        if registry::disallow_function(&env, &contract_type, &function_name) {
+            EventPublisher::function_disallowed(&env, &contract_type, &function_name, &admin);
+        }
+        Ok(())
+    }
+
+    /// Get the allowlist for `contract_type`, or `None` if unrestricted
+    pub fn get_allowed_functions(env: Env, contract_type: ContractType) -> Option<Vec<String>> {
+        registry::get_allowed_functions(&env, &contract_type)
+    }
+
+    /// Register the deployed contract for `contract_type` (admin only).
+    /// Fails with `IncompatibleInterface` if its reported interface version
+    /// is outside the range the orchestrator supports for that type.
+    pub fn register_participant(
+        env: Env,
+        admin: Address,
+        contract_type: ContractType,
+        address: Address,
+    ) -> Result<(), TransactionError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        Self::require_initialized(&env)?;
+
+        registry::register_participant(&env, &contract_type, &address)?;
+        EventPublisher::participant_registered(&env, &contract_type, &address, &admin);
+        Ok(())
+    }
+
+    /// Get the registered contract for `contract_type`, if any
+    pub fn get_participant(env: Env, contract_type: ContractType) -> Option<Address> {
+        registry::get_participant(&env, &contract_type)
+    }
+
+    /// Version of the interface this contract exposes to other contracts.
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
+    /// Transaction ids carrying the reserved tag `key = value`, oldest first.
+    /// Only reserved tags (see [`tags`]) are indexed.
+    pub fn find_transactions_by_tag(
+        env: Env,
+        key: Symbol,
+        value: TagValue,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<u64>, TransactionError> {
+        Self::require_initialized(&env)?;
+        tags::find_by_tag(&env, &key, &value, offset, limit)
+    }
+
     // Helper functions
     
     fn require_initialized(env: &Env) -> Result<(), TransactionError> {
@@ -318,10 +512,47 @@ impl OrchestratorContract {
         env.storage().instance().set(&RESOURCE_LOCKS, &new_locks);
         Ok(())
     }
+
+    /// Bumps the TTL of a patient's full footprint — identity, records,
+    /// prescriptions and ZK proof state — in one coordinated run. Each target
+    /// contract inspects at most `limit_per_contract` keys.
+    pub fn extend_patient_footprint(
+        env: Env,
+        identity: Address,
+        vision_records: Address,
+        zk_verifier: Address,
+        patient: Address,
+        limit_per_contract: u32,
+    ) -> u32 {
+        let targets = FootprintTargets {
+            identity,
+            vision_records,
+            zk_verifier,
+        };
+        maintenance::extend_patient_footprint(&env, &targets, &patient, limit_per_contract)
+    }
+
+    /// Extends the TTL of the entries described by `keys_spec`, inspecting at
+    /// most `limit` keys. Permissionless: it only keeps existing state alive.
+    pub fn extend_storage(env: Env, keys_spec: Vec<StorageKeySpec>, limit: u32) -> u32 {
+        storage_ttl::extend_storage(
+            &env,
+            &keys_spec,
+            limit,
+            storage_ttl::DEFAULT_TTL_THRESHOLD,
+            storage_ttl::DEFAULT_TTL_EXTEND_TO,
+        )
+    }
 }
 
 #[cfg(test)]
 mod test_orchestrator;
 
 #[cfg(test)]
-mod test_gas_benchmarks;
\ No newline at end of file
+mod test_gas_benchmarks;
+
+#[cfg(test)]
+mod test_properties;
+
+#[cfg(test)]
+mod test_budget;
@@ -0,0 +1,102 @@
+use common::StorageKeySpec;
+use soroban_sdk::{symbol_short, Address, Env, IntoVal, Symbol, Val, Vec};
+
+/// Contracts that hold a patient's on-chain footprint
+pub struct FootprintTargets {
+    pub identity: Address,
+    pub vision_records: Address,
+    pub zk_verifier: Address,
+}
+
+/// Identity state: guardians, recovery threshold, owner status and bound credentials.
+pub fn identity_specs(env: &Env, patient: &Address) -> Vec<StorageKeySpec> {
+    let mut specs = Vec::new(env);
+    specs.push_back(StorageKeySpec::Instance);
+    for prefix in [
+        symbol_short!("GUARD"),
+        symbol_short!("REC_THR"),
+        symbol_short!("OWN_ACT"),
+        symbol_short!("HLD_BIND"),
+    ] {
+        specs.push_back(StorageKeySpec::Address(prefix, patient.clone()));
+    }
+    specs
+}
+
+/// Vision records state: user entry, profile, records and prescriptions.
+pub fn vision_records_specs(env: &Env, patient: &Address) -> Vec<StorageKeySpec> {
+    let mut specs = Vec::new(env);
+    specs.push_back(StorageKeySpec::Instance);
+    specs.push_back(StorageKeySpec::Address(
+        symbol_short!("USER"),
+        patient.clone(),
+    ));
+    specs.push_back(StorageKeySpec::Address(
+        symbol_short!("PAT_PROF"),
+        patient.clone(),
+    ));
+    specs.push_back(StorageKeySpec::AddressIndex(
+        symbol_short!("PAT_REC"),
+        patient.clone(),
+        symbol_short!("RECORD"),
+    ));
+    specs.push_back(StorageKeySpec::AddressIndex(
+        symbol_short!("RX_HIST"),
+        patient.clone(),
+        symbol_short!("RX"),
+    ));
+    specs
+}
+
+/// ZK verifier state: proof nonce and the holder's credential list.
+pub fn zk_verifier_specs(env: &Env, patient: &Address) -> Vec<StorageKeySpec> {
+    let mut specs = Vec::new(env);
+    specs.push_back(StorageKeySpec::Instance);
+    specs.push_back(StorageKeySpec::Address(
+        symbol_short!("NONCE"),
+        patient.clone(),
+    ));
+    specs.push_back(StorageKeySpec::Address(
+        symbol_short!("HOLD_CRD"),
+        patient.clone(),
+    ));
+    specs
+}
+
+/// Calls `extend_storage` on a single target contract.
+fn extend_on(env: &Env, contract: &Address, specs: Vec<StorageKeySpec>, limit: u32) -> u32 {
+    let mut args: Vec<Val> = Vec::new(env);
+    args.push_back(specs.into_val(env));
+    args.push_back(limit.into_val(env));
+    env.invoke_contract::<u32>(contract, &Symbol::new(env, "extend_storage"), args)
+}
+
+/// Bumps the patient's footprint across all target contracts, giving each
+/// contract its own `limit_per_contract` budget. Returns the total number of
+/// entries extended.
+pub fn extend_patient_footprint(
+    env: &Env,
+    targets: &FootprintTargets,
+    patient: &Address,
+    limit_per_contract: u32,
+) -> u32 {
+    let identity = extend_on(
+        env,
+        &targets.identity,
+        identity_specs(env, patient),
+        limit_per_contract,
+    );
+    let records = extend_on(
+        env,
+        &targets.vision_records,
+        vision_records_specs(env, patient),
+        limit_per_contract,
+    );
+    let proofs = extend_on(
+        env,
+        &targets.zk_verifier,
+        zk_verifier_specs(env, patient),
+        limit_per_contract,
+    );
+    identity.saturating_add(records).saturating_add(proofs)
+}
@@ -0,0 +1,146 @@
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Vec};
+use common::transaction::{
+    ContractType, TransactionLog, TransactionOperation, TransactionPhase, TransactionStatus,
+};
+
+/// Final state of a single operation as recorded in a receipt
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum OperationOutcome {
+    /// Never reached the prepare phase
+    Pending,
+    /// Prepared but neither committed nor rolled back
+    Prepared,
+    /// Committed on the participant contract
+    Committed,
+    /// Prepared, then undone when the transaction rolled back
+    RolledBack,
+    /// Reported an error
+    Failed,
+}
+
+/// One operation in a transaction receipt.
+///
+/// `chain_hash` covers this entry and the `chain_hash` of the entry before
+/// it, so dropping, reordering or editing an operation changes every later
+/// hash and the receipt's `receipt_hash`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationReceipt {
+    pub operation_id: u64,
+    pub contract_type: ContractType,
+    pub contract_address: Address,
+    pub function_name: String,
+    pub outcome: OperationOutcome,
+    pub chain_hash: BytesN<32>,
+}
+
+/// Portable summary of a finished orchestrated transaction
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionReceipt {
+    pub transaction_id: u64,
+    pub orchestrator: Address,
+    pub initiator: Address,
+    pub phase: TransactionPhase,
+    pub status: TransactionStatus,
+    pub operations: Vec<OperationReceipt>,
+    /// Distinct participant contracts, in order of first appearance
+    pub participants: Vec<Address>,
+    pub created_at: u64,
+    pub finished_at: u64,
+    /// Head of the hash chain; the value to anchor elsewhere
+    pub receipt_hash: BytesN<32>,
+}
+
+/// Builds hash-chained receipts from transaction logs
+pub struct ReceiptBuilder<'a> {
+    env: &'a Env,
+}
+
+impl<'a> ReceiptBuilder<'a> {
+    pub fn new(env: &'a Env) -> Self {
+        Self { env }
+    }
+
+    /// Returns true once a transaction can no longer change
+    pub fn is_finished(log: &TransactionLog) -> bool {
+        log.status != TransactionStatus::Active
+    }
+
+    /// Summarise `log` into a receipt. The caller is expected to check
+    /// [`Self::is_finished`] first.
+    pub fn build(&self, log: &TransactionLog) -> TransactionReceipt {
+        let env = self.env;
+        let orchestrator = env.current_contract_address();
+
+        let mut header = Bytes::from_slice(env, b"TX_RCPT");
+        header.append(&orchestrator.clone().to_xdr(env));
+        header.append(&Bytes::from_slice(env, &log.transaction_id.to_be_bytes()));
+        header.append(&log.initiator.clone().to_xdr(env));
+        header.append(&Bytes::from_slice(env, &log.created_at.to_be_bytes()));
+        let mut head: BytesN<32> = env.crypto().sha256(&header).into();
+
+        let mut operations = Vec::new(env);
+        let mut participants: Vec<Address> = Vec::new(env);
+        for i in 0..log.operations.len() {
+            let operation = log.operations.get(i).unwrap();
+            let outcome = Self::outcome(log, &operation);
+
+            let mut entry = Bytes::from_array(env, &head.to_array());
+            entry.append(&Bytes::from_slice(env, &operation.operation_id.to_be_bytes()));
+            entry.append(&operation.contract_type.clone().to_xdr(env));
+            entry.append(&operation.contract_address.clone().to_xdr(env));
+            entry.append(&operation.function_name.clone().to_xdr(env));
+            entry.append(&outcome.clone().to_xdr(env));
+            head = env.crypto().sha256(&entry).into();
+
+            if !participants.contains(&operation.contract_address) {
+                participants.push_back(operation.contract_address.clone());
+            }
+            operations.push_back(OperationReceipt {
+                operation_id: operation.operation_id,
+                contract_type: operation.contract_type,
+                contract_address: operation.contract_address,
+                function_name: operation.function_name,
+                outcome,
+                chain_hash: head.clone(),
+            });
+        }
+
+        let mut footer = Bytes::from_array(env, &head.to_array());
+        footer.append(&log.phase.clone().to_xdr(env));
+        footer.append(&log.status.clone().to_xdr(env));
+        footer.append(&Bytes::from_slice(env, &log.updated_at.to_be_bytes()));
+        let receipt_hash: BytesN<32> = env.crypto().sha256(&footer).into();
+
+        TransactionReceipt {
+            transaction_id: log.transaction_id,
+            orchestrator,
+            initiator: log.initiator.clone(),
+            phase: log.phase.clone(),
+            status: log.status.clone(),
+            operations,
+            participants,
+            created_at: log.created_at,
+            finished_at: log.updated_at,
+            receipt_hash,
+        }
+    }
+
+    fn outcome(log: &TransactionLog, operation: &TransactionOperation) -> OperationOutcome {
+        if operation.error.is_some() {
+            OperationOutcome::Failed
+        } else if operation.committed {
+            OperationOutcome::Committed
+        } else if operation.prepared
+            && matches!(log.phase, TransactionPhase::RolledBack | TransactionPhase::TimedOut)
+        {
+            OperationOutcome::RolledBack
+        } else if operation.prepared {
+            OperationOutcome::Prepared
+        } else {
+            OperationOutcome::Pending
+        }
+    }
+}
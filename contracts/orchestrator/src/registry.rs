@@ -0,0 +1,112 @@
+use soroban_sdk::{symbol_short, Address, Env, String, Symbol, Vec};
+use common::interface::{self, InterfaceRange};
+use common::transaction::{ContractType, TransactionError};
+
+/// Storage prefix for per-contract function allowlists
+const ALLOWED_FUNCTIONS: Symbol = symbol_short!("ALLOW_FN");
+
+/// Storage prefix for registered participant contracts
+const PARTICIPANT: Symbol = symbol_short!("PARTCPNT");
+
+/// Upper bound on entries per contract type, keeps the list cheap to scan
+pub const MAX_ALLOWED_FUNCTIONS: u32 = 64;
+
+/// Interface versions the orchestrator can drive for `contract_type`.
+/// `None` for contract types that do not report a version yet.
+pub fn required_interface(contract_type: &ContractType) -> Option<InterfaceRange> {
+    let interface = match contract_type {
+        ContractType::VisionRecords => interface::VISION_RECORDS,
+        ContractType::Identity => interface::IDENTITY,
+        ContractType::ZkVerifier => interface::ZK_VERIFIER,
+        _ => return None,
+    };
+    Some(InterfaceRange {
+        interface,
+        major: 1,
+        min_minor: 0,
+    })
+}
+
+pub fn get_participant(env: &Env, contract_type: &ContractType) -> Option<Address> {
+    env.storage()
+        .instance()
+        .get(&(PARTICIPANT, contract_type.clone()))
+}
+
+/// Records `address` as the deployed `contract_type`, refusing contracts
+/// whose reported interface the orchestrator cannot drive.
+pub fn register_participant(
+    env: &Env,
+    contract_type: &ContractType,
+    address: &Address,
+) -> Result<(), TransactionError> {
+    if let Some(range) = required_interface(contract_type) {
+        if !interface::is_compatible(env, address, &range) {
+            return Err(TransactionError::IncompatibleInterface);
+        }
+    }
+    env.storage()
+        .instance()
+        .set(&(PARTICIPANT, contract_type.clone()), address);
+    Ok(())
+}
+
+/// Functions an orchestrated operation may target on `contract_type`.
+/// `None` means no allowlist has been configured for that contract type.
+pub fn get_allowed_functions(env: &Env, contract_type: &ContractType) -> Option<Vec<String>> {
+    env.storage()
+        .instance()
+        .get(&(ALLOWED_FUNCTIONS, contract_type.clone()))
+}
+
+/// Adds `function_name` to the allowlist. Returns false if it was already listed.
+pub fn allow_function(
+    env: &Env,
+    contract_type: &ContractType,
+    function_name: &String,
+) -> Result<bool, TransactionError> {
+    let mut allowed = get_allowed_functions(env, contract_type).unwrap_or(Vec::new(env));
+    if allowed.contains(function_name) {
+        return Ok(false);
+    }
+    if allowed.len() >= MAX_ALLOWED_FUNCTIONS {
+        return Err(TransactionError::InvalidInput);
+    }
+    allowed.push_back(function_name.clone());
+    env.storage()
+        .instance()
+        .set(&(ALLOWED_FUNCTIONS, contract_type.clone()), &allowed);
+    Ok(true)
+}
+
+/// Removes `function_name` from the allowlist. Returns false if it was not listed.
+///
+/// The list is kept even when it becomes empty, so a contract type that has
+/// been locked down stays locked down rather than reverting to unrestricted.
+pub fn disallow_function(env: &Env, contract_type: &ContractType, function_name: &String) -> bool {
+    let allowed = match get_allowed_functions(env, contract_type) {
+        Some(allowed) => allowed,
+        None => return false,
+    };
+    let mut remaining = Vec::new(env);
+    for name in allowed.iter() {
+        if name != *function_name {
+            remaining.push_back(name);
+        }
+    }
+    if remaining.len() == allowed.len() {
+        return false;
+    }
+    env.storage()
+        .instance()
+        .set(&(ALLOWED_FUNCTIONS, contract_type.clone()), &remaining);
+    true
+}
+
+/// Contract types without a configured allowlist accept any function.
+pub fn is_function_allowed(env: &Env, contract_type: &ContractType, function_name: &String) -> bool {
+    match get_allowed_functions(env, contract_type) {
+        Some(allowed) => allowed.contains(function_name),
+        None => true,
+    }
+}
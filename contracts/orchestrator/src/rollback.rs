@@ -4,7 +4,10 @@ use common::transaction::{
     get_transaction_log,
 };
 
+use super::compensation;
 use super::events::EventPublisher;
+#[cfg(any(test, feature = "testutils"))]
+use super::fault_injection::{self, FaultPhase};
 
 /// Rollback manager for handling transaction rollback operations
 pub struct RollbackManager<'a> {
@@ -26,7 +29,7 @@ impl<'a> RollbackManager<'a> {
 
             // Only rollback operations that were prepared but not committed
             if operation.prepared && !operation.committed {
-                match self.rollback_operation(&operation) {
+                match self.rollback_operation(log.transaction_id, &operation) {
                     Ok(_) => {
                         EventPublisher::operation_rolled_back(
                             self.env, log.transaction_id, operation.operation_id, &operation.contract_type,
@@ -51,12 +54,15 @@ impl<'a> RollbackManager<'a> {
         }
     }
 
-    /// Rollback a single operation via cross-contract invocation
-    pub fn rollback_operation(&self, operation: &TransactionOperation) -> Result<RollbackInfo, TransactionError> {
-        let func_sym = Symbol::new(self.env, "rollback_");
-
+    /// Rollback a single operation via cross-contract invocation. A compensation
+    /// registered during prepare takes precedence over the `rollback_` convention.
+    pub fn rollback_operation(
+        &self,
+        transaction_id: u64,
+        operation: &TransactionOperation,
+    ) -> Result<RollbackInfo, TransactionError> {
         let mut rollback_info = RollbackInfo {
-            transaction_id: 0,
+            transaction_id,
             operation_id: operation.operation_id,
             contract_address: operation.contract_address.clone(),
             rollback_function: String::from_str(self.env, "rollback_"),
@@ -65,6 +71,26 @@ impl<'a> RollbackManager<'a> {
             rollback_error: None,
         };
 
+        #[cfg(any(test, feature = "testutils"))]
+        if fault_injection::trip(self.env, FaultPhase::Rollback, operation) {
+            return Err(TransactionError::RollbackFailed);
+        }
+
+        if let Some(registered) = compensation::get(self.env, transaction_id, operation.operation_id) {
+            rollback_info.rollback_function = String::from_str(self.env, "compensation");
+            // Invoke compensation — panics on failure which Soroban runtime catches
+            let _result: Val = self.env.invoke_contract(
+                &operation.contract_address,
+                &registered.function,
+                registered.args,
+            );
+            compensation::clear(self.env, transaction_id, operation.operation_id);
+            rollback_info.rollback_successful = true;
+            return Ok(rollback_info);
+        }
+
+        let func_sym = Symbol::new(self.env, "rollback_");
+
         let mut args: Vec<Val> = Vec::new(self.env);
         for i in 0..operation.parameters.len() {
             let param = operation.parameters.get(i).unwrap();
@@ -129,7 +155,7 @@ impl<'a> RollbackManager<'a> {
                         return Err(TransactionError::InvalidPhase);
                     }
 
-                    match self.rollback_operation(&operation) {
+                    match self.rollback_operation(transaction_id, &operation) {
                         Ok(_) => {
                             EventPublisher::operation_rolled_back(
                                 self.env, transaction_id, operation_id, &operation.contract_type,
@@ -0,0 +1,256 @@
+//! Service-level tracking for tagged clinical workflows.
+//!
+//! A workflow is identified by the reserved `workflow` tag on its
+//! transactions. When an SLA is defined for it, the time each transaction
+//! spends in a phase is compared with the phase's limit whenever the phase
+//! changes and whenever the timeout sweeper passes over it. Overruns publish
+//! an `SLA_BRCH` event, at most once per phase, and finished transactions
+//! count towards the workflow's compliance totals.
+
+use soroban_sdk::{contracttype, symbol_short, Env, String, Symbol, Vec};
+use common::transaction::{TagValue, TransactionError, TransactionLog, TransactionPhase};
+
+use crate::events::EventPublisher;
+use crate::tags::TAG_WORKFLOW;
+
+const SLA_DEF: Symbol = symbol_short!("SLA_DEF");
+const SLA_TRK: Symbol = symbol_short!("SLA_TRK");
+const SLA_STAT: Symbol = symbol_short!("SLA_STAT");
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Upper bound on phase limits per SLA (one per non-terminal phase is enough)
+pub const MAX_PHASE_LIMITS: u32 = 8;
+
+/// Longest time a transaction may spend in `phase`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PhaseLimit {
+    pub phase: TransactionPhase,
+    pub max_seconds: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WorkflowSla {
+    pub workflow: String,
+    pub limits: Vec<PhaseLimit>,
+}
+
+/// How far past its limit a phase ran
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BreachSeverity {
+    /// Less than a quarter over the limit
+    Minor,
+    /// Up to twice the limit
+    Major,
+    /// Twice the limit or more
+    Critical,
+}
+
+/// Structured payload of an `SLA_BRCH` event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlaBreach {
+    pub transaction_id: u64,
+    pub workflow: String,
+    pub phase: TransactionPhase,
+    pub max_seconds: u64,
+    pub elapsed_seconds: u64,
+    pub severity: BreachSeverity,
+    pub detected_at: u64,
+}
+
+/// Compliance totals for a workflow: finished transactions, and how many of
+/// them breached at least one phase limit
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlaStats {
+    pub completed: u32,
+    pub breached: u32,
+}
+
+/// Phase a tracked transaction is in and whether it has breached yet
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct PhaseTrack {
+    workflow: String,
+    phase: TransactionPhase,
+    entered_at: u64,
+    phase_breached: bool,
+    any_breach: bool,
+}
+
+pub fn set_sla(env: &Env, sla: &WorkflowSla) -> Result<(), TransactionError> {
+    if sla.limits.is_empty() || sla.limits.len() > MAX_PHASE_LIMITS {
+        return Err(TransactionError::InvalidInput);
+    }
+    for limit in sla.limits.iter() {
+        if limit.max_seconds == 0 || is_terminal(&limit.phase) {
+            return Err(TransactionError::InvalidInput);
+        }
+    }
+    let key = (SLA_DEF, sla.workflow.clone());
+    env.storage().persistent().set(&key, sla);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    Ok(())
+}
+
+pub fn remove_sla(env: &Env, workflow: &String) {
+    env.storage().persistent().remove(&(SLA_DEF, workflow.clone()));
+}
+
+pub fn get_sla(env: &Env, workflow: &String) -> Option<WorkflowSla> {
+    env.storage().persistent().get(&(SLA_DEF, workflow.clone()))
+}
+
+pub fn get_stats(env: &Env, workflow: &String) -> SlaStats {
+    env.storage()
+        .persistent()
+        .get(&(SLA_STAT, workflow.clone()))
+        .unwrap_or(SlaStats {
+            completed: 0,
+            breached: 0,
+        })
+}
+
+fn is_terminal(phase: &TransactionPhase) -> bool {
+    matches!(
+        phase,
+        TransactionPhase::Committed | TransactionPhase::RolledBack | TransactionPhase::TimedOut
+    )
+}
+
+fn workflow_of(log: &TransactionLog) -> Option<String> {
+    for tag in log.metadata.iter() {
+        if tag.key == TAG_WORKFLOW {
+            if let TagValue::Text(name) = tag.value {
+                return Some(name);
+            }
+        }
+    }
+    None
+}
+
+fn severity(max_seconds: u64, elapsed: u64) -> BreachSeverity {
+    let overrun = elapsed.saturating_sub(max_seconds);
+    if overrun.saturating_mul(4) < max_seconds {
+        BreachSeverity::Minor
+    } else if overrun < max_seconds {
+        BreachSeverity::Major
+    } else {
+        BreachSeverity::Critical
+    }
+}
+
+/// Publishes a breach if `track`'s current phase has run past its limit.
+fn check_phase(env: &Env, transaction_id: u64, sla: &WorkflowSla, track: &mut PhaseTrack) {
+    if track.phase_breached {
+        return;
+    }
+    let now = env.ledger().timestamp();
+    let elapsed = now.saturating_sub(track.entered_at);
+    for limit in sla.limits.iter() {
+        if limit.phase == track.phase && elapsed > limit.max_seconds {
+            track.phase_breached = true;
+            track.any_breach = true;
+            EventPublisher::sla_breached(
+                env,
+                &SlaBreach {
+                    transaction_id,
+                    workflow: track.workflow.clone(),
+                    phase: track.phase.clone(),
+                    max_seconds: limit.max_seconds,
+                    elapsed_seconds: elapsed,
+                    severity: severity(limit.max_seconds, elapsed),
+                    detected_at: now,
+                },
+            );
+        }
+    }
+}
+
+fn save_track(env: &Env, transaction_id: u64, track: &PhaseTrack) {
+    let key = (SLA_TRK, transaction_id);
+    env.storage().persistent().set(&key, track);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD, TTL_EXTEND_TO);
+}
+
+/// Call after every phase change of `log`. Closes out the phase the
+/// transaction was last seen in, starts timing the new one, and settles the
+/// workflow's compliance totals once the transaction finishes.
+pub fn observe(env: &Env, log: &TransactionLog) {
+    let key = (SLA_TRK, log.transaction_id);
+    let tracked: Option<PhaseTrack> = env.storage().persistent().get(&key);
+    let mut track = match tracked {
+        Some(track) => track,
+        None => {
+            let workflow = match workflow_of(log) {
+                Some(workflow) => workflow,
+                None => return,
+            };
+            if get_sla(env, &workflow).is_none() {
+                return;
+            }
+            PhaseTrack {
+                workflow,
+                phase: log.phase.clone(),
+                entered_at: log.created_at,
+                phase_breached: false,
+                any_breach: false,
+            }
+        }
+    };
+
+    if let Some(sla) = get_sla(env, &track.workflow) {
+        if track.phase != log.phase {
+            check_phase(env, log.transaction_id, &sla, &mut track);
+            track.phase = log.phase.clone();
+            track.entered_at = env.ledger().timestamp();
+            track.phase_breached = false;
+        }
+    }
+
+    if is_terminal(&log.phase) {
+        let stats_key = (SLA_STAT, track.workflow.clone());
+        let mut stats = get_stats(env, &track.workflow);
+        stats.completed = stats.completed.saturating_add(1);
+        if track.any_breach {
+            stats.breached = stats.breached.saturating_add(1);
+        }
+        env.storage().persistent().set(&stats_key, &stats);
+        env.storage()
+            .persistent()
+            .extend_ttl(&stats_key, TTL_THRESHOLD, TTL_EXTEND_TO);
+        env.storage().persistent().remove(&key);
+    } else {
+        save_track(env, log.transaction_id, &track);
+    }
+}
+
+/// Called by the timeout sweeper for transactions still in flight: reports
+/// a breach for the current phase without waiting for it to end.
+pub fn check_overdue(env: &Env, log: &TransactionLog) {
+    let key = (SLA_TRK, log.transaction_id);
+    let tracked: Option<PhaseTrack> = env.storage().persistent().get(&key);
+    let mut track = match tracked {
+        Some(track) => track,
+        None => return,
+    };
+    let sla = match get_sla(env, &track.workflow) {
+        Some(sla) => sla,
+        None => return,
+    };
+    if track.phase == log.phase && !track.phase_breached {
+        check_phase(env, log.transaction_id, &sla, &mut track);
+        if track.phase_breached {
+            save_track(env, log.transaction_id, &track);
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use soroban_sdk::{symbol_short, Env, Symbol, Vec};
+use common::transaction::{TagValue, TransactionError, TransactionTag};
+
+/// Reserved tag: name of the workflow that started the transaction (`Text`)
+pub const TAG_WORKFLOW: Symbol = symbol_short!("workflow");
+/// Reserved tag: hash of the patient the transaction concerns (`Hash`)
+pub const TAG_PATIENT_HASH: Symbol = symbol_short!("pat_hash");
+
+/// Storage prefix for the reserved-tag index
+const TAG_INDEX: Symbol = symbol_short!("TAG_IDX");
+
+/// Largest page `find_by_tag` returns
+pub const MAX_TAG_PAGE: u32 = 50;
+
+const TTL_THRESHOLD: u32 = 5184000;
+const TTL_EXTEND_TO: u32 = 10368000;
+
+/// Only reserved tags are indexed and searchable.
+pub fn is_reserved(key: &Symbol) -> bool {
+    *key == TAG_WORKFLOW || *key == TAG_PATIENT_HASH
+}
+
+/// Reserved tags must carry the value type they are documented with.
+pub fn validate_reserved(tag: &TransactionTag) -> Result<(), TransactionError> {
+    let ok = if tag.key == TAG_WORKFLOW {
+        matches!(tag.value, TagValue::Text(_))
+    } else if tag.key == TAG_PATIENT_HASH {
+        matches!(tag.value, TagValue::Hash(_))
+    } else {
+        true
+    };
+    if ok {
+        Ok(())
+    } else {
+        Err(TransactionError::InvalidInput)
+    }
+}
+
+fn get_index(env: &Env, key: &Symbol, value: &TagValue) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(TAG_INDEX, key.clone(), value.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Indexes `transaction_id` under each of its reserved tags
+pub fn index_transaction(env: &Env, transaction_id: u64, tags: &Vec<TransactionTag>) {
+    for tag in tags.iter() {
+        if !is_reserved(&tag.key) {
+            continue;
+        }
+        let storage_key = (TAG_INDEX, tag.key.clone(), tag.value.clone());
+        let mut ids = get_index(env, &tag.key, &tag.value);
+        if ids.contains(&transaction_id) {
+            continue;
+        }
+        ids.push_back(transaction_id);
+        env.storage().persistent().set(&storage_key, &ids);
+        env.storage()
+            .persistent()
+            .extend_ttl(&storage_key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    }
+}
+
+/// Transaction ids tagged `key = value`, oldest first
+pub fn find_by_tag(
+    env: &Env,
+    key: &Symbol,
+    value: &TagValue,
+    offset: u32,
+    limit: u32,
+) -> Result<Vec<u64>, TransactionError> {
+    if !is_reserved(key) || limit == 0 || limit > MAX_TAG_PAGE {
+        return Err(TransactionError::InvalidInput);
+    }
+    let ids = get_index(env, key, value);
+    let mut page = Vec::new(env);
+    let end = offset.saturating_add(limit).min(ids.len());
+    for i in offset..end {
+        if let Some(id) = ids.get(i) {
+            page.push_back(id);
+        }
+    }
+    Ok(page)
+}
@@ -0,0 +1,143 @@
+//! Budget regression guards for the orchestrator's hot paths.
+//!
+//! Each guard resets the budget, runs one path and asserts the CPU
+//! instructions and memory it consumed stay under a ceiling. Results are
+//! printed so CI logs show the measured cost next to the threshold. Adjust
+//! a ceiling only alongside an intentional cost change.
+
+extern crate std;
+
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String, Vec};
+use common::transaction::{
+    set_transaction_log, ContractType, TransactionLog, TransactionOperation, TransactionPhase,
+    TransactionStatus, RESOURCE_LOCKS,
+};
+use std::{format, println};
+
+use crate::deadlock::DeadlockDetector;
+use crate::transaction::TransactionManager;
+use crate::OrchestratorContract;
+
+/// Prepare phase across 50 participant calls (the per-transaction maximum).
+const CPU_THRESHOLD_PREPARE_50: u64 = 60_000_000;
+const MEM_THRESHOLD_PREPARE_50: u64 = 20_000_000;
+
+/// Deadlock check of a 20-resource transaction against 20 held locks.
+const CPU_THRESHOLD_DEADLOCK_20: u64 = 20_000_000;
+const MEM_THRESHOLD_DEADLOCK_20: u64 = 10_000_000;
+
+/// Participant that accepts every prepare call.
+#[contract]
+struct MockParticipant;
+
+#[contractimpl]
+impl MockParticipant {
+    pub fn prepare_(_env: Env) {}
+}
+
+fn operation(
+    env: &Env,
+    id: u64,
+    participant: &Address,
+    resource: Option<String>,
+) -> TransactionOperation {
+    let mut locked_resources = Vec::new(env);
+    if let Some(resource) = resource {
+        locked_resources.push_back(resource);
+    }
+    TransactionOperation {
+        operation_id: id,
+        contract_type: ContractType::VisionRecords,
+        contract_address: participant.clone(),
+        function_name: String::from_str(env, "add_record"),
+        parameters: Vec::new(env),
+        locked_resources,
+        prepared: false,
+        committed: false,
+        error: None,
+    }
+}
+
+fn assert_within(label: &str, env: &Env, cpu_threshold: u64, mem_threshold: u64) {
+    let budget = env.cost_estimate().budget();
+    let cpu = budget.cpu_instruction_cost();
+    let mem = budget.memory_bytes_cost();
+    println!(
+        "[REGRESSION] {label}: cpu_instructions={cpu} (threshold={cpu_threshold}), \
+         memory_bytes={mem} (threshold={mem_threshold})"
+    );
+    assert!(
+        cpu <= cpu_threshold,
+        "CPU budget regression in {label}: measured {cpu} > threshold {cpu_threshold}"
+    );
+    assert!(
+        mem <= mem_threshold,
+        "Memory budget regression in {label}: measured {mem} > threshold {mem_threshold}"
+    );
+}
+
+#[test]
+fn regression_prepare_phase_50_operations() {
+    let env = Env::default();
+    let orchestrator = env.register(OrchestratorContract, ());
+    let participant = env.register(MockParticipant, ());
+
+    let mut operations = Vec::new(&env);
+    for id in 1..=50u64 {
+        operations.push_back(operation(&env, id, &participant, None));
+    }
+    let mut log = TransactionLog {
+        transaction_id: 1,
+        initiator: Address::generate(&env),
+        phase: TransactionPhase::Preparing,
+        status: TransactionStatus::Active,
+        operations,
+        created_at: 0,
+        updated_at: 0,
+        timeout_seconds: 300,
+        error: None,
+        metadata: Vec::new(&env),
+    };
+
+    env.as_contract(&orchestrator, || {
+        set_transaction_log(&env, &log);
+        env.cost_estimate().budget().reset_default();
+        TransactionManager::new(&env).prepare_phase(&mut log).unwrap();
+    });
+
+    assert_eq!(log.phase, TransactionPhase::Prepared);
+    assert_within(
+        "prepare_phase_50_operations",
+        &env,
+        CPU_THRESHOLD_PREPARE_50,
+        MEM_THRESHOLD_PREPARE_50,
+    );
+}
+
+#[test]
+fn regression_deadlock_check_20_locks() {
+    let env = Env::default();
+    let orchestrator = env.register(OrchestratorContract, ());
+    let participant = Address::generate(&env);
+
+    env.as_contract(&orchestrator, || {
+        let mut locks: Vec<(String, u64)> = Vec::new(&env);
+        let mut operations = Vec::new(&env);
+        for i in 0..20u64 {
+            let resource = String::from_str(&env, &format!("patient:{i}"));
+            locks.push_back((resource.clone(), i + 2));
+            operations.push_back(operation(&env, i + 1, &participant, Some(resource)));
+        }
+        env.storage().instance().set(&RESOURCE_LOCKS, &locks);
+
+        env.cost_estimate().budget().reset_default();
+        DeadlockDetector::new(&env).would_cause_deadlock(&1, &operations);
+    });
+
+    assert_within(
+        "deadlock_check_20_locks",
+        &env,
+        CPU_THRESHOLD_DEADLOCK_20,
+        MEM_THRESHOLD_DEADLOCK_20,
+    );
+}
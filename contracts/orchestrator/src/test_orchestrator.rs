@@ -4,8 +4,19 @@ mod tests {
     use soroban_sdk::{Address, Env, String, Vec};
     use common::{
         transaction::{TransactionOperation, TransactionPhase, TransactionStatus, TransactionError,
-                      ContractType, TransactionTimeoutConfig, get_default_timeout_config},
+                      ContractType, TransactionTimeoutConfig, get_default_timeout_config,
+                      TransactionLog, set_transaction_log, TagValue, TransactionTag},
     };
+    use crate::receipt;
+    use crate::validation;
+    use crate::tags;
+    use crate::compensation;
+    use crate::rollback::RollbackManager;
+    use crate::fault_injection::{self, FaultPhase};
+    use common::transaction::Compensation;
+    use common::interface::{self, InterfaceVersion};
+    use soroban_sdk::{contract, contractimpl, IntoVal, Symbol};
+    use soroban_sdk::{symbol_short, BytesN};
 
     #[test]
     fn test_orchestrator_initialization() {
@@ -516,4 +527,763 @@ mod tests {
             vec![&env, String::from_str(&env, "test_transaction")],
         );
     }
+
+    fn receipt_test_log(env: &Env, initiator: &Address, participant: &Address) -> TransactionLog {
+        let mut operations = Vec::new(env);
+        for (id, function) in [(1u64, "add_record"), (2u64, "grant_access")] {
+            operations.push_back(TransactionOperation {
+                operation_id: id,
+                contract_type: ContractType::VisionRecords,
+                contract_address: participant.clone(),
+                function_name: String::from_str(env, function),
+                parameters: Vec::new(env),
+                locked_resources: Vec::new(env),
+                prepared: true,
+                committed: true,
+                error: None,
+            });
+        }
+        TransactionLog {
+            transaction_id: 7,
+            initiator: initiator.clone(),
+            phase: TransactionPhase::Committed,
+            status: TransactionStatus::Completed,
+            operations,
+            created_at: 100,
+            updated_at: 160,
+            timeout_seconds: 300,
+            error: None,
+            metadata: Vec::new(env),
+        }
+    }
+
+    #[test]
+    fn test_transaction_receipt() {
+        let env = Env::default();
+        let contract_id = env.register(OrchestratorContract, ());
+        let admin = Address::generate(&env);
+        let initiator = Address::generate(&env);
+        let participant = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            OrchestratorContract::initialize(env.clone(), admin.clone(), None).unwrap();
+
+            assert_eq!(
+                OrchestratorContract::get_transaction_receipt(env.clone(), 7),
+                Err(TransactionError::TransactionNotFound)
+            );
+
+            // Receipts are only produced once the transaction is finished
+            let mut log = receipt_test_log(&env, &initiator, &participant);
+            log.status = TransactionStatus::Active;
+            log.phase = TransactionPhase::Prepared;
+            set_transaction_log(&env, &log);
+            assert_eq!(
+                OrchestratorContract::get_transaction_receipt(env.clone(), 7),
+                Err(TransactionError::InvalidPhase)
+            );
+
+            let log = receipt_test_log(&env, &initiator, &participant);
+            set_transaction_log(&env, &log);
+            let receipt = OrchestratorContract::get_transaction_receipt(env.clone(), 7).unwrap();
+            assert_eq!(receipt.orchestrator, contract_id);
+            assert_eq!(receipt.initiator, initiator);
+            assert_eq!(receipt.operations.len(), 2);
+            assert_eq!(receipt.participants.len(), 1);
+            assert_eq!(receipt.finished_at, 160);
+            let first = receipt.operations.get(0).unwrap();
+            let second = receipt.operations.get(1).unwrap();
+            assert_eq!(first.outcome, receipt::OperationOutcome::Committed);
+            assert_ne!(first.chain_hash, second.chain_hash);
+
+            // Deterministic for the same log
+            let again = OrchestratorContract::get_transaction_receipt(env.clone(), 7).unwrap();
+            assert_eq!(again.receipt_hash, receipt.receipt_hash);
+
+            // Any change to an operation moves the head of the chain
+            let mut rolled_back = receipt_test_log(&env, &initiator, &participant);
+            rolled_back.phase = TransactionPhase::RolledBack;
+            rolled_back.status = TransactionStatus::Failed;
+            let mut op = rolled_back.operations.get(1).unwrap();
+            op.committed = false;
+            rolled_back.operations.set(1, op);
+            set_transaction_log(&env, &rolled_back);
+            let changed = OrchestratorContract::get_transaction_receipt(env.clone(), 7).unwrap();
+            assert_eq!(changed.operations.get(0).unwrap().chain_hash, first.chain_hash);
+            assert_eq!(
+                changed.operations.get(1).unwrap().outcome,
+                receipt::OperationOutcome::RolledBack
+            );
+            assert_ne!(changed.receipt_hash, receipt.receipt_hash);
+        });
+    }
+
+    #[test]
+    fn test_event_redaction_config() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(OrchestratorContract, ());
+        let admin = Address::generate(&env);
+        let other = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            OrchestratorContract::initialize(env.clone(), admin.clone(), None).unwrap();
+            assert_eq!(
+                OrchestratorContract::get_event_redaction(env.clone()),
+                EventRedaction::Full
+            );
+
+            assert_eq!(
+                OrchestratorContract::set_event_redaction(env.clone(), other, EventRedaction::Minimal),
+                Err(TransactionError::Unauthorized)
+            );
+
+            OrchestratorContract::set_event_redaction(env.clone(), admin.clone(), EventRedaction::Hashed).unwrap();
+            assert_eq!(
+                OrchestratorContract::get_event_redaction(env.clone()),
+                EventRedaction::Hashed
+            );
+        });
+    }
+
+    #[test]
+    fn test_function_allowlist() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(OrchestratorContract, ());
+        let admin = Address::generate(&env);
+        let other = Address::generate(&env);
+        let initiator = Address::generate(&env);
+        let contract_address = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            OrchestratorContract::initialize(env.clone(), admin.clone(), None).unwrap();
+            let add_record = String::from_str(&env, "add_record");
+            let revoke = String::from_str(&env, "revoke_access");
+
+            // Unrestricted until an allowlist is configured
+            assert_eq!(
+                OrchestratorContract::get_allowed_functions(env.clone(), ContractType::VisionRecords),
+                None
+            );
+
+            assert_eq!(
+                OrchestratorContract::allow_function(
+                    env.clone(),
+                    other,
+                    ContractType::VisionRecords,
+                    add_record.clone(),
+                ),
+                Err(TransactionError::Unauthorized)
+            );
+
+            OrchestratorContract::allow_function(
+                env.clone(),
+                admin.clone(),
+                ContractType::VisionRecords,
+                add_record.clone(),
+            )
+            .unwrap();
+            let allowed = OrchestratorContract::get_allowed_functions(
+                env.clone(),
+                ContractType::VisionRecords,
+            )
+            .unwrap();
+            assert_eq!(allowed.len(), 1);
+            assert!(allowed.contains(&add_record));
+
+            let mut operations = Vec::new(&env);
+            operations.push_back(TransactionOperation {
+                operation_id: 1,
+                contract_type: ContractType::VisionRecords,
+                contract_address: contract_address.clone(),
+                function_name: revoke.clone(),
+                parameters: Vec::new(&env),
+                locked_resources: Vec::new(&env),
+                prepared: false,
+                committed: false,
+                error: None,
+            });
+            assert_eq!(
+                OrchestratorContract::start_transaction(
+                    env.clone(),
+                    initiator.clone(),
+                    operations,
+                    Some(300),
+                    Vec::new(&env),
+                ),
+                Err(TransactionError::FunctionNotAllowed)
+            );
+
+            // Other contract types keep their own (absent) allowlist
+            assert!(validation::validate_transaction_operation(
+                &env,
+                1,
+                &ContractType::Identity,
+                &revoke,
+                &Vec::new(&env),
+                &Vec::new(&env),
+            )
+            .is_ok());
+
+            // Emptying the list keeps the contract type locked down
+            OrchestratorContract::disallow_function(
+                env.clone(),
+                admin.clone(),
+                ContractType::VisionRecords,
+                add_record.clone(),
+            )
+            .unwrap();
+            assert_eq!(
+                OrchestratorContract::get_allowed_functions(env.clone(), ContractType::VisionRecords)
+                    .unwrap()
+                    .len(),
+                0
+            );
+            assert_eq!(
+                validation::validate_transaction_operation(
+                    &env,
+                    1,
+                    &ContractType::VisionRecords,
+                    &add_record,
+                    &Vec::new(&env),
+                    &Vec::new(&env),
+                ),
+                Err(TransactionError::FunctionNotAllowed)
+            );
+        });
+    }
+
+    #[test]
+    fn test_metadata_tags_and_search() {
+        let env = Env::default();
+        let contract_id = env.register(OrchestratorContract, ());
+        let admin = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            OrchestratorContract::initialize(env.clone(), admin.clone(), None).unwrap();
+
+            let workflow = TagValue::Text(String::from_str(&env, "referral_intake"));
+            let patient = TagValue::Hash(BytesN::from_array(&env, &[7u8; 32]));
+            let mut metadata = Vec::new(&env);
+            metadata.push_back(TransactionTag { key: tags::TAG_WORKFLOW, value: workflow.clone() });
+            metadata.push_back(TransactionTag { key: tags::TAG_PATIENT_HASH, value: patient.clone() });
+            metadata.push_back(TransactionTag {
+                key: symbol_short!("ticket"),
+                value: TagValue::Number(4411),
+            });
+            assert_eq!(validation::validate_metadata(&metadata), Ok(()));
+
+            for id in 1..=3u64 {
+                tags::index_transaction(&env, id, &metadata);
+            }
+            // Re-indexing the same transaction does not duplicate it
+            tags::index_transaction(&env, 2, &metadata);
+
+            let found = OrchestratorContract::find_transactions_by_tag(
+                env.clone(),
+                tags::TAG_PATIENT_HASH,
+                patient.clone(),
+                0,
+                10,
+            )
+            .unwrap();
+            assert_eq!(found.len(), 3);
+
+            let page = OrchestratorContract::find_transactions_by_tag(
+                env.clone(),
+                tags::TAG_WORKFLOW,
+                workflow.clone(),
+                1,
+                1,
+            )
+            .unwrap();
+            assert_eq!(page.len(), 1);
+            assert_eq!(page.get(0).unwrap(), 2);
+
+            let other = TagValue::Hash(BytesN::from_array(&env, &[8u8; 32]));
+            assert_eq!(
+                OrchestratorContract::find_transactions_by_tag(env.clone(), tags::TAG_PATIENT_HASH, other, 0, 10)
+                    .unwrap()
+                    .len(),
+                0
+            );
+
+            // Unreserved tags are stored but not searchable
+            assert_eq!(
+                OrchestratorContract::find_transactions_by_tag(
+                    env.clone(),
+                    symbol_short!("ticket"),
+                    TagValue::Number(4411),
+                    0,
+                    10,
+                ),
+                Err(TransactionError::InvalidInput)
+            );
+
+            // Reserved tags must carry the documented value type, keys are unique
+            let mut bad = Vec::new(&env);
+            bad.push_back(TransactionTag { key: tags::TAG_PATIENT_HASH, value: workflow.clone() });
+            assert_eq!(validation::validate_metadata(&bad), Err(TransactionError::InvalidInput));
+
+            let mut dup = Vec::new(&env);
+            dup.push_back(TransactionTag { key: tags::TAG_WORKFLOW, value: workflow.clone() });
+            dup.push_back(TransactionTag { key: tags::TAG_WORKFLOW, value: workflow });
+            assert_eq!(validation::validate_metadata(&dup), Err(TransactionError::InvalidInput));
+        });
+    }
+
+    #[contract]
+    struct CompensatingParticipant;
+
+    #[contractimpl]
+    impl CompensatingParticipant {
+        pub fn undo_write(env: Env, id: u64) {
+            env.storage().instance().set(&symbol_short!("UNDONE"), &id);
+        }
+
+        pub fn undone(env: Env) -> Option<u64> {
+            env.storage().instance().get(&symbol_short!("UNDONE"))
+        }
+    }
+
+    #[test]
+    fn test_rollback_prefers_registered_compensation() {
+        let env = Env::default();
+        let contract_id = env.register(OrchestratorContract, ());
+        let participant = env.register(CompensatingParticipant, ());
+        let participant_client = CompensatingParticipantClient::new(&env, &participant);
+
+        env.as_contract(&contract_id, || {
+            let mut args = Vec::new(&env);
+            args.push_back(42u64.into_val(&env));
+            compensation::register(
+                &env,
+                7,
+                1,
+                &Compensation { function: Symbol::new(&env, "undo_write"), args },
+            );
+
+            let operation = TransactionOperation {
+                operation_id: 1,
+                contract_type: ContractType::VisionRecords,
+                contract_address: participant.clone(),
+                function_name: String::from_str(&env, "add_record"),
+                parameters: Vec::new(&env),
+                locked_resources: Vec::new(&env),
+                prepared: true,
+                committed: false,
+                error: None,
+            };
+            let info = RollbackManager::new(&env).rollback_operation(7, &operation).unwrap();
+            assert!(info.rollback_successful);
+            assert_eq!(info.transaction_id, 7);
+            assert_eq!(info.rollback_function, String::from_str(&env, "compensation"));
+
+            // Consumed once applied
+            assert!(compensation::get(&env, 7, 1).is_none());
+        });
+
+        assert_eq!(participant_client.undone(), Some(42));
+    }
+
+    /// Participant answering the literal hook names the orchestrator invokes
+    #[contract]
+    struct CountingParticipant;
+
+    #[contractimpl]
+    impl CountingParticipant {
+        pub fn prepare_(env: Env) {
+            Self::bump(&env, symbol_short!("PREP"));
+        }
+
+        pub fn commit_(env: Env) {
+            Self::bump(&env, symbol_short!("COMMIT"));
+        }
+
+        pub fn rollback_(env: Env) {
+            Self::bump(&env, symbol_short!("ROLLBACK"));
+        }
+
+        pub fn calls(env: Env, hook: Symbol) -> u32 {
+            env.storage().instance().get(&hook).unwrap_or(0)
+        }
+
+        fn bump(env: &Env, hook: Symbol) {
+            let calls: u32 = env.storage().instance().get(&hook).unwrap_or(0);
+            env.storage().instance().set(&hook, &(calls + 1));
+        }
+    }
+
+    fn counting_operation(env: &Env, operation_id: u64, participant: &Address) -> TransactionOperation {
+        TransactionOperation {
+            operation_id,
+            contract_type: ContractType::VisionRecords,
+            contract_address: participant.clone(),
+            function_name: String::from_str(env, "add_record"),
+            parameters: Vec::new(env),
+            locked_resources: Vec::new(env),
+            prepared: false,
+            committed: false,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_injected_prepare_fault_rolls_back_prepared_operations() {
+        let env = Env::default();
+        let contract_id = env.register(OrchestratorContract, ());
+        let first = env.register(CountingParticipant, ());
+        let second = env.register(CountingParticipant, ());
+        let admin = Address::generate(&env);
+        let initiator = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            OrchestratorContract::initialize(env.clone(), admin.clone(), None).unwrap();
+            fault_injection::inject(&env, FaultPhase::Prepare, &second, None, 1);
+
+            let mut operations = Vec::new(&env);
+            operations.push_back(counting_operation(&env, 1, &first));
+            operations.push_back(counting_operation(&env, 2, &second));
+            assert_eq!(
+                OrchestratorContract::start_transaction(
+                    env.clone(),
+                    initiator.clone(),
+                    operations,
+                    Some(300),
+                    Vec::new(&env),
+                ),
+                Err(TransactionError::ContractCallFailed)
+            );
+
+            let log = OrchestratorContract::get_transaction(env.clone(), 1).unwrap();
+            assert_eq!(log.phase, TransactionPhase::RolledBack);
+            assert_eq!(log.error, Some(String::from_str(&env, "Prepare failed; rolled back")));
+            assert!(log.operations.get(0).unwrap().prepared);
+            assert!(log.operations.get(1).unwrap().error.is_some());
+            // Single-shot faults disarm after firing
+            assert_eq!(fault_injection::get_faults(&env).len(), 0);
+        });
+
+        let first_client = CountingParticipantClient::new(&env, &first);
+        let second_client = CountingParticipantClient::new(&env, &second);
+        assert_eq!(first_client.calls(&symbol_short!("PREP")), 1);
+        assert_eq!(first_client.calls(&symbol_short!("ROLLBACK")), 1);
+        assert_eq!(second_client.calls(&symbol_short!("PREP")), 0);
+    }
+
+    #[test]
+    fn test_injected_commit_and_rollback_faults() {
+        let env = Env::default();
+        let contract_id = env.register(OrchestratorContract, ());
+        let participant = env.register(CountingParticipant, ());
+        let admin = Address::generate(&env);
+        let initiator = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            OrchestratorContract::initialize(env.clone(), admin.clone(), None).unwrap();
+            fault_injection::inject(&env, FaultPhase::Commit, &participant, Some(2), 1);
+            fault_injection::inject(&env, FaultPhase::Rollback, &participant, None, fault_injection::ALWAYS);
+
+            let mut operations = Vec::new(&env);
+            operations.push_back(counting_operation(&env, 1, &participant));
+            operations.push_back(counting_operation(&env, 2, &participant));
+            assert_eq!(
+                OrchestratorContract::start_transaction(
+                    env.clone(),
+                    initiator.clone(),
+                    operations,
+                    Some(300),
+                    Vec::new(&env),
+                ),
+                Err(TransactionError::ContractCallFailed)
+            );
+
+            let log = OrchestratorContract::get_transaction(env.clone(), 1).unwrap();
+            assert_eq!(
+                log.error,
+                Some(String::from_str(&env, "Commit failed; rollback also failed"))
+            );
+            assert!(log.operations.get(0).unwrap().committed);
+            assert!(!log.operations.get(1).unwrap().committed);
+
+            // ALWAYS faults stay armed until cleared
+            assert_eq!(fault_injection::get_faults(&env).len(), 1);
+            fault_injection::clear(&env);
+            assert_eq!(fault_injection::get_faults(&env).len(), 0);
+        });
+
+        let client = CountingParticipantClient::new(&env, &participant);
+        assert_eq!(client.calls(&symbol_short!("PREP")), 2);
+        assert_eq!(client.calls(&symbol_short!("COMMIT")), 1);
+        assert_eq!(client.calls(&symbol_short!("ROLLBACK")), 0);
+    }
+
+    #[contract]
+    struct VersionedParticipant;
+
+    #[contractimpl]
+    impl VersionedParticipant {
+        pub fn set_version(env: Env, version: InterfaceVersion) {
+            env.storage().instance().set(&symbol_short!("VER"), &version);
+        }
+
+        pub fn get_interface_version(env: Env) -> InterfaceVersion {
+            env.storage().instance().get(&symbol_short!("VER")).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_register_participant_checks_interface_version() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(OrchestratorContract, ());
+        let participant_id = env.register(VersionedParticipant, ());
+        let participant = VersionedParticipantClient::new(&env, &participant_id);
+        let admin = Address::generate(&env);
+        let treasury = Address::generate(&env);
+
+        let register = |contract_type: ContractType, address: &Address| {
+            env.as_contract(&contract_id, || {
+                OrchestratorContract::register_participant(
+                    env.clone(),
+                    admin.clone(),
+                    contract_type,
+                    address.clone(),
+                )
+            })
+        };
+        let participant_for = |contract_type: ContractType| {
+            env.as_contract(&contract_id, || {
+                OrchestratorContract::get_participant(env.clone(), contract_type)
+            })
+        };
+
+        env.as_contract(&contract_id, || {
+            OrchestratorContract::initialize(env.clone(), admin.clone(), None).unwrap();
+        });
+
+        // Newer major version of the records interface
+        participant.set_version(&InterfaceVersion {
+            interface: interface::VISION_RECORDS,
+            major: 2,
+            minor: 0,
+        });
+        assert_eq!(
+            register(ContractType::VisionRecords, &participant_id),
+            Err(TransactionError::IncompatibleInterface)
+        );
+
+        // Right version, wrong interface
+        participant.set_version(&InterfaceVersion {
+            interface: interface::IDENTITY,
+            major: 1,
+            minor: 0,
+        });
+        assert_eq!(
+            register(ContractType::VisionRecords, &participant_id),
+            Err(TransactionError::IncompatibleInterface)
+        );
+        assert_eq!(participant_for(ContractType::VisionRecords), None);
+
+        // Additive minor releases stay compatible
+        participant.set_version(&InterfaceVersion {
+            interface: interface::VISION_RECORDS,
+            major: 1,
+            minor: 3,
+        });
+        register(ContractType::VisionRecords, &participant_id).unwrap();
+        assert_eq!(
+            participant_for(ContractType::VisionRecords),
+            Some(participant_id.clone())
+        );
+
+        // Contract types with no published interface are registered as-is
+        register(ContractType::Treasury, &treasury).unwrap();
+        assert_eq!(participant_for(ContractType::Treasury), Some(treasury));
+
+        assert_eq!(
+            OrchestratorContract::get_interface_version(env.clone()),
+            INTERFACE_VERSION
+        );
+    }
+
+    #[test]
+    fn test_workflow_sla_breaches_and_compliance() {
+        use crate::sla::{self, BreachSeverity, PhaseLimit, SlaStats, WorkflowSla};
+        use soroban_sdk::testutils::Ledger;
+
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(OrchestratorContract, ());
+        let admin = Address::generate(&env);
+        let name = String::from_str(&env, "referral_intake");
+
+        env.as_contract(&contract_id, || {
+            OrchestratorContract::initialize(env.clone(), admin.clone(), None).unwrap();
+
+            // Terminal phases and zero limits are rejected
+            let mut limits = Vec::new(&env);
+            limits.push_back(PhaseLimit { phase: TransactionPhase::Committed, max_seconds: 60 });
+            assert_eq!(
+                OrchestratorContract::set_workflow_sla(
+                    env.clone(),
+                    admin.clone(),
+                    WorkflowSla { workflow: name.clone(), limits },
+                ),
+                Err(TransactionError::InvalidInput)
+            );
+
+            let mut limits = Vec::new(&env);
+            limits.push_back(PhaseLimit { phase: TransactionPhase::Preparing, max_seconds: 100 });
+            limits.push_back(PhaseLimit { phase: TransactionPhase::Prepared, max_seconds: 100 });
+            let definition = WorkflowSla { workflow: name.clone(), limits };
+            OrchestratorContract::set_workflow_sla(env.clone(), admin.clone(), definition.clone())
+                .unwrap();
+            assert_eq!(
+                OrchestratorContract::get_workflow_sla(env.clone(), name.clone()),
+                Some(definition)
+            );
+
+            let mut metadata = Vec::new(&env);
+            metadata.push_back(TransactionTag {
+                key: tags::TAG_WORKFLOW,
+                value: TagValue::Text(name.clone()),
+            });
+            let mut log = TransactionLog {
+                transaction_id: 1,
+                initiator: admin.clone(),
+                phase: TransactionPhase::Preparing,
+                status: TransactionStatus::Active,
+                operations: Vec::new(&env),
+                created_at: env.ledger().timestamp(),
+                updated_at: env.ledger().timestamp(),
+                timeout_seconds: 3600,
+                error: None,
+                metadata: metadata.clone(),
+            };
+            sla::observe(&env, &log);
+
+            // The sweeper reports an overrun while the phase is still open
+            env.ledger().with_mut(|l| l.timestamp += 120);
+            sla::check_overdue(&env, &log);
+            let event = env.events().all().last().unwrap();
+            assert_eq!(event.1, (symbol_short!("SLA_BRCH"), 1u64).into_val(&env));
+            let breach: sla::SlaBreach = event.2.into_val(&env);
+            assert_eq!(breach.phase, TransactionPhase::Preparing);
+            assert_eq!(breach.elapsed_seconds, 120);
+            assert_eq!(breach.severity, BreachSeverity::Minor);
+
+            log.phase = TransactionPhase::Prepared;
+            sla::observe(&env, &log);
+            log.phase = TransactionPhase::Committed;
+            log.status = TransactionStatus::Completed;
+            sla::observe(&env, &log);
+
+            // A compliant run of the same workflow
+            log.transaction_id = 2;
+            log.phase = TransactionPhase::Preparing;
+            log.status = TransactionStatus::Active;
+            log.created_at = env.ledger().timestamp();
+            sla::observe(&env, &log);
+            log.phase = TransactionPhase::Committed;
+            sla::observe(&env, &log);
+
+            assert_eq!(
+                OrchestratorContract::get_sla_stats(env.clone(), name.clone()),
+                SlaStats { completed: 2, breached: 1 }
+            );
+
+            // Untagged transactions are not tracked
+            log.transaction_id = 3;
+            log.metadata = Vec::new(&env);
+            log.phase = TransactionPhase::Preparing;
+            sla::observe(&env, &log);
+            log.phase = TransactionPhase::RolledBack;
+            sla::observe(&env, &log);
+            assert_eq!(
+                OrchestratorContract::get_sla_stats(env.clone(), name.clone()).completed,
+                2
+            );
+        });
+    }
+
+    #[test]
+    fn test_invariant_checks_flag_orphaned_state() {
+        use common::invariant::InvariantSubject;
+
+        let env = Env::default();
+        let contract_id = env.register(OrchestratorContract, ());
+        let admin = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            OrchestratorContract::initialize(env.clone(), admin.clone(), None).unwrap();
+
+            let mut operations = Vec::new(&env);
+            operations.push_back(TransactionOperation {
+                operation_id: 1,
+                contract_type: ContractType::VisionRecords,
+                contract_address: Address::generate(&env),
+                function_name: String::from_str(&env, "add_record"),
+                parameters: Vec::new(&env),
+                locked_resources: Vec::new(&env),
+                prepared: true,
+                committed: false,
+                error: None,
+            });
+            let finished = |transaction_id: u64, phase, status| TransactionLog {
+                transaction_id,
+                initiator: admin.clone(),
+                phase,
+                status,
+                operations: operations.clone(),
+                created_at: 0,
+                updated_at: 0,
+                timeout_seconds: 300,
+                error: None,
+                metadata: Vec::new(&env),
+            };
+            set_transaction_log(
+                &env,
+                &finished(1, TransactionPhase::Committed, TransactionStatus::Completed),
+            );
+            set_transaction_log(
+                &env,
+                &finished(2, TransactionPhase::RolledBack, TransactionStatus::Failed),
+            );
+            env.storage().instance().set(&common::transaction::TRANSACTION_COUNTER, &2u64);
+            compensation::register(
+                &env,
+                2,
+                1,
+                &Compensation { function: Symbol::new(&env, "undo"), args: Vec::new(&env) },
+            );
+            let mut locks: Vec<(String, u64)> = Vec::new(&env);
+            locks.push_back((String::from_str(&env, "patient:7"), 1));
+            env.storage().instance().set(&common::transaction::RESOURCE_LOCKS, &locks);
+
+            let report = OrchestratorContract::run_invariant_checks(env.clone(), 10).unwrap();
+            assert!(report.wrapped);
+            assert_eq!(report.violation_count, 3);
+            assert_eq!(report.violations.get(0).unwrap().check, symbol_short!("op_uncmt"));
+            assert_eq!(
+                report.violations.get(1).unwrap().subject,
+                InvariantSubject::IdPair(2, 1)
+            );
+            assert_eq!(
+                report.violations.get(2).unwrap().subject,
+                InvariantSubject::Resource(String::from_str(&env, "patient:7"))
+            );
+
+            // Once the state is repaired a fresh pass is clean
+            compensation::clear(&env, 2, 1);
+            env.storage().instance().set(&common::transaction::RESOURCE_LOCKS, &Vec::<(String, u64)>::new(&env));
+            set_transaction_log(
+                &env,
+                &finished(1, TransactionPhase::RolledBack, TransactionStatus::Failed),
+            );
+            let report = OrchestratorContract::run_invariant_checks(env.clone(), 10).unwrap();
+            assert_eq!(report.violation_count, 0);
+        });
+    }
 }
@@ -0,0 +1,161 @@
+//! Property tests for the orchestrator's phase state machine.
+//!
+//! Invariants tested:
+//! - No transition leaves a terminal phase (Committed, RolledBack, TimedOut)
+//! - Committed is only reachable from Prepared
+//! - Any sequence of accepted transitions ends at most one step past Prepared
+//! - Only prepared, uncommitted operations can be rolled back
+//! - Receipts never report a committed operation as rolled back
+//! - Expiry is monotonic in the current timestamp
+
+extern crate std;
+
+use proptest::prelude::*;
+use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+use common::transaction::{
+    ContractType, TransactionError, TransactionLog, TransactionOperation, TransactionPhase,
+    TransactionStatus,
+};
+
+use crate::receipt::{OperationOutcome, ReceiptBuilder};
+use crate::validation::{
+    is_transaction_expired_check, validate_phase_transition, validate_rollback_operation,
+};
+use crate::OrchestratorContract;
+
+fn phase_from_u8(n: u8) -> TransactionPhase {
+    match n % 5 {
+        0 => TransactionPhase::Preparing,
+        1 => TransactionPhase::Prepared,
+        2 => TransactionPhase::Committed,
+        3 => TransactionPhase::RolledBack,
+        _ => TransactionPhase::TimedOut,
+    }
+}
+
+fn is_terminal(phase: &TransactionPhase) -> bool {
+    matches!(
+        phase,
+        TransactionPhase::Committed | TransactionPhase::RolledBack | TransactionPhase::TimedOut
+    )
+}
+
+proptest! {
+    #[test]
+    fn prop_no_transition_out_of_terminal_phase(from in any::<u8>(), to in any::<u8>()) {
+        let from = phase_from_u8(from);
+        let to = phase_from_u8(to);
+        if is_terminal(&from) {
+            prop_assert_eq!(
+                validate_phase_transition(&from, &to),
+                Err(TransactionError::InvalidPhase)
+            );
+        }
+    }
+
+    #[test]
+    fn prop_committed_only_from_prepared(from in any::<u8>()) {
+        let from = phase_from_u8(from);
+        let allowed = validate_phase_transition(&from, &TransactionPhase::Committed).is_ok();
+        prop_assert_eq!(allowed, from == TransactionPhase::Prepared);
+    }
+
+    #[test]
+    fn prop_no_self_transitions(phase in any::<u8>()) {
+        let phase = phase_from_u8(phase);
+        prop_assert!(validate_phase_transition(&phase, &phase).is_err());
+    }
+
+    /// Applying only the accepted steps from a random walk never takes more
+    /// than two steps and always stops in a terminal phase once it leaves
+    /// Prepared.
+    #[test]
+    fn prop_random_walk_is_bounded(steps in prop::collection::vec(any::<u8>(), 0..32)) {
+        let mut phase = TransactionPhase::Preparing;
+        let mut accepted = 0u32;
+        for step in steps {
+            let next = phase_from_u8(step);
+            if validate_phase_transition(&phase, &next).is_ok() {
+                phase = next;
+                accepted += 1;
+            }
+        }
+        prop_assert!(accepted <= 2);
+        if accepted == 2 {
+            prop_assert!(is_terminal(&phase));
+        }
+    }
+
+    #[test]
+    fn prop_rollback_requires_prepared_uncommitted(prepared in any::<bool>(), committed in any::<bool>()) {
+        prop_assert_eq!(
+            validate_rollback_operation(prepared, committed).is_ok(),
+            prepared && !committed
+        );
+    }
+
+    #[test]
+    fn prop_expiry_is_monotonic(
+        created_at in 0u64..=u64::MAX / 2,
+        timeout in 0u64..=604_800,
+        now in 0u64..=u64::MAX / 2,
+        later in 0u64..=1_000_000,
+    ) {
+        if is_transaction_expired_check(created_at, timeout, now) {
+            prop_assert!(is_transaction_expired_check(created_at, timeout, now + later));
+        }
+        prop_assert!(!is_transaction_expired_check(created_at, timeout, created_at + timeout));
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    #[test]
+    fn prop_receipt_never_rolls_back_committed_operations(
+        phase in any::<u8>(),
+        flags in prop::collection::vec((any::<bool>(), any::<bool>()), 1..8),
+    ) {
+        let env = Env::default();
+        let contract_id = env.register(OrchestratorContract, ());
+        let participant = Address::generate(&env);
+        let phase = phase_from_u8(phase);
+
+        let mut operations = Vec::new(&env);
+        for (i, (prepared, committed)) in flags.iter().enumerate() {
+            operations.push_back(TransactionOperation {
+                operation_id: i as u64 + 1,
+                contract_type: ContractType::VisionRecords,
+                contract_address: participant.clone(),
+                function_name: String::from_str(&env, "add_record"),
+                parameters: Vec::new(&env),
+                locked_resources: Vec::new(&env),
+                prepared: *prepared || *committed,
+                committed: *committed,
+                error: None,
+            });
+        }
+        let log = TransactionLog {
+            transaction_id: 1,
+            initiator: Address::generate(&env),
+            phase,
+            status: TransactionStatus::Failed,
+            operations,
+            created_at: 0,
+            updated_at: 10,
+            timeout_seconds: 300,
+            error: None,
+            metadata: Vec::new(&env),
+        };
+
+        let receipt = env.as_contract(&contract_id, || ReceiptBuilder::new(&env).build(&log));
+        prop_assert_eq!(receipt.operations.len() as usize, flags.len());
+        for (op, (_, committed)) in receipt.operations.iter().zip(flags.iter()) {
+            if *committed {
+                prop_assert_eq!(op.outcome, OperationOutcome::Committed);
+            } else {
+                prop_assert_ne!(op.outcome, OperationOutcome::Committed);
+            }
+        }
+    }
+}
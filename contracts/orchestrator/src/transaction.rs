@@ -1,10 +1,14 @@
-use soroban_sdk::{Env, Vec, String, Symbol, Val, IntoVal};
+use soroban_sdk::{Env, Vec, String, Symbol, Val, IntoVal, TryFromVal};
 use common::transaction::{
-    TransactionLog, TransactionPhase, TransactionOperation, TransactionError,
+    TransactionLog, TransactionPhase, TransactionOperation, TransactionError, PreparedOperation,
     set_transaction_log, get_transaction_log,
 };
 
+use super::compensation;
 use super::events::EventPublisher;
+use super::sla;
+#[cfg(any(test, feature = "testutils"))]
+use super::fault_injection::{self, FaultPhase};
 
 /// Transaction manager for handling two-phase commit protocol
 pub struct TransactionManager<'a> {
@@ -19,6 +23,8 @@ impl<'a> TransactionManager<'a> {
     /// Prepare phase: call prepare_* functions on all participating contracts.
     /// Each operation's `function_name` should be the base name (e.g., "stake").
     /// This method will invoke `prepare_<function_name>` on each target contract.
+    /// Participants that return a [`PreparedOperation`] have its compensation
+    /// registered so a later rollback can undo the provisional write.
     pub fn prepare_phase(&self, log: &mut TransactionLog) -> Result<(), TransactionError> {
         log.phase = TransactionPhase::Preparing;
         set_transaction_log(self.env, log);
@@ -28,6 +34,11 @@ impl<'a> TransactionManager<'a> {
         for i in 0..log.operations.len() {
             let mut operation = log.operations.get(i).unwrap().clone();
 
+            #[cfg(any(test, feature = "testutils"))]
+            if fault_injection::trip(self.env, FaultPhase::Prepare, &operation) {
+                return Err(self.record_injected_failure(log, prepared_operations, i));
+            }
+
             // Build the prepare function symbol and invoke
             let func_sym = Symbol::new(self.env, "prepare_");
 
@@ -39,11 +50,16 @@ impl<'a> TransactionManager<'a> {
 
             // invoke_contract panics on failure; Soroban runtime catches it
             // For orchestrated transactions, the caller should handle panics
-            let _result: Val = self.env.invoke_contract(
+            let result: Val = self.env.invoke_contract(
                 &operation.contract_address,
                 &func_sym,
                 args,
             );
+            if let Ok(prepared) = PreparedOperation::try_from_val(self.env, &result) {
+                compensation::register(
+                    self.env, log.transaction_id, operation.operation_id, &prepared.compensation,
+                );
+            }
 
             operation.prepared = true;
             prepared_operations.push_back(operation.clone());
@@ -56,6 +72,7 @@ impl<'a> TransactionManager<'a> {
         log.phase = TransactionPhase::Prepared;
         log.updated_at = self.env.ledger().timestamp();
         set_transaction_log(self.env, log);
+        sla::observe(self.env, log);
         EventPublisher::transaction_prepared(self.env, log);
 
         Ok(())
@@ -76,6 +93,11 @@ impl<'a> TransactionManager<'a> {
                 return Err(TransactionError::InvalidPhase);
             }
 
+            #[cfg(any(test, feature = "testutils"))]
+            if fault_injection::trip(self.env, FaultPhase::Commit, &operation) {
+                return Err(self.record_injected_failure(log, committed_operations, i));
+            }
+
             let func_sym = Symbol::new(self.env, "commit_");
 
             let mut args: Vec<Val> = Vec::new(self.env);
@@ -91,6 +113,7 @@ impl<'a> TransactionManager<'a> {
             );
 
             operation.committed = true;
+            compensation::clear(self.env, log.transaction_id, operation.operation_id);
             committed_operations.push_back(operation.clone());
             EventPublisher::operation_committed(
                 self.env, log.transaction_id, operation.operation_id, &operation.contract_type,
@@ -104,6 +127,34 @@ impl<'a> TransactionManager<'a> {
         Ok(())
     }
 
+    /// Persists progress up to the operation an injected fault hit, so the
+    /// caller's rollback sees exactly what a real participant failure leaves.
+    #[cfg(any(test, feature = "testutils"))]
+    fn record_injected_failure(
+        &self,
+        log: &mut TransactionLog,
+        done: Vec<TransactionOperation>,
+        failed_index: u32,
+    ) -> TransactionError {
+        let error = String::from_str(self.env, "Injected fault");
+        let mut operations = done;
+        for i in failed_index..log.operations.len() {
+            let mut operation = log.operations.get(i).unwrap();
+            if i == failed_index {
+                operation.error = Some(error.clone());
+                EventPublisher::operation_failed(
+                    self.env, log.transaction_id, operation.operation_id,
+                    &operation.contract_type, &error,
+                );
+            }
+            operations.push_back(operation);
+        }
+        log.operations = operations;
+        log.updated_at = self.env.ledger().timestamp();
+        set_transaction_log(self.env, log);
+        TransactionError::ContractCallFailed
+    }
+
     /// Validate that all operations in a transaction are compatible
     pub fn validate_transaction(&self, operations: &Vec<TransactionOperation>) -> Result<(), TransactionError> {
         if operations.is_empty() {
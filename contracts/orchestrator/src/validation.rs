@@ -1,11 +1,17 @@
-use soroban_sdk::{String, Vec};
-use common::transaction::{TransactionError, TransactionPhase, TransactionTimeoutConfig, TransactionOperation};
+use soroban_sdk::{Env, String, Vec};
+use common::transaction::{
+    ContractType, TagValue, TransactionError, TransactionPhase, TransactionTag,
+    TransactionTimeoutConfig, TransactionOperation,
+};
+
+use crate::{registry, tags};
 
 const MIN_TIMEOUT_SECONDS: u64 = 30;
 const MAX_TIMEOUT_SECONDS: u64 = 86400 * 7; // 7 days
 const MAX_OPERATIONS_PER_TRANSACTION: u32 = 50;
 const MAX_METADATA_ITEMS: u32 = 20;
 const MAX_PARAMETERS_PER_OPERATION: u32 = 10;
+const MAX_TAG_TEXT_LEN: u32 = 128;
 
 /// Validates timeout value
 pub fn validate_timeout(timeout_seconds: u64) -> Result<(), TransactionError> {
@@ -25,13 +31,26 @@ pub fn validate_operation_count(count: u32) -> Result<(), TransactionError> {
     }
 }
 
-/// Validates metadata size
-pub fn validate_metadata(metadata: &Vec<String>) -> Result<(), TransactionError> {
-    if metadata.len() > MAX_METADATA_ITEMS as u32 {
-        Err(TransactionError::InvalidInput)
-    } else {
-        Ok(())
+/// Validates metadata tags: size, unique keys, text length and reserved tag types
+pub fn validate_metadata(metadata: &Vec<TransactionTag>) -> Result<(), TransactionError> {
+    if metadata.len() > MAX_METADATA_ITEMS {
+        return Err(TransactionError::InvalidInput);
     }
+    for i in 0..metadata.len() {
+        let tag = metadata.get(i).unwrap();
+        for j in 0..i {
+            if metadata.get(j).unwrap().key == tag.key {
+                return Err(TransactionError::InvalidInput);
+            }
+        }
+        if let TagValue::Text(text) = &tag.value {
+            if text.len() > MAX_TAG_TEXT_LEN {
+                return Err(TransactionError::InvalidInput);
+            }
+        }
+        tags::validate_reserved(&tag)?;
+    }
+    Ok(())
 }
 
 /// Validates operation parameters
@@ -67,7 +86,7 @@ pub fn validate_resource_id(resource_id: &String) -> Result<(), TransactionError
 pub fn validate_transaction_metadata(
     operations_count: u32,
     timeout_seconds: u64,
-    metadata: &Vec<String>,
+    metadata: &Vec<TransactionTag>,
 ) -> Result<(), TransactionError> {
     validate_operation_count(operations_count)?;
     validate_timeout(timeout_seconds)?;
@@ -75,9 +94,11 @@ pub fn validate_transaction_metadata(
     Ok(())
 }
 
-/// Validates transaction operation
+/// Validates transaction operation, including the target contract's function allowlist
 pub fn validate_transaction_operation(
+    env: &Env,
     operation_id: u64,
+    contract_type: &ContractType,
     function_name: &String,
     parameters: &Vec<String>,
     locked_resources: &Vec<String>,
@@ -86,6 +107,9 @@ pub fn validate_transaction_operation(
         return Err(TransactionError::InvalidInput);
     }
     validate_function_name(function_name)?;
+    if !registry::is_function_allowed(env, contract_type, function_name) {
+        return Err(TransactionError::FunctionNotAllowed);
+    }
     validate_operation_parameters(parameters)?;
 
     for i in 0..locked_resources.len() {
@@ -13,7 +13,10 @@ use common::admin_tiers::{self, AdminTier};
 use common::multisig;
 use soroban_sdk::{
     contract, contractimpl, contracttype, symbol_short, token, Address, BytesN, Env, String, Symbol,
+    Vec,
 };
+use common::interface::{self, InterfaceVersion};
+use common::storage_ttl::{self, StorageKeySpec};
 
 use timelock::{RateChangeProposal, UnstakeRequest};
 
@@ -57,6 +60,13 @@ const USER_EARNED: Symbol = symbol_short!("ERND");
 // Used by the Governor DAO to compute the time-weighted loyalty multiplier.
 const USER_SINCE: Symbol = symbol_short!("SINCE");
 
+/// Cross-contract interface version reported by `get_interface_version`
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: interface::STAKING,
+    major: 1,
+    minor: 0,
+};
+
 // ── Contract errors ──────────────────────────────────────────────────────────
 
 #[soroban_sdk::contracterror]
@@ -178,6 +188,11 @@ impl StakingContract {
         Ok(())
     }
 
+    /// Version of the interface this contract exposes to other contracts.
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
     // ── Staking ─────────────────────────────────────────────────────────────
 
     /// Deposit `amount` stake tokens.
@@ -1202,6 +1217,18 @@ impl StakingContract {
         env.storage().temporary().remove(&prep_key);
         Ok(())
     }
+
+    /// Extends the TTL of the entries described by `keys_spec`, inspecting at
+    /// most `limit` keys. Permissionless: it only keeps existing state alive.
+    pub fn extend_storage(env: Env, keys_spec: Vec<StorageKeySpec>, limit: u32) -> u32 {
+        storage_ttl::extend_storage(
+            &env,
+            &keys_spec,
+            limit,
+            storage_ttl::DEFAULT_TTL_THRESHOLD,
+            storage_ttl::DEFAULT_TTL_EXTEND_TO,
+        )
+    }
 }
 
 // ── Tests ─────────────────────────────────────────────────────────────────────
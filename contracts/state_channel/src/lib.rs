@@ -1,7 +1,9 @@
 #![no_std]
 
 use common::CommonError;
-use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Symbol};
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, Symbol, Vec};
+use common::interface::{self, InterfaceVersion};
+use common::storage_ttl::{self, StorageKeySpec};
 use teye_common as common;
 
 pub mod channel;
@@ -14,6 +16,13 @@ mod test;
 const VISION_RECORDS: Symbol = symbol_short!("V_REC");
 const ADMIN: Symbol = symbol_short!("ADMIN");
 
+/// Cross-contract interface version reported by `get_interface_version`
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: interface::STATE_CHANNEL,
+    major: 1,
+    minor: 0,
+};
+
 #[contract]
 pub struct StateChannelContract;
 
@@ -34,6 +43,11 @@ impl StateChannelContract {
         Ok(())
     }
 
+    /// Version of the interface this contract exposes to other contracts.
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
     pub fn open_channel(
         env: Env,
         patient: Address,
@@ -107,4 +121,16 @@ impl StateChannelContract {
         patient.require_auth();
         channel::open_multi_hop(&env, patient, provider, intermediary, capacity)
     }
+
+    /// Extends the TTL of the entries described by `keys_spec`, inspecting at
+    /// most `limit` keys. Permissionless: it only keeps existing state alive.
+    pub fn extend_storage(env: Env, keys_spec: Vec<StorageKeySpec>, limit: u32) -> u32 {
+        storage_ttl::extend_storage(
+            &env,
+            &keys_spec,
+            limit,
+            storage_ttl::DEFAULT_TTL_THRESHOLD,
+            storage_ttl::DEFAULT_TTL_EXTEND_TO,
+        )
+    }
 }
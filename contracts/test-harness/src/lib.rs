@@ -0,0 +1,205 @@
+//! Multi-contract test harness for the Teye contract suite.
+//!
+//! Each contract crate's own tests deploy that contract alone. This crate
+//! deploys `vision_records`, `identity`, `zk_verifier` and `orchestrator`
+//! into a single [`Env`] and wires them together the way a real deployment
+//! would, so integration tests can exercise calls that cross contracts:
+//!
+//! - `vision_records` uses the deployed `zk_verifier` as its registration
+//!   verifier.
+//! - `identity` uses the same `zk_verifier` for credential checks.
+//! - The orchestrator is initialized with the shared admin, and
+//!   [`Workspace::footprint_targets`] points it at the other three contracts.
+//!
+//! [`scenario`] adds deterministic fixtures (patients with records, expiring
+//! grants, in-flight recoveries, prepared transactions), and the
+//! `advance_*` helpers move the ledger forward so sweepers, expiries and
+//! timeouts can be observed.
+//!
+//! ```ignore
+//! let ws = WorkspaceBuilder::new().build();
+//! let patient = ws.create_user(Role::Patient, "Alice");
+//! let provider = ws.create_user(Role::Optometrist, "Dr. Bob");
+//! let record_id = ws.add_record(&provider, &patient, RecordType::Examination, "QmHash");
+//! ```
+
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+pub mod scenario;
+
+use identity::{IdentityContract, IdentityContractClient};
+use orchestrator::maintenance::FootprintTargets;
+use orchestrator::{OrchestratorContract, OrchestratorContractClient};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    Address, Env, String,
+};
+use vision_records::{RecordType, Role, VisionRecordsContract, VisionRecordsContractClient};
+use zk_verifier::{ZkVerifierContract, ZkVerifierContractClient};
+
+/// All deployed contracts plus the admin that controls them
+pub struct Workspace {
+    pub env: Env,
+    pub admin: Address,
+    pub vision_records: VisionRecordsContractClient<'static>,
+    pub identity: IdentityContractClient<'static>,
+    pub zk_verifier: ZkVerifierContractClient<'static>,
+    pub orchestrator: OrchestratorContractClient<'static>,
+}
+
+/// Configures and deploys a [`Workspace`]
+pub struct WorkspaceBuilder {
+    env: Env,
+    admin: Option<Address>,
+    mock_all_auths: bool,
+    wire_registration_verifier: bool,
+    wire_identity_verifier: bool,
+}
+
+impl Default for WorkspaceBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WorkspaceBuilder {
+    /// Fresh environment, all auths mocked and every registry wired.
+    pub fn new() -> Self {
+        Self::with_env(Env::default())
+    }
+
+    /// Deploy into an existing environment, e.g. one with a custom ledger.
+    pub fn with_env(env: Env) -> Self {
+        Self {
+            env,
+            admin: None,
+            mock_all_auths: true,
+            wire_registration_verifier: true,
+            wire_identity_verifier: true,
+        }
+    }
+
+    /// Use `admin` instead of a generated address.
+    pub fn admin(mut self, admin: Address) -> Self {
+        self.admin = Some(admin);
+        self
+    }
+
+    /// Leave auth checks to the test, for asserting on `require_auth`.
+    /// Wiring is still done with mocked auths.
+    pub fn without_mocked_auths(mut self) -> Self {
+        self.mock_all_auths = false;
+        self
+    }
+
+    /// Skip pointing `vision_records` at the deployed verifier.
+    pub fn without_registration_verifier(mut self) -> Self {
+        self.wire_registration_verifier = false;
+        self
+    }
+
+    /// Skip pointing `identity` at the deployed verifier.
+    pub fn without_identity_verifier(mut self) -> Self {
+        self.wire_identity_verifier = false;
+        self
+    }
+
+    pub fn build(self) -> Workspace {
+        let env = self.env;
+        env.mock_all_auths();
+        let admin = self.admin.unwrap_or_else(|| Address::generate(&env));
+
+        let zk_verifier_id = env.register(ZkVerifierContract, ());
+        let zk_verifier = ZkVerifierContractClient::new(&env, &zk_verifier_id);
+        zk_verifier.initialize(&admin);
+
+        let vision_records_id = env.register(VisionRecordsContract, ());
+        let vision_records = VisionRecordsContractClient::new(&env, &vision_records_id);
+        vision_records.initialize(&admin);
+
+        let identity_id = env.register(IdentityContract, ());
+        let identity = IdentityContractClient::new(&env, &identity_id);
+        identity.initialize(&admin);
+
+        let orchestrator_id = env.register(OrchestratorContract, ());
+        let orchestrator = OrchestratorContractClient::new(&env, &orchestrator_id);
+        orchestrator.initialize(&admin, &None);
+
+        if self.wire_registration_verifier {
+            vision_records.set_registration_verifier(&admin, &zk_verifier_id);
+        }
+        if self.wire_identity_verifier {
+            identity.set_zk_verifier(&admin, &zk_verifier_id);
+        }
+
+        if !self.mock_all_auths {
+            env.set_auths(&[]);
+        }
+
+        Workspace {
+            env,
+            admin,
+            vision_records,
+            identity,
+            zk_verifier,
+            orchestrator,
+        }
+    }
+}
+
+impl Workspace {
+    /// Move the ledger clock forward by `seconds`, one ledger per call.
+    pub fn advance_time(&self, seconds: u64) {
+        self.env.ledger().with_mut(|li| {
+            li.timestamp = li.timestamp.saturating_add(seconds);
+            li.sequence_number = li.sequence_number.saturating_add(1);
+        });
+    }
+
+    /// Close `ledgers` ledgers at five seconds each.
+    pub fn advance_ledgers(&self, ledgers: u32) {
+        self.env.ledger().with_mut(|li| {
+            li.sequence_number = li.sequence_number.saturating_add(ledgers);
+            li.timestamp = li.timestamp.saturating_add(u64::from(ledgers) * 5);
+        });
+    }
+
+    /// Jump to just past `timestamp`, e.g. a grant's or request's deadline.
+    pub fn advance_past(&self, timestamp: u64) {
+        let now = self.env.ledger().timestamp();
+        if timestamp >= now {
+            self.advance_time(timestamp - now + 1);
+        }
+    }
+
+    /// Contracts the orchestrator maintains a patient's footprint across.
+    pub fn footprint_targets(&self) -> FootprintTargets {
+        FootprintTargets {
+            identity: self.identity.address.clone(),
+            vision_records: self.vision_records.address.clone(),
+            zk_verifier: self.zk_verifier.address.clone(),
+        }
+    }
+
+    /// Register a fresh address in `vision_records` under `role`.
+    pub fn create_user(&self, role: Role, name: &str) -> Address {
+        let user = Address::generate(&self.env);
+        let name = String::from_str(&self.env, name);
+        self.vision_records
+            .register_user(&self.admin, &user, &role, &name);
+        user
+    }
+
+    /// Add a record authored by `provider` and return its id.
+    pub fn add_record(
+        &self,
+        provider: &Address,
+        patient: &Address,
+        record_type: RecordType,
+        data_hash: &str,
+    ) -> u64 {
+        let hash = String::from_str(&self.env, data_hash);
+        self.vision_records
+            .add_record(provider, patient, provider, &record_type, &hash)
+    }
+}
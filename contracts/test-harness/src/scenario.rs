@@ -0,0 +1,165 @@
+use common::transaction::{
+    set_transaction_log, ContractType, TransactionLog, TransactionOperation, TransactionPhase,
+    TransactionStatus, RESOURCE_LOCKS,
+};
+use identity::{IdentityContract, IdentityContractClient};
+use soroban_sdk::{testutils::Address as _, Address, String, Vec};
+use vision_records::{AccessLevel, RecordType, Role};
+
+use crate::Workspace;
+
+/// A patient, the provider who wrote their records, and the record ids
+pub struct PatientFixture {
+    pub patient: Address,
+    pub provider: Address,
+    pub record_ids: std::vec::Vec<u64>,
+}
+
+/// A grant that lapses `expires_in` seconds after it was created
+pub struct GrantFixture {
+    pub patient: Address,
+    pub grantee: Address,
+    pub expires_in: u64,
+}
+
+/// An identity with a recovery request that has not been executed yet
+pub struct RecoveryFixture {
+    /// Dedicated identity deployment owned by `owner`
+    pub identity: IdentityContractClient<'static>,
+    pub owner: Address,
+    pub guardians: std::vec::Vec<Address>,
+    pub new_address: Address,
+}
+
+/// An orchestrated transaction left in the `Prepared` phase
+pub struct TransactionFixture {
+    pub transaction_id: u64,
+    pub initiator: Address,
+    pub locked_resources: Vec<String>,
+}
+
+impl Workspace {
+    /// Register a patient and an optometrist, then add `records` examination
+    /// records. Data hashes are `Qm<tag>-<n>`, so fixtures built with the
+    /// same tag are identical across runs.
+    pub fn patient_with_records(&self, tag: &str, records: u32) -> PatientFixture {
+        let patient = self.create_user(Role::Patient, tag);
+        let provider = self.create_user(Role::Optometrist, tag);
+        let record_ids = (0..records)
+            .map(|n| {
+                let hash = format!("Qm{tag}-{n}");
+                self.add_record(&provider, &patient, RecordType::Examination, &hash)
+            })
+            .collect();
+        PatientFixture {
+            patient,
+            provider,
+            record_ids,
+        }
+    }
+
+    /// Have `patient` grant a freshly registered optometrist read access for
+    /// `expires_in` seconds.
+    pub fn expiring_grant(&self, patient: &Address, expires_in: u64) -> GrantFixture {
+        let grantee = self.create_user(Role::Optometrist, "Grantee");
+        self.vision_records
+            .grant_access(patient, patient, &grantee, &AccessLevel::Read, &expires_in);
+        GrantFixture {
+            patient: patient.clone(),
+            grantee,
+            expires_in,
+        }
+    }
+
+    /// Deploy an identity for a new owner with `guardians` guardians and the
+    /// given threshold, then have the first guardian initiate recovery.
+    /// The request is still inside its cooldown when this returns.
+    pub fn in_flight_recovery(&self, guardians: u32, threshold: u32) -> RecoveryFixture {
+        let env = &self.env;
+        let identity_id = env.register(IdentityContract, ());
+        let identity = IdentityContractClient::new(env, &identity_id);
+        let owner = Address::generate(env);
+        identity.initialize(&owner);
+        identity.set_zk_verifier(&owner, &self.zk_verifier.address);
+
+        let guardians: std::vec::Vec<Address> =
+            (0..guardians).map(|_| Address::generate(env)).collect();
+        for guardian in &guardians {
+            identity.add_guardian(&owner, guardian);
+        }
+        identity.set_recovery_threshold(&owner, &threshold);
+
+        let new_address = Address::generate(env);
+        identity.initiate_recovery(&guardians[0], &owner, &new_address);
+
+        RecoveryFixture {
+            identity,
+            owner,
+            guardians,
+            new_address,
+        }
+    }
+
+    /// Store a transaction whose operations against `vision_records` are all
+    /// prepared but none committed, holding locks on `resources`. Timeout
+    /// and deadlock handling can then be driven with [`Workspace::advance_time`].
+    pub fn prepared_transaction(
+        &self,
+        transaction_id: u64,
+        resources: &[&str],
+        timeout_seconds: u64,
+    ) -> TransactionFixture {
+        let env = &self.env;
+        let initiator = Address::generate(env);
+        let mut locked_resources = Vec::new(env);
+        for resource in resources {
+            locked_resources.push_back(String::from_str(env, resource));
+        }
+
+        let mut operations = Vec::new(env);
+        operations.push_back(TransactionOperation {
+            operation_id: 1,
+            contract_type: ContractType::VisionRecords,
+            contract_address: self.vision_records.address.clone(),
+            function_name: String::from_str(env, "add_record"),
+            parameters: Vec::new(env),
+            locked_resources: locked_resources.clone(),
+            prepared: true,
+            committed: false,
+            error: None,
+        });
+
+        let now = env.ledger().timestamp();
+        let log = TransactionLog {
+            transaction_id,
+            initiator: initiator.clone(),
+            phase: TransactionPhase::Prepared,
+            status: TransactionStatus::Active,
+            operations,
+            created_at: now,
+            updated_at: now,
+            timeout_seconds,
+            error: None,
+            metadata: Vec::new(env),
+        };
+
+        env.as_contract(&self.orchestrator.address, || {
+            set_transaction_log(env, &log);
+            let mut locks: Vec<(String, u64)> = env
+                .storage()
+                .instance()
+                .get(&RESOURCE_LOCKS)
+                .unwrap_or(Vec::new(env));
+            for resource in locked_resources.iter() {
+                locks.push_back((resource, transaction_id));
+            }
+            env.storage().instance().set(&RESOURCE_LOCKS, &locks);
+        });
+
+        TransactionFixture {
+            transaction_id,
+            initiator,
+            locked_resources,
+        }
+    }
+}
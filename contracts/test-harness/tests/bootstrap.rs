@@ -0,0 +1,125 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use bootstrap::{
+    BootstrapContract, BootstrapContractClient, BootstrapError, SuiteContracts, SuiteWasm,
+    MANIFEST_VERSION,
+};
+use common::transaction::ContractType;
+use identity::{IdentityContract, IdentityContractClient};
+use orchestrator::{OrchestratorContract, OrchestratorContractClient};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+use vision_records::{VisionRecordsContract, VisionRecordsContractClient};
+use zk_verifier::{ZkVerifierContract, ZkVerifierContractClient};
+
+struct Suite {
+    env: Env,
+    bootstrap: BootstrapContractClient<'static>,
+    contracts: SuiteContracts,
+    wasm: SuiteWasm,
+    deployer: Address,
+    governance: Address,
+}
+
+/// Registers the suite natively, uninitialized, as a separate deploy would.
+fn setup() -> Suite {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let bootstrap_id = env.register(BootstrapContract, ());
+    let contracts = SuiteContracts {
+        identity: env.register(IdentityContract, ()),
+        vision_records: env.register(VisionRecordsContract, ()),
+        zk_verifier: env.register(ZkVerifierContract, ()),
+        orchestrator: env.register(OrchestratorContract, ()),
+    };
+    let wasm = SuiteWasm {
+        identity: BytesN::from_array(&env, &[1; 32]),
+        vision_records: BytesN::from_array(&env, &[2; 32]),
+        zk_verifier: BytesN::from_array(&env, &[3; 32]),
+        orchestrator: BytesN::from_array(&env, &[4; 32]),
+    };
+
+    Suite {
+        bootstrap: BootstrapContractClient::new(&env, &bootstrap_id),
+        deployer: Address::generate(&env),
+        governance: Address::generate(&env),
+        env,
+        contracts,
+        wasm,
+    }
+}
+
+#[test]
+fn test_adopt_suite_initializes_wires_and_records_manifest() {
+    let s = setup();
+    let manifest = s
+        .bootstrap
+        .adopt_suite(&s.deployer, &s.governance, &s.contracts, &s.wasm);
+
+    assert_eq!(manifest.version, MANIFEST_VERSION);
+    assert_eq!(manifest.contracts, s.contracts);
+    assert_eq!(manifest.wasm, s.wasm);
+    assert_eq!(manifest.governance, s.governance);
+    assert_eq!(s.bootstrap.get_manifest(), Some(manifest.clone()));
+
+    let vision_records = VisionRecordsContractClient::new(&s.env, &s.contracts.vision_records);
+    let identity = IdentityContractClient::new(&s.env, &s.contracts.identity);
+    let orchestrator = OrchestratorContractClient::new(&s.env, &s.contracts.orchestrator);
+    let zk_verifier = ZkVerifierContractClient::new(&s.env, &s.contracts.zk_verifier);
+
+    assert_eq!(
+        vision_records.get_registration_verifier(),
+        Some(s.contracts.zk_verifier.clone())
+    );
+    assert_eq!(identity.get_zk_verifier(), Some(s.contracts.zk_verifier.clone()));
+    assert!(identity.is_owner_active(&s.governance));
+    assert!(orchestrator.get_timeout_config().default_timeout > 0);
+    assert_eq!(
+        orchestrator.get_participant(&ContractType::VisionRecords),
+        Some(s.contracts.vision_records.clone())
+    );
+
+    // Governance completes the handover
+    assert_eq!(manifest.admin_handover.len(), 2);
+    vision_records.accept_admin(&s.governance);
+    zk_verifier.accept_admin(&s.governance);
+    assert_eq!(vision_records.get_admin(), s.governance);
+}
+
+#[test]
+fn test_bootstrap_runs_once() {
+    let s = setup();
+    s.bootstrap
+        .adopt_suite(&s.deployer, &s.governance, &s.contracts, &s.wasm);
+
+    let res = s
+        .bootstrap
+        .try_adopt_suite(&s.deployer, &s.governance, &s.contracts, &s.wasm);
+    assert_eq!(res.unwrap_err().unwrap(), BootstrapError::AlreadyBootstrapped);
+}
+
+#[test]
+fn test_rejects_duplicate_contracts() {
+    let s = setup();
+    let mut contracts = s.contracts.clone();
+    contracts.orchestrator = contracts.identity.clone();
+
+    let res = s
+        .bootstrap
+        .try_adopt_suite(&s.deployer, &s.governance, &contracts, &s.wasm);
+    assert_eq!(res.unwrap_err().unwrap(), BootstrapError::DuplicateContract);
+    assert_eq!(s.bootstrap.get_manifest(), None);
+}
+
+#[test]
+fn test_rejects_contracts_with_wrong_interface() {
+    let s = setup();
+    let mut contracts = s.contracts.clone();
+    contracts.zk_verifier = s.env.register(VisionRecordsContract, ());
+
+    let res = s
+        .bootstrap
+        .try_adopt_suite(&s.deployer, &s.governance, &contracts, &s.wasm);
+    assert_eq!(res.unwrap_err().unwrap(), BootstrapError::IncompatibleInterface);
+    assert_eq!(s.bootstrap.get_manifest(), None);
+}
@@ -0,0 +1,82 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use common::transaction::TransactionPhase;
+use test_harness::WorkspaceBuilder;
+use vision_records::AccessLevel;
+
+#[test]
+fn test_patient_fixture_is_deterministic() {
+    let first = WorkspaceBuilder::new().build();
+    let second = WorkspaceBuilder::new().build();
+    let a = first.patient_with_records("alice", 3);
+    let b = second.patient_with_records("alice", 3);
+
+    assert_eq!(a.record_ids, b.record_ids);
+    let record = first
+        .vision_records
+        .get_record(&a.patient, &a.record_ids[2]);
+    let other = second
+        .vision_records
+        .get_record(&b.patient, &b.record_ids[2]);
+    assert_eq!(record.data_hash, other.data_hash);
+}
+
+#[test]
+fn test_grant_expires_after_advancing_time() {
+    let ws = WorkspaceBuilder::new().build();
+    let fixture = ws.patient_with_records("bob", 1);
+    let grant = ws.expiring_grant(&fixture.patient, 3_600);
+
+    assert_eq!(
+        ws.vision_records.check_access(&grant.patient, &grant.grantee, &None, &None),
+        AccessLevel::Read
+    );
+    ws.advance_time(grant.expires_in + 1);
+    assert_eq!(
+        ws.vision_records.check_access(&grant.patient, &grant.grantee, &None, &None),
+        AccessLevel::None
+    );
+}
+
+#[test]
+fn test_recovery_waits_for_cooldown() {
+    let ws = WorkspaceBuilder::new().build();
+    let recovery = ws.in_flight_recovery(3, 2);
+    recovery
+        .identity
+        .approve_recovery(&recovery.guardians[1], &recovery.owner);
+
+    let caller = recovery.guardians[0].clone();
+    assert!(recovery
+        .identity
+        .try_execute_recovery(&caller, &recovery.owner)
+        .is_err());
+
+    let request = recovery
+        .identity
+        .get_recovery_request(&recovery.owner)
+        .unwrap();
+    ws.advance_past(request.execute_after);
+    assert_eq!(
+        recovery.identity.execute_recovery(&caller, &recovery.owner),
+        recovery.new_address
+    );
+    assert!(recovery.identity.is_owner_active(&recovery.new_address));
+}
+
+#[test]
+fn test_prepared_transaction_stays_active() {
+    let ws = WorkspaceBuilder::new().build();
+    let tx = ws.prepared_transaction(42, &["patient:alice"], 300);
+
+    let log = ws.orchestrator.get_transaction(&tx.transaction_id);
+    assert_eq!(log.phase, TransactionPhase::Prepared);
+    assert!(ws
+        .orchestrator
+        .get_active_transactions()
+        .contains(tx.transaction_id));
+    assert!(ws
+        .orchestrator
+        .try_get_transaction_receipt(&tx.transaction_id)
+        .is_err());
+}
@@ -0,0 +1,49 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use test_harness::WorkspaceBuilder;
+use vision_records::{RecordType, Role};
+
+#[test]
+fn test_registries_are_wired() {
+    let ws = WorkspaceBuilder::new().build();
+
+    assert_eq!(
+        ws.vision_records.get_registration_verifier(),
+        Some(ws.zk_verifier.address.clone())
+    );
+    assert_eq!(
+        ws.identity.get_zk_verifier(),
+        Some(ws.zk_verifier.address.clone())
+    );
+    assert!(ws.identity.is_owner_active(&ws.admin));
+}
+
+#[test]
+fn test_wiring_can_be_skipped() {
+    let ws = WorkspaceBuilder::new()
+        .without_registration_verifier()
+        .without_identity_verifier()
+        .build();
+
+    assert_eq!(ws.vision_records.get_registration_verifier(), None);
+    assert_eq!(ws.identity.get_zk_verifier(), None);
+}
+
+#[test]
+fn test_orchestrator_reaches_other_contracts() {
+    let ws = WorkspaceBuilder::new().build();
+    let patient = ws.create_user(Role::Patient, "Alice");
+    let provider = ws.create_user(Role::Optometrist, "Dr. Bob");
+    let record_id = ws.add_record(&provider, &patient, RecordType::Examination, "QmHash");
+    assert_eq!(ws.vision_records.get_record(&patient, &record_id).patient, patient);
+
+    let targets = ws.footprint_targets();
+    let extended = ws.orchestrator.extend_patient_footprint(
+        &targets.identity,
+        &targets.vision_records,
+        &targets.zk_verifier,
+        &patient,
+        &10,
+    );
+    assert!(extended > 0);
+}
@@ -0,0 +1,56 @@
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use soroban_sdk::Env;
+use zk_verifier::fixtures::{self, Circuit, FixtureOutcome, FIXTURES};
+use zk_verifier::ContractError;
+
+#[test]
+fn test_every_fixture_matches_recorded_outcome() {
+    let env = Env::default();
+    for fixture in FIXTURES {
+        assert!(
+            fixtures::verify_fixture(&env, fixture.name),
+            "fixture {} no longer matches",
+            fixture.name
+        );
+    }
+}
+
+#[test]
+fn test_fixture_names_are_unique() {
+    for (i, fixture) in FIXTURES.iter().enumerate() {
+        assert!(FIXTURES[i + 1..].iter().all(|f| f.name != fixture.name));
+    }
+}
+
+#[test]
+fn test_each_circuit_has_good_and_bad_vectors() {
+    for circuit in [Circuit::Access, Circuit::Level4Attributes] {
+        let vectors = || FIXTURES.iter().filter(move |f| f.circuit == circuit);
+        assert!(vectors().any(|f| f.expected == FixtureOutcome::Accepted));
+        assert!(vectors().any(|f| f.expected != FixtureOutcome::Accepted));
+    }
+}
+
+#[test]
+fn test_unknown_fixture_is_not_verified() {
+    let env = Env::default();
+    assert!(!fixtures::verify_fixture(&env, "no_such_fixture"));
+}
+
+#[test]
+fn test_evaluate_reports_prover_mistakes() {
+    let env = Env::default();
+    let valid = fixtures::find("access_valid").unwrap();
+
+    // The same proof submitted at level 4 without a commitment input.
+    let request = valid.request(&env, 0);
+    assert_eq!(
+        fixtures::evaluate(&env, Circuit::Level4Attributes, &request),
+        FixtureOutcome::Rejected(ContractError::ProofRequiredForAuthLevel)
+    );
+    assert_eq!(
+        fixtures::evaluate(&env, Circuit::Access, &request),
+        FixtureOutcome::Accepted
+    );
+}
@@ -6,6 +6,8 @@ mod test;
 use soroban_sdk::{
     contract, contractimpl, contracttype, symbol_short, token, Address, Env, String, Symbol, Vec,
 };
+use common::interface::{self, InterfaceVersion};
+use common::storage_ttl::{self, StorageKeySpec};
 
 // ── Storage keys ────────────────────────────────────────────────────────────────
 
@@ -17,6 +19,13 @@ const ALLOCATION: Symbol = symbol_short!("ALLOC");
 // without going through the normal multisig path.
 const GOVERNOR: Symbol = symbol_short!("GOVERNOR");
 
+/// Cross-contract interface version reported by `get_interface_version`
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: interface::TREASURY,
+    major: 1,
+    minor: 0,
+};
+
 // ── Types ──────────────────────────────────────────────────────────────────────
 
 #[contracttype]
@@ -164,6 +173,11 @@ impl TreasuryContract {
         Ok(())
     }
 
+    /// Version of the interface this contract exposes to other contracts.
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
     pub fn get_config(env: Env) -> Result<TreasuryConfig, ContractError> {
         load_config(&env)
     }
@@ -396,4 +410,16 @@ impl TreasuryContract {
             total_spent: spent,
         }
     }
+
+    /// Extends the TTL of the entries described by `keys_spec`, inspecting at
+    /// most `limit` keys. Permissionless: it only keeps existing state alive.
+    pub fn extend_storage(env: Env, keys_spec: Vec<StorageKeySpec>, limit: u32) -> u32 {
+        storage_ttl::extend_storage(
+            &env,
+            &keys_spec,
+            limit,
+            storage_ttl::DEFAULT_TTL_THRESHOLD,
+            storage_ttl::DEFAULT_TTL_EXTEND_TO,
+        )
+    }
 }
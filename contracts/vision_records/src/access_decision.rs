@@ -0,0 +1,412 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+
+use crate::cohort::{self, CohortGrant};
+use crate::consent_campaign;
+use crate::emergency;
+use crate::locum::{self, LocumArrangement};
+use crate::privacy;
+use crate::rbac::{self, Permission};
+use crate::retention::ArchivalSummary;
+use crate::scoped_grant;
+use crate::sensitivity;
+use crate::ttl_config;
+use crate::{AccessGrant, AccessLevel, ConsentGrant, GrantStatus, RecordType, User, VisionRecord};
+
+// ── Storage keys ──────────────────────────────────────────────
+const ACC_REVOKED: Symbol = symbol_short!("ACC_RVK");
+
+/// Extends the time-to-live (TTL) for (patient, grantee) revocation keys.
+fn extend_ttl_pair_key(env: &Env, key: &(Symbol, Address, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// What an access decision rests on.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AccessBasis {
+    None = 0,
+    /// A patient grant backed by active consent.
+    Grant = 1,
+}
+
+/// Why access was allowed or denied.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum AccessReasonCode {
+    Granted = 0,
+    NeverGranted = 1,
+    Expired = 2,
+    Revoked = 3,
+    ConsentMissing = 4,
+    ConsentExpired = 5,
+    ConsentRevoked = 6,
+    PolicyDenied = 7,
+    UserInactive = 8,
+    /// Allowed in every other respect, but outside the grant's daily window.
+    OutsideWindow = 9,
+    /// Consent was given under terms a re-consent campaign replaced and was
+    /// not renewed by its deadline.
+    ConsentLapsed = 10,
+}
+
+/// How much of a record a caller may see, from least to most.
+///
+/// `Existence` confirms the record id belongs to the patient; `Summary` adds
+/// type, author and timestamps; `Full` adds the payload hash, key version
+/// and the attestation and emergency links.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum DisclosureTier {
+    None = 0,
+    Existence = 1,
+    Summary = 2,
+    Full = 3,
+}
+
+impl DisclosureTier {
+    pub fn for_level(level: &AccessLevel) -> Self {
+        match level {
+            AccessLevel::None => DisclosureTier::None,
+            AccessLevel::Read => DisclosureTier::Summary,
+            AccessLevel::Write | AccessLevel::Admin => DisclosureTier::Full,
+        }
+    }
+}
+
+/// Outcome of `check_access_detailed`.
+///
+/// `expires_at` is when the decision stops holding: the earlier of the grant
+/// and consent expiries when access is allowed, or the lapsed expiry when the
+/// reason is `Expired`/`ConsentExpired`. It is 0 when there is nothing to
+/// report.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessDecision {
+    pub level: AccessLevel,
+    pub basis: AccessBasis,
+    pub expires_at: u64,
+    pub reason_code: AccessReasonCode,
+}
+
+/// An arrangement through which a caller reads a record they hold no grant
+/// for.
+#[derive(Clone, Debug)]
+pub enum Cover {
+    /// Standing in for an absent provider, who has access themselves.
+    Locum(LocumArrangement),
+    /// Supervising the resident who authored the record.
+    Cohort(CohortGrant),
+}
+
+impl AccessDecision {
+    fn denied(reason_code: AccessReasonCode, expires_at: u64) -> Self {
+        AccessDecision {
+            level: AccessLevel::None,
+            basis: AccessBasis::None,
+            expires_at,
+            reason_code,
+        }
+    }
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Remembers that `patient` explicitly revoked `grantee`'s access, so a
+/// later lookup can tell a revocation apart from a grant that never existed.
+pub fn mark_revoked(env: &Env, patient: &Address, grantee: &Address) {
+    let key = (ACC_REVOKED, patient.clone(), grantee.clone());
+    env.storage()
+        .persistent()
+        .set(&key, &env.ledger().timestamp());
+    extend_ttl_pair_key(env, &key);
+}
+
+pub fn revoked_at(env: &Env, patient: &Address, grantee: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&(ACC_REVOKED, patient.clone(), grantee.clone()))
+}
+
+/// Evaluates `grantee`'s access to `patient`'s records. Addresses are
+/// expected to be alias-resolved by the caller.
+pub fn decide(env: &Env, patient: &Address, grantee: &Address) -> AccessDecision {
+    let now = env.ledger().timestamp();
+
+    if !is_active_user(env, grantee) {
+        return AccessDecision::denied(AccessReasonCode::UserInactive, 0);
+    }
+
+    let grant: Option<AccessGrant> = env.storage().persistent().get(&(
+        symbol_short!("ACCESS"),
+        patient.clone(),
+        grantee.clone(),
+    ));
+    let grant = match grant {
+        Some(grant) => grant,
+        None if revoked_at(env, patient, grantee).is_some() => {
+            return AccessDecision::denied(AccessReasonCode::Revoked, 0);
+        }
+        None => return AccessDecision::denied(AccessReasonCode::NeverGranted, 0),
+    };
+    if grant.status != GrantStatus::Active {
+        return AccessDecision::denied(AccessReasonCode::Revoked, 0);
+    }
+    if grant.expires_at <= now {
+        return AccessDecision::denied(AccessReasonCode::Expired, grant.expires_at);
+    }
+
+    let (level, consent_expires_at) = match consented_level(env, patient, grantee, grant.level) {
+        Ok(consented) => consented,
+        Err(denial) => return denial,
+    };
+    if let Some(denial) = context_denial(env, patient, grantee) {
+        return denial;
+    }
+
+    AccessDecision {
+        level,
+        basis: AccessBasis::Grant,
+        expires_at: grant.expires_at.min(consent_expires_at),
+        reason_code: AccessReasonCode::Granted,
+    }
+}
+
+/// False for registered users who have been deactivated. Unregistered
+/// addresses count as active.
+pub fn is_active_user(env: &Env, address: &Address) -> bool {
+    let user: Option<User> = env
+        .storage()
+        .persistent()
+        .get(&(symbol_short!("USER"), address.clone()));
+    user.map_or(true, |user| user.is_active)
+}
+
+/// `level` as far as `grantee`'s consent from `patient` allows, with the
+/// consent's expiry. Missing, revoked, expired or lapsed consent denies.
+fn consented_level(
+    env: &Env,
+    patient: &Address,
+    grantee: &Address,
+    level: AccessLevel,
+) -> Result<(AccessLevel, u64), AccessDecision> {
+    let consent: Option<ConsentGrant> = env.storage().persistent().get(&(
+        symbol_short!("CONSENT"),
+        patient.clone(),
+        grantee.clone(),
+    ));
+    let consent = match consent {
+        Some(consent) => consent,
+        None => return Err(AccessDecision::denied(AccessReasonCode::ConsentMissing, 0)),
+    };
+    if consent.revoked {
+        return Err(AccessDecision::denied(AccessReasonCode::ConsentRevoked, 0));
+    }
+    if consent.expires_at <= env.ledger().timestamp() {
+        return Err(AccessDecision::denied(
+            AccessReasonCode::ConsentExpired,
+            consent.expires_at,
+        ));
+    }
+    match consent_campaign::lapsed_cap(env, &consent) {
+        Some(AccessLevel::None) => Err(AccessDecision::denied(AccessReasonCode::ConsentLapsed, 0)),
+        Some(cap) => Ok((consent_campaign::cap_level(level, &cap), consent.expires_at)),
+        None => Ok((level, consent.expires_at)),
+    }
+}
+
+/// A denial from the patient's access policies or `grantee`'s daily
+/// access window, if either stands in the way.
+fn context_denial(env: &Env, patient: &Address, grantee: &Address) -> Option<AccessDecision> {
+    if !crate::rbac::evaluate_access_policies(env, grantee, None, Some(patient.clone())) {
+        return Some(AccessDecision::denied(AccessReasonCode::PolicyDenied, 0));
+    }
+    if !crate::access_window::is_open(env, patient, grantee) {
+        return Some(AccessDecision::denied(AccessReasonCode::OutsideWindow, 0));
+    }
+    None
+}
+
+/// Level of the narrowest record-level grant (see
+/// `scoped_grant::narrowest_level`) once it has passed the checks `decide`
+/// applies to patient-wide grants: an active grantee, live consent, and
+/// the patient's policies and access window. A grant that applies but
+/// fails them yields `AccessLevel::None`, still shadowing the
+/// patient-wide grant.
+pub fn record_grant_level(
+    env: &Env,
+    patient: &Address,
+    grantee: &Address,
+    record_id: Option<u64>,
+    record_type: Option<&RecordType>,
+) -> Option<AccessLevel> {
+    let level = scoped_grant::narrowest_level(env, patient, grantee, record_id, record_type)?;
+    if !is_active_user(env, grantee) || context_denial(env, patient, grantee).is_some() {
+        return Some(AccessLevel::None);
+    }
+    Some(
+        consented_level(env, patient, grantee, level)
+            .map(|(level, _)| level)
+            .unwrap_or(AccessLevel::None),
+    )
+}
+
+/// The tier at which `caller` may see `record` through their own standing.
+/// This is the single place record getters decide disclosure; cover
+/// arrangements (locum, cohort) are added by `covered_tier`.
+///
+/// The patient, the provider of record and holders of `ReadAnyRecord` or
+/// `SystemAdmin` see everything. Otherwise the highest of the patient-wide
+/// of `granted_level`, active consent (Summary) and an active emergency
+/// grant (Full inside its scope, Existence outside) applies. For
+/// Restricted records only record-level grants count, and an emergency
+/// grant discloses no more than their existence.
+pub fn record_tier(env: &Env, caller: &Address, record: &VisionRecord) -> DisclosureTier {
+    standing_tier(
+        env,
+        caller,
+        &record.patient,
+        &record.provider,
+        record.id,
+        &record.record_type,
+    )
+}
+
+/// Like `record_tier`, for a record retention has replaced with an
+/// archival summary.
+pub fn archived_tier(env: &Env, caller: &Address, summary: &ArchivalSummary) -> DisclosureTier {
+    standing_tier(
+        env,
+        caller,
+        &summary.patient,
+        &summary.provider,
+        summary.record_id,
+        &summary.record_type,
+    )
+}
+
+fn standing_tier(
+    env: &Env,
+    caller: &Address,
+    patient: &Address,
+    provider: &Address,
+    record_id: u64,
+    record_type: &RecordType,
+) -> DisclosureTier {
+    let caller = crate::alias::resolve(env, caller);
+    if caller == *patient
+        || caller == *provider
+        || rbac::has_permission(env, &caller, &Permission::ReadAnyRecord)
+        || rbac::has_permission(env, &caller, &Permission::SystemAdmin)
+        || crate::guardian::is_guardian(env, &caller, patient)
+    {
+        return DisclosureTier::Full;
+    }
+
+    let restricted = sensitivity::requires_record_grant(env, record_id);
+    let level = if restricted {
+        record_grant_level(env, patient, &caller, Some(record_id), None)
+            .unwrap_or(AccessLevel::None)
+    } else {
+        granted_level(env, patient, &caller, Some(record_id), Some(record_type))
+    };
+    let mut tier = DisclosureTier::for_level(&level);
+
+    if !restricted
+        && crate::access_window::is_open(env, patient, &caller)
+        && crate::has_active_consent(env, patient, &caller)
+    {
+        tier = tier.max(DisclosureTier::Summary);
+    }
+
+    if let Some(access) = emergency::has_active_emergency_access(env, patient, &caller) {
+        let in_scope = !restricted && emergency::scope_allows(&access, record_type);
+        tier = tier.max(if in_scope {
+            DisclosureTier::Full
+        } else {
+            DisclosureTier::Existence
+        });
+    }
+
+    tier
+}
+
+/// Like `record_tier`, but an active caller with no access of their own
+/// may also read through cover: a locum sees what the absent provider
+/// would, unless the patient opted out, and a supervisor sees what their
+/// resident authored. Cover discloses at most a summary of a Restricted
+/// record. Returns the cover used, if any, so it can be logged.
+pub fn covered_tier(
+    env: &Env,
+    caller: &Address,
+    record: &VisionRecord,
+) -> (DisclosureTier, Option<Cover>) {
+    let tier = record_tier(env, caller, record);
+    let caller = crate::alias::resolve(env, caller);
+    if tier >= DisclosureTier::Summary || !is_active_user(env, &caller) {
+        return (tier, None);
+    }
+
+    let cap = if sensitivity::requires_record_grant(env, record.id) {
+        DisclosureTier::Summary
+    } else {
+        DisclosureTier::Full
+    };
+    let now = env.ledger().timestamp();
+    if !privacy::get_settings(env, &record.patient).locum_opt_out {
+        for arrangement in locum::active_cover(env, &caller, now).iter() {
+            let inherited = record_tier(env, &arrangement.absent_provider, record);
+            if inherited >= DisclosureTier::Summary {
+                return (inherited.min(cap), Some(Cover::Locum(arrangement)));
+            }
+        }
+    }
+    if let Some(grant) = cohort::active_grant(env, &caller, &record.provider, now) {
+        return (cap, Some(Cover::Cohort(grant)));
+    }
+    (tier, None)
+}
+
+/// The level `grantee` holds on `patient`'s records through grants. The
+/// narrowest grant wins: one scoped to `record_id` or `record_type` if it
+/// applies, even when it is lower than the patient-wide grant; otherwise
+/// the patient-wide grant, then a grant to the grantee's organization.
+/// Record-level grants are held to the same checks as `decide`, and a
+/// deactivated grantee gets nothing from any of them.
+pub fn granted_level(
+    env: &Env,
+    patient: &Address,
+    grantee: &Address,
+    record_id: Option<u64>,
+    record_type: Option<&RecordType>,
+) -> AccessLevel {
+    // A deactivated user holds nothing, whichever grant would otherwise apply.
+    if !is_active_user(env, grantee) {
+        return AccessLevel::None;
+    }
+    if let Some(level) = record_grant_level(env, patient, grantee, record_id, record_type) {
+        return level;
+    }
+    match decide(env, patient, grantee).level {
+        AccessLevel::None => {
+            crate::organization::level_for(env, patient, grantee).unwrap_or(AccessLevel::None)
+        }
+        level => level,
+    }
+}
+
+/// Strips the fields above `tier` from `record`. Callers must already have
+/// rejected tiers below `Summary`.
+pub fn redact(env: &Env, mut record: VisionRecord, tier: DisclosureTier) -> VisionRecord {
+    if tier < DisclosureTier::Full {
+        record.data_hash = String::from_str(env, "");
+        record.key_version = None;
+        record.eligibility_attestation = None;
+        record.emergency_access_id = None;
+    }
+    record
+}
@@ -0,0 +1,98 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::ttl_config;
+use crate::ContractError;
+
+// ── Storage keys ──────────────────────────────────────────────
+const ACC_WINDOW: Symbol = symbol_short!("ACC_WIN");
+const TZ_OFFSET: Symbol = symbol_short!("ACC_TZ");
+
+const MINUTES_PER_DAY: u32 = 1_440;
+/// Widest real-world UTC offset (UTC+14:00), in minutes.
+const MAX_TZ_OFFSET_MINUTES: i32 = 840;
+
+/// Extends the time-to-live (TTL) for (patient, grantee) window keys.
+fn extend_ttl_pair_key(env: &Env, key: &(Symbol, Address, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Daily window during which a grant may be used, in minutes after local
+/// midnight for the deployment's configured UTC offset. A window whose end
+/// is before its start runs overnight (e.g. 22:00–06:00).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessWindow {
+    pub start_minute: u32,
+    pub end_minute: u32,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn validate_window(window: &AccessWindow) -> Result<(), ContractError> {
+    if window.start_minute >= MINUTES_PER_DAY
+        || window.end_minute > MINUTES_PER_DAY
+        || window.start_minute == window.end_minute
+    {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+pub fn validate_offset(offset_minutes: i32) -> Result<(), ContractError> {
+    if !(-MAX_TZ_OFFSET_MINUTES..=MAX_TZ_OFFSET_MINUTES).contains(&offset_minutes) {
+        return Err(ContractError::InvalidInput);
+    }
+    Ok(())
+}
+
+pub fn set_timezone_offset(env: &Env, offset_minutes: i32) {
+    env.storage().instance().set(&TZ_OFFSET, &offset_minutes);
+}
+
+/// UTC offset, in minutes, that access windows are expressed in.
+pub fn get_timezone_offset(env: &Env) -> i32 {
+    env.storage().instance().get(&TZ_OFFSET).unwrap_or(0)
+}
+
+pub fn set_window(env: &Env, patient: &Address, grantee: &Address, window: &AccessWindow) {
+    let key = (ACC_WINDOW, patient.clone(), grantee.clone());
+    env.storage().persistent().set(&key, window);
+    extend_ttl_pair_key(env, &key);
+}
+
+pub fn remove_window(env: &Env, patient: &Address, grantee: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&(ACC_WINDOW, patient.clone(), grantee.clone()));
+}
+
+pub fn get_window(env: &Env, patient: &Address, grantee: &Address) -> Option<AccessWindow> {
+    env.storage()
+        .persistent()
+        .get(&(ACC_WINDOW, patient.clone(), grantee.clone()))
+}
+
+/// Current minute of the local day for the configured offset.
+fn local_minute_of_day(env: &Env) -> u32 {
+    let offset_seconds = i64::from(get_timezone_offset(env)).saturating_mul(60);
+    let now = i64::try_from(env.ledger().timestamp()).unwrap_or(i64::MAX);
+    let local = now.saturating_add(offset_seconds).rem_euclid(86_400);
+    u32::try_from(local / 60).unwrap_or(0)
+}
+
+/// Returns false only if `patient` restricted `grantee` to a daily window
+/// and the current local time falls outside it.
+pub fn is_open(env: &Env, patient: &Address, grantee: &Address) -> bool {
+    let window = match get_window(env, patient, grantee) {
+        Some(window) => window,
+        None => return true,
+    };
+    let minute = local_minute_of_day(env);
+    if window.start_minute < window.end_minute {
+        minute >= window.start_minute && minute < window.end_minute
+    } else {
+        minute >= window.start_minute || minute < window.end_minute
+    }
+}
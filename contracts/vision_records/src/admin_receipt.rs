@@ -0,0 +1,150 @@
+use soroban_sdk::{
+    contracttype, symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Vec,
+};
+use teye_common::audit_stream;
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const ADM_RCPT: Symbol = symbol_short!("ADM_RCPT");
+const ADM_RUSR: Symbol = symbol_short!("ADM_RUSR");
+
+/// Receipts kept per affected user; older ones remain readable by sequence.
+pub const MAX_RECEIPTS_PER_USER: u32 = 100;
+
+/// Extends the time-to-live (TTL) for receipt keys.
+fn extend_ttl_receipt_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-user receipt index keys.
+fn extend_ttl_user_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Proof that an admin performed `action` on `target` at a given ledger.
+///
+/// `seq` is the entry's position in the global audit stream. `receipt_hash`
+/// commits to this contract's address and every other field, and the admin
+/// authorised the transaction that produced it, so a patient holding a
+/// receipt can check it with `verify_admin_receipt` long after the event
+/// that announced it has aged out.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminActionReceipt {
+    pub seq: u64,
+    pub admin: Address,
+    pub action: Symbol,
+    pub target: Option<Address>,
+    pub ledger_sequence: u32,
+    pub timestamp: u64,
+    pub receipt_hash: BytesN<32>,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+fn receipt_hash(
+    env: &Env,
+    seq: u64,
+    admin: &Address,
+    action: &Symbol,
+    target: &Option<Address>,
+    ledger_sequence: u32,
+    timestamp: u64,
+) -> BytesN<32> {
+    let mut payload = Bytes::new(env);
+    payload.append(&Bytes::from_slice(env, b"ADM_RCPT"));
+    payload.append(&env.current_contract_address().to_xdr(env));
+    payload.append(&Bytes::from_slice(env, &seq.to_be_bytes()));
+    payload.append(&admin.clone().to_xdr(env));
+    payload.append(&action.clone().to_xdr(env));
+    payload.append(&target.clone().to_xdr(env));
+    payload.append(&Bytes::from_slice(env, &ledger_sequence.to_be_bytes()));
+    payload.append(&Bytes::from_slice(env, &timestamp.to_be_bytes()));
+    env.crypto().sha256(&payload).into()
+}
+
+/// Appends `action` to the audit stream and stores a receipt for it,
+/// indexed under `target` when the action touched a specific user.
+pub fn issue(
+    env: &Env,
+    admin: &Address,
+    action: Symbol,
+    target: Option<Address>,
+) -> AdminActionReceipt {
+    let seq = audit_stream::append(env, admin, action.clone(), target.clone());
+    let ledger_sequence = env.ledger().sequence();
+    let timestamp = env.ledger().timestamp();
+    let receipt = AdminActionReceipt {
+        seq,
+        admin: admin.clone(),
+        receipt_hash: receipt_hash(
+            env,
+            seq,
+            admin,
+            &action,
+            &target,
+            ledger_sequence,
+            timestamp,
+        ),
+        action,
+        target: target.clone(),
+        ledger_sequence,
+        timestamp,
+    };
+
+    let key = (ADM_RCPT, seq);
+    env.storage().persistent().set(&key, &receipt);
+    extend_ttl_receipt_key(env, &key);
+
+    if let Some(user) = target {
+        let key = (ADM_RUSR, user.clone());
+        let mut seqs = get_user_receipt_seqs(env, &user);
+        seqs.push_back(seq);
+        while seqs.len() > MAX_RECEIPTS_PER_USER {
+            seqs.pop_front();
+        }
+        env.storage().persistent().set(&key, &seqs);
+        extend_ttl_user_key(env, &key);
+    }
+
+    receipt
+}
+
+pub fn get_receipt(env: &Env, seq: u64) -> Option<AdminActionReceipt> {
+    env.storage().persistent().get(&(ADM_RCPT, seq))
+}
+
+pub fn get_user_receipt_seqs(env: &Env, user: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(ADM_RUSR, user.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Receipts for admin actions that targeted `user`, oldest first.
+pub fn get_user_receipts(env: &Env, user: &Address) -> Vec<AdminActionReceipt> {
+    let mut receipts = Vec::new(env);
+    for seq in get_user_receipt_seqs(env, user).iter() {
+        if let Some(receipt) = get_receipt(env, seq) {
+            receipts.push_back(receipt);
+        }
+    }
+    receipts
+}
+
+/// Returns true if `receipt` was issued by this contract and is unaltered.
+pub fn verify(env: &Env, receipt: &AdminActionReceipt) -> bool {
+    let expected = receipt_hash(
+        env,
+        receipt.seq,
+        &receipt.admin,
+        &receipt.action,
+        &receipt.target,
+        receipt.ledger_sequence,
+        receipt.timestamp,
+    );
+    expected == receipt.receipt_hash && get_receipt(env, receipt.seq).as_ref() == Some(receipt)
+}
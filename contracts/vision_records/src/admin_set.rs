@@ -0,0 +1,87 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const ADM_SET: Symbol = symbol_short!("ADM_SET");
+const ADM_PERM: Symbol = symbol_short!("ADM_PERM");
+
+/// Most addresses the admin set may hold
+pub const MAX_ADMINS: u32 = 10;
+
+/// Extends the time-to-live (TTL) for per-admin permission keys.
+fn extend_ttl_admin_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// What a secondary admin may do. The primary admin holds all of them.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AdminPermission {
+    /// Change user roles and (de)activate users
+    UserManagement,
+    /// Revoke emergency grants and curate the responder directory
+    EmergencyOversight,
+    /// Multisig configuration and encryption key rotation
+    Upgrade,
+    /// Resolve concurrent-edit conflicts on any record
+    RecordOversight,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Secondary admins, in the order they were added
+pub fn list(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&ADM_SET)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn permissions(env: &Env, admin: &Address) -> Vec<AdminPermission> {
+    env.storage()
+        .persistent()
+        .get(&(ADM_PERM, admin.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn is_member(env: &Env, admin: &Address) -> bool {
+    list(env).contains(admin)
+}
+
+pub fn has_permission(env: &Env, admin: &Address, permission: AdminPermission) -> bool {
+    permissions(env, admin).contains(permission)
+}
+
+/// Adds `admin` or replaces their permissions. Returns false if the set is
+/// full and `admin` is not already in it.
+pub fn set(env: &Env, admin: &Address, permissions: &Vec<AdminPermission>) -> bool {
+    let mut admins = list(env);
+    if !admins.contains(admin) {
+        if admins.len() >= MAX_ADMINS {
+            return false;
+        }
+        admins.push_back(admin.clone());
+        env.storage().instance().set(&ADM_SET, &admins);
+    }
+    let key = (ADM_PERM, admin.clone());
+    env.storage().persistent().set(&key, permissions);
+    extend_ttl_admin_key(env, &key);
+    true
+}
+
+/// Removes `admin`. Returns false if they were not in the set.
+pub fn remove(env: &Env, admin: &Address) -> bool {
+    let mut admins = list(env);
+    let Some(i) = admins.first_index_of(admin) else {
+        return false;
+    };
+    admins.remove(i);
+    env.storage().instance().set(&ADM_SET, &admins);
+    env.storage()
+        .persistent()
+        .remove(&(ADM_PERM, admin.clone()));
+    true
+}
@@ -0,0 +1,37 @@
+use soroban_sdk::{symbol_short, Env, Symbol};
+
+// ── Storage keys ──────────────────────────────────────────────
+const ADM_DLY: Symbol = symbol_short!("ADM_DLY");
+const ADM_UNLK: Symbol = symbol_short!("ADM_UNLK");
+
+/// Longest delay the admin may put between proposing and accepting a
+/// transfer: 30 days.
+pub const MAX_ADMIN_TRANSFER_DELAY: u64 = 2_592_000;
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Seconds a proposed admin must wait before `accept_admin` succeeds.
+/// Zero (the default) lets the transfer be accepted immediately.
+pub fn get_delay(env: &Env) -> u64 {
+    env.storage().instance().get(&ADM_DLY).unwrap_or(0)
+}
+
+pub fn set_delay(env: &Env, seconds: u64) {
+    env.storage().instance().set(&ADM_DLY, &seconds);
+}
+
+/// Earliest time the pending transfer may be accepted, if one is pending.
+pub fn unlock_at(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&ADM_UNLK)
+}
+
+/// Starts the delay for a newly proposed transfer and returns its unlock time.
+pub fn start(env: &Env) -> u64 {
+    let unlock = env.ledger().timestamp().saturating_add(get_delay(env));
+    env.storage().instance().set(&ADM_UNLK, &unlock);
+    unlock
+}
+
+pub fn clear(env: &Env) {
+    env.storage().instance().remove(&ADM_UNLK);
+}
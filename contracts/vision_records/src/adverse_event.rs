@@ -0,0 +1,146 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+pub const AE_CTR: Symbol = symbol_short!("AE_CTR");
+const AE_REPORT: Symbol = symbol_short!("AE_REP");
+const AE_PENDING: Symbol = symbol_short!("AE_PEND");
+const AE_COUNTS: Symbol = symbol_short!("AE_CNT");
+
+/// Extends the time-to-live (TTL) for adverse event report keys.
+fn extend_ttl_report_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-product aggregate keys.
+fn extend_ttl_product_key(env: &Env, key: &(Symbol, String)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Clinical severity of an adverse event
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdverseEventSeverity {
+    Mild,
+    Moderate,
+    Severe,
+    LifeThreatening,
+}
+
+/// Regulator-side processing state of a report
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdverseEventStatus {
+    Submitted,
+    Acknowledged,
+}
+
+/// An adverse event report tied to a dispensed product or procedure record
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct AdverseEventReport {
+    pub id: u64,
+    pub reporter: Address,
+    pub patient: Address,
+    pub record_id: u64,
+    /// Product identifier (e.g. NDC or lens SKU) or procedure code
+    pub product_id: String,
+    pub severity: AdverseEventSeverity,
+    /// Hash of the off-chain narrative
+    pub description_hash: String,
+    pub reported_at: u64,
+    pub status: AdverseEventStatus,
+    pub acknowledged_by: Option<Address>,
+    pub acknowledged_at: Option<u64>,
+}
+
+/// De-identified aggregate counts for a product
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdverseEventCounts {
+    pub product_id: String,
+    pub total: u32,
+    pub mild: u32,
+    pub moderate: u32,
+    pub severe: u32,
+    pub life_threatening: u32,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Increments and returns the next report ID
+pub fn increment_report_counter(env: &Env) -> u64 {
+    let current: u64 = env.storage().instance().get(&AE_CTR).unwrap_or(0);
+    let next = current.saturating_add(1);
+    env.storage().instance().set(&AE_CTR, &next);
+    next
+}
+
+pub fn set_report(env: &Env, report: &AdverseEventReport) {
+    let key = (AE_REPORT, report.id);
+    env.storage().persistent().set(&key, report);
+    extend_ttl_report_key(env, &key);
+}
+
+pub fn get_report(env: &Env, report_id: u64) -> Option<AdverseEventReport> {
+    env.storage().persistent().get(&(AE_REPORT, report_id))
+}
+
+/// Returns ids of reports awaiting regulator acknowledgement
+pub fn get_pending(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&AE_PENDING)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn add_pending(env: &Env, report_id: u64) {
+    let mut pending = get_pending(env);
+    pending.push_back(report_id);
+    env.storage().persistent().set(&AE_PENDING, &pending);
+}
+
+pub fn remove_pending(env: &Env, report_id: u64) {
+    let pending = get_pending(env);
+    let mut next = Vec::new(env);
+    for id in pending.iter() {
+        if id != report_id {
+            next.push_back(id);
+        }
+    }
+    env.storage().persistent().set(&AE_PENDING, &next);
+}
+
+pub fn get_counts(env: &Env, product_id: &String) -> AdverseEventCounts {
+    env.storage()
+        .persistent()
+        .get(&(AE_COUNTS, product_id.clone()))
+        .unwrap_or(AdverseEventCounts {
+            product_id: product_id.clone(),
+            total: 0,
+            mild: 0,
+            moderate: 0,
+            severe: 0,
+            life_threatening: 0,
+        })
+}
+
+/// Folds a new report into the product's aggregate counts
+pub fn record_count(env: &Env, product_id: &String, severity: &AdverseEventSeverity) {
+    let mut counts = get_counts(env, product_id);
+    counts.total = counts.total.saturating_add(1);
+    match severity {
+        AdverseEventSeverity::Mild => counts.mild = counts.mild.saturating_add(1),
+        AdverseEventSeverity::Moderate => counts.moderate = counts.moderate.saturating_add(1),
+        AdverseEventSeverity::Severe => counts.severe = counts.severe.saturating_add(1),
+        AdverseEventSeverity::LifeThreatening => {
+            counts.life_threatening = counts.life_threatening.saturating_add(1)
+        }
+    }
+    let key = (AE_COUNTS, product_id.clone());
+    env.storage().persistent().set(&key, &counts);
+    extend_ttl_product_key(env, &key);
+}
@@ -0,0 +1,111 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const AGR_CTR: Symbol = symbol_short!("AGR_CTR");
+const AGR: Symbol = symbol_short!("AGR");
+const AGR_GRT: Symbol = symbol_short!("AGR_GRT");
+const AGR_PAT: Symbol = symbol_short!("AGR_PAT");
+
+/// Extends the time-to-live (TTL) for per-agreement keys.
+fn extend_ttl_agreement_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-patient agreement indexes.
+fn extend_ttl_patient_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AgreementStatus {
+    /// Signed by the patient, awaiting the counterparty
+    Proposed,
+    /// Signed by both parties; grants may be issued under it
+    Active,
+    Terminated,
+}
+
+/// Data-sharing agreement between a patient and a counterparty such as an
+/// insurer or research group.
+///
+/// The legal terms live off-chain; both parties sign `terms_hash` through
+/// their own authorization, which is what makes the agreement binding.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DataSharingAgreement {
+    pub id: u64,
+    pub patient: Address,
+    pub counterparty: Address,
+    pub terms_hash: BytesN<32>,
+    pub status: AgreementStatus,
+    pub proposed_at: u64,
+    pub signed_at: Option<u64>,
+    pub terminated_at: Option<u64>,
+    pub terminated_by: Option<Address>,
+}
+
+/// A grant issued under an agreement. `record_id` is `None` for a
+/// patient-wide grant.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgreementGrant {
+    pub record_id: Option<u64>,
+    pub granted_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Increments and returns the next agreement ID
+pub fn increment_counter(env: &Env) -> u64 {
+    let current: u64 = env.storage().instance().get(&AGR_CTR).unwrap_or(0);
+    let next = current.saturating_add(1);
+    env.storage().instance().set(&AGR_CTR, &next);
+    next
+}
+
+pub fn set(env: &Env, agreement: &DataSharingAgreement) {
+    let key = (AGR, agreement.id);
+    env.storage().persistent().set(&key, agreement);
+    extend_ttl_agreement_key(env, &key);
+}
+
+pub fn get(env: &Env, id: u64) -> Option<DataSharingAgreement> {
+    env.storage().persistent().get(&(AGR, id))
+}
+
+/// Stores a new agreement and indexes it under the patient.
+pub fn create(env: &Env, agreement: &DataSharingAgreement) {
+    set(env, agreement);
+    let key = (AGR_PAT, agreement.patient.clone());
+    let mut ids = get_patient_ids(env, &agreement.patient);
+    ids.push_back(agreement.id);
+    env.storage().persistent().set(&key, &ids);
+    extend_ttl_patient_key(env, &key);
+}
+
+pub fn get_patient_ids(env: &Env, patient: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(AGR_PAT, patient.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn add_grant(env: &Env, agreement_id: u64, grant: &AgreementGrant) {
+    let key = (AGR_GRT, agreement_id);
+    let mut grants = get_grants(env, agreement_id);
+    grants.push_back(grant.clone());
+    env.storage().persistent().set(&key, &grants);
+    extend_ttl_agreement_key(env, &key);
+}
+
+pub fn get_grants(env: &Env, agreement_id: u64) -> Vec<AgreementGrant> {
+    env.storage()
+        .persistent()
+        .get(&(AGR_GRT, agreement_id))
+        .unwrap_or(Vec::new(env))
+}
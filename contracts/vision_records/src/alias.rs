@@ -0,0 +1,62 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const ALIAS: Symbol = symbol_short!("ALIAS");
+const ALIASES: Symbol = symbol_short!("ALIASES");
+
+/// Extends the time-to-live (TTL) for alias keys.
+fn extend_ttl_alias_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Returns the address `addr` now resolves to, or `addr` itself if it has
+/// never been retired. Aliases are always stored one hop from their
+/// current address, so a single lookup suffices.
+pub fn resolve(env: &Env, addr: &Address) -> Address {
+    env.storage()
+        .persistent()
+        .get(&(ALIAS, addr.clone()))
+        .unwrap_or(addr.clone())
+}
+
+pub fn is_alias(env: &Env, addr: &Address) -> bool {
+    env.storage().persistent().has(&(ALIAS, addr.clone()))
+}
+
+/// Retired addresses that resolve to `current`
+pub fn get_aliases(env: &Env, current: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&(ALIASES, current.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn point(env: &Env, old: &Address, current: &Address) {
+    let key = (ALIAS, old.clone());
+    env.storage().persistent().set(&key, current);
+    extend_ttl_alias_key(env, &key);
+}
+
+/// Records that `old` has been replaced by `current`. Anything that already
+/// resolved to `old` is re-pointed at `current`.
+pub fn set_alias(env: &Env, old: &Address, current: &Address) {
+    let mut aliases = get_aliases(env, current);
+
+    point(env, old, current);
+    aliases.push_back(old.clone());
+
+    let inherited_key = (ALIASES, old.clone());
+    for inherited in get_aliases(env, old).iter() {
+        point(env, &inherited, current);
+        aliases.push_back(inherited);
+    }
+    env.storage().persistent().remove(&inherited_key);
+
+    let key = (ALIASES, current.clone());
+    env.storage().persistent().set(&key, &aliases);
+    extend_ttl_alias_key(env, &key);
+}
@@ -0,0 +1,72 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const REC_AMND: Symbol = symbol_short!("REC_AMND");
+
+/// Extends the time-to-live (TTL) for amendment history keys.
+fn extend_ttl_history_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// One correction to a record. The record itself always carries the
+/// latest hash; each amendment keeps the hash it replaced, in the same
+/// stored (encrypted) form and with the key version needed to read it.
+///
+/// `version` is the record version the amendment produced: the original
+/// record is version 0, so the first amendment is version 1.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordAmendment {
+    pub record_id: u64,
+    pub version: u32,
+    pub previous_data_hash: String,
+    pub previous_key_version: Option<String>,
+    pub amended_by: Address,
+    pub reason: String,
+    pub amended_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Amendments to `record_id`, oldest first
+pub fn get_history(env: &Env, record_id: u64) -> Vec<RecordAmendment> {
+    env.storage()
+        .persistent()
+        .get(&(REC_AMND, record_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Appends an amendment to the record's history and returns it. Entries
+/// are never rewritten, and only removed when the patient's data is erased.
+pub fn append(
+    env: &Env,
+    record_id: u64,
+    previous_data_hash: String,
+    previous_key_version: Option<String>,
+    amended_by: Address,
+    reason: String,
+) -> RecordAmendment {
+    let mut history = get_history(env, record_id);
+    let amendment = RecordAmendment {
+        record_id,
+        version: history.len().saturating_add(1),
+        previous_data_hash,
+        previous_key_version,
+        amended_by,
+        reason,
+        amended_at: env.ledger().timestamp(),
+    };
+    history.push_back(amendment.clone());
+    let key = (REC_AMND, record_id);
+    env.storage().persistent().set(&key, &history);
+    extend_ttl_history_key(env, &key);
+    amendment
+}
+
+pub fn remove_history(env: &Env, record_id: u64) {
+    env.storage().persistent().remove(&(REC_AMND, record_id));
+}
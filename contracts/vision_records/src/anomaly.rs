@@ -0,0 +1,182 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const ANOM_RULE: Symbol = symbol_short!("ANOM_RULE");
+const ANOM_WIN: Symbol = symbol_short!("ANOM_WIN");
+const ANOM_FLAG: Symbol = symbol_short!("ANOM_FLAG");
+
+/// Longest counting window a rule may use (7 days).
+pub const MAX_WINDOW_SECONDS: u64 = 604_800;
+
+/// Extends the time-to-live (TTL) for per-actor activity windows.
+fn extend_ttl_window_key(env: &Env, key: &(Symbol, AnomalyKind, Address)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for actor flag keys.
+fn extend_ttl_flag_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Patterns of activity that can trip a security flag.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AnomalyKind {
+    /// Records read by a grantee (anyone other than the patient or the
+    /// record's provider).
+    RecordReads,
+    /// Distinct patients a requester opened emergency access against.
+    EmergencyRequests,
+}
+
+/// Admin-configured threshold for one kind of activity.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnomalyRule {
+    pub kind: AnomalyKind,
+    /// The flag is raised once the count in a window exceeds this value.
+    pub threshold: u32,
+    pub window_seconds: u64,
+    pub updated_by: Address,
+    pub updated_at: u64,
+}
+
+/// Activity counted for one actor in the current window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivityWindow {
+    pub window_start: u64,
+    pub count: u32,
+    /// Distinct patients seen in the window; only tracked for
+    /// [`AnomalyKind::EmergencyRequests`].
+    pub patients: Vec<Address>,
+}
+
+/// Reviewable flag set on an actor when a rule trips.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActorFlag {
+    pub actor: Address,
+    pub kind: AnomalyKind,
+    pub count: u32,
+    pub threshold: u32,
+    pub flagged_at: u64,
+    pub reviewed: bool,
+    pub reviewed_by: Option<Address>,
+    pub reviewed_at: Option<u64>,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn validate_rule(threshold: u32, window_seconds: u64) -> bool {
+    threshold > 0 && window_seconds > 0 && window_seconds <= MAX_WINDOW_SECONDS
+}
+
+pub fn set_rule(env: &Env, rule: &AnomalyRule) {
+    env.storage()
+        .instance()
+        .set(&(ANOM_RULE, rule.kind.clone()), rule);
+}
+
+pub fn remove_rule(env: &Env, kind: &AnomalyKind) {
+    env.storage().instance().remove(&(ANOM_RULE, kind.clone()));
+}
+
+pub fn get_rule(env: &Env, kind: &AnomalyKind) -> Option<AnomalyRule> {
+    env.storage().instance().get(&(ANOM_RULE, kind.clone()))
+}
+
+pub fn get_window(env: &Env, kind: &AnomalyKind, actor: &Address) -> Option<ActivityWindow> {
+    env.storage()
+        .persistent()
+        .get(&(ANOM_WIN, kind.clone(), actor.clone()))
+}
+
+pub fn get_flag(env: &Env, actor: &Address) -> Option<ActorFlag> {
+    env.storage().persistent().get(&(ANOM_FLAG, actor.clone()))
+}
+
+fn set_flag(env: &Env, flag: &ActorFlag) {
+    let key = (ANOM_FLAG, flag.actor.clone());
+    env.storage().persistent().set(&key, flag);
+    extend_ttl_flag_key(env, &key);
+}
+
+/// Returns true if `actor` carries a flag that has not been reviewed yet.
+pub fn is_flagged(env: &Env, actor: &Address) -> bool {
+    get_flag(env, actor).map(|f| !f.reviewed).unwrap_or(false)
+}
+
+/// Counts one occurrence of `kind` by `actor` against `patient`.
+///
+/// Does nothing when no rule is configured for `kind`. Returns the new flag
+/// when this occurrence takes the window over the rule's threshold and the
+/// actor is not already carrying an unreviewed flag.
+pub fn record_activity(
+    env: &Env,
+    kind: AnomalyKind,
+    actor: &Address,
+    patient: &Address,
+) -> Option<ActorFlag> {
+    let rule = get_rule(env, &kind)?;
+    let now = env.ledger().timestamp();
+
+    let key = (ANOM_WIN, kind.clone(), actor.clone());
+    let mut window = match env.storage().persistent().get::<_, ActivityWindow>(&key) {
+        Some(w) if now < w.window_start.saturating_add(rule.window_seconds) => w,
+        _ => ActivityWindow {
+            window_start: now,
+            count: 0,
+            patients: Vec::new(env),
+        },
+    };
+
+    match kind {
+        AnomalyKind::RecordReads => window.count = window.count.saturating_add(1),
+        AnomalyKind::EmergencyRequests => {
+            if !window.patients.contains(patient) {
+                window.patients.push_back(patient.clone());
+                window.count = window.patients.len();
+            }
+        }
+    }
+    env.storage().persistent().set(&key, &window);
+    extend_ttl_window_key(env, &key);
+
+    if window.count <= rule.threshold || is_flagged(env, actor) {
+        return None;
+    }
+
+    let flag = ActorFlag {
+        actor: actor.clone(),
+        kind,
+        count: window.count,
+        threshold: rule.threshold,
+        flagged_at: now,
+        reviewed: false,
+        reviewed_by: None,
+        reviewed_at: None,
+    };
+    set_flag(env, &flag);
+    Some(flag)
+}
+
+/// Marks the actor's flag as reviewed and restarts their activity windows.
+pub fn review_flag(env: &Env, actor: &Address, reviewer: &Address) -> Option<ActorFlag> {
+    let mut flag = get_flag(env, actor)?;
+    flag.reviewed = true;
+    flag.reviewed_by = Some(reviewer.clone());
+    flag.reviewed_at = Some(env.ledger().timestamp());
+    set_flag(env, &flag);
+
+    for kind in [AnomalyKind::RecordReads, AnomalyKind::EmergencyRequests] {
+        env.storage()
+            .persistent()
+            .remove(&(ANOM_WIN, kind, actor.clone()));
+    }
+    Some(flag)
+}
@@ -1,5 +1,7 @@
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
 
+use crate::ttl_config;
+
 // ── Storage keys ──────────────────────────────────────────────
 pub const APPT_CTR: Symbol = symbol_short!("APPT_CTR");
 const APPT_RECORD: Symbol = symbol_short!("APPT_REC");
@@ -7,28 +9,19 @@ const APPT_PATIENT: Symbol = symbol_short!("APPT_PAT");
 const APPT_PROVIDER: Symbol = symbol_short!("APPT_PROV");
 const APPT_HISTORY: Symbol = symbol_short!("APPT_HIST");
 
-const TTL_THRESHOLD: u32 = 5184000;
-const TTL_EXTEND_TO: u32 = 10368000;
-
 /// Extends the time-to-live (TTL) for appointment storage keys.
 fn extend_ttl_appointment_key(env: &Env, key: &(Symbol, u64)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 /// Extends the time-to-live (TTL) for appointment by patient keys.
 fn extend_ttl_appointment_patient_key(env: &Env, key: &(Symbol, Address, u64)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 /// Extends the time-to-live (TTL) for appointment by provider keys.
 fn extend_ttl_appointment_provider_key(env: &Env, key: &(Symbol, Address, u64)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 // ── Types ─────────────────────────────────────────────────────
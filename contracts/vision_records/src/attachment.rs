@@ -0,0 +1,83 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const REC_ATT: Symbol = symbol_short!("REC_ATT");
+
+/// Most attachments a single record may carry
+pub const MAX_ATTACHMENTS_PER_RECORD: u32 = 16;
+
+/// Extends the time-to-live (TTL) for per-record attachment keys.
+fn extend_ttl_record_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// The kind of artifact an attachment's hash points at
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AttachmentKind {
+    OctScan,
+    FundusPhoto,
+    VisualField,
+    Topography,
+    Other,
+}
+
+/// An additional off-chain artifact belonging to a record, alongside the
+/// record's own `data_hash`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordAttachment {
+    pub record_id: u64,
+    /// Position among the record's attachments, starting at 0
+    pub index: u32,
+    pub kind: AttachmentKind,
+    pub hash: String,
+    pub added_by: Address,
+    pub added_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get_all(env: &Env, record_id: u64) -> Vec<RecordAttachment> {
+    env.storage()
+        .persistent()
+        .get(&(REC_ATT, record_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Appends an attachment to `record_id`. Returns `None` if the record
+/// already has [`MAX_ATTACHMENTS_PER_RECORD`] attachments.
+pub fn add(
+    env: &Env,
+    record_id: u64,
+    kind: AttachmentKind,
+    hash: &String,
+    added_by: &Address,
+) -> Option<RecordAttachment> {
+    let mut attachments = get_all(env, record_id);
+    if attachments.len() >= MAX_ATTACHMENTS_PER_RECORD {
+        return None;
+    }
+    let attachment = RecordAttachment {
+        record_id,
+        index: attachments.len(),
+        kind,
+        hash: hash.clone(),
+        added_by: added_by.clone(),
+        added_at: env.ledger().timestamp(),
+    };
+    attachments.push_back(attachment.clone());
+
+    let key = (REC_ATT, record_id);
+    env.storage().persistent().set(&key, &attachments);
+    extend_ttl_record_key(env, &key);
+    Some(attachment)
+}
+
+pub fn remove_all(env: &Env, record_id: u64) {
+    env.storage().persistent().remove(&(REC_ATT, record_id));
+}
@@ -4,6 +4,8 @@ use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symb
 use audit::types::LogSegmentId;
 use audit::merkle_log::hash_leaf;
 
+use crate::ttl_config;
+
 const AUDIT_LATEST_HASH: Symbol = symbol_short!("AUD_HASH");
 const AUDIT_SEQUENCE: Symbol = symbol_short!("AUD_SEQ");
 
@@ -94,35 +96,24 @@ const AUDIT_RECORD: Symbol = symbol_short!("AUD_REC");
 const AUDIT_USER: Symbol = symbol_short!("AUD_USR");
 const AUDIT_PATIENT: Symbol = symbol_short!("AUD_PAT");
 
-const TTL_THRESHOLD: u32 = 5184000;
-const TTL_EXTEND_TO: u32 = 10368000;
-
 /// Extends the time-to-live (TTL) for audit entry storage keys.
 fn extend_ttl_audit_key(env: &Env, key: &(Symbol, u64)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 /// Extends the time-to-live (TTL) for audit by record keys.
 fn extend_ttl_audit_record_key(env: &Env, key: &(Symbol, u64, u64)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 /// Extends the time-to-live (TTL) for audit by user keys.
 fn extend_ttl_audit_user_key(env: &Env, key: &(Symbol, Address, u64)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 /// Extends the time-to-live (TTL) for audit by patient keys.
 fn extend_ttl_audit_patient_key(env: &Env, key: &(Symbol, Address, u64)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 // ── Types ─────────────────────────────────────────────────────
@@ -0,0 +1,89 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::locum::{self, LocumArrangement};
+use crate::privacy;
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const AVAIL: Symbol = symbol_short!("AVAIL");
+
+/// Extends the time-to-live (TTL) for provider availability keys.
+fn extend_ttl_availability_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AvailabilityStatus {
+    Available,
+    OutOfOffice,
+}
+
+/// A provider's declared availability. Out-of-office lapses on its own
+/// once `until` passes; `until` is 0 while available.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProviderAvailability {
+    pub provider: Address,
+    pub status: AvailabilityStatus,
+    pub until: u64,
+    pub updated_at: u64,
+}
+
+impl ProviderAvailability {
+    pub fn is_away(&self, now: u64) -> bool {
+        self.status == AvailabilityStatus::OutOfOffice && now < self.until
+    }
+}
+
+/// Where work addressed to a provider should go right now
+pub enum Routing {
+    Direct,
+    /// Hand over to the covering provider of an active locum arrangement
+    Cover(LocumArrangement),
+    /// Hold for the provider until the given timestamp
+    Queued(u64),
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get(env: &Env, provider: &Address) -> ProviderAvailability {
+    env.storage()
+        .persistent()
+        .get(&(AVAIL, provider.clone()))
+        .unwrap_or(ProviderAvailability {
+            provider: provider.clone(),
+            status: AvailabilityStatus::Available,
+            until: 0,
+            updated_at: 0,
+        })
+}
+
+pub fn set(env: &Env, availability: &ProviderAvailability) {
+    let key = (AVAIL, availability.provider.clone());
+    env.storage().persistent().set(&key, availability);
+    extend_ttl_availability_key(env, &key);
+}
+
+/// Routes work for `patient` addressed to `provider`.
+///
+/// An away provider's active locum cover takes it unless the patient has
+/// opted out of locum access, in which case it waits for the provider.
+pub fn route(env: &Env, provider: &Address, patient: &Address, now: u64) -> Routing {
+    let availability = get(env, provider);
+    if !availability.is_away(now) {
+        return Routing::Direct;
+    }
+    if !privacy::get_settings(env, patient).locum_opt_out {
+        for id in locum::get_absent_arrangements(env, provider).iter() {
+            if let Some(arrangement) = locum::get_arrangement(env, id) {
+                if arrangement.is_active(now) {
+                    return Routing::Cover(arrangement);
+                }
+            }
+        }
+    }
+    Routing::Queued(availability.until)
+}
@@ -0,0 +1,73 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const CARE_REL: Symbol = symbol_short!("CARE_REL");
+const CARE_TM: Symbol = symbol_short!("CARE_TM");
+
+/// Extends the time-to-live (TTL) for (patient, provider) relationship keys.
+fn extend_ttl_pair_key(env: &Env, key: &(Symbol, Address, Address)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-patient care team index keys.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A provider's standing place on a patient's care team. Ended
+/// relationships are kept, with `ended_at` set, until re-established.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CareRelationship {
+    pub patient: Address,
+    pub provider: Address,
+    pub established_at: u64,
+    pub ended_at: Option<u64>,
+}
+
+impl CareRelationship {
+    pub fn is_active(&self) -> bool {
+        self.ended_at.is_none()
+    }
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get(env: &Env, patient: &Address, provider: &Address) -> Option<CareRelationship> {
+    env.storage()
+        .persistent()
+        .get(&(CARE_REL, patient.clone(), provider.clone()))
+}
+
+pub fn is_active(env: &Env, patient: &Address, provider: &Address) -> bool {
+    get(env, patient, provider).is_some_and(|rel| rel.is_active())
+}
+
+/// Stores `rel`, adding its provider to the patient's care team index the
+/// first time the pair is seen.
+pub fn set(env: &Env, rel: &CareRelationship) {
+    let key = (CARE_REL, rel.patient.clone(), rel.provider.clone());
+    env.storage().persistent().set(&key, rel);
+    extend_ttl_pair_key(env, &key);
+
+    let mut providers = providers(env, &rel.patient);
+    if !providers.contains(&rel.provider) {
+        providers.push_back(rel.provider.clone());
+        let index_key = (CARE_TM, rel.patient.clone());
+        env.storage().persistent().set(&index_key, &providers);
+        extend_ttl_address_key(env, &index_key);
+    }
+}
+
+/// Every provider the patient has had a care relationship with, active or
+/// ended, in the order they were first added.
+pub fn providers(env: &Env, patient: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&(CARE_TM, patient.clone()))
+        .unwrap_or(Vec::new(env))
+}
@@ -29,6 +29,14 @@ pub fn function_pause_key(func: &Symbol) -> (Symbol, Symbol) {
     (symbol_short!("P_FUNC"), func.clone())
 }
 
+pub fn degraded_mode_key() -> Symbol {
+    symbol_short!("P_DEGR")
+}
+
+/// Function scopes that stay open in degraded mode: the emergency request
+/// path. Reads and grant checks never pass through the breaker.
+pub const DEGRADED_ALLOWED: [Symbol; 1] = [symbol_short!("EMRG_GRT")];
+
 // ── Core Logistics ───────────────────────────────────────────
 
 /// Asserts the specified scope is currently active and not halted. Automatically evaluates Global halts simultaneously.
@@ -43,7 +51,18 @@ pub fn require_not_paused(env: &Env, scope: &PauseScope) -> Result<(), ContractE
         return Err(ContractError::Paused);
     }
 
-    // 2. Check Specific Scope
+    // 2. In degraded mode only the clinically critical scopes get through
+    if is_degraded(env) {
+        let allowed = match scope {
+            PauseScope::Function(func_name) => DEGRADED_ALLOWED.contains(func_name),
+            PauseScope::Global => false,
+        };
+        if !allowed {
+            return Err(ContractError::Paused);
+        }
+    }
+
+    // 3. Check Specific Scope
     if let PauseScope::Function(func_name) = scope {
         if env
             .storage()
@@ -111,3 +130,33 @@ pub fn resume_contract(
 
     Ok(())
 }
+
+pub fn is_globally_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&global_pause_key())
+        .unwrap_or(false)
+}
+
+pub fn is_degraded(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&degraded_mode_key())
+        .unwrap_or(false)
+}
+
+/// Switches degraded mode on or off. While on, every mutation guarded by
+/// the breaker fails with `Paused` except those in [`DEGRADED_ALLOWED`].
+/// Requires at least `OperatorAdmin` tier, or the existing SystemAdmin RBAC permission.
+pub fn set_degraded(env: &Env, caller: &Address, enabled: bool) -> Result<(), ContractError> {
+    let has_tier = admin_tiers::require_tier(env, caller, &AdminTier::OperatorAdmin);
+    let has_rbac = rbac::has_permission(env, caller, &Permission::SystemAdmin);
+    if !has_tier && !has_rbac {
+        return Err(ContractError::Unauthorized);
+    }
+
+    env.storage().instance().set(&degraded_mode_key(), &enabled);
+    events::publish_degraded_mode(env, caller.clone(), enabled);
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const COH_CTR: Symbol = symbol_short!("COH_CTR");
+const COH_GRT: Symbol = symbol_short!("COH_GRT");
+const COH_PAIR: Symbol = symbol_short!("COH_PAIR");
+const COH_SUP: Symbol = symbol_short!("COH_SUP");
+const COH_DISC: Symbol = symbol_short!("COH_DISC");
+
+/// Most residents a single cohort grant call may list.
+pub const MAX_COHORT_RESIDENTS: u32 = 50;
+
+/// Extends the time-to-live (TTL) for cohort grant keys.
+fn extend_ttl_grant_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for supervisor/resident pair keys.
+fn extend_ttl_pair_key(env: &Env, key: &(Symbol, Address, Address)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-address cohort index keys.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Read access for a supervisor to every record one resident authored.
+///
+/// Unlike a patient grant this follows the record's provider, not the
+/// patient, so it covers the resident's whole caseload and nothing else.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CohortGrant {
+    pub id: u64,
+    pub supervisor: Address,
+    pub resident: Address,
+    pub granted_by: Address,
+    pub granted_at: u64,
+    pub expires_at: u64,
+    pub revoked_at: Option<u64>,
+}
+
+impl CohortGrant {
+    pub fn is_active(&self, now: u64) -> bool {
+        self.revoked_at.is_none() && now < self.expires_at
+    }
+}
+
+/// Patient-visible record of a read made under a cohort grant
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CohortDisclosure {
+    pub grant_id: u64,
+    pub supervisor: Address,
+    pub resident: Address,
+    pub record_id: u64,
+    pub accessed_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Increments and returns the next cohort grant ID
+pub fn increment_counter(env: &Env) -> u64 {
+    let current: u64 = env.storage().instance().get(&COH_CTR).unwrap_or(0);
+    let next = current.saturating_add(1);
+    env.storage().instance().set(&COH_CTR, &next);
+    next
+}
+
+pub fn set_grant(env: &Env, grant: &CohortGrant) {
+    let key = (COH_GRT, grant.id);
+    env.storage().persistent().set(&key, grant);
+    extend_ttl_grant_key(env, &key);
+}
+
+pub fn get_grant(env: &Env, id: u64) -> Option<CohortGrant> {
+    env.storage().persistent().get(&(COH_GRT, id))
+}
+
+/// The current grant for `supervisor` over `resident`, active or not.
+pub fn get_pair_grant(env: &Env, supervisor: &Address, resident: &Address) -> Option<CohortGrant> {
+    let id: u64 = env
+        .storage()
+        .persistent()
+        .get(&(COH_PAIR, supervisor.clone(), resident.clone()))?;
+    get_grant(env, id)
+}
+
+/// Stores `grant` as the current one for its pair, superseding any earlier
+/// grant, and indexes it under the supervisor.
+pub fn create_grant(env: &Env, grant: &CohortGrant) {
+    if let Some(mut previous) = get_pair_grant(env, &grant.supervisor, &grant.resident) {
+        if previous.revoked_at.is_none() {
+            previous.revoked_at = Some(grant.granted_at);
+            set_grant(env, &previous);
+        }
+    }
+    set_grant(env, grant);
+
+    let pair_key = (COH_PAIR, grant.supervisor.clone(), grant.resident.clone());
+    env.storage().persistent().set(&pair_key, &grant.id);
+    extend_ttl_pair_key(env, &pair_key);
+
+    let sup_key = (COH_SUP, grant.supervisor.clone());
+    let mut ids = get_supervisor_grant_ids(env, &grant.supervisor);
+    ids.push_back(grant.id);
+    env.storage().persistent().set(&sup_key, &ids);
+    extend_ttl_address_key(env, &sup_key);
+}
+
+pub fn get_supervisor_grant_ids(env: &Env, supervisor: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(COH_SUP, supervisor.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Active grant letting `supervisor` read records authored by `provider`
+pub fn active_grant(
+    env: &Env,
+    supervisor: &Address,
+    provider: &Address,
+    now: u64,
+) -> Option<CohortGrant> {
+    get_pair_grant(env, supervisor, provider).filter(|g| g.is_active(now))
+}
+
+pub fn add_disclosure(env: &Env, patient: &Address, disclosure: &CohortDisclosure) {
+    let key = (COH_DISC, patient.clone());
+    let mut log = get_disclosures(env, patient);
+    log.push_back(disclosure.clone());
+    env.storage().persistent().set(&key, &log);
+    extend_ttl_address_key(env, &key);
+}
+
+pub fn get_disclosures(env: &Env, patient: &Address) -> Vec<CohortDisclosure> {
+    env.storage()
+        .persistent()
+        .get(&(COH_DISC, patient.clone()))
+        .unwrap_or(Vec::new(env))
+}
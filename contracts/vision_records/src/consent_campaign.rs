@@ -0,0 +1,179 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+use crate::ttl_config;
+use crate::{AccessLevel, ConsentGrant, ConsentType};
+
+// ── Storage keys ──────────────────────────────────────────────
+const CMP_CTR: Symbol = symbol_short!("CMP_CTR");
+const CMP: Symbol = symbol_short!("CMP");
+const CMP_OPEN: Symbol = symbol_short!("CMP_OPEN");
+const CMP_RSP: Symbol = symbol_short!("CMP_RSP");
+
+/// Every open campaign is consulted on each access decision, so keep few.
+pub const MAX_OPEN_CAMPAIGNS: u32 = 8;
+
+/// Extends the time-to-live (TTL) for campaign keys.
+fn extend_ttl_campaign_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-patient response keys.
+fn extend_ttl_response_key(env: &Env, key: &(Symbol, u64, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A drive to re-collect consent after the terms behind it changed.
+///
+/// Consents of `consent_types` granted before `created_at` were given under
+/// the old terms. Once `deadline` passes, grants that rest on such a consent
+/// are capped at `downgrade_to` until the patient accepts `terms_hash`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsentCampaign {
+    pub id: u64,
+    pub terms_hash: BytesN<32>,
+    pub consent_types: Vec<ConsentType>,
+    pub deadline: u64,
+    pub downgrade_to: AccessLevel,
+    pub created_by: Address,
+    pub created_at: u64,
+    pub closed_at: Option<u64>,
+    pub accepted: u32,
+    pub declined: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReconsentStatus {
+    Pending,
+    Accepted,
+    /// Declined consents lapse at once rather than at the deadline
+    Declined,
+}
+
+/// Campaign progress for compliance reporting
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CampaignProgress {
+    pub campaign_id: u64,
+    pub accepted: u32,
+    pub declined: u32,
+    pub deadline: u64,
+    pub deadline_passed: bool,
+    pub closed: bool,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Increments and returns the next campaign ID
+pub fn increment_counter(env: &Env) -> u64 {
+    let current: u64 = env.storage().instance().get(&CMP_CTR).unwrap_or(0);
+    let next = current.saturating_add(1);
+    env.storage().instance().set(&CMP_CTR, &next);
+    next
+}
+
+pub fn get(env: &Env, id: u64) -> Option<ConsentCampaign> {
+    env.storage().persistent().get(&(CMP, id))
+}
+
+pub fn save(env: &Env, campaign: &ConsentCampaign) {
+    let key = (CMP, campaign.id);
+    env.storage().persistent().set(&key, campaign);
+    extend_ttl_campaign_key(env, &key);
+}
+
+pub fn get_open_ids(env: &Env) -> Vec<u64> {
+    env.storage().instance().get(&CMP_OPEN).unwrap_or(Vec::new(env))
+}
+
+pub fn add_open(env: &Env, id: u64) {
+    let mut ids = get_open_ids(env);
+    ids.push_back(id);
+    env.storage().instance().set(&CMP_OPEN, &ids);
+}
+
+pub fn remove_open(env: &Env, id: u64) {
+    let mut remaining = Vec::new(env);
+    for open_id in get_open_ids(env).iter() {
+        if open_id != id {
+            remaining.push_back(open_id);
+        }
+    }
+    env.storage().instance().set(&CMP_OPEN, &remaining);
+}
+
+pub fn get_status(env: &Env, campaign_id: u64, patient: &Address) -> ReconsentStatus {
+    env.storage()
+        .persistent()
+        .get(&(CMP_RSP, campaign_id, patient.clone()))
+        .unwrap_or(ReconsentStatus::Pending)
+}
+
+pub fn set_status(env: &Env, campaign_id: u64, patient: &Address, status: &ReconsentStatus) {
+    let key = (CMP_RSP, campaign_id, patient.clone());
+    env.storage().persistent().set(&key, status);
+    extend_ttl_response_key(env, &key);
+}
+
+fn rank(level: &AccessLevel) -> u32 {
+    match level {
+        AccessLevel::None => 0,
+        AccessLevel::Read => 1,
+        AccessLevel::Write => 2,
+        AccessLevel::Admin => 3,
+    }
+}
+
+/// The lower of two access levels
+pub fn cap_level(level: AccessLevel, cap: &AccessLevel) -> AccessLevel {
+    if rank(cap) < rank(&level) {
+        cap.clone()
+    } else {
+        level
+    }
+}
+
+/// The level grants resting on `consent` are held to because it was given
+/// under terms an open campaign replaced and was not renewed in time.
+/// `None` when no campaign affects it.
+pub fn lapsed_cap(env: &Env, consent: &ConsentGrant) -> Option<AccessLevel> {
+    let now = env.ledger().timestamp();
+    let mut cap: Option<AccessLevel> = None;
+    for id in get_open_ids(env).iter() {
+        let campaign = match get(env, id) {
+            Some(campaign) => campaign,
+            None => continue,
+        };
+        if consent.granted_at >= campaign.created_at
+            || !campaign.consent_types.contains(&consent.consent_type)
+        {
+            continue;
+        }
+        let lapsed = match get_status(env, id, &consent.patient) {
+            ReconsentStatus::Accepted => false,
+            ReconsentStatus::Declined => true,
+            ReconsentStatus::Pending => now >= campaign.deadline,
+        };
+        if lapsed {
+            cap = Some(match cap {
+                Some(current) => cap_level(current, &campaign.downgrade_to),
+                None => campaign.downgrade_to,
+            });
+        }
+    }
+    cap
+}
+
+pub fn progress(env: &Env, campaign: &ConsentCampaign) -> CampaignProgress {
+    CampaignProgress {
+        campaign_id: campaign.id,
+        accepted: campaign.accepted,
+        declined: campaign.declined,
+        deadline: campaign.deadline,
+        deadline_passed: env.ledger().timestamp() >= campaign.deadline,
+        closed: campaign.closed_at.is_some(),
+    }
+}
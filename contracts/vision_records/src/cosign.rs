@@ -0,0 +1,102 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const COSIGN: Symbol = symbol_short!("COSIGN");
+const COS_PEND: Symbol = symbol_short!("COS_PEND");
+
+/// Extends the time-to-live (TTL) for per-record co-sign request keys.
+fn extend_ttl_record_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-supervisor pending index keys.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A supervised provider's request for an attending to sign off on a
+/// record. The record is pending co-sign until `cosigned_at` is set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CosignRequest {
+    pub record_id: u64,
+    pub requested_by: Address,
+    pub supervisor: Address,
+    pub requested_at: u64,
+    pub cosigned_at: Option<u64>,
+}
+
+impl CosignRequest {
+    pub fn is_pending(&self) -> bool {
+        self.cosigned_at.is_none()
+    }
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get(env: &Env, record_id: u64) -> Option<CosignRequest> {
+    env.storage().persistent().get(&(COSIGN, record_id))
+}
+
+pub fn is_pending(env: &Env, record_id: u64) -> bool {
+    get(env, record_id).is_some_and(|req| req.is_pending())
+}
+
+/// Stores `req`, keeping the supervisor's pending index in step: a pending
+/// request is listed under its supervisor, a completed one is not. A
+/// pending request it replaces is dropped from the old supervisor's index.
+pub fn set(env: &Env, req: &CosignRequest) {
+    if let Some(previous) = get(env, req.record_id) {
+        if previous.is_pending() {
+            unlist(env, &previous.supervisor, req.record_id);
+        }
+    }
+
+    let key = (COSIGN, req.record_id);
+    env.storage().persistent().set(&key, req);
+    extend_ttl_record_key(env, &key);
+
+    if req.is_pending() {
+        let mut ids = pending_for(env, &req.supervisor);
+        if !ids.contains(req.record_id) {
+            ids.push_back(req.record_id);
+            write_index(env, &req.supervisor, &ids);
+        }
+    }
+}
+
+/// Records awaiting `supervisor`'s co-signature, oldest request first.
+pub fn pending_for(env: &Env, supervisor: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(COS_PEND, supervisor.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn unlist(env: &Env, supervisor: &Address, record_id: u64) {
+    let mut ids = pending_for(env, supervisor);
+    if let Some(i) = ids.first_index_of(record_id) {
+        ids.remove(i);
+        write_index(env, supervisor, &ids);
+    }
+}
+
+fn write_index(env: &Env, supervisor: &Address, ids: &Vec<u64>) {
+    let key = (COS_PEND, supervisor.clone());
+    env.storage().persistent().set(&key, ids);
+    extend_ttl_address_key(env, &key);
+}
+
+/// Drops a record's co-signature request, unlisting it if still pending.
+pub fn remove(env: &Env, record_id: u64) {
+    if let Some(req) = get(env, record_id) {
+        if req.is_pending() {
+            unlist(env, &req.supervisor, record_id);
+        }
+        env.storage().persistent().remove(&(COSIGN, record_id));
+    }
+}
@@ -0,0 +1,132 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+use teye_common::canonical::{self, Domain};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const CUS_CHN: Symbol = symbol_short!("CUS_CHN");
+
+/// Extends the time-to-live (TTL) for custody chain keys.
+fn extend_ttl_chain_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// One hand-over of a record from one provider to another.
+///
+/// `prev_hash` is the `entry_hash` of the previous transfer (all zeros for
+/// the first), and `entry_hash` covers every other field, so rewriting any
+/// link breaks every hash after it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustodyTransfer {
+    pub seq: u32,
+    pub record_id: u64,
+    pub from_provider: Address,
+    pub to_provider: Address,
+    pub authorizer: Address,
+    pub reason: String,
+    pub timestamp: u64,
+    pub prev_hash: BytesN<32>,
+    pub entry_hash: BytesN<32>,
+}
+
+/// Hashed body of a [`CustodyTransfer`]
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct CustodyLink {
+    seq: u32,
+    record_id: u64,
+    from_provider: Address,
+    to_provider: Address,
+    authorizer: Address,
+    reason: String,
+    timestamp: u64,
+    prev_hash: BytesN<32>,
+}
+
+impl CustodyTransfer {
+    fn compute_hash(&self, env: &Env) -> BytesN<32> {
+        let link = CustodyLink {
+            seq: self.seq,
+            record_id: self.record_id,
+            from_provider: self.from_provider.clone(),
+            to_provider: self.to_provider.clone(),
+            authorizer: self.authorizer.clone(),
+            reason: self.reason.clone(),
+            timestamp: self.timestamp,
+            prev_hash: self.prev_hash.clone(),
+        };
+        canonical::hash(env, Domain::Custody, &link)
+    }
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get_chain(env: &Env, record_id: u64) -> Vec<CustodyTransfer> {
+    env.storage()
+        .persistent()
+        .get(&(CUS_CHN, record_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Appends a transfer to the record's chain and returns it.
+pub fn append(
+    env: &Env,
+    record_id: u64,
+    from_provider: Address,
+    to_provider: Address,
+    authorizer: Address,
+    reason: String,
+) -> CustodyTransfer {
+    let mut chain = get_chain(env, record_id);
+    let prev_hash = chain
+        .last()
+        .map(|t| t.entry_hash)
+        .unwrap_or(BytesN::from_array(env, &[0u8; 32]));
+    let mut transfer = CustodyTransfer {
+        seq: chain.len(),
+        record_id,
+        from_provider,
+        to_provider,
+        authorizer,
+        reason,
+        timestamp: env.ledger().timestamp(),
+        prev_hash: prev_hash.clone(),
+        entry_hash: prev_hash,
+    };
+    transfer.entry_hash = transfer.compute_hash(env);
+
+    chain.push_back(transfer.clone());
+    let key = (CUS_CHN, record_id);
+    env.storage().persistent().set(&key, &chain);
+    extend_ttl_chain_key(env, &key);
+    transfer
+}
+
+/// Returns true if every link hashes correctly, points at the one before
+/// it, and hands over from the provider the previous link handed to.
+/// `current_provider` must be the last recipient (or the only provider if
+/// the record was never transferred).
+pub fn verify(env: &Env, record_id: u64, current_provider: &Address) -> bool {
+    let mut prev_hash = BytesN::from_array(env, &[0u8; 32]);
+    let mut holder: Option<Address> = None;
+    for (i, transfer) in get_chain(env, record_id).iter().enumerate() {
+        if transfer.seq != i as u32
+            || transfer.record_id != record_id
+            || transfer.prev_hash != prev_hash
+            || transfer.entry_hash != transfer.compute_hash(env)
+        {
+            return false;
+        }
+        if let Some(holder) = holder {
+            if transfer.from_provider != holder {
+                return false;
+            }
+        }
+        prev_hash = transfer.entry_hash.clone();
+        holder = Some(transfer.to_provider);
+    }
+    holder.map(|h| h == *current_provider).unwrap_or(true)
+}
@@ -0,0 +1,157 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, BytesN, Env, String, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const DAC_CTR: Symbol = symbol_short!("DAC_CTR");
+const DAC: Symbol = symbol_short!("DAC");
+const DAC_REC: Symbol = symbol_short!("DAC_REC");
+
+/// How long the custodian has to answer a challenge
+pub const RESPONSE_WINDOW_SECONDS: u64 = 259_200;
+
+/// Extends the time-to-live (TTL) for challenge and per-record index keys.
+fn extend_ttl_challenge_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataChallengeStatus {
+    Open,
+    /// Custodian signed a fresh digest of the payload and the nonce
+    Attested,
+    /// Custodian proved possession to the zk verifier
+    Proven,
+}
+
+/// How the custodian answers a challenge
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataChallengeResponse {
+    /// `sha256(payload || nonce)`, computed off-chain over the stored payload
+    /// and authorized by the custodian. Kept for off-chain audit; the
+    /// contract cannot recompute it without the payload.
+    Attestation(BytesN<32>),
+    /// Consume a zk verifier receipt for the custodian with the challenge
+    /// nonce as resource id.
+    ZkProof,
+}
+
+/// A demand that the custodian of a record's off-chain payload show it
+/// still holds it.
+///
+/// `nonce` is fixed when the challenge opens so an answer cannot be
+/// prepared in advance. `after_lapse` is set when the previous challenge
+/// on the record went unanswered; the record stays unverified until this
+/// one is answered.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DataChallenge {
+    pub id: u64,
+    pub record_id: u64,
+    pub data_hash: String,
+    pub challenger: Address,
+    pub nonce: BytesN<32>,
+    pub opened_at: u64,
+    pub respond_by: u64,
+    pub after_lapse: bool,
+    pub status: DataChallengeStatus,
+    pub custodian: Option<Address>,
+    pub attestation: Option<BytesN<32>>,
+    pub responded_at: Option<u64>,
+}
+
+impl DataChallenge {
+    /// Open past its deadline
+    pub fn is_lapsed(&self, now: u64) -> bool {
+        self.status == DataChallengeStatus::Open && now > self.respond_by
+    }
+}
+
+/// Availability of a record's off-chain payload, as far as challenges show
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DataAvailability {
+    Unchallenged,
+    /// A challenge is open and within its window
+    Challenged,
+    /// The latest challenge was answered
+    Verified,
+    /// A challenge went unanswered and none has been answered since
+    Unverified,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Increments and returns the next challenge ID
+pub fn increment_counter(env: &Env) -> u64 {
+    let current: u64 = env.storage().instance().get(&DAC_CTR).unwrap_or(0);
+    let next = current.saturating_add(1);
+    env.storage().instance().set(&DAC_CTR, &next);
+    next
+}
+
+pub fn get(env: &Env, id: u64) -> Option<DataChallenge> {
+    env.storage().persistent().get(&(DAC, id))
+}
+
+pub fn save(env: &Env, challenge: &DataChallenge) {
+    let key = (DAC, challenge.id);
+    env.storage().persistent().set(&key, challenge);
+    extend_ttl_challenge_key(env, &key);
+}
+
+/// Challenge ids raised against `record_id`, oldest first
+pub fn get_record_ids(env: &Env, record_id: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(DAC_REC, record_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn latest_for_record(env: &Env, record_id: u64) -> Option<DataChallenge> {
+    let ids = get_record_ids(env, record_id);
+    match ids.last() {
+        Some(id) => get(env, id),
+        None => None,
+    }
+}
+
+/// Stores a new challenge and appends it to the record's index.
+pub fn create(env: &Env, challenge: &DataChallenge) {
+    save(env, challenge);
+    let key = (DAC_REC, challenge.record_id);
+    let mut ids = get_record_ids(env, challenge.record_id);
+    ids.push_back(challenge.id);
+    env.storage().persistent().set(&key, &ids);
+    extend_ttl_challenge_key(env, &key);
+}
+
+/// Derives the challenge nonce from its id, the record and the ledger it
+/// was opened in.
+pub fn derive_nonce(env: &Env, id: u64, record_id: u64) -> BytesN<32> {
+    let mut seed = Bytes::new(env);
+    seed.extend_from_array(&id.to_be_bytes());
+    seed.extend_from_array(&record_id.to_be_bytes());
+    seed.extend_from_array(&env.ledger().timestamp().to_be_bytes());
+    seed.extend_from_array(&env.ledger().sequence().to_be_bytes());
+    env.crypto().sha256(&seed).into()
+}
+
+pub fn availability(env: &Env, record_id: u64) -> DataAvailability {
+    let challenge = match latest_for_record(env, record_id) {
+        Some(challenge) => challenge,
+        None => return DataAvailability::Unchallenged,
+    };
+    let now = env.ledger().timestamp();
+    match challenge.status {
+        DataChallengeStatus::Attested | DataChallengeStatus::Proven => DataAvailability::Verified,
+        DataChallengeStatus::Open if challenge.after_lapse || challenge.is_lapsed(now) => {
+            DataAvailability::Unverified
+        }
+        DataChallengeStatus::Open => DataAvailability::Challenged,
+    }
+}
@@ -0,0 +1,132 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+use teye_common::canonical::{self, Domain};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const DEV_CTR: Symbol = symbol_short!("DEV_CTR");
+const DEVICE: Symbol = symbol_short!("DEVICE");
+const DEV_ORG: Symbol = symbol_short!("DEV_ORG");
+const DEV_REC: Symbol = symbol_short!("DEV_REC");
+
+/// Longest accepted device model string.
+pub const MAX_MODEL_LEN: u32 = 64;
+
+/// Extends the time-to-live (TTL) for per-device, per-organization and
+/// per-record keys.
+fn extend_ttl_id_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A retinal camera, OCT machine or similar instrument that submits
+/// records itself, signing each with its Ed25519 key. `next_seq` is the
+/// sequence number its next submission must sign, so a captured
+/// submission cannot be replayed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImagingDevice {
+    pub id: u64,
+    pub org_id: u64,
+    pub public_key: BytesN<32>,
+    pub model: String,
+    pub registered_at: u64,
+    pub active: bool,
+    pub next_seq: u64,
+}
+
+/// Who originated a record: a provider, or a registered device.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RecordProvenance {
+    Provider(Address),
+    Device(u64),
+}
+
+/// The fields a device signs for one submission.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct DeviceSubmission {
+    contract: Address,
+    device_id: u64,
+    seq: u64,
+    patient: Address,
+    data_hash: String,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn is_valid_model(model: &String) -> bool {
+    model.len() > 0 && model.len() <= MAX_MODEL_LEN
+}
+
+/// Increments and returns the next device ID
+pub fn increment_counter(env: &Env) -> u64 {
+    let current: u64 = env.storage().instance().get(&DEV_CTR).unwrap_or(0);
+    let next = current.saturating_add(1);
+    env.storage().instance().set(&DEV_CTR, &next);
+    next
+}
+
+pub fn get(env: &Env, id: u64) -> Option<ImagingDevice> {
+    env.storage().persistent().get(&(DEVICE, id))
+}
+
+pub fn set(env: &Env, device: &ImagingDevice) {
+    let key = (DEVICE, device.id);
+    env.storage().persistent().set(&key, device);
+    extend_ttl_id_key(env, &key);
+}
+
+/// Stores a new device and indexes it under its organization.
+pub fn create(env: &Env, device: &ImagingDevice) {
+    set(env, device);
+    let key = (DEV_ORG, device.org_id);
+    let mut ids = get_org_devices(env, device.org_id);
+    ids.push_back(device.id);
+    env.storage().persistent().set(&key, &ids);
+    extend_ttl_id_key(env, &key);
+}
+
+/// Device ids registered to `org_id`, oldest first.
+pub fn get_org_devices(env: &Env, org_id: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(DEV_ORG, org_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Hash a device signs to submit `data_hash` for `patient` as its
+/// `seq`-th submission to this contract.
+pub fn submission_hash(
+    env: &Env,
+    device_id: u64,
+    seq: u64,
+    patient: &Address,
+    data_hash: &String,
+) -> BytesN<32> {
+    let submission = DeviceSubmission {
+        contract: env.current_contract_address(),
+        device_id,
+        seq,
+        patient: patient.clone(),
+        data_hash: data_hash.clone(),
+    };
+    canonical::hash(env, Domain::Signature, &submission)
+}
+
+pub fn set_record_device(env: &Env, record_id: u64, device_id: u64) {
+    let key = (DEV_REC, record_id);
+    env.storage().persistent().set(&key, &device_id);
+    extend_ttl_id_key(env, &key);
+}
+
+/// The device that submitted `record_id`, if a device did.
+pub fn get_record_device(env: &Env, record_id: u64) -> Option<u64> {
+    env.storage().persistent().get(&(DEV_REC, record_id))
+}
+
+pub fn remove_record_device(env: &Env, record_id: u64) {
+    env.storage().persistent().remove(&(DEV_REC, record_id));
+}
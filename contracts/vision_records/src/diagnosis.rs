@@ -0,0 +1,257 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const DX_CODES: Symbol = symbol_short!("DX_CODES");
+const DX_INDEX: Symbol = symbol_short!("DX_IDX");
+const DX_DET: Symbol = symbol_short!("DX_DET");
+const DX_PAT: Symbol = symbol_short!("DX_PAT");
+
+/// Maximum number of record ids returned by a single code search page.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Maximum accepted length for a diagnosis code (ICD-10 codes are at most 8).
+pub const MAX_CODE_LEN: u32 = 16;
+
+/// Maximum number of structured diagnoses kept on one record.
+pub const MAX_DETAILS_PER_RECORD: u32 = 12;
+
+/// Extends the time-to-live (TTL) for per-record diagnosis code keys.
+fn extend_ttl_record_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-patient code index keys.
+fn extend_ttl_index_key(env: &Env, key: &(Symbol, Address, String)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-patient diagnosed record keys.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Which eye a diagnosis applies to
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Laterality {
+    Right,
+    Left,
+    Bilateral,
+    Unspecified,
+}
+
+/// Clinical severity of a diagnosed condition
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DiagnosisSeverity {
+    Mild,
+    Moderate,
+    Severe,
+    Indeterminate,
+}
+
+/// A structured ICD-10 diagnosis on a Diagnosis record, readable by other
+/// contracts without resolving the record's document hash.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiagnosisDetail {
+    pub record_id: u64,
+    pub code: String,
+    pub laterality: Laterality,
+    pub severity: DiagnosisSeverity,
+    pub recorded_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn is_valid_code(code: &String) -> bool {
+    code.len() > 0 && code.len() <= MAX_CODE_LEN
+}
+
+/// True for an ICD-10 code in dotted form: a letter, two characters of
+/// which the first is a digit, then optionally a dot and one to four
+/// letters or digits, e.g. `H40.1131`. Letters must be upper case.
+pub fn is_valid_icd10(code: &String) -> bool {
+    let len = code.len() as usize;
+    if !(3..=8).contains(&len) || len == 4 {
+        return false;
+    }
+    let mut buf = [0u8; 8];
+    code.copy_into_slice(&mut buf[..len]);
+    let upper_alnum = |b: u8| b.is_ascii_uppercase() || b.is_ascii_digit();
+
+    buf[0].is_ascii_uppercase()
+        && buf[1].is_ascii_digit()
+        && upper_alnum(buf[2])
+        && (len == 3 || (buf[3] == b'.' && buf[4..len].iter().all(|b| upper_alnum(*b))))
+}
+
+pub fn get_record_codes(env: &Env, record_id: u64) -> Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&(DX_CODES, record_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns the ids of `patient`'s records tagged with `code`, oldest first.
+pub fn get_indexed_records(env: &Env, patient: &Address, code: &String) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(DX_INDEX, patient.clone(), code.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Tags a record with a diagnosis code and updates the patient's code index.
+/// Returns false if the record already carried the code.
+pub fn add_code(env: &Env, patient: &Address, record_id: u64, code: &String) -> bool {
+    let mut codes = get_record_codes(env, record_id);
+    if codes.contains(code) {
+        return false;
+    }
+    codes.push_back(code.clone());
+    let codes_key = (DX_CODES, record_id);
+    env.storage().persistent().set(&codes_key, &codes);
+    extend_ttl_record_key(env, &codes_key);
+
+    let index_key = (DX_INDEX, patient.clone(), code.clone());
+    let mut ids = get_indexed_records(env, patient, code);
+    ids.push_back(record_id);
+    env.storage().persistent().set(&index_key, &ids);
+    extend_ttl_index_key(env, &index_key);
+
+    true
+}
+
+pub fn get_details(env: &Env, record_id: u64) -> Vec<DiagnosisDetail> {
+    env.storage()
+        .persistent()
+        .get(&(DX_DET, record_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Ids of `patient`'s records carrying structured diagnoses, oldest first.
+pub fn diagnosed_records(env: &Env, patient: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(DX_PAT, patient.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores `detail` on its record, replacing an earlier detail for the same
+/// code. Returns false when the record already holds
+/// `MAX_DETAILS_PER_RECORD` other codes.
+pub fn set_detail(env: &Env, patient: &Address, detail: &DiagnosisDetail) -> bool {
+    let mut details = get_details(env, detail.record_id);
+    match details.iter().position(|d| d.code == detail.code) {
+        Some(i) => details.set(i as u32, detail.clone()),
+        None if details.len() >= MAX_DETAILS_PER_RECORD => return false,
+        None => details.push_back(detail.clone()),
+    }
+    let key = (DX_DET, detail.record_id);
+    env.storage().persistent().set(&key, &details);
+    extend_ttl_record_key(env, &key);
+
+    let mut ids = diagnosed_records(env, patient);
+    if !ids.contains(detail.record_id) {
+        ids.push_back(detail.record_id);
+        let index_key = (DX_PAT, patient.clone());
+        env.storage().persistent().set(&index_key, &ids);
+        extend_ttl_address_key(env, &index_key);
+    }
+    true
+}
+
+/// Returns one page of the patient's code index.
+pub fn page(env: &Env, patient: &Address, code: &String, offset: u32, limit: u32) -> Vec<u64> {
+    let ids = get_indexed_records(env, patient, code);
+    let mut out = Vec::new(env);
+    let limit = limit.min(MAX_PAGE_SIZE);
+    let end = offset.saturating_add(limit).min(ids.len());
+    for i in offset..end {
+        if let Some(id) = ids.get(i) {
+            out.push_back(id);
+        }
+    }
+    out
+}
+
+/// Moves a record's entries in the code index and the diagnosed record
+/// index from one patient to another.
+pub fn reindex_record(env: &Env, from: &Address, to: &Address, record_id: u64) {
+    let mut diagnosed = diagnosed_records(env, from);
+    if let Some(i) = diagnosed.first_index_of(record_id) {
+        diagnosed.remove(i);
+        let from_key = (DX_PAT, from.clone());
+        if diagnosed.is_empty() {
+            env.storage().persistent().remove(&from_key);
+        } else {
+            env.storage().persistent().set(&from_key, &diagnosed);
+        }
+
+        let mut ids = diagnosed_records(env, to);
+        if !ids.contains(record_id) {
+            ids.push_back(record_id);
+            let to_key = (DX_PAT, to.clone());
+            env.storage().persistent().set(&to_key, &ids);
+            extend_ttl_address_key(env, &to_key);
+        }
+    }
+
+    for code in get_record_codes(env, record_id).iter() {
+        let from_key = (DX_INDEX, from.clone(), code.clone());
+        let mut remaining = Vec::new(env);
+        for id in get_indexed_records(env, from, &code).iter() {
+            if id != record_id {
+                remaining.push_back(id);
+            }
+        }
+        if remaining.is_empty() {
+            env.storage().persistent().remove(&from_key);
+        } else {
+            env.storage().persistent().set(&from_key, &remaining);
+        }
+
+        let to_key = (DX_INDEX, to.clone(), code.clone());
+        let mut ids = get_indexed_records(env, to, &code);
+        if !ids.contains(record_id) {
+            ids.push_back(record_id);
+            env.storage().persistent().set(&to_key, &ids);
+            extend_ttl_index_key(env, &to_key);
+        }
+    }
+}
+
+/// Drops a record's codes, structured details and index entries.
+pub fn remove_record(env: &Env, patient: &Address, record_id: u64) {
+    for code in get_record_codes(env, record_id).iter() {
+        let key = (DX_INDEX, patient.clone(), code.clone());
+        let mut remaining = Vec::new(env);
+        for id in get_indexed_records(env, patient, &code).iter() {
+            if id != record_id {
+                remaining.push_back(id);
+            }
+        }
+        if remaining.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &remaining);
+        }
+    }
+    env.storage().persistent().remove(&(DX_CODES, record_id));
+    env.storage().persistent().remove(&(DX_DET, record_id));
+
+    let mut diagnosed = diagnosed_records(env, patient);
+    if let Some(i) = diagnosed.first_index_of(record_id) {
+        diagnosed.remove(i);
+        let key = (DX_PAT, patient.clone());
+        if diagnosed.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &diagnosed);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use soroban_sdk::{symbol_short, Address, Env, Symbol};
+
+use crate::ttl_config;
+use crate::{ContractError, RecordType};
+
+// ── Storage keys ──────────────────────────────────────────────
+const CLAIMS_CONTRACT: Symbol = symbol_short!("ELIG_CLM");
+const ELIG_REQUIRED: Symbol = symbol_short!("ELIG_REQ");
+const ELIG_USED: Symbol = symbol_short!("ELIG_USE");
+
+/// Extends the time-to-live (TTL) for per-record-type eligibility keys.
+fn extend_ttl_record_type_key(env: &Env, key: &(Symbol, RecordType)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for consumed attestation keys.
+fn extend_ttl_attestation_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Claims contract interface ────────────────────────────────
+
+/// Interface exposed by the claims contract for eligibility pre-checks.
+#[soroban_sdk::contractclient(name = "ClaimsClient")]
+pub trait ClaimsInterface {
+    /// Returns true if `attestation_id` is a valid, unexpired eligibility
+    /// attestation covering `patient` for a record of `record_type`.
+    fn verify_eligibility(
+        env: Env,
+        attestation_id: u64,
+        patient: Address,
+        record_type: RecordType,
+    ) -> bool;
+
+    /// Returns true if `payer` adjudicated `claim_id` for `patient`'s
+    /// prescription `rx_id`.
+    fn is_rx_claim_adjudicated(
+        env: Env,
+        claim_id: u64,
+        payer: Address,
+        patient: Address,
+        rx_id: u64,
+    ) -> bool;
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn set_claims_contract(env: &Env, claims: &Address) {
+    env.storage().instance().set(&CLAIMS_CONTRACT, claims);
+}
+
+pub fn get_claims_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&CLAIMS_CONTRACT)
+}
+
+pub fn set_required(env: &Env, record_type: &RecordType, required: bool) {
+    let key = (ELIG_REQUIRED, record_type.clone());
+    if required {
+        env.storage().persistent().set(&key, &true);
+        extend_ttl_record_type_key(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_required(env: &Env, record_type: &RecordType) -> bool {
+    env.storage()
+        .persistent()
+        .get(&(ELIG_REQUIRED, record_type.clone()))
+        .unwrap_or(false)
+}
+
+/// Returns the record an attestation was consumed by, if any.
+pub fn get_attestation_record(env: &Env, attestation_id: u64) -> Option<u64> {
+    env.storage().persistent().get(&(ELIG_USED, attestation_id))
+}
+
+pub fn mark_attestation_used(env: &Env, attestation_id: u64, record_id: u64) {
+    let key = (ELIG_USED, attestation_id);
+    env.storage().persistent().set(&key, &record_id);
+    extend_ttl_attestation_key(env, &key);
+}
+
+/// Enforces the eligibility hook for a new record.
+///
+/// When the hook is enabled for `record_type` an attestation must be
+/// supplied. Any supplied attestation is verified against the claims
+/// contract and may only back a single record.
+pub fn verify_eligibility(
+    env: &Env,
+    patient: &Address,
+    record_type: &RecordType,
+    attestation_id: Option<u64>,
+) -> Result<(), ContractError> {
+    let attestation_id = match attestation_id {
+        Some(id) => id,
+        None if is_required(env, record_type) => return Err(ContractError::EligibilityRequired),
+        None => return Ok(()),
+    };
+
+    if get_attestation_record(env, attestation_id).is_some() {
+        return Err(ContractError::InvalidEligibility);
+    }
+
+    let claims = get_claims_contract(env).ok_or(ContractError::EligibilityRequired)?;
+    let client = ClaimsClient::new(env, &claims);
+    if !client.verify_eligibility(&attestation_id, patient, record_type) {
+        return Err(ContractError::InvalidEligibility);
+    }
+
+    Ok(())
+}
@@ -1,26 +1,38 @@
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
 
+use crate::ttl_config;
+use crate::RecordType;
+
 // ── Storage keys ──────────────────────────────────────────────
 pub const EMRG_CTR: Symbol = symbol_short!("EMRG_CTR");
 const EMRG_ACCESS: Symbol = symbol_short!("EMRG_ACC");
 const EMRG_AUDIT: Symbol = symbol_short!("EMRG_AUD");
 const EMRG_PATIENT: Symbol = symbol_short!("EMRG_PAT");
+const EMRG_PAT_LIST: Symbol = symbol_short!("EMRG_PLST");
+const EMRG_POLICY: Symbol = symbol_short!("EMRG_POL");
+const EMRG_MAX_CONTACTS: Symbol = symbol_short!("EMRG_MAXC");
+const EMRG_CONTACTS: Symbol = symbol_short!("EMRG_CNT");
+const EMRG_RECORDS: Symbol = symbol_short!("EMRG_REC");
+
+/// Contacts notified per emergency grant unless an admin configures otherwise
+pub const DEFAULT_MAX_CONTACTS: u32 = 5;
 
-const TTL_THRESHOLD: u32 = 5184000;
-const TTL_EXTEND_TO: u32 = 10368000;
+/// Longest window a single emergency grant may stay open
+pub const MAX_EMERGENCY_SECONDS: u64 = 86400;
 
 /// Extends the time-to-live (TTL) for emergency access storage keys.
 fn extend_ttl_emergency_key(env: &Env, key: &(Symbol, u64)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-patient emergency policy, contact and grant-list keys.
+fn extend_ttl_policy_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
 }
 
 /// Extends the time-to-live (TTL) for emergency access by patient keys.
 fn extend_ttl_emergency_patient_key(env: &Env, key: &(Symbol, Address, u64)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 // ── Types ─────────────────────────────────────────────────────
@@ -33,12 +45,23 @@ pub enum EmergencyCondition {
     Unconscious,
     SurgicalEmergency,
     Masscasualties,
+    /// Time-sensitive but not life-threatening
+    Urgent,
+}
+
+impl EmergencyCondition {
+    /// Critical conditions never wait for patient acknowledgement.
+    pub fn is_critical(&self) -> bool {
+        !matches!(self, EmergencyCondition::Urgent)
+    }
 }
 
 /// Status of an emergency access request
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum EmergencyStatus {
+    /// Waiting for the patient's acknowledgement or the override deadline
+    Pending,
     Active,
     Expired,
     Revoked,
@@ -58,6 +81,32 @@ pub struct EmergencyAccess {
     pub expires_at: u64,
     pub status: EmergencyStatus,
     pub notified_contacts: Vec<Address>,
+    /// Record types readable under this grant
+    pub scope: Vec<RecordType>,
+    /// Responder directory membership the grant was issued under, if any
+    pub responder_id: Option<u64>,
+    /// For pending grants, when the grant activates without acknowledgement
+    pub ack_deadline: Option<u64>,
+}
+
+/// Patient-defined defaults applied to new emergency grants
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct EmergencyPolicy {
+    pub patient: Address,
+    pub default_scope: Vec<RecordType>,
+    pub updated_at: u64,
+}
+
+/// Contacts a patient wants notified of emergency grants on their data.
+/// With `listed_only`, requesters may not notify anyone else.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyContacts {
+    pub patient: Address,
+    pub contacts: Vec<Address>,
+    pub listed_only: bool,
+    pub updated_at: u64,
 }
 
 /// Immutable audit entry — written once, never deleted
@@ -90,6 +139,85 @@ pub fn set_emergency_access(env: &Env, access: &EmergencyAccess) {
     let patient_key = (EMRG_PATIENT, access.patient.clone(), access.id);
     env.storage().persistent().set(&patient_key, &true);
     extend_ttl_emergency_patient_key(env, &patient_key);
+
+    let list_key = (EMRG_PAT_LIST, access.patient.clone());
+    let mut ids = patient_access_ids(env, &access.patient);
+    if !ids.contains(access.id) {
+        ids.push_back(access.id);
+        env.storage().persistent().set(&list_key, &ids);
+    }
+    extend_ttl_policy_key(env, &list_key);
+}
+
+/// Every emergency grant ever made on `patient`'s data, oldest first.
+pub fn patient_access_ids(env: &Env, patient: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(EMRG_PAT_LIST, patient.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores a patient's emergency policy
+pub fn set_emergency_policy(env: &Env, policy: &EmergencyPolicy) {
+    let key = (EMRG_POLICY, policy.patient.clone());
+    env.storage().persistent().set(&key, policy);
+    extend_ttl_policy_key(env, &key);
+}
+
+/// Retrieves a patient's emergency policy, if one has been set
+pub fn get_emergency_policy(env: &Env, patient: &Address) -> Option<EmergencyPolicy> {
+    let key = (EMRG_POLICY, patient.clone());
+    env.storage().persistent().get(&key)
+}
+
+/// Stores a patient's emergency contact list
+pub fn set_emergency_contacts(env: &Env, contacts: &EmergencyContacts) {
+    let key = (EMRG_CONTACTS, contacts.patient.clone());
+    env.storage().persistent().set(&key, contacts);
+    extend_ttl_policy_key(env, &key);
+}
+
+/// Retrieves a patient's emergency contact list, if one has been set
+pub fn get_emergency_contacts(env: &Env, patient: &Address) -> Option<EmergencyContacts> {
+    env.storage()
+        .persistent()
+        .get(&(EMRG_CONTACTS, patient.clone()))
+}
+
+/// Maximum number of emergency contacts accepted per grant
+pub fn get_max_contacts(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&EMRG_MAX_CONTACTS)
+        .unwrap_or(DEFAULT_MAX_CONTACTS)
+}
+
+pub fn set_max_contacts(env: &Env, max_contacts: u32) {
+    env.storage()
+        .instance()
+        .set(&EMRG_MAX_CONTACTS, &max_contacts);
+}
+
+/// Scope applied when the patient has not set an emergency policy:
+/// examinations and prescriptions only.
+pub fn default_scope(env: &Env) -> Vec<RecordType> {
+    let mut scope = Vec::new(env);
+    scope.push_back(RecordType::Examination);
+    scope.push_back(RecordType::Prescription);
+    scope
+}
+
+/// Resolves the scope for a new emergency grant on `patient`'s data
+pub fn resolve_scope(env: &Env, patient: &Address) -> Vec<RecordType> {
+    match get_emergency_policy(env, patient) {
+        Some(policy) => policy.default_scope,
+        None => default_scope(env),
+    }
+}
+
+/// Returns true if `record_type` is readable under `access`
+pub fn scope_allows(access: &EmergencyAccess, record_type: &RecordType) -> bool {
+    access.scope.contains(record_type)
 }
 
 /// Retrieves an emergency access grant by ID
@@ -104,19 +232,9 @@ pub fn has_active_emergency_access(
     patient: &Address,
     requester: &Address,
 ) -> Option<EmergencyAccess> {
-    // We need to iterate through potential access IDs
-    // For efficiency, we'll check recent IDs (last 100)
-    let counter: u64 = env.storage().instance().get(&EMRG_CTR).unwrap_or(0);
-    let start_id = if counter > 100 { counter - 100 } else { 1 };
-
-    for id in start_id..=counter {
-        let key = (EMRG_ACCESS, id);
-        if let Some(access) = env.storage().persistent().get::<_, EmergencyAccess>(&key) {
-            if access.patient == *patient
-                && access.requester == *requester
-                && access.status == EmergencyStatus::Active
-                && access.expires_at > env.ledger().timestamp()
-            {
+    for id in patient_access_ids(env, patient).iter() {
+        if let Some(access) = get_emergency_access(env, id) {
+            if is_active_for(env, &access, patient, requester) {
                 return Some(access);
             }
         }
@@ -124,6 +242,59 @@ pub fn has_active_emergency_access(
     None
 }
 
+/// Returns true if `access` is an active, unexpired grant to `requester`
+/// for `patient`. A pending grant counts once its acknowledgement deadline
+/// has passed.
+pub fn is_active_for(
+    env: &Env,
+    access: &EmergencyAccess,
+    patient: &Address,
+    requester: &Address,
+) -> bool {
+    let now = env.ledger().timestamp();
+    let usable = match access.status {
+        EmergencyStatus::Active => true,
+        EmergencyStatus::Pending => matches!(access.ack_deadline, Some(d) if now >= d),
+        _ => false,
+    };
+    access.patient == *patient
+        && access.requester == *requester
+        && usable
+        && access.expires_at > now
+}
+
+/// Activates a pending grant. The access window starts now and keeps the
+/// length it was requested with.
+pub fn activate(env: &Env, access: &mut EmergencyAccess) {
+    let now = env.ledger().timestamp();
+    if let Some(deadline) = access.ack_deadline {
+        let duration = access.expires_at.saturating_sub(deadline);
+        if now < deadline {
+            access.expires_at = now.saturating_add(duration);
+        }
+    }
+    access.status = EmergencyStatus::Active;
+    access.ack_deadline = None;
+    set_emergency_access(env, access);
+}
+
+/// Records that `record_id` was created under emergency grant `access_id`.
+pub fn index_record(env: &Env, access_id: u64, record_id: u64) {
+    let key = (EMRG_RECORDS, access_id);
+    let mut records = get_records(env, access_id);
+    records.push_back(record_id);
+    env.storage().persistent().set(&key, &records);
+    extend_ttl_emergency_key(env, &key);
+}
+
+/// Retrieves the records created under an emergency grant
+pub fn get_records(env: &Env, access_id: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(EMRG_RECORDS, access_id))
+        .unwrap_or(Vec::new(env))
+}
+
 /// Revokes an emergency access grant
 pub fn revoke_emergency_access(env: &Env, access_id: u64) -> Option<EmergencyAccess> {
     let key = (EMRG_ACCESS, access_id);
@@ -175,12 +346,8 @@ pub fn get_audit_entries(env: &Env, access_id: u64) -> Vec<EmergencyAuditEntry>
 /// Gets all active emergency accesses for a patient
 pub fn get_patient_emergency_accesses(env: &Env, patient: &Address) -> Vec<EmergencyAccess> {
     let mut accesses = Vec::new(env);
-    let counter: u64 = env.storage().instance().get(&EMRG_CTR).unwrap_or(0);
-    let start_id = if counter > 100 { counter - 100 } else { 1 };
-
-    for id in start_id..=counter {
-        let key = (EMRG_ACCESS, id);
-        if let Some(access) = env.storage().persistent().get::<_, EmergencyAccess>(&key) {
+    for id in patient_access_ids(env, patient).iter() {
+        if let Some(access) = get_emergency_access(env, id) {
             if access.patient == *patient && access.status == EmergencyStatus::Active {
                 accesses.push_back(access);
             }
@@ -189,6 +356,22 @@ pub fn get_patient_emergency_accesses(env: &Env, patient: &Address) -> Vec<Emerg
     accesses
 }
 
+/// Like `get_patient_emergency_accesses`, but also includes grants still
+/// waiting for acknowledgement.
+pub fn get_patient_open_accesses(env: &Env, patient: &Address) -> Vec<EmergencyAccess> {
+    let mut accesses = Vec::new(env);
+    for id in patient_access_ids(env, patient).iter() {
+        if let Some(access) = get_emergency_access(env, id) {
+            let open = access.status == EmergencyStatus::Active
+                || access.status == EmergencyStatus::Pending;
+            if access.patient == *patient && open {
+                accesses.push_back(access);
+            }
+        }
+    }
+    accesses
+}
+
 /// Expires emergency accesses that have passed their expiration time
 pub fn expire_emergency_accesses(env: &Env) -> u32 {
     let mut expired_count = 0u32;
@@ -199,7 +382,9 @@ pub fn expire_emergency_accesses(env: &Env) -> u32 {
     for id in start_id..=counter {
         let key = (EMRG_ACCESS, id);
         if let Some(mut access) = env.storage().persistent().get::<_, EmergencyAccess>(&key) {
-            if access.status == EmergencyStatus::Active && access.expires_at <= current_time {
+            let open = access.status == EmergencyStatus::Active
+                || access.status == EmergencyStatus::Pending;
+            if open && access.expires_at <= current_time {
                 access.status = EmergencyStatus::Expired;
                 env.storage().persistent().set(&key, &access);
                 extend_ttl_emergency_key(env, &key);
@@ -209,3 +394,23 @@ pub fn expire_emergency_accesses(env: &Env) -> u32 {
     }
     expired_count
 }
+
+/// Re-points every emergency grant made on `from`'s data at `to`. Returns
+/// the number of grants moved.
+pub fn move_history(env: &Env, from: &Address, to: &Address) -> u32 {
+    let mut moved = 0u32;
+    for id in patient_access_ids(env, from).iter() {
+        env.storage()
+            .persistent()
+            .remove(&(EMRG_PATIENT, from.clone(), id));
+        if let Some(mut access) = get_emergency_access(env, id) {
+            access.patient = to.clone();
+            set_emergency_access(env, &access);
+            moved += 1;
+        }
+    }
+    env.storage()
+        .persistent()
+        .remove(&(EMRG_PAT_LIST, from.clone()));
+    moved
+}
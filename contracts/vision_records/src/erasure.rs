@@ -0,0 +1,86 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+use crate::ttl_config;
+use crate::VisionRecord;
+
+// ── Storage keys ──────────────────────────────────────────────
+const ERASURE: Symbol = symbol_short!("ERASURE");
+
+/// Extends the time-to-live (TTL) for per-patient erasure keys.
+fn extend_ttl_patient_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ErasureStatus {
+    /// Filed by the patient, waiting for an admin to carry it out
+    Requested,
+    /// Personal data removed
+    Completed,
+}
+
+/// A patient's request to have their personal data erased.
+///
+/// Once completed, `record_hashes` holds a salted SHA-256 of each erased
+/// record ID. An auditor holding a record ID from the audit trail can
+/// recompute its hash with `salt` and match it to the erasure, while the
+/// request itself no longer lists the IDs.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ErasureRequest {
+    pub patient: Address,
+    pub status: ErasureStatus,
+    pub requested_at: u64,
+    pub executed_by: Option<Address>,
+    pub executed_at: Option<u64>,
+    pub salt: Option<BytesN<32>>,
+    pub record_hashes: Vec<BytesN<32>>,
+    pub measurements_removed: u32,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get(env: &Env, patient: &Address) -> Option<ErasureRequest> {
+    env.storage().persistent().get(&(ERASURE, patient.clone()))
+}
+
+pub fn set(env: &Env, request: &ErasureRequest) {
+    let key = (ERASURE, request.patient.clone());
+    env.storage().persistent().set(&key, request);
+    extend_ttl_patient_key(env, &key);
+}
+
+/// SHA-256 over `salt || record_id` (big-endian)
+pub fn salted_record_hash(env: &Env, salt: &BytesN<32>, record_id: u64) -> BytesN<32> {
+    let mut preimage = Bytes::from_array(env, &salt.to_array());
+    preimage.extend_from_array(&record_id.to_be_bytes());
+    env.crypto().sha256(&preimage).into()
+}
+
+/// Removes `record` and everything stored against its ID: clinical detail
+/// (diagnoses, examination, attachments, lab results, FHIR envelope,
+/// amendment history) and the pointers other features keep to it. Audit,
+/// read and custody logs are left in place.
+pub fn clear_record(env: &Env, record: &VisionRecord) {
+    let id = record.id;
+    crate::diagnosis::remove_record(env, &record.patient, id);
+    crate::examination::remove_examination(env, id);
+    crate::attachment::remove_all(env, id);
+    crate::lab::remove_record(env, id);
+    crate::fhir::remove(env, &record.patient, id);
+    crate::amendment::remove_history(env, id);
+    crate::residency::remove_record(env, &record.provider, id);
+    crate::cosign::remove(env, id);
+    crate::record_signature::remove(env, id);
+    crate::patient_keys::remove_record_key(env, id);
+    crate::reencryption::remove_record(env, id);
+    crate::device::remove_record_device(env, id);
+    crate::tombstone::remove(env, id);
+    crate::retention::set_open_episode(env, id, false);
+    env.storage()
+        .persistent()
+        .remove(&(symbol_short!("RECORD"), id));
+}
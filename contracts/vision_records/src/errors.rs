@@ -1,20 +1,17 @@
 #![allow(clippy::arithmetic_side_effects)]
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
 
+use crate::ttl_config;
+
 pub const ERROR_LOG_KEY: Symbol = symbol_short!("ERR_LOG");
 pub const ERROR_COUNT_KEY: Symbol = symbol_short!("ERR_CNT");
 pub const MAX_ERROR_LOG_SIZE: u32 = 100;
 
-const TTL_THRESHOLD: u32 = 5184000;
-const TTL_EXTEND_TO: u32 = 10368000;
-
 /// Extends the time-to-live (TTL) for instance storage.
 /// Instance storage TTL applies to all keys in the instance storage.
 /// This ensures the data remains accessible for the extended period.
 fn extend_ttl_instance(env: &Env) {
-    env.storage()
-        .instance()
-        .extend_ttl(TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend_instance(env);
 }
 
 /// Error categories for classifying different types of errors
@@ -116,6 +113,63 @@ pub enum ContractError {
     VersionConflict = 37,
     ConflictQueued = 38,
     ConflictNotFound = 39,
+    RecordArchived = 40,
+    EligibilityRequired = 41,
+    InvalidEligibility = 42,
+    AdverseEventNotFound = 43,
+    LegalHoldActive = 44,
+    LegalHoldNotFound = 45,
+    MissingRequiredField = 46,
+    LocumNotFound = 47,
+    RegistrationProofRequired = 48,
+    InvalidShareCredential = 49,
+    PrescriptionExpired = 50,
+    ActorFlagNotFound = 51,
+    CustomRecordTypeExists = 52,
+    CustomRecordTypeNotFound = 53,
+    CohortGrantNotFound = 54,
+    StateCursorExpired = 55,
+    ResponderNotFound = 56,
+    ResponderAlreadyListed = 57,
+    AccessGrantNotFound = 58,
+    RestoreWindowClosed = 59,
+    AgreementNotFound = 60,
+    AgreementNotActive = 61,
+    ReferralNotFound = 62,
+    ResidencyNotAllowed = 63,
+    QuotaExceeded = 64,
+    IncompatibleInterface = 65,
+    DataChallengeNotFound = 66,
+    DataChallengeClosed = 67,
+    ConsentCampaignNotFound = 68,
+    ConsentCampaignClosed = 69,
+    ClaimNotAdjudicated = 70,
+    ContactNotListed = 71,
+    InvalidPrintAuthorization = 72,
+    FeatureDisabled = 73,
+    RegistrationNotPending = 74,
+    GrantDurationExceeded = 75,
+    ReferralNotPending = 76,
+    AdminTransferLocked = 77,
+    CareRelationshipNotFound = 78,
+    GuardianshipNotFound = 79,
+    OrganizationNotFound = 80,
+    SigningKeyNotRegistered = 81,
+    CosignNotPending = 82,
+    SurgeryNotFound = 83,
+    SurgeryStepOutOfOrder = 84,
+    LabResultNotFound = 85,
+    DeviceNotFound = 86,
+    DeviceInactive = 87,
+    EncryptionKeyNotFound = 88,
+    EncryptionKeyExists = 89,
+    ReencryptionGrantNotFound = 90,
+    ErasureAlreadyRequested = 91,
+    ErasureNotPending = 92,
+    PracticeTransferNotFound = 93,
+    TransferOptOutClosed = 94,
+    TransferOptOutOpen = 95,
+    PracticeTransferCompleted = 96,
 }
 
 impl ContractError {
@@ -162,6 +216,63 @@ impl ContractError {
             ContractError::TransientFailure | ContractError::RateLimitExceeded => {
                 ErrorCategory::Transient
             }
+            ContractError::RecordArchived => ErrorCategory::StateConflict,
+            ContractError::EligibilityRequired => ErrorCategory::Authorization,
+            ContractError::InvalidEligibility => ErrorCategory::Authorization,
+            ContractError::AdverseEventNotFound => ErrorCategory::NotFound,
+            ContractError::LegalHoldActive => ErrorCategory::StateConflict,
+            ContractError::LegalHoldNotFound => ErrorCategory::NotFound,
+            ContractError::MissingRequiredField => ErrorCategory::Validation,
+            ContractError::LocumNotFound => ErrorCategory::NotFound,
+            ContractError::RegistrationProofRequired => ErrorCategory::Authorization,
+            ContractError::InvalidShareCredential => ErrorCategory::Authorization,
+            ContractError::PrescriptionExpired => ErrorCategory::Validation,
+            ContractError::ActorFlagNotFound => ErrorCategory::NotFound,
+            ContractError::CustomRecordTypeExists => ErrorCategory::StateConflict,
+            ContractError::CustomRecordTypeNotFound => ErrorCategory::NotFound,
+            ContractError::CohortGrantNotFound => ErrorCategory::NotFound,
+            ContractError::StateCursorExpired => ErrorCategory::Validation,
+            ContractError::ResponderNotFound => ErrorCategory::NotFound,
+            ContractError::ResponderAlreadyListed => ErrorCategory::StateConflict,
+            ContractError::AccessGrantNotFound => ErrorCategory::NotFound,
+            ContractError::RestoreWindowClosed => ErrorCategory::StateConflict,
+            ContractError::AgreementNotFound => ErrorCategory::NotFound,
+            ContractError::AgreementNotActive => ErrorCategory::StateConflict,
+            ContractError::ReferralNotFound => ErrorCategory::NotFound,
+            ContractError::ResidencyNotAllowed => ErrorCategory::Validation,
+            ContractError::QuotaExceeded => ErrorCategory::StateConflict,
+            ContractError::IncompatibleInterface => ErrorCategory::Validation,
+            ContractError::DataChallengeNotFound => ErrorCategory::NotFound,
+            ContractError::DataChallengeClosed => ErrorCategory::StateConflict,
+            ContractError::ConsentCampaignNotFound => ErrorCategory::NotFound,
+            ContractError::ConsentCampaignClosed => ErrorCategory::StateConflict,
+            ContractError::ClaimNotAdjudicated => ErrorCategory::Authorization,
+            ContractError::ContactNotListed => ErrorCategory::Authorization,
+            ContractError::InvalidPrintAuthorization => ErrorCategory::Authorization,
+            ContractError::FeatureDisabled => ErrorCategory::System,
+            ContractError::RegistrationNotPending => ErrorCategory::NotFound,
+            ContractError::GrantDurationExceeded => ErrorCategory::Validation,
+            ContractError::ReferralNotPending => ErrorCategory::StateConflict,
+            ContractError::AdminTransferLocked => ErrorCategory::StateConflict,
+            ContractError::CareRelationshipNotFound => ErrorCategory::NotFound,
+            ContractError::GuardianshipNotFound => ErrorCategory::NotFound,
+            ContractError::OrganizationNotFound => ErrorCategory::NotFound,
+            ContractError::SigningKeyNotRegistered => ErrorCategory::NotFound,
+            ContractError::CosignNotPending => ErrorCategory::StateConflict,
+            ContractError::SurgeryNotFound => ErrorCategory::NotFound,
+            ContractError::SurgeryStepOutOfOrder => ErrorCategory::StateConflict,
+            ContractError::LabResultNotFound => ErrorCategory::NotFound,
+            ContractError::DeviceNotFound => ErrorCategory::NotFound,
+            ContractError::DeviceInactive => ErrorCategory::Authorization,
+            ContractError::EncryptionKeyNotFound => ErrorCategory::NotFound,
+            ContractError::EncryptionKeyExists => ErrorCategory::StateConflict,
+            ContractError::ReencryptionGrantNotFound => ErrorCategory::NotFound,
+            ContractError::ErasureAlreadyRequested => ErrorCategory::StateConflict,
+            ContractError::ErasureNotPending => ErrorCategory::StateConflict,
+            ContractError::PracticeTransferNotFound => ErrorCategory::NotFound,
+            ContractError::TransferOptOutClosed => ErrorCategory::StateConflict,
+            ContractError::TransferOptOutOpen => ErrorCategory::StateConflict,
+            ContractError::PracticeTransferCompleted => ErrorCategory::StateConflict,
             ContractError::Paused | ContractError::ContractPaused => ErrorCategory::System,
         }
     }
@@ -205,6 +316,63 @@ impl ContractError {
             ContractError::VersionConflict | ContractError::ConflictQueued => ErrorSeverity::Medium,
             ContractError::ConflictNotFound => ErrorSeverity::Low,
             ContractError::StorageError | ContractError::TransientFailure => ErrorSeverity::High,
+            ContractError::RecordArchived => ErrorSeverity::Low,
+            ContractError::EligibilityRequired => ErrorSeverity::Medium,
+            ContractError::InvalidEligibility => ErrorSeverity::Medium,
+            ContractError::AdverseEventNotFound => ErrorSeverity::Low,
+            ContractError::LegalHoldActive => ErrorSeverity::Medium,
+            ContractError::LegalHoldNotFound => ErrorSeverity::Low,
+            ContractError::MissingRequiredField => ErrorSeverity::Low,
+            ContractError::LocumNotFound => ErrorSeverity::Low,
+            ContractError::RegistrationProofRequired => ErrorSeverity::Medium,
+            ContractError::InvalidShareCredential => ErrorSeverity::Medium,
+            ContractError::PrescriptionExpired => ErrorSeverity::Low,
+            ContractError::ActorFlagNotFound => ErrorSeverity::Low,
+            ContractError::CustomRecordTypeExists => ErrorSeverity::Low,
+            ContractError::CustomRecordTypeNotFound => ErrorSeverity::Low,
+            ContractError::CohortGrantNotFound => ErrorSeverity::Low,
+            ContractError::StateCursorExpired => ErrorSeverity::Low,
+            ContractError::ResponderNotFound => ErrorSeverity::Low,
+            ContractError::ResponderAlreadyListed => ErrorSeverity::Low,
+            ContractError::AccessGrantNotFound => ErrorSeverity::Low,
+            ContractError::RestoreWindowClosed => ErrorSeverity::Low,
+            ContractError::AgreementNotFound => ErrorSeverity::Low,
+            ContractError::AgreementNotActive => ErrorSeverity::Low,
+            ContractError::ReferralNotFound => ErrorSeverity::Low,
+            ContractError::ResidencyNotAllowed => ErrorSeverity::Medium,
+            ContractError::QuotaExceeded => ErrorSeverity::Medium,
+            ContractError::IncompatibleInterface => ErrorSeverity::Medium,
+            ContractError::DataChallengeNotFound => ErrorSeverity::Low,
+            ContractError::DataChallengeClosed => ErrorSeverity::Low,
+            ContractError::ConsentCampaignNotFound => ErrorSeverity::Low,
+            ContractError::ConsentCampaignClosed => ErrorSeverity::Low,
+            ContractError::ClaimNotAdjudicated => ErrorSeverity::Medium,
+            ContractError::ContactNotListed => ErrorSeverity::Medium,
+            ContractError::InvalidPrintAuthorization => ErrorSeverity::Medium,
+            ContractError::FeatureDisabled => ErrorSeverity::Low,
+            ContractError::RegistrationNotPending => ErrorSeverity::Low,
+            ContractError::GrantDurationExceeded => ErrorSeverity::Low,
+            ContractError::ReferralNotPending => ErrorSeverity::Low,
+            ContractError::AdminTransferLocked => ErrorSeverity::Low,
+            ContractError::CareRelationshipNotFound => ErrorSeverity::Low,
+            ContractError::GuardianshipNotFound => ErrorSeverity::Low,
+            ContractError::OrganizationNotFound => ErrorSeverity::Low,
+            ContractError::SigningKeyNotRegistered => ErrorSeverity::Low,
+            ContractError::CosignNotPending => ErrorSeverity::Low,
+            ContractError::SurgeryNotFound => ErrorSeverity::Low,
+            ContractError::SurgeryStepOutOfOrder => ErrorSeverity::Medium,
+            ContractError::LabResultNotFound => ErrorSeverity::Low,
+            ContractError::DeviceNotFound => ErrorSeverity::Low,
+            ContractError::DeviceInactive => ErrorSeverity::Medium,
+            ContractError::EncryptionKeyNotFound => ErrorSeverity::Low,
+            ContractError::EncryptionKeyExists => ErrorSeverity::Low,
+            ContractError::ReencryptionGrantNotFound => ErrorSeverity::Low,
+            ContractError::ErasureAlreadyRequested => ErrorSeverity::Low,
+            ContractError::ErasureNotPending => ErrorSeverity::Low,
+            ContractError::PracticeTransferNotFound => ErrorSeverity::Low,
+            ContractError::TransferOptOutClosed => ErrorSeverity::Low,
+            ContractError::TransferOptOutOpen => ErrorSeverity::Low,
+            ContractError::PracticeTransferCompleted => ErrorSeverity::Low,
             ContractError::Paused | ContractError::ContractPaused => ErrorSeverity::Critical,
         }
     }
@@ -266,6 +434,79 @@ impl ContractError {
             }
             ContractError::ConflictQueued => "Concurrent modification conflict queued for review",
             ContractError::ConflictNotFound => "Conflict entry not found",
+            ContractError::RecordArchived => "Record has been archived",
+            ContractError::EligibilityRequired => {
+                "Insurance eligibility attestation is required for this record type"
+            }
+            ContractError::InvalidEligibility => {
+                "Eligibility attestation is invalid or already used"
+            }
+            ContractError::AdverseEventNotFound => "Adverse event report not found",
+            ContractError::LegalHoldActive => "Data is under an active legal hold",
+            ContractError::LegalHoldNotFound => "Legal hold not found",
+            ContractError::MissingRequiredField => "A required prescription field is missing",
+            ContractError::LocumNotFound => "Locum arrangement not found",
+            ContractError::RegistrationProofRequired => {
+                "A valid zk registration proof is required for this role"
+            }
+            ContractError::InvalidShareCredential => "Prescription share code or proof is invalid",
+            ContractError::PrescriptionExpired => "Prescription has expired",
+            ContractError::ActorFlagNotFound => "No security flag recorded for actor",
+            ContractError::CustomRecordTypeExists => "Custom record type code already registered",
+            ContractError::CustomRecordTypeNotFound => "Custom record type not registered",
+            ContractError::CohortGrantNotFound => "Cohort grant not found",
+            ContractError::StateCursorExpired => "Change cursor is older than the retained journal",
+            ContractError::ResponderNotFound => "Emergency responder not found in directory",
+            ContractError::ResponderAlreadyListed => {
+                "Emergency responder is already in the directory"
+            }
+            ContractError::AccessGrantNotFound => "Access grant not found",
+            ContractError::RestoreWindowClosed => "Revoked grant is past its restore window",
+            ContractError::AgreementNotFound => "Data-sharing agreement not found",
+            ContractError::AgreementNotActive => "Data-sharing agreement is not active",
+            ContractError::ReferralNotFound => "Referral not found",
+            ContractError::ResidencyNotAllowed => {
+                "Storage region not allowed by organization policy"
+            }
+            ContractError::QuotaExceeded => "Organization write quota exceeded",
+            ContractError::IncompatibleInterface => {
+                "Counterpart contract interface version is not supported"
+            }
+            ContractError::DataChallengeNotFound => "Data availability challenge not found",
+            ContractError::DataChallengeClosed => "Data availability challenge is no longer open",
+            ContractError::ConsentCampaignNotFound => "Consent campaign not found",
+            ContractError::ConsentCampaignClosed => "Consent campaign is closed",
+            ContractError::ClaimNotAdjudicated => {
+                "Claim has not been adjudicated for this prescription"
+            }
+            ContractError::ContactNotListed => "Emergency contact is not on the patient's list",
+            ContractError::InvalidPrintAuthorization => {
+                "Print authorization code is invalid, expired or used"
+            }
+            ContractError::FeatureDisabled => "This feature is disabled on this deployment",
+            ContractError::RegistrationNotPending => "No pending registration for this user",
+            ContractError::GrantDurationExceeded => "Grant would exceed the maximum total duration",
+            ContractError::ReferralNotPending => "Referral has already been accepted or declined",
+            ContractError::AdminTransferLocked => "Admin transfer delay has not elapsed",
+            ContractError::CareRelationshipNotFound => "Care relationship not found",
+            ContractError::GuardianshipNotFound => "Guardianship not found",
+            ContractError::OrganizationNotFound => "Organization not found",
+            ContractError::SigningKeyNotRegistered => "Provider has no registered signing key",
+            ContractError::CosignNotPending => "Record is not awaiting this supervisor's co-signature",
+            ContractError::SurgeryNotFound => "Surgical case not found",
+            ContractError::SurgeryStepOutOfOrder => "Surgical case is not at the step this action documents",
+            ContractError::LabResultNotFound => "Lab result not found",
+            ContractError::DeviceNotFound => "Imaging device not found",
+            ContractError::DeviceInactive => "Imaging device has been deactivated",
+            ContractError::EncryptionKeyNotFound => "Patient encryption key not found",
+            ContractError::EncryptionKeyExists => "Patient already registered a key with this id",
+            ContractError::ReencryptionGrantNotFound => "Re-encryption grant not found",
+            ContractError::ErasureAlreadyRequested => "Patient already requested erasure",
+            ContractError::ErasureNotPending => "No erasure request is awaiting execution",
+            ContractError::PracticeTransferNotFound => "Practice transfer not found",
+            ContractError::TransferOptOutClosed => "Opt-out window for this transfer has closed",
+            ContractError::TransferOptOutOpen => "Patients can still opt out of this transfer",
+            ContractError::PracticeTransferCompleted => "Practice transfer already carried out",
         }
     }
 }
@@ -1,12 +1,11 @@
-#![allow(deprecated)] // events().publish migration tracked separately
-
 use crate::appointment::AppointmentType;
 use crate::audit::{AccessAction, AccessResult, AuditEntry};
 use crate::circuit_breaker::PauseScope;
 use crate::emergency::EmergencyCondition;
 use crate::errors::{ErrorCategory, ErrorContext, ErrorSeverity};
 use crate::{AccessLevel, RecordType, Role, VerificationStatus};
-use soroban_sdk::{symbol_short, Address, Env, String};
+use soroban_sdk::{symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+use teye_common::event_redaction;
 
 /// Event published when the contract is initialized.
 #[soroban_sdk::contracttype]
@@ -22,6 +21,8 @@ pub struct InitializedEvent {
 pub struct AdminTransferProposedEvent {
     pub current_admin: Address,
     pub proposed_admin: Address,
+    /// Earliest time the proposed admin can accept the transfer
+    pub unlock_at: u64,
     pub timestamp: u64,
 }
 
@@ -49,7 +50,7 @@ pub struct AdminTransferCancelledEvent {
 pub struct UserRegisteredEvent {
     pub user: Address,
     pub role: Role,
-    pub name: String,
+    pub name_hash: BytesN<32>,
     pub timestamp: u64,
 }
 
@@ -64,6 +65,17 @@ pub struct RecordAddedEvent {
     pub timestamp: u64,
 }
 
+/// Event published when a provider amends a vision record.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordAmendedEvent {
+    pub record_id: u64,
+    pub patient: Address,
+    pub provider: Address,
+    pub version: u32,
+    pub timestamp: u64,
+}
+
 /// Event published when access is granted to a record.
 #[soroban_sdk::contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -98,6 +110,29 @@ pub struct AccessRevokedEvent {
     pub timestamp: u64,
 }
 
+/// Event published when a revoked grant is restored.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessRestoredEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub level: AccessLevel,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Event published when a live grant is extended or renewed.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessExtendedEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub previous_expires_at: u64,
+    pub expires_at: u64,
+    pub renewed: bool,
+    pub timestamp: u64,
+}
+
 /// Event published when revoking a grantee's access cascades to delegations they issued.
 #[soroban_sdk::contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -165,14 +200,20 @@ pub struct AccessViolationEvent {
     pub timestamp: u64,
 }
 
-pub fn publish_admin_transfer_proposed(env: &Env, current_admin: Address, proposed_admin: Address) {
+pub fn publish_admin_transfer_proposed(
+    env: &Env,
+    current_admin: Address,
+    proposed_admin: Address,
+    unlock_at: u64,
+) {
     let topics = (symbol_short!("ADM_PROP"), current_admin.clone());
     let data = AdminTransferProposedEvent {
         current_admin,
         proposed_admin,
+        unlock_at,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 pub fn publish_admin_transfer_accepted(env: &Env, old_admin: Address, new_admin: Address) {
@@ -182,7 +223,7 @@ pub fn publish_admin_transfer_accepted(env: &Env, old_admin: Address, new_admin:
         new_admin,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 pub fn publish_admin_transfer_cancelled(env: &Env, admin: Address, cancelled_proposed: Address) {
@@ -192,7 +233,7 @@ pub fn publish_admin_transfer_cancelled(env: &Env, admin: Address, cancelled_pro
         cancelled_proposed,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 pub fn publish_initialized(env: &Env, admin: Address) {
@@ -201,20 +242,79 @@ pub fn publish_initialized(env: &Env, admin: Address) {
         admin,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when a new user is registered.
-/// This event includes the user address, role, name, and registration timestamp.
-pub fn publish_user_registered(env: &Env, user: Address, role: Role, name: String) {
+/// This event includes the user address, role, salted name hash, and registration timestamp.
+pub fn publish_user_registered(env: &Env, user: Address, role: Role, name_hash: BytesN<32>) {
     let topics = (symbol_short!("USR_REG"), user.clone());
     let data = UserRegisteredEvent {
         user,
         role,
-        name,
+        name_hash,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when a provider registration is queued for approval.
+pub fn publish_registration_pending(env: &Env, pending: &crate::PendingRegistration) {
+    let topics = (symbol_short!("PRV_PEND"), pending.user.clone());
+    event_redaction::publish(env, topics, pending.clone());
+}
+
+/// Publishes an event when a pending provider registration is approved.
+pub fn publish_provider_approved(env: &Env, approver: Address, user: Address, role: Role) {
+    let topics = (symbol_short!("PRV_APPR"), user);
+    event_redaction::publish(env, topics, (approver, role, env.ledger().timestamp()));
+}
+
+/// Publishes an event when a pending provider registration is rejected.
+pub fn publish_provider_rejected(env: &Env, approver: Address, user: Address, role: Role) {
+    let topics = (symbol_short!("PRV_REJ"), user);
+    event_redaction::publish(env, topics, (approver, role, env.ledger().timestamp()));
+}
+
+/// Publishes an event when an admin changes a user's role.
+pub fn publish_user_role_updated(env: &Env, admin: Address, user: Address, old: Role, new: Role) {
+    let topics = (symbol_short!("USR_ROLE"), user);
+    event_redaction::publish(env, topics, (admin, old, new, env.ledger().timestamp()));
+}
+
+/// Publishes `USR_DEAC` or `USR_REAC` when an admin deactivates or
+/// reactivates a user.
+pub fn publish_user_active_changed(env: &Env, admin: Address, user: Address, active: bool) {
+    let name = if active {
+        symbol_short!("USR_REAC")
+    } else {
+        symbol_short!("USR_DEAC")
+    };
+    event_redaction::publish(env, (name, user), (admin, env.ledger().timestamp()));
+}
+
+/// Publishes an event when an address joins the admin set or has its
+/// admin permissions changed.
+pub fn publish_admin_added(
+    env: &Env,
+    caller: Address,
+    admin: Address,
+    permissions: Vec<crate::AdminPermission>,
+) {
+    let topics = (symbol_short!("ADM_ADD"), admin);
+    event_redaction::publish(env, topics, (caller, permissions, env.ledger().timestamp()));
+}
+
+/// Publishes an event when an address leaves the admin set.
+pub fn publish_admin_removed(env: &Env, caller: Address, admin: Address) {
+    let topics = (symbol_short!("ADM_REM"), admin);
+    event_redaction::publish(env, topics, (caller, env.ledger().timestamp()));
+}
+
+/// Publishes an event when `migrate_user_names` scrubs plaintext names.
+pub fn publish_user_names_scrubbed(env: &Env, admin: Address, scrubbed: u32) {
+    let topics = (symbol_short!("USR_SCRB"), admin);
+    event_redaction::publish(env, topics, (scrubbed, env.ledger().timestamp()));
 }
 
 /// Publishes an event when a new vision record is added.
@@ -234,7 +334,7 @@ pub fn publish_record_added(
         record_type,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when access is granted to a record.
@@ -256,7 +356,7 @@ pub fn publish_access_granted(
         expires_at,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 pub fn publish_record_access_granted(
@@ -283,7 +383,7 @@ pub fn publish_record_access_granted(
         expires_at,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when access to a record is revoked.
@@ -295,7 +395,49 @@ pub fn publish_access_revoked(env: &Env, patient: Address, grantee: Address) {
         grantee,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when the patient restores a grant they revoked.
+pub fn publish_access_restored(env: &Env, grant: &crate::AccessGrant) {
+    let topics = (
+        symbol_short!("ACC_RST"),
+        grant.patient.clone(),
+        grant.grantee.clone(),
+    );
+    let data = AccessRestoredEvent {
+        patient: grant.patient.clone(),
+        grantee: grant.grantee.clone(),
+        level: grant.level.clone(),
+        expires_at: grant.expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Published as `ACC_EXT` for `extend_access` and `ACC_RNW` for
+/// `renew_access`.
+pub fn publish_access_extended(
+    env: &Env,
+    grant: &crate::AccessGrant,
+    previous_expires_at: u64,
+    renewed: bool,
+) {
+    let name = if renewed {
+        symbol_short!("ACC_RNW")
+    } else {
+        symbol_short!("ACC_EXT")
+    };
+    let topics = (name, grant.patient.clone(), grant.grantee.clone());
+    let data = AccessExtendedEvent {
+        patient: grant.patient.clone(),
+        grantee: grant.grantee.clone(),
+        previous_expires_at,
+        expires_at: grant.expires_at,
+        renewed,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
 }
 
 pub fn publish_cascading_revocation(
@@ -318,7 +460,7 @@ pub fn publish_cascading_revocation(
         is_scoped,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 pub fn publish_batch_records_added(env: &Env, provider: Address, count: u32) {
@@ -328,7 +470,7 @@ pub fn publish_batch_records_added(env: &Env, provider: Address, count: u32) {
         count,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 pub fn publish_contract_paused(env: &Env, caller: Address, scope: PauseScope) {
@@ -338,7 +480,24 @@ pub fn publish_contract_paused(env: &Env, caller: Address, scope: PauseScope) {
         scope,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when degraded mode is switched on or off.
+pub fn publish_degraded_mode(env: &Env, caller: Address, enabled: bool) {
+    let topics = (symbol_short!("DEGRADE"),);
+    event_redaction::publish(env, topics, (caller, enabled, env.ledger().timestamp()));
+}
+
+/// Publishes an event when an operator switches a feature on or off.
+pub fn publish_feature_flag_set(
+    env: &Env,
+    caller: Address,
+    feature: crate::feature_flags::Feature,
+    enabled: bool,
+) {
+    let topics = (symbol_short!("FEAT_SET"), feature);
+    event_redaction::publish(env, topics, (caller, enabled, env.ledger().timestamp()));
 }
 
 pub fn publish_contract_resumed(env: &Env, caller: Address, scope: PauseScope) {
@@ -348,7 +507,7 @@ pub fn publish_contract_resumed(env: &Env, caller: Address, scope: PauseScope) {
         scope,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 pub fn publish_access_violation(
@@ -364,7 +523,7 @@ pub fn publish_access_violation(
         required_permission,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 pub fn publish_access_expired(env: &Env, patient: Address, grantee: Address, expired_at: u64) {
@@ -375,7 +534,7 @@ pub fn publish_access_expired(env: &Env, patient: Address, grantee: Address, exp
         expired_at,
         purged_at: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 /// Event published when a new provider is registered.
 #[soroban_sdk::contracttype]
@@ -423,7 +582,7 @@ pub fn publish_provider_registered(env: &Env, provider: Address, name: String, p
         provider_id,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when a provider's verification status is updated.
@@ -445,7 +604,7 @@ pub fn publish_provider_verified(
         status,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 pub fn publish_batch_access_granted(env: &Env, patient: Address, count: u32) {
@@ -455,7 +614,7 @@ pub fn publish_batch_access_granted(env: &Env, patient: Address, count: u32) {
         count,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when an examination is added.
@@ -466,7 +625,7 @@ pub fn publish_examination_added(env: &Env, record_id: u64) {
         record_id,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Event published when access is granted via meta-transaction.
@@ -502,7 +661,7 @@ pub fn publish_meta_access_granted(
         nonce,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Event published when consent is granted by a patient.
@@ -541,7 +700,7 @@ pub fn publish_consent_granted(
         expires_at,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when consent is revoked.
@@ -552,7 +711,26 @@ pub fn publish_consent_revoked(env: &Env, patient: Address, grantee: Address) {
         grantee,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes `CNS_REC` when a purpose consent is recorded and `CNS_WDR`
+/// when it is withdrawn.
+pub fn publish_purpose_consent_changed(env: &Env, consent: &crate::PurposeConsent) {
+    let name = if consent.withdrawn_at.is_none() {
+        symbol_short!("CNS_REC")
+    } else {
+        symbol_short!("CNS_WDR")
+    };
+    let topics = (name, consent.patient.clone(), consent.purpose);
+    event_redaction::publish(env, topics, consent.clone());
+}
+
+/// Publishes `ALL_REVK` when a patient revokes all access at once. This is
+/// the only event a lockdown emits; the summary carries the counts.
+pub fn publish_access_lockdown(env: &Env, summary: &crate::AccessLockdownSummary) {
+    let topics = (symbol_short!("ALL_REVK"), summary.patient.clone());
+    event_redaction::publish(env, topics, summary.clone());
 }
 
 /// Event published when a patient profile is created.
@@ -577,7 +755,7 @@ pub fn publish_profile_created(env: &Env, patient: Address) {
         patient,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 pub fn publish_profile_updated(env: &Env, patient: Address) {
@@ -586,7 +764,7 @@ pub fn publish_profile_updated(env: &Env, patient: Address) {
         patient,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Event published when an error occurs.
@@ -622,7 +800,7 @@ pub fn publish_error(env: &Env, error_code: u32, context: ErrorContext) {
         retryable: context.retryable,
         timestamp: context.timestamp,
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Event published when emergency access is granted.
@@ -647,6 +825,40 @@ pub struct EmergencyAccessRevokedEvent {
     pub timestamp: u64,
 }
 
+/// Event published when an emergency grant waits for patient acknowledgement.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyAccessPendingEvent {
+    pub access_id: u64,
+    pub patient: Address,
+    pub requester: Address,
+    pub condition: EmergencyCondition,
+    pub ack_deadline: u64,
+    pub timestamp: u64,
+}
+
+/// Event published when a pending emergency grant becomes usable.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyAccessActivatedEvent {
+    pub access_id: u64,
+    pub patient: Address,
+    /// Patient or custodian who acknowledged; `None` when the deadline passed
+    pub acknowledged_by: Option<Address>,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Event published when a patient updates notification preferences.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotificationPrefsSetEvent {
+    pub patient: Address,
+    pub require_emergency_ack: bool,
+    pub ack_window_seconds: u64,
+    pub timestamp: u64,
+}
+
 /// Event published when emergency contacts are notified.
 #[soroban_sdk::contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -690,7 +902,7 @@ pub fn publish_emergency_access_granted(
         expires_at,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when emergency access is revoked.
@@ -707,7 +919,64 @@ pub fn publish_emergency_access_revoked(
         revoker,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when an emergency grant is held for acknowledgement.
+pub fn publish_emergency_access_pending(
+    env: &Env,
+    access_id: u64,
+    patient: Address,
+    requester: Address,
+    condition: EmergencyCondition,
+    ack_deadline: u64,
+) {
+    let topics = (symbol_short!("EMRG_PND"), patient.clone(), requester.clone());
+    let data = EmergencyAccessPendingEvent {
+        access_id,
+        patient,
+        requester,
+        condition,
+        ack_deadline,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when a pending emergency grant activates.
+pub fn publish_emergency_access_activated(
+    env: &Env,
+    access_id: u64,
+    patient: Address,
+    acknowledged_by: Option<Address>,
+    expires_at: u64,
+) {
+    let topics = (symbol_short!("EMRG_ACT"), patient.clone());
+    let data = EmergencyAccessActivatedEvent {
+        access_id,
+        patient,
+        acknowledged_by,
+        expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when notification preferences change.
+pub fn publish_notification_prefs_set(
+    env: &Env,
+    patient: Address,
+    require_emergency_ack: bool,
+    ack_window_seconds: u64,
+) {
+    let topics = (symbol_short!("NTF_PREF"), patient.clone());
+    let data = NotificationPrefsSetEvent {
+        patient,
+        require_emergency_ack,
+        ack_window_seconds,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when an emergency contact is notified.
@@ -724,7 +993,7 @@ pub fn publish_emergency_contact_notified(
         contact,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when emergency access is used to access records.
@@ -747,7 +1016,7 @@ pub fn publish_emergency_access_used(
         record_id,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Event published when an appointment is created/scheduled.
@@ -848,7 +1117,7 @@ pub fn publish_appointment_scheduled(
         scheduled_at,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when an appointment is confirmed.
@@ -867,7 +1136,7 @@ pub fn publish_appointment_confirmed(
         confirmed_by,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when an appointment is cancelled.
@@ -886,7 +1155,7 @@ pub fn publish_appointment_cancelled(
         cancelled_by,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when an appointment is rescheduled.
@@ -913,7 +1182,7 @@ pub fn publish_appointment_rescheduled(
         rescheduled_by,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when an appointment is completed.
@@ -932,7 +1201,7 @@ pub fn publish_appointment_completed(
         completed_by,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when an appointment reminder is sent.
@@ -951,7 +1220,7 @@ pub fn publish_appointment_reminder(
         scheduled_at,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when an appointment is verified.
@@ -970,7 +1239,7 @@ pub fn publish_appointment_verified(
         verifier,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Event published when an audit log entry is created.
@@ -1004,7 +1273,7 @@ pub fn publish_audit_log_entry(env: &Env, entry: &AuditEntry) {
         reason: entry.reason.clone(),
         timestamp: entry.timestamp,
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Event published when rate limit is exceeded.
@@ -1058,7 +1327,7 @@ pub fn publish_rate_limit_exceeded(
         reset_at,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes a rate limit configuration updated event.
@@ -1077,7 +1346,7 @@ pub fn publish_rate_limit_config_updated(
         updated_by: updated_by.clone(),
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes a rate limit bypass updated event.
@@ -1094,7 +1363,7 @@ pub fn publish_rate_limit_bypass_updated(
         updated_by: updated_by.clone(),
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Event published when an access policy is created.
@@ -1134,7 +1403,7 @@ pub fn publish_policy_created(env: &Env, policy_id: String, created_by: Address)
         created_by,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when a user credential is set.
@@ -1151,7 +1420,7 @@ pub fn publish_credential_set(
         set_by,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
 }
 
 /// Publishes an event when a record sensitivity level is set.
@@ -1168,5 +1437,1449 @@ pub fn publish_sensitivity_set(
         set_by,
         timestamp: env.ledger().timestamp(),
     };
-    env.events().publish(topics, data);
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when a retention policy is configured.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetentionPolicySetEvent {
+    pub record_type: crate::RecordType,
+    pub retain_seconds: u64,
+    pub enabled: bool,
+    pub set_by: Address,
+    pub timestamp: u64,
+}
+
+/// Event published when a patient changes their retention opt-out.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetentionOptOutEvent {
+    pub patient: Address,
+    pub opted_out: bool,
+    pub timestamp: u64,
+}
+
+/// Event published when the retention sweeper archives a record.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordArchivedEvent {
+    pub record_id: u64,
+    pub patient: Address,
+    pub record_type: crate::RecordType,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a retention policy is configured.
+pub fn publish_retention_policy_set(
+    env: &Env,
+    record_type: crate::RecordType,
+    retain_seconds: u64,
+    enabled: bool,
+    set_by: Address,
+) {
+    let topics = (symbol_short!("RET_SET"),);
+    let data = RetentionPolicySetEvent {
+        record_type,
+        retain_seconds,
+        enabled,
+        set_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when a patient opts in or out of retention archival.
+pub fn publish_retention_opt_out(env: &Env, patient: Address, opted_out: bool) {
+    let topics = (symbol_short!("RET_OPT"), patient.clone());
+    let data = RetentionOptOutEvent {
+        patient,
+        opted_out,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when a record is compacted into an archival summary.
+pub fn publish_record_archived(
+    env: &Env,
+    record_id: u64,
+    patient: Address,
+    record_type: crate::RecordType,
+) {
+    let topics = (symbol_short!("REC_ARCH"), patient.clone());
+    let data = RecordArchivedEvent {
+        record_id,
+        patient,
+        record_type,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when a record archived with `archive_record` is restored.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordRestoredEvent {
+    pub record_id: u64,
+    pub restored_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a patient or admin archives a record.
+pub fn publish_record_tombstoned(env: &Env, patient: Address, tombstone: &crate::RecordTombstone) {
+    let topics = (symbol_short!("REC_TOMB"), patient);
+    event_redaction::publish(env, topics, tombstone.clone());
+}
+
+/// Publishes an event when an archived record is restored.
+pub fn publish_record_restored(env: &Env, patient: Address, record_id: u64, restored_by: Address) {
+    let topics = (symbol_short!("REC_UNTB"), patient);
+    let data = RecordRestoredEvent {
+        record_id,
+        restored_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when the eligibility pre-check is toggled for a record type.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EligibilityRequirementSetEvent {
+    pub record_type: crate::RecordType,
+    pub required: bool,
+    pub set_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when the eligibility pre-check is toggled for a record type.
+pub fn publish_eligibility_requirement_set(
+    env: &Env,
+    record_type: crate::RecordType,
+    required: bool,
+    set_by: Address,
+) {
+    let topics = (symbol_short!("ELIG_SET"),);
+    let data = EligibilityRequirementSetEvent {
+        record_type,
+        required,
+        set_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when a patient updates their emergency policy.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyPolicySetEvent {
+    pub patient: Address,
+    pub default_scope: soroban_sdk::Vec<RecordType>,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a patient updates their emergency policy.
+pub fn publish_emergency_policy_set(
+    env: &Env,
+    patient: Address,
+    default_scope: soroban_sdk::Vec<RecordType>,
+) {
+    let topics = (symbol_short!("EMRG_POL"), patient.clone());
+    let data = EmergencyPolicySetEvent {
+        patient,
+        default_scope,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when a patient updates their emergency contact list.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyContactsSetEvent {
+    pub patient: Address,
+    pub contacts: soroban_sdk::Vec<Address>,
+    pub listed_only: bool,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a patient updates their emergency contact list.
+pub fn publish_emergency_contacts_set(
+    env: &Env,
+    patient: Address,
+    contacts: soroban_sdk::Vec<Address>,
+    listed_only: bool,
+) {
+    let topics = (symbol_short!("EMRG_CNT"), patient.clone());
+    let data = EmergencyContactsSetEvent {
+        patient,
+        contacts,
+        listed_only,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when a diagnosis code is attached to a record.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiagnosisCodeAddedEvent {
+    pub record_id: u64,
+    pub patient: Address,
+    pub code: String,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a diagnosis code is attached to a record.
+pub fn publish_diagnosis_code_added(env: &Env, record_id: u64, patient: Address, code: String) {
+    let topics = (symbol_short!("DX_ADD"), patient.clone());
+    let data = DiagnosisCodeAddedEvent {
+        record_id,
+        patient,
+        code,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes `DX_DET` when a structured diagnosis is set on a record.
+pub fn publish_diagnosis_detail_set(env: &Env, patient: Address, detail: &crate::DiagnosisDetail) {
+    let topics = (symbol_short!("DX_DET"), patient, detail.record_id);
+    event_redaction::publish(env, topics, detail.clone());
+}
+
+/// Publishes `MSR_ADD` when a clinical measurement is added to a patient's
+/// series.
+pub fn publish_measurement_recorded(env: &Env, patient: Address, measurement: &crate::Measurement) {
+    let topics = (symbol_short!("MSR_ADD"), patient, measurement.measure_type);
+    event_redaction::publish(env, topics, measurement.clone());
+}
+
+/// Publishes `SRG_STEP` when a surgical case is scheduled or moves to its
+/// next documented step.
+pub fn publish_surgery_step(env: &Env, case: &crate::SurgicalCase) {
+    let topics = (symbol_short!("SRG_STEP"), case.patient.clone(), case.id);
+    event_redaction::publish(env, topics, (case.status, case.surgeon.clone()));
+}
+
+/// Publishes `LAB_RES` when a lab submits a result. The ordering provider
+/// is a topic so they are notified of results they ordered.
+pub fn publish_lab_result_submitted(env: &Env, result: &crate::LabResult) {
+    let topics = (
+        symbol_short!("LAB_RES"),
+        result.ordering_provider.clone(),
+        result.patient.clone(),
+    );
+    event_redaction::publish(env, topics, result.clone());
+}
+
+/// Publishes `DEV_REG` when an imaging device is registered to an
+/// organization or deactivated.
+pub fn publish_device_changed(env: &Env, device: &crate::ImagingDevice) {
+    let topics = (symbol_short!("DEV_REG"), device.org_id, device.id);
+    event_redaction::publish(env, topics, (device.active, device.public_key.clone()));
+}
+
+/// Publishes `PKEY_REG` when a patient registers an encryption key.
+pub fn publish_encryption_key_registered(env: &Env, key: &crate::PatientEncryptionKey) {
+    let topics = (symbol_short!("PKEY_REG"), key.patient.clone(), key.key_id);
+    event_redaction::publish(env, topics, key.public_key.clone());
+}
+
+/// Publishes `PKEY_ROT` when a patient rotates to a new current key.
+/// Records under the previous key are pending re-encryption from then on.
+pub fn publish_encryption_key_rotated(
+    env: &Env,
+    patient: Address,
+    previous_key_id: Option<u32>,
+    new_key_id: u32,
+) {
+    let topics = (symbol_short!("PKEY_ROT"), patient);
+    event_redaction::publish(env, topics, (previous_key_id, new_key_id));
+}
+
+/// Publishes `REC_RENC` when a record is marked re-encrypted under the
+/// patient's current key.
+pub fn publish_record_reencrypted(env: &Env, patient: Address, record_id: u64, key_id: u32) {
+    let topics = (symbol_short!("REC_RENC"), patient, record_id);
+    event_redaction::publish(env, topics, key_id);
+}
+
+/// Publishes `REENC_GR` when a patient hands a grantee key material for a
+/// record. Only the pointer's presence is announced, not the pointer.
+pub fn publish_reencryption_granted(env: &Env, grant: &crate::ReencryptionGrant) {
+    let topics = (
+        symbol_short!("REENC_GR"),
+        grant.patient.clone(),
+        grant.grantee.clone(),
+    );
+    event_redaction::publish(env, topics, (grant.record_id, grant.key_id));
+}
+
+/// Publishes `REENC_RV` when a patient withdraws a grantee's key material.
+pub fn publish_reencryption_revoked(
+    env: &Env,
+    patient: Address,
+    grantee: Address,
+    record_id: u64,
+) {
+    let topics = (symbol_short!("REENC_RV"), patient, grantee);
+    event_redaction::publish(env, topics, record_id);
+}
+
+/// Publishes `ERASE_REQ` when a patient files an erasure request.
+pub fn publish_erasure_requested(env: &Env, patient: Address) {
+    let topics = (symbol_short!("ERASE_REQ"), patient);
+    event_redaction::publish(env, topics, env.ledger().timestamp());
+}
+
+/// Publishes `ERASED` once an erasure request has been carried out.
+pub fn publish_erasure_executed(env: &Env, request: &crate::ErasureRequest) {
+    let topics = (symbol_short!("ERASED"), request.patient.clone());
+    event_redaction::publish(
+        env,
+        topics,
+        (request.record_hashes.len(), request.measurements_removed),
+    );
+}
+
+/// Publishes `PXF_SCHD` to each patient covered by a scheduled practice
+/// transfer, so they can opt out before `opt_out_deadline`.
+pub fn publish_practice_transfer_scheduled(
+    env: &Env,
+    transfer: &crate::PracticeTransfer,
+    patient: Address,
+) {
+    let topics = (symbol_short!("PXF_SCHD"), patient, transfer.id);
+    let data = (
+        transfer.closing_provider.clone(),
+        transfer.receiving_provider.clone(),
+        transfer.opt_out_deadline,
+    );
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes `PXF_OPT` when a patient opts out of a practice transfer.
+pub fn publish_practice_transfer_opted_out(env: &Env, transfer_id: u64, patient: Address) {
+    let topics = (symbol_short!("PXF_OPT"), patient, transfer_id);
+    event_redaction::publish(env, topics, env.ledger().timestamp());
+}
+
+/// Publishes `PXF_PAT` for each patient whose records moved when a
+/// practice transfer was carried out.
+pub fn publish_patient_records_transferred(
+    env: &Env,
+    transfer: &crate::PracticeTransfer,
+    patient: Address,
+    records_moved: u32,
+) {
+    let topics = (symbol_short!("PXF_PAT"), patient, transfer.id);
+    let data = (transfer.receiving_provider.clone(), records_moved);
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when an adverse event report is filed. Carries no
+/// patient or reporter identity.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdverseEventReportedEvent {
+    pub report_id: u64,
+    pub product_id: String,
+    pub severity: crate::AdverseEventSeverity,
+    pub timestamp: u64,
+}
+
+/// Event published when a regulator acknowledges an adverse event report.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdverseEventAcknowledgedEvent {
+    pub report_id: u64,
+    pub regulator: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when an adverse event report is filed.
+pub fn publish_adverse_event_reported(
+    env: &Env,
+    report_id: u64,
+    product_id: String,
+    severity: crate::AdverseEventSeverity,
+) {
+    let topics = (symbol_short!("AE_RPT"), product_id.clone());
+    let data = AdverseEventReportedEvent {
+        report_id,
+        product_id,
+        severity,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when a regulator acknowledges an adverse event report.
+pub fn publish_adverse_event_acknowledged(env: &Env, report_id: u64, regulator: Address) {
+    let topics = (symbol_short!("AE_ACK"), regulator.clone());
+    let data = AdverseEventAcknowledgedEvent {
+        report_id,
+        regulator,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when a legal hold is placed or lifted.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegalHoldEvent {
+    pub hold_id: u64,
+    pub target: crate::LegalHoldTarget,
+    pub case_ref_hash: soroban_sdk::BytesN<32>,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a legal hold is placed.
+pub fn publish_legal_hold_placed(env: &Env, hold: &crate::LegalHold) {
+    let topics = (symbol_short!("LH_PLACE"), hold.id);
+    let data = LegalHoldEvent {
+        hold_id: hold.id,
+        target: hold.target.clone(),
+        case_ref_hash: hold.case_ref_hash.clone(),
+        actor: hold.placed_by.clone(),
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when a legal hold is lifted.
+pub fn publish_legal_hold_lifted(env: &Env, hold: &crate::LegalHold, lifted_by: Address) {
+    let topics = (symbol_short!("LH_LIFT"), hold.id);
+    let data = LegalHoldEvent {
+        hold_id: hold.id,
+        target: hold.target.clone(),
+        case_ref_hash: hold.case_ref_hash.clone(),
+        actor: lifted_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when a prescription is added or matched as a duplicate.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrescriptionAddedEvent {
+    pub rx_id: u64,
+    pub patient: Address,
+    pub provider: Address,
+    pub canonical_hash: soroban_sdk::BytesN<32>,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a new prescription is stored.
+pub fn publish_prescription_added(env: &Env, rx: &crate::Prescription) {
+    let topics = (symbol_short!("RX_ADD"), rx.patient.clone());
+    let data = PrescriptionAddedEvent {
+        rx_id: rx.id,
+        patient: rx.patient.clone(),
+        provider: rx.provider.clone(),
+        canonical_hash: rx.canonical_hash.clone(),
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when a second provider enters a prescription that
+/// converges onto an existing one. `provider` is the provider whose entry was
+/// folded into `rx`.
+pub fn publish_prescription_duplicate(env: &Env, rx: &crate::Prescription, provider: Address) {
+    let topics = (symbol_short!("RX_DUP"), rx.patient.clone());
+    let data = PrescriptionAddedEvent {
+        rx_id: rx.id,
+        patient: rx.patient.clone(),
+        provider,
+        canonical_hash: rx.canonical_hash.clone(),
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when locum cover is arranged or cancelled.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocumArrangementEvent {
+    pub arrangement_id: u64,
+    pub absent_provider: Address,
+    pub covering_provider: Address,
+    pub starts_at: u64,
+    pub ends_at: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a provider designates a covering provider.
+pub fn publish_locum_designated(env: &Env, arrangement: &crate::LocumArrangement) {
+    let topics = (
+        symbol_short!("LOC_SET"),
+        arrangement.absent_provider.clone(),
+        arrangement.covering_provider.clone(),
+    );
+    event_redaction::publish(env, topics, locum_event(env, arrangement));
+}
+
+/// Publishes an event when locum cover is cancelled before it ends.
+pub fn publish_locum_cancelled(env: &Env, arrangement: &crate::LocumArrangement) {
+    let topics = (
+        symbol_short!("LOC_END"),
+        arrangement.absent_provider.clone(),
+        arrangement.covering_provider.clone(),
+    );
+    event_redaction::publish(env, topics, locum_event(env, arrangement));
+}
+
+fn locum_event(env: &Env, arrangement: &crate::LocumArrangement) -> LocumArrangementEvent {
+    LocumArrangementEvent {
+        arrangement_id: arrangement.id,
+        absent_provider: arrangement.absent_provider.clone(),
+        covering_provider: arrangement.covering_provider.clone(),
+        starts_at: arrangement.starts_at,
+        ends_at: arrangement.ends_at,
+        timestamp: env.ledger().timestamp(),
+    }
+}
+
+/// Publishes an event when a provider authorizes a paper copy of a
+/// prescription.
+pub fn publish_print_authorized(env: &Env, auth: &crate::PrintAuthorization) {
+    let topics = (symbol_short!("PRT_ISS"), auth.rx_id, auth.provider.clone());
+    event_redaction::publish(env, topics, (auth.id, auth.expires_at));
+}
+
+/// Publishes an event when a kiosk consumes a print authorization.
+pub fn publish_print_redeemed(env: &Env, auth: &crate::PrintAuthorization) {
+    let topics = (symbol_short!("PRT_RDM"), auth.rx_id, auth.patient.clone());
+    event_redaction::publish(env, topics, auth.clone());
+}
+
+/// Publishes an event when a covering provider reads a patient's record
+/// through an absent provider's grant. The patient is a topic so wallets
+/// can surface the disclosure.
+pub fn publish_locum_access(env: &Env, patient: Address, disclosure: &crate::LocumDisclosure) {
+    let topics = (
+        symbol_short!("LOC_READ"),
+        patient,
+        disclosure.covering_provider.clone(),
+    );
+    event_redaction::publish(env, topics, disclosure.clone());
+}
+
+/// Publishes an event when a Restricted record is read by someone other
+/// than the patient. The patient is a topic so wallets can surface it.
+pub fn publish_read_receipt(env: &Env, patient: Address, receipt: &crate::ReadReceipt) {
+    let topics = (symbol_short!("SENS_READ"), patient, receipt.reader.clone());
+    event_redaction::publish(env, topics, receipt.clone());
+}
+
+/// Publishes an event for each entry added to a record's read log.
+pub fn publish_record_read(env: &Env, patient: Address, entry: &crate::RecordReadEntry) {
+    let topics = (symbol_short!("REC_READ"), patient, entry.reader.clone());
+    event_redaction::publish(env, topics, entry.clone());
+}
+
+/// Publishes `REC_ATCH` when an artifact is attached to a record.
+pub fn publish_attachment_added(
+    env: &Env,
+    patient: Address,
+    attachment: &crate::RecordAttachment,
+) {
+    let topics = (symbol_short!("REC_ATCH"), patient, attachment.record_id);
+    event_redaction::publish(env, topics, attachment.clone());
+}
+
+/// Publishes `FHIR_ANC` when a record is anchored as a FHIR resource.
+pub fn publish_fhir_anchored(env: &Env, patient: Address, envelope: &crate::FhirEnvelope) {
+    let topics = (symbol_short!("FHIR_ANC"), patient, envelope.record_id);
+    event_redaction::publish(env, topics, envelope.clone());
+}
+
+/// Publishes `SIG_KEY` when a provider registers or replaces the Ed25519
+/// key they sign records with.
+pub fn publish_signing_key_registered(env: &Env, provider: Address, public_key: &BytesN<32>) {
+    let topics = (symbol_short!("SIG_KEY"), provider);
+    event_redaction::publish(env, topics, public_key.clone());
+}
+
+/// Publishes `MTA_KEY` when a patient registers or replaces the key they
+/// sign sponsored grants with.
+pub fn publish_meta_signer_registered(env: &Env, patient: Address, public_key: &BytesN<32>) {
+    let topics = (symbol_short!("MTA_KEY"), patient);
+    event_redaction::publish(env, topics, public_key.clone());
+}
+
+/// Publishes `REC_SIGN` when a provider's signature over a record is
+/// verified and stored.
+pub fn publish_record_signed(env: &Env, patient: Address, signature: &crate::RecordSignature) {
+    let topics = (symbol_short!("REC_SIGN"), patient, signature.record_id);
+    event_redaction::publish(env, topics, signature.clone());
+}
+
+/// Publishes `COS_REQ` when a record is sent to a supervisor for
+/// co-signature. The supervisor is a topic so they can watch their queue.
+pub fn publish_cosign_requested(env: &Env, request: &crate::CosignRequest) {
+    let topics = (
+        symbol_short!("COS_REQ"),
+        request.supervisor.clone(),
+        request.record_id,
+    );
+    event_redaction::publish(env, topics, request.clone());
+}
+
+/// Publishes `COS_DONE` when a supervisor co-signs a record.
+pub fn publish_record_cosigned(env: &Env, request: &crate::CosignRequest) {
+    let topics = (
+        symbol_short!("COS_DONE"),
+        request.requested_by.clone(),
+        request.record_id,
+    );
+    event_redaction::publish(env, topics, request.clone());
+}
+
+/// Publishes an event for each integrity check of a record against an
+/// off-chain document, matched or not.
+pub fn publish_integrity_attested(
+    env: &Env,
+    patient: Address,
+    attestation: &crate::IntegrityAttestation,
+) {
+    let topics = (
+        symbol_short!("REC_ATST"),
+        patient,
+        attestation.attester.clone(),
+    );
+    event_redaction::publish(env, topics, attestation.clone());
+}
+
+/// Publishes an event when a duplicate patient is merged into a primary
+/// address, so downstream systems can re-point their references.
+pub fn publish_patients_merged(env: &Env, merge: &crate::PatientMerge) {
+    let topics = (
+        symbol_short!("PAT_MRG"),
+        merge.primary.clone(),
+        merge.duplicate.clone(),
+    );
+    event_redaction::publish(env, topics, merge.clone());
+}
+
+/// Event published when a retired address is aliased to its replacement.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AddressAliasedEvent {
+    pub old: Address,
+    pub current: Address,
+    pub registered_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when an address alias is registered.
+pub fn publish_address_aliased(env: &Env, old: Address, current: Address, registered_by: Address) {
+    let topics = (symbol_short!("ALIAS"), old.clone(), current.clone());
+    let data = AddressAliasedEvent {
+        old,
+        current,
+        registered_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when a role's self-registration proof requirement changes.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistrationProofSetEvent {
+    pub role: Role,
+    pub resource_id: Option<BytesN<32>>,
+    pub max_proof_age: u64,
+    pub set_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a role's registration proof requirement is set or cleared.
+pub fn publish_registration_proof_set(
+    env: &Env,
+    role: Role,
+    resource_id: Option<BytesN<32>>,
+    max_proof_age: u64,
+    set_by: Address,
+) {
+    let topics = (symbol_short!("REG_PRF"), role.clone());
+    let data = RegistrationProofSetEvent {
+        role,
+        resource_id,
+        max_proof_age,
+        set_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published once for a whole bulk or template access grant.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BulkAccessGrantedEvent {
+    pub patient: Address,
+    pub grantees: soroban_sdk::Vec<Address>,
+    pub levels: soroban_sdk::Vec<AccessLevel>,
+    pub template: Option<soroban_sdk::Symbol>,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes a single event covering every grant made by a bulk call.
+pub fn publish_bulk_access_granted(
+    env: &Env,
+    patient: Address,
+    grants: &soroban_sdk::Vec<(Address, AccessLevel)>,
+    template: Option<soroban_sdk::Symbol>,
+    expires_at: u64,
+) {
+    let mut grantees = soroban_sdk::Vec::new(env);
+    let mut levels = soroban_sdk::Vec::new(env);
+    for (grantee, level) in grants.iter() {
+        grantees.push_back(grantee);
+        levels.push_back(level);
+    }
+    let topics = (symbol_short!("BULK_ACC"), patient.clone());
+    let data = BulkAccessGrantedEvent {
+        patient,
+        grantees,
+        levels,
+        template,
+        expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when a patient sets or clears a grantee's daily access window.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessWindowSetEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub window: Option<crate::AccessWindow>,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a grantee's daily access window changes.
+pub fn publish_access_window_set(
+    env: &Env,
+    patient: Address,
+    grantee: Address,
+    window: Option<crate::AccessWindow>,
+) {
+    let topics = (symbol_short!("ACC_WIN"), patient.clone(), grantee.clone());
+    let data = AccessWindowSetEvent {
+        patient,
+        grantee,
+        window,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when a retailer consumes a patient's prescription data.
+pub fn publish_prescription_shared(env: &Env, receipt: &crate::PrescriptionConsentReceipt) {
+    let topics = (
+        symbol_short!("RX_SHARE"),
+        receipt.patient.clone(),
+        receipt.rx_id,
+    );
+    event_redaction::publish(env, topics, receipt.clone());
+}
+
+/// Event published when a prescription passes its expiry date.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrescriptionExpiredEvent {
+    pub rx_id: u64,
+    pub patient: Address,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a prescription is marked expired.
+pub fn publish_prescription_expired(env: &Env, rx: &crate::Prescription) {
+    let topics = (symbol_short!("RX_EXP"), rx.patient.clone());
+    let data = PrescriptionExpiredEvent {
+        rx_id: rx.id,
+        patient: rx.patient.clone(),
+        expires_at: rx.expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when an actor trips an anomaly rule.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SecurityFlagEvent {
+    pub actor: Address,
+    pub kind: crate::AnomalyKind,
+    pub count: u32,
+    pub threshold: u32,
+    pub timestamp: u64,
+}
+
+/// Publishes a `SECURITY` event when an actor is flagged for review.
+pub fn publish_security_flag(env: &Env, flag: &crate::ActorFlag) {
+    let topics = (symbol_short!("SECURITY"), flag.actor.clone());
+    let data = SecurityFlagEvent {
+        actor: flag.actor.clone(),
+        kind: flag.kind.clone(),
+        count: flag.count,
+        threshold: flag.threshold,
+        timestamp: flag.flagged_at,
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when a custom record type is registered or retired.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomRecordTypeEvent {
+    pub code: u32,
+    pub label_hash: BytesN<32>,
+    pub active: bool,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when an admin registers or retires a custom record type.
+pub fn publish_custom_record_type(env: &Env, custom: &crate::CustomRecordType, admin: Address) {
+    let topics = (symbol_short!("REC_TYPE"), custom.code);
+    let data = CustomRecordTypeEvent {
+        code: custom.code,
+        label_hash: custom.label_hash.clone(),
+        active: custom.active,
+        admin,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when a cohort grant is issued or revoked.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CohortGrantEvent {
+    pub grant_id: u64,
+    pub supervisor: Address,
+    pub resident: Address,
+    pub actor: Address,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a supervisor is granted read access to a
+/// resident's records.
+pub fn publish_cohort_granted(env: &Env, grant: &crate::CohortGrant) {
+    let topics = (
+        symbol_short!("COH_GRT"),
+        grant.supervisor.clone(),
+        grant.resident.clone(),
+    );
+    let data = CohortGrantEvent {
+        grant_id: grant.id,
+        supervisor: grant.supervisor.clone(),
+        resident: grant.resident.clone(),
+        actor: grant.granted_by.clone(),
+        expires_at: grant.expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when a supervisor's access to one resident is revoked.
+pub fn publish_cohort_revoked(env: &Env, grant: &crate::CohortGrant, revoked_by: Address) {
+    let topics = (
+        symbol_short!("COH_REV"),
+        grant.supervisor.clone(),
+        grant.resident.clone(),
+    );
+    let data = CohortGrantEvent {
+        grant_id: grant.id,
+        supervisor: grant.supervisor.clone(),
+        resident: grant.resident.clone(),
+        actor: revoked_by,
+        expires_at: grant.expires_at,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when a supervisor reads a patient's record under a
+/// cohort grant, with the patient as a topic so it can be surfaced to them.
+pub fn publish_cohort_access(env: &Env, patient: Address, disclosure: &crate::CohortDisclosure) {
+    let topics = (
+        symbol_short!("COH_READ"),
+        patient,
+        disclosure.supervisor.clone(),
+    );
+    event_redaction::publish(env, topics, disclosure.clone());
+}
+
+/// Event published when an emergency responder directory entry changes.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResponderDirectoryEvent {
+    pub membership_id: u64,
+    pub responder: Address,
+    pub facility_hash: BytesN<32>,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a responder is added to the directory.
+pub fn publish_responder_listed(env: &Env, entry: &crate::ResponderEntry) {
+    let topics = (symbol_short!("RSP_ADD"), entry.responder.clone());
+    let data = ResponderDirectoryEvent {
+        membership_id: entry.id,
+        responder: entry.responder.clone(),
+        facility_hash: entry.facility_hash.clone(),
+        actor: entry.added_by.clone(),
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when a responder is removed from the directory.
+pub fn publish_responder_unlisted(env: &Env, entry: &crate::ResponderEntry, removed_by: Address) {
+    let topics = (symbol_short!("RSP_REM"), entry.responder.clone());
+    let data = ResponderDirectoryEvent {
+        membership_id: entry.id,
+        responder: entry.responder.clone(),
+        facility_hash: entry.facility_hash.clone(),
+        actor: removed_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Event published when a data-sharing agreement changes state.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgreementEvent {
+    pub agreement_id: u64,
+    pub patient: Address,
+    pub counterparty: Address,
+    pub terms_hash: BytesN<32>,
+    pub revoked_grants: u32,
+    pub timestamp: u64,
+}
+
+fn agreement_event(
+    env: &Env,
+    agreement: &crate::DataSharingAgreement,
+    revoked_grants: u32,
+) -> AgreementEvent {
+    AgreementEvent {
+        agreement_id: agreement.id,
+        patient: agreement.patient.clone(),
+        counterparty: agreement.counterparty.clone(),
+        terms_hash: agreement.terms_hash.clone(),
+        revoked_grants,
+        timestamp: env.ledger().timestamp(),
+    }
+}
+
+/// Publishes an event when a patient proposes a data-sharing agreement.
+pub fn publish_agreement_proposed(env: &Env, agreement: &crate::DataSharingAgreement) {
+    let topics = (
+        symbol_short!("AGR_PROP"),
+        agreement.patient.clone(),
+        agreement.counterparty.clone(),
+    );
+    event_redaction::publish(env, topics, agreement_event(env, agreement, 0));
+}
+
+/// Publishes an event when the counterparty countersigns an agreement.
+pub fn publish_agreement_signed(env: &Env, agreement: &crate::DataSharingAgreement) {
+    let topics = (
+        symbol_short!("AGR_SIGN"),
+        agreement.patient.clone(),
+        agreement.counterparty.clone(),
+    );
+    event_redaction::publish(env, topics, agreement_event(env, agreement, 0));
+}
+
+/// Publishes an event when an agreement is terminated, with the number of
+/// linked grants revoked alongside it.
+pub fn publish_agreement_terminated(
+    env: &Env,
+    agreement: &crate::DataSharingAgreement,
+    revoked_grants: u32,
+) {
+    let topics = (
+        symbol_short!("AGR_TERM"),
+        agreement.patient.clone(),
+        agreement.counterparty.clone(),
+    );
+    event_redaction::publish(env, topics, agreement_event(env, agreement, revoked_grants));
+}
+
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AvailabilityEvent {
+    pub provider: Address,
+    pub status: crate::AvailabilityStatus,
+    pub until: u64,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a provider changes their availability.
+pub fn publish_availability_set(env: &Env, availability: &crate::ProviderAvailability) {
+    let topics = (symbol_short!("AVL_SET"), availability.provider.clone());
+    let data = AvailabilityEvent {
+        provider: availability.provider.clone(),
+        status: availability.status.clone(),
+        until: availability.until,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralRoutedEvent {
+    pub referral_id: u64,
+    pub patient: Address,
+    pub requested_provider: Address,
+    pub assigned_provider: Address,
+    pub arrangement_id: Option<u64>,
+    pub queued_until: Option<u64>,
+    pub timestamp: u64,
+}
+
+/// Event published when the assigned provider accepts or declines a referral.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferralRespondedEvent {
+    pub referral_id: u64,
+    pub patient: Address,
+    pub provider: Address,
+    pub status: crate::ReferralStatus,
+    pub timestamp: u64,
+}
+
+/// Publishes an event for a new referral. The topic tells apart referrals
+/// sent straight through (`REF_NEW`), rerouted to locum cover (`REF_RRT`)
+/// and queued for an away provider (`REF_QUE`).
+pub fn publish_referral_created(env: &Env, referral: &crate::Referral) {
+    let name = if referral.queued_until.is_some() {
+        symbol_short!("REF_QUE")
+    } else if referral.arrangement_id.is_some() {
+        symbol_short!("REF_RRT")
+    } else {
+        symbol_short!("REF_NEW")
+    };
+    let topics = (
+        name,
+        referral.patient.clone(),
+        referral.assigned_provider.clone(),
+    );
+    let data = ReferralRoutedEvent {
+        referral_id: referral.id,
+        patient: referral.patient.clone(),
+        requested_provider: referral.requested_provider.clone(),
+        assigned_provider: referral.assigned_provider.clone(),
+        arrangement_id: referral.arrangement_id,
+        queued_until: referral.queued_until,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes `ORG_REG` when an organization is registered.
+pub fn publish_organization_registered(env: &Env, org: &crate::Organization) {
+    let topics = (symbol_short!("ORG_REG"), org.id, org.admin.clone());
+    event_redaction::publish(env, topics, org.clone());
+}
+
+/// Event published when a provider joins or leaves an organization.
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgMembershipEvent {
+    pub org_id: u64,
+    pub provider: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes `ORG_ADD` or `ORG_REM` as a provider joins or leaves.
+pub fn publish_org_membership_changed(env: &Env, org_id: u64, provider: Address, added: bool) {
+    let name = if added {
+        symbol_short!("ORG_ADD")
+    } else {
+        symbol_short!("ORG_REM")
+    };
+    let topics = (name, org_id, provider.clone());
+    let data = OrgMembershipEvent {
+        org_id,
+        provider,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes `ORG_GRT` or `ORG_RVK` when a patient grants or revokes an
+/// organization's access.
+pub fn publish_org_access_changed(env: &Env, grant: &crate::OrgAccessGrant, granted: bool) {
+    let name = if granted {
+        symbol_short!("ORG_GRT")
+    } else {
+        symbol_short!("ORG_RVK")
+    };
+    let topics = (name, grant.patient.clone(), grant.org_id);
+    event_redaction::publish(env, topics, grant.clone());
+}
+
+/// Publishes `GRD_REG` when a guardian is registered for a dependent and
+/// `GRD_END` when the guardianship is ended early.
+pub fn publish_guardianship_changed(env: &Env, guardianship: &crate::Guardianship) {
+    let name = if guardianship.ended_at.is_none() {
+        symbol_short!("GRD_REG")
+    } else {
+        symbol_short!("GRD_END")
+    };
+    let topics = (
+        name,
+        guardianship.dependent.clone(),
+        guardianship.guardian.clone(),
+    );
+    event_redaction::publish(env, topics, guardianship.clone());
+}
+
+/// Publishes `CARE_EST` when a provider joins a patient's care team and
+/// `CARE_END` when the relationship ends.
+pub fn publish_care_relationship_changed(env: &Env, rel: &crate::CareRelationship) {
+    let name = if rel.is_active() {
+        symbol_short!("CARE_EST")
+    } else {
+        symbol_short!("CARE_END")
+    };
+    let topics = (name, rel.patient.clone(), rel.provider.clone());
+    event_redaction::publish(env, topics, rel.clone());
+}
+
+/// Publishes `REF_ACC` or `REF_DEC` when a referral is answered.
+pub fn publish_referral_responded(env: &Env, referral: &crate::Referral) {
+    let name = if referral.status == crate::ReferralStatus::Accepted {
+        symbol_short!("REF_ACC")
+    } else {
+        symbol_short!("REF_DEC")
+    };
+    let topics = (
+        name,
+        referral.patient.clone(),
+        referral.assigned_provider.clone(),
+    );
+    let data = ReferralRespondedEvent {
+        referral_id: referral.id,
+        patient: referral.patient.clone(),
+        provider: referral.assigned_provider.clone(),
+        status: referral.status,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantRoutedEvent {
+    pub patient: Address,
+    pub grantee: Address,
+    pub covering_provider: Option<Address>,
+    pub arrangement_id: Option<u64>,
+    pub queued_until: Option<u64>,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when access is granted to a provider under locum
+/// cover, so the covering provider knows to pick it up.
+pub fn publish_grant_rerouted(
+    env: &Env,
+    patient: Address,
+    grantee: Address,
+    arrangement: &crate::LocumArrangement,
+) {
+    let topics = (
+        symbol_short!("GRT_RRT"),
+        patient.clone(),
+        arrangement.covering_provider.clone(),
+    );
+    let data = GrantRoutedEvent {
+        patient,
+        grantee,
+        covering_provider: Some(arrangement.covering_provider.clone()),
+        arrangement_id: Some(arrangement.id),
+        queued_until: None,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when access is granted to an away provider with no
+/// cover; the grant waits for them to return.
+pub fn publish_grant_queued(env: &Env, patient: Address, grantee: Address, until: u64) {
+    let topics = (symbol_short!("GRT_QUE"), patient.clone(), grantee.clone());
+    let data = GrantRoutedEvent {
+        patient,
+        grantee,
+        covering_provider: None,
+        arrangement_id: None,
+        queued_until: Some(until),
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResidencyPolicyEvent {
+    pub org_id: u64,
+    pub allowed_regions: Vec<Symbol>,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when an organization's residency policy changes.
+/// Compliance tooling should re-run the violation scan after this.
+pub fn publish_residency_policy_set(env: &Env, policy: &crate::ResidencyPolicy) {
+    let topics = (symbol_short!("RES_POL"), policy.org_id);
+    let data = ResidencyPolicyEvent {
+        org_id: policy.org_id,
+        allowed_regions: policy.allowed_regions.clone(),
+        updated_by: policy.updated_by.clone(),
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordResidencyEvent {
+    pub record_id: u64,
+    pub region: Symbol,
+    pub tagged_by: Address,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a record's storage region is set or changed.
+pub fn publish_record_residency_set(env: &Env, record_id: u64, region: Symbol, tagged_by: Address) {
+    let topics = (symbol_short!("RES_TAG"), record_id);
+    let data = RecordResidencyEvent {
+        record_id,
+        region,
+        tagged_by,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustodyTransferredEvent {
+    pub record_id: u64,
+    pub seq: u32,
+    pub from_provider: Address,
+    pub to_provider: Address,
+    pub authorizer: Address,
+    pub entry_hash: BytesN<32>,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when a patient grants record- or type-scoped access.
+pub fn publish_scoped_access_granted(env: &Env, grant: &crate::ScopedAccessGrant) {
+    let topics = (
+        symbol_short!("ACC_SCP"),
+        grant.patient.clone(),
+        grant.grantee.clone(),
+    );
+    event_redaction::publish(env, topics, grant.clone());
+}
+
+/// Publishes an event when a patient revokes a scoped grant.
+pub fn publish_scoped_access_revoked(
+    env: &Env,
+    patient: Address,
+    grantee: Address,
+    scope: crate::AccessScope,
+) {
+    let topics = (symbol_short!("ACC_SRV"), patient, grantee);
+    event_redaction::publish(env, topics, (scope, env.ledger().timestamp()));
+}
+
+/// Publishes an event when a provider amends a record.
+pub fn publish_record_amended(env: &Env, patient: Address, amendment: &crate::RecordAmendment) {
+    let topics = (
+        symbol_short!("REC_AMND"),
+        patient.clone(),
+        amendment.amended_by.clone(),
+    );
+    let data = RecordAmendedEvent {
+        record_id: amendment.record_id,
+        patient,
+        provider: amendment.amended_by.clone(),
+        version: amendment.version,
+        timestamp: amendment.amended_at,
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when a record passes to a new provider.
+pub fn publish_custody_transferred(env: &Env, transfer: &crate::CustodyTransfer) {
+    let topics = (
+        symbol_short!("CUS_XFER"),
+        transfer.record_id,
+        transfer.to_provider.clone(),
+    );
+    let data = CustodyTransferredEvent {
+        record_id: transfer.record_id,
+        seq: transfer.seq,
+        from_provider: transfer.from_provider.clone(),
+        to_provider: transfer.to_provider.clone(),
+        authorizer: transfer.authorizer.clone(),
+        entry_hash: transfer.entry_hash.clone(),
+        timestamp: transfer.timestamp,
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes `CUS_PAT` so the patient learns which provider now holds
+/// one of their records.
+pub fn publish_patient_custody_notice(
+    env: &Env,
+    patient: Address,
+    transfer: &crate::CustodyTransfer,
+) {
+    let topics = (symbol_short!("CUS_PAT"), patient, transfer.record_id);
+    let data = (transfer.to_provider.clone(), transfer.timestamp);
+    event_redaction::publish(env, topics, data);
+}
+
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgQuotaEvent {
+    pub org_id: u64,
+    pub records_per_period: u32,
+    pub period_seconds: u64,
+    pub purchased_remaining: u32,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when an admin configures an organization's quota.
+pub fn publish_org_quota_set(env: &Env, quota: &crate::OrgQuota, usage: &crate::OrgQuotaUsage) {
+    let topics = (symbol_short!("QTA_SET"), quota.org_id);
+    let data = OrgQuotaEvent {
+        org_id: quota.org_id,
+        records_per_period: quota.records_per_period,
+        period_seconds: quota.period_seconds,
+        purchased_remaining: usage.purchased_remaining,
+        timestamp: env.ledger().timestamp(),
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when extra records are bought through billing.
+pub fn publish_org_quota_purchased(env: &Env, usage: &crate::OrgQuotaUsage, records: u32) {
+    let topics = (symbol_short!("QTA_BUY"), usage.org_id);
+    event_redaction::publish(env, topics, (records, usage.purchased_remaining));
+}
+
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DataChallengeEvent {
+    pub challenge_id: u64,
+    pub record_id: u64,
+    pub challenger: Address,
+    pub nonce: BytesN<32>,
+    pub respond_by: u64,
+    pub status: crate::DataChallengeStatus,
+    pub timestamp: u64,
+}
+
+fn data_challenge_event(env: &Env, challenge: &crate::DataChallenge) -> DataChallengeEvent {
+    DataChallengeEvent {
+        challenge_id: challenge.id,
+        record_id: challenge.record_id,
+        challenger: challenge.challenger.clone(),
+        nonce: challenge.nonce.clone(),
+        respond_by: challenge.respond_by,
+        status: challenge.status.clone(),
+        timestamp: env.ledger().timestamp(),
+    }
+}
+
+/// Publishes an event when a record's payload is challenged. The custodian
+/// is in the topics so they can watch for challenges addressed to them.
+pub fn publish_data_challenge_opened(
+    env: &Env,
+    challenge: &crate::DataChallenge,
+    custodian: &Address,
+) {
+    let topics = (
+        symbol_short!("DAC_OPEN"),
+        challenge.record_id,
+        custodian.clone(),
+    );
+    event_redaction::publish(env, topics, data_challenge_event(env, challenge));
+}
+
+/// Publishes an event when the custodian answers a challenge.
+pub fn publish_data_challenge_answered(env: &Env, challenge: &crate::DataChallenge) {
+    let topics = (symbol_short!("DAC_RESP"), challenge.record_id, challenge.id);
+    event_redaction::publish(env, topics, data_challenge_event(env, challenge));
+}
+
+#[soroban_sdk::contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConsentCampaignEvent {
+    pub campaign_id: u64,
+    pub terms_hash: BytesN<32>,
+    pub deadline: u64,
+    pub downgrade_to: AccessLevel,
+    pub timestamp: u64,
+}
+
+/// Publishes an event when consent re-collection starts, so clinics can
+/// prompt their patients.
+pub fn publish_consent_campaign_created(env: &Env, campaign: &crate::ConsentCampaign) {
+    let topics = (symbol_short!("CMP_NEW"), campaign.id);
+    let data = ConsentCampaignEvent {
+        campaign_id: campaign.id,
+        terms_hash: campaign.terms_hash.clone(),
+        deadline: campaign.deadline,
+        downgrade_to: campaign.downgrade_to.clone(),
+        timestamp: campaign.created_at,
+    };
+    event_redaction::publish(env, topics, data);
+}
+
+/// Publishes an event when a patient accepts or declines new terms.
+pub fn publish_reconsent(
+    env: &Env,
+    campaign_id: u64,
+    patient: &Address,
+    status: &crate::ReconsentStatus,
+) {
+    let topics = (symbol_short!("CMP_RSP"), campaign_id, patient.clone());
+    event_redaction::publish(env, topics, (status.clone(), env.ledger().timestamp()));
+}
+
+/// Publishes an event when a payer records a coverage hint on a prescription.
+pub fn publish_rx_coverage_set(env: &Env, coverage: &crate::RxCoverage) {
+    let topics = (symbol_short!("RX_COV"), coverage.rx_id, coverage.payer.clone());
+    event_redaction::publish(env, topics, coverage.clone());
+}
+
+/// Publishes an event when a payer withdraws a coverage hint.
+pub fn publish_rx_coverage_cleared(env: &Env, coverage: &crate::RxCoverage) {
+    let topics = (symbol_short!("RX_COVCLR"), coverage.rx_id, coverage.payer.clone());
+    event_redaction::publish(env, topics, (coverage.claim_id, env.ledger().timestamp()));
 }
@@ -1,14 +1,12 @@
 #![allow(clippy::arithmetic_side_effects)]
 use soroban_sdk::{contracttype, symbol_short, Env, String, Symbol, Vec};
 use teye_common::concurrency::{self, FieldChange, UpdateOutcome, VersionStamp};
+use teye_common::optometry;
 
-const TTL_THRESHOLD: u32 = 5184000;
-const TTL_EXTEND_TO: u32 = 10368000;
+use crate::ttl_config;
 
 fn extend_ttl_exam_key(env: &Env, key: &(Symbol, u64)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 #[contracttype]
@@ -32,6 +30,17 @@ pub struct VisualAcuity {
     pub corrected: OptPhysicalMeasurement,
 }
 
+/// Change in visual acuity between two examinations, in thousandths of
+/// logMAR. Positive values mean the eye got worse.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AcuityChange {
+    pub left_eye: i32,
+    pub right_eye: i32,
+    pub left_eye_lines: i32,
+    pub right_eye_lines: i32,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct IntraocularPressure {
@@ -114,6 +123,48 @@ pub struct EyeExamination {
     pub clinical_notes: String,
 }
 
+/// Returns `true` if every recorded acuity is a Snellen fraction or decimal
+/// acuity that [`optometry::parse_acuity`] understands, or a categorical
+/// entry such as `"CF"` or `"hand motion"`, which is stored as written.
+pub fn acuity_is_valid(acuity: &VisualAcuity) -> bool {
+    let valid = |value: &String| {
+        optometry::parse_acuity(value).is_some() || optometry::is_categorical_acuity(value)
+    };
+    let measured = |m: &PhysicalMeasurement| valid(&m.left_eye) && valid(&m.right_eye);
+    measured(&acuity.uncorrected)
+        && match &acuity.corrected {
+            OptPhysicalMeasurement::None => true,
+            OptPhysicalMeasurement::Some(m) => measured(m),
+        }
+}
+
+/// Best available acuity per eye in logMAR thousandths: corrected when it was
+/// recorded, uncorrected otherwise.
+fn best_logmar(acuity: &VisualAcuity) -> Option<(i32, i32)> {
+    let m = match &acuity.corrected {
+        OptPhysicalMeasurement::Some(m) => m,
+        OptPhysicalMeasurement::None => &acuity.uncorrected,
+    };
+    Some((
+        optometry::parse_acuity(&m.left_eye)?,
+        optometry::parse_acuity(&m.right_eye)?,
+    ))
+}
+
+/// Computes the acuity change from `baseline` to `followup`. Returns `None`
+/// if either examination holds acuity that cannot be normalized, such as a
+/// categorical entry; those examinations are left out of comparisons.
+pub fn acuity_change(baseline: &EyeExamination, followup: &EyeExamination) -> Option<AcuityChange> {
+    let (base_left, base_right) = best_logmar(&baseline.visual_acuity)?;
+    let (left, right) = best_logmar(&followup.visual_acuity)?;
+    Some(AcuityChange {
+        left_eye: left - base_left,
+        right_eye: right - base_right,
+        left_eye_lines: optometry::chart_line(left) - optometry::chart_line(base_left),
+        right_eye_lines: optometry::chart_line(right) - optometry::chart_line(base_right),
+    })
+}
+
 pub fn exam_key(record_id: u64) -> (Symbol, u64) {
     (symbol_short!("EXAM"), record_id)
 }
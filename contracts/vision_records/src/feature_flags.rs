@@ -0,0 +1,60 @@
+use soroban_sdk::{contracttype, symbol_short, Env, Symbol, Vec};
+
+use crate::ContractError;
+
+// ── Storage keys ──────────────────────────────────────────────
+const FEATURE: Symbol = symbol_short!("FEATURE");
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Optional subsystems an operator can switch off for their deployment.
+/// Every feature is enabled until an admin disables it.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Feature {
+    /// `grant_emergency_access`
+    EmergencyAccess,
+    /// Data-sharing agreements with insurers and research groups
+    DataSharingAgreements,
+    /// Relayer-sponsored calls such as `grant_access_meta`
+    SponsoredCalls,
+}
+
+/// Every feature, in the order `enabled_features` reports them
+pub const ALL_FEATURES: [Feature; 3] = [
+    Feature::EmergencyAccess,
+    Feature::DataSharingAgreements,
+    Feature::SponsoredCalls,
+];
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn set_enabled(env: &Env, feature: Feature, enabled: bool) {
+    env.storage().instance().set(&(FEATURE, feature), &enabled);
+}
+
+pub fn is_enabled(env: &Env, feature: Feature) -> bool {
+    env.storage()
+        .instance()
+        .get(&(FEATURE, feature))
+        .unwrap_or(true)
+}
+
+/// Fails with `FeatureDisabled` if the operator has switched `feature` off.
+pub fn require_enabled(env: &Env, feature: Feature) -> Result<(), ContractError> {
+    if is_enabled(env, feature) {
+        Ok(())
+    } else {
+        Err(ContractError::FeatureDisabled)
+    }
+}
+
+pub fn enabled_features(env: &Env) -> Vec<Feature> {
+    let mut features = Vec::new(env);
+    for feature in ALL_FEATURES {
+        if is_enabled(env, feature) {
+            features.push_back(feature);
+        }
+    }
+    features
+}
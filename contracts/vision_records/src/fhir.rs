@@ -0,0 +1,124 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const FHIR_ENV: Symbol = symbol_short!("FHIR_ENV");
+const FHIR_IDX: Symbol = symbol_short!("FHIR_IDX");
+
+/// Longest accepted FHIR resource type name.
+pub const MAX_RESOURCE_TYPE_LEN: u32 = 64;
+
+/// Extends the time-to-live (TTL) for per-record envelope keys.
+fn extend_ttl_record_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for (patient, resource type) index keys.
+fn extend_ttl_index_key(env: &Env, key: &(Symbol, Address, String)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FhirVersion {
+    Stu3,
+    R4,
+    R4B,
+    R5,
+}
+
+/// Describes a record as an anchored FHIR resource, e.g. an `Observation`
+/// or `DiagnosticReport`. `canonical_json_hash` is the SHA-256 of the
+/// resource's canonical JSON, so an integrator can check a bundle entry
+/// against the record without any convention of their own.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FhirEnvelope {
+    pub record_id: u64,
+    pub resource_type: String,
+    pub fhir_version: FhirVersion,
+    pub canonical_json_hash: BytesN<32>,
+    pub anchored_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// True for a FHIR resource type name: ASCII letters starting with an
+/// upper-case one, e.g. `Observation`.
+pub fn is_valid_resource_type(resource_type: &String) -> bool {
+    let len = resource_type.len() as usize;
+    if len == 0 || len > MAX_RESOURCE_TYPE_LEN as usize {
+        return false;
+    }
+    let mut buf = [0u8; MAX_RESOURCE_TYPE_LEN as usize];
+    resource_type.copy_into_slice(&mut buf[..len]);
+    buf[0].is_ascii_uppercase() && buf[..len].iter().all(|b| b.is_ascii_alphabetic())
+}
+
+pub fn get(env: &Env, record_id: u64) -> Option<FhirEnvelope> {
+    env.storage().persistent().get(&(FHIR_ENV, record_id))
+}
+
+/// Ids of `patient`'s records anchored as `resource_type`, oldest first.
+pub fn get_indexed_records(env: &Env, patient: &Address, resource_type: &String) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(FHIR_IDX, patient.clone(), resource_type.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn unindex(env: &Env, patient: &Address, resource_type: &String, record_id: u64) {
+    let key = (FHIR_IDX, patient.clone(), resource_type.clone());
+    let mut ids = get_indexed_records(env, patient, resource_type);
+    if let Some(i) = ids.first_index_of(record_id) {
+        ids.remove(i);
+        if ids.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &ids);
+        }
+    }
+}
+
+fn index(env: &Env, patient: &Address, resource_type: &String, record_id: u64) {
+    let key = (FHIR_IDX, patient.clone(), resource_type.clone());
+    let mut ids = get_indexed_records(env, patient, resource_type);
+    if !ids.contains(record_id) {
+        ids.push_back(record_id);
+        env.storage().persistent().set(&key, &ids);
+        extend_ttl_index_key(env, &key);
+    }
+}
+
+/// Stores `envelope`, moving the record between resource type indexes if
+/// it replaces an envelope of another type.
+pub fn set(env: &Env, patient: &Address, envelope: &FhirEnvelope) {
+    if let Some(previous) = get(env, envelope.record_id) {
+        if previous.resource_type != envelope.resource_type {
+            unindex(env, patient, &previous.resource_type, envelope.record_id);
+        }
+    }
+    let key = (FHIR_ENV, envelope.record_id);
+    env.storage().persistent().set(&key, envelope);
+    extend_ttl_record_key(env, &key);
+    index(env, patient, &envelope.resource_type, envelope.record_id);
+}
+
+/// Moves a record's resource type index entry from one patient to another.
+pub fn reindex_record(env: &Env, from: &Address, to: &Address, record_id: u64) {
+    if let Some(envelope) = get(env, record_id) {
+        unindex(env, from, &envelope.resource_type, record_id);
+        index(env, to, &envelope.resource_type, record_id);
+    }
+}
+
+/// Drops a record's envelope and its resource type index entry.
+pub fn remove(env: &Env, patient: &Address, record_id: u64) {
+    if let Some(envelope) = get(env, record_id) {
+        unindex(env, patient, &envelope.resource_type, record_id);
+        env.storage().persistent().remove(&(FHIR_ENV, record_id));
+    }
+}
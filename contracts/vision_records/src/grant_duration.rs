@@ -0,0 +1,32 @@
+use soroban_sdk::{symbol_short, Env, Symbol};
+
+use crate::{AccessGrant, ContractError};
+
+// ── Storage keys ──────────────────────────────────────────────
+const GRT_MAXD: Symbol = symbol_short!("GRT_MAXD");
+
+/// Default cap on a grant's total lifetime: five years, matching the
+/// longest duration a single grant may be issued for.
+pub const DEFAULT_MAX_TOTAL_DURATION: u64 = 157_680_000;
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get_max_total(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&GRT_MAXD)
+        .unwrap_or(DEFAULT_MAX_TOTAL_DURATION)
+}
+
+pub fn set_max_total(env: &Env, seconds: u64) {
+    env.storage().instance().set(&GRT_MAXD, &seconds);
+}
+
+/// Fails with `GrantDurationExceeded` if `grant` would stay live for longer
+/// than the configured maximum, counted from when it was granted.
+pub fn check(env: &Env, grant: &AccessGrant) -> Result<(), ContractError> {
+    if grant.expires_at.saturating_sub(grant.granted_at) > get_max_total(env) {
+        return Err(ContractError::GrantDurationExceeded);
+    }
+    Ok(())
+}
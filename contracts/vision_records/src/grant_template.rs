@@ -0,0 +1,97 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+use crate::{AccessLevel, ContractError};
+
+// ── Storage keys ──────────────────────────────────────────────
+const GRANT_TEMPLATE: Symbol = symbol_short!("GRT_TPL");
+
+/// Upper bound on grants applied in one bulk call.
+pub const MAX_BULK_GRANTS: u32 = 20;
+
+/// Extends the time-to-live (TTL) for named grant template keys.
+fn extend_ttl_template_key(env: &Env, key: &(Symbol, Symbol)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A role within a grant template, e.g. `org` → Read.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TemplateSlot {
+    pub label: Symbol,
+    pub level: AccessLevel,
+}
+
+/// Named set of grants a patient can apply in one call by supplying one
+/// grantee per slot, such as "new clinic onboarding" (Read to the clinic,
+/// Write to the primary optometrist).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GrantTemplate {
+    pub name: Symbol,
+    pub slots: Vec<TemplateSlot>,
+    pub duration_seconds: u64,
+    pub updated_by: Address,
+    pub updated_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn set_template(env: &Env, template: &GrantTemplate) {
+    let key = (GRANT_TEMPLATE, template.name.clone());
+    env.storage().persistent().set(&key, template);
+    extend_ttl_template_key(env, &key);
+}
+
+pub fn get_template(env: &Env, name: &Symbol) -> Option<GrantTemplate> {
+    env.storage()
+        .persistent()
+        .get(&(GRANT_TEMPLATE, name.clone()))
+}
+
+pub fn remove_template(env: &Env, name: &Symbol) {
+    env.storage()
+        .persistent()
+        .remove(&(GRANT_TEMPLATE, name.clone()));
+}
+
+/// Pairs each template slot with the grantee supplied for it.
+pub fn fill(
+    env: &Env,
+    template: &GrantTemplate,
+    grantees: &Vec<Address>,
+) -> Result<Vec<(Address, AccessLevel)>, ContractError> {
+    if grantees.len() != template.slots.len() {
+        return Err(ContractError::InvalidInput);
+    }
+    let mut grants = Vec::new(env);
+    for (slot, grantee) in template.slots.iter().zip(grantees.iter()) {
+        grants.push_back((grantee, slot.level));
+    }
+    Ok(grants)
+}
+
+/// Validates a bulk grant up front so it is applied all-or-nothing: the
+/// list must be non-empty, bounded, free of duplicates and `None` levels,
+/// and must not name the patient.
+pub fn validate_bulk(
+    patient: &Address,
+    grants: &Vec<(Address, AccessLevel)>,
+) -> Result<(), ContractError> {
+    if grants.is_empty() || grants.len() > MAX_BULK_GRANTS {
+        return Err(ContractError::InvalidInput);
+    }
+    for (i, (grantee, level)) in grants.iter().enumerate() {
+        if &grantee == patient || level == AccessLevel::None {
+            return Err(ContractError::InvalidInput);
+        }
+        for (other, _) in grants.iter().skip(i.saturating_add(1)) {
+            if other == grantee {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+    }
+    Ok(())
+}
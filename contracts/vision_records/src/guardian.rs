@@ -0,0 +1,94 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const GRD: Symbol = symbol_short!("GRD");
+const GRD_LST: Symbol = symbol_short!("GRD_LST");
+
+/// Extends the time-to-live (TTL) for (dependent, guardian) keys.
+fn extend_ttl_pair_key(env: &Env, key: &(Symbol, Address, Address)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-dependent guardian index keys.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A guardian acting for a minor or other dependent patient, confirmed by
+/// an admin or provider. The guardian's powers lapse at `age_out_at`, when
+/// the dependent takes over, or earlier if the guardianship is ended.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Guardianship {
+    pub guardian: Address,
+    pub dependent: Address,
+    pub confirmed_by: Address,
+    pub registered_at: u64,
+    pub age_out_at: u64,
+    pub ended_at: Option<u64>,
+}
+
+impl Guardianship {
+    pub fn is_active(&self, now: u64) -> bool {
+        self.ended_at.is_none() && now < self.age_out_at
+    }
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get(env: &Env, dependent: &Address, guardian: &Address) -> Option<Guardianship> {
+    env.storage()
+        .persistent()
+        .get(&(GRD, dependent.clone(), guardian.clone()))
+}
+
+/// True if `guardian` currently acts for `dependent`.
+pub fn is_guardian(env: &Env, guardian: &Address, dependent: &Address) -> bool {
+    get(env, dependent, guardian).is_some_and(|g| g.is_active(env.ledger().timestamp()))
+}
+
+/// Stores `guardianship`, adding the guardian to the dependent's index the
+/// first time the pair is seen.
+pub fn set(env: &Env, guardianship: &Guardianship) {
+    let key = (
+        GRD,
+        guardianship.dependent.clone(),
+        guardianship.guardian.clone(),
+    );
+    env.storage().persistent().set(&key, guardianship);
+    extend_ttl_pair_key(env, &key);
+
+    let mut guardians = guardians(env, &guardianship.dependent);
+    if !guardians.contains(&guardianship.guardian) {
+        guardians.push_back(guardianship.guardian.clone());
+        let index_key = (GRD_LST, guardianship.dependent.clone());
+        env.storage().persistent().set(&index_key, &guardians);
+        extend_ttl_address_key(env, &index_key);
+    }
+}
+
+/// Every guardian ever registered for `dependent`, active or not.
+pub fn guardians(env: &Env, dependent: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&(GRD_LST, dependent.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// The guardians currently acting for `dependent`.
+pub fn active_guardians(env: &Env, dependent: &Address) -> Vec<Guardianship> {
+    let now = env.ledger().timestamp();
+    let mut out = Vec::new(env);
+    for guardian in guardians(env, dependent).iter() {
+        if let Some(g) = get(env, dependent, &guardian) {
+            if g.is_active(now) {
+                out.push_back(g);
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,86 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const ATT_CNT: Symbol = symbol_short!("ATT_CNT");
+const ATT_LOG: Symbol = symbol_short!("ATT_LOG");
+
+/// Attestations per storage bucket, which is also the page size of
+/// `get_integrity_attestations`
+pub const ATTESTATION_BUCKET_SIZE: u32 = 50;
+
+/// Extends the time-to-live (TTL) for per-record attestation counters.
+fn extend_ttl_counter_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for (record, bucket) attestation keys.
+fn extend_ttl_bucket_key(env: &Env, key: &(Symbol, u64, u32)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// The outcome of checking an off-chain document's hash against a record's
+/// stored `data_hash`. Kept whether or not the hashes matched, so a failed
+/// check is as provable as a passing one. The submitted hash itself is not
+/// kept: on a match it is the record's `data_hash`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IntegrityAttestation {
+    /// Position in the record's attestation log, starting at 0
+    pub seq: u32,
+    pub record_id: u64,
+    pub attester: Address,
+    pub matched: bool,
+    pub attested_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn count(env: &Env, record_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&(ATT_CNT, record_id))
+        .unwrap_or(0)
+}
+
+/// Appends an attestation for `record_id` and returns it.
+pub fn append(
+    env: &Env,
+    record_id: u64,
+    attester: &Address,
+    matched: bool,
+) -> IntegrityAttestation {
+    let seq = count(env, record_id);
+    let attestation = IntegrityAttestation {
+        seq,
+        record_id,
+        attester: attester.clone(),
+        matched,
+        attested_at: env.ledger().timestamp(),
+    };
+
+    let bucket_key = (ATT_LOG, record_id, seq / ATTESTATION_BUCKET_SIZE);
+    let mut bucket = page(env, record_id, seq / ATTESTATION_BUCKET_SIZE);
+    bucket.push_back(attestation.clone());
+    env.storage().persistent().set(&bucket_key, &bucket);
+    extend_ttl_bucket_key(env, &bucket_key);
+
+    let count_key = (ATT_CNT, record_id);
+    env.storage()
+        .persistent()
+        .set(&count_key, &seq.saturating_add(1));
+    extend_ttl_counter_key(env, &count_key);
+    attestation
+}
+
+/// Attestations `page * ATTESTATION_BUCKET_SIZE` up to the next page
+/// boundary, oldest first. Empty past the end of the log.
+pub fn page(env: &Env, record_id: u64, page: u32) -> Vec<IntegrityAttestation> {
+    env.storage()
+        .persistent()
+        .get(&(ATT_LOG, record_id, page))
+        .unwrap_or(Vec::new(env))
+}
@@ -0,0 +1,94 @@
+use soroban_sdk::{symbol_short, Address, Env, Vec};
+use teye_common::invariant::{InvariantReport, InvariantScan, InvariantSubject};
+
+use crate::retention;
+use crate::{AccessGrant, GrantStatus, VisionRecord};
+
+/// Checks records `1..=REC_CTR` a slice at a time. For each record, and
+/// once for each patient met along the way:
+///
+/// - `idx_miss`: the record is missing from its patient's index
+/// - `idx_dang`: a patient index entry has no record or archival summary,
+///   or one owned by another patient
+/// - `grt_exp`: an active grant expires no later than it was granted
+/// - `grt_rev`: a grant's status and `revoked_at` disagree
+pub fn run(env: &Env, limit: u32) -> InvariantReport {
+    let last_id: u64 = env
+        .storage()
+        .instance()
+        .get(&symbol_short!("REC_CTR"))
+        .unwrap_or(0);
+    let mut scan = InvariantScan::start(env, last_id, limit);
+    let mut seen: Vec<Address> = Vec::new(env);
+
+    for id in scan.ids() {
+        let record: Option<VisionRecord> = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), id));
+        let Some(record) = record else {
+            continue;
+        };
+
+        let index = patient_index(env, &record.patient);
+        if !index.contains(id) {
+            scan.flag(symbol_short!("idx_miss"), InvariantSubject::Id(id));
+        }
+
+        if seen.contains(&record.patient) {
+            continue;
+        }
+        seen.push_back(record.patient.clone());
+        check_index(env, &mut scan, &record.patient, &index);
+        check_grants(env, &mut scan, &record.patient);
+    }
+
+    scan.finish()
+}
+
+fn patient_index(env: &Env, patient: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(symbol_short!("PAT_REC"), patient.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn check_index(env: &Env, scan: &mut InvariantScan, patient: &Address, index: &Vec<u64>) {
+    for id in index.iter() {
+        // Retention archival keeps the id indexed behind a summary
+        let owner = env
+            .storage()
+            .persistent()
+            .get::<_, VisionRecord>(&(symbol_short!("RECORD"), id))
+            .map(|r| r.patient)
+            .or_else(|| retention::get_archival_summary(env, id).map(|s| s.patient));
+        if owner.as_ref() != Some(patient) {
+            scan.flag(symbol_short!("idx_dang"), InvariantSubject::Id(id));
+        }
+    }
+}
+
+fn check_grants(env: &Env, scan: &mut InvariantScan, patient: &Address) {
+    let grantees: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&(symbol_short!("ACC_LST"), patient.clone()))
+        .unwrap_or(Vec::new(env));
+    for grantee in grantees.iter() {
+        let grant: Option<AccessGrant> = env.storage().persistent().get(&(
+            symbol_short!("ACCESS"),
+            patient.clone(),
+            grantee.clone(),
+        ));
+        let Some(grant) = grant else {
+            continue;
+        };
+        let subject = InvariantSubject::AddressPair(patient.clone(), grantee);
+        let revoked = grant.status == GrantStatus::Revoked;
+        if revoked != grant.revoked_at.is_some() {
+            scan.flag(symbol_short!("grt_rev"), subject);
+        } else if !revoked && grant.expires_at <= grant.granted_at {
+            scan.flag(symbol_short!("grt_exp"), subject);
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const LAB_CTR: Symbol = symbol_short!("LAB_CTR");
+const LAB_RES: Symbol = symbol_short!("LAB_RES");
+const LAB_REC: Symbol = symbol_short!("LAB_REC");
+const LAB_ORD: Symbol = symbol_short!("LAB_ORD");
+
+/// Longest accepted panel code, e.g. a LOINC code such as `24331-1`.
+pub const MAX_PANEL_CODE_LEN: u32 = 16;
+
+/// Extends the time-to-live (TTL) for lab result and per-record index keys.
+fn extend_ttl_id_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-provider order index keys.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A result a registered laboratory returned for a test a provider
+/// ordered. `ordering_record_id` is the provider's record the order was
+/// made from; `result_hash` points at the off-chain report.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LabResult {
+    pub id: u64,
+    pub lab: Address,
+    pub patient: Address,
+    pub ordering_provider: Address,
+    pub ordering_record_id: u64,
+    pub panel_code: String,
+    pub result_hash: String,
+    pub submitted_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn is_valid_panel_code(code: &String) -> bool {
+    code.len() > 0 && code.len() <= MAX_PANEL_CODE_LEN
+}
+
+/// Increments and returns the next lab result ID
+pub fn increment_counter(env: &Env) -> u64 {
+    let current: u64 = env.storage().instance().get(&LAB_CTR).unwrap_or(0);
+    let next = current.saturating_add(1);
+    env.storage().instance().set(&LAB_CTR, &next);
+    next
+}
+
+pub fn get(env: &Env, id: u64) -> Option<LabResult> {
+    env.storage().persistent().get(&(LAB_RES, id))
+}
+
+/// Result ids linked to `record_id`, oldest first.
+pub fn get_record_ids(env: &Env, record_id: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(LAB_REC, record_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Result ids for tests `provider` ordered, oldest first.
+pub fn get_ordered_ids(env: &Env, provider: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(LAB_ORD, provider.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores a new result and links it to its ordering record and provider.
+pub fn create(env: &Env, result: &LabResult) {
+    let key = (LAB_RES, result.id);
+    env.storage().persistent().set(&key, result);
+    extend_ttl_id_key(env, &key);
+
+    let record_key = (LAB_REC, result.ordering_record_id);
+    let mut ids = get_record_ids(env, result.ordering_record_id);
+    ids.push_back(result.id);
+    env.storage().persistent().set(&record_key, &ids);
+    extend_ttl_id_key(env, &record_key);
+
+    let order_key = (LAB_ORD, result.ordering_provider.clone());
+    let mut ids = get_ordered_ids(env, &result.ordering_provider);
+    ids.push_back(result.id);
+    env.storage().persistent().set(&order_key, &ids);
+    extend_ttl_address_key(env, &order_key);
+}
+
+/// Drops the results linked to `record_id` and unlists them from their
+/// ordering providers.
+pub fn remove_record(env: &Env, record_id: u64) {
+    for id in get_record_ids(env, record_id).iter() {
+        if let Some(result) = get(env, id) {
+            let mut ids = get_ordered_ids(env, &result.ordering_provider);
+            if let Some(i) = ids.first_index_of(id) {
+                ids.remove(i);
+                env.storage()
+                    .persistent()
+                    .set(&(LAB_ORD, result.ordering_provider.clone()), &ids);
+            }
+        }
+        env.storage().persistent().remove(&(LAB_RES, id));
+    }
+    env.storage().persistent().remove(&(LAB_REC, record_id));
+}
@@ -0,0 +1,137 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+pub const LH_CTR: Symbol = symbol_short!("LH_CTR");
+const LH_HOLD: Symbol = symbol_short!("LH_HOLD");
+const LH_TARGET: Symbol = symbol_short!("LH_TGT");
+const LH_ACTIVE: Symbol = symbol_short!("LH_ACT");
+
+/// Extends the time-to-live (TTL) for legal hold keys.
+fn extend_ttl_hold_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-target hold index keys.
+fn extend_ttl_target_key(env: &Env, key: &(Symbol, LegalHoldTarget)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Data covered by a legal hold
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LegalHoldTarget {
+    /// Every record belonging to the patient, and the patient's address
+    Patient(Address),
+    /// A single record
+    Record(u64),
+}
+
+/// A litigation or regulatory hold on patient data
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct LegalHold {
+    pub id: u64,
+    pub target: LegalHoldTarget,
+    /// Hash of the external case reference
+    pub case_ref_hash: BytesN<32>,
+    pub placed_by: Address,
+    pub placed_at: u64,
+    pub lifted_by: Option<Address>,
+    pub lifted_at: Option<u64>,
+}
+
+impl LegalHold {
+    pub fn is_active(&self) -> bool {
+        self.lifted_at.is_none()
+    }
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Increments and returns the next hold ID
+pub fn increment_hold_counter(env: &Env) -> u64 {
+    let current: u64 = env.storage().instance().get(&LH_CTR).unwrap_or(0);
+    let next = current.saturating_add(1);
+    env.storage().instance().set(&LH_CTR, &next);
+    next
+}
+
+pub fn set_hold(env: &Env, hold: &LegalHold) {
+    let key = (LH_HOLD, hold.id);
+    env.storage().persistent().set(&key, hold);
+    extend_ttl_hold_key(env, &key);
+}
+
+pub fn get_hold(env: &Env, hold_id: u64) -> Option<LegalHold> {
+    env.storage().persistent().get(&(LH_HOLD, hold_id))
+}
+
+/// Returns ids of active holds on `target`
+pub fn get_target_holds(env: &Env, target: &LegalHoldTarget) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(LH_TARGET, target.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Returns ids of all active holds
+pub fn get_active_holds(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&LH_ACTIVE)
+        .unwrap_or(Vec::new(env))
+}
+
+fn without(env: &Env, ids: &Vec<u64>, hold_id: u64) -> Vec<u64> {
+    let mut out = Vec::new(env);
+    for id in ids.iter() {
+        if id != hold_id {
+            out.push_back(id);
+        }
+    }
+    out
+}
+
+/// Records a new active hold in the target and global indexes
+pub fn activate(env: &Env, hold: &LegalHold) {
+    let target_key = (LH_TARGET, hold.target.clone());
+    let mut ids = get_target_holds(env, &hold.target);
+    ids.push_back(hold.id);
+    env.storage().persistent().set(&target_key, &ids);
+    extend_ttl_target_key(env, &target_key);
+
+    let mut active = get_active_holds(env);
+    active.push_back(hold.id);
+    env.storage().persistent().set(&LH_ACTIVE, &active);
+}
+
+/// Removes a lifted hold from the target and global indexes
+pub fn deactivate(env: &Env, hold: &LegalHold) {
+    let target_key = (LH_TARGET, hold.target.clone());
+    let ids = without(env, &get_target_holds(env, &hold.target), hold.id);
+    if ids.is_empty() {
+        env.storage().persistent().remove(&target_key);
+    } else {
+        env.storage().persistent().set(&target_key, &ids);
+    }
+
+    let active = without(env, &get_active_holds(env), hold.id);
+    env.storage().persistent().set(&LH_ACTIVE, &active);
+}
+
+pub fn is_held(env: &Env, target: &LegalHoldTarget) -> bool {
+    !get_target_holds(env, target).is_empty()
+}
+
+pub fn is_patient_held(env: &Env, patient: &Address) -> bool {
+    is_held(env, &LegalHoldTarget::Patient(patient.clone()))
+}
+
+/// Returns true if the record is held directly or through its patient
+pub fn is_record_held(env: &Env, record_id: u64, patient: &Address) -> bool {
+    is_held(env, &LegalHoldTarget::Record(record_id)) || is_patient_held(env, patient)
+}
@@ -7,29 +7,98 @@ use alloc::{
     vec::Vec as StdVec,
 };
 
+pub mod access_decision;
+pub mod access_window;
+pub mod admin_receipt;
+pub mod admin_set;
+pub mod admin_transfer;
+pub mod adverse_event;
+pub mod agreement;
+pub mod alias;
+pub mod amendment;
+pub mod anomaly;
 pub mod appointment;
+pub mod attachment;
 pub mod audit;
+pub mod availability;
+pub mod care_team;
 pub mod circuit_breaker;
+pub mod cohort;
+pub mod consent_campaign;
+pub mod cosign;
+pub mod custody;
+pub mod data_challenge;
+pub mod device;
+pub mod diagnosis;
+pub mod eligibility;
 pub mod emergency;
+pub mod erasure;
 pub mod errors;
 pub mod events;
 pub mod examination;
+pub mod feature_flags;
+pub mod fhir;
+pub mod grant_duration;
+pub mod grant_template;
+pub mod guardian;
+pub mod integrity;
+pub mod invariants;
+pub mod lab;
+pub mod legal_hold;
+pub mod locum;
+pub mod measurement;
+pub mod merge;
+pub mod meta_signer;
+pub mod notification_prefs;
+pub mod org_quota;
+pub mod organization;
+pub mod patient_keys;
 pub mod patient_profile;
+pub mod practice_transfer;
 pub mod prescription;
+pub mod print_auth;
+pub mod privacy;
 pub mod provider;
+pub mod provider_approval;
+pub mod purpose_consent;
 pub mod rate_limit;
 pub mod rbac;
+pub mod read_log;
+pub mod record_signature;
+pub mod record_types;
+pub mod reencryption;
+pub mod referral;
+pub mod registration_gate;
+pub mod residency;
+pub mod responder;
+pub mod retention;
+pub mod rx_coverage;
+pub mod rx_share;
+pub mod scoped_grant;
+pub mod sensitivity;
+pub mod snapshot;
+pub mod surgery;
+pub mod tombstone;
+pub mod ttl_config;
+pub mod user_name;
 pub mod validation;
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, String,
-    Symbol, Vec,
+    contract, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, IntoVal, Map,
+    String, Symbol, Val, Vec,
 };
+use common::audit_stream::{self, AuditStreamEntry};
+use common::transaction::{Compensation, PreparedOperation};
+use common::event_redaction::{self, EventRedaction};
+use common::interface::{self, InterfaceVersion};
+use common::invariant::InvariantReport;
+use common::state_proof::{self, EntryProof};
+use common::storage_ttl::{self, StorageKeySpec};
 use alloc::string::ToString;
 
 use teye_common as common;
 use common::{whitelist, KeyManager, AdminTier, admin_tiers};
-use teye_common::{admin_tiers, multisig, whitelist, AdminTier, KeyManager};
+use teye_common::{admin_tiers, meta_tx, multisig, nonce, whitelist, AdminTier, KeyManager};
 
 /// Re-export the contract-specific error type at the crate root.
 pub use errors::ContractError;
@@ -41,16 +110,83 @@ pub use provider::VerificationStatus;
 pub use errors::{create_error_context, log_error};
 
 /// Re-export types from submodules used directly in the contract impl.
+pub use access_decision::{AccessBasis, AccessDecision, AccessReasonCode, Cover, DisclosureTier};
+pub use access_window::AccessWindow;
+pub use admin_receipt::AdminActionReceipt;
+pub use anomaly::{ActivityWindow, ActorFlag, AnomalyKind, AnomalyRule};
+pub use record_types::CustomRecordType;
+pub use agreement::{AgreementGrant, AgreementStatus, DataSharingAgreement};
+pub use adverse_event::{
+    AdverseEventCounts, AdverseEventReport, AdverseEventSeverity, AdverseEventStatus,
+};
 pub use audit::{AccessAction, AccessResult};
 pub use examination::{
-    EyeExamination, IntraocularPressure, OptFundusPhotography, OptRetinalImaging, OptVisualField,
+    AcuityChange, EyeExamination, IntraocularPressure, OptFundusPhotography, OptRetinalImaging, OptVisualField,
     SlitLampFindings, VisualAcuity,
 };
+pub use emergency::{
+    EmergencyAccess, EmergencyAuditEntry, EmergencyCondition, EmergencyContacts, EmergencyPolicy,
+    EmergencyStatus,
+};
+pub use grant_template::{GrantTemplate, TemplateSlot};
+pub use legal_hold::{LegalHold, LegalHoldTarget};
+pub use cohort::{CohortDisclosure, CohortGrant};
+pub use consent_campaign::{CampaignProgress, ConsentCampaign, ReconsentStatus};
+pub use custody::CustodyTransfer;
+pub use data_challenge::{
+    DataAvailability, DataChallenge, DataChallengeResponse, DataChallengeStatus,
+};
+pub use locum::{LocumArrangement, LocumDisclosure};
+pub use availability::{AvailabilityStatus, ProviderAvailability};
+pub use merge::PatientMerge;
+pub use org_quota::{OrgQuota, OrgQuotaUsage};
 pub use patient_profile::{
     EmergencyContact, InsuranceInfo, OptionalEmergencyContact, OptionalInsuranceInfo,
     PatientProfile,
 };
-pub use prescription::{LensType, OptionalContactLensData, Prescription, PrescriptionData};
+pub use prescription::{
+    ContactLensData, LensType, OptionalContactLensData, Prescription, PrescriptionData,
+    PrescriptionStatus,
+};
+pub use privacy::PrivacySettings;
+pub use referral::{Referral, ReferralStatus, ReferralUrgency};
+pub use registration_gate::RegistrationProofRequirement;
+pub use residency::{ResidencyPolicy, ResidencyViolation};
+pub use responder::ResponderEntry;
+pub use snapshot::{StateChange, StateChangeKind, StateDigest};
+pub use amendment::RecordAmendment;
+pub use feature_flags::Feature;
+pub use scoped_grant::{AccessScope, ScopedAccessGrant};
+pub use provider_approval::PendingRegistration;
+pub use print_auth::PrintAuthorization;
+pub use admin_set::AdminPermission;
+pub use attachment::{AttachmentKind, RecordAttachment};
+pub use record_signature::RecordSignature;
+pub use cosign::CosignRequest;
+pub use diagnosis::{DiagnosisDetail, DiagnosisSeverity, Laterality};
+pub use measurement::{Eye, MeasureType, MeasureUnit, Measurement};
+pub use surgery::{SurgeryStatus, SurgeryStep, SurgicalCase};
+pub use lab::LabResult;
+pub use device::{ImagingDevice, RecordProvenance};
+pub use fhir::{FhirEnvelope, FhirVersion};
+pub use patient_keys::{PatientEncryptionKey, RecordKeyInfo};
+pub use reencryption::ReencryptionGrant;
+pub use erasure::{ErasureRequest, ErasureStatus};
+pub use practice_transfer::PracticeTransfer;
+pub use care_team::CareRelationship;
+pub use guardian::Guardianship;
+pub use organization::{OrgAccessGrant, Organization};
+pub use purpose_consent::{ConsentPurpose, ConsentScope, PurposeConsent};
+pub use integrity::IntegrityAttestation;
+pub use read_log::RecordReadEntry;
+pub use tombstone::RecordTombstone;
+pub use ttl_config::TtlConfig;
+pub use rx_coverage::RxCoverage;
+pub use user_name::UserName;
+pub use sensitivity::ReadReceipt;
+pub use rx_share::{
+    PrescriptionConsentReceipt, PrescriptionShareCode, PrescriptionShareScope, ShareMethod,
+};
 
 /// Storage keys for the contract
 const ADMIN: Symbol = symbol_short!("ADMIN");
@@ -59,8 +195,21 @@ const INITIALIZED: Symbol = symbol_short!("INIT");
 const RATE_CFG: Symbol = symbol_short!("RL_IN_CFG");
 const RATE_TRACK: Symbol = symbol_short!("RL_IN_TRK");
 
-const TTL_THRESHOLD: u32 = 5184000;
-const TTL_EXTEND_TO: u32 = 10368000;
+/// Most persistent entries `extend_patient_data_ttl` bumps per call
+pub const MAX_TTL_BUMP_ENTRIES: u32 = 100;
+
+/// Cross-contract interface version reported by `get_interface_version`
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: interface::VISION_RECORDS,
+    major: 1,
+    minor: 0,
+};
+
+/// Most ids `get_patient_records_filtered` returns per call
+pub const MAX_RECORD_PAGE: u32 = 50;
+
+/// Most records one `add_records_batch` call may create
+pub const MAX_RECORD_BATCH: u32 = 200;
 
 const ENC_CUR: Symbol = symbol_short!("ENC_CUR");
 const ENC_KEY: Symbol = symbol_short!("ENC_KEY");
@@ -70,31 +219,23 @@ const KEY_MGR_KEY: Symbol = symbol_short!("KEY_MGRK");
 /// Extends the time-to-live (TTL) for a storage key containing an Address.
 /// This ensures the data remains accessible for the extended period.
 fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 /// Extends the time-to-live (TTL) for a storage key containing a u64 value.
 /// This ensures the data remains accessible for the extended period.
 fn extend_ttl_u64_key(env: &Env, key: &(Symbol, u64)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 /// Extends the time-to-live (TTL) for an access grant storage key.
 /// This ensures access grant data remains accessible for the extended period.
 fn extend_ttl_access_key(env: &Env, key: &(Symbol, Address, Address)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 fn extend_ttl_record_access_key(env: &Env, key: &(Symbol, u64, Address)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 fn rate_limit_action_hash(
@@ -129,10 +270,12 @@ fn consent_key(patient: &Address, grantee: &Address) -> (Symbol, Address, Addres
     (symbol_short!("CONSENT"), patient.clone(), grantee.clone())
 }
 
-fn has_active_consent(env: &Env, patient: &Address, grantee: &Address) -> bool {
+pub(crate) fn has_active_consent(env: &Env, patient: &Address, grantee: &Address) -> bool {
     let key = consent_key(patient, grantee);
     if let Some(consent) = env.storage().persistent().get::<_, ConsentGrant>(&key) {
-        !consent.revoked && consent.expires_at > env.ledger().timestamp()
+        !consent.revoked
+            && consent.expires_at > env.ledger().timestamp()
+            && consent_campaign::lapsed_cap(env, &consent) != Some(AccessLevel::None)
     } else {
         false
     }
@@ -165,7 +308,9 @@ pub struct PrepareUserRegistration {
     pub caller: Address,
     pub user: Address,
     pub role: Role,
-    pub name: String,
+    /// Hash of the name under `user_name::plaintext_salt`; the plaintext
+    /// is not kept between phases.
+    pub name_hash: BytesN<32>,
     pub timestamp: u64,
 }
 
@@ -231,15 +376,9 @@ pub enum RecordType {
     Surgery,
     /// Laboratory result record
     LabResult,
-}
-
-/// Status for emergency access grants
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub enum EmergencyStatus {
-    Active,
-    Revoked,
-    Expired,
+    /// Admin-registered specialty type, e.g. orthokeratology fitting;
+    /// see `register_custom_record_type`
+    Custom(u32),
 }
 
 /// User information structure
@@ -248,6 +387,8 @@ pub enum EmergencyStatus {
 pub struct User {
     pub address: Address,
     pub role: Role,
+    /// Empty; see [`UserName`] for the salted name hash. Older entries keep
+    /// their plaintext until `migrate_user_names` scrubs them.
     pub name: String,
     pub registered_at: u64,
     pub is_active: bool,
@@ -263,10 +404,26 @@ pub struct VisionRecord {
     pub record_type: RecordType,
     pub data_hash: String,
     pub key_version: Option<String>,
+    /// Insurance eligibility attestation verified when the record was created.
+    pub eligibility_attestation: Option<u64>,
+    /// Emergency grant the record was created under, if any.
+    pub emergency_access_id: Option<u64>,
     pub created_at: u64,
     pub updated_at: u64,
 }
 
+/// Lifecycle of an access grant. Revoked grants are kept so the patient
+/// can restore them within [`ACCESS_RESTORE_WINDOW`].
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GrantStatus {
+    Active,
+    Revoked,
+}
+
+/// How long after `revoke_access` the patient may call `restore_access`.
+pub const ACCESS_RESTORE_WINDOW: u64 = 604_800;
+
 /// Access grant structure
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -276,6 +433,10 @@ pub struct AccessGrant {
     pub level: AccessLevel,
     pub granted_at: u64,
     pub expires_at: u64,
+    pub status: GrantStatus,
+    pub revoked_at: Option<u64>,
+    /// Data-sharing agreement the grant was issued under, if any
+    pub agreement_id: Option<u64>,
 }
 
 /// Consent grant structure for patient-to-provider consent tracking
@@ -290,6 +451,20 @@ pub struct ConsentGrant {
     pub revoked: bool,
 }
 
+/// What `revoke_all_access` ended for a patient
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessLockdownSummary {
+    pub patient: Address,
+    pub grants_revoked: u32,
+    pub scoped_grants_revoked: u32,
+    pub record_grants_revoked: u32,
+    pub org_grants_revoked: u32,
+    pub consents_revoked: u32,
+    pub emergency_grants_revoked: u32,
+    pub locked_at: u64,
+}
+
 /// Input for batch record creation
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -308,6 +483,25 @@ pub struct BatchGrantInput {
     pub duration_seconds: u64,
 }
 
+/// Access grant signed off-chain by a patient and submitted by a relayer.
+///
+/// `level` is 1 = Read, 2 = Write, 3 = Admin. The signature covers the
+/// message built by `meta_tx::build_grant_message`, including both
+/// addresses, and must be made with the key the patient registered through
+/// `register_meta_signer`.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct SignedGrant {
+    pub patient: Address,
+    pub patient_pubkey: BytesN<32>,
+    pub grantee: Address,
+    pub grantee_id: BytesN<32>,
+    pub level: u32,
+    pub expires_at: u64,
+    pub nonce: u64,
+    pub signature: BytesN<64>,
+}
+
 #[contract]
 #[allow(clippy::too_many_arguments)]
 pub struct VisionRecordsContract;
@@ -450,6 +644,7 @@ impl VisionRecordsContract {
         admin_tiers::set_super_admin(&env, &admin);
         admin_tiers::track_admin(&env, &admin);
 
+        admin_receipt::issue(&env, &admin, symbol_short!("INIT"), None);
         events::publish_initialized(&env, admin);
 
         Ok(())
@@ -479,7 +674,8 @@ impl VisionRecordsContract {
     }
 
     /// Propose a new admin address. Only the current admin can call this.
-    /// The new admin must call `accept_admin` to complete the transfer.
+    /// The new admin must call `accept_admin` to complete the transfer, once
+    /// the configured transfer delay has elapsed.
     pub fn propose_admin(
         env: Env,
         current_admin: Address,
@@ -493,14 +689,22 @@ impl VisionRecordsContract {
         }
 
         env.storage().instance().set(&PENDING_ADMIN, &new_admin);
+        let unlock_at = admin_transfer::start(&env);
 
-        events::publish_admin_transfer_proposed(&env, current_admin, new_admin);
+        admin_receipt::issue(
+            &env,
+            &current_admin,
+            symbol_short!("ADM_PROP"),
+            Some(new_admin.clone()),
+        );
+        events::publish_admin_transfer_proposed(&env, current_admin, new_admin, unlock_at);
 
         Ok(())
     }
 
     /// Accept the pending admin transfer. Only the proposed new admin can call this.
-    /// Completes the two-step admin transfer process.
+    /// Completes the two-step admin transfer process, moving the Admin role
+    /// and SuperAdmin tier from the old admin to the new one.
     pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), ContractError> {
         new_admin.require_auth();
 
@@ -513,12 +717,26 @@ impl VisionRecordsContract {
         if new_admin != pending {
             return Self::unauthorized(&env, &new_admin, "accept_admin", "pending_admin");
         }
+        if admin_transfer::unlock_at(&env).is_some_and(|at| env.ledger().timestamp() < at) {
+            return Err(ContractError::AdminTransferLocked);
+        }
 
         let old_admin = Self::get_admin(env.clone())?;
 
         env.storage().instance().set(&ADMIN, &new_admin);
         env.storage().instance().remove(&PENDING_ADMIN);
-
+        admin_transfer::clear(&env);
+
+        // The role and tier travel with the admin key, so the old admin
+        // keeps nothing the key gave them.
+        rbac::clear_role(&env, &old_admin);
+        rbac::assign_role(&env, new_admin.clone(), Role::Admin, 0);
+        admin_tiers::remove_admin_tier(&env, &old_admin);
+        admin_tiers::untrack_admin(&env, &old_admin);
+        admin_tiers::set_super_admin(&env, &new_admin);
+        admin_tiers::track_admin(&env, &new_admin);
+
+        admin_receipt::issue(&env, &new_admin, symbol_short!("ADM_ACC"), Some(old_admin.clone()));
         events::publish_admin_transfer_accepted(&env, old_admin, new_admin);
 
         Ok(())
@@ -545,7 +763,14 @@ impl VisionRecordsContract {
             .ok_or(ContractError::InvalidInput)?;
 
         env.storage().instance().remove(&PENDING_ADMIN);
+        admin_transfer::clear(&env);
 
+        admin_receipt::issue(
+            &env,
+            &current_admin,
+            symbol_short!("ADM_CNCL"),
+            Some(pending.clone()),
+        );
         events::publish_admin_transfer_cancelled(&env, current_admin, pending);
 
         Ok(())
@@ -556,6 +781,114 @@ impl VisionRecordsContract {
         env.storage().instance().get(&PENDING_ADMIN)
     }
 
+    /// Earliest time the pending admin may call `accept_admin`, if a
+    /// transfer is pending.
+    pub fn get_admin_transfer_unlock(env: Env) -> Option<u64> {
+        env.storage()
+            .instance()
+            .get::<_, Address>(&PENDING_ADMIN)
+            .and_then(|_| admin_transfer::unlock_at(&env))
+    }
+
+    /// Set the delay, in seconds, between proposing an admin transfer and the
+    /// proposed admin being able to accept it. Applies to later proposals.
+    pub fn set_admin_transfer_delay(
+        env: Env,
+        current_admin: Address,
+        seconds: u64,
+    ) -> Result<(), ContractError> {
+        current_admin.require_auth();
+
+        let admin = Self::get_admin(env.clone())?;
+        if current_admin != admin {
+            return Self::unauthorized(
+                &env,
+                &current_admin,
+                "set_admin_transfer_delay",
+                "current_admin",
+            );
+        }
+        if seconds > admin_transfer::MAX_ADMIN_TRANSFER_DELAY {
+            return Err(ContractError::InvalidInput);
+        }
+
+        admin_transfer::set_delay(&env, seconds);
+        admin_receipt::issue(&env, &current_admin, symbol_short!("ADM_DLY"), None);
+
+        Ok(())
+    }
+
+    pub fn get_admin_transfer_delay(env: Env) -> u64 {
+        admin_transfer::get_delay(&env)
+    }
+
+    // ── Admin set ────────────────────────────────────────────────────────────
+
+    /// Add `admin` to the admin set with `permissions`, or replace the
+    /// permissions of an admin already in it. Only the primary admin or a
+    /// SystemAdmin may manage the set.
+    pub fn add_admin(
+        env: Env,
+        caller: Address,
+        admin: Address,
+        permissions: Vec<AdminPermission>,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        if !Self::is_primary_admin(&env, &caller) {
+            return Self::unauthorized(&env, &caller, "add_admin", "primary_admin");
+        }
+        if permissions.is_empty() || Some(&admin) == Self::get_admin(env.clone()).ok().as_ref() {
+            return Err(ContractError::InvalidInput);
+        }
+        if !admin_set::set(&env, &admin, &permissions) {
+            return Err(ContractError::QuotaExceeded);
+        }
+
+        admin_receipt::issue(&env, &caller, symbol_short!("ADM_ADD"), Some(admin.clone()));
+        events::publish_admin_added(&env, caller, admin, permissions);
+        Ok(())
+    }
+
+    /// Remove `admin` from the admin set. Only the primary admin or a
+    /// SystemAdmin may manage the set.
+    pub fn remove_admin(env: Env, caller: Address, admin: Address) -> Result<(), ContractError> {
+        caller.require_auth();
+        if !Self::is_primary_admin(&env, &caller) {
+            return Self::unauthorized(&env, &caller, "remove_admin", "primary_admin");
+        }
+        if !admin_set::remove(&env, &admin) {
+            return Err(ContractError::UserNotFound);
+        }
+
+        admin_receipt::issue(&env, &caller, symbol_short!("ADM_REM"), Some(admin.clone()));
+        events::publish_admin_removed(&env, caller, admin);
+        Ok(())
+    }
+
+    /// Secondary admins, in the order they were added
+    pub fn get_admins(env: Env) -> Vec<Address> {
+        admin_set::list(&env)
+    }
+
+    pub fn get_admin_permissions(env: Env, admin: Address) -> Vec<AdminPermission> {
+        admin_set::permissions(&env, &admin)
+    }
+
+    /// True if `admin` may act with `permission`: the primary admin and
+    /// SystemAdmins always may, secondary admins if they were granted it.
+    pub fn has_admin_permission(env: Env, admin: Address, permission: AdminPermission) -> bool {
+        Self::admin_can(&env, &admin, permission)
+    }
+
+    fn is_primary_admin(env: &Env, caller: &Address) -> bool {
+        Self::get_admin(env.clone()).is_ok_and(|admin| admin == *caller)
+            || rbac::has_permission(env, caller, &Permission::SystemAdmin)
+    }
+
+    fn admin_can(env: &Env, caller: &Address, permission: AdminPermission) -> bool {
+        Self::is_primary_admin(env, caller) || admin_set::has_permission(env, caller, permission)
+    }
+
     // ── Multisig management ──────────────────────────────────────────────────
 
     /// Configure M-of-N multisig for admin operations.
@@ -570,12 +903,13 @@ impl VisionRecordsContract {
         }
         caller.require_auth();
 
-        let admin = Self::get_admin(env.clone())?;
-        if caller != admin {
+        if !Self::admin_can(&env, &caller, AdminPermission::Upgrade) {
             return Err(ContractError::Unauthorized);
         }
 
-        multisig::configure(&env, signers, threshold).map_err(|_| ContractError::InvalidInput)
+        multisig::configure(&env, signers, threshold).map_err(|_| ContractError::InvalidInput)?;
+        admin_receipt::issue(&env, &caller, symbol_short!("MSIG_CFG"), None);
+        Ok(())
     }
 
     pub fn propose_admin_action(
@@ -686,6 +1020,7 @@ impl VisionRecordsContract {
             &(max_requests_per_window, window_duration_seconds),
         );
 
+        admin_receipt::issue(&env, &caller, symbol_short!("RATE_SET"), None);
         Ok(())
     }
 
@@ -706,9 +1041,7 @@ impl VisionRecordsContract {
             }
             multisig::mark_executed(&env, proposal_id).map_err(|_| ContractError::Unauthorized)?;
         } else {
-            let admin = Self::get_admin(env.clone())?;
-            let has_system_admin = rbac::has_permission(&env, &caller, &Permission::SystemAdmin);
-            if caller != admin && !has_system_admin {
+            if !Self::admin_can(&env, &caller, AdminPermission::Upgrade) {
                 return Err(ContractError::Unauthorized);
             }
         }
@@ -720,6 +1053,7 @@ impl VisionRecordsContract {
         // Update current active version
         env.storage().instance().set(&ENC_CUR, &version);
 
+        admin_receipt::issue(&env, &caller, symbol_short!("ENC_KEY"), None);
         Ok(())
     }
 
@@ -739,6 +1073,7 @@ impl VisionRecordsContract {
         env.storage().instance().set(&KEY_MGR, &manager);
         env.storage().instance().set(&KEY_MGR_KEY, &root_key_id);
 
+        admin_receipt::issue(&env, &caller, symbol_short!("KEY_MGR"), Some(manager));
         Ok(())
     }
 
@@ -765,13 +1100,18 @@ impl VisionRecordsContract {
             );
         }
         whitelist::set_whitelist_enabled(&env, enabled);
+        admin_receipt::issue(&env, &caller, symbol_short!("WL_SET"), None);
         Ok(())
     }
 
     /// Adds an address to the whitelist.
     ///
     /// Requires at least `ContractAdmin` tier, or legacy admin/SystemAdmin.
-    pub fn add_to_whitelist(env: Env, caller: Address, user: Address) -> Result<(), ContractError> {
+    pub fn add_to_whitelist(
+        env: Env,
+        caller: Address,
+        user: Address,
+    ) -> Result<AdminActionReceipt, ContractError> {
         caller.require_auth();
         if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
             return Self::unauthorized(
@@ -782,7 +1122,12 @@ impl VisionRecordsContract {
             );
         }
         whitelist::add_to_whitelist(&env, &user);
-        Ok(())
+        Ok(admin_receipt::issue(
+            &env,
+            &caller,
+            symbol_short!("WL_ADD"),
+            Some(user),
+        ))
     }
 
     /// Removes an address from the whitelist.
@@ -792,7 +1137,7 @@ impl VisionRecordsContract {
         env: Env,
         caller: Address,
         user: Address,
-    ) -> Result<(), ContractError> {
+    ) -> Result<AdminActionReceipt, ContractError> {
         caller.require_auth();
         if !Self::has_admin_access(&env, &caller, &AdminTier::ContractAdmin) {
             return Self::unauthorized(
@@ -803,7 +1148,12 @@ impl VisionRecordsContract {
             );
         }
         whitelist::remove_from_whitelist(&env, &user);
-        Ok(())
+        Ok(admin_receipt::issue(
+            &env,
+            &caller,
+            symbol_short!("WL_DEL"),
+            Some(user),
+        ))
     }
 
     pub fn is_whitelist_enabled(env: Env) -> bool {
@@ -814,13 +1164,48 @@ impl VisionRecordsContract {
         whitelist::is_whitelisted(&env, &user)
     }
 
-    /// Register a new user
+    /// Register a new user. The name is not stored, only its hash under
+    /// `user_name::plaintext_salt`. That salt can be recomputed by anyone
+    /// reading contract storage, so the hash does not make the name private;
+    /// use `register_user_hashed` with a salt the user keeps for that.
     pub fn register_user(
         env: Env,
         caller: Address,
         user: Address,
         role: Role,
         name: String,
+    ) -> Result<(), ContractError> {
+        validation::validate_name(&name)?;
+        let salt = user_name::plaintext_salt(&env, &user);
+        let name_hash =
+            user_name::salted_hash(&env, &name, &salt).ok_or(ContractError::InvalidInput)?;
+        Self::register_user_inner(env, caller, user, role, name_hash, None)
+    }
+
+    /// Register a new user by the SHA-256 of `salt || name` and, optionally,
+    /// a reference to an encrypted display-name blob. The salt stays with
+    /// the user and is only needed for `verify_name`.
+    pub fn register_user_hashed(
+        env: Env,
+        caller: Address,
+        user: Address,
+        role: Role,
+        name_hash: BytesN<32>,
+        display_ref: Option<String>,
+    ) -> Result<(), ContractError> {
+        if let Some(display_ref) = &display_ref {
+            validation::validate_data_hash(display_ref)?;
+        }
+        Self::register_user_inner(env, caller, user, role, name_hash, display_ref)
+    }
+
+    fn register_user_inner(
+        env: Env,
+        caller: Address,
+        user: Address,
+        role: Role,
+        name_hash: BytesN<32>,
+        display_ref: Option<String>,
     ) -> Result<(), ContractError> {
         circuit_breaker::require_not_paused(
             &env,
@@ -832,8 +1217,17 @@ impl VisionRecordsContract {
             return Self::unauthorized(&env, &caller, "register_user", "whitelisted_caller");
         }
 
-        // Unified check: covers direct role, custom grants, and delegated roles
-        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+        // Users may register themselves under a role that has a registration
+        // proof configured; everyone else (including minors, registered by a
+        // custodian) goes through a caller holding ManageUsers.
+        let self_registration = caller == user
+            && !rbac::has_permission(&env, &caller, &Permission::ManageUsers)
+            && registration_gate::get_requirement(&env, &role).is_some();
+
+        if self_registration {
+            registration_gate::verify_self_registration(&env, &user, &role)?;
+        } else if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+            // Unified check: covers direct role, custom grants, and delegated roles
             let resource_id = String::from_str(&env, "register_user");
             let context = create_error_context(
                 &env,
@@ -852,19 +1246,42 @@ impl VisionRecordsContract {
             return Self::unauthorized(&env, &caller, "register_user", "permission:ManageUsers");
         }
 
-        validation::validate_name(&name)?;
+        // Provider roles registered by anyone but an approver wait in the
+        // approval queue, inactive and without their role.
+        let pending = provider_approval::is_provider_role(&role)
+            && !provider_approval::can_approve(&env, &caller);
 
         let user_data = User {
             address: user.clone(),
             role: role.clone(),
-            name: name.clone(),
+            name: String::from_str(&env, ""),
             registered_at: env.ledger().timestamp(),
-            is_active: true,
+            is_active: !pending,
         };
 
         let key = (symbol_short!("USER"), user.clone());
         env.storage().persistent().set(&key, &user_data);
         extend_ttl_address_key(&env, &key);
+        user_name::set(
+            &env,
+            &user,
+            &UserName {
+                name_hash: name_hash.clone(),
+                display_ref,
+                updated_at: env.ledger().timestamp(),
+            },
+        );
+        if pending {
+            let entry = PendingRegistration {
+                user: user.clone(),
+                role,
+                requested_by: caller,
+                requested_at: env.ledger().timestamp(),
+            };
+            provider_approval::enqueue(&env, &entry);
+            events::publish_registration_pending(&env, &entry);
+            return Ok(());
+        }
         rbac::assign_role(&env, user.clone(), role.clone(), 0);
 
         rbac::assign_role(&env, user.clone(), role.clone(), 0);
@@ -875,86 +1292,448 @@ impl VisionRecordsContract {
         // Create the RBAC role assignment so has_permission works
         rbac::assign_role(&env, user.clone(), role.clone(), 0);
 
-        events::publish_user_registered(&env, user, role, name);
+        admin_receipt::issue(&env, &caller, symbol_short!("REG_USR"), Some(user.clone()));
+        events::publish_user_registered(&env, user, role, name_hash);
 
         Ok(())
     }
 
-    /// Get user information
-    pub fn get_user(env: Env, user: Address) -> Result<User, ContractError> {
+    /// Set (or, with `None`, clear) the address that may approve provider
+    /// registrations alongside SystemAdmins. SystemAdmin only.
+    pub fn set_credentialing_authority(
+        env: Env,
+        caller: Address,
+        authority: Option<Address>,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_credentialing_authority",
+                "permission:SystemAdmin",
+            );
+        }
+        provider_approval::set_credentialing_authority(&env, &authority);
+        admin_receipt::issue(&env, &caller, symbol_short!("CRED_AUTH"), authority);
+        Ok(())
+    }
+
+    pub fn get_credentialing_authority(env: Env) -> Option<Address> {
+        provider_approval::get_credentialing_authority(&env)
+    }
+
+    /// Activate a pending provider registration and assign its role.
+    /// Callable by a SystemAdmin or the credentialing authority.
+    pub fn approve_provider(env: Env, caller: Address, user: Address) -> Result<(), ContractError> {
+        caller.require_auth();
+        if !provider_approval::can_approve(&env, &caller) {
+            return Self::unauthorized(&env, &caller, "approve_provider", "credentialing_authority");
+        }
+        let entry =
+            provider_approval::get(&env, &user).ok_or(ContractError::RegistrationNotPending)?;
+
         let key = (symbol_short!("USER"), user.clone());
-        match env.storage().persistent().get(&key) {
-            Some(user_data) => Ok(user_data),
-            None => {
-                let resource_id = String::from_str(&env, "get_user");
-                let context = create_error_context(
-                    &env,
-                    ContractError::UserNotFound,
-                    Some(user.clone()),
-                    Some(resource_id.clone()),
-                );
-                log_error(
-                    &env,
-                    ContractError::UserNotFound,
-                    Some(user),
-                    Some(resource_id),
-                    None,
-                );
-                events::publish_error(&env, ContractError::UserNotFound as u32, context);
-                Err(ContractError::UserNotFound)
-            }
+        let mut user_data: User = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ContractError::UserNotFound)?;
+        user_data.is_active = true;
+        env.storage().persistent().set(&key, &user_data);
+        extend_ttl_address_key(&env, &key);
+        rbac::assign_role(&env, user.clone(), entry.role.clone(), 0);
+        provider_approval::remove(&env, &user);
+
+        admin_receipt::issue(&env, &caller, symbol_short!("PRV_APPR"), Some(user.clone()));
+        events::publish_provider_approved(&env, caller, user.clone(), entry.role.clone());
+        if let Some(name) = user_name::get(&env, &user) {
+            events::publish_user_registered(&env, user, entry.role, name.name_hash);
         }
+        Ok(())
     }
 
-    /// Add a vision record
-    #[allow(clippy::arithmetic_side_effects)]
-    pub fn add_record(
+    /// Turn down a pending provider registration. The inactive user entry
+    /// is removed so the address can apply again. Callable by a SystemAdmin
+    /// or the credentialing authority.
+    pub fn reject_provider(env: Env, caller: Address, user: Address) -> Result<(), ContractError> {
+        caller.require_auth();
+        if !provider_approval::can_approve(&env, &caller) {
+            return Self::unauthorized(&env, &caller, "reject_provider", "credentialing_authority");
+        }
+        let entry =
+            provider_approval::get(&env, &user).ok_or(ContractError::RegistrationNotPending)?;
+
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("USER"), user.clone()));
+        provider_approval::remove(&env, &user);
+
+        admin_receipt::issue(&env, &caller, symbol_short!("PRV_REJ"), Some(user.clone()));
+        events::publish_provider_rejected(&env, caller, user, entry.role);
+        Ok(())
+    }
+
+    /// Change a registered user's role. Takes effect immediately for active
+    /// users and on reactivation otherwise. Requires the `UserManagement`
+    /// admin permission; only the primary admin may grant `Role::Admin` or
+    /// change an admin's role.
+    pub fn update_user_role(
         env: Env,
         caller: Address,
-        patient: Address,
-        provider: Address,
-        record_type: RecordType,
-        data_hash: String,
-    ) -> Result<u64, ContractError> {
-        let _guard = teye_common::ReentrancyGuard::new(&env);
-        circuit_breaker::require_not_paused(
-            &env,
-            &circuit_breaker::PauseScope::Function(symbol_short!("ADD_REC")),
-        )?;
+        user: Address,
+        role: Role,
+    ) -> Result<(), ContractError> {
         caller.require_auth();
+        if !Self::admin_can(&env, &caller, AdminPermission::UserManagement) {
+            return Self::unauthorized(&env, &caller, "update_user_role", "admin:UserManagement");
+        }
+        Self::require_primary_admin_for(&env, &caller, &user, &role, "update_user_role")?;
+        if provider_approval::get(&env, &user).is_some() {
+            return Err(ContractError::InvalidInput);
+        }
 
-        if !whitelist::check_whitelist_access(&env, &caller) {
-            return Self::unauthorized(&env, &caller, "add_record", "whitelisted_caller");
+        let key = (symbol_short!("USER"), user.clone());
+        let mut user_data: User = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ContractError::UserNotFound)?;
+        let old = user_data.role.clone();
+        user_data.role = role.clone();
+        env.storage().persistent().set(&key, &user_data);
+        extend_ttl_address_key(&env, &key);
+        if user_data.is_active {
+            rbac::assign_role(&env, user.clone(), role.clone(), 0);
         }
 
-        Self::enforce_rate_limit(&env, &caller)?;
+        admin_receipt::issue(&env, &caller, symbol_short!("USR_ROLE"), Some(user.clone()));
+        events::publish_user_role_updated(&env, caller, user, old, role);
+        Ok(())
+    }
 
-        validation::validate_data_hash(&data_hash)?;
+    /// Deactivate a user, e.g. a provider whose keys are compromised. Their
+    /// role is withdrawn and they can no longer add records or
+    /// prescriptions. Requires the `UserManagement` admin permission, or the
+    /// primary admin for admin accounts; admins cannot deactivate
+    /// themselves.
+    pub fn deactivate_user(env: Env, caller: Address, user: Address) -> Result<(), ContractError> {
+        caller.require_auth();
+        if !Self::admin_can(&env, &caller, AdminPermission::UserManagement) {
+            return Self::unauthorized(&env, &caller, "deactivate_user", "admin:UserManagement");
+        }
+        if caller == user {
+            return Err(ContractError::InvalidInput);
+        }
+        let role = Self::stored_role(&env, &user)?;
+        Self::require_primary_admin_for(&env, &caller, &user, &role, "deactivate_user")?;
+        Self::set_user_active(&env, &user, false)?;
+        rbac::clear_role(&env, &user);
 
-        // If caller is the provider, unified check covers direct + delegated WriteRecord.
-        // Otherwise, check if this specific provider delegated to the caller.
-        let has_perm = if caller == provider {
-            rbac::has_permission(&env, &caller, &Permission::WriteRecord)
-        } else {
-            rbac::has_delegated_permission(&env, &provider, &caller, &Permission::WriteRecord)
-        };
+        admin_receipt::issue(&env, &caller, symbol_short!("USR_DEAC"), Some(user.clone()));
+        events::publish_user_active_changed(&env, caller, user, false);
+        Ok(())
+    }
 
-        // Fall back to SystemAdmin (unified: direct role + any delegation)
-        if !has_perm && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
-            // Log failed write attempt
-            let audit_entry = audit::create_audit_entry(
-                &env,
-                caller.clone(),
-                patient.clone(),
-                None,
-                AccessAction::Write,
-                AccessResult::Denied,
-                Some(String::from_str(&env, "Insufficient permissions")),
-            );
-            audit::add_audit_entry(&env, &audit_entry);
-            events::publish_audit_log_entry(&env, &audit_entry);
+    /// Undo `deactivate_user`, restoring the user's stored role. Requires
+    /// the `UserManagement` admin permission, or the primary admin when the
+    /// stored role is `Role::Admin`.
+    pub fn reactivate_user(env: Env, caller: Address, user: Address) -> Result<(), ContractError> {
+        caller.require_auth();
+        if !Self::admin_can(&env, &caller, AdminPermission::UserManagement) {
+            return Self::unauthorized(&env, &caller, "reactivate_user", "admin:UserManagement");
+        }
+        let role = Self::stored_role(&env, &user)?;
+        Self::require_primary_admin_for(&env, &caller, &user, &role, "reactivate_user")?;
+        if provider_approval::get(&env, &user).is_some() {
+            return Err(ContractError::InvalidInput);
+        }
+        let user_data = Self::set_user_active(&env, &user, true)?;
+        rbac::assign_role(&env, user.clone(), user_data.role, 0);
 
-            let context = create_error_context(
+        admin_receipt::issue(&env, &caller, symbol_short!("USR_REAC"), Some(user.clone()));
+        events::publish_user_active_changed(&env, caller, user, true);
+        Ok(())
+    }
+
+    /// The role stored for `user`, active or not.
+    fn stored_role(env: &Env, user: &Address) -> Result<Role, ContractError> {
+        env.storage()
+            .persistent()
+            .get::<_, User>(&(symbol_short!("USER"), user.clone()))
+            .map(|u| u.role)
+            .ok_or(ContractError::UserNotFound)
+    }
+
+    /// `Role::Admin` carries SystemAdmin, which outranks every `admin_set`
+    /// permission, so only the primary admin may hand it out or act on an
+    /// account that holds it.
+    fn require_primary_admin_for(
+        env: &Env,
+        caller: &Address,
+        user: &Address,
+        role: &Role,
+        action: &str,
+    ) -> Result<(), ContractError> {
+        let elevated = *role == Role::Admin || Self::is_primary_admin(env, user);
+        if elevated && !Self::is_primary_admin(env, caller) {
+            return Self::unauthorized(env, caller, action, "primary_admin");
+        }
+        Ok(())
+    }
+
+    /// Sets `is_active` on a stored user. Fails with `InvalidInput` if it is
+    /// already in that state.
+    fn set_user_active(env: &Env, user: &Address, active: bool) -> Result<User, ContractError> {
+        let key = (symbol_short!("USER"), user.clone());
+        let mut user_data: User = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ContractError::UserNotFound)?;
+        if user_data.is_active == active {
+            return Err(ContractError::InvalidInput);
+        }
+        user_data.is_active = active;
+        env.storage().persistent().set(&key, &user_data);
+        extend_ttl_address_key(env, &key);
+        Ok(user_data)
+    }
+
+    /// Refuses registered users that are inactive, whether deactivated or
+    /// still awaiting provider approval. Unregistered addresses pass.
+    fn require_active_user(env: &Env, user: &Address, action: &str) -> Result<(), ContractError> {
+        let user_data: Option<User> = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("USER"), user.clone()));
+        if user_data.is_some_and(|u| !u.is_active) {
+            return Self::unauthorized(env, user, action, "active_user");
+        }
+        Ok(())
+    }
+
+    /// Provider registrations awaiting approval, oldest first
+    pub fn list_pending_registrations(env: Env) -> Vec<PendingRegistration> {
+        provider_approval::list(&env)
+    }
+
+    /// The stored name hash and display-blob reference of `user`
+    pub fn get_user_name(env: Env, user: Address) -> Option<UserName> {
+        user_name::get(&env, &alias::resolve(&env, &user))
+    }
+
+    /// True if `candidate`, salted with `salt`, is the name `user` was
+    /// registered under. For KYC-style checks by a party holding the salt.
+    pub fn verify_name(env: Env, user: Address, candidate: String, salt: Bytes) -> bool {
+        let stored = match user_name::get(&env, &alias::resolve(&env, &user)) {
+            Some(stored) => stored,
+            None => return false,
+        };
+        user_name::salted_hash(&env, &candidate, &salt) == Some(stored.name_hash)
+    }
+
+    /// Storage migration that replaces plaintext `User.name` values with
+    /// their hash under `user_name::plaintext_salt` and blanks the
+    /// plaintext. Users already scrubbed are skipped. Requires SystemAdmin.
+    /// Returns the number of users scrubbed.
+    pub fn migrate_user_names(
+        env: Env,
+        caller: Address,
+        users: Vec<Address>,
+    ) -> Result<u32, ContractError> {
+        caller.require_auth();
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(&env, &caller, "migrate_user_names", "permission:SystemAdmin");
+        }
+        if users.len() > user_name::MAX_MIGRATION_BATCH {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut scrubbed = 0u32;
+        for user in users.iter() {
+            let key = (symbol_short!("USER"), user.clone());
+            let Some(mut user_data) = env.storage().persistent().get::<_, User>(&key) else {
+                continue;
+            };
+            if user_data.name.is_empty() {
+                continue;
+            }
+            let salt = user_name::plaintext_salt(&env, &user);
+            let name_hash = user_name::salted_hash(&env, &user_data.name, &salt)
+                .ok_or(ContractError::InvalidInput)?;
+            user_name::set(
+                &env,
+                &user,
+                &UserName {
+                    name_hash,
+                    display_ref: None,
+                    updated_at: env.ledger().timestamp(),
+                },
+            );
+            user_data.name = String::from_str(&env, "");
+            env.storage().persistent().set(&key, &user_data);
+            extend_ttl_address_key(&env, &key);
+            scrubbed = scrubbed.saturating_add(1);
+        }
+
+        admin_receipt::issue(&env, &caller, symbol_short!("USR_SCRB"), None);
+        events::publish_user_names_scrubbed(&env, caller, scrubbed);
+        Ok(scrubbed)
+    }
+
+    /// Get user information
+    pub fn get_user(env: Env, user: Address) -> Result<User, ContractError> {
+        let user = alias::resolve(&env, &user);
+        let key = (symbol_short!("USER"), user.clone());
+        match env.storage().persistent().get(&key) {
+            Some(user_data) => Ok(user_data),
+            None => {
+                let resource_id = String::from_str(&env, "get_user");
+                let context = create_error_context(
+                    &env,
+                    ContractError::UserNotFound,
+                    Some(user.clone()),
+                    Some(resource_id.clone()),
+                );
+                log_error(
+                    &env,
+                    ContractError::UserNotFound,
+                    Some(user),
+                    Some(resource_id),
+                    None,
+                );
+                events::publish_error(&env, ContractError::UserNotFound as u32, context);
+                Err(ContractError::UserNotFound)
+            }
+        }
+    }
+
+    /// Add a vision record
+    pub fn add_record(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        record_type: RecordType,
+        data_hash: String,
+    ) -> Result<u64, ContractError> {
+        Self::add_record_internal(
+            env,
+            caller,
+            patient,
+            provider,
+            record_type,
+            data_hash,
+            None,
+            None,
+        )
+    }
+
+    /// Add a vision record backed by an insurance eligibility attestation
+    /// issued by the configured claims contract. Required for record types
+    /// that have the eligibility hook enabled.
+    pub fn add_record_with_eligibility(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        record_type: RecordType,
+        data_hash: String,
+        attestation_id: u64,
+    ) -> Result<u64, ContractError> {
+        Self::add_record_internal(
+            env,
+            caller,
+            patient,
+            provider,
+            record_type,
+            data_hash,
+            Some(attestation_id),
+            None,
+        )
+    }
+
+    /// Add a vision record created while treating the patient under an
+    /// emergency grant. The grant must be active, issued to `caller` for
+    /// `patient`, and cover `record_type`; the record is indexed against it
+    /// for post-hoc review via `get_records_for_emergency`.
+    pub fn add_emergency_record(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        record_type: RecordType,
+        data_hash: String,
+        emergency_access_id: u64,
+    ) -> Result<u64, ContractError> {
+        Self::add_record_internal(
+            env,
+            caller,
+            patient,
+            provider,
+            record_type,
+            data_hash,
+            None,
+            Some(emergency_access_id),
+        )
+    }
+
+    #[allow(clippy::arithmetic_side_effects, clippy::too_many_arguments)]
+    fn add_record_internal(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        record_type: RecordType,
+        data_hash: String,
+        eligibility_attestation: Option<u64>,
+        emergency_access_id: Option<u64>,
+    ) -> Result<u64, ContractError> {
+        let _guard = teye_common::ReentrancyGuard::new(&env);
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("ADD_REC")),
+        )?;
+        caller.require_auth();
+
+        if !whitelist::check_whitelist_access(&env, &caller) {
+            return Self::unauthorized(&env, &caller, "add_record", "whitelisted_caller");
+        }
+
+        Self::enforce_rate_limit(&env, &caller)?;
+        Self::require_active_user(&env, &caller, "add_record")?;
+        Self::require_active_user(&env, &provider, "add_record")?;
+
+        validation::validate_data_hash(&data_hash)?;
+        if !record_types::is_usable(&env, &record_type) {
+            return Err(ContractError::InvalidRecordType);
+        }
+
+        // If caller is the provider, unified check covers direct + delegated WriteRecord.
+        // Otherwise, check if this specific provider delegated to the caller.
+        let has_perm = if caller == provider {
+            rbac::has_permission(&env, &caller, &Permission::WriteRecord)
+        } else {
+            rbac::has_delegated_permission(&env, &provider, &caller, &Permission::WriteRecord)
+        };
+
+        // Fall back to SystemAdmin (unified: direct role + any delegation)
+        if !has_perm && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            // Log failed write attempt
+            let audit_entry = audit::create_audit_entry(
+                &env,
+                caller.clone(),
+                patient.clone(),
+                None,
+                AccessAction::Write,
+                AccessResult::Denied,
+                Some(String::from_str(&env, "Insufficient permissions")),
+            );
+            audit::add_audit_entry(&env, &audit_entry);
+            events::publish_audit_log_entry(&env, &audit_entry);
+
+            let context = create_error_context(
                 &env,
                 ContractError::Unauthorized,
                 Some(caller.clone()),
@@ -976,6 +1755,25 @@ impl VisionRecordsContract {
             );
         }
 
+        eligibility::verify_eligibility(&env, &patient, &record_type, eligibility_attestation)?;
+
+        if let Some(access_id) = emergency_access_id {
+            let access = emergency::get_emergency_access(&env, access_id)
+                .ok_or(ContractError::EmergencyAccessNotFound)?;
+            if !emergency::is_active_for(&env, &access, &patient, &caller)
+                || !emergency::scope_allows(&access, &record_type)
+            {
+                return Self::access_denied(
+                    &env,
+                    &caller,
+                    "add_emergency_record",
+                    "active_emergency_access",
+                );
+            }
+        }
+
+        org_quota::consume(&env, &provider, 1)?;
+
         // Generate record ID
         let counter_key = symbol_short!("REC_CTR");
         let record_id: u64 = env.storage().instance().get(&counter_key).unwrap_or(0) + 1;
@@ -1010,6 +1808,8 @@ impl VisionRecordsContract {
             record_type: record_type.clone(),
             data_hash: stored_hash,
             key_version,
+            eligibility_attestation,
+            emergency_access_id,
             created_at: env.ledger().timestamp(),
             updated_at: env.ledger().timestamp(),
         };
@@ -1019,6 +1819,14 @@ impl VisionRecordsContract {
         extend_ttl_u64_key(&env, &key);
         teye_common::concurrency::init_record_version(&env, record_id, 0);
 
+        if let Some(attestation_id) = eligibility_attestation {
+            eligibility::mark_attestation_used(&env, attestation_id, record_id);
+        }
+        if let Some(access_id) = emergency_access_id {
+            emergency::index_record(&env, access_id, record_id);
+            Self::log_emergency_action(&env, access_id, &caller, "RECORDED");
+        }
+
         // Meter: write operation for the provider.
         Self::meter_op(&env, &provider, MeteringOpType::Write);
 
@@ -1033,27 +1841,56 @@ impl VisionRecordsContract {
         env.storage()
             .persistent()
             .set(&patient_key, &patient_records);
+        snapshot::record_change(
+            &env,
+            &patient,
+            StateChangeKind::RecordAdded,
+            Some(record_id),
+            None,
+        );
+        residency::index_record(&env, &provider, record_id);
+        patient_keys::attach_current(&env, &patient, record_id);
+        events::publish_record_added(&env, record_id, patient, provider, record_type);
 
         Ok(record_id)
     }
 
     /// Add multiple vision records in a single transaction.
-    /// Validates provider permission once, then creates all records atomically.
-    #[allow(clippy::arithmetic_side_effects)]
+    /// Same as `add_records_batch`, kept for existing callers.
     pub fn add_records(
         env: Env,
         provider: Address,
         records: Vec<BatchRecordInput>,
+    ) -> Result<Vec<u64>, ContractError> {
+        Self::add_records_batch(env, provider, records)
+    }
+
+    /// Add up to [`MAX_RECORD_BATCH`] records in one transaction, e.g. for a
+    /// clinic migrating from another EMR. Validates provider permission once
+    /// and each entry's data hash, files records under the patient's current
+    /// address, assigns sequential ids in input order and writes each
+    /// patient's record index once.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn add_records_batch(
+        env: Env,
+        provider: Address,
+        entries: Vec<BatchRecordInput>,
     ) -> Result<Vec<u64>, ContractError> {
         circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
         provider.require_auth();
 
-        if records.is_empty() {
+        if entries.is_empty() || entries.len() > MAX_RECORD_BATCH {
             return Err(ContractError::InvalidInput);
         }
+        Self::require_active_user(&env, &provider, "add_records_batch")?;
 
         if !whitelist::check_whitelist_access(&env, &provider) {
-            return Self::unauthorized(&env, &provider, "add_records", "whitelisted_provider");
+            return Self::unauthorized(
+                &env,
+                &provider,
+                "add_records_batch",
+                "whitelisted_provider",
+            );
         }
 
         // Check provider has WriteRecord permission once for the whole batch
@@ -1063,11 +1900,13 @@ impl VisionRecordsContract {
             return Self::unauthorized(
                 &env,
                 &provider,
-                "add_records",
+                "add_records_batch",
                 "permission:WriteRecord_or_SystemAdmin",
             );
         }
 
+        org_quota::consume(&env, &provider, entries.len())?;
+
         let counter_key = symbol_short!("REC_CTR");
         let mut current_id: u64 = env.storage().instance().get(&counter_key).unwrap_or(0);
         let mut record_ids = Vec::new(&env);
@@ -1094,7 +1933,14 @@ impl VisionRecordsContract {
             }
         }
 
-        for input in records.iter() {
+        let mut new_by_patient: Map<Address, Vec<u64>> = Map::new(&env);
+        for input in entries.iter() {
+            validation::validate_data_hash(&input.data_hash)?;
+            if !record_types::is_usable(&env, &input.record_type) {
+                return Err(ContractError::InvalidRecordType);
+            }
+            let patient = alias::resolve(&env, &input.patient);
+            eligibility::verify_eligibility(&env, &patient, &input.record_type, None)?;
             current_id += 1;
 
             // Encrypt input.data_hash with batch master
@@ -1119,34 +1965,39 @@ impl VisionRecordsContract {
 
             let record = VisionRecord {
                 id: current_id,
-                patient: input.patient.clone(),
+                patient: patient.clone(),
                 provider: provider.clone(),
                 record_type: input.record_type.clone(),
                 data_hash: stored_hash,
                 key_version,
+                eligibility_attestation: None,
+                emergency_access_id: None,
                 created_at: env.ledger().timestamp(),
                 updated_at: env.ledger().timestamp(),
             };
 
             let key = (symbol_short!("RECORD"), current_id);
             env.storage().persistent().set(&key, &record);
+            extend_ttl_u64_key(&env, &key);
             teye_common::concurrency::init_record_version(&env, current_id, 0);
 
-            let patient_key = (symbol_short!("PAT_REC"), input.patient.clone());
-            let mut patient_records: Vec<u64> = env
-                .storage()
-                .persistent()
-                .get(&patient_key)
-                .unwrap_or(Vec::new(&env));
-            patient_records.push_back(current_id);
-            env.storage()
-                .persistent()
-                .set(&patient_key, &patient_records);
+            let mut new_ids = new_by_patient.get(patient.clone()).unwrap_or(Vec::new(&env));
+            new_ids.push_back(current_id);
+            new_by_patient.set(patient.clone(), new_ids);
+            snapshot::record_change(
+                &env,
+                &patient,
+                StateChangeKind::RecordAdded,
+                Some(current_id),
+                None,
+            );
+            residency::index_record(&env, &provider, current_id);
+            patient_keys::attach_current(&env, &patient, current_id);
 
             events::publish_record_added(
                 &env,
                 current_id,
-                input.patient.clone(),
+                patient.clone(),
                 provider.clone(),
                 input.record_type.clone(),
             );
@@ -1156,60 +2007,594 @@ impl VisionRecordsContract {
 
         env.storage().instance().set(&counter_key, &current_id);
 
+        for (patient, new_ids) in new_by_patient.iter() {
+            let patient_key = (symbol_short!("PAT_REC"), patient);
+            let mut patient_records: Vec<u64> = env
+                .storage()
+                .persistent()
+                .get(&patient_key)
+                .unwrap_or(Vec::new(&env));
+            patient_records.append(&new_ids);
+            env.storage()
+                .persistent()
+                .set(&patient_key, &patient_records);
+            extend_ttl_address_key(&env, &patient_key);
+        }
+
         events::publish_batch_records_added(&env, provider, record_ids.len());
 
         Ok(record_ids)
     }
 
-    /// Get a vision record by ID.
-    pub fn get_record(
+    /// Read a record, failing with `AccessDenied` unless `reader` is the
+    /// patient, the authoring provider, an active grant holder or an active
+    /// emergency grantee (or reads through locum or cohort cover). Disclosure
+    /// and auditing follow `get_record`, which reports the refusal as
+    /// `Unauthorized`.
+    pub fn read_record(
         env: Env,
-        caller: Address,
+        reader: Address,
         record_id: u64,
     ) -> Result<VisionRecord, ContractError> {
-        caller.require_auth();
-        let key = (symbol_short!("RECORD"), record_id);
-        match env.storage().persistent().get::<_, VisionRecord>(&key) {
-            Some(record) => {
-                // Check access permissions
-                let has_access = if caller == record.patient || caller == record.provider {
-                    // Patient can always read their own records
-                    // Provider can read records they created
-                    true
-                } else {
-                    // Check if caller has broad read permissions, active consent, or explicit grant
-                    rbac::has_permission(&env, &caller, &Permission::ReadAnyRecord)
-                        || rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
-                        || has_active_consent(&env, &record.patient, &caller)
-                        || {
-                            let access_level = Self::check_access(
-                                env.clone(),
-                                record.patient.clone(),
-                                caller.clone(),
-                            );
-                            access_level != AccessLevel::None
-                        }
-                        || Self::check_record_access(env.clone(), record_id, caller.clone())
-                            != AccessLevel::None
-                };
+        Self::get_record(env, reader, record_id).map_err(|err| match err {
+            ContractError::Unauthorized => ContractError::AccessDenied,
+            other => other,
+        })
+    }
 
-                if !has_access {
-                    // Log failed access attempt
-                    let audit_entry = audit::create_audit_entry(
-                        &env,
-                        caller.clone(),
-                        record.patient.clone(),
-                        Some(record_id),
-                        AccessAction::Read,
-                        AccessResult::Denied,
-                        Some(String::from_str(&env, "Insufficient permissions")),
-                    );
-                    audit::add_audit_entry(&env, &audit_entry);
-                    events::publish_audit_log_entry(&env, &audit_entry);
+    /// Log that `reader` read `record_id` and why. Entries are append-only;
+    /// the reader must currently be allowed to read the record.
+    pub fn log_record_access(
+        env: Env,
+        reader: Address,
+        record_id: u64,
+        purpose: String,
+    ) -> Result<RecordReadEntry, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        reader.require_auth();
+        validation::validate_reason(&purpose)?;
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if access_decision::record_tier(&env, &reader, &record) < DisclosureTier::Summary {
+            return Err(ContractError::AccessDenied);
+        }
+
+        let entry = read_log::append(&env, record_id, &reader, &purpose);
+        events::publish_record_read(&env, record.patient, &entry);
+        Ok(entry)
+    }
+
+    /// One page of `record_id`'s read log, oldest first, with
+    /// `read_log::READ_LOG_BUCKET_SIZE` entries per page. Only the record's
+    /// patient or a SystemAdmin may read the log.
+    pub fn get_record_access_log(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        page: u32,
+    ) -> Result<Vec<RecordReadEntry>, ContractError> {
+        Self::require_read_log_viewer(&env, &caller, record_id, "get_record_access_log")?;
+        Ok(read_log::page(&env, record_id, page))
+    }
+
+    /// Total number of logged reads of `record_id`. Same access rule as
+    /// `get_record_access_log`.
+    pub fn get_record_access_count(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<u32, ContractError> {
+        Self::require_read_log_viewer(&env, &caller, record_id, "get_record_access_count")?;
+        Ok(read_log::count(&env, record_id))
+    }
+
+    fn require_read_log_viewer(
+        env: &Env,
+        caller: &Address,
+        record_id: u64,
+        action: &str,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if alias::resolve(env, caller) != record.patient
+            && !rbac::has_permission(env, caller, &Permission::SystemAdmin)
+        {
+            return Self::unauthorized(env, caller, action, "record_patient_or_admin");
+        }
+        Ok(())
+    }
+
+    /// Check `provided_hash`, the hash of an off-chain document, against the
+    /// stored `data_hash` of `record_id` and keep the outcome, without the
+    /// submitted hash, on-chain. The caller needs full disclosure of the
+    /// record, since the check reveals whether a guessed hash is right.
+    pub fn attest_record_integrity(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        provided_hash: String,
+    ) -> Result<IntegrityAttestation, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+        validation::validate_data_hash(&provided_hash)?;
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if access_decision::record_tier(&env, &caller, &record) < DisclosureTier::Full {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "attest_record_integrity",
+                "record_full_disclosure",
+            );
+        }
+
+        let matched = Self::decrypt_data_hash(&env, &record) == provided_hash;
+        let attestation = integrity::append(&env, record_id, &caller, matched);
+        events::publish_integrity_attested(&env, record.patient, &attestation);
+        Ok(attestation)
+    }
+
+    /// One page of `record_id`'s integrity attestations, oldest first, with
+    /// `integrity::ATTESTATION_BUCKET_SIZE` entries per page. Needs full
+    /// disclosure of the record, like its attestation links.
+    pub fn get_integrity_attestations(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        page: u32,
+    ) -> Result<Vec<IntegrityAttestation>, ContractError> {
+        caller.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if access_decision::record_tier(&env, &caller, &record) < DisclosureTier::Full {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_integrity_attestations",
+                "record_full_disclosure",
+            );
+        }
+        Ok(integrity::page(&env, record_id, page))
+    }
+
+    /// Total number of integrity attestations made for `record_id`.
+    pub fn get_integrity_attestation_count(env: Env, record_id: u64) -> u32 {
+        integrity::count(&env, record_id)
+    }
+
+    /// Attach another artifact, such as an OCT scan or fundus photo, to a
+    /// record. Only the record's provider may attach, up to
+    /// `attachment::MAX_ATTACHMENTS_PER_RECORD` per record.
+    pub fn add_attachment(
+        env: Env,
+        provider: Address,
+        record_id: u64,
+        kind: AttachmentKind,
+        hash: String,
+    ) -> Result<RecordAttachment, ContractError> {
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("ADD_REC")),
+        )?;
+        provider.require_auth();
+        validation::validate_data_hash(&hash)?;
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if alias::resolve(&env, &provider) != record.provider {
+            return Self::unauthorized(&env, &provider, "add_attachment", "record_provider");
+        }
+        Self::require_active_user(&env, &provider, "add_attachment")?;
+        if tombstone::is_archived(&env, record_id) {
+            return Err(ContractError::RecordArchived);
+        }
+
+        let attachment = attachment::add(&env, record_id, kind, &hash, &record.provider)
+            .ok_or(ContractError::QuotaExceeded)?;
+        events::publish_attachment_added(&env, record.patient, &attachment);
+        Ok(attachment)
+    }
+
+    /// A record's attachments, oldest first. Attachment hashes are disclosed
+    /// on the same terms as the record's `data_hash`.
+    pub fn get_attachments(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<Vec<RecordAttachment>, ContractError> {
+        caller.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if access_decision::record_tier(&env, &caller, &record) < DisclosureTier::Full {
+            return Self::unauthorized(&env, &caller, "get_attachments", "record_full_disclosure");
+        }
+        Ok(attachment::get_all(&env, record_id))
+    }
+
+    /// Anchor a record as a FHIR resource of `resource_type`, committing to
+    /// the SHA-256 of its canonical JSON. Replaces any earlier envelope on
+    /// the record. Only the record's provider may anchor it.
+    pub fn anchor_fhir_resource(
+        env: Env,
+        provider: Address,
+        record_id: u64,
+        resource_type: String,
+        fhir_version: FhirVersion,
+        canonical_json_hash: BytesN<32>,
+    ) -> Result<FhirEnvelope, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        provider.require_auth();
+        if !fhir::is_valid_resource_type(&resource_type) {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if alias::resolve(&env, &provider) != record.provider {
+            return Self::unauthorized(&env, &provider, "anchor_fhir_resource", "record_provider");
+        }
+        Self::require_active_user(&env, &provider, "anchor_fhir_resource")?;
+        if tombstone::is_archived(&env, record_id) {
+            return Err(ContractError::RecordArchived);
+        }
+
+        let envelope = FhirEnvelope {
+            record_id,
+            resource_type,
+            fhir_version,
+            canonical_json_hash,
+            anchored_at: env.ledger().timestamp(),
+        };
+        fhir::set(&env, &record.patient, &envelope);
+        events::publish_fhir_anchored(&env, record.patient, &envelope);
+        Ok(envelope)
+    }
+
+    /// A record's FHIR envelope, disclosed on the same terms as its
+    /// `data_hash`.
+    pub fn get_fhir_envelope(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<Option<FhirEnvelope>, ContractError> {
+        caller.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if access_decision::record_tier(&env, &caller, &record) < DisclosureTier::Full {
+            return Self::unauthorized(&env, &caller, "get_fhir_envelope", "record_full_disclosure");
+        }
+        Ok(fhir::get(&env, record_id))
+    }
+
+    /// Ids of `patient`'s records anchored as `resource_type`, oldest first.
+    /// The caller must be the patient or hold an active access grant from
+    /// them.
+    pub fn get_records_by_fhir_type(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        resource_type: String,
+    ) -> Result<Vec<u64>, ContractError> {
+        caller.require_auth();
+        let patient = alias::resolve(&env, &patient);
+        if caller != patient
+            && Self::check_access(env.clone(), patient.clone(), caller.clone(), None, None)
+                == AccessLevel::None
+        {
+            return Self::access_denied(&env, &caller, "get_records_by_fhir_type", "active_grant");
+        }
+        Ok(fhir::get_indexed_records(&env, &patient, &resource_type))
+    }
+
+    /// Register, or replace, the Ed25519 public key `provider` signs records
+    /// with. Signatures already stored keep the key they were made with.
+    pub fn register_signing_key(
+        env: Env,
+        provider: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        provider.require_auth();
+        if !rbac::has_permission(&env, &provider, &Permission::WriteRecord) {
+            return Self::unauthorized(&env, &provider, "register_signing_key", "WriteRecord");
+        }
+        Self::require_active_user(&env, &provider, "register_signing_key")?;
+
+        record_signature::set_key(&env, &provider, &public_key);
+        events::publish_signing_key_registered(&env, provider, &public_key);
+        Ok(())
+    }
+
+    /// The Ed25519 public key `provider` signs records with, if registered.
+    pub fn get_signing_key(env: Env, provider: Address) -> Option<BytesN<32>> {
+        record_signature::get_key(&env, &provider)
+    }
+
+    /// The hash a provider signs to vouch for `record_id`; see
+    /// [`record_signature::canonical_hash`]. Needs full disclosure of the
+    /// record, since the hash commits to its `data_hash`.
+    pub fn get_record_signing_hash(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<BytesN<32>, ContractError> {
+        caller.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if access_decision::record_tier(&env, &caller, &record) < DisclosureTier::Full {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_record_signing_hash",
+                "record_full_disclosure",
+            );
+        }
+        let data_hash = Self::decrypt_data_hash(&env, &record);
+        Ok(record_signature::canonical_hash(&env, &record, &data_hash))
+    }
+
+    /// Store the record provider's Ed25519 signature over the record's
+    /// canonical hash, checked against their registered signing key. An
+    /// invalid signature aborts the call. Signing again, e.g. after an
+    /// amendment, replaces the stored signature.
+    pub fn sign_record(
+        env: Env,
+        provider: Address,
+        record_id: u64,
+        signature: BytesN<64>,
+    ) -> Result<RecordSignature, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        provider.require_auth();
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if alias::resolve(&env, &provider) != record.provider {
+            return Self::unauthorized(&env, &provider, "sign_record", "record_provider");
+        }
+        Self::require_active_user(&env, &provider, "sign_record")?;
+        if tombstone::is_archived(&env, record_id) {
+            return Err(ContractError::RecordArchived);
+        }
+        let public_key = record_signature::get_key(&env, &record.provider)
+            .ok_or(ContractError::SigningKeyNotRegistered)?;
+
+        let data_hash = Self::decrypt_data_hash(&env, &record);
+        let canonical_hash = record_signature::canonical_hash(&env, &record, &data_hash);
+        env.crypto().ed25519_verify(
+            &public_key,
+            &Bytes::from_array(&env, &canonical_hash.to_array()),
+            &signature,
+        );
+
+        let stored = RecordSignature {
+            record_id,
+            signer: record.provider.clone(),
+            public_key,
+            canonical_hash,
+            signature,
+            signed_at: env.ledger().timestamp(),
+        };
+        record_signature::set(&env, &stored);
+        events::publish_record_signed(&env, record.patient, &stored);
+        Ok(stored)
+    }
+
+    /// The provider signature stored for `record_id`, if any.
+    pub fn get_record_signature(env: Env, record_id: u64) -> Option<RecordSignature> {
+        record_signature::get(&env, record_id)
+    }
+
+    /// Whether `record_id` carries a provider signature over its current
+    /// contents. False when unsigned or amended since signing.
+    pub fn is_record_signature_current(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<bool, ContractError> {
+        let signing_hash = Self::get_record_signing_hash(env.clone(), caller, record_id)?;
+        Ok(record_signature::get(&env, record_id)
+            .is_some_and(|s| s.canonical_hash == signing_hash))
+    }
+
+    /// Send a record to `supervisor` for co-signature, as a resident or
+    /// student must before the record counts as reviewed. Only the record's
+    /// provider may ask, and the supervisor must be an active provider. A
+    /// new request replaces any earlier one for the record.
+    pub fn request_cosign(
+        env: Env,
+        provider: Address,
+        record_id: u64,
+        supervisor: Address,
+    ) -> Result<CosignRequest, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        provider.require_auth();
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if alias::resolve(&env, &provider) != record.provider {
+            return Self::unauthorized(&env, &provider, "request_cosign", "record_provider");
+        }
+        Self::require_active_user(&env, &provider, "request_cosign")?;
+        if tombstone::is_archived(&env, record_id) {
+            return Err(ContractError::RecordArchived);
+        }
+
+        let supervisor = alias::resolve(&env, &supervisor);
+        if supervisor == record.provider {
+            return Err(ContractError::InvalidInput);
+        }
+        if !rbac::has_permission(&env, &supervisor, &Permission::WriteRecord) {
+            return Err(ContractError::InvalidRole);
+        }
+        Self::require_active_user(&env, &supervisor, "request_cosign")?;
+
+        let request = CosignRequest {
+            record_id,
+            requested_by: record.provider,
+            supervisor,
+            requested_at: env.ledger().timestamp(),
+            cosigned_at: None,
+        };
+        cosign::set(&env, &request);
+        events::publish_cosign_requested(&env, &request);
+        Ok(request)
+    }
+
+    /// Sign off on a record awaiting `supervisor`'s co-signature.
+    pub fn cosign_record(
+        env: Env,
+        supervisor: Address,
+        record_id: u64,
+    ) -> Result<CosignRequest, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        supervisor.require_auth();
+        Self::require_active_user(&env, &supervisor, "cosign_record")?;
+
+        let mut request = cosign::get(&env, record_id)
+            .filter(|r| r.is_pending() && r.supervisor == alias::resolve(&env, &supervisor))
+            .ok_or(ContractError::CosignNotPending)?;
+        request.cosigned_at = Some(env.ledger().timestamp());
+        cosign::set(&env, &request);
+        events::publish_record_cosigned(&env, &request);
+        Ok(request)
+    }
+
+    /// The latest co-sign request for `record_id`, pending or completed.
+    pub fn get_cosign_request(env: Env, record_id: u64) -> Option<CosignRequest> {
+        cosign::get(&env, record_id)
+    }
+
+    /// True while `record_id` is awaiting a supervisor's co-signature.
+    pub fn is_pending_cosign(env: Env, record_id: u64) -> bool {
+        cosign::is_pending(&env, record_id)
+    }
+
+    /// Records awaiting `supervisor`'s co-signature, oldest request first.
+    pub fn get_pending_cosigns(env: Env, supervisor: Address) -> Vec<u64> {
+        cosign::pending_for(&env, &alias::resolve(&env, &supervisor))
+    }
+
+    /// Get a vision record by ID.
+    pub fn get_record(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<VisionRecord, ContractError> {
+        caller.require_auth();
+        let key = (symbol_short!("RECORD"), record_id);
+        match env.storage().persistent().get::<_, VisionRecord>(&key) {
+            Some(record) => {
+                if tombstone::is_archived(&env, record_id)
+                    && !Self::can_archive(&env, &caller, &record)
+                {
+                    return Err(ContractError::RecordArchived);
+                }
+                // A covering provider may read through the absent provider's
+                // grants, and a supervisor what their residents authored.
+                let (tier, cover) = access_decision::covered_tier(&env, &caller, &record);
+
+                if tier < DisclosureTier::Summary {
+                    // Say so when the caller would have been let in at another time of day.
+                    let outside_window = !access_window::is_open(&env, &record.patient, &caller)
+                        && (has_active_consent(&env, &record.patient, &caller)
+                            || access_decision::decide(&env, &record.patient, &caller).reason_code
+                                == AccessReasonCode::OutsideWindow);
+                    let reason = if outside_window {
+                        "Outside access window"
+                    } else {
+                        "Insufficient permissions"
+                    };
+
+                    // Log failed access attempt
+                    let audit_entry = audit::create_audit_entry(
+                        &env,
+                        caller.clone(),
+                        record.patient.clone(),
+                        Some(record_id),
+                        AccessAction::Read,
+                        AccessResult::Denied,
+                        Some(String::from_str(&env, reason)),
+                    );
+                    audit::add_audit_entry(&env, &audit_entry);
+                    events::publish_audit_log_entry(&env, &audit_entry);
 
                     return Self::unauthorized(&env, &caller, "get_record", "record_read_access");
                 }
 
+                match cover {
+                    Some(Cover::Locum(arrangement)) => {
+                        let disclosure = LocumDisclosure {
+                            arrangement_id: arrangement.id,
+                            covering_provider: caller.clone(),
+                            absent_provider: arrangement.absent_provider,
+                            record_id,
+                            accessed_at: env.ledger().timestamp(),
+                        };
+                        locum::add_disclosure(&env, &record.patient, &disclosure);
+                        events::publish_locum_access(&env, record.patient.clone(), &disclosure);
+                    }
+                    Some(Cover::Cohort(grant)) => {
+                        let disclosure = CohortDisclosure {
+                            grant_id: grant.id,
+                            supervisor: caller.clone(),
+                            resident: grant.resident,
+                            record_id,
+                            accessed_at: env.ledger().timestamp(),
+                        };
+                        cohort::add_disclosure(&env, &record.patient, &disclosure);
+                        events::publish_cohort_access(&env, record.patient.clone(), &disclosure);
+                    }
+                    None => {}
+                }
+
+                if sensitivity::requires_record_grant(&env, record_id)
+                    && alias::resolve(&env, &caller) != record.patient
+                {
+                    let receipt = ReadReceipt {
+                        record_id,
+                        reader: caller.clone(),
+                        read_at: env.ledger().timestamp(),
+                    };
+                    sensitivity::add_receipt(&env, &receipt);
+                    events::publish_read_receipt(&env, record.patient.clone(), &receipt);
+                }
+
                 // Log successful access
                 let audit_entry = audit::create_audit_entry(
                     &env,
@@ -1223,9 +2608,21 @@ impl VisionRecordsContract {
                 audit::add_audit_entry(&env, &audit_entry);
                 events::publish_audit_log_entry(&env, &audit_entry);
 
-                // Meter: read operation for the caller.
-                Self::meter_op(&env, &caller, MeteringOpType::Read);
-
+                if caller != record.patient && caller != record.provider {
+                    if let Some(flag) = anomaly::record_activity(
+                        &env,
+                        AnomalyKind::RecordReads,
+                        &caller,
+                        &record.patient,
+                    ) {
+                        events::publish_security_flag(&env, &flag);
+                    }
+                }
+
+                // Meter: read operation for the caller.
+                Self::meter_op(&env, &caller, MeteringOpType::Read);
+                extend_ttl_u64_key(&env, &key);
+
                 // Decrypt data_hash for authorized caller before returning
                 let mut out_record = record.clone();
                 // Prefer record's key_version, fall back to current instance version
@@ -1251,1605 +2648,8542 @@ impl VisionRecordsContract {
                     }
                 }
 
-                if !used_key_manager {
-                    let key_ver = out_record
-                        .key_version
-                        .clone()
-                        .or_else(|| env.storage().instance().get(&ENC_CUR));
-                    if let Some(ver) = key_ver {
-                        if let Some(sv) = env
-                            .storage()
-                            .persistent()
-                            .get::<(Symbol, String), String>(&(ENC_KEY, ver.clone()))
-                        {
-                            let hex = sv.to_string();
-                            if let Some(bytes) = teye_common::hex_to_bytes(&hex) {
-                                master_bytes = bytes;
-                            }
-                        }
-                    }
-                }
+                if !used_key_manager {
+                    let key_ver = out_record
+                        .key_version
+                        .clone()
+                        .or_else(|| env.storage().instance().get(&ENC_CUR));
+                    if let Some(ver) = key_ver {
+                        if let Some(sv) = env
+                            .storage()
+                            .persistent()
+                            .get::<(Symbol, String), String>(&(ENC_KEY, ver.clone()))
+                        {
+                            let hex = sv.to_string();
+                            if let Some(bytes) = teye_common::hex_to_bytes(&hex) {
+                                master_bytes = bytes;
+                            }
+                        }
+                    }
+                }
+
+                if !master_bytes.is_empty() || out_record.key_version.is_none() {
+                    let km = KeyManager::new(master_bytes);
+                    if let Some(plain) = km.decrypt(&env, out_record.data_hash.clone()) {
+                        out_record.data_hash = plain;
+                    }
+                }
+
+                Ok(access_decision::redact(&env, out_record, tier))
+            }
+            None => {
+                if retention::is_archived(&env, record_id) {
+                    return Err(ContractError::RecordArchived);
+                }
+
+                // Log failed access attempt (record not found)
+                // We don't know the patient, so we'll use caller as placeholder
+                let audit_entry = audit::create_audit_entry(
+                    &env,
+                    caller.clone(),
+                    caller.clone(), // Placeholder since we don't know patient
+                    Some(record_id),
+                    AccessAction::Read,
+                    AccessResult::NotFound,
+                    Some(String::from_str(&env, "Record not found")),
+                );
+                audit::add_audit_entry(&env, &audit_entry);
+                events::publish_audit_log_entry(&env, &audit_entry);
+
+                let resource_id = String::from_str(&env, "get_record");
+                let context = create_error_context(
+                    &env,
+                    ContractError::RecordNotFound,
+                    None,
+                    Some(resource_id.clone()),
+                );
+                log_error(
+                    &env,
+                    ContractError::RecordNotFound,
+                    None,
+                    Some(resource_id),
+                    None,
+                );
+                events::publish_error(&env, ContractError::RecordNotFound as u32, context);
+                Err(ContractError::RecordNotFound)
+            }
+        }
+    }
+
+    /// Add eye examination details for an existing record
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_eye_examination(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        visual_acuity: VisualAcuity,
+        iop: IntraocularPressure,
+        slit_lamp: SlitLampFindings,
+        visual_field: OptVisualField,
+        retina_imaging: OptRetinalImaging,
+        fundus_photo: OptFundusPhotography,
+        clinical_notes: String,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        let record = Self::get_record(env.clone(), caller.clone(), record_id)?;
+
+        let has_perm = if caller == record.provider {
+            rbac::has_permission(&env, &caller, &Permission::WriteRecord)
+        } else {
+            rbac::has_delegated_permission(
+                &env,
+                &record.provider,
+                &caller,
+                &Permission::WriteRecord,
+            )
+        };
+
+        if !has_perm && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "add_eye_examination",
+                "permission:WriteRecord_or_SystemAdmin",
+            );
+        }
+
+        if record.record_type != RecordType::Examination {
+            return Err(ContractError::InvalidRecordType);
+        }
+        if !examination::acuity_is_valid(&visual_acuity) {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let exam = EyeExamination {
+            record_id,
+            visual_acuity,
+            iop,
+            slit_lamp,
+            visual_field,
+            retina_imaging,
+            fundus_photo,
+            clinical_notes,
+        };
+
+        examination::set_examination(&env, &exam);
+
+        audit::AuditManager::log_event(
+            &env,
+            caller.clone(),
+            "examination.add",
+            soroban_sdk::String::from_str(&env, &record_id.to_string()),
+            "ok",
+        );
+
+        events::publish_examination_added(&env, record_id);
+
+        Ok(())
+    }
+
+    /// Update eye examination details using optimistic concurrency control (OCC).
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_examination_versioned(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        expected_version: u64,
+        node_id: u32,
+        visual_acuity: VisualAcuity,
+        iop: IntraocularPressure,
+        slit_lamp: SlitLampFindings,
+        visual_field: OptVisualField,
+        retina_imaging: OptRetinalImaging,
+        fundus_photo: OptFundusPhotography,
+        clinical_notes: String,
+        changed_fields: Vec<FieldChange>,
+    ) -> Result<UpdateOutcome, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        let record = Self::get_record(env.clone(), caller.clone(), record_id)?;
+
+        let has_perm = if caller == record.provider {
+            rbac::has_permission(&env, &caller, &Permission::WriteRecord)
+        } else {
+            rbac::has_delegated_permission(
+                &env,
+                &record.provider,
+                &caller,
+                &Permission::WriteRecord,
+            )
+        };
+
+        if !has_perm && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "update_examination_versioned",
+                "permission:WriteRecord_or_SystemAdmin",
+            );
+        }
+
+        if record.record_type != RecordType::Examination {
+            return Err(ContractError::InvalidRecordType);
+        }
+        if !examination::acuity_is_valid(&visual_acuity) {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let exam = EyeExamination {
+            record_id,
+            visual_acuity,
+            iop,
+            slit_lamp,
+            visual_field,
+            retina_imaging,
+            fundus_photo,
+            clinical_notes,
+        };
+
+        let outcome = examination::versioned_set_examination(
+            &env,
+            &exam,
+            expected_version,
+            node_id,
+            &caller,
+            &changed_fields,
+        );
+
+        Ok(outcome)
+    }
+
+    /// Retrieve eye examination details for a record
+    pub fn get_eye_examination(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<EyeExamination, ContractError> {
+        caller.require_auth();
+        let record = Self::get_record(env.clone(), caller.clone(), record_id)?;
+
+        let has_perm = if caller == record.patient || caller == record.provider {
+            true
+        } else {
+            let access = Self::check_access(
+                env.clone(),
+                record.patient.clone(),
+                caller.clone(),
+                None,
+                None,
+            );
+            let record_access = Self::check_record_access(env.clone(), record_id, caller.clone());
+            access == AccessLevel::Read
+                || access == AccessLevel::Write
+                || access == AccessLevel::Full
+                || record_access != AccessLevel::None
+                || rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
+        };
+
+        if !has_perm {
+            return Self::access_denied(&env, &caller, "get_eye_examination", "record_read_access");
+        }
+
+        examination::get_examination(&env, record_id).ok_or(ContractError::RecordNotFound)
+    }
+
+    /// Return the current OCC version stamp for a record.
+    pub fn get_record_version_stamp(env: Env, record_id: u64) -> VersionStamp {
+        examination::get_exam_version(&env, record_id)
+    }
+
+    /// Configure conflict resolution strategy for a record.
+    pub fn set_record_resolution_strategy(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        strategy: ResolutionStrategy,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        let record = Self::get_record(env.clone(), caller.clone(), record_id)?;
+        let has_perm = if caller == record.provider {
+            rbac::has_permission(&env, &caller, &Permission::WriteRecord)
+        } else {
+            rbac::has_delegated_permission(
+                &env,
+                &record.provider,
+                &caller,
+                &Permission::WriteRecord,
+            )
+        };
+
+        if !has_perm && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_record_resolution_strategy",
+                "permission:WriteRecord_or_SystemAdmin",
+            );
+        }
+
+        teye_common::concurrency::set_resolution_strategy(&env, record_id, &strategy);
+        admin_receipt::issue(&env, &caller, symbol_short!("RES_STRAT"), None);
+        Ok(())
+    }
+
+    /// Retrieve conflicts for a specific record.
+    pub fn get_record_conflicts(env: Env, record_id: u64) -> Vec<ConflictEntry> {
+        teye_common::concurrency::get_record_conflicts(&env, record_id)
+    }
+
+    /// Retrieve all pending conflicts.
+    pub fn get_pending_conflicts(env: Env) -> Vec<ConflictEntry> {
+        teye_common::concurrency::get_pending_conflicts(&env)
+    }
+
+    /// Resolve a conflict by marking it handled. Callable by admins holding
+    /// `RecordOversight` or by the record's provider (or their delegate).
+    pub fn resolve_conflict(
+        env: Env,
+        caller: Address,
+        conflict_id: u64,
+        record_id: u64,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        if !Self::admin_can(&env, &caller, AdminPermission::RecordOversight) {
+            let key = (symbol_short!("RECORD"), record_id);
+            let record = env
+                .storage()
+                .persistent()
+                .get::<_, VisionRecord>(&key)
+                .ok_or(ContractError::RecordNotFound)?;
+
+            let has_perm = if caller == record.provider {
+                rbac::has_permission(&env, &caller, &Permission::WriteRecord)
+            } else {
+                rbac::has_delegated_permission(
+                    &env,
+                    &record.provider,
+                    &caller,
+                    &Permission::WriteRecord,
+                )
+            };
+
+            if !has_perm {
+                return Self::unauthorized(
+                    &env,
+                    &caller,
+                    "resolve_conflict",
+                    "permission:WriteRecord_or_RecordOversight",
+                );
+            }
+        }
+
+        if !teye_common::concurrency::resolve_conflict(&env, conflict_id, &caller) {
+            return Err(ContractError::RecordNotFound);
+        }
+
+        Ok(())
+    }
+
+    /// Get all records for a patient
+    pub fn get_patient_records(env: Env, patient: Address) -> Vec<u64> {
+        let key = (symbol_short!("PAT_REC"), alias::resolve(&env, &patient));
+        env.storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Ids of `patient`'s records of `record_type`, oldest first.
+    pub fn get_patient_records_by_type(
+        env: Env,
+        patient: Address,
+        record_type: RecordType,
+    ) -> Vec<u64> {
+        let mut matching = Vec::new(&env);
+        for id in Self::get_patient_records(env.clone(), patient).iter() {
+            let record: Option<VisionRecord> = env
+                .storage()
+                .persistent()
+                .get(&(symbol_short!("RECORD"), id));
+            if record.map(|r| r.record_type == record_type).unwrap_or(false) {
+                matching.push_back(id);
+            }
+        }
+        matching
+    }
+
+    /// Ids of `patient`'s records created within `[from_ts, to_ts]` and,
+    /// if given, of `record_type`, oldest first. Records archived with
+    /// `archive_record` are skipped unless `include_archived` is set.
+    /// `offset` counts matching records; at most [`MAX_RECORD_PAGE`] ids are
+    /// returned per call.
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_patient_records_filtered(
+        env: Env,
+        patient: Address,
+        record_type: Option<RecordType>,
+        from_ts: u64,
+        to_ts: u64,
+        include_archived: bool,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<u64> {
+        let limit = limit.min(MAX_RECORD_PAGE);
+        let mut page = Vec::new(&env);
+        let mut skipped: u32 = 0;
+        for id in Self::get_patient_records(env.clone(), patient).iter() {
+            if page.len() >= limit {
+                break;
+            }
+            let record: Option<VisionRecord> = env
+                .storage()
+                .persistent()
+                .get(&(symbol_short!("RECORD"), id));
+            let Some(record) = record else {
+                continue;
+            };
+            if record.created_at < from_ts
+                || record.created_at > to_ts
+                || record_type.as_ref().is_some_and(|t| *t != record.record_type)
+                || (!include_archived && tombstone::is_archived(&env, id))
+            {
+                continue;
+            }
+            if skipped < offset {
+                skipped = skipped.saturating_add(1);
+                continue;
+            }
+            page.push_back(id);
+        }
+        page
+    }
+
+    /// Archive a record without deleting it. Archived records are hidden
+    /// from everyone but the patient and admins and can be brought back with
+    /// `restore_record`. The patient or a SystemAdmin must authorize.
+    /// Refused while the record or its patient is under a legal hold.
+    pub fn archive_record(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<RecordTombstone, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        let record = Self::record_for_archive(&env, &caller, record_id, "archive_record")?;
+        if tombstone::is_archived(&env, record_id) {
+            return Err(ContractError::RecordArchived);
+        }
+        if legal_hold::is_record_held(&env, record_id, &record.patient) {
+            return Err(ContractError::LegalHoldActive);
+        }
+
+        let entry = RecordTombstone {
+            record_id,
+            archived_by: caller.clone(),
+            archived_at: env.ledger().timestamp(),
+        };
+        tombstone::set(&env, &entry);
+        Self::log_archive_change(&env, &caller, &record, StateChangeKind::RecordArchived);
+        events::publish_record_tombstoned(&env, record.patient, &entry);
+        Ok(entry)
+    }
+
+    /// Undo `archive_record`. The patient or a SystemAdmin must authorize.
+    pub fn restore_record(env: Env, caller: Address, record_id: u64) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        let record = Self::record_for_archive(&env, &caller, record_id, "restore_record")?;
+        if !tombstone::is_archived(&env, record_id) {
+            return Err(ContractError::InvalidInput);
+        }
+
+        tombstone::remove(&env, record_id);
+        Self::log_archive_change(&env, &caller, &record, StateChangeKind::RecordRestored);
+        events::publish_record_restored(&env, record.patient, record_id, caller);
+        Ok(())
+    }
+
+    /// The archive marker for `record_id`, if it is archived.
+    pub fn get_record_tombstone(env: Env, record_id: u64) -> Option<RecordTombstone> {
+        tombstone::get(&env, record_id)
+    }
+
+    fn can_archive(env: &Env, caller: &Address, record: &VisionRecord) -> bool {
+        alias::resolve(env, caller) == record.patient
+            || rbac::has_permission(env, caller, &Permission::SystemAdmin)
+    }
+
+    fn record_for_archive(
+        env: &Env,
+        caller: &Address,
+        record_id: u64,
+        function: &str,
+    ) -> Result<VisionRecord, ContractError> {
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if !Self::can_archive(env, caller, &record) {
+            return Self::unauthorized(env, caller, function, "patient_or_SystemAdmin");
+        }
+        Ok(record)
+    }
+
+    /// Keeps archiving visible in the audit trail and the patient's change
+    /// journal; the record and its history are left untouched.
+    fn log_archive_change(
+        env: &Env,
+        caller: &Address,
+        record: &VisionRecord,
+        kind: StateChangeKind,
+    ) {
+        let audit_entry = audit::create_audit_entry(
+            env,
+            caller.clone(),
+            record.patient.clone(),
+            Some(record.id),
+            AccessAction::Delete,
+            AccessResult::Success,
+            None,
+        );
+        audit::add_audit_entry(env, &audit_entry);
+        events::publish_audit_log_entry(env, &audit_entry);
+        snapshot::record_change(env, &record.patient, kind, Some(record.id), None);
+    }
+
+    /// Grant access to a user
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn grant_access(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        grantee: Address,
+        level: AccessLevel,
+        duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        let _guard = teye_common::ReentrancyGuard::new(&env);
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("GRT_ACC")),
+        )?;
+        caller.require_auth();
+
+        Self::enforce_rate_limit(&env, &caller)?;
+
+        validation::validate_duration(duration_seconds)?;
+
+        let has_perm = if caller == patient {
+            true // Patient manages own access
+        } else {
+            // Specific patient→caller delegation for ManageAccess
+            rbac::has_delegated_permission(&env, &patient, &caller, &Permission::ManageAccess)
+                // Or caller has SystemAdmin (unified: direct + any delegation)
+                || rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
+                // Or caller is the patient's guardian
+                || guardian::is_guardian(&env, &caller, &patient)
+        };
+
+        if !has_perm {
+            // Log failed access grant attempt
+            let audit_entry = audit::create_audit_entry(
+                &env,
+                caller.clone(),
+                patient.clone(),
+                None,
+                AccessAction::GrantAccess,
+                AccessResult::Denied,
+                Some(String::from_str(&env, "Insufficient permissions")),
+            );
+            audit::add_audit_entry(&env, &audit_entry);
+            events::publish_audit_log_entry(&env, &audit_entry);
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "grant_access",
+                "patient_guardian_or_permission:ManageAccess_or_SystemAdmin",
+            );
+        }
+
+        Self::store_access_grant(&env, patient, grantee, level, duration_seconds);
+        Ok(())
+    }
+
+    /// Writes an active grant from `patient` to `grantee` once the caller's
+    /// authority has been checked, and publishes the usual grant events.
+    #[allow(clippy::arithmetic_side_effects)]
+    fn store_access_grant(
+        env: &Env,
+        patient: Address,
+        grantee: Address,
+        level: AccessLevel,
+        duration_seconds: u64,
+    ) {
+        let expires_at = env.ledger().timestamp() + duration_seconds;
+        let grant = AccessGrant {
+            patient: patient.clone(),
+            grantee: grantee.clone(),
+            level: level.clone(),
+            granted_at: env.ledger().timestamp(),
+            expires_at,
+            status: GrantStatus::Active,
+            revoked_at: None,
+            agreement_id: None,
+        };
+
+        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+        env.storage().persistent().set(&key, &grant);
+        extend_ttl_access_key(env, &key);
+
+        Self::track_grantee(env, &patient, &grantee);
+        snapshot::record_change(
+            env,
+            &patient,
+            StateChangeKind::AccessGranted,
+            None,
+            Some(grantee.clone()),
+        );
+
+        Self::notify_grant_routing(env, &patient, &grantee);
+        events::publish_access_granted(env, patient, grantee, level, duration_seconds, expires_at);
+    }
+
+    /// Grant access to multiple users in a single transaction.
+    /// Patient authorizes once for the entire batch.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn grant_access_batch(
+        env: Env,
+        patient: Address,
+        grants: Vec<BatchGrantInput>,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+
+        if grants.is_empty() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let now = env.ledger().timestamp();
+        for grant in grants.iter() {
+            let expires_at = now + grant.duration_seconds;
+            let access_grant = AccessGrant {
+                patient: patient.clone(),
+                grantee: grant.grantee.clone(),
+                level: grant.level.clone(),
+                granted_at: now,
+                expires_at,
+                status: GrantStatus::Active,
+                revoked_at: None,
+                agreement_id: None,
+            };
+            let key = (
+                symbol_short!("ACCESS"),
+                patient.clone(),
+                grant.grantee.clone(),
+            );
+            env.storage().persistent().set(&key, &access_grant);
+            Self::track_grantee(&env, &patient, &grant.grantee);
+            snapshot::record_change(
+                &env,
+                &patient,
+                StateChangeKind::AccessGranted,
+                None,
+                Some(grant.grantee.clone()),
+            );
+
+            events::publish_access_granted(
+                &env,
+                patient.clone(),
+                grant.grantee.clone(),
+                grant.level.clone(),
+                grant.duration_seconds,
+                expires_at,
+            );
+            Self::notify_grant_routing(&env, &patient, &grant.grantee);
+        }
+
+        events::publish_batch_access_granted(&env, patient, grants.len());
+
+        Ok(())
+    }
+
+    /// Check access level with ABAC policy evaluation. Given a `record_id`
+    /// or `record_type`, the narrowest active scoped grant covering it
+    /// decides: one on the record, then one on its type (taken from the
+    /// record when only `record_id` is given). Without a matching scoped
+    /// grant, the patient-wide grant decides, then a grant to the grantee's
+    /// organization. A provider on the patient's care team gets at least
+    /// Read on records they authored.
+    pub fn check_access(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        record_id: Option<u64>,
+        record_type: Option<RecordType>,
+    ) -> AccessLevel {
+        let resolved_patient = alias::resolve(&env, &patient);
+        let resolved_grantee = alias::resolve(&env, &grantee);
+        let record: Option<VisionRecord> = record_id.and_then(|id| {
+            env.storage()
+                .persistent()
+                .get(&(symbol_short!("RECORD"), id))
+        });
+        // A care-team provider reads back what they wrote without a grant.
+        let care_team_author = record.as_ref().is_some_and(|r| {
+            r.patient == resolved_patient
+                && r.provider == resolved_grantee
+                && care_team::is_active(&env, &resolved_patient, &resolved_grantee)
+                && access_decision::is_active_user(&env, &resolved_grantee)
+        });
+        let record_type = record_type.or_else(|| record.map(|r| r.record_type));
+        let level = access_decision::granted_level(
+            &env,
+            &resolved_patient,
+            &resolved_grantee,
+            record_id,
+            record_type.as_ref(),
+        );
+        if level == AccessLevel::None && care_team_author {
+            AccessLevel::Read
+        } else {
+            level
+        }
+    }
+
+    /// Like `check_access`, but also reports what the decision rests on,
+    /// when it lapses and, for denials, why access was refused.
+    pub fn check_access_detailed(env: Env, patient: Address, grantee: Address) -> AccessDecision {
+        let patient = alias::resolve(&env, &patient);
+        let grantee = alias::resolve(&env, &grantee);
+        access_decision::decide(&env, &patient, &grantee)
+    }
+
+    /// How much of `record_id` `caller` would see through `get_record`,
+    /// before any locum or cohort cover. `Existence` means the record id can
+    /// be acknowledged but not read.
+    pub fn get_record_tier(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<DisclosureTier, ContractError> {
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        Ok(access_decision::record_tier(&env, &caller, &record))
+    }
+
+    /// Grant record-level access to a specific record.
+    #[allow(clippy::arithmetic_side_effects)]
+    pub fn grant_record_access(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        record_id: u64,
+        level: AccessLevel,
+        duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        validation::validate_duration(duration_seconds)?;
+
+        let record_key = (symbol_short!("RECORD"), record_id);
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&record_key)
+            .ok_or(ContractError::RecordNotFound)?;
+        if record.patient != patient {
+            return Self::unauthorized(&env, &patient, "grant_record_access", "record_owner");
+        }
+
+        let now = env.ledger().timestamp();
+        let expires_at = now + duration_seconds;
+        let grant = AccessGrant {
+            patient: patient.clone(),
+            grantee: grantee.clone(),
+            level: level.clone(),
+            granted_at: now,
+            expires_at,
+            status: GrantStatus::Active,
+            revoked_at: None,
+            agreement_id: None,
+        };
+
+        let key = (symbol_short!("REC_ACC"), record_id, grantee.clone());
+        env.storage().persistent().set(&key, &grant);
+        extend_ttl_record_access_key(&env, &key);
+        Self::track_record_grantee(&env, record_id, &grantee);
+
+        events::publish_record_access_granted(
+            &env,
+            patient,
+            grantee,
+            record_id,
+            level,
+            duration_seconds,
+            expires_at,
+        );
+        Ok(())
+    }
+
+    /// Check record-level access for a specific grantee.
+    pub fn check_record_access(env: Env, record_id: u64, grantee: Address) -> AccessLevel {
+        let key = (symbol_short!("REC_ACC"), record_id, grantee);
+        if let Some(grant) = env.storage().persistent().get::<_, AccessGrant>(&key) {
+            if grant.status == GrantStatus::Active && grant.expires_at > env.ledger().timestamp() {
+                return grant.level;
+            }
+        }
+        AccessLevel::None
+    }
+
+    /// Revoke record-level access for a specific record.
+    pub fn revoke_record_access(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        record_id: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        let record_key = (symbol_short!("RECORD"), record_id);
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&record_key)
+            .ok_or(ContractError::RecordNotFound)?;
+        if record.patient != patient {
+            return Self::unauthorized(&env, &patient, "revoke_record_access", "record_owner");
+        }
+
+        let key = (symbol_short!("REC_ACC"), record_id, grantee);
+        env.storage().persistent().remove(&key);
+        Ok(())
+    }
+
+    /// Grant `grantee` access to one record or to every record of one type.
+    /// Granting the same scope again replaces the earlier grant.
+    pub fn grant_scoped_access(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        scope: AccessScope,
+        level: AccessLevel,
+        duration_seconds: u64,
+    ) -> Result<ScopedAccessGrant, ContractError> {
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("GRT_ACC")),
+        )?;
+        patient.require_auth();
+        validation::validate_duration(duration_seconds)?;
+        if level == AccessLevel::None || patient == grantee {
+            return Err(ContractError::InvalidInput);
+        }
+        if let AccessScope::Record(record_id) = &scope {
+            let record: VisionRecord = env
+                .storage()
+                .persistent()
+                .get(&(symbol_short!("RECORD"), *record_id))
+                .ok_or(ContractError::RecordNotFound)?;
+            if record.patient != patient {
+                return Self::unauthorized(&env, &patient, "grant_scoped_access", "record_owner");
+            }
+        }
+
+        let now = env.ledger().timestamp();
+        let grant = ScopedAccessGrant {
+            patient,
+            grantee,
+            scope,
+            level,
+            granted_at: now,
+            expires_at: now.saturating_add(duration_seconds),
+            status: GrantStatus::Active,
+            revoked_at: None,
+        };
+        if !scoped_grant::put(&env, &grant) {
+            return Err(ContractError::QuotaExceeded);
+        }
+        events::publish_scoped_access_granted(&env, &grant);
+        Ok(grant)
+    }
+
+    /// Revoke `grantee`'s grant for `scope`.
+    pub fn revoke_scoped_access(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        scope: AccessScope,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("RVK_ACC")),
+        )?;
+        patient.require_auth();
+        if !scoped_grant::revoke(&env, &patient, &grantee, &scope) {
+            return Err(ContractError::AccessGrantNotFound);
+        }
+        events::publish_scoped_access_revoked(&env, patient, grantee, scope);
+        Ok(())
+    }
+
+    /// Every scoped grant `grantee` holds from `patient`, including
+    /// revoked and expired ones.
+    pub fn get_scoped_grants(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+    ) -> Vec<ScopedAccessGrant> {
+        scoped_grant::get_grants(
+            &env,
+            &alias::resolve(&env, &patient),
+            &alias::resolve(&env, &grantee),
+        )
+    }
+
+    /// Grant consent for a grantee.
+    pub fn grant_consent(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        consent_type: ConsentType,
+        duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        if duration_seconds == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        let now = env.ledger().timestamp();
+        let consent = ConsentGrant {
+            patient: patient.clone(),
+            grantee: grantee.clone(),
+            consent_type: consent_type.clone(),
+            granted_at: now,
+            expires_at: now.saturating_add(duration_seconds),
+            revoked: false,
+        };
+        let key = consent_key(&patient, &grantee);
+        env.storage().persistent().set(&key, &consent);
+        extend_ttl_access_key(&env, &key);
+        Self::track_consent_grantee(&env, &patient, &grantee);
+        snapshot::record_change(
+            &env,
+            &patient,
+            StateChangeKind::ConsentGranted,
+            None,
+            Some(grantee.clone()),
+        );
+        events::publish_consent_granted(&env, patient, grantee, consent_type, consent.expires_at);
+        Ok(())
+    }
+
+    /// Revoke previously granted consent.
+    pub fn revoke_consent(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        let key = consent_key(&patient, &grantee);
+        if let Some(mut consent) = env.storage().persistent().get::<_, ConsentGrant>(&key) {
+            consent.revoked = true;
+            env.storage().persistent().set(&key, &consent);
+            snapshot::record_change(
+                &env,
+                &patient,
+                StateChangeKind::ConsentRevoked,
+                None,
+                Some(grantee.clone()),
+            );
+        }
+        events::publish_consent_revoked(&env, patient, grantee);
+        Ok(())
+    }
+
+    /// Record the patient's consent to their data being used for `purpose`,
+    /// over `scope`, until `expires_at`. Replaces any earlier consent for
+    /// the same purpose. Independent of access grants, which name recipients.
+    pub fn record_consent(
+        env: Env,
+        patient: Address,
+        purpose: ConsentPurpose,
+        scope: ConsentScope,
+        expires_at: u64,
+    ) -> Result<PurposeConsent, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        let patient = alias::resolve(&env, &patient);
+
+        let now = env.ledger().timestamp();
+        if expires_at <= now {
+            return Err(ContractError::InvalidInput);
+        }
+        if let ConsentScope::Record(record_id) = scope {
+            let record: VisionRecord = env
+                .storage()
+                .persistent()
+                .get(&(symbol_short!("RECORD"), record_id))
+                .ok_or(ContractError::RecordNotFound)?;
+            if record.patient != patient {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+
+        let consent = PurposeConsent {
+            patient,
+            purpose,
+            scope,
+            recorded_at: now,
+            expires_at,
+            withdrawn_at: None,
+        };
+        purpose_consent::set(&env, &consent);
+        events::publish_purpose_consent_changed(&env, &consent);
+        Ok(consent)
+    }
+
+    /// Withdraw the patient's live consent for `purpose`.
+    pub fn withdraw_consent(
+        env: Env,
+        patient: Address,
+        purpose: ConsentPurpose,
+    ) -> Result<PurposeConsent, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        let patient = alias::resolve(&env, &patient);
+        let mut consent =
+            purpose_consent::active(&env, &patient, purpose).ok_or(ContractError::InvalidInput)?;
+
+        consent.withdrawn_at = Some(env.ledger().timestamp());
+        purpose_consent::set(&env, &consent);
+        events::publish_purpose_consent_changed(&env, &consent);
+        Ok(consent)
+    }
+
+    /// True if the patient currently consents to `purpose` for any of their
+    /// records. Meant to be consulted, here or by other contracts, before
+    /// data is disclosed for that purpose.
+    pub fn check_consent(env: Env, patient: Address, purpose: ConsentPurpose) -> bool {
+        purpose_consent::active(&env, &alias::resolve(&env, &patient), purpose).is_some()
+    }
+
+    /// Like `check_consent`, but also requires the consent's scope to take
+    /// in `record_id`.
+    pub fn check_record_consent(
+        env: Env,
+        patient: Address,
+        purpose: ConsentPurpose,
+        record_id: u64,
+    ) -> bool {
+        let patient = alias::resolve(&env, &patient);
+        let record: Option<VisionRecord> = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id));
+        match (record, purpose_consent::active(&env, &patient, purpose)) {
+            (Some(record), Some(consent)) => {
+                record.patient == patient && consent.covers(record_id, &record.record_type)
+            }
+            _ => false,
+        }
+    }
+
+    /// Every purpose consent the patient has recorded, including withdrawn
+    /// and expired ones.
+    pub fn get_purpose_consents(env: Env, patient: Address) -> Vec<PurposeConsent> {
+        let patient = alias::resolve(&env, &patient);
+        let mut out = Vec::new(&env);
+        for purpose in purpose_consent::purposes(&env, &patient).iter() {
+            if let Some(consent) = purpose_consent::get(&env, &patient, purpose) {
+                out.push_back(consent);
+            }
+        }
+        out
+    }
+
+    /// Soft-revokes `patient`'s grant to `grantee` on behalf of `actor`, who
+    /// has already been authorized, and cascades to the grantee's
+    /// delegations.
+    fn revoke_grant(env: &Env, actor: &Address, patient: &Address, grantee: &Address) {
+        // Soft delete: keep the grant so it can be restored within the window.
+        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+        if let Some(mut grant) = env.storage().persistent().get::<_, AccessGrant>(&key) {
+            if grant.status == GrantStatus::Active {
+                grant.status = GrantStatus::Revoked;
+                grant.revoked_at = Some(env.ledger().timestamp());
+                env.storage().persistent().set(&key, &grant);
+                extend_ttl_access_key(env, &key);
+                events::publish_access_revoked(env, patient.clone(), grantee.clone());
+            }
+        }
+        access_decision::mark_revoked(env, patient, grantee);
+        Self::prune_grantees(env, patient);
+        snapshot::record_change(
+            env,
+            patient,
+            StateChangeKind::AccessRevoked,
+            None,
+            Some(grantee.clone()),
+        );
+
+        let revoked_delegations = rbac::revoke_delegations_from(env, grantee);
+        for revoked in revoked_delegations.iter() {
+            events::publish_cascading_revocation(
+                env,
+                patient.clone(),
+                grantee.clone(),
+                revoked.delegatee.clone(),
+                revoked.is_scoped,
+            );
+        }
+
+        // Log successful access revoke
+        let audit_entry = audit::create_audit_entry(
+            env,
+            actor.clone(),
+            patient.clone(),
+            None,
+            AccessAction::RevokeAccess,
+            AccessResult::Success,
+            None,
+        );
+        audit::add_audit_entry(env, &audit_entry);
+        events::publish_audit_log_entry(env, &audit_entry);
+    }
+
+    /// Revoke every outstanding grant the patient has issued (patient-wide,
+    /// scoped, record-level, organization and consent) and end any open
+    /// emergency access to their records, for when a patient needs to lock
+    /// down their chart at once. Emits one summary event rather than one per
+    /// grant. Revoked patient-wide grants stay restorable for the usual
+    /// window.
+    pub fn revoke_all_access(
+        env: Env,
+        patient: Address,
+    ) -> Result<AccessLockdownSummary, ContractError> {
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("RVK_ACC")),
+        )?;
+        patient.require_auth();
+        let now = env.ledger().timestamp();
+
+        let mut grants_revoked = 0u32;
+        let grantees: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("ACC_LST"), patient.clone()))
+            .unwrap_or(Vec::new(&env));
+        for grantee in grantees.iter() {
+            let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+            if let Some(mut grant) = env.storage().persistent().get::<_, AccessGrant>(&key) {
+                if grant.status == GrantStatus::Active {
+                    grant.status = GrantStatus::Revoked;
+                    grant.revoked_at = Some(now);
+                    env.storage().persistent().set(&key, &grant);
+                    extend_ttl_access_key(&env, &key);
+                    access_decision::mark_revoked(&env, &patient, &grantee);
+                    grants_revoked = grants_revoked.saturating_add(1);
+                }
+            }
+        }
+        Self::prune_grantees(&env, &patient);
+
+        let mut scoped_grants_revoked = 0u32;
+        for grantee in scoped_grant::grantees(&env, &patient).iter() {
+            scoped_grants_revoked = scoped_grants_revoked
+                .saturating_add(scoped_grant::revoke_all(&env, &patient, &grantee));
+        }
+
+        let mut record_grants_revoked = 0u32;
+        let record_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("PAT_REC"), patient.clone()))
+            .unwrap_or(Vec::new(&env));
+        for record_id in record_ids.iter() {
+            let record_grantees: Vec<Address> = env
+                .storage()
+                .persistent()
+                .get(&(symbol_short!("REC_ALST"), record_id))
+                .unwrap_or(Vec::new(&env));
+            for grantee in record_grantees.iter() {
+                let key = (symbol_short!("REC_ACC"), record_id, grantee);
+                if let Some(mut grant) = env.storage().persistent().get::<_, AccessGrant>(&key) {
+                    if grant.status == GrantStatus::Active {
+                        grant.status = GrantStatus::Revoked;
+                        grant.revoked_at = Some(now);
+                        env.storage().persistent().set(&key, &grant);
+                        extend_ttl_record_access_key(&env, &key);
+                        record_grants_revoked = record_grants_revoked.saturating_add(1);
+                    }
+                }
+            }
+        }
+
+        let mut org_grants_revoked = 0u32;
+        for org_id in organization::granted_orgs(&env, &patient).iter() {
+            if organization::get_grant(&env, &patient, org_id).is_some() {
+                organization::remove_grant(&env, &patient, org_id);
+                org_grants_revoked = org_grants_revoked.saturating_add(1);
+            }
+        }
+
+        let mut consents_revoked = 0u32;
+        let consent_grantees: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("CONS_LST"), patient.clone()))
+            .unwrap_or(Vec::new(&env));
+        for grantee in consent_grantees.iter() {
+            let key = consent_key(&patient, &grantee);
+            if let Some(mut consent) = env.storage().persistent().get::<_, ConsentGrant>(&key) {
+                if !consent.revoked {
+                    consent.revoked = true;
+                    env.storage().persistent().set(&key, &consent);
+                    extend_ttl_access_key(&env, &key);
+                    consents_revoked = consents_revoked.saturating_add(1);
+                }
+            }
+        }
+
+        let mut emergency_grants_revoked = 0u32;
+        for access in emergency::get_patient_open_accesses(&env, &patient).iter() {
+            emergency::revoke_emergency_access(&env, access.id);
+            Self::log_emergency_action(&env, access.id, &patient, "REVOKED");
+            emergency_grants_revoked = emergency_grants_revoked.saturating_add(1);
+        }
+
+        snapshot::record_change(&env, &patient, StateChangeKind::AccessRevoked, None, None);
+        let audit_entry = audit::create_audit_entry(
+            &env,
+            patient.clone(),
+            patient.clone(),
+            None,
+            AccessAction::RevokeAccess,
+            AccessResult::Success,
+            Some(String::from_str(&env, "revoke_all_access")),
+        );
+        audit::add_audit_entry(&env, &audit_entry);
+
+        let summary = AccessLockdownSummary {
+            patient,
+            grants_revoked,
+            scoped_grants_revoked,
+            record_grants_revoked,
+            org_grants_revoked,
+            consents_revoked,
+            emergency_grants_revoked,
+            locked_at: now,
+        };
+        events::publish_access_lockdown(&env, &summary);
+        Ok(summary)
+    }
+
+    /// Revoke access
+    pub fn revoke_access(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("RVK_ACC")),
+        )?;
+        patient.require_auth();
+        Self::revoke_grant(&env, &patient, &patient, &grantee);
+
+        env.storage().persistent().set(&profile_key, &profile);
+        events::publish_profile_updated(&env, patient);
+
+        Ok(())
+    }
+
+    /// Update emergency contact information
+    pub fn update_emergency_contact(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        contact: Option<EmergencyContact>,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        // Only profile owner can update
+        if caller != patient {
+            return Self::unauthorized(&env, &caller, "update_emergency_contact", "profile_owner");
+        }
+
+        let profile_key = (symbol_short!("PAT_PROF"), patient.clone());
+        let mut profile: PatientProfile = env
+            .storage()
+            .persistent()
+            .get(&profile_key)
+            .ok_or(ContractError::UserNotFound)?;
+
+        profile.emergency_contact = match contact {
+            Some(c) => OptionalEmergencyContact::Some(c),
+            None => OptionalEmergencyContact::None,
+        };
+        profile.updated_at = env.ledger().timestamp();
+
+        env.storage().persistent().set(&profile_key, &profile);
+        events::publish_profile_updated(&env, patient);
+
+        Ok(())
+    }
+
+    /// Update insurance information (hashed values only)
+    pub fn update_insurance(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        insurance_info: Option<InsuranceInfo>,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        // Only profile owner can update
+        if caller != patient {
+            return Self::unauthorized(&env, &caller, "update_insurance", "profile_owner");
+        }
+
+        let profile_key = (symbol_short!("PAT_PROF"), patient.clone());
+        let mut profile: PatientProfile = env
+            .storage()
+            .persistent()
+            .get(&profile_key)
+            .ok_or(ContractError::UserNotFound)?;
+
+        profile.insurance_info = match insurance_info {
+            Some(info) => OptionalInsuranceInfo::Some(info),
+            None => OptionalInsuranceInfo::None,
+        };
+        profile.updated_at = env.ledger().timestamp();
+
+        env.storage().persistent().set(&profile_key, &profile);
+        events::publish_profile_updated(&env, patient);
+
+        Ok(())
+    }
+
+    /// Add medical history reference (IPFS hash or record ID)
+    pub fn add_medical_history_reference(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        reference: String,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        // Only profile owner can update
+        if caller != patient {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "add_medical_history_reference",
+                "profile_owner",
+            );
+        }
+
+        let profile_key = (symbol_short!("PAT_PROF"), patient.clone());
+        let mut profile: PatientProfile = env
+            .storage()
+            .persistent()
+            .get(&profile_key)
+            .ok_or(ContractError::UserNotFound)?;
+
+        profile.medical_history_refs.push_back(reference);
+        profile.updated_at = env.ledger().timestamp();
+
+        env.storage().persistent().set(&profile_key, &profile);
+        events::publish_profile_updated(&env, patient);
+
+        Ok(())
+    }
+
+    /// Get patient profile
+    pub fn get_profile(env: Env, patient: Address) -> Result<PatientProfile, ContractError> {
+        let profile_key = (symbol_short!("PAT_PROF"), alias::resolve(&env, &patient));
+        env.storage()
+            .persistent()
+            .get(&profile_key)
+            .ok_or(ContractError::UserNotFound)
+    }
+
+    /// Check if patient profile exists
+    pub fn profile_exists(env: Env, patient: Address) -> bool {
+        let profile_key = (symbol_short!("PAT_PROF"), patient);
+        env.storage().persistent().has(&profile_key)
+    }
+
+    /// Grants a custom permission to a user.
+    /// Requires the caller to have ManageUsers permission.
+    pub fn grant_custom_permission(
+        env: Env,
+        caller: Address,
+        user: Address,
+        permission: Permission,
+    ) -> Result<AdminActionReceipt, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+        // Unified check: covers direct role, custom grants, and delegated roles
+        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "grant_custom_permission",
+                "permission:ManageUsers",
+            );
+        }
+        rbac::grant_custom_permission(&env, user.clone(), permission)
+            .map_err(|_| ContractError::UserNotFound)?;
+        Ok(admin_receipt::issue(
+            &env,
+            &caller,
+            symbol_short!("PERM_GRNT"),
+            Some(user),
+        ))
+    }
+
+    /// Revokes a custom permission from a user.
+    /// Requires the caller to have ManageUsers permission.
+    pub fn revoke_custom_permission(
+        env: Env,
+        caller: Address,
+        user: Address,
+        permission: Permission,
+    ) -> Result<AdminActionReceipt, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+        // Unified check: covers direct role, custom grants, and delegated roles
+        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "revoke_custom_permission",
+                "permission:ManageUsers",
+            );
+        }
+        rbac::revoke_custom_permission(&env, user.clone(), permission)
+            .map_err(|_| ContractError::UserNotFound)?;
+        Ok(admin_receipt::issue(
+            &env,
+            &caller,
+            symbol_short!("PERM_RVK"),
+            Some(user),
+        ))
+    }
+
+    /// Delegates a role to another user with an expiration timestamp.
+    /// The delegator must authenticate the transaction.
+    pub fn delegate_role(
+        env: Env,
+        delegator: Address,
+        delegatee: Address,
+        role: Role,
+        expires_at: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        delegator.require_auth();
+        rbac::delegate_role(&env, delegator, delegatee, role, expires_at);
+        Ok(())
+    }
+
+    /// Halts every guarded state-changing entrypoint, which then fails with
+    /// `Paused`. Reads are unaffected. Shorthand for `pause_contract` with
+    /// the global scope.
+    pub fn pause(env: Env, caller: Address) -> Result<(), ContractError> {
+        Self::pause_contract(env, caller, circuit_breaker::PauseScope::Global)
+    }
+
+    /// Lifts a global pause set by `pause`.
+    pub fn unpause(env: Env, caller: Address) -> Result<(), ContractError> {
+        Self::resume_contract(env, caller, circuit_breaker::PauseScope::Global)
+    }
+
+    pub fn is_paused(env: Env) -> bool {
+        circuit_breaker::is_globally_paused(&env)
+    }
+
+    /// Pauses contract operations for a given scope.
+    pub fn pause_contract(
+        env: Env,
+        caller: Address,
+        scope: circuit_breaker::PauseScope,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        circuit_breaker::pause_contract(&env, &caller, scope)?;
+        admin_receipt::issue(&env, &caller, symbol_short!("PAUSE"), None);
+        Ok(())
+    }
+
+    /// Resumes contract operations for a given scope.
+    pub fn resume_contract(
+        env: Env,
+        caller: Address,
+        scope: circuit_breaker::PauseScope,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        circuit_breaker::resume_contract(&env, &caller, scope)?;
+        admin_receipt::issue(&env, &caller, symbol_short!("RESUME"), None);
+        Ok(())
+    }
+
+    /// Enter or leave degraded mode. While degraded, only emergency access
+    /// requests among guarded mutations are accepted; reads, grant checks
+    /// and emergency reads are unaffected.
+    pub fn set_degraded_mode(env: Env, caller: Address, enabled: bool) -> Result<(), ContractError> {
+        caller.require_auth();
+        circuit_breaker::set_degraded(&env, &caller, enabled)?;
+        admin_receipt::issue(&env, &caller, symbol_short!("DEGRADE"), None);
+        Ok(())
+    }
+
+    pub fn is_degraded_mode(env: Env) -> bool {
+        circuit_breaker::is_degraded(&env)
+    }
+
+    /// Switch an optional subsystem on or off for this deployment.
+    pub fn set_feature_flag(
+        env: Env,
+        caller: Address,
+        feature: Feature,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_feature_flag",
+                "permission:SystemAdmin",
+            );
+        }
+        feature_flags::set_enabled(&env, feature, enabled);
+        events::publish_feature_flag_set(&env, caller.clone(), feature, enabled);
+        admin_receipt::issue(&env, &caller, symbol_short!("FEAT_SET"), None);
+        Ok(())
+    }
+
+    pub fn is_feature_enabled(env: Env, feature: Feature) -> bool {
+        feature_flags::is_enabled(&env, feature)
+    }
+
+    /// Features currently enabled, for clients discovering what this
+    /// deployment supports.
+    pub fn get_enabled_features(env: Env) -> Vec<Feature> {
+        feature_flags::enabled_features(&env)
+    }
+
+    /// Creates an ACL group.
+    pub fn create_acl_group(
+        env: Env,
+        caller: Address,
+        group_name: String,
+        permissions: Vec<Permission>,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+            return Self::unauthorized(&env, &caller, "create_acl_group", "permission:ManageUsers");
+        }
+        rbac::create_group(&env, group_name, permissions);
+        admin_receipt::issue(&env, &caller, symbol_short!("GRP_NEW"), None);
+        Ok(())
+    }
+
+    /// Adds a user to an ACL group.
+    pub fn add_user_to_group(
+        env: Env,
+        caller: Address,
+        user: Address,
+        group_name: String,
+    ) -> Result<AdminActionReceipt, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "add_user_to_group",
+                "permission:ManageUsers",
+            );
+        }
+        rbac::add_to_group(&env, user.clone(), group_name)
+            .map_err(|_| ContractError::InvalidInput)?;
+        Ok(admin_receipt::issue(
+            &env,
+            &caller,
+            symbol_short!("GRP_ADD"),
+            Some(user),
+        ))
+    }
+
+    /// Removes a user from an ACL group.
+    pub fn remove_user_from_group(
+        env: Env,
+        caller: Address,
+        user: Address,
+        group_name: String,
+    ) -> Result<AdminActionReceipt, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "remove_user_from_group",
+                "permission:ManageUsers",
+            );
+        }
+        rbac::remove_from_group(&env, user.clone(), group_name);
+        Ok(admin_receipt::issue(
+            &env,
+            &caller,
+            symbol_short!("GRP_DEL"),
+            Some(user),
+        ))
+    }
+
+    /// Returns all ACL groups assigned to a user.
+    pub fn get_user_groups(env: Env, user: Address) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&rbac::user_groups_key(&user))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Checks if a user has a specific permission.
+    /// Returns true if the user has the permission, false otherwise.
+    pub fn check_permission(env: Env, user: Address, permission: Permission) -> bool {
+        rbac::has_permission(&env, &user, &permission)
+    }
+
+    /// Create an access policy with ABAC attributes
+    pub fn create_access_policy(
+        env: Env,
+        caller: Address,
+        policy_id: String,
+        name: String,
+        required_role: Option<Role>,
+        time_restriction: TimeRestriction,
+        required_credential: CredentialType,
+        min_sensitivity_level: SensitivityLevel,
+        consent_required: bool,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        // Only SystemAdmin can create policies
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        let conditions = rbac::PolicyConditions {
+            required_role,
+            time_restriction,
+            required_credential,
+            min_sensitivity_level,
+            consent_required,
+        };
+
+        let policy = rbac::AccessPolicy {
+            id: policy_id.clone(),
+            name,
+            conditions,
+            enabled: true,
+        };
+
+        rbac::create_access_policy(&env, policy);
+        admin_receipt::issue(&env, &caller, symbol_short!("POL_NEW"), None);
+        events::publish_policy_created(&env, policy_id, caller);
+
+        Ok(())
+    }
+
+    /// Set credential type for a user
+    pub fn set_user_credential(
+        env: Env,
+        caller: Address,
+        user: Address,
+        credential: CredentialType,
+    ) -> Result<AdminActionReceipt, ContractError> {
+        caller.require_auth();
+
+        // Only SystemAdmin can set credentials
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        rbac::set_user_credential(&env, user.clone(), credential);
+        let receipt =
+            admin_receipt::issue(&env, &caller, symbol_short!("CRED_SET"), Some(user.clone()));
+        events::publish_credential_set(&env, user, credential, caller);
+
+        Ok(receipt)
+    }
+
+    /// Set sensitivity level for a record
+    pub fn set_record_sensitivity(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        sensitivity: SensitivityLevel,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        // Only the patient, record provider or SystemAdmin can set sensitivity
+        let record_key = (symbol_short!("RECORD"), record_id);
+        let record: VisionRecord = env.storage().persistent().get(&record_key)
+            .ok_or(ContractError::RecordNotFound)?;
+
+        let has_perm = caller == record.patient
+            || caller == record.provider
+            || rbac::has_permission(&env, &caller, &Permission::SystemAdmin);
+        if !has_perm {
+            return Err(ContractError::Unauthorized);
+        }
+
+        rbac::set_record_sensitivity(&env, record_id, sensitivity.clone());
+        admin_receipt::issue(&env, &caller, symbol_short!("SENS_SET"), None);
+        events::publish_sensitivity_set(&env, record_id, sensitivity, caller);
+
+        Ok(())
+    }
+
+    /// Reads of a Restricted record by anyone other than the patient.
+    /// Visible to the patient and the provider of record.
+    pub fn get_read_receipts(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<Vec<ReadReceipt>, ContractError> {
+        caller.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        let caller_resolved = alias::resolve(&env, &caller);
+        if caller_resolved != record.patient && caller_resolved != record.provider {
+            return Self::unauthorized(&env, &caller, "get_read_receipts", "record_owner");
+        }
+        Ok(sensitivity::get_receipts(&env, record_id))
+    }
+
+    /// Check access for a specific record with ABAC evaluation
+    pub fn check_record_access(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<AccessLevel, ContractError> {
+        caller.require_auth();
+
+        let record_key = (symbol_short!("RECORD"), record_id);
+        let record: VisionRecord = env.storage().persistent().get(&record_key)
+            .ok_or(ContractError::RecordNotFound)?;
+
+        // Check if caller is patient or provider (always allowed)
+        if caller == record.patient || caller == record.provider {
+            return Ok(AccessLevel::Full);
+        }
+
+        // Check traditional access grants
+        let traditional_access = Self::check_access(env.clone(), record.patient.clone(), caller.clone(), None, None);
+        if traditional_access != AccessLevel::None {
+            return Ok(traditional_access);
+        }
+
+        // Check ABAC policies for this specific record
+        let abac_allowed = evaluate_access_policies(&env, &caller, Some(record_id), Some(record.patient));
+        if abac_allowed {
+            return Ok(AccessLevel::Read);
+        }
+
+        Ok(AccessLevel::None)
+    }
+
+    /// Prepare phase for register_user operation
+    pub fn prepare_register_user(
+        env: Env,
+        caller: Address,
+        user: Address,
+        role: Role,
+        name: String,
+    ) -> Result<(), ContractError> {
+        // Validate all inputs without making state changes
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("REG_USR")),
+        )?;
+
+        if !whitelist::check_whitelist_access(&env, &caller) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        validation::validate_name(&name)?;
+
+        // Check if user already exists
+        let key = (symbol_short!("USER"), user.clone());
+        if env.storage().persistent().get::<_, User>(&key).is_some() {
+            return Err(ContractError::UserAlreadyExists);
+        }
+
+        // Store temporary preparation data
+        let salt = user_name::plaintext_salt(&env, &user);
+        let name_hash =
+            user_name::salted_hash(&env, &name, &salt).ok_or(ContractError::InvalidInput)?;
+        let prep_key = (symbol_short!("PREP_REG_USER"), user.clone());
+        let prep_data = PrepareUserRegistration {
+            caller: caller.clone(),
+            user: user.clone(),
+            role: role.clone(),
+            name_hash,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().temporary().set(&prep_key, &prep_data);
+
+        Ok(())
+    }
+
+    /// Commit phase for register_user operation
+    pub fn commit_register_user(
+        env: Env,
+        caller: Address,
+        user: Address,
+        role: Role,
+        name: String,
+    ) -> Result<(), ContractError> {
+        // Retrieve preparation data
+        let prep_key = (symbol_short!("PREP_REG_USER"), user.clone());
+        let prep_data: PrepareUserRegistration = env.storage().temporary().get(&prep_key)
+            .ok_or(ContractError::InvalidPhase)?;
+
+        // Verify preparation data matches commit parameters
+        let salt = user_name::plaintext_salt(&env, &user);
+        let name_hash =
+            user_name::salted_hash(&env, &name, &salt).ok_or(ContractError::InvalidInput)?;
+        if prep_data.caller != caller
+            || prep_data.user != user
+            || prep_data.role != role
+            || prep_data.name_hash != name_hash
+        {
+            return Err(ContractError::InvalidPhase);
+        }
+
+        // Clean up preparation data
+        env.storage().temporary().remove(&prep_key);
+
+        // Execute the actual registration, through the same checks and
+        // provider approval queue as `register_user`
+        Self::register_user_inner(env, caller, user, role, name_hash, None)
+    }
+
+    /// Rollback for register_user operation
+    pub fn rollback_register_user(
+        env: Env,
+        user: Address,
+        _role: Role,
+        _name: String,
+    ) -> Result<(), ContractError> {
+        // Clean up preparation data
+        let prep_key = (symbol_short!("PREP_REG_USER"), user.clone());
+        env.storage().temporary().remove(&prep_key);
+
+        Ok(())
+    }
+
+    /// Prepare phase for add_record operation
+    pub fn prepare_add_record(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        record_type: RecordType,
+        data_hash: String,
+    ) -> Result<(), ContractError> {
+        // Validate all inputs without making state changes
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("ADD_REC")),
+        )?;
+
+        if !whitelist::check_whitelist_access(&env, &caller) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        // Verify provider permissions
+        if !rbac::has_permission(&env, &provider, &Permission::CreateRecords) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        // Validate inputs
+        validation::validate_address(&patient)?;
+        validation::validate_address(&provider)?;
+        validation::validate_hash(&data_hash)?;
+
+        // Store temporary preparation data
+        let prep_key = (symbol_short!("PREP_ADD_REC"), provider.clone(), env.ledger().timestamp());
+        let prep_data = PrepareRecordAddition {
+            caller: caller.clone(),
+            patient: patient.clone(),
+            provider: provider.clone(),
+            record_type: record_type.clone(),
+            data_hash: data_hash.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().temporary().set(&prep_key, &prep_data);
+
+        Ok(())
+    }
+
+    /// Commit phase for add_record operation
+    pub fn commit_add_record(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        record_type: RecordType,
+        data_hash: String,
+    ) -> Result<u64, ContractError> {
+        // Retrieve preparation data using timestamp lookup
+        let prep_keys = env.storage().temporary().range::<_, PrepareRecordAddition>();
+        let mut prep_data: Option<PrepareRecordAddition> = None;
+        let mut prep_key_to_remove: Option<(Symbol, Address, u64)> = None;
+
+        for key in prep_keys {
+            if let Ok((_, data)) = key {
+                if data.caller == caller && data.patient == patient && 
+                   data.provider == provider && data.record_type == record_type && 
+                   data.data_hash == data_hash {
+                    prep_data = Some(data);
+                    prep_key_to_remove = Some(key);
+                    break;
+                }
+            }
+        }
+
+        let prep_data = prep_data.ok_or(ContractError::InvalidPhase)?;
+
+        // Execute the actual record addition
+        let record_id = increment_record_counter(&env);
+        let record = VisionRecord {
+            id: record_id,
+            patient: patient.clone(),
+            provider: provider.clone(),
+            record_type: record_type.clone(),
+            data_hash: data_hash.clone(),
+            created_at: env.ledger().timestamp(),
+            updated_at: env.ledger().timestamp(),
+            access_count: 0,
+        };
+
+        let key = (symbol_short!("RECORD"), record_id);
+        env.storage().persistent().set(&key, &record);
+        extend_ttl_record_key(&env, &key);
+
+        // Clean up preparation data
+        if let Some(key) = prep_key_to_remove {
+            env.storage().temporary().remove(&key);
+        }
+
+        events::publish_record_added(&env, record_id, patient, provider, record_type);
+
+        Ok(record_id)
+    }
+
+    /// Rollback for add_record operation
+    pub fn rollback_add_record(
+        env: Env,
+        _caller: Address,
+        _patient: Address,
+        provider: Address,
+        _record_type: RecordType,
+        _data_hash: String,
+    ) -> Result<(), ContractError> {
+        // Clean up preparation data for this provider
+        let prep_keys = env.storage().temporary().range::<_, PrepareRecordAddition>();
+        for key in prep_keys {
+            if let Ok((_, data)) = key {
+                if data.provider == provider {
+                    env.storage().temporary().remove(&key);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // ======================== Two-Phase Commit Hooks ========================
+
+    /// Prepare phase for adding a vision record
+    pub fn prepare_add_record(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        provider: Address,
+        record_type: RecordType,
+        data_hash: String,
+    ) -> Result<PreparedOperation, ContractError> {
+        // Validate all inputs without making state changes
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("ADD_REC")),
+        )?;
+
+        if !whitelist::check_whitelist_access(&env, &caller) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        Self::enforce_rate_limit(&env, &caller)?;
+        validation::validate_data_hash(&data_hash)?;
+
+        // Check permissions
+        let has_perm = if caller == provider {
+            rbac::has_permission(&env, &caller, &Permission::WriteRecord)
+        } else {
+            rbac::has_delegated_permission(&env, &provider, &caller, &Permission::WriteRecord)
+        };
+
+        if !has_perm && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        // Generate and return the record ID that will be used
+        let counter_key = symbol_short!("REC_CTR");
+        let record_id: u64 = env
+            .storage()
+            .instance()
+            .get(&counter_key)
+            .unwrap_or(0u64)
+            .saturating_add(1u64);
+        // A new provisional write supersedes any earlier compensated one
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("CMP_REC"), record_id));
+
+        // Store preparation data temporarily
+        let prep_key = (symbol_short!("PREP_ADD_REC"), record_id);
+        let prep_data = PrepareAddRecord {
+            caller,
+            patient,
+            provider,
+            record_type,
+            data_hash,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().temporary().set(&prep_key, &prep_data);
+
+        let mut args: Vec<Val> = Vec::new(&env);
+        args.push_back(record_id.into_val(&env));
+        Ok(PreparedOperation {
+            id: record_id,
+            compensation: Compensation {
+                function: Symbol::new(&env, "compensate_add_record"),
+                args,
+            },
+        })
+    }
+
+    /// Commit phase for adding a vision record
+    pub fn commit_add_record(
+        env: Env,
+        record_id: u64,
+    ) -> Result<(), ContractError> {
+        // A compensated provisional record can no longer be committed
+        if env
+            .storage()
+            .persistent()
+            .has(&(symbol_short!("CMP_REC"), record_id))
+        {
+            return Err(ContractError::InvalidInput);
+        }
+
+        // Retrieve preparation data
+        let prep_key = (symbol_short!("PREP_ADD_REC"), record_id);
+        let prep_data: PrepareAddRecord = env.storage().temporary()
+            .get(&prep_key)
+            .ok_or(ContractError::InvalidInput)?;
+
+        // Update the counter
+        let counter_key = symbol_short!("REC_CTR");
+        env.storage().instance().set(&counter_key, &record_id);
+
+        // Create the actual record
+        let record = VisionRecord {
+            id: record_id,
+            patient: prep_data.patient.clone(),
+            provider: prep_data.provider.clone(),
+            record_type: prep_data.record_type.clone(),
+            data_hash: prep_data.data_hash.clone(),
+            created_at: prep_data.timestamp,
+            updated_at: prep_data.timestamp,
+        };
+
+        // Store the record
+        let key = (symbol_short!("RECORD"), record_id);
+        env.storage().persistent().set(&key, &record);
+        extend_ttl_u64_key(&env, &key);
+
+        // Add to patient's record list
+        let patient_key = (symbol_short!("PAT_REC"), prep_data.patient.clone());
+        let mut patient_records: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&patient_key)
+            .unwrap_or(Vec::new(&env));
+        patient_records.push_back(record_id);
+        env.storage()
+            .persistent()
+            .set(&patient_key, &patient_records);
+        extend_ttl_address_key(&env, &patient_key);
+
+        // Clean up preparation data
+        env.storage().temporary().remove(&prep_key);
+
+        Ok(())
+    }
+
+    /// Rollback phase for adding a vision record
+    pub fn rollback_add_record(
+        env: Env,
+        record_id: u64,
+    ) -> Result<(), ContractError> {
+        // Clean up preparation data
+        let prep_key = (symbol_short!("PREP_ADD_REC"), record_id);
+        env.storage().temporary().remove(&prep_key);
+
+        Ok(())
+    }
+
+    /// Compensation registered by `prepare_add_record`: drops the provisional
+    /// record and tombstones its id so a late commit is refused.
+    pub fn compensate_add_record(env: Env, record_id: u64) -> Result<(), ContractError> {
+        let prep_key = (symbol_short!("PREP_ADD_REC"), record_id);
+        env.storage().temporary().remove(&prep_key);
+
+        let tomb_key = (symbol_short!("CMP_REC"), record_id);
+        env.storage()
+            .persistent()
+            .set(&tomb_key, &env.ledger().timestamp());
+        extend_ttl_u64_key(&env, &tomb_key);
+
+        Ok(())
+    }
+
+    /// Prepare phase for granting access
+    pub fn prepare_grant_access(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        grantee: Address,
+        access_level: AccessLevel,
+        expires_at: Option<u64>,
+    ) -> Result<(), ContractError> {
+        // Validate inputs without state changes
+        if caller != patient && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Err(ContractError::Unauthorized);
+        }
+
+        if access_level == AccessLevel::None {
+            return Err(ContractError::InvalidInput);
+        }
+
+        // Store preparation data
+        let prep_key = (symbol_short!("PREP_GRANT_ACC"), patient.clone(), grantee.clone());
+        let prep_data = PrepareGrantAccess {
+            caller,
+            patient,
+            grantee,
+            access_level,
+            expires_at,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().temporary().set(&prep_key, &prep_data);
+
+        Ok(())
+    }
+
+    /// Commit phase for granting access
+    pub fn commit_grant_access(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+    ) -> Result<(), ContractError> {
+        // Retrieve preparation data
+        let prep_key = (symbol_short!("PREP_GRANT_ACC"), patient.clone(), grantee.clone());
+        let prep_data: PrepareGrantAccess = env.storage().temporary()
+            .get(&prep_key)
+            .ok_or(ContractError::InvalidInput)?;
+
+        // Create the access grant
+        let grant = AccessGrant {
+            patient: prep_data.patient.clone(),
+            grantee: prep_data.grantee.clone(),
+            level: prep_data.access_level,
+            granted_at: prep_data.timestamp,
+            expires_at: prep_data.expires_at.unwrap_or(0),
+            status: GrantStatus::Active,
+            revoked_at: None,
+            agreement_id: None,
+        };
+
+        // Store the grant
+        let key = (symbol_short!("GRANT"), prep_data.patient.clone(), prep_data.grantee.clone());
+        env.storage().persistent().set(&key, &grant);
+        extend_ttl_address_key(&env, &key);
+
+        // Add to patient's grants list
+        let grants_key = (symbol_short!("GRANTS"), prep_data.patient.clone());
+        let mut grants: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&grants_key)
+            .unwrap_or(Vec::new(&env));
+        
+        if !grants.contains(&prep_data.grantee) {
+            grants.push_back(prep_data.grantee.clone());
+            env.storage().persistent().set(&grants_key, &grants);
+            extend_ttl_address_key(&env, &grants_key);
+        }
+
+        // Clean up preparation data
+        env.storage().temporary().remove(&prep_key);
+
+        Ok(())
+    }
+
+    /// Rollback phase for granting access
+    pub fn rollback_grant_access(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+    ) -> Result<(), ContractError> {
+        // Clean up preparation data
+        let prep_key = (symbol_short!("PREP_GRANT_ACC"), patient.clone(), grantee.clone());
+        env.storage().temporary().remove(&prep_key);
+
+        Ok(())
+    }
+
+    /// Prepare phase for adding a prescription
+    pub fn prepare_add_prescription(
+        env: Env,
+        patient: Address,
+        provider: Address,
+        prescription_data: prescription::PrescriptionData,
+    ) -> Result<PreparedOperation, ContractError> {
+        // Validate without state changes
+        validation::validate_prescription_data(&prescription_data)?;
+
+        // Check provider permissions
+        let caller = provider.clone();
+        let has_perm = rbac::has_permission(&env, &caller, &Permission::WriteRecord) ||
+                      rbac::has_delegated_permission(&env, &provider, &caller, &Permission::WriteRecord) ||
+                      rbac::has_permission(&env, &caller, &Permission::SystemAdmin);
+
+        if !has_perm {
+            return Err(ContractError::Unauthorized);
+        }
+
+        // Generate prescription ID
+        let counter_key = symbol_short!("RX_CTR");
+        let rx_id: u64 = env
+            .storage()
+            .instance()
+            .get(&counter_key)
+            .unwrap_or(0u64)
+            .saturating_add(1u64);
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("CMP_RX"), rx_id));
+
+        // Store preparation data
+        let prep_key = (symbol_short!("PREP_ADD_RX"), rx_id);
+        let prep_data = PrepareAddPrescription {
+            patient,
+            provider,
+            prescription_data,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().temporary().set(&prep_key, &prep_data);
+
+        let mut args: Vec<Val> = Vec::new(&env);
+        args.push_back(rx_id.into_val(&env));
+        Ok(PreparedOperation {
+            id: rx_id,
+            compensation: Compensation {
+                function: Symbol::new(&env, "compensate_add_prescription"),
+                args,
+            },
+        })
+    }
+
+    /// Commit phase for adding a prescription
+    pub fn commit_add_prescription(
+        env: Env,
+        rx_id: u64,
+    ) -> Result<(), ContractError> {
+        if env
+            .storage()
+            .persistent()
+            .has(&(symbol_short!("CMP_RX"), rx_id))
+        {
+            return Err(ContractError::InvalidInput);
+        }
+
+        // Retrieve preparation data
+        let prep_key = (symbol_short!("PREP_ADD_RX"), rx_id);
+        let prep_data: PrepareAddPrescription = env.storage().temporary()
+            .get(&prep_key)
+            .ok_or(ContractError::InvalidInput)?;
+
+        // Update counter
+        let counter_key = symbol_short!("RX_CTR");
+        env.storage().instance().set(&counter_key, &rx_id);
+
+        // Create the prescription
+        let prescription = prescription::Prescription {
+            id: rx_id,
+            patient: prep_data.patient.clone(),
+            provider: prep_data.provider.clone(),
+            data: prep_data.prescription_data.clone(),
+            created_at: prep_data.timestamp,
+            verified: false,
+        };
+
+        // Store the prescription
+        let key = (symbol_short!("PRESCRIPTION"), rx_id);
+        env.storage().persistent().set(&key, &prescription);
+        extend_ttl_u64_key(&env, &key);
+
+        // Add to patient's prescription history
+        prescription::add_to_patient_history(&env, prep_data.patient.clone(), rx_id);
+
+        // Clean up preparation data
+        env.storage().temporary().remove(&prep_key);
+
+        Ok(())
+    }
+
+    /// Rollback phase for adding a prescription
+    pub fn rollback_add_prescription(
+        env: Env,
+        rx_id: u64,
+    ) -> Result<(), ContractError> {
+        // Clean up preparation data
+        let prep_key = (symbol_short!("PREP_ADD_RX"), rx_id);
+        env.storage().temporary().remove(&prep_key);
+
+        Ok(())
+    }
+
+    /// Compensation registered by `prepare_add_prescription`
+    pub fn compensate_add_prescription(env: Env, rx_id: u64) -> Result<(), ContractError> {
+        let prep_key = (symbol_short!("PREP_ADD_RX"), rx_id);
+        env.storage().temporary().remove(&prep_key);
+
+        let tomb_key = (symbol_short!("CMP_RX"), rx_id);
+        env.storage()
+            .persistent()
+            .set(&tomb_key, &env.ledger().timestamp());
+        extend_ttl_u64_key(&env, &tomb_key);
+
+        Ok(())
+    }
+
+    // ======================== Data Retention ========================
+
+    /// Configure the retention window for a record type. Requires SystemAdmin.
+    pub fn set_retention_policy(
+        env: Env,
+        caller: Address,
+        record_type: RecordType,
+        retain_seconds: u64,
+        enabled: bool,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_retention_policy",
+                "permission:SystemAdmin",
+            );
+        }
+
+        if retain_seconds == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        if !record_types::is_usable(&env, &record_type) {
+            return Err(ContractError::InvalidRecordType);
+        }
+
+        let policy = retention::RetentionPolicy {
+            record_type: record_type.clone(),
+            retain_seconds,
+            enabled,
+            updated_by: caller.clone(),
+            updated_at: env.ledger().timestamp(),
+        };
+        retention::set_policy(&env, &policy);
+
+        admin_receipt::issue(&env, &caller, symbol_short!("RET_SET"), None);
+        events::publish_retention_policy_set(&env, record_type, retain_seconds, enabled, caller);
+
+        Ok(())
+    }
+
+    pub fn get_retention_policy(
+        env: Env,
+        record_type: RecordType,
+    ) -> Option<retention::RetentionPolicy> {
+        retention::get_policy(&env, &record_type)
+    }
+
+    /// Lets a patient opt out of the default retention window. Their records
+    /// are still archived, but only once `retention::OPT_OUT_RETENTION_FACTOR`
+    /// times the policy's window has passed.
+    pub fn set_retention_opt_out(
+        env: Env,
+        patient: Address,
+        opted_out: bool,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+
+        retention::set_opt_out(&env, &patient, opted_out);
+        events::publish_retention_opt_out(&env, patient, opted_out);
+
+        Ok(())
+    }
+
+    pub fn is_retention_opted_out(env: Env, patient: Address) -> bool {
+        retention::is_opted_out(&env, &patient)
+    }
+
+    /// Flag a record as part of an open episode of care, which keeps it out of
+    /// archival until the flag is cleared. Callable by the record's provider
+    /// or a SystemAdmin.
+    pub fn set_record_episode_open(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        open: bool,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        let record_key = (symbol_short!("RECORD"), record_id);
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&record_key)
+            .ok_or(ContractError::RecordNotFound)?;
+
+        if caller != record.provider
+            && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_record_episode_open",
+                "record_provider_or_SystemAdmin",
+            );
+        }
+
+        retention::set_open_episode(&env, record_id, open);
+
+        admin_receipt::issue(&env, &caller, symbol_short!("EPI_SET"), None);
+        Ok(())
+    }
+
+    /// Permissionless retention sweeper. Inspects up to `limit` records
+    /// starting from the stored cursor and archives those past their
+    /// retention window. Returns the number of records archived.
+    pub fn apply_retention(env: Env, limit: u32) -> Result<u32, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+
+        if limit == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let total: u64 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("REC_CTR"))
+            .unwrap_or(0);
+        if total == 0 {
+            return Ok(0);
+        }
+
+        let now = env.ledger().timestamp();
+        let mut cursor = retention::get_cursor(&env);
+        let mut archived: u32 = 0;
+
+        for _ in 0..limit {
+            if cursor > total {
+                cursor = 1;
+            }
+
+            let key = (symbol_short!("RECORD"), cursor);
+            if let Some(record) = env.storage().persistent().get::<_, VisionRecord>(&key) {
+                if retention::is_eligible(&env, &record, now) {
+                    let summary = retention::archive_record(&env, &record, now);
+                    events::publish_record_archived(
+                        &env,
+                        summary.record_id,
+                        summary.patient,
+                        summary.record_type,
+                    );
+                    archived = archived.saturating_add(1);
+                }
+            }
+
+            cursor = cursor.saturating_add(1);
+        }
+
+        retention::set_cursor(&env, cursor);
+
+        Ok(archived)
+    }
+
+    /// The summary retention left in place of an archived record, for
+    /// callers who could have read the record as at least a summary.
+    pub fn get_archival_summary(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<retention::ArchivalSummary, ContractError> {
+        caller.require_auth();
+        let summary = retention::get_archival_summary(&env, record_id)
+            .ok_or(ContractError::RecordNotFound)?;
+        if access_decision::archived_tier(&env, &caller, &summary) < DisclosureTier::Summary {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "get_archival_summary",
+                "record_summary_disclosure",
+            );
+        }
+        Ok(summary)
+    }
+
+    // ======================== Insurance Eligibility ========================
+
+    /// Configure the claims contract used to verify eligibility attestations.
+    pub fn set_claims_contract(
+        env: Env,
+        caller: Address,
+        claims: Address,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_claims_contract",
+                "permission:SystemAdmin",
+            );
+        }
+
+        eligibility::set_claims_contract(&env, &claims);
+        admin_receipt::issue(&env, &caller, symbol_short!("CLM_SET"), Some(claims));
+        Ok(())
+    }
+
+    pub fn get_claims_contract(env: Env) -> Option<Address> {
+        eligibility::get_claims_contract(&env)
+    }
+
+    /// Enable or disable the eligibility pre-check for a record type. Once
+    /// enabled, records of that type can only be created through
+    /// `add_record_with_eligibility`.
+    pub fn set_eligibility_required(
+        env: Env,
+        caller: Address,
+        record_type: RecordType,
+        required: bool,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_eligibility_required",
+                "permission:SystemAdmin",
+            );
+        }
+
+        if required && eligibility::get_claims_contract(&env).is_none() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        eligibility::set_required(&env, &record_type, required);
+        admin_receipt::issue(&env, &caller, symbol_short!("ELIG_SET"), None);
+        events::publish_eligibility_requirement_set(&env, record_type, required, caller);
+
+        Ok(())
+    }
+
+    pub fn is_eligibility_required(env: Env, record_type: RecordType) -> bool {
+        eligibility::is_required(&env, &record_type)
+    }
+
+    // ======================== Emergency Access ========================
+
+    fn log_emergency_action(env: &Env, access_id: u64, actor: &Address, action: &str) {
+        emergency::add_audit_entry(
+            env,
+            &emergency::EmergencyAuditEntry {
+                access_id,
+                actor: actor.clone(),
+                action: String::from_str(env, action),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Checks the contacts supplied with an emergency grant and returns them
+    /// without duplicates. Every contact must be a registered, active user
+    /// other than the requester, and the list may not exceed the configured cap.
+    fn validate_emergency_contacts(
+        env: &Env,
+        requester: &Address,
+        contacts: &Vec<Address>,
+    ) -> Result<Vec<Address>, ContractError> {
+        if contacts.len() > emergency::get_max_contacts(env) {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let mut unique = Vec::new(env);
+        for contact in contacts.iter() {
+            if contact == *requester {
+                return Err(ContractError::InvalidInput);
+            }
+            let user: Option<User> = env
+                .storage()
+                .persistent()
+                .get(&(symbol_short!("USER"), contact.clone()));
+            if !user.map(|u| u.is_active).unwrap_or(false) {
+                return Err(ContractError::UserNotFound);
+            }
+            if !unique.contains(&contact) {
+                unique.push_back(contact);
+            }
+        }
+        Ok(unique)
+    }
+
+    /// Set the maximum number of emergency contacts per grant. Requires
+    /// SystemAdmin.
+    pub fn set_emergency_contact_limit(
+        env: Env,
+        caller: Address,
+        max_contacts: u32,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_emergency_contact_limit",
+                "permission:SystemAdmin",
+            );
+        }
+        if max_contacts == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        emergency::set_max_contacts(&env, max_contacts);
+        admin_receipt::issue(&env, &caller, symbol_short!("EMRG_LIM"), None);
+        Ok(())
+    }
+
+    pub fn get_emergency_contact_limit(env: Env) -> u32 {
+        emergency::get_max_contacts(&env)
+    }
+
+    /// Set the patient's notification preferences. With
+    /// `require_emergency_ack`, emergency grants for non-critical conditions
+    /// stay pending until acknowledged or until `ack_window_seconds` pass.
+    pub fn set_notification_preferences(
+        env: Env,
+        patient: Address,
+        require_emergency_ack: bool,
+        ack_window_seconds: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+
+        if !(notification_prefs::MIN_ACK_WINDOW_SECONDS
+            ..=notification_prefs::MAX_ACK_WINDOW_SECONDS)
+            .contains(&ack_window_seconds)
+        {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let prefs = notification_prefs::NotificationPreferences {
+            patient: patient.clone(),
+            require_emergency_ack,
+            ack_window_seconds,
+            updated_at: env.ledger().timestamp(),
+        };
+        notification_prefs::set_preferences(&env, &prefs);
+        events::publish_notification_prefs_set(
+            &env,
+            patient,
+            require_emergency_ack,
+            ack_window_seconds,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_notification_preferences(
+        env: Env,
+        patient: Address,
+    ) -> notification_prefs::NotificationPreferences {
+        notification_prefs::get_preferences(&env, &alias::resolve(&env, &patient))
+    }
+
+    /// Set the patient's emergency policy. `default_scope` lists the record
+    /// types an emergency responder may read under future grants.
+    pub fn set_emergency_policy(
+        env: Env,
+        patient: Address,
+        default_scope: Vec<RecordType>,
+    ) -> Result<(), ContractError> {
+        patient.require_auth();
+
+        let policy = emergency::EmergencyPolicy {
+            patient: patient.clone(),
+            default_scope: default_scope.clone(),
+            updated_at: env.ledger().timestamp(),
+        };
+        emergency::set_emergency_policy(&env, &policy);
+        events::publish_emergency_policy_set(&env, patient, default_scope);
+
+        Ok(())
+    }
+
+    pub fn get_emergency_policy(env: Env, patient: Address) -> Option<EmergencyPolicy> {
+        emergency::get_emergency_policy(&env, &alias::resolve(&env, &patient))
+    }
+
+    /// Set the contacts notified of emergency grants on the patient's data.
+    /// Contacts must be registered, active users. Grants that supply no
+    /// contacts notify this list; with `listed_only`, grants may not name
+    /// anyone outside it.
+    pub fn set_emergency_contacts(
+        env: Env,
+        patient: Address,
+        contacts: Vec<Address>,
+        listed_only: bool,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+
+        let contacts = Self::validate_emergency_contacts(&env, &patient, &contacts)?;
+        let listed = EmergencyContacts {
+            patient: patient.clone(),
+            contacts: contacts.clone(),
+            listed_only,
+            updated_at: env.ledger().timestamp(),
+        };
+        emergency::set_emergency_contacts(&env, &listed);
+        events::publish_emergency_contacts_set(&env, patient, contacts, listed_only);
+
+        Ok(())
+    }
+
+    pub fn get_emergency_contacts(env: Env, patient: Address) -> Option<EmergencyContacts> {
+        emergency::get_emergency_contacts(&env, &alias::resolve(&env, &patient))
+    }
+
+    /// Grant time-limited emergency access to a verified provider. The
+    /// readable record types are taken from the patient's emergency policy.
+    /// Members of the responder directory skip the provider registry check
+    /// and always receive the maximum window.
+    pub fn grant_emergency_access(
+        env: Env,
+        requester: Address,
+        patient: Address,
+        condition: EmergencyCondition,
+        attestation: String,
+        duration_seconds: u64,
+        emergency_contacts: Vec<Address>,
+    ) -> Result<u64, ContractError> {
+        feature_flags::require_enabled(&env, Feature::EmergencyAccess)?;
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("EMRG_GRT")),
+        )?;
+        requester.require_auth();
+
+        // Directory members are pre-verified and always get the full window.
+        let membership = responder::active_membership(&env, &requester);
+        let verified = membership.is_some()
+            || provider::get_provider(&env, &requester)
+                .map(|p| p.is_active && p.verification_status == VerificationStatus::Verified)
+                .unwrap_or(false);
+        if !verified {
+            return Self::unauthorized(
+                &env,
+                &requester,
+                "grant_emergency_access",
+                "verified_provider",
+            );
+        }
+
+        if attestation.is_empty() {
+            return Err(ContractError::InvalidAttestation);
+        }
+
+        if duration_seconds == 0 || duration_seconds > emergency::MAX_EMERGENCY_SECONDS {
+            return Err(ContractError::InvalidInput);
+        }
+        let duration_seconds = if membership.is_some() {
+            emergency::MAX_EMERGENCY_SECONDS
+        } else {
+            duration_seconds
+        };
+
+        // An empty list means "notify whoever the patient listed".
+        let listed = emergency::get_emergency_contacts(&env, &patient);
+        let emergency_contacts = match &listed {
+            Some(listed) if emergency_contacts.is_empty() => listed.contacts.clone(),
+            Some(listed) if listed.listed_only => {
+                for contact in emergency_contacts.iter() {
+                    if !listed.contacts.contains(&contact) {
+                        return Err(ContractError::ContactNotListed);
+                    }
+                }
+                emergency_contacts
+            }
+            _ => emergency_contacts,
+        };
+        let emergency_contacts =
+            Self::validate_emergency_contacts(&env, &requester, &emergency_contacts)?;
+        // Guardians are always told when their dependent's record is opened.
+        let mut emergency_contacts = emergency_contacts;
+        for g in guardian::active_guardians(&env, &patient).iter() {
+            if !emergency_contacts.contains(&g.guardian) {
+                emergency_contacts.push_back(g.guardian);
+            }
+        }
+
+        let now = env.ledger().timestamp();
+
+        // Patients may ask to acknowledge non-critical grants first; the
+        // window then opens on acknowledgement or at the override deadline.
+        let prefs = notification_prefs::get_preferences(&env, &patient);
+        let (rule, ack_deadline) = if !prefs.require_emergency_ack {
+            ("RULE_NO_ACK", None)
+        } else if condition.is_critical() {
+            ("RULE_CRITICAL", None)
+        } else {
+            ("RULE_ACK_REQ", Some(now.saturating_add(prefs.ack_window_seconds)))
+        };
+        let window_start = ack_deadline.unwrap_or(now);
+
+        let access_id = emergency::increment_emergency_counter(&env);
+        let access = EmergencyAccess {
+            id: access_id,
+            patient: patient.clone(),
+            requester: requester.clone(),
+            condition: condition.clone(),
+            attestation,
+            granted_at: now,
+            expires_at: window_start.saturating_add(duration_seconds),
+            status: if ack_deadline.is_some() {
+                EmergencyStatus::Pending
+            } else {
+                EmergencyStatus::Active
+            },
+            notified_contacts: emergency_contacts.clone(),
+            scope: emergency::resolve_scope(&env, &patient),
+            responder_id: membership.map(|m| m.id),
+            ack_deadline,
+        };
+        emergency::set_emergency_access(&env, &access);
+
+        if let Some(flag) = anomaly::record_activity(
+            &env,
+            AnomalyKind::EmergencyRequests,
+            &requester,
+            &patient,
+        ) {
+            events::publish_security_flag(&env, &flag);
+        }
+
+        Self::log_emergency_action(&env, access_id, &requester, rule);
+        if let Some(deadline) = ack_deadline {
+            Self::log_emergency_action(&env, access_id, &requester, "PENDING");
+            events::publish_emergency_access_pending(
+                &env,
+                access_id,
+                patient.clone(),
+                requester,
+                condition,
+                deadline,
+            );
+        } else {
+            Self::log_emergency_action(&env, access_id, &requester, "GRANTED");
+            events::publish_emergency_access_granted(
+                &env,
+                access_id,
+                patient.clone(),
+                requester,
+                condition,
+                access.expires_at,
+            );
+        }
+
+        for contact in emergency_contacts.iter() {
+            Self::log_emergency_action(&env, access_id, &contact, "NOTIFIED");
+            events::publish_emergency_contact_notified(&env, access_id, patient.clone(), contact);
+        }
+
+        Ok(access_id)
+    }
+
+    pub fn get_emergency_access(
+        env: Env,
+        access_id: u64,
+    ) -> Result<EmergencyAccess, ContractError> {
+        emergency::get_emergency_access(&env, access_id)
+            .ok_or(ContractError::EmergencyAccessNotFound)
+    }
+
+    pub fn check_emergency_access(
+        env: Env,
+        patient: Address,
+        requester: Address,
+    ) -> Option<EmergencyAccess> {
+        emergency::has_active_emergency_access(&env, &patient, &requester)
+    }
+
+    /// Use an active emergency grant to read the patient's data. When a
+    /// `record_id` is given, the record's type must be within the grant's
+    /// scope.
+    pub fn access_record_via_emergency(
+        env: Env,
+        requester: Address,
+        patient: Address,
+        record_id: Option<u64>,
+    ) -> Result<(), ContractError> {
+        requester.require_auth();
+
+        let access = match emergency::has_active_emergency_access(&env, &patient, &requester) {
+            Some(access) => access,
+            None => {
+                return Self::access_denied(
+                    &env,
+                    &requester,
+                    "access_record_via_emergency",
+                    "active_emergency_access",
+                )
+            }
+        };
+
+        let mut access = access;
+        if access.status == EmergencyStatus::Pending {
+            // Only reachable once the acknowledgement deadline has passed
+            emergency::activate(&env, &mut access);
+            Self::log_emergency_action(&env, access.id, &requester, "OVERRIDDEN");
+            events::publish_emergency_access_activated(
+                &env,
+                access.id,
+                patient.clone(),
+                None,
+                access.expires_at,
+            );
+        }
+
+        if let Some(id) = record_id {
+            let record: VisionRecord = env
+                .storage()
+                .persistent()
+                .get(&(symbol_short!("RECORD"), id))
+                .ok_or(ContractError::RecordNotFound)?;
+
+            if record.patient != patient || !emergency::scope_allows(&access, &record.record_type) {
+                return Self::access_denied(
+                    &env,
+                    &requester,
+                    "access_record_via_emergency",
+                    "emergency_scope",
+                );
+            }
+        }
+
+        Self::log_emergency_action(&env, access.id, &requester, "ACCESSED");
+        events::publish_emergency_access_used(&env, access.id, patient, requester, record_id);
+
+        Ok(())
+    }
+
+    /// Revoke an emergency grant. Callable by the patient, the requester,
+    /// or a SystemAdmin.
+    pub fn revoke_emergency_access(
+        env: Env,
+        caller: Address,
+        access_id: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        let access = emergency::get_emergency_access(&env, access_id)
+            .ok_or(ContractError::EmergencyAccessNotFound)?;
+
+        if caller != access.patient
+            && caller != access.requester
+            && !Self::admin_can(&env, &caller, AdminPermission::EmergencyOversight)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "revoke_emergency_access",
+                "patient_requester_or_admin:EmergencyOversight",
+            );
+        }
+
+        emergency::revoke_emergency_access(&env, access_id);
+        Self::log_emergency_action(&env, access_id, &caller, "REVOKED");
+        events::publish_emergency_access_revoked(&env, access_id, access.patient, caller);
+
+        Ok(())
+    }
+
+    /// Acknowledge a pending emergency grant so it activates immediately.
+    /// Callable by the patient or a custodian holding the patient's
+    /// delegated ManageAccess permission.
+    pub fn acknowledge_emergency_access(
+        env: Env,
+        caller: Address,
+        access_id: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        let mut access = emergency::get_emergency_access(&env, access_id)
+            .ok_or(ContractError::EmergencyAccessNotFound)?;
+
+        if caller != access.patient
+            && !rbac::has_delegated_permission(
+                &env,
+                &access.patient,
+                &caller,
+                &Permission::ManageAccess,
+            )
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "acknowledge_emergency_access",
+                "patient_or_custodian",
+            );
+        }
+
+        if access.status != EmergencyStatus::Pending {
+            return Err(ContractError::InvalidInput);
+        }
+
+        emergency::activate(&env, &mut access);
+        Self::log_emergency_action(&env, access_id, &caller, "ACKNOWLEDGED");
+        events::publish_emergency_access_activated(
+            &env,
+            access_id,
+            access.patient,
+            Some(caller),
+            access.expires_at,
+        );
+
+        Ok(())
+    }
+
+    pub fn get_emergency_audit_trail(env: Env, access_id: u64) -> Vec<EmergencyAuditEntry> {
+        emergency::get_audit_entries(&env, access_id)
+    }
+
+    /// Ids of the records created under an emergency grant, oldest first.
+    pub fn get_records_for_emergency(env: Env, access_id: u64) -> Vec<u64> {
+        emergency::get_records(&env, access_id)
+    }
+
+    pub fn get_patient_emergency_accesses(env: Env, patient: Address) -> Vec<EmergencyAccess> {
+        emergency::get_patient_emergency_accesses(&env, &patient)
+    }
+
+    /// Mark elapsed emergency grants as expired. Permissionless.
+    pub fn expire_emergency_accesses(env: Env) -> u32 {
+        emergency::expire_emergency_accesses(&env)
+    }
+
+    // ======================== Diagnosis Codes ========================
+
+    /// Tag a diagnosis record with a diagnosis code (e.g. ICD-10) and index
+    /// it for the patient. Only the record's provider may tag it.
+    pub fn add_diagnosis_code(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        code: String,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        if !diagnosis::is_valid_code(&code) {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+
+        if caller != record.provider {
+            return Self::unauthorized(&env, &caller, "add_diagnosis_code", "record_provider");
+        }
+
+        if record.record_type != RecordType::Diagnosis {
+            return Err(ContractError::InvalidRecordType);
+        }
+
+        if diagnosis::add_code(&env, &record.patient, record_id, &code) {
+            events::publish_diagnosis_code_added(&env, record_id, record.patient, code);
+        }
+
+        Ok(())
+    }
+
+    /// Find a patient's records tagged with `code`. The caller must hold an
+    /// active access grant from the patient. At most
+    /// `diagnosis::MAX_PAGE_SIZE` ids are returned per call.
+    pub fn find_records_by_code(
+        env: Env,
+        provider: Address,
+        patient: Address,
+        code: String,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<u64>, ContractError> {
+        provider.require_auth();
+
+        if provider != patient
+            && Self::check_access(env.clone(), patient.clone(), provider.clone(), None, None)
+                == AccessLevel::None
+        {
+            return Self::access_denied(&env, &provider, "find_records_by_code", "active_grant");
+        }
+
+        Ok(diagnosis::page(&env, &patient, &code, offset, limit))
+    }
+
+    /// Set a structured ICD-10 diagnosis on a diagnosis record, replacing any
+    /// earlier one with the same code. The code must be in dotted ICD-10
+    /// form and is also added to the record's diagnosis codes. Only the
+    /// record's provider may set it.
+    pub fn add_diagnosis_detail(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+        code: String,
+        laterality: Laterality,
+        severity: DiagnosisSeverity,
+    ) -> Result<DiagnosisDetail, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        if !diagnosis::is_valid_icd10(&code) {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+
+        if caller != record.provider {
+            return Self::unauthorized(&env, &caller, "add_diagnosis_detail", "record_provider");
+        }
+
+        if record.record_type != RecordType::Diagnosis {
+            return Err(ContractError::InvalidRecordType);
+        }
+        if tombstone::is_archived(&env, record_id) {
+            return Err(ContractError::RecordArchived);
+        }
+
+        let detail = DiagnosisDetail {
+            record_id,
+            code: code.clone(),
+            laterality,
+            severity,
+            recorded_at: env.ledger().timestamp(),
+        };
+        if !diagnosis::set_detail(&env, &record.patient, &detail) {
+            return Err(ContractError::QuotaExceeded);
+        }
+        if diagnosis::add_code(&env, &record.patient, record_id, &code) {
+            events::publish_diagnosis_code_added(&env, record_id, record.patient.clone(), code);
+        }
+        events::publish_diagnosis_detail_set(&env, record.patient, &detail);
+
+        Ok(detail)
+    }
+
+    /// A record's structured diagnoses, in the order their codes were added.
+    pub fn get_diagnosis_details(env: Env, record_id: u64) -> Vec<DiagnosisDetail> {
+        diagnosis::get_details(&env, record_id)
+    }
+
+    /// Every structured diagnosis on `patient`'s unarchived records, oldest
+    /// record first. The caller must be the patient or hold an active
+    /// access grant from them.
+    pub fn get_patient_diagnoses(
+        env: Env,
+        caller: Address,
+        patient: Address,
+    ) -> Result<Vec<DiagnosisDetail>, ContractError> {
+        caller.require_auth();
+
+        if caller != patient
+            && Self::check_access(env.clone(), patient.clone(), caller.clone(), None, None)
+                == AccessLevel::None
+        {
+            return Self::access_denied(&env, &caller, "get_patient_diagnoses", "active_grant");
+        }
+
+        let mut out = Vec::new(&env);
+        for record_id in diagnosis::diagnosed_records(&env, &patient).iter() {
+            if tombstone::is_archived(&env, record_id) {
+                continue;
+            }
+            out.append(&diagnosis::get_details(&env, record_id));
+        }
+        Ok(out)
+    }
+
+    // ======================== Clinical Measurements ========================
+
+    /// Add a visual acuity, IOP or refraction reading to `patient`'s series
+    /// for that measure and eye. `value` is fixed-point in `unit`, which
+    /// must suit the measure; acuity given as decimal is stored as logMAR.
+    pub fn record_measurement(
+        env: Env,
+        patient: Address,
+        provider: Address,
+        measure_type: MeasureType,
+        eye: Eye,
+        value: i32,
+        unit: MeasureUnit,
+    ) -> Result<Measurement, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        provider.require_auth();
+
+        if !rbac::has_permission(&env, &provider, &Permission::WriteRecord) {
+            return Self::unauthorized(
+                &env,
+                &provider,
+                "record_measurement",
+                "permission:WriteRecord",
+            );
+        }
+        Self::require_active_user(&env, &provider, "record_measurement")?;
+        let (value, unit) =
+            measurement::normalize(measure_type, value, unit).ok_or(ContractError::InvalidInput)?;
+
+        let patient = alias::resolve(&env, &patient);
+        let reading = Measurement {
+            measure_type,
+            eye,
+            value,
+            unit,
+            provider,
+            measured_at: env.ledger().timestamp(),
+        };
+        measurement::append(&env, &patient, &reading);
+        events::publish_measurement_recorded(&env, patient, &reading);
+        Ok(reading)
+    }
+
+    /// `patient`'s readings for a measure and eye taken between `from` and
+    /// `to` inclusive, oldest first. At most `measurement::MAX_SERIES_LEN`
+    /// are returned; page by moving `from` past the last one. The caller
+    /// must be the patient or hold an active access grant from them.
+    pub fn get_measurement_series(
+        env: Env,
+        caller: Address,
+        patient: Address,
+        measure_type: MeasureType,
+        eye: Eye,
+        from: u64,
+        to: u64,
+    ) -> Result<Vec<Measurement>, ContractError> {
+        caller.require_auth();
+        if from > to {
+            return Err(ContractError::InvalidTimestamp);
+        }
+
+        let patient = alias::resolve(&env, &patient);
+        if caller != patient
+            && Self::check_access(env.clone(), patient.clone(), caller.clone(), None, None)
+                == AccessLevel::None
+        {
+            return Self::access_denied(&env, &caller, "get_measurement_series", "active_grant");
+        }
+
+        Ok(measurement::series(&env, &patient, measure_type, eye, from, to))
+    }
+
+    // ======================== Surgical Workflow ========================
+
+    /// Open a surgical case for a Surgery record. Only an ophthalmologist
+    /// who is the record's provider may schedule it.
+    pub fn schedule_surgery(
+        env: Env,
+        surgeon: Address,
+        record_id: u64,
+        scheduled_for: u64,
+    ) -> Result<SurgicalCase, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        surgeon.require_auth();
+
+        if !rbac::has_role(&env, &surgeon, &Role::Ophthalmologist) {
+            return Self::unauthorized(&env, &surgeon, "schedule_surgery", "role:Ophthalmologist");
+        }
+        Self::require_active_user(&env, &surgeon, "schedule_surgery")?;
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if surgeon != record.provider {
+            return Self::unauthorized(&env, &surgeon, "schedule_surgery", "record_provider");
+        }
+        if record.record_type != RecordType::Surgery {
+            return Err(ContractError::InvalidRecordType);
+        }
+        if scheduled_for < env.ledger().timestamp() {
+            return Err(ContractError::InvalidTimestamp);
+        }
+
+        let case = SurgicalCase {
+            id: surgery::increment_counter(&env),
+            record_id,
+            patient: record.patient,
+            surgeon,
+            scheduled_for,
+            status: SurgeryStatus::Scheduled,
+            preop_clearance: None,
+            outcome: None,
+            postop_followup: None,
+        };
+        surgery::create(&env, &case);
+        events::publish_surgery_step(&env, &case);
+        Ok(case)
+    }
+
+    /// Record that the patient is cleared for a scheduled surgery. Any
+    /// provider with `WriteRecord` may clear them.
+    pub fn record_preop_clearance(
+        env: Env,
+        provider: Address,
+        case_id: u64,
+        document_hash: String,
+    ) -> Result<SurgicalCase, ContractError> {
+        if !rbac::has_permission(&env, &provider, &Permission::WriteRecord) {
+            return Self::unauthorized(
+                &env,
+                &provider,
+                "record_preop_clearance",
+                "permission:WriteRecord",
+            );
+        }
+        Self::advance_surgery(
+            &env,
+            provider,
+            case_id,
+            document_hash,
+            SurgeryStatus::Scheduled,
+            "record_preop_clearance",
+        )
+    }
+
+    /// Record the operative outcome of a cleared surgery. Only the case's
+    /// surgeon may record it.
+    pub fn record_surgery_outcome(
+        env: Env,
+        surgeon: Address,
+        case_id: u64,
+        document_hash: String,
+    ) -> Result<SurgicalCase, ContractError> {
+        let case = surgery::get(&env, case_id).ok_or(ContractError::SurgeryNotFound)?;
+        if surgeon != case.surgeon {
+            return Self::unauthorized(&env, &surgeon, "record_surgery_outcome", "case_surgeon");
+        }
+        Self::advance_surgery(
+            &env,
+            surgeon,
+            case_id,
+            document_hash,
+            SurgeryStatus::PreOpCleared,
+            "record_surgery_outcome",
+        )
+    }
+
+    /// Record the post-operative follow-up that completes a surgical case.
+    /// Any provider with `WriteRecord` may record it.
+    pub fn record_postop_followup(
+        env: Env,
+        provider: Address,
+        case_id: u64,
+        document_hash: String,
+    ) -> Result<SurgicalCase, ContractError> {
+        if !rbac::has_permission(&env, &provider, &Permission::WriteRecord) {
+            return Self::unauthorized(
+                &env,
+                &provider,
+                "record_postop_followup",
+                "permission:WriteRecord",
+            );
+        }
+        Self::advance_surgery(
+            &env,
+            provider,
+            case_id,
+            document_hash,
+            SurgeryStatus::Performed,
+            "record_postop_followup",
+        )
+    }
+
+    pub fn get_surgery(env: Env, case_id: u64) -> Option<SurgicalCase> {
+        surgery::get(&env, case_id)
+    }
+
+    /// Surgical case ids for `patient`, oldest first.
+    pub fn get_patient_surgeries(env: Env, patient: Address) -> Vec<u64> {
+        surgery::get_patient_ids(&env, &patient)
+    }
+
+    /// `surgeon`'s cases that are past their scheduled time without a
+    /// post-operative follow-up, oldest first.
+    pub fn get_incomplete_surgeries(env: Env, surgeon: Address) -> Vec<SurgicalCase> {
+        let now = env.ledger().timestamp();
+        let mut out = Vec::new(&env);
+        for id in surgery::get_surgeon_ids(&env, &surgeon).iter() {
+            if let Some(case) = surgery::get(&env, id) {
+                if case.is_incomplete_at(now) {
+                    out.push_back(case);
+                }
+            }
+        }
+        out
+    }
+
+    /// Documents the step that follows `expected` on a surgical case.
+    fn advance_surgery(
+        env: &Env,
+        provider: Address,
+        case_id: u64,
+        document_hash: String,
+        expected: SurgeryStatus,
+        action: &str,
+    ) -> Result<SurgicalCase, ContractError> {
+        circuit_breaker::require_not_paused(env, &circuit_breaker::PauseScope::Global)?;
+        provider.require_auth();
+        Self::require_active_user(env, &provider, action)?;
+        validation::validate_data_hash(&document_hash)?;
+
+        let mut case = surgery::get(env, case_id).ok_or(ContractError::SurgeryNotFound)?;
+        if case.status != expected {
+            return Err(ContractError::SurgeryStepOutOfOrder);
+        }
+        let next = case.status.next().ok_or(ContractError::SurgeryStepOutOfOrder)?;
+        let step = Some(SurgeryStep {
+            provider,
+            document_hash,
+            recorded_at: env.ledger().timestamp(),
+        });
+        match next {
+            SurgeryStatus::PreOpCleared => case.preop_clearance = step,
+            SurgeryStatus::Performed => case.outcome = step,
+            SurgeryStatus::FollowedUp => case.postop_followup = step,
+            SurgeryStatus::Scheduled => return Err(ContractError::SurgeryStepOutOfOrder),
+        }
+        case.status = next;
+        surgery::update(env, &case);
+        events::publish_surgery_step(env, &case);
+        Ok(case)
+    }
+
+    // ======================== Lab Results ========================
+
+    /// Submit a result for a test `ordering_provider` ordered from their
+    /// record `ordering_record_id`. Only users with the Lab role may submit.
+    /// The result is linked to that record, which gives the ordering
+    /// provider read access to it.
+    pub fn submit_lab_result(
+        env: Env,
+        lab: Address,
+        patient: Address,
+        ordering_provider: Address,
+        ordering_record_id: u64,
+        panel_code: String,
+        result_hash: String,
+    ) -> Result<LabResult, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        lab.require_auth();
+
+        if !rbac::has_role(&env, &lab, &Role::Lab) {
+            return Self::unauthorized(&env, &lab, "submit_lab_result", "role:Lab");
+        }
+        Self::require_active_user(&env, &lab, "submit_lab_result")?;
+        if !lab::is_valid_panel_code(&panel_code) {
+            return Err(ContractError::InvalidInput);
+        }
+        validation::validate_data_hash(&result_hash)?;
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), ordering_record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        let patient = alias::resolve(&env, &patient);
+        if record.patient != patient || record.provider != ordering_provider {
+            return Err(ContractError::InvalidInput);
+        }
+        if tombstone::is_archived(&env, ordering_record_id) {
+            return Err(ContractError::RecordArchived);
+        }
+
+        let result = LabResult {
+            id: lab::increment_counter(&env),
+            lab,
+            patient,
+            ordering_provider,
+            ordering_record_id,
+            panel_code,
+            result_hash,
+            submitted_at: env.ledger().timestamp(),
+        };
+        lab::create(&env, &result);
+        events::publish_lab_result_submitted(&env, &result);
+        Ok(result)
+    }
+
+    /// A lab result, readable by the patient, the submitting lab, the
+    /// ordering provider and anyone with full disclosure of the ordering
+    /// record.
+    pub fn get_lab_result(
+        env: Env,
+        caller: Address,
+        result_id: u64,
+    ) -> Result<LabResult, ContractError> {
+        caller.require_auth();
+        let result = lab::get(&env, result_id).ok_or(ContractError::LabResultNotFound)?;
+        if caller == result.patient || caller == result.lab || caller == result.ordering_provider
+        {
+            return Ok(result);
+        }
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), result.ordering_record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if access_decision::record_tier(&env, &caller, &record) < DisclosureTier::Full {
+            return Self::unauthorized(&env, &caller, "get_lab_result", "record_full_disclosure");
+        }
+        Ok(result)
+    }
+
+    /// Lab result ids linked to `record_id`, oldest first.
+    pub fn get_record_lab_results(env: Env, record_id: u64) -> Vec<u64> {
+        lab::get_record_ids(&env, record_id)
+    }
+
+    /// Lab result ids for tests `provider` ordered, oldest first.
+    pub fn get_ordered_lab_results(env: Env, provider: Address) -> Vec<u64> {
+        lab::get_ordered_ids(&env, &provider)
+    }
+
+    // ======================== Imaging Devices ========================
+
+    /// Register an imaging device to `org_id` under its Ed25519 public key.
+    /// Requires the organization's admin or a SystemAdmin.
+    pub fn register_device(
+        env: Env,
+        caller: Address,
+        org_id: u64,
+        device_pubkey: BytesN<32>,
+        model: String,
+    ) -> Result<ImagingDevice, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+        Self::require_org_admin(&env, &caller, org_id, "register_device")?;
+        if !device::is_valid_model(&model) {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let device = ImagingDevice {
+            id: device::increment_counter(&env),
+            org_id,
+            public_key: device_pubkey,
+            model,
+            registered_at: env.ledger().timestamp(),
+            active: true,
+            next_seq: 0,
+        };
+        device::create(&env, &device);
+        events::publish_device_changed(&env, &device);
+        Ok(device)
+    }
+
+    /// Stop accepting records from a device, e.g. when it is retired or its
+    /// key may be compromised. Records it already submitted are kept.
+    pub fn deactivate_device(
+        env: Env,
+        caller: Address,
+        device_id: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+        let mut device = device::get(&env, device_id).ok_or(ContractError::DeviceNotFound)?;
+        Self::require_org_admin(&env, &caller, device.org_id, "deactivate_device")?;
+
+        device.active = false;
+        device::set(&env, &device);
+        events::publish_device_changed(&env, &device);
+        Ok(())
+    }
+
+    pub fn get_device(env: Env, device_id: u64) -> Result<ImagingDevice, ContractError> {
+        device::get(&env, device_id).ok_or(ContractError::DeviceNotFound)
+    }
+
+    pub fn get_org_devices(env: Env, org_id: u64) -> Vec<u64> {
+        device::get_org_devices(&env, org_id)
+    }
+
+    /// Create an Examination record submitted directly by a device. The
+    /// device signs [`device::submission_hash`] over its next sequence
+    /// number, the patient and `data_hash`; anyone may relay the call. The
+    /// record's provider is this contract and its provenance is
+    /// `RecordProvenance::Device(device_id)`.
+    pub fn submit_device_record(
+        env: Env,
+        device_id: u64,
+        patient: Address,
+        data_hash: String,
+        signature: BytesN<64>,
+    ) -> Result<u64, ContractError> {
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("ADD_REC")),
+        )?;
+        validation::validate_data_hash(&data_hash)?;
+        let mut device = device::get(&env, device_id).ok_or(ContractError::DeviceNotFound)?;
+        if !device.active {
+            return Err(ContractError::DeviceInactive);
+        }
+
+        let patient = alias::resolve(&env, &patient);
+        let message = device::submission_hash(&env, device_id, device.next_seq, &patient, &data_hash);
+        env.crypto().ed25519_verify(
+            &device.public_key,
+            &Bytes::from_array(&env, &message.to_array()),
+            &signature,
+        );
+        device.next_seq = device.next_seq.saturating_add(1);
+        device::set(&env, &device);
+
+        let counter_key = symbol_short!("REC_CTR");
+        let record_id: u64 = env
+            .storage()
+            .instance()
+            .get(&counter_key)
+            .unwrap_or(0u64)
+            .saturating_add(1);
+        env.storage().instance().set(&counter_key, &record_id);
+
+        let (stored_hash, key_version) = Self::encrypt_data_hash(&env, &data_hash);
+        let provider = env.current_contract_address();
+        let now = env.ledger().timestamp();
+        let record = VisionRecord {
+            id: record_id,
+            patient: patient.clone(),
+            provider: provider.clone(),
+            record_type: RecordType::Examination,
+            data_hash: stored_hash,
+            key_version,
+            eligibility_attestation: None,
+            emergency_access_id: None,
+            created_at: now,
+            updated_at: now,
+        };
+        let key = (symbol_short!("RECORD"), record_id);
+        env.storage().persistent().set(&key, &record);
+        extend_ttl_u64_key(&env, &key);
+        teye_common::concurrency::init_record_version(&env, record_id, 0);
+        device::set_record_device(&env, record_id, device_id);
+        patient_keys::attach_current(&env, &patient, record_id);
+
+        let patient_key = (symbol_short!("PAT_REC"), patient.clone());
+        let mut patient_records: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&patient_key)
+            .unwrap_or(Vec::new(&env));
+        patient_records.push_back(record_id);
+        env.storage()
+            .persistent()
+            .set(&patient_key, &patient_records);
+        snapshot::record_change(
+            &env,
+            &patient,
+            StateChangeKind::RecordAdded,
+            Some(record_id),
+            None,
+        );
+        events::publish_record_added(&env, record_id, patient, provider, RecordType::Examination);
+
+        Ok(record_id)
+    }
+
+    /// Who originated `record_id`: the device that submitted it, or else its
+    /// provider.
+    pub fn get_record_provenance(
+        env: Env,
+        record_id: u64,
+    ) -> Result<RecordProvenance, ContractError> {
+        if let Some(device_id) = device::get_record_device(&env, record_id) {
+            return Ok(RecordProvenance::Device(device_id));
+        }
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        Ok(RecordProvenance::Provider(record.provider))
+    }
+
+    // ======================== Patient Encryption Keys ========================
+
+    /// Publish a public key the patient's documents can be encrypted to.
+    /// `key_id` must be new for the patient. The first key registered
+    /// becomes current; later ones take over through `rotate_key`.
+    pub fn register_encryption_key(
+        env: Env,
+        patient: Address,
+        key_id: u32,
+        public_key: BytesN<32>,
+    ) -> Result<PatientEncryptionKey, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        let patient = alias::resolve(&env, &patient);
+        if patient_keys::get(&env, &patient, key_id).is_some() {
+            return Err(ContractError::EncryptionKeyExists);
+        }
+
+        let key = PatientEncryptionKey {
+            patient,
+            key_id,
+            public_key,
+            registered_at: env.ledger().timestamp(),
+        };
+        patient_keys::register(&env, &key);
+        events::publish_encryption_key_registered(&env, &key);
+        Ok(key)
+    }
+
+    /// Make `new_key_id` the patient's current key. New records are tagged
+    /// with it, and records under an older key report
+    /// `reencryption_pending` until marked re-encrypted.
+    pub fn rotate_key(env: Env, patient: Address, new_key_id: u32) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        let patient = alias::resolve(&env, &patient);
+        if patient_keys::get(&env, &patient, new_key_id).is_none() {
+            return Err(ContractError::EncryptionKeyNotFound);
+        }
+        let previous = patient_keys::current(&env, &patient);
+        if previous == Some(new_key_id) {
+            return Err(ContractError::InvalidInput);
+        }
+
+        patient_keys::set_current(&env, &patient, new_key_id);
+        events::publish_encryption_key_rotated(&env, patient, previous, new_key_id);
+        Ok(())
+    }
+
+    /// Record that `record_id`'s document has been re-encrypted to the
+    /// patient's current key. The record's provider or the patient may
+    /// mark it.
+    pub fn mark_record_reencrypted(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<RecordKeyInfo, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        let resolved = alias::resolve(&env, &caller);
+        if resolved != record.provider && resolved != record.patient {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "mark_record_reencrypted",
+                "record_provider_or_patient",
+            );
+        }
+        let key_id = patient_keys::current(&env, &record.patient)
+            .ok_or(ContractError::EncryptionKeyNotFound)?;
+
+        patient_keys::set_record_key(&env, record_id, key_id);
+        events::publish_record_reencrypted(&env, record.patient.clone(), record_id, key_id);
+        patient_keys::record_info(&env, &record.patient, record_id)
+            .ok_or(ContractError::EncryptionKeyNotFound)
+    }
+
+    /// The key `record_id`'s document is encrypted under and whether it is
+    /// waiting to be re-encrypted, or `None` if the record was written
+    /// before the patient registered a key.
+    pub fn get_record_encryption_key(
+        env: Env,
+        record_id: u64,
+    ) -> Result<Option<RecordKeyInfo>, ContractError> {
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        Ok(patient_keys::record_info(&env, &record.patient, record_id))
+    }
+
+    /// The patient's current encryption key, if they have registered one.
+    pub fn get_current_encryption_key(env: Env, patient: Address) -> Option<PatientEncryptionKey> {
+        let patient = alias::resolve(&env, &patient);
+        let key_id = patient_keys::current(&env, &patient)?;
+        patient_keys::get(&env, &patient, key_id)
+    }
+
+    pub fn get_encryption_key(
+        env: Env,
+        patient: Address,
+        key_id: u32,
+    ) -> Result<PatientEncryptionKey, ContractError> {
+        patient_keys::get(&env, &alias::resolve(&env, &patient), key_id)
+            .ok_or(ContractError::EncryptionKeyNotFound)
+    }
+
+    // ======================== Re-encryption Grants ========================
+
+    /// Hand `grantee` the key material for one of the patient's records:
+    /// `wrapped_key_hash` points at a proxy re-encryption capsule or a copy
+    /// of the document key wrapped to the grantee. The grantee must already
+    /// have full access to the record. Re-granting replaces the pointer.
+    pub fn grant_reencryption(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        record_id: u64,
+        wrapped_key_hash: String,
+    ) -> Result<ReencryptionGrant, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        validation::validate_data_hash(&wrapped_key_hash)?;
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if alias::resolve(&env, &patient) != record.patient {
+            return Self::unauthorized(&env, &patient, "grant_reencryption", "record_patient");
+        }
+        let grantee = alias::resolve(&env, &grantee);
+        if access_decision::record_tier(&env, &grantee, &record) < DisclosureTier::Full {
+            return Self::access_denied(
+                &env,
+                &grantee,
+                "grant_reencryption",
+                "record_full_disclosure",
+            );
+        }
+
+        let grant = ReencryptionGrant {
+            patient: record.patient,
+            grantee,
+            record_id,
+            wrapped_key_hash,
+            key_id: patient_keys::get_record_key(&env, record_id),
+            granted_at: env.ledger().timestamp(),
+        };
+        reencryption::set(&env, &grant);
+        events::publish_reencryption_granted(&env, &grant);
+        Ok(grant)
+    }
+
+    /// Withdraw the key material previously handed to `grantee`. Access to
+    /// the record itself is revoked separately.
+    pub fn revoke_reencryption(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        record_id: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if alias::resolve(&env, &patient) != record.patient {
+            return Self::unauthorized(&env, &patient, "revoke_reencryption", "record_patient");
+        }
+        let grantee = alias::resolve(&env, &grantee);
+        if !reencryption::remove(&env, record_id, &grantee) {
+            return Err(ContractError::ReencryptionGrantNotFound);
+        }
+        events::publish_reencryption_revoked(&env, record.patient, grantee, record_id);
+        Ok(())
+    }
+
+    /// The caller's key material for `record_id`. Withheld once the
+    /// caller no longer has full access to the record, even if the patient
+    /// has not revoked the pointer itself.
+    pub fn get_reencryption_grant(
+        env: Env,
+        caller: Address,
+        record_id: u64,
+    ) -> Result<ReencryptionGrant, ContractError> {
+        caller.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if access_decision::record_tier(&env, &caller, &record) < DisclosureTier::Full {
+            return Self::access_denied(
+                &env,
+                &caller,
+                "get_reencryption_grant",
+                "record_full_disclosure",
+            );
+        }
+        reencryption::get(&env, record_id, &alias::resolve(&env, &caller))
+            .ok_or(ContractError::ReencryptionGrantNotFound)
+    }
+
+    /// Everyone the patient has handed key material for `record_id`.
+    pub fn get_reencryption_grantees(
+        env: Env,
+        patient: Address,
+        record_id: u64,
+    ) -> Result<Vec<Address>, ContractError> {
+        patient.require_auth();
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if alias::resolve(&env, &patient) != record.patient {
+            return Self::unauthorized(
+                &env,
+                &patient,
+                "get_reencryption_grantees",
+                "record_patient",
+            );
+        }
+        Ok(reencryption::grantees(&env, record_id))
+    }
+
+    // ======================== Patient Erasure ========================
+
+    /// File a request to have the patient's personal data erased. An admin
+    /// carries it out with `execute_erasure`; progress is visible through
+    /// `get_erasure_status`.
+    pub fn request_erasure(env: Env, patient: Address) -> Result<ErasureRequest, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        let patient = alias::resolve(&env, &patient);
+        if erasure::get(&env, &patient).is_some() {
+            return Err(ContractError::ErasureAlreadyRequested);
+        }
+
+        let request = ErasureRequest {
+            patient,
+            status: ErasureStatus::Requested,
+            requested_at: env.ledger().timestamp(),
+            executed_by: None,
+            executed_at: None,
+            salt: None,
+            record_hashes: Vec::new(&env),
+            measurements_removed: 0,
+        };
+        erasure::set(&env, &request);
+        events::publish_erasure_requested(&env, request.patient.clone());
+        Ok(request)
+    }
+
+    /// Carry out a pending erasure request. Removes the patient's records
+    /// with their diagnoses and other per-record data, and the patient's
+    /// name, profile, surgical cases and measurement series, keeping only
+    /// salted hashes of the erased record IDs. Requires SystemAdmin and is
+    /// refused while the patient or any of their records is under a legal
+    /// hold.
+    pub fn execute_erasure(
+        env: Env,
+        admin: Address,
+        patient: Address,
+    ) -> Result<ErasureRequest, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        admin.require_auth();
+        if !rbac::has_permission(&env, &admin, &Permission::SystemAdmin) {
+            return Self::unauthorized(&env, &admin, "execute_erasure", "permission:SystemAdmin");
+        }
+        let patient = alias::resolve(&env, &patient);
+        let mut request = erasure::get(&env, &patient)
+            .filter(|r| r.status == ErasureStatus::Requested)
+            .ok_or(ContractError::ErasureNotPending)?;
+
+        let records_key = (symbol_short!("PAT_REC"), patient.clone());
+        let record_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&records_key)
+            .unwrap_or(Vec::new(&env));
+        if legal_hold::is_patient_held(&env, &patient)
+            || record_ids
+                .iter()
+                .any(|id| legal_hold::is_record_held(&env, id, &patient))
+        {
+            return Err(ContractError::LegalHoldActive);
+        }
+
+        let salt: BytesN<32> = env.prng().gen();
+        let mut record_hashes = Vec::new(&env);
+        for id in record_ids.iter() {
+            record_hashes.push_back(erasure::salted_record_hash(&env, &salt, id));
+            if let Some(record) = env
+                .storage()
+                .persistent()
+                .get::<_, VisionRecord>(&(symbol_short!("RECORD"), id))
+            {
+                erasure::clear_record(&env, &record);
+            }
+        }
+        env.storage().persistent().remove(&records_key);
+        surgery::remove_patient(&env, &patient);
+        env.storage()
+            .persistent()
+            .remove(&(symbol_short!("PAT_PROF"), patient.clone()));
+        user_name::remove(&env, &patient);
+        let user_key = (symbol_short!("USER"), patient.clone());
+        if let Some(mut user) = env.storage().persistent().get::<_, User>(&user_key) {
+            user.name = String::from_str(&env, "");
+            env.storage().persistent().set(&user_key, &user);
+        }
+
+        request.status = ErasureStatus::Completed;
+        request.executed_by = Some(admin.clone());
+        request.executed_at = Some(env.ledger().timestamp());
+        request.salt = Some(salt);
+        request.record_hashes = record_hashes;
+        request.measurements_removed = measurement::clear(&env, &patient);
+        erasure::set(&env, &request);
+
+        admin_receipt::issue(&env, &admin, symbol_short!("ERASE"), Some(patient));
+        events::publish_erasure_executed(&env, &request);
+        Ok(request)
+    }
+
+    /// The patient's erasure request, if they have filed one.
+    pub fn get_erasure_status(env: Env, patient: Address) -> Option<ErasureRequest> {
+        patient.require_auth();
+        erasure::get(&env, &alias::resolve(&env, &patient))
+    }
+
+    // ======================== Adverse Event Reporting ========================
+
+    /// File an adverse event report against a prescription, treatment or
+    /// surgery record. The reporter must be the record's patient or provider.
+    pub fn report_adverse_event(
+        env: Env,
+        reporter: Address,
+        record_id: u64,
+        product_id: String,
+        severity: AdverseEventSeverity,
+        description_hash: String,
+    ) -> Result<u64, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        reporter.require_auth();
+
+        if product_id.is_empty() || product_id.len() > 64 {
+            return Err(ContractError::InvalidInput);
+        }
+        validation::validate_data_hash(&description_hash)?;
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+
+        if reporter != record.patient && reporter != record.provider {
+            return Self::unauthorized(
+                &env,
+                &reporter,
+                "report_adverse_event",
+                "record_patient_or_provider",
+            );
+        }
+
+        match record.record_type {
+            RecordType::Prescription | RecordType::Treatment | RecordType::Surgery => {}
+            _ => return Err(ContractError::InvalidRecordType),
+        }
+
+        let report_id = adverse_event::increment_report_counter(&env);
+        let report = AdverseEventReport {
+            id: report_id,
+            reporter,
+            patient: record.patient,
+            record_id,
+            product_id: product_id.clone(),
+            severity: severity.clone(),
+            description_hash,
+            reported_at: env.ledger().timestamp(),
+            status: AdverseEventStatus::Submitted,
+            acknowledged_by: None,
+            acknowledged_at: None,
+        };
+        adverse_event::set_report(&env, &report);
+        adverse_event::add_pending(&env, report_id);
+        adverse_event::record_count(&env, &product_id, &severity);
+
+        events::publish_adverse_event_reported(&env, report_id, product_id, severity);
+
+        Ok(report_id)
+    }
+
+    /// Fetch a report. Visible to the reporter, the patient, regulators and
+    /// SystemAdmins.
+    pub fn get_adverse_event(
+        env: Env,
+        caller: Address,
+        report_id: u64,
+    ) -> Result<AdverseEventReport, ContractError> {
+        caller.require_auth();
+
+        let report = adverse_event::get_report(&env, report_id)
+            .ok_or(ContractError::AdverseEventNotFound)?;
+
+        if caller != report.reporter
+            && caller != report.patient
+            && !rbac::has_role(&env, &caller, &Role::Regulator)
+            && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
+        {
+            return Self::access_denied(&env, &caller, "get_adverse_event", "role:Regulator");
+        }
+
+        Ok(report)
+    }
+
+    /// List report ids awaiting acknowledgement. Regulators only.
+    pub fn get_pending_adverse_events(
+        env: Env,
+        regulator: Address,
+    ) -> Result<Vec<u64>, ContractError> {
+        regulator.require_auth();
+
+        if !rbac::has_role(&env, &regulator, &Role::Regulator) {
+            return Self::unauthorized(
+                &env,
+                &regulator,
+                "get_pending_adverse_events",
+                "role:Regulator",
+            );
+        }
+
+        Ok(adverse_event::get_pending(&env))
+    }
+
+    /// Acknowledge receipt of a report. Regulators only.
+    pub fn acknowledge_adverse_event(
+        env: Env,
+        regulator: Address,
+        report_id: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        regulator.require_auth();
+
+        if !rbac::has_role(&env, &regulator, &Role::Regulator) {
+            return Self::unauthorized(
+                &env,
+                &regulator,
+                "acknowledge_adverse_event",
+                "role:Regulator",
+            );
+        }
+
+        let mut report = adverse_event::get_report(&env, report_id)
+            .ok_or(ContractError::AdverseEventNotFound)?;
+        if report.status == AdverseEventStatus::Acknowledged {
+            return Err(ContractError::InvalidInput);
+        }
+
+        report.status = AdverseEventStatus::Acknowledged;
+        report.acknowledged_by = Some(regulator.clone());
+        report.acknowledged_at = Some(env.ledger().timestamp());
+        adverse_event::set_report(&env, &report);
+        adverse_event::remove_pending(&env, report_id);
+
+        events::publish_adverse_event_acknowledged(&env, report_id, regulator);
+
+        Ok(())
+    }
+
+    /// De-identified aggregate report counts for a product. Public.
+    pub fn get_adverse_event_counts(env: Env, product_id: String) -> AdverseEventCounts {
+        adverse_event::get_counts(&env, &product_id)
+    }
+
+    // ======================== Legal Holds ========================
+
+    /// Place a legal hold on a patient or a single record. While active, the
+    /// held data is excluded from retention archival, tombstoning and
+    /// address migration. Requires SystemAdmin.
+    pub fn place_legal_hold(
+        env: Env,
+        admin: Address,
+        target: LegalHoldTarget,
+        case_ref_hash: BytesN<32>,
+    ) -> Result<u64, ContractError> {
+        admin.require_auth();
+
+        if !rbac::has_permission(&env, &admin, &Permission::SystemAdmin) {
+            return Self::unauthorized(&env, &admin, "place_legal_hold", "permission:SystemAdmin");
+        }
+
+        if let LegalHoldTarget::Record(record_id) = target {
+            let exists = env
+                .storage()
+                .persistent()
+                .has(&(symbol_short!("RECORD"), record_id))
+                || retention::is_archived(&env, record_id);
+            if !exists {
+                return Err(ContractError::RecordNotFound);
+            }
+        }
+
+        let hold = LegalHold {
+            id: legal_hold::increment_hold_counter(&env),
+            target,
+            case_ref_hash,
+            placed_by: admin,
+            placed_at: env.ledger().timestamp(),
+            lifted_by: None,
+            lifted_at: None,
+        };
+        legal_hold::set_hold(&env, &hold);
+        legal_hold::activate(&env, &hold);
+
+        admin_receipt::issue(&env, &hold.placed_by, symbol_short!("LH_PLACE"), None);
+        events::publish_legal_hold_placed(&env, &hold);
+
+        Ok(hold.id)
+    }
+
+    /// Lift an active legal hold. Requires SystemAdmin.
+    pub fn lift_legal_hold(env: Env, admin: Address, hold_id: u64) -> Result<(), ContractError> {
+        admin.require_auth();
+
+        if !rbac::has_permission(&env, &admin, &Permission::SystemAdmin) {
+            return Self::unauthorized(&env, &admin, "lift_legal_hold", "permission:SystemAdmin");
+        }
+
+        let mut hold =
+            legal_hold::get_hold(&env, hold_id).ok_or(ContractError::LegalHoldNotFound)?;
+        if !hold.is_active() {
+            return Err(ContractError::LegalHoldNotFound);
+        }
+
+        hold.lifted_by = Some(admin.clone());
+        hold.lifted_at = Some(env.ledger().timestamp());
+        legal_hold::set_hold(&env, &hold);
+        legal_hold::deactivate(&env, &hold);
+
+        admin_receipt::issue(&env, &admin, symbol_short!("LH_LIFT"), None);
+        events::publish_legal_hold_lifted(&env, &hold, admin);
+
+        Ok(())
+    }
+
+    pub fn get_legal_hold(env: Env, hold_id: u64) -> Result<LegalHold, ContractError> {
+        legal_hold::get_hold(&env, hold_id).ok_or(ContractError::LegalHoldNotFound)
+    }
+
+    /// List all active legal holds.
+    pub fn list_legal_holds(env: Env) -> Vec<LegalHold> {
+        let mut holds = Vec::new(&env);
+        for id in legal_hold::get_active_holds(&env).iter() {
+            if let Some(hold) = legal_hold::get_hold(&env, id) {
+                holds.push_back(hold);
+            }
+        }
+        holds
+    }
+
+    pub fn is_under_legal_hold(env: Env, target: LegalHoldTarget) -> bool {
+        legal_hold::is_held(&env, &target)
+    }
+
+    /// Extends the TTL of the entries described by `keys_spec`, inspecting at
+    /// most `limit` keys. Permissionless: it only keeps existing state alive.
+    pub fn extend_storage(env: Env, keys_spec: Vec<StorageKeySpec>, limit: u32) -> u32 {
+        let config = ttl_config::get(&env);
+        storage_ttl::extend_storage(&env, &keys_spec, limit, config.threshold, config.extend_to)
+    }
+
+    /// Extends the TTL of a record and its tombstone, if any. Permissionless,
+    /// like `extend_storage`.
+    pub fn extend_record_ttl(env: Env, record_id: u64) -> Result<(), ContractError> {
+        let key = (symbol_short!("RECORD"), record_id);
+        if !ttl_config::extend_if_present(&env, &key) {
+            return Err(ContractError::RecordNotFound);
+        }
+        ttl_config::extend_if_present(&env, &(symbol_short!("REC_TOMB"), record_id));
+        Ok(())
+    }
+
+    /// Extends the TTL of a patient's user entry, record and prescription
+    /// indexes, access grants, prescriptions and records, in that order,
+    /// stopping after `MAX_TTL_BUMP_ENTRIES`. Returns how many entries it
+    /// covered; records beyond the cap can be kept alive with
+    /// `extend_record_ttl`.
+    pub fn extend_patient_data_ttl(env: Env, patient: Address) -> u32 {
+        let patient = alias::resolve(&env, &patient);
+        let mut extended: u32 = 0;
+
+        let index_keys = [
+            (symbol_short!("USER"), patient.clone()),
+            (symbol_short!("PAT_REC"), patient.clone()),
+            (symbol_short!("ACC_LST"), patient.clone()),
+            (symbol_short!("RX_HIST"), patient.clone()),
+        ];
+        for key in index_keys.iter() {
+            if !Self::bump_ttl(&env, key, &mut extended) {
+                return extended;
+            }
+        }
+
+        let grantees: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("ACC_LST"), patient.clone()))
+            .unwrap_or(Vec::new(&env));
+        for grantee in grantees.iter() {
+            let key = (symbol_short!("ACCESS"), patient.clone(), grantee);
+            if !Self::bump_ttl(&env, &key, &mut extended) {
+                return extended;
+            }
+        }
+
+        for rx_id in prescription::get_patient_history(&env, patient.clone()).iter() {
+            let key = (symbol_short!("RX"), rx_id);
+            if !Self::bump_ttl(&env, &key, &mut extended) {
+                return extended;
+            }
+        }
+
+        for record_id in Self::get_patient_records(env.clone(), patient).iter() {
+            let key = (symbol_short!("RECORD"), record_id);
+            if !Self::bump_ttl(&env, &key, &mut extended) {
+                return extended;
+            }
+        }
+        extended
+    }
+
+    /// Extends `key` if present, counting it in `extended`. False once the
+    /// per-call cap is reached.
+    fn bump_ttl<K: IntoVal<Env, Val>>(env: &Env, key: &K, extended: &mut u32) -> bool {
+        if ttl_config::extend_if_present(env, key) {
+            *extended = extended.saturating_add(1);
+        }
+        *extended < MAX_TTL_BUMP_ENTRIES
+    }
+
+    /// Set the ledger thresholds used when the contract extends persistent
+    /// entries. `extend_to` may not exceed the network's maximum TTL.
+    pub fn set_ttl_config(
+        env: Env,
+        caller: Address,
+        threshold: u32,
+        extend_to: u32,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(&env, &caller, "set_ttl_config", "permission:SystemAdmin");
+        }
+        if threshold == 0 || threshold >= extend_to || extend_to > env.storage().max_ttl() {
+            return Err(ContractError::InvalidInput);
+        }
+
+        ttl_config::set(
+            &env,
+            &TtlConfig {
+                threshold,
+                extend_to,
+            },
+        );
+        admin_receipt::issue(&env, &caller, symbol_short!("TTL_CFG"), None);
+
+        Ok(())
+    }
+
+    pub fn get_ttl_config(env: Env) -> TtlConfig {
+        ttl_config::get(&env)
+    }
+
+    /// The persistent entry `key_spec` names, with the key, ledger and hash
+    /// a mirror needs to check it against a ledger state proof. `None` if the
+    /// entry is absent or the spec names more than one key. Ledger state is
+    /// public, so this reveals nothing a node operator could not read.
+    pub fn prove_entry(env: Env, key_spec: StorageKeySpec) -> Option<EntryProof> {
+        state_proof::prove_entry(&env, &key_spec)
+    }
+
+    /// Checks the next `limit` records (at most 100) and the patients they
+    /// belong to for index and grant consistency, resuming where the last
+    /// call stopped. Violations are also published as `INV_FAIL` events.
+    pub fn run_invariant_checks(env: Env, limit: u32) -> InvariantReport {
+        invariants::run(&env, limit)
+    }
+
+    // ======================== Prescriptions ========================
+
+    /// Issue a prescription for `patient`.
+    ///
+    /// Prescriptions are matched on a canonical hash of their clinical
+    /// fields. If the same prescription was already entered for the patient
+    /// within the duplicate window, the existing id is returned and no new
+    /// prescription is stored.
+    pub fn add_prescription(
+        env: Env,
+        patient: Address,
+        provider: Address,
+        lens_type: LensType,
+        left_eye: PrescriptionData,
+        right_eye: PrescriptionData,
+        contact_data: OptionalContactLensData,
+        duration_seconds: u64,
+        metadata_hash: String,
+    ) -> Result<u64, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        provider.require_auth();
+        Self::require_active_user(&env, &provider, "add_prescription")?;
+
+        if !rbac::has_permission(&env, &provider, &Permission::WriteRecord)
+            && !rbac::has_permission(&env, &provider, &Permission::SystemAdmin)
+        {
+            return Self::unauthorized(
+                &env,
+                &provider,
+                "add_prescription",
+                "permission:WriteRecord",
+            );
+        }
+        validation::validate_duration(duration_seconds)?;
+
+        // Report which field is at fault through the error context.
+        if let Err(e) =
+            prescription::validate_for_lens_type(&lens_type, &left_eye, &right_eye, &contact_data)
+        {
+            let field = String::from_str(&env, e.field);
+            log_error(&env, e.error, Some(provider.clone()), Some(field.clone()), None);
+            let context = create_error_context(&env, e.error, Some(provider), Some(field));
+            events::publish_error(&env, e.error as u32, context);
+            return Err(e.error);
+        }
+
+        let hash =
+            prescription::canonical_hash(&env, &lens_type, &left_eye, &right_eye, &contact_data)?;
+        let now = env.ledger().timestamp();
+
+        if let Some(existing) = prescription::find_recent_duplicate(&env, &patient, &hash, now) {
+            events::publish_prescription_duplicate(&env, &existing, provider);
+            return Ok(existing.id);
+        }
+
+        let counter_key = symbol_short!("RX_CTR");
+        let rx_id: u64 = env
+            .storage()
+            .instance()
+            .get(&counter_key)
+            .unwrap_or(0u64)
+            .saturating_add(1);
+        env.storage().instance().set(&counter_key, &rx_id);
+
+        let rx = Prescription {
+            id: rx_id,
+            patient: patient.clone(),
+            provider,
+            lens_type,
+            left_eye,
+            right_eye,
+            contact_data,
+            issued_at: now,
+            expires_at: now.saturating_add(duration_seconds),
+            verified: false,
+            metadata_hash,
+            canonical_hash: hash.clone(),
+            status: PrescriptionStatus::Active,
+        };
+        prescription::save_prescription(&env, &rx);
+        prescription::index_canonical_hash(&env, &patient, &hash, rx_id);
+        snapshot::record_change(
+            &env,
+            &patient,
+            StateChangeKind::PrescriptionAdded,
+            Some(rx_id),
+            None,
+        );
+
+        events::publish_prescription_added(&env, &rx);
+        Ok(rx_id)
+    }
+
+    /// Returns a prescription, marking it expired first if it has passed
+    /// `expires_at` since the last sweep.
+    pub fn get_prescription(env: Env, rx_id: u64) -> Result<Prescription, ContractError> {
+        let rx = prescription::get_prescription(&env, rx_id).ok_or(ContractError::RecordNotFound)?;
+        extend_ttl_u64_key(&env, &(symbol_short!("RX"), rx_id));
+        Ok(Self::refresh_prescription_status(&env, rx))
+    }
+
+    pub fn verify_prescription(env: Env, rx_id: u64, verifier: Address) -> bool {
+        prescription::verify_prescription(&env, rx_id, verifier)
+    }
+
+    pub fn get_prescription_history(env: Env, patient: Address) -> Vec<u64> {
+        prescription::get_patient_history(&env, alias::resolve(&env, &patient))
+    }
+
+    /// Look up the latest prescription for `patient` with the given
+    /// canonical hash.
+    pub fn find_prescription_by_canonical_hash(
+        env: Env,
+        patient: Address,
+        canonical_hash: BytesN<32>,
+    ) -> Result<Prescription, ContractError> {
+        let patient = alias::resolve(&env, &patient);
+        prescription::find_by_canonical_hash(&env, &patient, &canonical_hash)
+            .and_then(|rx_id| prescription::get_prescription(&env, rx_id))
+            .map(|rx| Self::refresh_prescription_status(&env, rx))
+            .ok_or(ContractError::RecordNotFound)
+    }
+
+    /// Set how long after issue an identical prescription is treated as a
+    /// duplicate. Requires SystemAdmin.
+    pub fn set_prescription_duplicate_window(
+        env: Env,
+        caller: Address,
+        window_seconds: u64,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_prescription_duplicate_window",
+                "permission:SystemAdmin",
+            );
+        }
+        prescription::set_duplicate_window(&env, window_seconds);
+        admin_receipt::issue(&env, &caller, symbol_short!("RX_DUPW"), None);
+        Ok(())
+    }
+
+    pub fn get_prescription_duplicate_window(env: Env) -> u64 {
+        prescription::get_duplicate_window(&env)
+    }
+
+    // ======================== Locum Cover ========================
+
+    /// Designate `covering_provider` to cover for `absent_provider` between
+    /// `starts_at` and `ends_at`. During that window the covering provider
+    /// can read records the absent provider has been granted access to.
+    pub fn designate_locum(
+        env: Env,
+        absent_provider: Address,
+        covering_provider: Address,
+        starts_at: u64,
+        ends_at: u64,
+    ) -> Result<u64, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        absent_provider.require_auth();
+
+        if !rbac::has_permission(&env, &absent_provider, &Permission::WriteRecord) {
+            return Self::unauthorized(
+                &env,
+                &absent_provider,
+                "designate_locum",
+                "permission:WriteRecord",
+            );
+        }
+        if !rbac::has_permission(&env, &covering_provider, &Permission::WriteRecord) {
+            return Err(ContractError::InvalidRole);
+        }
+        if absent_provider == covering_provider {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let now = env.ledger().timestamp();
+        if ends_at <= starts_at || ends_at <= now || ends_at - starts_at > locum::MAX_LOCUM_SECONDS
+        {
+            return Err(ContractError::InvalidTimestamp);
+        }
+
+        let arrangement = LocumArrangement {
+            id: locum::increment_locum_counter(&env),
+            absent_provider,
+            covering_provider,
+            starts_at,
+            ends_at,
+            created_at: now,
+            cancelled: false,
+        };
+        locum::create_arrangement(&env, &arrangement);
+        events::publish_locum_designated(&env, &arrangement);
+
+        Ok(arrangement.id)
+    }
+
+    /// End a locum arrangement early. Either provider or a SystemAdmin may
+    /// cancel; arrangements otherwise expire on their own at `ends_at`.
+    pub fn cancel_locum(
+        env: Env,
+        caller: Address,
+        arrangement_id: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        let mut arrangement =
+            locum::get_arrangement(&env, arrangement_id).ok_or(ContractError::LocumNotFound)?;
+        if caller != arrangement.absent_provider
+            && caller != arrangement.covering_provider
+            && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
+        {
+            return Self::unauthorized(&env, &caller, "cancel_locum", "locum_party_or_admin");
+        }
+        if arrangement.cancelled || env.ledger().timestamp() >= arrangement.ends_at {
+            return Err(ContractError::InvalidInput);
+        }
+
+        arrangement.cancelled = true;
+        locum::set_arrangement(&env, &arrangement);
+        events::publish_locum_cancelled(&env, &arrangement);
+        Ok(())
+    }
+
+    pub fn get_locum_arrangement(
+        env: Env,
+        arrangement_id: u64,
+    ) -> Result<LocumArrangement, ContractError> {
+        locum::get_arrangement(&env, arrangement_id).ok_or(ContractError::LocumNotFound)
+    }
+
+    /// Arrangements in which `provider` is either the absent or the
+    /// covering provider.
+    pub fn get_provider_locum_arrangements(env: Env, provider: Address) -> Vec<LocumArrangement> {
+        let mut out = Vec::new(&env);
+        let ids = locum::get_absent_arrangements(&env, &provider);
+        for id in ids
+            .iter()
+            .chain(locum::get_covering_arrangements(&env, &provider).iter())
+        {
+            if let Some(arrangement) = locum::get_arrangement(&env, id) {
+                out.push_back(arrangement);
+            }
+        }
+        out
+    }
+
+    /// Reads of the patient's records made under locum cover.
+    pub fn get_locum_disclosures(env: Env, patient: Address) -> Vec<LocumDisclosure> {
+        patient.require_auth();
+        locum::get_disclosures(&env, &patient)
+    }
+
+    /// Opt in or out of covering providers reading through the patient's
+    /// grants to an absent provider.
+    pub fn set_locum_opt_out(
+        env: Env,
+        patient: Address,
+        opted_out: bool,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        let mut settings = privacy::get_settings(&env, &patient);
+        settings.locum_opt_out = opted_out;
+        settings.updated_at = env.ledger().timestamp();
+        privacy::set_settings(&env, &settings);
+        Ok(())
+    }
+
+    pub fn get_privacy_settings(env: Env, patient: Address) -> PrivacySettings {
+        privacy::get_settings(&env, &alias::resolve(&env, &patient))
+    }
+
+    // ======================== Patient Merge ========================
+
+    /// Merge a duplicate patient registration into `primary`.
+    ///
+    /// Initiated by a SystemAdmin or one of the two patients, and
+    /// authorized by both patient addresses. Records, prescriptions, access
+    /// grants, consents, scoped and organization grants and emergency
+    /// history are re-indexed under `primary`, and `duplicate` is left with
+    /// a tombstone pointing at it.
+    /// Refused while either address is under a legal hold.
+    pub fn merge_patients(
+        env: Env,
+        caller: Address,
+        primary: Address,
+        duplicate: Address,
+    ) -> Result<PatientMerge, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+        primary.require_auth();
+        duplicate.require_auth();
+
+        if caller != primary
+            && caller != duplicate
+            && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "merge_patients",
+                "patient_or_permission:SystemAdmin",
+            );
+        }
+        if primary == duplicate
+            || alias::is_alias(&env, &primary)
+            || alias::is_alias(&env, &duplicate)
+        {
+            return Err(ContractError::InvalidInput);
+        }
+        if legal_hold::is_patient_held(&env, &primary)
+            || legal_hold::is_patient_held(&env, &duplicate)
+        {
+            return Err(ContractError::LegalHoldActive);
+        }
+
+        let merged = PatientMerge {
+            primary: primary.clone(),
+            duplicate: duplicate.clone(),
+            merged_by: caller,
+            merged_at: env.ledger().timestamp(),
+            records_moved: merge::move_records(&env, &duplicate, &primary),
+            prescriptions_moved: prescription::move_history(&env, &duplicate, &primary),
+            grants_moved: merge::move_grants(&env, &duplicate, &primary),
+            emergency_moved: emergency::move_history(&env, &duplicate, &primary),
+        };
+        merge::set_tombstone(&env, &merged);
+        alias::set_alias(&env, &duplicate, &primary);
+
+        admin_receipt::issue(
+            &env,
+            &merged.merged_by,
+            symbol_short!("PAT_MRG"),
+            Some(merged.duplicate.clone()),
+        );
+        events::publish_patients_merged(&env, &merged);
+        Ok(merged)
+    }
+
+    /// Tombstone for an address that was merged into another patient.
+    pub fn get_patient_merge(env: Env, duplicate: Address) -> Option<PatientMerge> {
+        merge::get_tombstone(&env, &duplicate)
+    }
+
+    // ======================== Address Aliases ========================
+
+    /// Record that `old` has been migrated to `current`, so lookups made with
+    /// the old address resolve to the new one. Requires SystemAdmin and is
+    /// refused while `old` is under a legal hold.
+    pub fn register_address_alias(
+        env: Env,
+        admin: Address,
+        old: Address,
+        current: Address,
+    ) -> Result<AdminActionReceipt, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        admin.require_auth();
+
+        if !rbac::has_permission(&env, &admin, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &admin,
+                "register_address_alias",
+                "permission:SystemAdmin",
+            );
+        }
+        if old == current || alias::is_alias(&env, &old) || alias::is_alias(&env, &current) {
+            return Err(ContractError::InvalidInput);
+        }
+        if legal_hold::is_patient_held(&env, &old) {
+            return Err(ContractError::LegalHoldActive);
+        }
+
+        alias::set_alias(&env, &old, &current);
+        let receipt =
+            admin_receipt::issue(&env, &admin, symbol_short!("ALIAS"), Some(old.clone()));
+        events::publish_address_aliased(&env, old, current, admin);
+        Ok(receipt)
+    }
+
+    /// The address `addr` currently resolves to.
+    pub fn resolve_address(env: Env, addr: Address) -> Address {
+        alias::resolve(&env, &addr)
+    }
+
+    /// Retired addresses that resolve to `current`, for audit.
+    pub fn get_aliases(env: Env, current: Address) -> Vec<Address> {
+        alias::get_aliases(&env, &current)
+    }
+
+    // ======================== Registration Proofs ========================
+
+    /// Set the zk_verifier contract whose consumption receipts back
+    /// self-registration and prescription proofs.
+    pub fn set_registration_verifier(
+        env: Env,
+        caller: Address,
+        verifier: Address,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_registration_verifier",
+                "permission:SystemAdmin",
+            );
+        }
+
+        if !interface::is_compatible(&env, &verifier, &registration_gate::ZK_VERIFIER_INTERFACE) {
+            return Err(ContractError::IncompatibleInterface);
+        }
+
+        registration_gate::set_zk_verifier(&env, &verifier);
+        admin_receipt::issue(&env, &caller, symbol_short!("REG_VFY"), Some(verifier));
+        Ok(())
+    }
+
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
+    pub fn get_registration_verifier(env: Env) -> Option<Address> {
+        registration_gate::get_zk_verifier(&env)
+    }
+
+    /// Require (or, with `resource_id = None`, stop requiring) a zk proof of
+    /// `resource_id` for users registering themselves under `role`. Roles
+    /// without a requirement can only be registered by a caller holding
+    /// ManageUsers, which is also how custodians register minors.
+    pub fn set_registration_proof_requirement(
+        env: Env,
+        caller: Address,
+        role: Role,
+        resource_id: Option<BytesN<32>>,
+        max_proof_age: u64,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_registration_proof_requirement",
+                "permission:SystemAdmin",
+            );
+        }
+
+        match resource_id.clone() {
+            Some(resource_id) => {
+                if registration_gate::get_zk_verifier(&env).is_none() || max_proof_age == 0 {
+                    return Err(ContractError::InvalidInput);
+                }
+                registration_gate::set_requirement(
+                    &env,
+                    &RegistrationProofRequirement {
+                        role: role.clone(),
+                        resource_id,
+                        max_proof_age,
+                        updated_by: caller.clone(),
+                        updated_at: env.ledger().timestamp(),
+                    },
+                );
+            }
+            None => registration_gate::remove_requirement(&env, &role),
+        }
+
+        admin_receipt::issue(&env, &caller, symbol_short!("REG_PRF"), None);
+        events::publish_registration_proof_set(&env, role, resource_id, max_proof_age, caller);
+        Ok(())
+    }
+
+    pub fn get_registration_proof_requirement(
+        env: Env,
+        role: Role,
+    ) -> Option<RegistrationProofRequirement> {
+        registration_gate::get_requirement(&env, &role)
+    }
+
+    // ======================== Audit Stream ========================
+
+    /// Returns privileged-action entries with sequence numbers in
+    /// `[from_seq, to_seq]`, at most `audit_stream::MAX_AUDIT_RANGE` per call.
+    pub fn get_audit_range(env: Env, from_seq: u64, to_seq: u64) -> Vec<AuditStreamEntry> {
+        audit_stream::get_audit_range(&env, from_seq, to_seq)
+    }
+
+    /// Sequence number of the most recent privileged-action entry.
+    pub fn get_audit_sequence(env: Env) -> u64 {
+        audit_stream::last_sequence(&env)
+    }
+
+    // ======================== Sponsored Calls ========================
+
+    /// Registers the Ed25519 key `patient` signs sponsored grants with,
+    /// replacing any earlier one. Only grants signed by this key are
+    /// accepted by `grant_access_meta`.
+    pub fn register_meta_signer(
+        env: Env,
+        patient: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+
+        meta_signer::set_key(&env, &patient, &public_key);
+        events::publish_meta_signer_registered(&env, patient, &public_key);
+        Ok(())
+    }
+
+    /// The key `patient` signs sponsored grants with, if registered.
+    pub fn get_meta_signer(env: Env, patient: Address) -> Option<BytesN<32>> {
+        meta_signer::get_key(&env, &patient)
+    }
+
+    /// Applies an access grant the patient signed off-chain. The relayer
+    /// pays for the call; the patient's nonce is consumed so the same signed
+    /// grant cannot be submitted twice. The signing key must be the one the
+    /// patient registered, and the grant goes through the same duration,
+    /// rate-limit and bookkeeping as `grant_access`.
+    pub fn grant_access_meta(
+        env: Env,
+        relayer: Address,
+        signed: SignedGrant,
+    ) -> Result<(), ContractError> {
+        feature_flags::require_enabled(&env, Feature::SponsoredCalls)?;
+        let _guard = teye_common::ReentrancyGuard::new(&env);
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("GRT_ACC")),
+        )?;
+        relayer.require_auth();
+
+        let now = env.ledger().timestamp();
+        if signed.expires_at <= now {
+            return Err(ContractError::MetaTxExpired);
+        }
+        let level = match signed.level {
+            1 => AccessLevel::Read,
+            2 => AccessLevel::Write,
+            3 => AccessLevel::Admin,
+            _ => return Err(ContractError::InvalidInput),
+        };
+        let duration_seconds = signed.expires_at - now;
+        validation::validate_duration(duration_seconds)?;
+
+        if meta_signer::get_key(&env, &signed.patient).as_ref() != Some(&signed.patient_pubkey) {
+            return Self::unauthorized(
+                &env,
+                &relayer,
+                "grant_access_meta",
+                "patient_registered_meta_signer",
+            );
+        }
+
+        let message = meta_tx::build_grant_message(
+            &env,
+            &signed.patient_pubkey,
+            &signed.grantee_id,
+            signed.level,
+            signed.expires_at,
+            signed.nonce,
+            &signed.patient,
+            &signed.grantee,
+        );
+        meta_tx::verify_meta_signature(&env, &signed.patient_pubkey, &message, &signed.signature);
+
+        let payload_hash: BytesN<32> = env.crypto().sha256(&message).into();
+        nonce::consume_nonce(&env, &signed.patient, signed.nonce, &payload_hash)
+            .map_err(|_| ContractError::NonceAlreadyUsed)?;
+        Self::enforce_rate_limit(&env, &signed.patient)?;
+
+        Self::store_access_grant(
+            &env,
+            signed.patient,
+            signed.grantee,
+            level,
+            duration_seconds,
+        );
+        Ok(())
+    }
+
+    /// Returns the payload hash `nonce` was consumed with for `user`, if any.
+    pub fn get_consumed_nonce(env: Env, user: Address, nonce: u64) -> Option<BytesN<32>> {
+        nonce::consumed_payload(&env, &user, nonce)
+    }
+
+    // ======================== Bulk Grants & Templates ========================
+
+    /// Writes every grant in `grants` for `patient`, expiring after
+    /// `duration_seconds`, and publishes one combined event. The whole list is
+    /// validated before anything is written.
+    fn apply_bulk_grants(
+        env: &Env,
+        patient: &Address,
+        grants: &Vec<(Address, AccessLevel)>,
+        duration_seconds: u64,
+        template: Option<Symbol>,
+    ) -> Result<(), ContractError> {
+        validation::validate_duration(duration_seconds)?;
+        grant_template::validate_bulk(patient, grants)?;
+
+        let now = env.ledger().timestamp();
+        let expires_at = now.saturating_add(duration_seconds);
+
+        let list_key = (symbol_short!("ACC_LST"), patient.clone());
+        let mut tracked: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&list_key)
+            .unwrap_or(Vec::new(env));
+
+        for (grantee, level) in grants.iter() {
+            let grant = AccessGrant {
+                patient: patient.clone(),
+                grantee: grantee.clone(),
+                level,
+                granted_at: now,
+                expires_at,
+                status: GrantStatus::Active,
+                revoked_at: None,
+                agreement_id: None,
+            };
+            let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+            env.storage().persistent().set(&key, &grant);
+            extend_ttl_access_key(env, &key);
+
+            if !tracked.contains(&grantee) {
+                tracked.push_back(grantee);
+            }
+        }
+        env.storage().persistent().set(&list_key, &tracked);
+
+        events::publish_bulk_access_granted(env, patient.clone(), grants, template, expires_at);
+        Ok(())
+    }
+
+    /// Grants each `(grantee, level)` pair in one transaction. Either every
+    /// grant is applied or none is.
+    pub fn grant_access_bulk(
+        env: Env,
+        patient: Address,
+        grants: Vec<(Address, AccessLevel)>,
+        duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("GRT_ACC")),
+        )?;
+        patient.require_auth();
+        Self::enforce_rate_limit(&env, &patient)?;
+
+        Self::apply_bulk_grants(&env, &patient, &grants, duration_seconds, None)
+    }
+
+    /// Define or replace a named grant template. Only SystemAdmin may manage
+    /// templates.
+    pub fn set_grant_template(
+        env: Env,
+        caller: Address,
+        name: Symbol,
+        slots: Vec<TemplateSlot>,
+        duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_grant_template",
+                "permission:SystemAdmin",
+            );
+        }
+
+        if slots.is_empty() || slots.len() > grant_template::MAX_BULK_GRANTS {
+            return Err(ContractError::InvalidInput);
+        }
+        for slot in slots.iter() {
+            if slot.level == AccessLevel::None {
+                return Err(ContractError::InvalidInput);
+            }
+        }
+        validation::validate_duration(duration_seconds)?;
+
+        grant_template::set_template(
+            &env,
+            &GrantTemplate {
+                name,
+                slots,
+                duration_seconds,
+                updated_by: caller.clone(),
+                updated_at: env.ledger().timestamp(),
+            },
+        );
+        admin_receipt::issue(&env, &caller, symbol_short!("TPL_SET"), None);
+        Ok(())
+    }
+
+    pub fn remove_grant_template(
+        env: Env,
+        caller: Address,
+        name: Symbol,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "remove_grant_template",
+                "permission:SystemAdmin",
+            );
+        }
+
+        grant_template::remove_template(&env, &name);
+        admin_receipt::issue(&env, &caller, symbol_short!("TPL_DEL"), None);
+        Ok(())
+    }
+
+    pub fn get_grant_template(env: Env, name: Symbol) -> Option<GrantTemplate> {
+        grant_template::get_template(&env, &name)
+    }
+
+    /// Applies template `name`, granting each slot's level to the grantee at
+    /// the same position in `grantees`.
+    pub fn apply_grant_template(
+        env: Env,
+        patient: Address,
+        name: Symbol,
+        grantees: Vec<Address>,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("GRT_ACC")),
+        )?;
+        patient.require_auth();
+        Self::enforce_rate_limit(&env, &patient)?;
+
+        let template =
+            grant_template::get_template(&env, &name).ok_or(ContractError::InvalidInput)?;
+        let grants = grant_template::fill(&env, &template, &grantees)?;
+
+        Self::apply_bulk_grants(
+            &env,
+            &patient,
+            &grants,
+            template.duration_seconds,
+            Some(name),
+        )
+    }
+
+    // ======================== Access Windows ========================
+
+    /// Set the UTC offset, in minutes, that daily access windows are
+    /// evaluated against. SystemAdmin only.
+    pub fn set_access_timezone(
+        env: Env,
+        caller: Address,
+        offset_minutes: i32,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_access_timezone",
+                "permission:SystemAdmin",
+            );
+        }
+
+        access_window::validate_offset(offset_minutes)?;
+        access_window::set_timezone_offset(&env, offset_minutes);
+        admin_receipt::issue(&env, &caller, symbol_short!("ACC_TZ"), None);
+        Ok(())
+    }
+
+    pub fn get_access_timezone(env: Env) -> i32 {
+        access_window::get_timezone_offset(&env)
+    }
+
+    /// Restrict `grantee` to reading the patient's records within a daily
+    /// window, or lift the restriction with `None`.
+    pub fn set_access_window(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        window: Option<AccessWindow>,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+
+        match window.clone() {
+            Some(window) => {
+                access_window::validate_window(&window)?;
+                access_window::set_window(&env, &patient, &grantee, &window);
+            }
+            None => access_window::remove_window(&env, &patient, &grantee),
+        }
+
+        events::publish_access_window_set(&env, patient, grantee, window);
+        Ok(())
+    }
+
+    pub fn get_access_window(env: Env, patient: Address, grantee: Address) -> Option<AccessWindow> {
+        let patient = alias::resolve(&env, &patient);
+        let grantee = alias::resolve(&env, &grantee);
+        access_window::get_window(&env, &patient, &grantee)
+    }
+
+    // ======================== Prescription Sharing ========================
+
+    /// Loads `rx_id` and checks it can still be shared.
+    fn shareable_prescription(env: &Env, rx_id: u64) -> Result<Prescription, ContractError> {
+        let rx = prescription::get_prescription(env, rx_id).ok_or(ContractError::RecordNotFound)?;
+        let rx = Self::refresh_prescription_status(env, rx);
+        if rx.status == PrescriptionStatus::Expired {
+            return Err(ContractError::PrescriptionExpired);
+        }
+        Ok(rx)
+    }
+
+    fn record_rx_receipt(
+        env: &Env,
+        rx: &Prescription,
+        retailer_hash: BytesN<32>,
+        scope: PrescriptionShareScope,
+        method: ShareMethod,
+    ) -> PrescriptionConsentReceipt {
+        let receipt = PrescriptionConsentReceipt {
+            patient: rx.patient.clone(),
+            retailer_hash,
+            rx_id: rx.id,
+            scope,
+            method,
+            timestamp: env.ledger().timestamp(),
+        };
+        rx_share::add_receipt(env, &receipt);
+        events::publish_prescription_shared(env, &receipt);
+        receipt
+    }
+
+    /// Issue a one-off share code for a prescription. The patient hands the
+    /// code to a retailer off-chain; only its SHA-256 hash is stored.
+    pub fn issue_prescription_share_code(
+        env: Env,
+        patient: Address,
+        rx_id: u64,
+        code_hash: BytesN<32>,
+        scope: PrescriptionShareScope,
+        expires_at: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+
+        let rx = Self::shareable_prescription(&env, rx_id)?;
+        if rx.patient != alias::resolve(&env, &patient) {
+            return Self::unauthorized(
+                &env,
+                &patient,
+                "issue_prescription_share_code",
+                "prescription_owner",
+            );
+        }
+        if expires_at <= env.ledger().timestamp() {
+            return Err(ContractError::InvalidTimestamp);
+        }
+
+        rx_share::set_share_code(
+            &env,
+            &PrescriptionShareCode {
+                rx_id,
+                code_hash,
+                scope,
+                expires_at,
+            },
+        );
+        Ok(())
+    }
+
+    /// Verify a prescription with a share code presented by the patient.
+    /// `retailer_hash` identifies the retailer without putting its name on
+    /// chain. The code is single-use.
+    pub fn verify_prescription_with_code(
+        env: Env,
+        retailer: Address,
+        retailer_hash: BytesN<32>,
+        rx_id: u64,
+        code: Bytes,
+    ) -> Result<PrescriptionConsentReceipt, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        retailer.require_auth();
+
+        let rx = Self::shareable_prescription(&env, rx_id)?;
+        let scope = rx_share::redeem_share_code(&env, rx_id, &code)?;
+
+        Ok(Self::record_rx_receipt(
+            &env,
+            &rx,
+            retailer_hash,
+            scope,
+            ShareMethod::ShareCode,
+        ))
+    }
+
+    /// Verify a prescription against a zk proof the patient submitted to the
+    /// configured verifier for the prescription's canonical hash.
+    pub fn verify_prescription_with_proof(
+        env: Env,
+        retailer: Address,
+        retailer_hash: BytesN<32>,
+        rx_id: u64,
+        scope: PrescriptionShareScope,
+    ) -> Result<PrescriptionConsentReceipt, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        retailer.require_auth();
+
+        let rx = Self::shareable_prescription(&env, rx_id)?;
+        let verifier = registration_gate::get_zk_verifier(&env)
+            .ok_or(ContractError::InvalidShareCredential)?;
+        let client = registration_gate::ZkVerifierClient::new(&env, &verifier);
+        if !client.consume_receipt(
+            &env.current_contract_address(),
+            &rx.patient,
+            &rx.canonical_hash,
+            &rx_share::PROOF_MAX_AGE,
+        ) {
+            return Err(ContractError::InvalidShareCredential);
+        }
+
+        Ok(Self::record_rx_receipt(
+            &env,
+            &rx,
+            retailer_hash,
+            scope,
+            ShareMethod::ZkProof,
+        ))
+    }
+
+    /// Every third-party disclosure of the patient's prescriptions.
+    pub fn get_prescription_receipts(
+        env: Env,
+        patient: Address,
+    ) -> Vec<PrescriptionConsentReceipt> {
+        rx_share::get_receipts(&env, &alias::resolve(&env, &patient))
+    }
+
+    // ======================== Prescription Printing ========================
+
+    /// Authorize one paper copy of `rx_id`. Returns an eight-digit code,
+    /// valid for `PRINT_CODE_TTL_SECONDS`, that a printing kiosk or portal
+    /// redeems before printing. Only the prescribing provider may issue.
+    pub fn issue_print_authorization(
+        env: Env,
+        provider: Address,
+        rx_id: u64,
+    ) -> Result<u32, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        provider.require_auth();
+
+        let rx = Self::shareable_prescription(&env, rx_id)?;
+        if rx.provider != provider {
+            return Self::unauthorized(
+                &env,
+                &provider,
+                "issue_print_authorization",
+                "prescribing_provider",
+            );
+        }
+
+        let now = env.ledger().timestamp();
+        let id = print_auth::increment_counter(&env);
+        let code = print_auth::assign_code(&env, id);
+        let auth = PrintAuthorization {
+            id,
+            rx_id,
+            patient: rx.patient,
+            provider,
+            code,
+            issued_at: now,
+            expires_at: now.saturating_add(print_auth::PRINT_CODE_TTL_SECONDS),
+            redeemed_by: None,
+            redeemed_at: None,
+        };
+        print_auth::save(&env, &auth);
+        print_auth::add_to_prescription(&env, rx_id, id);
+        events::publish_print_authorized(&env, &auth);
+
+        Ok(code)
+    }
+
+    /// Consume a print code. The kiosk prints only after this succeeds; the
+    /// returned authorization identifies the prescription to print.
+    pub fn redeem_print_authorization(
+        env: Env,
+        kiosk: Address,
+        code: u32,
+    ) -> Result<PrintAuthorization, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        kiosk.require_auth();
+
+        let mut auth =
+            print_auth::find_by_code(&env, code).ok_or(ContractError::InvalidPrintAuthorization)?;
+        Self::shareable_prescription(&env, auth.rx_id)?;
+
+        auth.redeemed_by = Some(kiosk);
+        auth.redeemed_at = Some(env.ledger().timestamp());
+        print_auth::save(&env, &auth);
+        print_auth::release_code(&env, code);
+        events::publish_print_redeemed(&env, &auth);
+
+        Ok(auth)
+    }
+
+    pub fn get_print_authorization(env: Env, id: u64) -> Result<PrintAuthorization, ContractError> {
+        print_auth::get(&env, id).ok_or(ContractError::InvalidPrintAuthorization)
+    }
+
+    /// Every print authorization issued for `rx_id`, so paper copies can be
+    /// traced back to the code printed on them.
+    pub fn get_print_authorizations(env: Env, rx_id: u64) -> Vec<PrintAuthorization> {
+        let mut out = Vec::new(&env);
+        for id in print_auth::get_for_prescription(&env, rx_id).iter() {
+            if let Some(auth) = print_auth::get(&env, id) {
+                out.push_back(auth);
+            }
+        }
+        out
+    }
+
+    // ======================== Prescription Coverage ========================
+
+    /// Record what a payer adjudicated `rx_id` to cover. The claims contract
+    /// must confirm that `payer` adjudicated `claim_id` for this prescription.
+    /// Replaces any earlier hint.
+    pub fn set_rx_coverage(
+        env: Env,
+        payer: Address,
+        rx_id: u64,
+        claim_id: u64,
+        plan_ref: String,
+        category_code: Symbol,
+    ) -> Result<RxCoverage, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        payer.require_auth();
+
+        if !rbac::has_role(&env, &payer, &Role::Payer) {
+            return Self::unauthorized(&env, &payer, "set_rx_coverage", "role:Payer");
+        }
+        let rx = prescription::get_prescription(&env, rx_id).ok_or(ContractError::RecordNotFound)?;
+        if plan_ref.is_empty() || plan_ref.len() > rx_coverage::MAX_PLAN_REF_LEN {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let claims =
+            eligibility::get_claims_contract(&env).ok_or(ContractError::ClaimNotAdjudicated)?;
+        let client = eligibility::ClaimsClient::new(&env, &claims);
+        if !client.is_rx_claim_adjudicated(&claim_id, &payer, &rx.patient, &rx_id) {
+            return Err(ContractError::ClaimNotAdjudicated);
+        }
+
+        let coverage = RxCoverage {
+            rx_id,
+            payer,
+            plan_ref,
+            category_code,
+            claim_id,
+            recorded_at: env.ledger().timestamp(),
+        };
+        rx_coverage::set(&env, &coverage);
+        events::publish_rx_coverage_set(&env, &coverage);
+        Ok(coverage)
+    }
+
+    /// Withdraw a coverage hint, e.g. after the claim is reversed. Only the
+    /// payer that recorded it may.
+    pub fn clear_rx_coverage(env: Env, payer: Address, rx_id: u64) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        payer.require_auth();
+
+        let coverage = match rx_coverage::get(&env, rx_id) {
+            Some(coverage) => coverage,
+            None => return Ok(()),
+        };
+        if coverage.payer != payer {
+            return Self::unauthorized(&env, &payer, "clear_rx_coverage", "coverage_payer");
+        }
+        rx_coverage::remove(&env, rx_id);
+        events::publish_rx_coverage_cleared(&env, &coverage);
+        Ok(())
+    }
+
+    /// Coverage hint for `rx_id`, for point-of-sale lookups.
+    pub fn get_rx_coverage(env: Env, rx_id: u64) -> Option<RxCoverage> {
+        rx_coverage::get(&env, rx_id)
+    }
+
+    // ======================== Acuity Progression ========================
+
+    /// Returns how visual acuity changed between two examinations of the
+    /// same patient, normalized to logMAR so Snellen and decimal entries
+    /// compare consistently.
+    pub fn get_acuity_change(
+        env: Env,
+        caller: Address,
+        baseline_record_id: u64,
+        followup_record_id: u64,
+    ) -> Result<AcuityChange, ContractError> {
+        let baseline_record = Self::get_record(env.clone(), caller.clone(), baseline_record_id)?;
+        let followup_record = Self::get_record(env.clone(), caller.clone(), followup_record_id)?;
+        if baseline_record.patient != followup_record.patient {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let baseline = Self::get_eye_examination(env.clone(), caller.clone(), baseline_record_id)?;
+        let followup = Self::get_eye_examination(env, caller, followup_record_id)?;
+        examination::acuity_change(&baseline, &followup).ok_or(ContractError::InvalidInput)
+    }
+
+    // ======================== Prescription Expiry ========================
+
+    /// Marks `rx` expired if it has reached `expires_at`, publishing the
+    /// expiry event. Returns the prescription as it is now stored.
+    fn refresh_prescription_status(env: &Env, mut rx: Prescription) -> Prescription {
+        if prescription::is_due_for_expiry(&rx, env.ledger().timestamp()) {
+            prescription::mark_expired(env, &mut rx);
+            snapshot::record_change(
+                env,
+                &rx.patient,
+                StateChangeKind::PrescriptionExpired,
+                Some(rx.id),
+                None,
+            );
+            events::publish_prescription_expired(env, &rx);
+        }
+        rx
+    }
+
+    /// Permissionless sweeper that marks up to `limit` prescriptions as
+    /// expired once they pass `expires_at`. Each call resumes where the
+    /// previous one stopped, wrapping around after the newest prescription.
+    /// Returns the number of prescriptions expired by this call.
+    pub fn expire_prescriptions(env: Env, limit: u32) -> Result<u32, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+
+        if limit == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let total: u64 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("RX_CTR"))
+            .unwrap_or(0);
+        if total == 0 {
+            return Ok(0);
+        }
+
+        let now = env.ledger().timestamp();
+        let mut cursor = prescription::get_sweep_cursor(&env);
+        let mut expired: u32 = 0;
+
+        for _ in 0..limit {
+            if cursor > total {
+                cursor = 1;
+            }
+
+            if let Some(mut rx) = prescription::get_prescription(&env, cursor) {
+                if prescription::is_due_for_expiry(&rx, now) {
+                    prescription::mark_expired(&env, &mut rx);
+                    snapshot::record_change(
+                        &env,
+                        &rx.patient,
+                        StateChangeKind::PrescriptionExpired,
+                        Some(rx.id),
+                        None,
+                    );
+                    events::publish_prescription_expired(&env, &rx);
+                    expired = expired.saturating_add(1);
+                }
+            }
+
+            cursor = cursor.saturating_add(1);
+        }
+
+        prescription::set_sweep_cursor(&env, cursor);
+
+        Ok(expired)
+    }
+
+    /// Ids of the patient's prescriptions that are still active. Entries
+    /// that expired since the last sweep are expired on the way out.
+    pub fn get_active_prescriptions(env: Env, patient: Address) -> Vec<u64> {
+        let patient = alias::resolve(&env, &patient);
+        let mut active = Vec::new(&env);
+        for rx_id in prescription::get_active(&env, &patient).iter() {
+            if let Some(rx) = prescription::get_prescription(&env, rx_id) {
+                if Self::refresh_prescription_status(&env, rx).status == PrescriptionStatus::Active
+                {
+                    active.push_back(rx_id);
+                }
+            }
+        }
+        active
+    }
+
+    // ======================== Anomaly Flagging ========================
+
+    /// Configure the threshold for one kind of suspicious activity. The actor
+    /// is flagged once their count within `window_seconds` exceeds
+    /// `threshold`.
+    pub fn set_anomaly_rule(
+        env: Env,
+        caller: Address,
+        kind: AnomalyKind,
+        threshold: u32,
+        window_seconds: u64,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(&env, &caller, "set_anomaly_rule", "permission:SystemAdmin");
+        }
+        if !anomaly::validate_rule(threshold, window_seconds) {
+            return Err(ContractError::InvalidInput);
+        }
+
+        anomaly::set_rule(
+            &env,
+            &AnomalyRule {
+                kind,
+                threshold,
+                window_seconds,
+                updated_by: caller.clone(),
+                updated_at: env.ledger().timestamp(),
+            },
+        );
+        admin_receipt::issue(&env, &caller, symbol_short!("ANOM_SET"), None);
+        Ok(())
+    }
+
+    /// Stop counting a kind of activity. Existing flags are kept.
+    pub fn remove_anomaly_rule(
+        env: Env,
+        caller: Address,
+        kind: AnomalyKind,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "remove_anomaly_rule",
+                "permission:SystemAdmin",
+            );
+        }
+
+        anomaly::remove_rule(&env, &kind);
+        admin_receipt::issue(&env, &caller, symbol_short!("ANOM_DEL"), None);
+        Ok(())
+    }
+
+    pub fn get_anomaly_rule(env: Env, kind: AnomalyKind) -> Option<AnomalyRule> {
+        anomaly::get_rule(&env, &kind)
+    }
+
+    /// Activity counted for `actor` in the current window of `kind`.
+    pub fn get_activity_window(
+        env: Env,
+        kind: AnomalyKind,
+        actor: Address,
+    ) -> Option<ActivityWindow> {
+        anomaly::get_window(&env, &kind, &actor)
+    }
+
+    pub fn get_actor_flag(env: Env, actor: Address) -> Option<ActorFlag> {
+        anomaly::get_flag(&env, &actor)
+    }
+
+    /// Mark an actor's security flag as reviewed. Their activity counters
+    /// restart so a later burst raises a fresh flag.
+    pub fn review_actor_flag(
+        env: Env,
+        caller: Address,
+        actor: Address,
+    ) -> Result<ActorFlag, ContractError> {
+        caller.require_auth();
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "review_actor_flag",
+                "permission:SystemAdmin",
+            );
+        }
+
+        let flag =
+            anomaly::review_flag(&env, &actor, &caller).ok_or(ContractError::ActorFlagNotFound)?;
+        admin_receipt::issue(&env, &caller, symbol_short!("ANOM_REV"), Some(actor));
+        Ok(flag)
+    }
+
+    // ======================== Admin Action Receipts ========================
+
+    /// Receipts for admin actions that targeted `user`, oldest first.
+    pub fn get_admin_receipts(env: Env, user: Address) -> Vec<AdminActionReceipt> {
+        admin_receipt::get_user_receipts(&env, &user)
+    }
+
+    /// The receipt stored for audit stream entry `seq`, if it was an admin
+    /// action.
+    pub fn get_admin_receipt(env: Env, seq: u64) -> Option<AdminActionReceipt> {
+        admin_receipt::get_receipt(&env, seq)
+    }
+
+    /// Check that a receipt presented by a user was issued by this contract
+    /// and has not been altered.
+    pub fn verify_admin_receipt(env: Env, receipt: AdminActionReceipt) -> bool {
+        admin_receipt::verify(&env, &receipt)
+    }
+
+    // ======================== Custom Record Types ========================
+
+    /// Register a specialty record type under `code`. Records, retention
+    /// policies and emergency scopes then accept `RecordType::Custom(code)`.
+    /// Codes are permanent: a retired code is never reassigned.
+    pub fn register_custom_record_type(
+        env: Env,
+        caller: Address,
+        code: u32,
+        label_hash: BytesN<32>,
+    ) -> Result<CustomRecordType, ContractError> {
+        caller.require_auth();
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "register_custom_record_type",
+                "permission:SystemAdmin",
+            );
+        }
+
+        if record_types::get(&env, code).is_some() {
+            return Err(ContractError::CustomRecordTypeExists);
+        }
+        if !record_types::add_code(&env, code) {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let custom = CustomRecordType {
+            code,
+            label_hash,
+            active: true,
+            registered_by: caller.clone(),
+            registered_at: env.ledger().timestamp(),
+        };
+        record_types::set(&env, &custom);
+
+        admin_receipt::issue(&env, &caller, symbol_short!("TYPE_ADD"), None);
+        events::publish_custom_record_type(&env, &custom, caller);
+        Ok(custom)
+    }
+
+    /// Stop accepting new records of a custom type. Existing records keep
+    /// their type and remain queryable.
+    pub fn retire_custom_record_type(
+        env: Env,
+        caller: Address,
+        code: u32,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "retire_custom_record_type",
+                "permission:SystemAdmin",
+            );
+        }
+
+        let mut custom =
+            record_types::get(&env, code).ok_or(ContractError::CustomRecordTypeNotFound)?;
+        custom.active = false;
+        record_types::set(&env, &custom);
+
+        admin_receipt::issue(&env, &caller, symbol_short!("TYPE_RET"), None);
+        events::publish_custom_record_type(&env, &custom, caller);
+        Ok(())
+    }
+
+    pub fn get_custom_record_type(env: Env, code: u32) -> Option<CustomRecordType> {
+        record_types::get(&env, code)
+    }
+
+    /// Every registered custom type, including retired ones.
+    pub fn get_custom_record_types(env: Env) -> Vec<CustomRecordType> {
+        record_types::get_all(&env)
+    }
+
+    // ======================== Cohort Grants ========================
+
+    /// Let `supervisor` read every record authored by each of `residents`
+    /// for `duration_seconds`. Access follows the record's provider, so the
+    /// supervisor sees the residents' caseload without any patient-wide
+    /// grant. Returns one grant ID per resident so each can be revoked
+    /// independently.
+    pub fn grant_cohort_access(
+        env: Env,
+        caller: Address,
+        supervisor: Address,
+        residents: Vec<Address>,
+        duration_seconds: u64,
+    ) -> Result<Vec<u64>, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "grant_cohort_access",
+                "permission:SystemAdmin",
+            );
+        }
+        if residents.is_empty() || residents.len() > cohort::MAX_COHORT_RESIDENTS {
+            return Err(ContractError::InvalidInput);
+        }
+        validation::validate_duration(duration_seconds)?;
+
+        for resident in residents.iter() {
+            if resident == supervisor {
+                return Err(ContractError::InvalidInput);
+            }
+            if !rbac::has_permission(&env, &resident, &Permission::WriteRecord) {
+                return Err(ContractError::InvalidRole);
+            }
+        }
+
+        let now = env.ledger().timestamp();
+        let mut ids = Vec::new(&env);
+        for resident in residents.iter() {
+            let grant = CohortGrant {
+                id: cohort::increment_counter(&env),
+                supervisor: supervisor.clone(),
+                resident,
+                granted_by: caller.clone(),
+                granted_at: now,
+                expires_at: now.saturating_add(duration_seconds),
+                revoked_at: None,
+            };
+            cohort::create_grant(&env, &grant);
+            events::publish_cohort_granted(&env, &grant);
+            ids.push_back(grant.id);
+        }
+
+        admin_receipt::issue(&env, &caller, symbol_short!("COH_GRT"), Some(supervisor));
+        Ok(ids)
+    }
+
+    /// Withdraw `supervisor`'s access to one resident's records. Grants for
+    /// the supervisor's other residents are unaffected.
+    pub fn revoke_cohort_resident(
+        env: Env,
+        caller: Address,
+        supervisor: Address,
+        resident: Address,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "revoke_cohort_resident",
+                "permission:SystemAdmin",
+            );
+        }
+
+        let now = env.ledger().timestamp();
+        let mut grant = cohort::active_grant(&env, &supervisor, &resident, now)
+            .ok_or(ContractError::CohortGrantNotFound)?;
+        grant.revoked_at = Some(now);
+        cohort::set_grant(&env, &grant);
+
+        admin_receipt::issue(&env, &caller, symbol_short!("COH_REV"), Some(supervisor));
+        events::publish_cohort_revoked(&env, &grant, caller);
+        Ok(())
+    }
+
+    pub fn get_cohort_grant(env: Env, grant_id: u64) -> Result<CohortGrant, ContractError> {
+        cohort::get_grant(&env, grant_id).ok_or(ContractError::CohortGrantNotFound)
+    }
+
+    /// Every cohort grant issued to `supervisor`, including revoked and
+    /// expired ones.
+    pub fn get_supervisor_cohort(env: Env, supervisor: Address) -> Vec<CohortGrant> {
+        let mut out = Vec::new(&env);
+        for id in cohort::get_supervisor_grant_ids(&env, &supervisor).iter() {
+            if let Some(grant) = cohort::get_grant(&env, id) {
+                out.push_back(grant);
+            }
+        }
+        out
+    }
+
+    /// Reads of the patient's records made by a supervisor under a cohort
+    /// grant.
+    pub fn get_cohort_disclosures(env: Env, patient: Address) -> Vec<CohortDisclosure> {
+        patient.require_auth();
+        cohort::get_disclosures(&env, &patient)
+    }
+
+    // ======================== State Snapshots ========================
+
+    /// Deterministic digest of everything stored for `patient`: records,
+    /// prescriptions, access grants and consents. Read-only mirrors compare
+    /// the digest with their own to detect divergence, and keep `version`
+    /// as the cursor for `get_patient_changes_since`.
+    pub fn snapshot_patient_state(env: Env, patient: Address) -> StateDigest {
+        snapshot::compute(&env, &alias::resolve(&env, &patient))
+    }
+
+    /// Current change cursor for `patient`. Cheaper than a full snapshot
+    /// when a mirror only needs to know whether anything changed.
+    pub fn get_patient_state_version(env: Env, patient: Address) -> u64 {
+        snapshot::get_version(&env, &alias::resolve(&env, &patient))
+    }
+
+    /// Changes to `patient`'s state after `cursor`, oldest first. Fails with
+    /// `StateCursorExpired` once the journal no longer reaches back to the
+    /// cursor, in which case the mirror must resync from a snapshot.
+    pub fn get_patient_changes_since(
+        env: Env,
+        patient: Address,
+        cursor: u64,
+    ) -> Result<Vec<StateChange>, ContractError> {
+        snapshot::changes_since(&env, &alias::resolve(&env, &patient), cursor)
+            .ok_or(ContractError::StateCursorExpired)
+    }
+
+    // ======================== Event Redaction ========================
+
+    /// Choose how much contract events reveal: `Full` payloads for indexers,
+    /// `Hashed` commitments, or `Minimal` event names only. Requires
+    /// SystemAdmin and applies to every event emitted afterwards.
+    pub fn set_event_redaction(
+        env: Env,
+        caller: Address,
+        level: EventRedaction,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_event_redaction",
+                "permission:SystemAdmin",
+            );
+        }
+        event_redaction::set_level(&env, level);
+        admin_receipt::issue(&env, &caller, symbol_short!("EVT_RDCT"), None);
+        Ok(())
+    }
+
+    pub fn get_event_redaction(env: Env) -> EventRedaction {
+        event_redaction::get_level(&env)
+    }
+
+    // ======================== Responder Directory ========================
+
+    /// List `responder` in the emergency responder directory. The responder
+    /// must already hold `EmergencyCredentials` in the credential registry.
+    /// Returns the membership id recorded on their emergency grants.
+    pub fn add_emergency_responder(
+        env: Env,
+        caller: Address,
+        responder: Address,
+        facility_hash: BytesN<32>,
+    ) -> Result<u64, ContractError> {
+        caller.require_auth();
+
+        if !Self::admin_can(&env, &caller, AdminPermission::EmergencyOversight) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "add_emergency_responder",
+                "admin:EmergencyOversight",
+            );
+        }
+        if rbac::get_user_credential(&env, &responder) != CredentialType::EmergencyCredentials {
+            return Err(ContractError::InvalidRole);
+        }
+        if responder::active_membership(&env, &responder).is_some() {
+            return Err(ContractError::ResponderAlreadyListed);
+        }
+
+        let entry = ResponderEntry {
+            id: responder::increment_counter(&env),
+            responder: responder.clone(),
+            facility_hash,
+            added_by: caller.clone(),
+            added_at: env.ledger().timestamp(),
+            active: true,
+        };
+        responder::add_entry(&env, &entry);
+
+        admin_receipt::issue(&env, &caller, symbol_short!("RSP_ADD"), Some(responder));
+        events::publish_responder_listed(&env, &entry);
+        Ok(entry.id)
+    }
+
+    /// Remove `responder` from the directory. Emergency grants already
+    /// issued under the membership are unaffected.
+    pub fn remove_emergency_responder(
+        env: Env,
+        caller: Address,
+        responder: Address,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        if !Self::admin_can(&env, &caller, AdminPermission::EmergencyOversight) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "remove_emergency_responder",
+                "admin:EmergencyOversight",
+            );
+        }
+
+        let mut entry = responder::active_membership(&env, &responder)
+            .ok_or(ContractError::ResponderNotFound)?;
+        entry.active = false;
+        responder::set_entry(&env, &entry);
+
+        admin_receipt::issue(&env, &caller, symbol_short!("RSP_REM"), Some(responder));
+        events::publish_responder_unlisted(&env, &entry, caller);
+        Ok(())
+    }
+
+    /// The responder's current directory membership, active or not.
+    pub fn get_emergency_responder(
+        env: Env,
+        responder: Address,
+    ) -> Result<ResponderEntry, ContractError> {
+        responder::get_by_address(&env, &responder).ok_or(ContractError::ResponderNotFound)
+    }
+
+    /// Active directory members.
+    pub fn get_emergency_responders(env: Env) -> Vec<ResponderEntry> {
+        let mut out = Vec::new(&env);
+        for id in responder::get_ids(&env).iter() {
+            if let Some(entry) = responder::get_entry(&env, id) {
+                if entry.active {
+                    out.push_back(entry);
+                }
+            }
+        }
+        out
+    }
+
+    // ======================== Grant Restore ========================
+
+    /// Track the grantee address in the patient's grantee list for purge
+    /// iteration and `list_access_grants`.
+    fn track_grantee(env: &Env, patient: &Address, grantee: &Address) {
+        let list_key = (symbol_short!("ACC_LST"), patient.clone());
+        let mut grantees: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&list_key)
+            .unwrap_or(Vec::new(env));
+        if !grantees.contains(grantee) {
+            grantees.push_back(grantee.clone());
+            env.storage().persistent().set(&list_key, &grantees);
+        }
+    }
+
+    /// Track everyone holding a record-level grant on `record_id`, so a
+    /// lockdown can find them.
+    fn track_record_grantee(env: &Env, record_id: u64, grantee: &Address) {
+        let list_key = (symbol_short!("REC_ALST"), record_id);
+        let mut grantees: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&list_key)
+            .unwrap_or(Vec::new(env));
+        if !grantees.contains(grantee) {
+            grantees.push_back(grantee.clone());
+            env.storage().persistent().set(&list_key, &grantees);
+        }
+        extend_ttl_u64_key(env, &list_key);
+    }
+
+    /// Track everyone the patient has given consent to, so a lockdown can
+    /// find them.
+    fn track_consent_grantee(env: &Env, patient: &Address, grantee: &Address) {
+        let list_key = (symbol_short!("CONS_LST"), patient.clone());
+        let mut grantees: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&list_key)
+            .unwrap_or(Vec::new(env));
+        if !grantees.contains(grantee) {
+            grantees.push_back(grantee.clone());
+            env.storage().persistent().set(&list_key, &grantees);
+        }
+        extend_ttl_address_key(env, &list_key);
+    }
+
+    /// Undo an accidental `revoke_access` within `ACCESS_RESTORE_WINDOW`.
+    /// The grant comes back with its original level and expiry; delegations
+    /// cascaded away by the revocation are not restored.
+    pub fn restore_access(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+
+        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+        let mut grant: AccessGrant = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ContractError::AccessGrantNotFound)?;
+        let revoked_at = match (&grant.status, grant.revoked_at) {
+            (GrantStatus::Revoked, Some(at)) => at,
+            _ => return Err(ContractError::InvalidInput),
+        };
+        if env.ledger().timestamp() > revoked_at.saturating_add(ACCESS_RESTORE_WINDOW) {
+            return Err(ContractError::RestoreWindowClosed);
+        }
+        // Grants ended with their agreement cannot be revived on their own.
+        if let Some(agreement_id) = grant.agreement_id {
+            let active = agreement::get(&env, agreement_id)
+                .map(|a| a.status == AgreementStatus::Active)
+                .unwrap_or(false);
+            if !active {
+                return Err(ContractError::AgreementNotActive);
+            }
+        }
+
+        grant.status = GrantStatus::Active;
+        grant.revoked_at = None;
+        env.storage().persistent().set(&key, &grant);
+        extend_ttl_access_key(&env, &key);
+        snapshot::record_change(
+            &env,
+            &patient,
+            StateChangeKind::AccessRestored,
+            None,
+            Some(grantee.clone()),
+        );
+
+        events::publish_access_restored(&env, &grant);
+        Ok(())
+    }
+
+    /// Push back the expiry of a live grant by `additional_seconds`. The
+    /// grant's total lifetime may not exceed `get_max_grant_duration`.
+    pub fn extend_access(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        additional_seconds: u64,
+    ) -> Result<AccessGrant, ContractError> {
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("GRT_ACC")),
+        )?;
+        patient.require_auth();
+        if additional_seconds == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let (key, mut grant) = Self::live_grant(&env, &patient, &grantee)?;
+        if grant.expires_at <= env.ledger().timestamp() {
+            return Err(ContractError::ExpiredAccess);
+        }
+        let previous_expires_at = grant.expires_at;
+        grant.expires_at = grant.expires_at.saturating_add(additional_seconds);
+        grant_duration::check(&env, &grant)?;
+
+        Self::store_updated_grant(&env, &key, &grant, previous_expires_at, false);
+        Ok(grant)
+    }
+
+    /// Restart a grant for `new_duration` seconds from now, keeping its
+    /// level and original grant time, so repeated renewals stay within the
+    /// maximum grant duration. Unlike `extend_access` this also revives a
+    /// grant that has lapsed; revoked grants go through `restore_access`
+    /// instead.
+    pub fn renew_access(
+        env: Env,
+        patient: Address,
+        grantee: Address,
+        new_duration: u64,
+    ) -> Result<AccessGrant, ContractError> {
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("GRT_ACC")),
+        )?;
+        patient.require_auth();
+        validation::validate_duration(new_duration)?;
+
+        let (key, mut grant) = Self::live_grant(&env, &patient, &grantee)?;
+        let now = env.ledger().timestamp();
+        let previous_expires_at = grant.expires_at;
+        grant.expires_at = now.saturating_add(new_duration);
+        grant_duration::check(&env, &grant)?;
+
+        Self::store_updated_grant(&env, &key, &grant, previous_expires_at, true);
+        Ok(grant)
+    }
+
+    /// Loads the patient-wide grant to `grantee` unless it is missing or
+    /// revoked.
+    fn live_grant(
+        env: &Env,
+        patient: &Address,
+        grantee: &Address,
+    ) -> Result<((Symbol, Address, Address), AccessGrant), ContractError> {
+        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+        match env.storage().persistent().get::<_, AccessGrant>(&key) {
+            Some(grant) if grant.status == GrantStatus::Active => Ok((key, grant)),
+            _ => Err(ContractError::AccessGrantNotFound),
+        }
+    }
+
+    fn store_updated_grant(
+        env: &Env,
+        key: &(Symbol, Address, Address),
+        grant: &AccessGrant,
+        previous_expires_at: u64,
+        renewed: bool,
+    ) {
+        env.storage().persistent().set(key, grant);
+        extend_ttl_access_key(env, key);
+        snapshot::record_change(
+            env,
+            &grant.patient,
+            StateChangeKind::AccessGranted,
+            None,
+            Some(grant.grantee.clone()),
+        );
+        events::publish_access_extended(env, grant, previous_expires_at, renewed);
+    }
+
+    /// Set the longest a grant may stay live through extensions and
+    /// renewals, counted from when it was first granted. Requires SystemAdmin.
+    pub fn set_max_grant_duration(
+        env: Env,
+        caller: Address,
+        max_seconds: u64,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_max_grant_duration",
+                "permission:SystemAdmin",
+            );
+        }
+        validation::validate_duration(max_seconds)?;
+        grant_duration::set_max_total(&env, max_seconds);
+        admin_receipt::issue(&env, &caller, symbol_short!("GRT_MAXD"), None);
+        Ok(())
+    }
+
+    pub fn get_max_grant_duration(env: Env) -> u64 {
+        grant_duration::get_max_total(&env)
+    }
+
+    /// Drop grantees from the patient's grantee list whose grant is gone or
+    /// was revoked longer ago than `ACCESS_RESTORE_WINDOW`.
+    fn prune_grantees(env: &Env, patient: &Address) {
+        let list_key = (symbol_short!("ACC_LST"), patient.clone());
+        let grantees: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&list_key)
+            .unwrap_or(Vec::new(env));
+        let now = env.ledger().timestamp();
+        let mut kept = Vec::new(env);
+        for grantee in grantees.iter() {
+            let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+            let stale = match env.storage().persistent().get::<_, AccessGrant>(&key) {
+                Some(grant) => grant
+                    .revoked_at
+                    .is_some_and(|at| now > at.saturating_add(ACCESS_RESTORE_WINDOW)),
+                None => true,
+            };
+            if !stale {
+                kept.push_back(grantee);
+            }
+        }
+        if kept.len() != grantees.len() {
+            env.storage().persistent().set(&list_key, &kept);
+        }
+    }
+
+    fn grants_for(env: &Env, patient: &Address) -> Vec<AccessGrant> {
+        let grantees: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("ACC_LST"), patient.clone()))
+            .unwrap_or(Vec::new(env));
+        let mut out = Vec::new(env);
+        for grantee in grantees.iter() {
+            let key = (symbol_short!("ACCESS"), patient.clone(), grantee);
+            if let Some(grant) = env.storage().persistent().get::<_, AccessGrant>(&key) {
+                extend_ttl_access_key(env, &key);
+                out.push_back(grant);
+            }
+        }
+        out
+    }
+
+    /// Every patient-wide grant the patient has issued, including revoked
+    /// ones still held for restore.
+    pub fn list_access_grants(env: Env, patient: Address) -> Vec<AccessGrant> {
+        let patient = alias::resolve(&env, &patient);
+        Self::grants_for(&env, &patient)
+    }
+
+    /// The patient-wide grants that currently give their grantee access:
+    /// active and not yet expired.
+    pub fn get_access_grants(env: Env, patient: Address) -> Vec<AccessGrant> {
+        let patient = alias::resolve(&env, &patient);
+        let now = env.ledger().timestamp();
+        let mut out = Vec::new(&env);
+        for grant in Self::grants_for(&env, &patient).iter() {
+            if grant.status == GrantStatus::Active && grant.expires_at > now {
+                out.push_back(grant);
+            }
+        }
+        out
+    }
+
+    // ======================== Data-Sharing Agreements ========================
+
+    /// Propose a data-sharing agreement with `counterparty`. The patient's
+    /// authorization is their signature over `terms_hash`; the agreement
+    /// becomes usable once the counterparty signs it too.
+    pub fn propose_agreement(
+        env: Env,
+        patient: Address,
+        counterparty: Address,
+        terms_hash: BytesN<32>,
+    ) -> Result<u64, ContractError> {
+        feature_flags::require_enabled(&env, Feature::DataSharingAgreements)?;
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        if patient == counterparty {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let agreement = DataSharingAgreement {
+            id: agreement::increment_counter(&env),
+            patient,
+            counterparty,
+            terms_hash,
+            status: AgreementStatus::Proposed,
+            proposed_at: env.ledger().timestamp(),
+            signed_at: None,
+            terminated_at: None,
+            terminated_by: None,
+        };
+        agreement::create(&env, &agreement);
+        events::publish_agreement_proposed(&env, &agreement);
+        Ok(agreement.id)
+    }
+
+    /// Countersign a proposed agreement, making it active.
+    pub fn sign_agreement(
+        env: Env,
+        counterparty: Address,
+        agreement_id: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        feature_flags::require_enabled(&env, Feature::DataSharingAgreements)?;
+        counterparty.require_auth();
+
+        let mut agreement =
+            agreement::get(&env, agreement_id).ok_or(ContractError::AgreementNotFound)?;
+        if agreement.counterparty != counterparty {
+            return Self::unauthorized(
+                &env,
+                &counterparty,
+                "sign_agreement",
+                "agreement_counterparty",
+            );
+        }
+        if agreement.status != AgreementStatus::Proposed {
+            return Err(ContractError::AgreementNotActive);
+        }
+
+        agreement.status = AgreementStatus::Active;
+        agreement.signed_at = Some(env.ledger().timestamp());
+        agreement::set(&env, &agreement);
+        events::publish_agreement_signed(&env, &agreement);
+        Ok(())
+    }
 
-                if !master_bytes.is_empty() || out_record.key_version.is_none() {
-                    let km = KeyManager::new(master_bytes);
-                    if let Some(plain) = km.decrypt(&env, out_record.data_hash.clone()) {
-                        out_record.data_hash = plain;
-                    }
-                }
+    /// Grant the agreement's counterparty access under an active agreement,
+    /// either patient-wide (`record_id` of `None`) or to a single record.
+    /// The grant carries the agreement id and is revoked with it.
+    pub fn grant_under_agreement(
+        env: Env,
+        patient: Address,
+        agreement_id: u64,
+        record_id: Option<u64>,
+        level: AccessLevel,
+        duration_seconds: u64,
+    ) -> Result<(), ContractError> {
+        feature_flags::require_enabled(&env, Feature::DataSharingAgreements)?;
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        validation::validate_duration(duration_seconds)?;
 
-                Ok(out_record)
-            }
-            None => {
-                // Log failed access attempt (record not found)
-                // We don't know the patient, so we'll use caller as placeholder
-                let audit_entry = audit::create_audit_entry(
+        let agreement =
+            agreement::get(&env, agreement_id).ok_or(ContractError::AgreementNotFound)?;
+        if agreement.patient != patient {
+            return Self::unauthorized(
+                &env,
+                &patient,
+                "grant_under_agreement",
+                "agreement_patient",
+            );
+        }
+        if agreement.status != AgreementStatus::Active {
+            return Err(ContractError::AgreementNotActive);
+        }
+
+        let now = env.ledger().timestamp();
+        let expires_at = now.saturating_add(duration_seconds);
+        let grantee = agreement.counterparty;
+        let grant = AccessGrant {
+            patient: patient.clone(),
+            grantee: grantee.clone(),
+            level: level.clone(),
+            granted_at: now,
+            expires_at,
+            status: GrantStatus::Active,
+            revoked_at: None,
+            agreement_id: Some(agreement_id),
+        };
+
+        match record_id {
+            Some(id) => {
+                let record: VisionRecord = env
+                    .storage()
+                    .persistent()
+                    .get(&(symbol_short!("RECORD"), id))
+                    .ok_or(ContractError::RecordNotFound)?;
+                if record.patient != patient {
+                    return Self::unauthorized(
+                        &env,
+                        &patient,
+                        "grant_under_agreement",
+                        "record_owner",
+                    );
+                }
+                let key = (symbol_short!("REC_ACC"), id, grantee.clone());
+                env.storage().persistent().set(&key, &grant);
+                extend_ttl_record_access_key(&env, &key);
+                Self::track_record_grantee(&env, id, &grantee);
+                events::publish_record_access_granted(
                     &env,
-                    caller.clone(),
-                    caller.clone(), // Placeholder since we don't know patient
-                    Some(record_id),
-                    AccessAction::Read,
-                    AccessResult::NotFound,
-                    Some(String::from_str(&env, "Record not found")),
+                    patient,
+                    grantee,
+                    id,
+                    level,
+                    duration_seconds,
+                    expires_at,
                 );
-                audit::add_audit_entry(&env, &audit_entry);
-                events::publish_audit_log_entry(&env, &audit_entry);
-
-                let resource_id = String::from_str(&env, "get_record");
-                let context = create_error_context(
+            }
+            None => {
+                let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+                env.storage().persistent().set(&key, &grant);
+                extend_ttl_access_key(&env, &key);
+                Self::track_grantee(&env, &patient, &grantee);
+                snapshot::record_change(
                     &env,
-                    ContractError::RecordNotFound,
+                    &patient,
+                    StateChangeKind::AccessGranted,
                     None,
-                    Some(resource_id.clone()),
+                    Some(grantee.clone()),
                 );
-                log_error(
+                events::publish_access_granted(
                     &env,
-                    ContractError::RecordNotFound,
-                    None,
-                    Some(resource_id),
-                    None,
+                    patient,
+                    grantee,
+                    level,
+                    duration_seconds,
+                    expires_at,
                 );
-                events::publish_error(&env, ContractError::RecordNotFound as u32, context);
-                Err(ContractError::RecordNotFound)
             }
         }
+
+        agreement::add_grant(
+            &env,
+            agreement_id,
+            &AgreementGrant {
+                record_id,
+                granted_at: now,
+            },
+        );
+        Ok(())
     }
 
-    /// Add eye examination details for an existing record
-    #[allow(clippy::too_many_arguments)]
-    pub fn add_eye_examination(
+    /// End an agreement. Either party may terminate; every grant still
+    /// carrying the agreement id is revoked in the same call. Returns the
+    /// number of grants revoked.
+    pub fn terminate_agreement(
         env: Env,
         caller: Address,
-        record_id: u64,
-        visual_acuity: VisualAcuity,
-        iop: IntraocularPressure,
-        slit_lamp: SlitLampFindings,
-        visual_field: OptVisualField,
-        retina_imaging: OptRetinalImaging,
-        fundus_photo: OptFundusPhotography,
-        clinical_notes: String,
-    ) -> Result<(), ContractError> {
+        agreement_id: u64,
+    ) -> Result<u32, ContractError> {
         circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
         caller.require_auth();
 
-        let record = Self::get_record(env.clone(), caller.clone(), record_id)?;
+        let mut agreement =
+            agreement::get(&env, agreement_id).ok_or(ContractError::AgreementNotFound)?;
+        if caller != agreement.patient && caller != agreement.counterparty {
+            return Self::unauthorized(&env, &caller, "terminate_agreement", "agreement_party");
+        }
+        if agreement.status == AgreementStatus::Terminated {
+            return Err(ContractError::AgreementNotActive);
+        }
 
-        let has_perm = if caller == record.provider {
-            rbac::has_permission(&env, &caller, &Permission::WriteRecord)
-        } else {
-            rbac::has_delegated_permission(
-                &env,
-                &record.provider,
-                &caller,
-                &Permission::WriteRecord,
-            )
+        let now = env.ledger().timestamp();
+        let patient = agreement.patient.clone();
+        let grantee = agreement.counterparty.clone();
+        let mut revoked = 0u32;
+        for linked in agreement::get_grants(&env, agreement_id).iter() {
+            let did_revoke = match linked.record_id {
+                Some(id) => Self::revoke_agreement_grant(
+                    &env,
+                    &(symbol_short!("REC_ACC"), id, grantee.clone()),
+                    agreement_id,
+                    now,
+                ),
+                None => {
+                    let did_revoke = Self::revoke_agreement_grant(
+                        &env,
+                        &(symbol_short!("ACCESS"), patient.clone(), grantee.clone()),
+                        agreement_id,
+                        now,
+                    );
+                    if did_revoke {
+                        access_decision::mark_revoked(&env, &patient, &grantee);
+                        snapshot::record_change(
+                            &env,
+                            &patient,
+                            StateChangeKind::AccessRevoked,
+                            None,
+                            Some(grantee.clone()),
+                        );
+                    }
+                    did_revoke
+                }
+            };
+            if did_revoke {
+                revoked = revoked.saturating_add(1);
+            }
+        }
+
+        agreement.status = AgreementStatus::Terminated;
+        agreement.terminated_at = Some(now);
+        agreement.terminated_by = Some(caller);
+        agreement::set(&env, &agreement);
+        events::publish_agreement_terminated(&env, &agreement, revoked);
+        Ok(revoked)
+    }
+
+    /// Revokes the grant stored at `key` if it is still active and was
+    /// issued under `agreement_id`. A grant the patient has since replaced
+    /// outside the agreement is left alone.
+    fn revoke_agreement_grant<K>(env: &Env, key: &K, agreement_id: u64, now: u64) -> bool
+    where
+        K: IntoVal<Env, Val>,
+    {
+        let mut grant = match env.storage().persistent().get::<_, AccessGrant>(key) {
+            Some(grant) => grant,
+            None => return false,
         };
+        if grant.agreement_id != Some(agreement_id) || grant.status != GrantStatus::Active {
+            return false;
+        }
+        grant.status = GrantStatus::Revoked;
+        grant.revoked_at = Some(now);
+        env.storage().persistent().set(key, &grant);
+        events::publish_access_revoked(env, grant.patient, grant.grantee);
+        true
+    }
 
-        if !has_perm && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+    pub fn get_agreement(
+        env: Env,
+        agreement_id: u64,
+    ) -> Result<DataSharingAgreement, ContractError> {
+        agreement::get(&env, agreement_id).ok_or(ContractError::AgreementNotFound)
+    }
+
+    pub fn get_patient_agreements(env: Env, patient: Address) -> Vec<DataSharingAgreement> {
+        let mut out = Vec::new(&env);
+        for id in agreement::get_patient_ids(&env, &patient).iter() {
+            if let Some(agreement) = agreement::get(&env, id) {
+                out.push_back(agreement);
+            }
+        }
+        out
+    }
+
+    /// Grants issued under the agreement, oldest first.
+    pub fn get_agreement_grants(env: Env, agreement_id: u64) -> Vec<AgreementGrant> {
+        agreement::get_grants(&env, agreement_id)
+    }
+
+    // ======================== Provider Availability ========================
+
+    /// Mark `provider` out of office until `until`, or available again.
+    /// `until` is ignored when setting `Available`.
+    pub fn set_availability(
+        env: Env,
+        provider: Address,
+        status: AvailabilityStatus,
+        until: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        provider.require_auth();
+        if !rbac::has_permission(&env, &provider, &Permission::WriteRecord) {
             return Self::unauthorized(
                 &env,
-                &caller,
-                "add_eye_examination",
-                "permission:WriteRecord_or_SystemAdmin",
+                &provider,
+                "set_availability",
+                "permission:WriteRecord",
             );
         }
 
-        if record.record_type != RecordType::Examination {
-            return Err(ContractError::InvalidRecordType);
+        let now = env.ledger().timestamp();
+        let until = match status {
+            AvailabilityStatus::Available => 0,
+            AvailabilityStatus::OutOfOffice => {
+                if until <= now {
+                    return Err(ContractError::InvalidTimestamp);
+                }
+                until
+            }
+        };
+        let availability = ProviderAvailability {
+            provider,
+            status,
+            until,
+            updated_at: now,
+        };
+        availability::set(&env, &availability);
+        events::publish_availability_set(&env, &availability);
+        Ok(())
+    }
+
+    pub fn get_availability(env: Env, provider: Address) -> ProviderAvailability {
+        availability::get(&env, &provider)
+    }
+
+    /// Emits a routing event when `grantee` is away. The grant itself is
+    /// unchanged: locum cover already reads through it, and the grantee can
+    /// use it on return.
+    fn notify_grant_routing(env: &Env, patient: &Address, grantee: &Address) {
+        match availability::route(env, grantee, patient, env.ledger().timestamp()) {
+            availability::Routing::Direct => {}
+            availability::Routing::Cover(arrangement) => {
+                events::publish_grant_rerouted(env, patient.clone(), grantee.clone(), &arrangement);
+            }
+            availability::Routing::Queued(until) => {
+                events::publish_grant_queued(env, patient.clone(), grantee.clone(), until);
+            }
+        }
+    }
+
+    /// Refer `patient` to `to_provider`. If `to_provider` is out of office the
+    /// referral goes to their active locum cover, or otherwise waits in their
+    /// queue until they return. `reason_hash` commits to the off-chain
+    /// clinical reason.
+    pub fn create_referral(
+        env: Env,
+        referring_provider: Address,
+        patient: Address,
+        to_provider: Address,
+        reason_hash: BytesN<32>,
+        urgency: ReferralUrgency,
+    ) -> Result<u64, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        referring_provider.require_auth();
+
+        if !rbac::has_permission(&env, &referring_provider, &Permission::WriteRecord) {
+            return Self::unauthorized(
+                &env,
+                &referring_provider,
+                "create_referral",
+                "permission:WriteRecord",
+            );
+        }
+        if Self::check_access(env.clone(), patient.clone(), referring_provider.clone(), None, None)
+            == AccessLevel::None
+        {
+            return Self::unauthorized(
+                &env,
+                &referring_provider,
+                "create_referral",
+                "patient_access",
+            );
+        }
+        if !rbac::has_permission(&env, &to_provider, &Permission::WriteRecord) {
+            return Err(ContractError::InvalidRole);
+        }
+        if referring_provider == to_provider {
+            return Err(ContractError::InvalidInput);
         }
 
-        let exam = EyeExamination {
-            record_id,
-            visual_acuity,
-            iop,
-            slit_lamp,
-            visual_field,
-            retina_imaging,
-            fundus_photo,
-            clinical_notes,
+        let now = env.ledger().timestamp();
+        let (assigned_provider, arrangement_id, queued_until) =
+            match availability::route(&env, &to_provider, &patient, now) {
+                availability::Routing::Direct => (to_provider.clone(), None, None),
+                availability::Routing::Cover(arrangement) => {
+                    (arrangement.covering_provider, Some(arrangement.id), None)
+                }
+                availability::Routing::Queued(until) => (to_provider.clone(), None, Some(until)),
+            };
+
+        let referral = Referral {
+            id: referral::increment_counter(&env),
+            patient,
+            referring_provider,
+            requested_provider: to_provider,
+            assigned_provider,
+            arrangement_id,
+            queued_until,
+            reason_hash,
+            urgency,
+            status: ReferralStatus::Pending,
+            created_at: now,
+            responded_at: None,
         };
+        referral::create(&env, &referral);
+        events::publish_referral_created(&env, &referral);
+        Ok(referral.id)
+    }
 
-        examination::set_examination(&env, &exam);
+    /// Accept a pending referral. The assigned provider and the patient both
+    /// sign; with the patient's co-signature the provider receives a Read
+    /// grant for `referral::REFERRAL_GRANT_SECONDS` unless they already hold
+    /// live access.
+    pub fn accept_referral(
+        env: Env,
+        provider: Address,
+        referral_id: u64,
+    ) -> Result<Referral, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        let mut referral = Self::pending_referral_for(&env, &provider, referral_id)?;
+        referral.patient.require_auth();
 
-        audit::AuditManager::log_event(
-            &env,
-            caller.clone(),
-            "examination.add",
-            soroban_sdk::String::from_str(&env, &record_id.to_string()),
-            "ok",
+        let now = env.ledger().timestamp();
+        referral.status = ReferralStatus::Accepted;
+        referral.responded_at = Some(now);
+        referral::update(&env, &referral);
+
+        let key = (
+            symbol_short!("ACCESS"),
+            referral.patient.clone(),
+            provider.clone(),
         );
+        let live = env
+            .storage()
+            .persistent()
+            .get::<_, AccessGrant>(&key)
+            .is_some_and(|g| {
+                g.status == GrantStatus::Active
+                    && g.expires_at > now
+                    && g.level != AccessLevel::None
+            });
+        if !live {
+            let grant = AccessGrant {
+                patient: referral.patient.clone(),
+                grantee: provider.clone(),
+                level: AccessLevel::Read,
+                granted_at: now,
+                expires_at: now.saturating_add(referral::REFERRAL_GRANT_SECONDS),
+                status: GrantStatus::Active,
+                revoked_at: None,
+                agreement_id: None,
+            };
+            env.storage().persistent().set(&key, &grant);
+            extend_ttl_access_key(&env, &key);
+            Self::track_grantee(&env, &referral.patient, &provider);
+            snapshot::record_change(
+                &env,
+                &referral.patient,
+                StateChangeKind::AccessGranted,
+                None,
+                Some(provider.clone()),
+            );
+            events::publish_access_granted(
+                &env,
+                referral.patient.clone(),
+                provider,
+                AccessLevel::Read,
+                referral::REFERRAL_GRANT_SECONDS,
+                grant.expires_at,
+            );
+        }
 
-        events::publish_examination_added(&env, record_id);
-
-        Ok(())
+        events::publish_referral_responded(&env, &referral);
+        Ok(referral)
     }
 
-    /// Update eye examination details using optimistic concurrency control (OCC).
-    #[allow(clippy::too_many_arguments)]
-    pub fn update_examination_versioned(
+    /// Decline a pending referral. Only the assigned provider signs.
+    pub fn decline_referral(
         env: Env,
-        caller: Address,
-        record_id: u64,
-        expected_version: u64,
-        node_id: u32,
-        visual_acuity: VisualAcuity,
-        iop: IntraocularPressure,
-        slit_lamp: SlitLampFindings,
-        visual_field: OptVisualField,
-        retina_imaging: OptRetinalImaging,
-        fundus_photo: OptFundusPhotography,
-        clinical_notes: String,
-        changed_fields: Vec<FieldChange>,
-    ) -> Result<UpdateOutcome, ContractError> {
+        provider: Address,
+        referral_id: u64,
+    ) -> Result<Referral, ContractError> {
         circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
-        caller.require_auth();
+        let mut referral = Self::pending_referral_for(&env, &provider, referral_id)?;
+
+        referral.status = ReferralStatus::Declined;
+        referral.responded_at = Some(env.ledger().timestamp());
+        referral::update(&env, &referral);
+        events::publish_referral_responded(&env, &referral);
+        Ok(referral)
+    }
+
+    /// Loads `referral_id` for `provider` to respond to: they must be its
+    /// assigned provider and it must still be pending.
+    fn pending_referral_for(
+        env: &Env,
+        provider: &Address,
+        referral_id: u64,
+    ) -> Result<Referral, ContractError> {
+        provider.require_auth();
+        let referral = referral::get(env, referral_id).ok_or(ContractError::ReferralNotFound)?;
+        if referral.assigned_provider != *provider {
+            return Self::unauthorized(env, provider, "respond_referral", "assigned_provider");
+        }
+        if referral.status != ReferralStatus::Pending {
+            return Err(ContractError::ReferralNotPending);
+        }
+        Ok(referral)
+    }
+
+    pub fn get_referral(env: Env, referral_id: u64) -> Result<Referral, ContractError> {
+        referral::get(&env, referral_id).ok_or(ContractError::ReferralNotFound)
+    }
+
+    /// Referrals assigned to `provider`, including ones rerouted to them as
+    /// locum cover and ones queued for their return.
+    pub fn get_provider_referrals(env: Env, provider: Address) -> Vec<Referral> {
+        let mut out = Vec::new(&env);
+        for id in referral::get_provider_ids(&env, &provider).iter() {
+            if let Some(referral) = referral::get(&env, id) {
+                out.push_back(referral);
+            }
+        }
+        out
+    }
+
+    /// Referrals assigned to `provider`, optionally only those in `status`.
+    pub fn get_referrals_for_provider(
+        env: Env,
+        provider: Address,
+        status: Option<ReferralStatus>,
+    ) -> Vec<Referral> {
+        let mut out = Vec::new(&env);
+        for referral in Self::get_provider_referrals(env.clone(), provider).iter() {
+            if status.is_none() || status == Some(referral.status) {
+                out.push_back(referral);
+            }
+        }
+        out
+    }
 
-        let record = Self::get_record(env.clone(), caller.clone(), record_id)?;
+    pub fn get_patient_referrals(env: Env, patient: Address) -> Vec<Referral> {
+        patient.require_auth();
+        let mut out = Vec::new(&env);
+        for id in referral::get_patient_ids(&env, &patient).iter() {
+            if let Some(referral) = referral::get(&env, id) {
+                out.push_back(referral);
+            }
+        }
+        out
+    }
 
-        let has_perm = if caller == record.provider {
-            rbac::has_permission(&env, &caller, &Permission::WriteRecord)
-        } else {
-            rbac::has_delegated_permission(
-                &env,
-                &record.provider,
-                &caller,
-                &Permission::WriteRecord,
-            )
-        };
+    // ======================== Care Team ========================
 
-        if !has_perm && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+    /// Put `provider` on `patient`'s care team. Both must sign, and the
+    /// provider must be able to write records. While the relationship is
+    /// active, `check_access` reports at least Read for records the provider
+    /// authored, without a separate grant.
+    pub fn establish_care_relationship(
+        env: Env,
+        patient: Address,
+        provider: Address,
+    ) -> Result<CareRelationship, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        provider.require_auth();
+        let patient = alias::resolve(&env, &patient);
+        let provider = alias::resolve(&env, &provider);
+
+        if !rbac::has_permission(&env, &provider, &Permission::WriteRecord) {
             return Self::unauthorized(
                 &env,
-                &caller,
-                "update_examination_versioned",
-                "permission:WriteRecord_or_SystemAdmin",
+                &provider,
+                "establish_care_relationship",
+                "permission:WriteRecord",
             );
         }
-
-        if record.record_type != RecordType::Examination {
-            return Err(ContractError::InvalidRecordType);
+        Self::require_active_user(&env, &provider, "establish_care_relationship")?;
+        if patient == provider || care_team::is_active(&env, &patient, &provider) {
+            return Err(ContractError::InvalidInput);
         }
 
-        let exam = EyeExamination {
-            record_id,
-            visual_acuity,
-            iop,
-            slit_lamp,
-            visual_field,
-            retina_imaging,
-            fundus_photo,
-            clinical_notes,
+        let rel = CareRelationship {
+            patient,
+            provider,
+            established_at: env.ledger().timestamp(),
+            ended_at: None,
         };
-
-        let outcome = examination::versioned_set_examination(
-            &env,
-            &exam,
-            expected_version,
-            node_id,
-            &caller,
-            &changed_fields,
-        );
-
-        Ok(outcome)
+        care_team::set(&env, &rel);
+        events::publish_care_relationship_changed(&env, &rel);
+        Ok(rel)
     }
 
-    /// Retrieve eye examination details for a record
-    pub fn get_eye_examination(
+    /// End an active care relationship. Either the patient or the provider
+    /// may end it.
+    pub fn end_care_relationship(
         env: Env,
         caller: Address,
-        record_id: u64,
-    ) -> Result<EyeExamination, ContractError> {
+        patient: Address,
+        provider: Address,
+    ) -> Result<CareRelationship, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
         caller.require_auth();
-        let record = Self::get_record(env.clone(), caller.clone(), record_id)?;
-
-        let has_perm = if caller == record.patient || caller == record.provider {
-            true
-        } else {
-            let access = Self::check_access(env.clone(), record.patient.clone(), caller.clone());
-            let record_access = Self::check_record_access(env.clone(), record_id, caller.clone());
-            access == AccessLevel::Read
-                || access == AccessLevel::Write
-                || access == AccessLevel::Full
-                || record_access != AccessLevel::None
-                || rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
-        };
+        let caller = alias::resolve(&env, &caller);
+        let patient = alias::resolve(&env, &patient);
+        let provider = alias::resolve(&env, &provider);
 
-        if !has_perm {
-            return Self::access_denied(&env, &caller, "get_eye_examination", "record_read_access");
+        if caller != patient && caller != provider {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "end_care_relationship",
+                "patient_or_provider",
+            );
         }
-
-        examination::get_examination(&env, record_id).ok_or(ContractError::RecordNotFound)
+        let mut rel = care_team::get(&env, &patient, &provider)
+            .filter(|rel| rel.is_active())
+            .ok_or(ContractError::CareRelationshipNotFound)?;
+
+        rel.ended_at = Some(env.ledger().timestamp());
+        care_team::set(&env, &rel);
+        events::publish_care_relationship_changed(&env, &rel);
+        Ok(rel)
     }
 
-    /// Return the current OCC version stamp for a record.
-    pub fn get_record_version_stamp(env: Env, record_id: u64) -> VersionStamp {
-        examination::get_exam_version(&env, record_id)
+    /// The patient's active care relationships, oldest first.
+    pub fn get_care_team(env: Env, patient: Address) -> Vec<CareRelationship> {
+        let patient = alias::resolve(&env, &patient);
+        let mut out = Vec::new(&env);
+        for provider in care_team::providers(&env, &patient).iter() {
+            if let Some(rel) = care_team::get(&env, &patient, &provider) {
+                if rel.is_active() {
+                    out.push_back(rel);
+                }
+            }
+        }
+        out
     }
 
-    /// Configure conflict resolution strategy for a record.
-    pub fn set_record_resolution_strategy(
-        env: Env,
-        caller: Address,
-        record_id: u64,
-        strategy: ResolutionStrategy,
-    ) -> Result<(), ContractError> {
-        caller.require_auth();
+    // ======================== Guardianship ========================
 
-        let record = Self::get_record(env.clone(), caller.clone(), record_id)?;
-        let has_perm = if caller == record.provider {
-            rbac::has_permission(&env, &caller, &Permission::WriteRecord)
-        } else {
-            rbac::has_delegated_permission(
-                &env,
-                &record.provider,
-                &caller,
-                &Permission::WriteRecord,
-            )
-        };
+    /// Let `guardian` act for `dependent`, a minor or other dependent
+    /// patient, until `age_out_at`. A SystemAdmin or a provider who can
+    /// write records must confirm. The guardian may grant and revoke access,
+    /// read the dependent's records and is notified of emergency access.
+    pub fn register_dependent(
+        env: Env,
+        confirmer: Address,
+        guardian: Address,
+        dependent: Address,
+        age_out_at: u64,
+    ) -> Result<Guardianship, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        confirmer.require_auth();
+        guardian.require_auth();
+        let guardian = alias::resolve(&env, &guardian);
+        let dependent = alias::resolve(&env, &dependent);
 
-        if !has_perm && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+        if !rbac::has_permission(&env, &confirmer, &Permission::SystemAdmin)
+            && !rbac::has_permission(&env, &confirmer, &Permission::WriteRecord)
+        {
             return Self::unauthorized(
                 &env,
-                &caller,
-                "set_record_resolution_strategy",
-                "permission:WriteRecord_or_SystemAdmin",
+                &confirmer,
+                "register_dependent",
+                "permission:SystemAdmin_or_WriteRecord",
             );
         }
+        let now = env.ledger().timestamp();
+        if guardian == dependent
+            || age_out_at <= now
+            || guardian::is_guardian(&env, &guardian, &dependent)
+        {
+            return Err(ContractError::InvalidInput);
+        }
 
-        teye_common::concurrency::set_resolution_strategy(&env, record_id, &strategy);
-        Ok(())
-    }
-
-    /// Retrieve conflicts for a specific record.
-    pub fn get_record_conflicts(env: Env, record_id: u64) -> Vec<ConflictEntry> {
-        teye_common::concurrency::get_record_conflicts(&env, record_id)
-    }
-
-    /// Retrieve all pending conflicts.
-    pub fn get_pending_conflicts(env: Env) -> Vec<ConflictEntry> {
-        teye_common::concurrency::get_pending_conflicts(&env)
+        let guardianship = Guardianship {
+            guardian,
+            dependent,
+            confirmed_by: confirmer,
+            registered_at: now,
+            age_out_at,
+            ended_at: None,
+        };
+        guardian::set(&env, &guardianship);
+        events::publish_guardianship_changed(&env, &guardianship);
+        Ok(guardianship)
     }
 
-    /// Resolve a conflict by marking it handled.
-    pub fn resolve_conflict(
+    /// End a guardianship before it ages out. The guardian or a SystemAdmin
+    /// may end it.
+    pub fn end_guardianship(
         env: Env,
         caller: Address,
-        conflict_id: u64,
-        record_id: u64,
-    ) -> Result<(), ContractError> {
+        guardian: Address,
+        dependent: Address,
+    ) -> Result<Guardianship, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
         caller.require_auth();
+        let guardian = alias::resolve(&env, &guardian);
+        let dependent = alias::resolve(&env, &dependent);
 
-        let admin = Self::get_admin(env.clone())?;
-        let has_admin = caller == admin
-            || rbac::has_permission(&env, &caller, &Permission::SystemAdmin);
-
-        if !has_admin {
-            let key = (symbol_short!("RECORD"), record_id);
-            let record = env
-                .storage()
-                .persistent()
-                .get::<_, VisionRecord>(&key)
-                .ok_or(ContractError::RecordNotFound)?;
-
-            let has_perm = if caller == record.provider {
-                rbac::has_permission(&env, &caller, &Permission::WriteRecord)
-            } else {
-                rbac::has_delegated_permission(
-                    &env,
-                    &record.provider,
-                    &caller,
-                    &Permission::WriteRecord,
-                )
-            };
-
-            if !has_perm {
-                return Self::unauthorized(
-                    &env,
-                    &caller,
-                    "resolve_conflict",
-                    "permission:WriteRecord_or_SystemAdmin",
-                );
-            }
-        }
-
-        if !teye_common::concurrency::resolve_conflict(&env, conflict_id, &caller) {
-            return Err(ContractError::RecordNotFound);
+        if alias::resolve(&env, &caller) != guardian
+            && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "end_guardianship",
+                "guardian_or_permission:SystemAdmin",
+            );
         }
-
-        Ok(())
+        let now = env.ledger().timestamp();
+        let mut guardianship = guardian::get(&env, &dependent, &guardian)
+            .filter(|g| g.is_active(now))
+            .ok_or(ContractError::GuardianshipNotFound)?;
+
+        guardianship.ended_at = Some(now);
+        guardian::set(&env, &guardianship);
+        events::publish_guardianship_changed(&env, &guardianship);
+        Ok(guardianship)
     }
 
-    /// Get all records for a patient
-    pub fn get_patient_records(env: Env, patient: Address) -> Vec<u64> {
-        let key = (symbol_short!("PAT_REC"), patient);
-        env.storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or(Vec::new(&env))
+    /// The guardians currently acting for `dependent`. Guardianships past
+    /// their age-out time are left out.
+    pub fn get_guardians(env: Env, dependent: Address) -> Vec<Guardianship> {
+        guardian::active_guardians(&env, &alias::resolve(&env, &dependent))
     }
 
-    /// Grant access to a user
-    #[allow(clippy::arithmetic_side_effects)]
-    pub fn grant_access(
+    /// Revoke `grantee`'s access to `dependent`'s records on the
+    /// dependent's behalf.
+    pub fn guardian_revoke_access(
         env: Env,
-        caller: Address,
-        patient: Address,
+        guardian: Address,
+        dependent: Address,
         grantee: Address,
-        level: AccessLevel,
-        duration_seconds: u64,
     ) -> Result<(), ContractError> {
-        let _guard = teye_common::ReentrancyGuard::new(&env);
         circuit_breaker::require_not_paused(
             &env,
-            &circuit_breaker::PauseScope::Function(symbol_short!("GRT_ACC")),
+            &circuit_breaker::PauseScope::Function(symbol_short!("RVK_ACC")),
         )?;
-        caller.require_auth();
-
-        Self::enforce_rate_limit(&env, &caller)?;
-
-        validation::validate_duration(duration_seconds)?;
+        guardian.require_auth();
+        if !guardian::is_guardian(&env, &guardian, &dependent) {
+            return Self::unauthorized(&env, &guardian, "guardian_revoke_access", "guardian");
+        }
+        Self::revoke_grant(&env, &guardian, &dependent, &grantee);
+        Ok(())
+    }
 
-        let has_perm = if caller == patient {
-            true // Patient manages own access
-        } else {
-            // Specific patient→caller delegation for ManageAccess
-            rbac::has_delegated_permission(&env, &patient, &caller, &Permission::ManageAccess)
-                // Or caller has SystemAdmin (unified: direct + any delegation)
-                || rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
-        };
+    // ======================== Organizations ========================
 
-        if !has_perm {
-            // Log failed access grant attempt
-            let audit_entry = audit::create_audit_entry(
-                &env,
-                caller.clone(),
-                patient.clone(),
-                None,
-                AccessAction::GrantAccess,
-                AccessResult::Denied,
-                Some(String::from_str(&env, "Insufficient permissions")),
-            );
-            audit::add_audit_entry(&env, &audit_entry);
-            events::publish_audit_log_entry(&env, &audit_entry);
+    /// Register a clinic or practice with `admin` managing its providers.
+    /// Requires SystemAdmin. Returns the new organization's id.
+    pub fn register_organization(
+        env: Env,
+        caller: Address,
+        name: String,
+        admin: Address,
+    ) -> Result<u64, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
             return Self::unauthorized(
                 &env,
                 &caller,
-                "grant_access",
-                "patient_or_permission:ManageAccess_or_SystemAdmin",
+                "register_organization",
+                "permission:SystemAdmin",
             );
         }
+        validation::validate_name(&name)?;
 
-        let expires_at = env.ledger().timestamp() + duration_seconds;
-        let grant = AccessGrant {
-            patient: patient.clone(),
-            grantee: grantee.clone(),
-            level: level.clone(),
-            granted_at: env.ledger().timestamp(),
-            expires_at,
+        let org = Organization {
+            id: organization::next_id(&env),
+            name,
+            admin,
+            created_at: env.ledger().timestamp(),
         };
+        organization::set(&env, &org);
+        admin_receipt::issue(&env, &caller, symbol_short!("ORG_REG"), None);
+        events::publish_organization_registered(&env, &org);
+        Ok(org.id)
+    }
 
-        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
-        env.storage().persistent().set(&key, &grant);
-        extend_ttl_access_key(&env, &key);
+    pub fn get_organization(env: Env, org_id: u64) -> Result<Organization, ContractError> {
+        organization::get(&env, org_id).ok_or(ContractError::OrganizationNotFound)
+    }
 
-        // Track the grantee address in the patient's grantee list for purge iteration.
-        let list_key = (symbol_short!("ACC_LST"), patient.clone());
-        let mut grantees: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&list_key)
-            .unwrap_or(Vec::new(&env));
-        // Avoid duplicates: only append if not already present.
-        let mut found = false;
-        for i in 0..grantees.len() {
-            if grantees.get(i) == Some(grantee.clone()) {
-                found = true;
-                break;
-            }
-        }
-        if !found {
-            grantees.push_back(grantee.clone());
-            env.storage().persistent().set(&list_key, &grantees);
+    /// Fails unless `caller` is `org_id`'s admin or a SystemAdmin.
+    fn require_org_admin(
+        env: &Env,
+        caller: &Address,
+        org_id: u64,
+        action: &str,
+    ) -> Result<(), ContractError> {
+        let org = organization::get(env, org_id).ok_or(ContractError::OrganizationNotFound)?;
+        if *caller != org.admin && !rbac::has_permission(env, caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(env, caller, action, "org_admin_or_permission:SystemAdmin");
         }
-
-        events::publish_access_granted(&env, patient, grantee, level, duration_seconds, expires_at);
-
         Ok(())
     }
 
-    /// Grant access to multiple users in a single transaction.
-    /// Patient authorizes once for the entire batch.
-    #[allow(clippy::arithmetic_side_effects)]
-    pub fn grant_access_batch(
+    /// Add `provider` to `org_id`, moving them out of any organization they
+    /// were in. The provider must be able to write records.
+    pub fn add_provider_to_org(
         env: Env,
-        patient: Address,
-        grants: Vec<BatchGrantInput>,
+        caller: Address,
+        org_id: u64,
+        provider: Address,
     ) -> Result<(), ContractError> {
         circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
-        patient.require_auth();
-
-        if grants.is_empty() {
+        caller.require_auth();
+        Self::require_org_admin(&env, &caller, org_id, "add_provider_to_org")?;
+        if !rbac::has_permission(&env, &provider, &Permission::WriteRecord) {
             return Err(ContractError::InvalidInput);
         }
 
-        let now = env.ledger().timestamp();
-        for grant in grants.iter() {
-            let expires_at = now + grant.duration_seconds;
-            let access_grant = AccessGrant {
-                patient: patient.clone(),
-                grantee: grant.grantee.clone(),
-                level: grant.level.clone(),
-                granted_at: now,
-                expires_at,
-            };
-            let key = (
-                symbol_short!("ACCESS"),
-                patient.clone(),
-                grant.grantee.clone(),
-            );
-            env.storage().persistent().set(&key, &access_grant);
-
-            events::publish_access_granted(
-                &env,
-                patient.clone(),
-                grant.grantee.clone(),
-                grant.level.clone(),
-                grant.duration_seconds,
-                expires_at,
-            );
-        }
-
-        events::publish_batch_access_granted(&env, patient, grants.len());
-
+        organization::add_member(&env, org_id, &provider);
+        events::publish_org_membership_changed(&env, org_id, provider, true);
         Ok(())
     }
 
-    /// Check access level with ABAC policy evaluation
-    pub fn check_access(env: Env, patient: Address, grantee: Address) -> AccessLevel {
-        // First check traditional consent-based access
-        if !has_active_consent(&env, &patient, &grantee) {
-            return AccessLevel::None;
+    /// Take `provider` out of `org_id`. They lose any access the
+    /// organization's grants gave them.
+    pub fn remove_provider_from_org(
+        env: Env,
+        caller: Address,
+        org_id: u64,
+        provider: Address,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+        Self::require_org_admin(&env, &caller, org_id, "remove_provider_from_org")?;
+        if !organization::remove_member(&env, org_id, &provider) {
+            return Err(ContractError::UserNotFound);
         }
 
-        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+        events::publish_org_membership_changed(&env, org_id, provider, false);
+        Ok(())
+    }
 
-        if let Some(grant) = env.storage().persistent().get::<_, AccessGrant>(&key) {
-            if grant.expires_at > env.ledger().timestamp() {
-                // Check if ABAC policies also allow this access
-                let abac_allowed = evaluate_access_policies(&env, &grantee, None, Some(patient.clone()));
-                if abac_allowed {
-                    return grant.level;
-                }
-            }
-        }
-        AccessLevel::None
+    pub fn get_org_providers(env: Env, org_id: u64) -> Vec<Address> {
+        organization::members(&env, org_id)
     }
 
-    /// Grant record-level access to a specific record.
-    #[allow(clippy::arithmetic_side_effects)]
-    pub fn grant_record_access(
+    /// Grant every provider currently in `org_id` access to the patient's
+    /// records at `level`, replacing any earlier grant to the organization.
+    pub fn grant_org_access(
         env: Env,
         patient: Address,
-        grantee: Address,
-        record_id: u64,
+        org_id: u64,
         level: AccessLevel,
         duration_seconds: u64,
-    ) -> Result<(), ContractError> {
-        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+    ) -> Result<OrgAccessGrant, ContractError> {
+        circuit_breaker::require_not_paused(
+            &env,
+            &circuit_breaker::PauseScope::Function(symbol_short!("GRT_ACC")),
+        )?;
         patient.require_auth();
         validation::validate_duration(duration_seconds)?;
-
-        let record_key = (symbol_short!("RECORD"), record_id);
-        let record: VisionRecord = env
-            .storage()
-            .persistent()
-            .get(&record_key)
-            .ok_or(ContractError::RecordNotFound)?;
-        if record.patient != patient {
-            return Self::unauthorized(&env, &patient, "grant_record_access", "record_owner");
+        if level == AccessLevel::None {
+            return Err(ContractError::InvalidInput);
+        }
+        if organization::get(&env, org_id).is_none() {
+            return Err(ContractError::OrganizationNotFound);
         }
 
         let now = env.ledger().timestamp();
-        let expires_at = now + duration_seconds;
-        let grant = AccessGrant {
-            patient: patient.clone(),
-            grantee: grantee.clone(),
-            level: level.clone(),
+        let grant = OrgAccessGrant {
+            patient: alias::resolve(&env, &patient),
+            org_id,
+            level,
             granted_at: now,
-            expires_at,
+            expires_at: now.saturating_add(duration_seconds),
         };
+        organization::set_grant(&env, &grant);
+        events::publish_org_access_changed(&env, &grant, true);
+        Ok(grant)
+    }
 
-        let key = (symbol_short!("REC_ACC"), record_id, grantee.clone());
-        env.storage().persistent().set(&key, &grant);
-        extend_ttl_record_access_key(&env, &key);
-
-        events::publish_record_access_granted(
+    pub fn revoke_org_access(env: Env, patient: Address, org_id: u64) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(
             &env,
-            patient,
-            grantee,
-            record_id,
-            level,
-            duration_seconds,
-            expires_at,
-        );
+            &circuit_breaker::PauseScope::Function(symbol_short!("RVK_ACC")),
+        )?;
+        patient.require_auth();
+        let patient = alias::resolve(&env, &patient);
+        let grant = organization::get_grant(&env, &patient, org_id)
+            .ok_or(ContractError::AccessGrantNotFound)?;
+
+        organization::remove_grant(&env, &patient, org_id);
+        events::publish_org_access_changed(&env, &grant, false);
         Ok(())
     }
 
-    /// Check record-level access for a specific grantee.
-    pub fn check_record_access(env: Env, record_id: u64, grantee: Address) -> AccessLevel {
-        let key = (symbol_short!("REC_ACC"), record_id, grantee);
-        if let Some(grant) = env.storage().persistent().get::<_, AccessGrant>(&key) {
-            if grant.expires_at > env.ledger().timestamp() {
-                return grant.level;
+    /// The patient's organization grants that have not yet expired.
+    pub fn get_org_access_grants(env: Env, patient: Address) -> Vec<OrgAccessGrant> {
+        let patient = alias::resolve(&env, &patient);
+        let now = env.ledger().timestamp();
+        let mut out = Vec::new(&env);
+        for org_id in organization::granted_orgs(&env, &patient).iter() {
+            if let Some(grant) = organization::get_grant(&env, &patient, org_id) {
+                if grant.expires_at > now {
+                    out.push_back(grant);
+                }
             }
         }
-        AccessLevel::None
+        out
     }
 
-    /// Revoke record-level access for a specific record.
-    pub fn revoke_record_access(
+    // ======================== Data Residency ========================
+
+    /// Place `provider` in organization `org_id` for residency policy purposes.
+    /// Records they write from now on count against that organization.
+    pub fn set_provider_org(
+        env: Env,
+        caller: Address,
+        provider: Address,
+        org_id: u64,
+    ) -> Result<(), ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        caller.require_auth();
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(&env, &caller, "set_provider_org", "permission:SystemAdmin");
+        }
+        organization::add_member(&env, org_id, &provider);
+        admin_receipt::issue(&env, &caller, symbol_short!("RES_ORG"), Some(provider));
+        Ok(())
+    }
+
+    pub fn get_provider_org(env: Env, provider: Address) -> Option<u64> {
+        residency::get_provider_org(&env, &provider)
+    }
+
+    /// Set the regions in which organization `org_id` may store record
+    /// payloads. Existing records are not re-checked here; use
+    /// `list_records_violating_policy` afterwards.
+    pub fn set_residency_policy(
+        env: Env,
+        caller: Address,
+        org_id: u64,
+        allowed_regions: Vec<Symbol>,
+    ) -> Result<(), ContractError> {
+        caller.require_auth();
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_residency_policy",
+                "permission:SystemAdmin",
+            );
+        }
+        if allowed_regions.is_empty() || allowed_regions.len() > residency::MAX_ALLOWED_REGIONS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let policy = ResidencyPolicy {
+            org_id,
+            allowed_regions,
+            updated_by: caller.clone(),
+            updated_at: env.ledger().timestamp(),
+        };
+        residency::set_policy(&env, &policy);
+        admin_receipt::issue(&env, &caller, symbol_short!("RES_POL"), None);
+        events::publish_residency_policy_set(&env, &policy);
+        Ok(())
+    }
+
+    pub fn get_residency_policy(env: Env, org_id: u64) -> Option<ResidencyPolicy> {
+        residency::get_policy(&env, org_id)
+    }
+
+    /// Like `add_record`, tagging the record with the region its payload is
+    /// stored in. Fails if the provider's organization does not allow it.
+    pub fn add_record_with_residency(
         env: Env,
+        caller: Address,
         patient: Address,
-        grantee: Address,
+        provider: Address,
+        record_type: RecordType,
+        data_hash: String,
+        region: Symbol,
+    ) -> Result<u64, ContractError> {
+        if !residency::is_allowed(&env, &provider, &region) {
+            return Err(ContractError::ResidencyNotAllowed);
+        }
+        let record_id = Self::add_record_internal(
+            env.clone(),
+            caller.clone(),
+            patient,
+            provider,
+            record_type,
+            data_hash,
+            None,
+            None,
+        )?;
+        residency::set_tag(&env, record_id, &region);
+        events::publish_record_residency_set(&env, record_id, region, caller);
+        Ok(record_id)
+    }
+
+    /// Tag or re-tag an existing record after its payload has been stored or
+    /// moved. Only the record's provider or a SystemAdmin may do this.
+    pub fn set_record_residency(
+        env: Env,
+        caller: Address,
         record_id: u64,
+        region: Symbol,
     ) -> Result<(), ContractError> {
         circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
-        patient.require_auth();
-        let record_key = (symbol_short!("RECORD"), record_id);
+        caller.require_auth();
         let record: VisionRecord = env
             .storage()
             .persistent()
-            .get(&record_key)
+            .get(&(symbol_short!("RECORD"), record_id))
             .ok_or(ContractError::RecordNotFound)?;
-        if record.patient != patient {
-            return Self::unauthorized(&env, &patient, "revoke_record_access", "record_owner");
+        if caller != record.provider
+            && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
+        {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_record_residency",
+                "record_provider_or_admin",
+            );
         }
-
-        let key = (symbol_short!("REC_ACC"), record_id, grantee);
-        env.storage().persistent().remove(&key);
+        if !residency::is_allowed(&env, &record.provider, &region) {
+            return Err(ContractError::ResidencyNotAllowed);
+        }
+        residency::set_tag(&env, record_id, &region);
+        events::publish_record_residency_set(&env, record_id, region, caller);
         Ok(())
     }
 
-    /// Grant consent for a grantee.
-    pub fn grant_consent(
+    pub fn get_record_residency(env: Env, record_id: u64) -> Option<Symbol> {
+        residency::get_tag(&env, record_id)
+    }
+
+    /// Records written by the organization's providers that are untagged or
+    /// tagged with a region the current policy no longer allows. Empty if the
+    /// organization has no policy.
+    pub fn list_records_violating_policy(
         env: Env,
-        patient: Address,
-        grantee: Address,
-        consent_type: ConsentType,
-        duration_seconds: u64,
-    ) -> Result<(), ContractError> {
-        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
-        patient.require_auth();
-        if duration_seconds == 0 {
-            return Err(ContractError::InvalidInput);
+        caller: Address,
+        org_id: u64,
+    ) -> Result<Vec<ResidencyViolation>, ContractError> {
+        caller.require_auth();
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "list_records_violating_policy",
+                "permission:SystemAdmin",
+            );
         }
-        let now = env.ledger().timestamp();
-        let consent = ConsentGrant {
-            patient: patient.clone(),
-            grantee: grantee.clone(),
-            consent_type: consent_type.clone(),
-            granted_at: now,
-            expires_at: now.saturating_add(duration_seconds),
-            revoked: false,
+
+        let mut out = Vec::new(&env);
+        let policy = match residency::get_policy(&env, org_id) {
+            Some(policy) => policy,
+            None => return Ok(out),
         };
-        let key = consent_key(&patient, &grantee);
-        env.storage().persistent().set(&key, &consent);
-        extend_ttl_access_key(&env, &key);
-        events::publish_consent_granted(&env, patient, grantee, consent_type, consent.expires_at);
-        Ok(())
+        for record_id in residency::get_org_records(&env, org_id).iter() {
+            let record: Option<VisionRecord> = env
+                .storage()
+                .persistent()
+                .get(&(symbol_short!("RECORD"), record_id));
+            if let Some(record) = record {
+                let region = residency::get_tag(&env, record_id);
+                let compliant = region.as_ref().map(|r| policy.allows(r)).unwrap_or(false);
+                if !compliant {
+                    out.push_back(ResidencyViolation {
+                        record_id,
+                        provider: record.provider,
+                        region,
+                    });
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    // ======================== Record Amendments ========================
+
+    /// Correct a record by replacing its data hash. Only the record's
+    /// current provider may amend it; the replaced hash is kept in the
+    /// record's amendment history along with `reason`.
+    pub fn amend_record(
+        env: Env,
+        provider: Address,
+        record_id: u64,
+        new_data_hash: String,
+        reason: String,
+    ) -> Result<RecordAmendment, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        provider.require_auth();
+
+        let key = (symbol_short!("RECORD"), record_id);
+        let mut record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ContractError::RecordNotFound)?;
+        if provider != record.provider
+            || !rbac::has_permission(&env, &provider, &Permission::WriteRecord)
+        {
+            return Self::unauthorized(&env, &provider, "amend_record", "record_provider");
+        }
+        validation::validate_data_hash(&new_data_hash)?;
+        validation::validate_reason(&reason)?;
+
+        let (stored_hash, key_version) = Self::encrypt_data_hash(&env, &new_data_hash);
+        let amendment = amendment::append(
+            &env,
+            record_id,
+            record.data_hash.clone(),
+            record.key_version.clone(),
+            provider,
+            reason,
+        );
+        record.data_hash = stored_hash;
+        record.key_version = key_version;
+        record.updated_at = env.ledger().timestamp();
+        env.storage().persistent().set(&key, &record);
+        extend_ttl_u64_key(&env, &key);
+
+        snapshot::record_change(
+            &env,
+            &record.patient,
+            StateChangeKind::RecordAmended,
+            Some(record_id),
+            None,
+        );
+        events::publish_record_amended(&env, record.patient, &amendment);
+        Ok(amendment)
+    }
+
+    /// Every amendment to `record_id`, oldest first. Replaced hashes are
+    /// returned as stored, with the key version they were encrypted under.
+    pub fn get_record_versions(env: Env, record_id: u64) -> Vec<RecordAmendment> {
+        amendment::get_history(&env, record_id)
+    }
+
+    /// Encrypts `data_hash` under the current `ENC_KEY` version.
+    fn encrypt_data_hash(env: &Env, data_hash: &String) -> (String, Option<String>) {
+        let key_version: Option<String> = env.storage().instance().get(&ENC_CUR);
+        let mut master_bytes: StdVec<u8> = StdVec::new();
+        if let Some(ver) = key_version.clone() {
+            if let Some(sv) = env
+                .storage()
+                .persistent()
+                .get::<(Symbol, String), String>(&(ENC_KEY, ver))
+            {
+                let hex = sv.to_string();
+                if let Some(bytes) = teye_common::hex_to_bytes(&hex) {
+                    master_bytes = bytes;
+                }
+            }
+        }
+        let km = KeyManager::new(master_bytes);
+        let plaintext: StdString = data_hash.to_string();
+        let ciphertext = km.encrypt(None, &plaintext);
+        (String::from_str(env, &ciphertext), key_version)
     }
 
-    /// Revoke previously granted consent.
-    pub fn revoke_consent(
-        env: Env,
-        patient: Address,
-        grantee: Address,
-    ) -> Result<(), ContractError> {
-        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
-        patient.require_auth();
-        let key = consent_key(&patient, &grantee);
-        if let Some(mut consent) = env.storage().persistent().get::<_, ConsentGrant>(&key) {
-            consent.revoked = true;
-            env.storage().persistent().set(&key, &consent);
+    /// Decrypts a record's stored `data_hash` with the key version it was
+    /// written under, falling back to the stored value like `get_record`.
+    fn decrypt_data_hash(env: &Env, record: &VisionRecord) -> String {
+        let key_version: Option<String> = record
+            .key_version
+            .clone()
+            .or_else(|| env.storage().instance().get(&ENC_CUR));
+        let mut master_bytes: StdVec<u8> = StdVec::new();
+        if let Some(ver) = key_version {
+            if let Some(sv) = env
+                .storage()
+                .persistent()
+                .get::<(Symbol, String), String>(&(ENC_KEY, ver))
+            {
+                let hex = sv.to_string();
+                if let Some(bytes) = teye_common::hex_to_bytes(&hex) {
+                    master_bytes = bytes;
+                }
+            }
         }
-        events::publish_consent_revoked(&env, patient, grantee);
-        Ok(())
+        let km = KeyManager::new(master_bytes);
+        let ciphertext: StdString = record.data_hash.to_string();
+        km.decrypt(None, &ciphertext)
+            .map(|plain| String::from_str(env, &plain))
+            .unwrap_or_else(|| record.data_hash.clone())
     }
 
-    /// Revoke access
-    pub fn revoke_access(
-        env: Env,
-        patient: Address,
-        grantee: Address,
-    ) -> Result<(), ContractError> {
-        circuit_breaker::require_not_paused(
-            &env,
-            &circuit_breaker::PauseScope::Function(symbol_short!("RVK_ACC")),
-        )?;
-        patient.require_auth();
+    // ======================== Record Custody ========================
 
-        let key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
-        env.storage().persistent().remove(&key);
+    /// Move stewardship of `record_id` to `to_provider`, e.g. when a clinic
+    /// merges or closes. The current provider of record or a SystemAdmin must
+    /// authorize; each transfer is appended to the record's custody chain
+    /// and the patient is notified. Archived records, records under a legal
+    /// hold and moves that would break the record's residency tag are
+    /// refused.
+    pub fn transfer_record_custody(
+        env: Env,
+        authorizer: Address,
+        record_id: u64,
+        to_provider: Address,
+        reason: String,
+    ) -> Result<CustodyTransfer, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        authorizer.require_auth();
 
-        let revoked_delegations = rbac::revoke_delegations_from(&env, &grantee);
-        for revoked in revoked_delegations.iter() {
-            events::publish_cascading_revocation(
+        let key = (symbol_short!("RECORD"), record_id);
+        let mut record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .ok_or(ContractError::RecordNotFound)?;
+        if authorizer != record.provider
+            && !rbac::has_permission(&env, &authorizer, &Permission::SystemAdmin)
+        {
+            return Self::unauthorized(
                 &env,
-                patient.clone(),
-                grantee.clone(),
-                revoked.delegatee.clone(),
-                revoked.is_scoped,
+                &authorizer,
+                "transfer_record_custody",
+                "record_provider_or_admin",
             );
         }
+        validation::validate_reason(&reason)?;
+        if !rbac::has_permission(&env, &to_provider, &Permission::WriteRecord) {
+            return Err(ContractError::InvalidRole);
+        }
+        if to_provider == record.provider {
+            return Err(ContractError::InvalidInput);
+        }
+        if tombstone::is_archived(&env, record_id) {
+            return Err(ContractError::RecordArchived);
+        }
+        if legal_hold::is_record_held(&env, record_id, &record.patient) {
+            return Err(ContractError::LegalHoldActive);
+        }
+        if residency::get_tag(&env, record_id)
+            .is_some_and(|region| !residency::is_allowed(&env, &to_provider, &region))
+        {
+            return Err(ContractError::ResidencyNotAllowed);
+        }
 
-        // Log successful access revoke
-        let audit_entry = audit::create_audit_entry(
+        let from_provider = record.provider.clone();
+        record.provider = to_provider.clone();
+        record.updated_at = env.ledger().timestamp();
+        env.storage().persistent().set(&key, &record);
+        extend_ttl_u64_key(&env, &key);
+        residency::reindex_record(&env, &from_provider, &to_provider, record_id);
+
+        let transfer = custody::append(
             &env,
-            patient.clone(),
-            patient.clone(),
-            None,
-            AccessAction::RevokeAccess,
-            AccessResult::Success,
-            None,
+            record_id,
+            from_provider,
+            to_provider,
+            authorizer,
+            reason,
         );
-        audit::add_audit_entry(&env, &audit_entry);
-        events::publish_audit_log_entry(&env, &audit_entry);
+        events::publish_custody_transferred(&env, &transfer);
+        events::publish_patient_custody_notice(&env, record.patient, &transfer);
+        Ok(transfer)
+    }
 
-        env.storage().persistent().set(&profile_key, &profile);
-        events::publish_profile_updated(&env, patient);
+    /// Every provider transfer of `record_id`, oldest first.
+    pub fn get_custody_chain(env: Env, record_id: u64) -> Vec<CustodyTransfer> {
+        custody::get_chain(&env, record_id)
+    }
 
-        Ok(())
+    /// True if the custody chain is intact and ends with the record's current
+    /// provider.
+    pub fn verify_custody_chain(env: Env, record_id: u64) -> Result<bool, ContractError> {
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        Ok(custody::verify(&env, record_id, &record.provider))
     }
 
-    /// Update emergency contact information
-    pub fn update_emergency_contact(
+    // ======================== Practice Closure Transfers ========================
+
+    /// Schedule the hand-over of a closing practice's patients to
+    /// `receiving_provider`. Every listed patient must hold at least one
+    /// record from the closing provider. Each is notified and may opt out
+    /// with `opt_out_of_transfer` until the opt-out deadline, after which
+    /// `execute_records_transfer` moves their records.
+    pub fn transfer_records(
         env: Env,
-        caller: Address,
-        patient: Address,
-        contact: Option<EmergencyContact>,
-    ) -> Result<(), ContractError> {
+        closing_provider: Address,
+        receiving_provider: Address,
+        patient_list: Vec<Address>,
+    ) -> Result<PracticeTransfer, ContractError> {
         circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
-        caller.require_auth();
+        closing_provider.require_auth();
+        let closing_provider = alias::resolve(&env, &closing_provider);
+        let receiving_provider = alias::resolve(&env, &receiving_provider);
 
-        // Only profile owner can update
-        if caller != patient {
-            return Self::unauthorized(&env, &caller, "update_emergency_contact", "profile_owner");
+        if !rbac::has_permission(&env, &closing_provider, &Permission::WriteRecord) {
+            return Self::unauthorized(
+                &env,
+                &closing_provider,
+                "transfer_records",
+                "permission:WriteRecord",
+            );
+        }
+        if !rbac::has_permission(&env, &receiving_provider, &Permission::WriteRecord) {
+            return Err(ContractError::InvalidRole);
+        }
+        Self::require_active_user(&env, &receiving_provider, "transfer_records")?;
+        if closing_provider == receiving_provider
+            || patient_list.is_empty()
+            || patient_list.len() > practice_transfer::MAX_TRANSFER_PATIENTS
+        {
+            return Err(ContractError::InvalidInput);
         }
 
-        let profile_key = (symbol_short!("PAT_PROF"), patient.clone());
-        let mut profile: PatientProfile = env
-            .storage()
-            .persistent()
-            .get(&profile_key)
-            .ok_or(ContractError::UserNotFound)?;
-
-        profile.emergency_contact = match contact {
-            Some(c) => OptionalEmergencyContact::Some(c),
-            None => OptionalEmergencyContact::None,
+        let mut patients = Vec::new(&env);
+        for patient in patient_list.iter() {
+            let patient = alias::resolve(&env, &patient);
+            if patients.contains(&patient) {
+                continue;
+            }
+            if Self::records_by_provider(&env, &patient, &closing_provider).is_empty() {
+                return Err(ContractError::InvalidInput);
+            }
+            patients.push_back(patient);
+        }
+        let now = env.ledger().timestamp();
+        let transfer = PracticeTransfer {
+            id: practice_transfer::next_id(&env),
+            closing_provider,
+            receiving_provider,
+            patients,
+            scheduled_at: now,
+            opt_out_deadline: now.saturating_add(practice_transfer::OPT_OUT_WINDOW_SECONDS),
+            executed_at: None,
+            records_moved: 0,
         };
-        profile.updated_at = env.ledger().timestamp();
-
-        env.storage().persistent().set(&profile_key, &profile);
-        events::publish_profile_updated(&env, patient);
-
-        Ok(())
+        practice_transfer::set(&env, &transfer);
+        for patient in transfer.patients.iter() {
+            events::publish_practice_transfer_scheduled(&env, &transfer, patient);
+        }
+        Ok(transfer)
     }
 
-    /// Update insurance information (hashed values only)
-    pub fn update_insurance(
+    /// Keep the patient's records with the closing provider rather than
+    /// moving them. Only possible before the opt-out deadline.
+    pub fn opt_out_of_transfer(
         env: Env,
-        caller: Address,
         patient: Address,
-        insurance_info: Option<InsuranceInfo>,
+        transfer_id: u64,
     ) -> Result<(), ContractError> {
         circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
-        caller.require_auth();
-
-        // Only profile owner can update
-        if caller != patient {
-            return Self::unauthorized(&env, &caller, "update_insurance", "profile_owner");
+        patient.require_auth();
+        let patient = alias::resolve(&env, &patient);
+        let transfer = practice_transfer::get(&env, transfer_id)
+            .filter(|t| t.patients.contains(&patient))
+            .ok_or(ContractError::PracticeTransferNotFound)?;
+        if transfer.executed_at.is_some() || env.ledger().timestamp() >= transfer.opt_out_deadline {
+            return Err(ContractError::TransferOptOutClosed);
         }
 
-        let profile_key = (symbol_short!("PAT_PROF"), patient.clone());
-        let mut profile: PatientProfile = env
-            .storage()
-            .persistent()
-            .get(&profile_key)
-            .ok_or(ContractError::UserNotFound)?;
-
-        profile.insurance_info = match insurance_info {
-            Some(info) => OptionalInsuranceInfo::Some(info),
-            None => OptionalInsuranceInfo::None,
-        };
-        profile.updated_at = env.ledger().timestamp();
-
-        env.storage().persistent().set(&profile_key, &profile);
-        events::publish_profile_updated(&env, patient);
-
+        practice_transfer::set_opted_out(&env, transfer_id, &patient);
+        events::publish_practice_transfer_opted_out(&env, transfer_id, patient);
         Ok(())
     }
 
-    /// Add medical history reference (IPFS hash or record ID)
-    pub fn add_medical_history_reference(
+    /// Carry out a practice transfer once its opt-out window has closed.
+    /// For every patient who did not opt out, records held by the closing
+    /// provider move to the receiving provider through the custody chain.
+    /// Where records moved, the closing provider leaves the patient's care
+    /// team, and the receiving provider joins it if the patient has given
+    /// them consent; otherwise the two establish the relationship with
+    /// `establish_care_relationship` as usual. Callable by the receiving
+    /// provider or a SystemAdmin.
+    pub fn execute_records_transfer(
         env: Env,
         caller: Address,
-        patient: Address,
-        reference: String,
-    ) -> Result<(), ContractError> {
+        transfer_id: u64,
+    ) -> Result<PracticeTransfer, ContractError> {
         circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
         caller.require_auth();
-
-        // Only profile owner can update
-        if caller != patient {
+        let mut transfer = practice_transfer::get(&env, transfer_id)
+            .ok_or(ContractError::PracticeTransferNotFound)?;
+        if alias::resolve(&env, &caller) != transfer.receiving_provider
+            && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
+        {
             return Self::unauthorized(
                 &env,
                 &caller,
-                "add_medical_history_reference",
-                "profile_owner",
+                "execute_records_transfer",
+                "receiving_provider_or_admin",
             );
         }
+        if transfer.executed_at.is_some() {
+            return Err(ContractError::PracticeTransferCompleted);
+        }
+        let now = env.ledger().timestamp();
+        if now < transfer.opt_out_deadline {
+            return Err(ContractError::TransferOptOutOpen);
+        }
 
-        let profile_key = (symbol_short!("PAT_PROF"), patient.clone());
-        let mut profile: PatientProfile = env
-            .storage()
-            .persistent()
-            .get(&profile_key)
-            .ok_or(ContractError::UserNotFound)?;
+        let reason = String::from_str(&env, "practice closure");
+        for patient in transfer.patients.iter() {
+            if practice_transfer::is_opted_out(&env, transfer_id, &patient) {
+                continue;
+            }
 
-        profile.medical_history_refs.push_back(reference);
-        profile.updated_at = env.ledger().timestamp();
+            let mut moved = 0u32;
+            for mut record in
+                Self::records_by_provider(&env, &patient, &transfer.closing_provider).iter()
+            {
+                let id = record.id;
+                let key = (symbol_short!("RECORD"), id);
+                record.provider = transfer.receiving_provider.clone();
+                record.updated_at = now;
+                env.storage().persistent().set(&key, &record);
+                extend_ttl_u64_key(&env, &key);
+
+                let custody = custody::append(
+                    &env,
+                    id,
+                    transfer.closing_provider.clone(),
+                    transfer.receiving_provider.clone(),
+                    caller.clone(),
+                    reason.clone(),
+                );
+                events::publish_custody_transferred(&env, &custody);
+                moved = moved.saturating_add(1);
+            }
+            if moved > 0 {
+                if let Some(mut rel) = care_team::get(&env, &patient, &transfer.closing_provider)
+                    .filter(|rel| rel.is_active())
+                {
+                    rel.ended_at = Some(now);
+                    care_team::set(&env, &rel);
+                    events::publish_care_relationship_changed(&env, &rel);
+                }
+                if !care_team::is_active(&env, &patient, &transfer.receiving_provider)
+                    && has_active_consent(&env, &patient, &transfer.receiving_provider)
+                {
+                    let rel = CareRelationship {
+                        patient: patient.clone(),
+                        provider: transfer.receiving_provider.clone(),
+                        established_at: now,
+                        ended_at: None,
+                    };
+                    care_team::set(&env, &rel);
+                    events::publish_care_relationship_changed(&env, &rel);
+                }
+            }
 
-        env.storage().persistent().set(&profile_key, &profile);
-        events::publish_profile_updated(&env, patient);
+            transfer.records_moved = transfer.records_moved.saturating_add(moved);
+            events::publish_patient_records_transferred(&env, &transfer, patient, moved);
+        }
 
-        Ok(())
+        transfer.executed_at = Some(now);
+        practice_transfer::set(&env, &transfer);
+        Ok(transfer)
     }
 
-    /// Get patient profile
-    pub fn get_profile(env: Env, patient: Address) -> Result<PatientProfile, ContractError> {
-        let profile_key = (symbol_short!("PAT_PROF"), patient);
-        env.storage()
-            .persistent()
-            .get(&profile_key)
-            .ok_or(ContractError::UserNotFound)
+    pub fn get_practice_transfer(
+        env: Env,
+        transfer_id: u64,
+    ) -> Result<PracticeTransfer, ContractError> {
+        practice_transfer::get(&env, transfer_id).ok_or(ContractError::PracticeTransferNotFound)
     }
 
-    /// Check if patient profile exists
-    pub fn profile_exists(env: Env, patient: Address) -> bool {
-        let profile_key = (symbol_short!("PAT_PROF"), patient);
-        env.storage().persistent().has(&profile_key)
+    /// True if `patient` opted out of practice transfer `transfer_id`.
+    pub fn is_transfer_opted_out(env: Env, transfer_id: u64, patient: Address) -> bool {
+        practice_transfer::is_opted_out(&env, transfer_id, &alias::resolve(&env, &patient))
     }
 
-    /// Grants a custom permission to a user.
-    /// Requires the caller to have ManageUsers permission.
-    pub fn grant_custom_permission(
-        env: Env,
-        caller: Address,
-        user: Address,
-        permission: Permission,
-    ) -> Result<(), ContractError> {
-        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
-        caller.require_auth();
-        // Unified check: covers direct role, custom grants, and delegated roles
-        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
-            return Self::unauthorized(
-                &env,
-                &caller,
-                "grant_custom_permission",
-                "permission:ManageUsers",
-            );
+    /// The patient's records currently held by `provider`.
+    fn records_by_provider(env: &Env, patient: &Address, provider: &Address) -> Vec<VisionRecord> {
+        let record_ids: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("PAT_REC"), patient.clone()))
+            .unwrap_or(Vec::new(env));
+        let mut out = Vec::new(env);
+        for id in record_ids.iter() {
+            if let Some(record) = env
+                .storage()
+                .persistent()
+                .get::<_, VisionRecord>(&(symbol_short!("RECORD"), id))
+            {
+                if record.provider == *provider {
+                    out.push_back(record);
+                }
+            }
         }
-        rbac::grant_custom_permission(&env, user, permission)
-            .map_err(|_| ContractError::UserNotFound)?;
-        Ok(())
+        out
     }
 
-    /// Revokes a custom permission from a user.
-    /// Requires the caller to have ManageUsers permission.
-    pub fn revoke_custom_permission(
+    // ======================== Data Availability Challenges ========================
+
+    /// Challenge the custodian of `record_id` to show they still hold the
+    /// off-chain payload behind its `data_hash`. Anyone may challenge. While
+    /// a challenge is open and within its window, it is returned instead of
+    /// opening another.
+    pub fn open_data_challenge(
         env: Env,
-        caller: Address,
-        user: Address,
-        permission: Permission,
-    ) -> Result<(), ContractError> {
+        challenger: Address,
+        record_id: u64,
+    ) -> Result<u64, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        challenger.require_auth();
+
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+
+        let now = env.ledger().timestamp();
+        let mut after_lapse = false;
+        if let Some(previous) = data_challenge::latest_for_record(&env, record_id) {
+            if previous.status == DataChallengeStatus::Open {
+                if !previous.is_lapsed(now) {
+                    return Ok(previous.id);
+                }
+                after_lapse = true;
+            }
+        }
+
+        let id = data_challenge::increment_counter(&env);
+        let challenge = DataChallenge {
+            id,
+            record_id,
+            data_hash: record.data_hash,
+            challenger,
+            nonce: data_challenge::derive_nonce(&env, id, record_id),
+            opened_at: now,
+            respond_by: now.saturating_add(data_challenge::RESPONSE_WINDOW_SECONDS),
+            after_lapse,
+            status: DataChallengeStatus::Open,
+            custodian: None,
+            attestation: None,
+            responded_at: None,
+        };
+        data_challenge::create(&env, &challenge);
+        events::publish_data_challenge_opened(&env, &challenge, &record.provider);
+        Ok(id)
+    }
+
+    /// Answer an open challenge as the record's current provider, either with
+    /// a signed possession attestation or a zk proof of possession.
+    pub fn respond_data_challenge(
+        env: Env,
+        custodian: Address,
+        challenge_id: u64,
+        response: DataChallengeResponse,
+    ) -> Result<DataChallenge, ContractError> {
         circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
-        caller.require_auth();
-        // Unified check: covers direct role, custom grants, and delegated roles
-        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+        custodian.require_auth();
+
+        let mut challenge = data_challenge::get(&env, challenge_id)
+            .ok_or(ContractError::DataChallengeNotFound)?;
+        let record: VisionRecord = env
+            .storage()
+            .persistent()
+            .get(&(symbol_short!("RECORD"), challenge.record_id))
+            .ok_or(ContractError::RecordNotFound)?;
+        if custodian != record.provider {
             return Self::unauthorized(
                 &env,
-                &caller,
-                "revoke_custom_permission",
-                "permission:ManageUsers",
+                &custodian,
+                "respond_data_challenge",
+                "record_provider",
             );
         }
-        rbac::revoke_custom_permission(&env, user, permission)
-            .map_err(|_| ContractError::UserNotFound)?;
-        Ok(())
-    }
+        let now = env.ledger().timestamp();
+        if challenge.status != DataChallengeStatus::Open || challenge.is_lapsed(now) {
+            return Err(ContractError::DataChallengeClosed);
+        }
 
-    /// Delegates a role to another user with an expiration timestamp.
-    /// The delegator must authenticate the transaction.
-    pub fn delegate_role(
-        env: Env,
-        delegator: Address,
-        delegatee: Address,
-        role: Role,
-        expires_at: u64,
-    ) -> Result<(), ContractError> {
-        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
-        delegator.require_auth();
-        rbac::delegate_role(&env, delegator, delegatee, role, expires_at);
-        Ok(())
+        match response {
+            DataChallengeResponse::Attestation(digest) => {
+                challenge.status = DataChallengeStatus::Attested;
+                challenge.attestation = Some(digest);
+            }
+            DataChallengeResponse::ZkProof => {
+                let verifier = registration_gate::get_zk_verifier(&env)
+                    .ok_or(ContractError::InvalidAttestation)?;
+                let client = registration_gate::ZkVerifierClient::new(&env, &verifier);
+                if !client.consume_receipt(
+                    &env.current_contract_address(),
+                    &custodian,
+                    &challenge.nonce,
+                    &data_challenge::RESPONSE_WINDOW_SECONDS,
+                ) {
+                    return Err(ContractError::InvalidAttestation);
+                }
+                challenge.status = DataChallengeStatus::Proven;
+            }
+        }
+        challenge.custodian = Some(custodian);
+        challenge.responded_at = Some(now);
+        data_challenge::save(&env, &challenge);
+        events::publish_data_challenge_answered(&env, &challenge);
+        Ok(challenge)
     }
 
-    /// Pauses contract operations for a given scope.
-    pub fn pause_contract(
-        env: Env,
-        caller: Address,
-        scope: circuit_breaker::PauseScope,
-    ) -> Result<(), ContractError> {
-        caller.require_auth();
-        circuit_breaker::pause_contract(&env, &caller, scope)
+    pub fn get_data_challenge(env: Env, challenge_id: u64) -> Result<DataChallenge, ContractError> {
+        data_challenge::get(&env, challenge_id).ok_or(ContractError::DataChallengeNotFound)
     }
 
-    /// Resumes contract operations for a given scope.
-    pub fn resume_contract(
-        env: Env,
-        caller: Address,
-        scope: circuit_breaker::PauseScope,
-    ) -> Result<(), ContractError> {
-        caller.require_auth();
-        circuit_breaker::resume_contract(&env, &caller, scope)
+    /// Every challenge raised against `record_id`, oldest first.
+    pub fn get_record_data_challenges(env: Env, record_id: u64) -> Vec<DataChallenge> {
+        let mut out = Vec::new(&env);
+        for id in data_challenge::get_record_ids(&env, record_id).iter() {
+            if let Some(challenge) = data_challenge::get(&env, id) {
+                out.push_back(challenge);
+            }
+        }
+        out
     }
 
-    /// Creates an ACL group.
-    pub fn create_acl_group(
-        env: Env,
-        caller: Address,
-        group_name: String,
-        permissions: Vec<Permission>,
-    ) -> Result<(), ContractError> {
-        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
-        caller.require_auth();
-        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
-            return Self::unauthorized(&env, &caller, "create_acl_group", "permission:ManageUsers");
-        }
-        rbac::create_group(&env, group_name, permissions);
-        Ok(())
+    /// `Unverified` once a challenge on the record goes unanswered past its
+    /// window, until the custodian answers a later one.
+    pub fn get_record_availability(env: Env, record_id: u64) -> DataAvailability {
+        data_challenge::availability(&env, record_id)
     }
 
-    /// Adds a user to an ACL group.
-    pub fn add_user_to_group(
+    // ======================== Consent Re-collection ========================
+
+    /// Start re-collecting consent of `consent_types` under new terms. After
+    /// `deadline`, grants resting on a consent of those types given before
+    /// now are held to `downgrade_to` (`Read` or `None`) for every patient
+    /// who has not accepted the new terms.
+    pub fn create_consent_campaign(
         env: Env,
         caller: Address,
-        user: Address,
-        group_name: String,
-    ) -> Result<(), ContractError> {
-        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        terms_hash: BytesN<32>,
+        consent_types: Vec<ConsentType>,
+        deadline: u64,
+        downgrade_to: AccessLevel,
+    ) -> Result<u64, ContractError> {
         caller.require_auth();
-        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
             return Self::unauthorized(
                 &env,
                 &caller,
-                "add_user_to_group",
-                "permission:ManageUsers",
+                "create_consent_campaign",
+                "permission:SystemAdmin",
             );
         }
-        rbac::add_to_group(&env, user, group_name).map_err(|_| ContractError::InvalidInput)
+        let now = env.ledger().timestamp();
+        if consent_types.is_empty() || deadline <= now {
+            return Err(ContractError::InvalidInput);
+        }
+        if !matches!(downgrade_to, AccessLevel::None | AccessLevel::Read) {
+            return Err(ContractError::InvalidInput);
+        }
+        if consent_campaign::get_open_ids(&env).len() >= consent_campaign::MAX_OPEN_CAMPAIGNS {
+            return Err(ContractError::InvalidInput);
+        }
+
+        let campaign = ConsentCampaign {
+            id: consent_campaign::increment_counter(&env),
+            terms_hash,
+            consent_types,
+            deadline,
+            downgrade_to,
+            created_by: caller.clone(),
+            created_at: now,
+            closed_at: None,
+            accepted: 0,
+            declined: 0,
+        };
+        consent_campaign::save(&env, &campaign);
+        consent_campaign::add_open(&env, campaign.id);
+        admin_receipt::issue(&env, &caller, symbol_short!("CMP_NEW"), None);
+        events::publish_consent_campaign_created(&env, &campaign);
+        Ok(campaign.id)
     }
 
-    /// Removes a user from an ACL group.
-    pub fn remove_user_from_group(
+    /// Stop enforcing a campaign, e.g. once a later one supersedes it.
+    /// Responses and counts are kept for reporting.
+    pub fn close_consent_campaign(
         env: Env,
         caller: Address,
-        user: Address,
-        group_name: String,
+        campaign_id: u64,
     ) -> Result<(), ContractError> {
-        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
         caller.require_auth();
-        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
+
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
             return Self::unauthorized(
                 &env,
                 &caller,
-                "remove_user_from_group",
-                "permission:ManageUsers",
+                "close_consent_campaign",
+                "permission:SystemAdmin",
             );
         }
-        rbac::remove_from_group(&env, user, group_name);
+        let mut campaign = consent_campaign::get(&env, campaign_id)
+            .ok_or(ContractError::ConsentCampaignNotFound)?;
+        if campaign.closed_at.is_some() {
+            return Err(ContractError::ConsentCampaignClosed);
+        }
+        campaign.closed_at = Some(env.ledger().timestamp());
+        consent_campaign::save(&env, &campaign);
+        consent_campaign::remove_open(&env, campaign_id);
+        admin_receipt::issue(&env, &caller, symbol_short!("CMP_CLS"), None);
         Ok(())
     }
 
-    /// Returns all ACL groups assigned to a user.
-    pub fn get_user_groups(env: Env, user: Address) -> Vec<String> {
-        env.storage()
-            .persistent()
-            .get(&rbac::user_groups_key(&user))
-            .unwrap_or(Vec::new(&env))
-    }
-
-    /// Checks if a user has a specific permission.
-    /// Returns true if the user has the permission, false otherwise.
-    pub fn check_permission(env: Env, user: Address, permission: Permission) -> bool {
-        rbac::has_permission(&env, &user, &permission)
-    }
-
-    /// Create an access policy with ABAC attributes
-    pub fn create_access_policy(
+    /// Accept or decline a campaign's new terms. A patient may change their
+    /// answer while the campaign is open; declining lapses the affected
+    /// consents immediately.
+    pub fn respond_consent_campaign(
         env: Env,
-        caller: Address,
-        policy_id: String,
-        name: String,
-        required_role: Option<Role>,
-        time_restriction: TimeRestriction,
-        required_credential: CredentialType,
-        min_sensitivity_level: SensitivityLevel,
-        consent_required: bool,
+        patient: Address,
+        campaign_id: u64,
+        accept: bool,
     ) -> Result<(), ContractError> {
-        caller.require_auth();
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        patient.require_auth();
+        let patient = alias::resolve(&env, &patient);
 
-        // Only SystemAdmin can create policies
-        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
-            return Err(ContractError::Unauthorized);
+        let mut campaign = consent_campaign::get(&env, campaign_id)
+            .ok_or(ContractError::ConsentCampaignNotFound)?;
+        if campaign.closed_at.is_some() {
+            return Err(ContractError::ConsentCampaignClosed);
         }
 
-        let conditions = rbac::PolicyConditions {
-            required_role,
-            time_restriction,
-            required_credential,
-            min_sensitivity_level,
-            consent_required,
+        let status = if accept {
+            ReconsentStatus::Accepted
+        } else {
+            ReconsentStatus::Declined
         };
+        let previous = consent_campaign::get_status(&env, campaign_id, &patient);
+        if previous == status {
+            return Ok(());
+        }
+        match previous {
+            ReconsentStatus::Accepted => campaign.accepted = campaign.accepted.saturating_sub(1),
+            ReconsentStatus::Declined => campaign.declined = campaign.declined.saturating_sub(1),
+            ReconsentStatus::Pending => {}
+        }
+        if accept {
+            campaign.accepted = campaign.accepted.saturating_add(1);
+        } else {
+            campaign.declined = campaign.declined.saturating_add(1);
+        }
 
-        let policy = rbac::AccessPolicy {
-            id: policy_id.clone(),
-            name,
-            conditions,
-            enabled: true,
-        };
+        consent_campaign::set_status(&env, campaign_id, &patient, &status);
+        consent_campaign::save(&env, &campaign);
+        events::publish_reconsent(&env, campaign_id, &patient, &status);
+        Ok(())
+    }
 
-        rbac::create_access_policy(&env, policy);
-        events::publish_policy_created(&env, policy_id, caller);
+    pub fn get_consent_campaign(
+        env: Env,
+        campaign_id: u64,
+    ) -> Result<ConsentCampaign, ContractError> {
+        consent_campaign::get(&env, campaign_id).ok_or(ContractError::ConsentCampaignNotFound)
+    }
 
-        Ok(())
+    /// Campaigns currently enforced, oldest first.
+    pub fn get_open_consent_campaigns(env: Env) -> Vec<ConsentCampaign> {
+        let mut out = Vec::new(&env);
+        for id in consent_campaign::get_open_ids(&env).iter() {
+            if let Some(campaign) = consent_campaign::get(&env, id) {
+                out.push_back(campaign);
+            }
+        }
+        out
     }
 
-    /// Set credential type for a user
-    pub fn set_user_credential(
+    pub fn get_reconsent_status(env: Env, campaign_id: u64, patient: Address) -> ReconsentStatus {
+        consent_campaign::get_status(&env, campaign_id, &alias::resolve(&env, &patient))
+    }
+
+    /// Response counts and deadline state for compliance reporting.
+    pub fn get_consent_campaign_progress(
+        env: Env,
+        campaign_id: u64,
+    ) -> Result<CampaignProgress, ContractError> {
+        let campaign = consent_campaign::get(&env, campaign_id)
+            .ok_or(ContractError::ConsentCampaignNotFound)?;
+        Ok(consent_campaign::progress(&env, &campaign))
+    }
+
+    // ======================== Organization Quotas ========================
+
+    /// Limit organization `org_id` to `records_per_period` new records every
+    /// `period_seconds`. Usage resets automatically when a period ends.
+    pub fn set_org_quota(
         env: Env,
         caller: Address,
-        user: Address,
-        credential: CredentialType,
+        org_id: u64,
+        records_per_period: u32,
+        period_seconds: u64,
     ) -> Result<(), ContractError> {
         caller.require_auth();
-
-        // Only SystemAdmin can set credentials
         if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
-            return Err(ContractError::Unauthorized);
+            return Self::unauthorized(&env, &caller, "set_org_quota", "permission:SystemAdmin");
+        }
+        if period_seconds == 0 {
+            return Err(ContractError::InvalidInput);
         }
 
-        rbac::set_user_credential(&env, user.clone(), credential);
-        events::publish_credential_set(&env, user, credential, caller);
-
+        let quota = OrgQuota {
+            org_id,
+            records_per_period,
+            period_seconds,
+            updated_by: caller.clone(),
+            updated_at: env.ledger().timestamp(),
+        };
+        org_quota::set_quota(&env, &quota);
+        admin_receipt::issue(&env, &caller, symbol_short!("QTA_SET"), None);
+        events::publish_org_quota_set(&env, &quota, &org_quota::get_usage(&env, org_id));
         Ok(())
     }
 
-    /// Set sensitivity level for a record
-    pub fn set_record_sensitivity(
+    pub fn get_org_quota(env: Env, org_id: u64) -> Option<OrgQuota> {
+        org_quota::get_quota(&env, org_id)
+    }
+
+    /// Configure the billing contract allowed to credit purchased quota.
+    pub fn set_billing_contract(
         env: Env,
         caller: Address,
-        record_id: u64,
-        sensitivity: SensitivityLevel,
+        billing: Address,
     ) -> Result<(), ContractError> {
         caller.require_auth();
-
-        // Only record provider or SystemAdmin can set sensitivity
-        let record_key = (symbol_short!("RECORD"), record_id);
-        let record: VisionRecord = env.storage().persistent().get(&record_key)
-            .ok_or(ContractError::RecordNotFound)?;
-
-        let has_perm = caller == record.provider || rbac::has_permission(&env, &caller, &Permission::SystemAdmin);
-        if !has_perm {
-            return Err(ContractError::Unauthorized);
+        if !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
+            return Self::unauthorized(
+                &env,
+                &caller,
+                "set_billing_contract",
+                "permission:SystemAdmin",
+            );
         }
+        org_quota::set_billing_contract(&env, &billing);
+        admin_receipt::issue(&env, &caller, symbol_short!("BIL_SET"), Some(billing));
+        Ok(())
+    }
 
-        rbac::set_record_sensitivity(&env, record_id, sensitivity.clone());
-        events::publish_sensitivity_set(&env, record_id, sensitivity, caller);
+    pub fn get_billing_contract(env: Env) -> Option<Address> {
+        org_quota::get_billing_contract(&env)
+    }
 
-        Ok(())
+    /// Credit `records` purchased writes to `org_id`. Only callable by the
+    /// configured billing contract once payment has cleared.
+    pub fn purchase_org_quota(
+        env: Env,
+        billing: Address,
+        org_id: u64,
+        records: u32,
+    ) -> Result<OrgQuotaUsage, ContractError> {
+        circuit_breaker::require_not_paused(&env, &circuit_breaker::PauseScope::Global)?;
+        billing.require_auth();
+        if org_quota::get_billing_contract(&env) != Some(billing.clone()) {
+            return Self::unauthorized(&env, &billing, "purchase_org_quota", "billing_contract");
+        }
+        if records == 0 {
+            return Err(ContractError::InvalidInput);
+        }
+        let usage = org_quota::add_purchased(&env, org_id, records);
+        events::publish_org_quota_purchased(&env, &usage, records);
+        Ok(usage)
     }
 
-    /// Check access for a specific record with ABAC evaluation
-    pub fn check_record_access(
+    /// Current-period usage for `org_id`. Visible to SystemAdmins and to the
+    /// organization's own providers.
+    pub fn get_org_quota_usage(
         env: Env,
         caller: Address,
-        record_id: u64,
-    ) -> Result<AccessLevel, ContractError> {
+        org_id: u64,
+    ) -> Result<OrgQuotaUsage, ContractError> {
         caller.require_auth();
-
-        let record_key = (symbol_short!("RECORD"), record_id);
-        let record: VisionRecord = env.storage().persistent().get(&record_key)
-            .ok_or(ContractError::RecordNotFound)?;
-
-        // Check if caller is patient or provider (always allowed)
-        if caller == record.patient || caller == record.provider {
-            return Ok(AccessLevel::Full);
+        if residency::get_provider_org(&env, &caller) != Some(org_id)
+            && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin)
+        {
+            return Self::unauthorized(&env, &caller, "get_org_quota_usage", "org_member_or_admin");
         }
+        Ok(org_quota::get_usage(&env, org_id))
+    }
+}
 
-        // Check traditional access grants
-        let traditional_access = Self::check_access(env.clone(), record.patient.clone(), caller.clone());
-        if traditional_access != AccessLevel::None {
-            return Ok(traditional_access);
-        }
+#[cfg(test)]
+mod test_utils;
 
-        // Check ABAC policies for this specific record
-        let abac_allowed = evaluate_access_policies(&env, &caller, Some(record_id), Some(record.patient));
-        if abac_allowed {
-            return Ok(AccessLevel::Read);
-        }
+#[cfg(test)]
+mod test;
 
-        Ok(AccessLevel::None)
-    }
+#[cfg(test)]
+mod test_pause;
+#[cfg(test)]
+mod test_rbac;
 
-    /// Prepare phase for register_user operation
-    pub fn prepare_register_user(
-        env: Env,
-        caller: Address,
-        user: Address,
-        role: Role,
-        name: String,
-    ) -> Result<(), ContractError> {
-        // Validate all inputs without making state changes
-        circuit_breaker::require_not_paused(
-            &env,
-            &circuit_breaker::PauseScope::Function(symbol_short!("REG_USR")),
-        )?;
+#[cfg(test)]
+mod test_batch;
 
-        if !whitelist::check_whitelist_access(&env, &caller) {
-            return Err(ContractError::Unauthorized);
-        }
+#[cfg(test)]
+mod test_admin_tiers;
 
-        if !rbac::has_permission(&env, &caller, &Permission::ManageUsers) {
-            return Err(ContractError::Unauthorized);
-        }
+#[cfg(test)]
+mod test_occ;
 
-        validation::validate_name(&name)?;
+#[cfg(test)]
+mod test_retention;
 
-        // Check if user already exists
-        let key = (symbol_short!("USER"), user.clone());
-        if env.storage().persistent().get::<_, User>(&key).is_some() {
-            return Err(ContractError::UserAlreadyExists);
-        }
+#[cfg(test)]
+mod test_eligibility;
 
-        // Store temporary preparation data
-        let prep_key = (symbol_short!("PREP_REG_USER"), user.clone());
-        let prep_data = PrepareUserRegistration {
-            caller: caller.clone(),
-            user: user.clone(),
-            role: role.clone(),
-            name: name.clone(),
-            timestamp: env.ledger().timestamp(),
-        };
-        env.storage().temporary().set(&prep_key, &prep_data);
+#[cfg(test)]
+mod test_emergency_scope;
 
-        Ok(())
-    }
+#[cfg(test)]
+mod test_diagnosis_index;
 
-    /// Commit phase for register_user operation
-    pub fn commit_register_user(
-        env: Env,
-        caller: Address,
-        user: Address,
-        role: Role,
-        name: String,
-    ) -> Result<(), ContractError> {
-        // Retrieve preparation data
-        let prep_key = (symbol_short!("PREP_REG_USER"), user.clone());
-        let prep_data: PrepareUserRegistration = env.storage().temporary().get(&prep_key)
-            .ok_or(ContractError::InvalidPhase)?;
+#[cfg(test)]
+mod test_adverse_event;
 
-        // Verify preparation data matches commit parameters
-        if prep_data.caller != caller || prep_data.user != user || 
-           prep_data.role != role || prep_data.name != name {
-            return Err(ContractError::InvalidPhase);
-        }
+#[cfg(test)]
+mod test_legal_hold;
 
-        // Execute the actual registration
-        let user_data = User {
-            address: user.clone(),
-            role: role.clone(),
-            name: name.clone(),
-            registered_at: env.ledger().timestamp(),
-            is_active: true,
-        };
+#[cfg(test)]
+mod test_prescription_canonical;
 
-        let key = (symbol_short!("USER"), user.clone());
-        env.storage().persistent().set(&key, &user_data);
-        extend_ttl_address_key(&env, &key);
-        rbac::assign_role(&env, user.clone(), role.clone(), 0);
+#[cfg(test)]
+mod test_prescription_lens;
 
-        // Clean up preparation data
-        env.storage().temporary().remove(&prep_key);
+#[cfg(test)]
+mod test_emergency_contacts;
 
-        events::publish_user_registered(&env, user, role, name);
+#[cfg(test)]
+mod test_locum;
 
-        Ok(())
-    }
+#[cfg(test)]
+mod test_merge;
 
-    /// Rollback for register_user operation
-    pub fn rollback_register_user(
-        env: Env,
-        user: Address,
-        _role: Role,
-        _name: String,
-    ) -> Result<(), ContractError> {
-        // Clean up preparation data
-        let prep_key = (symbol_short!("PREP_REG_USER"), user.clone());
-        env.storage().temporary().remove(&prep_key);
+#[cfg(test)]
+mod test_alias;
 
-        Ok(())
-    }
+#[cfg(test)]
+mod test_registration_gate;
 
-    /// Prepare phase for add_record operation
-    pub fn prepare_add_record(
-        env: Env,
-        caller: Address,
-        patient: Address,
-        provider: Address,
-        record_type: RecordType,
-        data_hash: String,
-    ) -> Result<(), ContractError> {
-        // Validate all inputs without making state changes
-        circuit_breaker::require_not_paused(
-            &env,
-            &circuit_breaker::PauseScope::Function(symbol_short!("ADD_REC")),
-        )?;
+#[cfg(test)]
+mod test_audit_stream;
 
-        if !whitelist::check_whitelist_access(&env, &caller) {
-            return Err(ContractError::Unauthorized);
-        }
+#[cfg(test)]
+mod test_meta_tx;
 
-        // Verify provider permissions
-        if !rbac::has_permission(&env, &provider, &Permission::CreateRecords) {
-            return Err(ContractError::Unauthorized);
-        }
+#[cfg(test)]
+mod test_access_decision;
 
-        // Validate inputs
-        validation::validate_address(&patient)?;
-        validation::validate_address(&provider)?;
-        validation::validate_hash(&data_hash)?;
+#[cfg(test)]
+mod test_bulk_grants;
 
-        // Store temporary preparation data
-        let prep_key = (symbol_short!("PREP_ADD_REC"), provider.clone(), env.ledger().timestamp());
-        let prep_data = PrepareRecordAddition {
-            caller: caller.clone(),
-            patient: patient.clone(),
-            provider: provider.clone(),
-            record_type: record_type.clone(),
-            data_hash: data_hash.clone(),
-            timestamp: env.ledger().timestamp(),
-        };
-        env.storage().temporary().set(&prep_key, &prep_data);
+#[cfg(test)]
+mod test_access_window;
 
-        Ok(())
-    }
+#[cfg(test)]
+mod test_rx_share;
 
-    /// Commit phase for add_record operation
-    pub fn commit_add_record(
-        env: Env,
-        caller: Address,
-        patient: Address,
-        provider: Address,
-        record_type: RecordType,
-        data_hash: String,
-    ) -> Result<u64, ContractError> {
-        // Retrieve preparation data using timestamp lookup
-        let prep_keys = env.storage().temporary().range::<_, PrepareRecordAddition>();
-        let mut prep_data: Option<PrepareRecordAddition> = None;
-        let mut prep_key_to_remove: Option<(Symbol, Address, u64)> = None;
+#[cfg(test)]
+mod test_prescription_expiry;
 
-        for key in prep_keys {
-            if let Ok((_, data)) = key {
-                if data.caller == caller && data.patient == patient && 
-                   data.provider == provider && data.record_type == record_type && 
-                   data.data_hash == data_hash {
-                    prep_data = Some(data);
-                    prep_key_to_remove = Some(key);
-                    break;
-                }
-            }
-        }
+#[cfg(test)]
+mod test_emergency_records;
 
-        let prep_data = prep_data.ok_or(ContractError::InvalidPhase)?;
+#[cfg(test)]
+mod test_anomaly;
 
-        // Execute the actual record addition
-        let record_id = increment_record_counter(&env);
-        let record = VisionRecord {
-            id: record_id,
-            patient: patient.clone(),
-            provider: provider.clone(),
-            record_type: record_type.clone(),
-            data_hash: data_hash.clone(),
-            created_at: env.ledger().timestamp(),
-            updated_at: env.ledger().timestamp(),
-            access_count: 0,
-        };
+#[cfg(test)]
+mod test_admin_receipt;
 
-        let key = (symbol_short!("RECORD"), record_id);
-        env.storage().persistent().set(&key, &record);
-        extend_ttl_record_key(&env, &key);
+#[cfg(test)]
+mod test_properties;
 
-        // Clean up preparation data
-        if let Some(key) = prep_key_to_remove {
-            env.storage().temporary().remove(&key);
-        }
+#[cfg(test)]
+mod test_budget;
 
-        events::publish_record_added(&env, record_id, patient, provider, record_type);
+#[cfg(test)]
+mod test_custom_record_types;
 
-        Ok(record_id)
-    }
+#[cfg(test)]
+mod test_cohort;
 
-    /// Rollback for add_record operation
-    pub fn rollback_add_record(
-        env: Env,
-        _caller: Address,
-        _patient: Address,
-        provider: Address,
-        _record_type: RecordType,
-        _data_hash: String,
-    ) -> Result<(), ContractError> {
-        // Clean up preparation data for this provider
-        let prep_keys = env.storage().temporary().range::<_, PrepareRecordAddition>();
-        for key in prep_keys {
-            if let Ok((_, data)) = key {
-                if data.provider == provider {
-                    env.storage().temporary().remove(&key);
-                }
-            }
-        }
+#[cfg(test)]
+mod test_snapshot;
 
-        Ok(())
-    }
+#[cfg(test)]
+mod test_event_redaction;
 
-    // ======================== Two-Phase Commit Hooks ========================
+#[cfg(test)]
+mod test_responder_directory;
 
-    /// Prepare phase for adding a vision record
-    pub fn prepare_add_record(
-        env: Env,
-        caller: Address,
-        patient: Address,
-        provider: Address,
-        record_type: RecordType,
-        data_hash: String,
-    ) -> Result<u64, ContractError> {
-        // Validate all inputs without making state changes
-        circuit_breaker::require_not_paused(
-            &env,
-            &circuit_breaker::PauseScope::Function(symbol_short!("ADD_REC")),
-        )?;
+#[cfg(test)]
+mod test_grant_restore;
 
-        if !whitelist::check_whitelist_access(&env, &caller) {
-            return Err(ContractError::Unauthorized);
-        }
+#[cfg(test)]
+mod test_agreements;
 
-        Self::enforce_rate_limit(&env, &caller)?;
-        validation::validate_data_hash(&data_hash)?;
+#[cfg(test)]
+mod test_availability;
 
-        // Check permissions
-        let has_perm = if caller == provider {
-            rbac::has_permission(&env, &caller, &Permission::WriteRecord)
-        } else {
-            rbac::has_delegated_permission(&env, &provider, &caller, &Permission::WriteRecord)
-        };
+#[cfg(test)]
+mod test_residency;
 
-        if !has_perm && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
-            return Err(ContractError::Unauthorized);
-        }
+#[cfg(test)]
+mod test_disclosure_tiers;
 
-        // Generate and return the record ID that will be used
-        let counter_key = symbol_short!("REC_CTR");
-        let record_id: u64 = env
-            .storage()
-            .instance()
-            .get(&counter_key)
-            .unwrap_or(0u64)
-            .saturating_add(1u64);
+#[cfg(test)]
+mod test_custody;
 
-        // Store preparation data temporarily
-        let prep_key = (symbol_short!("PREP_ADD_REC"), record_id);
-        let prep_data = PrepareAddRecord {
-            caller,
-            patient,
-            provider,
-            record_type,
-            data_hash,
-            timestamp: env.ledger().timestamp(),
-        };
-        env.storage().temporary().set(&prep_key, &prep_data);
+#[cfg(test)]
+mod test_org_quota;
 
-        Ok(record_id)
-    }
+#[cfg(test)]
+mod test_emergency_ack;
 
-    /// Commit phase for adding a vision record
-    pub fn commit_add_record(
-        env: Env,
-        record_id: u64,
-    ) -> Result<(), ContractError> {
-        // Retrieve preparation data
-        let prep_key = (symbol_short!("PREP_ADD_REC"), record_id);
-        let prep_data: PrepareAddRecord = env.storage().temporary()
-            .get(&prep_key)
-            .ok_or(ContractError::InvalidInput)?;
+#[cfg(test)]
+mod test_data_challenge;
 
-        // Update the counter
-        let counter_key = symbol_short!("REC_CTR");
-        env.storage().instance().set(&counter_key, &record_id);
+#[cfg(test)]
+mod test_consent_campaign;
 
-        // Create the actual record
-        let record = VisionRecord {
-            id: record_id,
-            patient: prep_data.patient.clone(),
-            provider: prep_data.provider.clone(),
-            record_type: prep_data.record_type.clone(),
-            data_hash: prep_data.data_hash.clone(),
-            created_at: prep_data.timestamp,
-            updated_at: prep_data.timestamp,
-        };
+#[cfg(test)]
+mod test_rx_coverage;
 
-        // Store the record
-        let key = (symbol_short!("RECORD"), record_id);
-        env.storage().persistent().set(&key, &record);
-        extend_ttl_u64_key(&env, &key);
+#[cfg(test)]
+mod test_state_proof;
 
-        // Add to patient's record list
-        let patient_key = (symbol_short!("PAT_REC"), prep_data.patient.clone());
-        let mut patient_records: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&patient_key)
-            .unwrap_or(Vec::new(&env));
-        patient_records.push_back(record_id);
-        env.storage()
-            .persistent()
-            .set(&patient_key, &patient_records);
-        extend_ttl_address_key(&env, &patient_key);
+#[cfg(test)]
+mod test_sensitivity;
 
-        // Clean up preparation data
-        env.storage().temporary().remove(&prep_key);
+#[cfg(test)]
+mod test_invariants;
 
-        Ok(())
-    }
+#[cfg(test)]
+mod test_user_name;
 
-    /// Rollback phase for adding a vision record
-    pub fn rollback_add_record(
-        env: Env,
-        record_id: u64,
-    ) -> Result<(), ContractError> {
-        // Clean up preparation data
-        let prep_key = (symbol_short!("PREP_ADD_REC"), record_id);
-        env.storage().temporary().remove(&prep_key);
+#[cfg(test)]
+mod test_print_auth;
 
-        Ok(())
-    }
+#[cfg(test)]
+mod test_feature_flags;
 
-    /// Prepare phase for granting access
-    pub fn prepare_grant_access(
-        env: Env,
-        caller: Address,
-        patient: Address,
-        grantee: Address,
-        access_level: AccessLevel,
-        expires_at: Option<u64>,
-    ) -> Result<(), ContractError> {
-        // Validate inputs without state changes
-        if caller != patient && !rbac::has_permission(&env, &caller, &Permission::SystemAdmin) {
-            return Err(ContractError::Unauthorized);
-        }
+#[cfg(test)]
+mod test_provider_approval;
 
-        if access_level == AccessLevel::None {
-            return Err(ContractError::InvalidInput);
-        }
+#[cfg(test)]
+mod test_amendment;
 
-        // Store preparation data
-        let prep_key = (symbol_short!("PREP_GRANT_ACC"), patient.clone(), grantee.clone());
-        let prep_data = PrepareGrantAccess {
-            caller,
-            patient,
-            grantee,
-            access_level,
-            expires_at,
-            timestamp: env.ledger().timestamp(),
-        };
-        env.storage().temporary().set(&prep_key, &prep_data);
+#[cfg(test)]
+mod test_record_filter;
 
-        Ok(())
-    }
+#[cfg(test)]
+mod test_read_record;
 
-    /// Commit phase for granting access
-    pub fn commit_grant_access(
-        env: Env,
-        patient: Address,
-        grantee: Address,
-    ) -> Result<(), ContractError> {
-        // Retrieve preparation data
-        let prep_key = (symbol_short!("PREP_GRANT_ACC"), patient.clone(), grantee.clone());
-        let prep_data: PrepareGrantAccess = env.storage().temporary()
-            .get(&prep_key)
-            .ok_or(ContractError::InvalidInput)?;
+#[cfg(test)]
+mod test_lifecycle_events;
 
-        // Create the access grant
-        let grant = AccessGrant {
-            patient: prep_data.patient.clone(),
-            grantee: prep_data.grantee.clone(),
-            level: prep_data.access_level,
-            granted_at: prep_data.timestamp,
-            expires_at: prep_data.expires_at.unwrap_or(0),
-        };
+#[cfg(test)]
+mod test_scoped_grants;
 
-        // Store the grant
-        let key = (symbol_short!("GRANT"), prep_data.patient.clone(), prep_data.grantee.clone());
-        env.storage().persistent().set(&key, &grant);
-        extend_ttl_address_key(&env, &key);
+#[cfg(test)]
+mod test_access_extension;
 
-        // Add to patient's grants list
-        let grants_key = (symbol_short!("GRANTS"), prep_data.patient.clone());
-        let mut grants: Vec<Address> = env
-            .storage()
-            .persistent()
-            .get(&grants_key)
-            .unwrap_or(Vec::new(&env));
-        
-        if !grants.contains(&prep_data.grantee) {
-            grants.push_back(prep_data.grantee.clone());
-            env.storage().persistent().set(&grants_key, &grants);
-            extend_ttl_address_key(&env, &grants_key);
-        }
+#[cfg(test)]
+mod test_read_log;
 
-        // Clean up preparation data
-        env.storage().temporary().remove(&prep_key);
+#[cfg(test)]
+mod test_record_archive;
 
-        Ok(())
-    }
+#[cfg(test)]
+mod test_referrals;
 
-    /// Rollback phase for granting access
-    pub fn rollback_grant_access(
-        env: Env,
-        patient: Address,
-        grantee: Address,
-    ) -> Result<(), ContractError> {
-        // Clean up preparation data
-        let prep_key = (symbol_short!("PREP_GRANT_ACC"), patient.clone(), grantee.clone());
-        env.storage().temporary().remove(&prep_key);
+#[cfg(test)]
+mod test_user_status;
 
-        Ok(())
-    }
+#[cfg(test)]
+mod test_admin_set;
 
-    /// Prepare phase for adding a prescription
-    pub fn prepare_add_prescription(
-        env: Env,
-        patient: Address,
-        provider: Address,
-        prescription_data: prescription::PrescriptionData,
-    ) -> Result<u64, ContractError> {
-        // Validate without state changes
-        validation::validate_prescription_data(&prescription_data)?;
+#[cfg(test)]
+mod test_admin_transfer;
 
-        // Check provider permissions
-        let caller = provider.clone();
-        let has_perm = rbac::has_permission(&env, &caller, &Permission::WriteRecord) ||
-                      rbac::has_delegated_permission(&env, &provider, &caller, &Permission::WriteRecord) ||
-                      rbac::has_permission(&env, &caller, &Permission::SystemAdmin);
+#[cfg(test)]
+mod test_ttl;
 
-        if !has_perm {
-            return Err(ContractError::Unauthorized);
-        }
+#[cfg(test)]
+mod test_integrity;
 
-        // Generate prescription ID
-        let counter_key = symbol_short!("RX_CTR");
-        let rx_id: u64 = env
-            .storage()
-            .instance()
-            .get(&counter_key)
-            .unwrap_or(0u64)
-            .saturating_add(1u64);
+#[cfg(test)]
+mod test_care_team;
 
-        // Store preparation data
-        let prep_key = (symbol_short!("PREP_ADD_RX"), rx_id);
-        let prep_data = PrepareAddPrescription {
-            patient,
-            provider,
-            prescription_data,
-            timestamp: env.ledger().timestamp(),
-        };
-        env.storage().temporary().set(&prep_key, &prep_data);
+#[cfg(test)]
+mod test_guardian;
 
-        Ok(rx_id)
-    }
+#[cfg(test)]
+mod test_organization;
 
-    /// Commit phase for adding a prescription
-    pub fn commit_add_prescription(
-        env: Env,
-        rx_id: u64,
-    ) -> Result<(), ContractError> {
-        // Retrieve preparation data
-        let prep_key = (symbol_short!("PREP_ADD_RX"), rx_id);
-        let prep_data: PrepareAddPrescription = env.storage().temporary()
-            .get(&prep_key)
-            .ok_or(ContractError::InvalidInput)?;
+#[cfg(test)]
+mod test_purpose_consent;
 
-        // Update counter
-        let counter_key = symbol_short!("RX_CTR");
-        env.storage().instance().set(&counter_key, &rx_id);
+#[cfg(test)]
+mod test_access_lockdown;
 
-        // Create the prescription
-        let prescription = prescription::Prescription {
-            id: rx_id,
-            patient: prep_data.patient.clone(),
-            provider: prep_data.provider.clone(),
-            data: prep_data.prescription_data.clone(),
-            created_at: prep_data.timestamp,
-            verified: false,
-        };
+#[cfg(test)]
+mod test_attachments;
 
-        // Store the prescription
-        let key = (symbol_short!("PRESCRIPTION"), rx_id);
-        env.storage().persistent().set(&key, &prescription);
-        extend_ttl_u64_key(&env, &key);
+#[cfg(test)]
+mod test_record_signature;
 
-        // Add to patient's prescription history
-        prescription::add_to_patient_history(&env, prep_data.patient.clone(), rx_id);
+#[cfg(test)]
+mod test_cosign;
 
-        // Clean up preparation data
-        env.storage().temporary().remove(&prep_key);
+#[cfg(test)]
+mod test_diagnosis_detail;
 
-        Ok(())
-    }
+#[cfg(test)]
+mod test_measurements;
 
-    /// Rollback phase for adding a prescription
-    pub fn rollback_add_prescription(
-        env: Env,
-        rx_id: u64,
-    ) -> Result<(), ContractError> {
-        // Clean up preparation data
-        let prep_key = (symbol_short!("PREP_ADD_RX"), rx_id);
-        env.storage().temporary().remove(&prep_key);
+#[cfg(test)]
+mod test_surgery;
 
-        Ok(())
-    }
-}
+#[cfg(test)]
+mod test_lab_results;
 
 #[cfg(test)]
-mod test;
+mod test_devices;
 
 #[cfg(test)]
-mod test_pause;
+mod test_fhir;
+
 #[cfg(test)]
-mod test_rbac;
+mod test_patient_keys;
 
 #[cfg(test)]
-mod test_batch;
+mod test_reencryption;
 
 #[cfg(test)]
-mod test_admin_tiers;
+mod test_erasure;
 
 #[cfg(test)]
-mod test_occ;
+mod test_practice_transfer;
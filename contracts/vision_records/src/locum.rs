@@ -0,0 +1,146 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+pub const LOC_CTR: Symbol = symbol_short!("LOC_CTR");
+const LOC_ARR: Symbol = symbol_short!("LOC_ARR");
+const LOC_ABSENT: Symbol = symbol_short!("LOC_ABS");
+const LOC_COVER: Symbol = symbol_short!("LOC_COV");
+const LOC_DISC: Symbol = symbol_short!("LOC_DISC");
+
+/// Longest cover period a single arrangement may span (90 days)
+pub const MAX_LOCUM_SECONDS: u64 = 7_776_000;
+
+/// Extends the time-to-live (TTL) for locum arrangement keys.
+fn extend_ttl_arrangement_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-address locum index keys.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Cover arranged by an absent provider for a fixed period
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocumArrangement {
+    pub id: u64,
+    pub absent_provider: Address,
+    pub covering_provider: Address,
+    pub starts_at: u64,
+    pub ends_at: u64,
+    pub created_at: u64,
+    pub cancelled: bool,
+}
+
+impl LocumArrangement {
+    /// Arrangements lapse on their own once `ends_at` passes.
+    pub fn is_active(&self, now: u64) -> bool {
+        !self.cancelled && self.starts_at <= now && now < self.ends_at
+    }
+}
+
+/// Patient-visible record of a read made under locum cover
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LocumDisclosure {
+    pub arrangement_id: u64,
+    pub covering_provider: Address,
+    pub absent_provider: Address,
+    pub record_id: u64,
+    pub accessed_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Increments and returns the next arrangement ID
+pub fn increment_locum_counter(env: &Env) -> u64 {
+    let current: u64 = env.storage().instance().get(&LOC_CTR).unwrap_or(0);
+    let next = current.saturating_add(1);
+    env.storage().instance().set(&LOC_CTR, &next);
+    next
+}
+
+pub fn set_arrangement(env: &Env, arrangement: &LocumArrangement) {
+    let key = (LOC_ARR, arrangement.id);
+    env.storage().persistent().set(&key, arrangement);
+    extend_ttl_arrangement_key(env, &key);
+}
+
+pub fn get_arrangement(env: &Env, id: u64) -> Option<LocumArrangement> {
+    env.storage().persistent().get(&(LOC_ARR, id))
+}
+
+fn get_index(env: &Env, prefix: Symbol, provider: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(prefix, provider.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn push_index(env: &Env, prefix: Symbol, provider: &Address, id: u64) {
+    let key = (prefix.clone(), provider.clone());
+    let mut ids = get_index(env, prefix, provider);
+    ids.push_back(id);
+    env.storage().persistent().set(&key, &ids);
+    extend_ttl_address_key(env, &key);
+}
+
+/// Stores a new arrangement and indexes it under both providers
+pub fn create_arrangement(env: &Env, arrangement: &LocumArrangement) {
+    set_arrangement(env, arrangement);
+    push_index(
+        env,
+        LOC_ABSENT,
+        &arrangement.absent_provider,
+        arrangement.id,
+    );
+    push_index(
+        env,
+        LOC_COVER,
+        &arrangement.covering_provider,
+        arrangement.id,
+    );
+}
+
+/// Arrangement ids where `provider` is the absent provider
+pub fn get_absent_arrangements(env: &Env, provider: &Address) -> Vec<u64> {
+    get_index(env, LOC_ABSENT, provider)
+}
+
+/// Arrangement ids where `provider` is covering
+pub fn get_covering_arrangements(env: &Env, provider: &Address) -> Vec<u64> {
+    get_index(env, LOC_COVER, provider)
+}
+
+/// Active arrangements under which `covering` is standing in for someone
+pub fn active_cover(env: &Env, covering: &Address, now: u64) -> Vec<LocumArrangement> {
+    let mut out = Vec::new(env);
+    for id in get_covering_arrangements(env, covering).iter() {
+        if let Some(arrangement) = get_arrangement(env, id) {
+            if arrangement.is_active(now) {
+                out.push_back(arrangement);
+            }
+        }
+    }
+    out
+}
+
+pub fn add_disclosure(env: &Env, patient: &Address, disclosure: &LocumDisclosure) {
+    let key = (LOC_DISC, patient.clone());
+    let mut log = get_disclosures(env, patient);
+    log.push_back(disclosure.clone());
+    env.storage().persistent().set(&key, &log);
+    extend_ttl_address_key(env, &key);
+}
+
+pub fn get_disclosures(env: &Env, patient: &Address) -> Vec<LocumDisclosure> {
+    env.storage()
+        .persistent()
+        .get(&(LOC_DISC, patient.clone()))
+        .unwrap_or(Vec::new(env))
+}
@@ -0,0 +1,202 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+use teye_common::optometry;
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const MSR_CNT: Symbol = symbol_short!("MSR_CNT");
+const MSR_LOG: Symbol = symbol_short!("MSR_LOG");
+
+/// Measurements per storage bucket of a series.
+pub const MEASUREMENT_BUCKET_SIZE: u32 = 50;
+
+/// Most measurements returned by one series query.
+pub const MAX_SERIES_LEN: u32 = 100;
+
+/// Highest intraocular pressure accepted: 80.0 mmHg.
+pub const MAX_IOP_TENTHS: i32 = 800;
+
+/// Largest refraction accepted either side of plano: 30.00 D.
+pub const MAX_DIOPTER_HUNDREDTHS: i32 = 3_000;
+
+/// Extends the time-to-live (TTL) for per-series counters.
+fn extend_ttl_counter_key(env: &Env, key: &(Symbol, Address, MeasureType, Eye)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for (series, bucket) keys.
+fn extend_ttl_bucket_key(env: &Env, key: &(Symbol, Address, MeasureType, Eye, u32)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Clinical measure tracked over time
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MeasureType {
+    VisualAcuity,
+    /// Intraocular pressure
+    IOP,
+    /// Refraction as spherical equivalent
+    Refraction,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Eye {
+    Left,
+    Right,
+}
+
+/// Unit and fixed-point scale of a measurement value
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MeasureUnit {
+    /// Thousandths of logMAR
+    LogMar,
+    /// Thousandths of decimal acuity; stored as `LogMar`
+    DecimalAcuity,
+    /// Tenths of a millimetre of mercury
+    MmHg,
+    /// Hundredths of a diopter
+    Diopter,
+}
+
+/// One reading in a patient's series for a measure and eye.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Measurement {
+    pub measure_type: MeasureType,
+    pub eye: Eye,
+    pub value: i32,
+    pub unit: MeasureUnit,
+    pub provider: Address,
+    pub measured_at: u64,
+}
+
+// ── Validation ───────────────────────────────────────────────
+
+/// Checks `value` is in range for `measure_type` in `unit` and returns it
+/// in the unit it is stored in. Visual acuity is always stored in logMAR so
+/// a series compares on one scale.
+pub fn normalize(
+    measure_type: MeasureType,
+    value: i32,
+    unit: MeasureUnit,
+) -> Option<(i32, MeasureUnit)> {
+    match (measure_type, unit) {
+        (MeasureType::VisualAcuity, MeasureUnit::LogMar) => (optometry::MIN_LOGMAR
+            ..=optometry::MAX_LOGMAR)
+            .contains(&value)
+            .then_some((value, MeasureUnit::LogMar)),
+        (MeasureType::VisualAcuity, MeasureUnit::DecimalAcuity) => {
+            let logmar = optometry::decimal_to_logmar(u32::try_from(value).ok()?)?;
+            Some((logmar, MeasureUnit::LogMar))
+        }
+        (MeasureType::IOP, MeasureUnit::MmHg) => (0..=MAX_IOP_TENTHS)
+            .contains(&value)
+            .then_some((value, unit)),
+        (MeasureType::Refraction, MeasureUnit::Diopter) => (-MAX_DIOPTER_HUNDREDTHS
+            ..=MAX_DIOPTER_HUNDREDTHS)
+            .contains(&value)
+            .then_some((value, unit)),
+        _ => None,
+    }
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn count(env: &Env, patient: &Address, measure_type: MeasureType, eye: Eye) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&(MSR_CNT, patient.clone(), measure_type, eye))
+        .unwrap_or(0)
+}
+
+fn bucket(
+    env: &Env,
+    patient: &Address,
+    measure_type: MeasureType,
+    eye: Eye,
+    bucket: u32,
+) -> Vec<Measurement> {
+    env.storage()
+        .persistent()
+        .get(&(MSR_LOG, patient.clone(), measure_type, eye, bucket))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Appends `measurement` to `patient`'s series for its measure and eye.
+pub fn append(env: &Env, patient: &Address, measurement: &Measurement) {
+    let (measure_type, eye) = (measurement.measure_type, measurement.eye);
+    let seq = count(env, patient, measure_type, eye);
+    let index = seq / MEASUREMENT_BUCKET_SIZE;
+
+    let mut entries = bucket(env, patient, measure_type, eye, index);
+    entries.push_back(measurement.clone());
+    let bucket_key = (MSR_LOG, patient.clone(), measure_type, eye, index);
+    env.storage().persistent().set(&bucket_key, &entries);
+    extend_ttl_bucket_key(env, &bucket_key);
+
+    let count_key = (MSR_CNT, patient.clone(), measure_type, eye);
+    env.storage()
+        .persistent()
+        .set(&count_key, &seq.saturating_add(1));
+    extend_ttl_counter_key(env, &count_key);
+}
+
+/// Measurements taken between `from` and `to` inclusive, oldest first, at
+/// most `MAX_SERIES_LEN` of them. Series are appended in ledger time order,
+/// so the scan stops at the first reading after `to`.
+pub fn series(
+    env: &Env,
+    patient: &Address,
+    measure_type: MeasureType,
+    eye: Eye,
+    from: u64,
+    to: u64,
+) -> Vec<Measurement> {
+    let mut out = Vec::new(env);
+    let total = count(env, patient, measure_type, eye);
+    let buckets = total.div_ceil(MEASUREMENT_BUCKET_SIZE);
+    for index in 0..buckets {
+        let entries = bucket(env, patient, measure_type, eye, index);
+        if entries.last().is_some_and(|m| m.measured_at < from) {
+            continue;
+        }
+        for m in entries.iter() {
+            if m.measured_at > to || out.len() >= MAX_SERIES_LEN {
+                return out;
+            }
+            if m.measured_at >= from {
+                out.push_back(m);
+            }
+        }
+    }
+    out
+}
+
+/// Deletes every series recorded for `patient`. Returns the number of
+/// measurements removed.
+pub fn clear(env: &Env, patient: &Address) -> u32 {
+    let mut removed = 0u32;
+    for measure_type in [
+        MeasureType::VisualAcuity,
+        MeasureType::IOP,
+        MeasureType::Refraction,
+    ] {
+        for eye in [Eye::Left, Eye::Right] {
+            let total = count(env, patient, measure_type, eye);
+            for index in 0..total.div_ceil(MEASUREMENT_BUCKET_SIZE) {
+                let bucket_key = (MSR_LOG, patient.clone(), measure_type, eye, index);
+                env.storage().persistent().remove(&bucket_key);
+            }
+            env.storage()
+                .persistent()
+                .remove(&(MSR_CNT, patient.clone(), measure_type, eye));
+            removed = removed.saturating_add(total);
+        }
+    }
+    removed
+}
@@ -0,0 +1,176 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+use crate::{AccessGrant, ConsentGrant, GrantStatus, VisionRecord};
+
+// ── Storage keys ──────────────────────────────────────────────
+const MERGED: Symbol = symbol_short!("MERGED");
+
+/// Extends the time-to-live (TTL) for address-keyed merge entries.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for (patient, grantee) keys.
+fn extend_ttl_pair_key(env: &Env, key: &(Symbol, Address, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Tombstone left at a duplicate patient address after it is merged
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PatientMerge {
+    pub primary: Address,
+    pub duplicate: Address,
+    pub merged_by: Address,
+    pub merged_at: u64,
+    pub records_moved: u32,
+    pub prescriptions_moved: u32,
+    pub grants_moved: u32,
+    pub emergency_moved: u32,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn set_tombstone(env: &Env, merge: &PatientMerge) {
+    let key = (MERGED, merge.duplicate.clone());
+    env.storage().persistent().set(&key, merge);
+    extend_ttl_address_key(env, &key);
+}
+
+pub fn get_tombstone(env: &Env, duplicate: &Address) -> Option<PatientMerge> {
+    env.storage().persistent().get(&(MERGED, duplicate.clone()))
+}
+
+pub fn is_merged(env: &Env, patient: &Address) -> bool {
+    env.storage().persistent().has(&(MERGED, patient.clone()))
+}
+
+/// Moves `from`'s records to `to`, rewriting the patient on each record and
+/// its diagnosis index entries. Returns the number of records moved.
+pub fn move_records(env: &Env, from: &Address, to: &Address) -> u32 {
+    let from_key = (symbol_short!("PAT_REC"), from.clone());
+    let moved: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&from_key)
+        .unwrap_or(Vec::new(env));
+    if moved.is_empty() {
+        return 0;
+    }
+
+    let to_key = (symbol_short!("PAT_REC"), to.clone());
+    let mut ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&to_key)
+        .unwrap_or(Vec::new(env));
+    for id in moved.iter() {
+        let record_key = (symbol_short!("RECORD"), id);
+        if let Some(mut record) = env
+            .storage()
+            .persistent()
+            .get::<_, VisionRecord>(&record_key)
+        {
+            record.patient = to.clone();
+            env.storage().persistent().set(&record_key, &record);
+        }
+        crate::diagnosis::reindex_record(env, from, to, id);
+        crate::fhir::reindex_record(env, from, to, id);
+        ids.push_back(id);
+    }
+    env.storage().persistent().set(&to_key, &ids);
+    extend_ttl_address_key(env, &to_key);
+    env.storage().persistent().remove(&from_key);
+
+    moved.len()
+}
+
+/// Moves access grants, consents, scoped grants and organization grants
+/// made by `from` to `to`, adding them to `to`'s grantee lists. Where both
+/// addresses granted the same grantee (or scope, or organization), the
+/// grant that lasts longer is kept. Record-level grants follow their
+/// records. Returns the number of grantees and organizations whose access
+/// was carried over.
+pub fn move_grants(env: &Env, from: &Address, to: &Address) -> u32 {
+    let access_list = |patient: &Address| (symbol_short!("ACC_LST"), patient.clone());
+    let consent_list = |patient: &Address| (symbol_short!("CONS_LST"), patient.clone());
+    let load = |key: &(Symbol, Address)| -> Vec<Address> {
+        env.storage().persistent().get(key).unwrap_or(Vec::new(env))
+    };
+
+    let mut grantees = load(&access_list(from));
+    for grantee in load(&consent_list(from)).iter() {
+        if !grantees.contains(&grantee) {
+            grantees.push_back(grantee);
+        }
+    }
+    let mut to_grantees = load(&access_list(to));
+    let mut to_consents = load(&consent_list(to));
+
+    let mut moved = 0u32;
+    for grantee in grantees.iter() {
+        let mut carried = false;
+
+        let from_key = (symbol_short!("ACCESS"), from.clone(), grantee.clone());
+        if let Some(mut grant) = env.storage().persistent().get::<_, AccessGrant>(&from_key) {
+            let to_key = (symbol_short!("ACCESS"), to.clone(), grantee.clone());
+            let keep_existing = match env.storage().persistent().get::<_, AccessGrant>(&to_key) {
+                Some(existing) => {
+                    existing.status == GrantStatus::Active
+                        && existing.expires_at >= grant.expires_at
+                }
+                None => false,
+            };
+            if grant.status == GrantStatus::Active && !keep_existing {
+                grant.patient = to.clone();
+                env.storage().persistent().set(&to_key, &grant);
+                extend_ttl_pair_key(env, &to_key);
+            }
+            env.storage().persistent().remove(&from_key);
+            if !to_grantees.contains(&grantee) {
+                to_grantees.push_back(grantee.clone());
+            }
+            carried = true;
+        }
+
+        let from_key = (symbol_short!("CONSENT"), from.clone(), grantee.clone());
+        if let Some(mut consent) = env.storage().persistent().get::<_, ConsentGrant>(&from_key) {
+            let to_key = (symbol_short!("CONSENT"), to.clone(), grantee.clone());
+            let keep_existing = match env.storage().persistent().get::<_, ConsentGrant>(&to_key) {
+                Some(existing) => !existing.revoked && existing.expires_at >= consent.expires_at,
+                None => false,
+            };
+            if !consent.revoked && !keep_existing {
+                consent.patient = to.clone();
+                env.storage().persistent().set(&to_key, &consent);
+                extend_ttl_pair_key(env, &to_key);
+            }
+            env.storage().persistent().remove(&from_key);
+            if !to_consents.contains(&grantee) {
+                to_consents.push_back(grantee.clone());
+            }
+            carried = true;
+        }
+
+        if carried {
+            moved = moved.saturating_add(1);
+        }
+    }
+
+    for (key, list) in [
+        (access_list(to), to_grantees),
+        (consent_list(to), to_consents),
+    ] {
+        env.storage().persistent().set(&key, &list);
+        extend_ttl_address_key(env, &key);
+    }
+    env.storage().persistent().remove(&access_list(from));
+    env.storage().persistent().remove(&consent_list(from));
+
+    moved
+        .saturating_add(crate::scoped_grant::move_patient(env, from, to))
+        .saturating_add(crate::organization::move_patient(env, from, to))
+}
@@ -0,0 +1,25 @@
+use soroban_sdk::{symbol_short, Address, BytesN, Env, Symbol};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const MTA_KEY: Symbol = symbol_short!("MTA_KEY");
+
+/// Extends the time-to-live (TTL) for per-patient meta-signer entries.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// The Ed25519 key `patient` registered for signing sponsored grants.
+/// Relayer-supplied keys are only trusted when they match this one.
+pub fn get_key(env: &Env, patient: &Address) -> Option<BytesN<32>> {
+    env.storage().persistent().get(&(MTA_KEY, patient.clone()))
+}
+
+pub fn set_key(env: &Env, patient: &Address, public_key: &BytesN<32>) {
+    let key = (MTA_KEY, patient.clone());
+    env.storage().persistent().set(&key, public_key);
+    extend_ttl_address_key(env, &key);
+}
@@ -0,0 +1,53 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const NTF_PREF: Symbol = symbol_short!("NTF_PREF");
+
+/// Shortest time a patient may give themselves to acknowledge a grant
+pub const MIN_ACK_WINDOW_SECONDS: u64 = 900;
+
+/// Longest a non-critical emergency grant may wait for acknowledgement
+pub const MAX_ACK_WINDOW_SECONDS: u64 = 86400;
+
+/// Extends the time-to-live (TTL) for notification preference keys.
+fn extend_ttl_pref_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// How a patient wants to be involved when their data is reached for
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotificationPreferences {
+    pub patient: Address,
+    /// Emergency grants for non-critical conditions wait for the patient
+    /// (or their custodian) to acknowledge them
+    pub require_emergency_ack: bool,
+    /// After this long an unacknowledged grant activates anyway
+    pub ack_window_seconds: u64,
+    pub updated_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn set_preferences(env: &Env, prefs: &NotificationPreferences) {
+    let key = (NTF_PREF, prefs.patient.clone());
+    env.storage().persistent().set(&key, prefs);
+    extend_ttl_pref_key(env, &key);
+}
+
+/// Returns the patient's preferences, or the defaults if none have been saved
+pub fn get_preferences(env: &Env, patient: &Address) -> NotificationPreferences {
+    env.storage()
+        .persistent()
+        .get(&(NTF_PREF, patient.clone()))
+        .unwrap_or(NotificationPreferences {
+            patient: patient.clone(),
+            require_emergency_ack: false,
+            ack_window_seconds: MAX_ACK_WINDOW_SECONDS,
+            updated_at: 0,
+        })
+}
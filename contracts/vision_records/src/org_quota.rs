@@ -0,0 +1,125 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::residency;
+use crate::ttl_config;
+use crate::ContractError;
+
+// ── Storage keys ──────────────────────────────────────────────
+const QTA_CFG: Symbol = symbol_short!("QTA_CFG");
+const QTA_USE: Symbol = symbol_short!("QTA_USE");
+const QTA_BILL: Symbol = symbol_short!("QTA_BILL");
+
+/// Extends the time-to-live (TTL) for per-organization quota keys.
+fn extend_ttl_org_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Records an organization's providers may write per period.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgQuota {
+    pub org_id: u64,
+    pub records_per_period: u32,
+    pub period_seconds: u64,
+    pub updated_by: Address,
+    pub updated_at: u64,
+}
+
+/// Usage in the current period.
+///
+/// `purchased_remaining` holds records bought through the billing contract.
+/// They are drawn only once the period allowance is used up and carry over
+/// between periods until spent.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgQuotaUsage {
+    pub org_id: u64,
+    pub period_start: u64,
+    pub records_written: u32,
+    pub purchased_remaining: u32,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get_quota(env: &Env, org_id: u64) -> Option<OrgQuota> {
+    env.storage().persistent().get(&(QTA_CFG, org_id))
+}
+
+pub fn set_quota(env: &Env, quota: &OrgQuota) {
+    let key = (QTA_CFG, quota.org_id);
+    env.storage().persistent().set(&key, quota);
+    extend_ttl_org_key(env, &key);
+}
+
+fn set_usage(env: &Env, usage: &OrgQuotaUsage) {
+    let key = (QTA_USE, usage.org_id);
+    env.storage().persistent().set(&key, usage);
+    extend_ttl_org_key(env, &key);
+}
+
+/// Usage as of now, rolled over to the current period if the stored one
+/// has ended. Periods are aligned to the first write after the quota was
+/// configured.
+pub fn get_usage(env: &Env, org_id: u64) -> OrgQuotaUsage {
+    let now = env.ledger().timestamp();
+    let stored: Option<OrgQuotaUsage> = env.storage().persistent().get(&(QTA_USE, org_id));
+    let mut usage = stored.unwrap_or(OrgQuotaUsage {
+        org_id,
+        period_start: now,
+        records_written: 0,
+        purchased_remaining: 0,
+    });
+    if let Some(quota) = get_quota(env, org_id) {
+        let elapsed = now.saturating_sub(usage.period_start);
+        if quota.period_seconds > 0 && elapsed >= quota.period_seconds {
+            usage.period_start = now.saturating_sub(elapsed % quota.period_seconds);
+            usage.records_written = 0;
+        }
+    }
+    usage
+}
+
+/// Charges `count` record writes by `provider` to their organization.
+/// Providers outside any organization, or in one without a quota, are not
+/// limited.
+pub fn consume(env: &Env, provider: &Address, count: u32) -> Result<(), ContractError> {
+    let org_id = match residency::get_provider_org(env, provider) {
+        Some(org_id) => org_id,
+        None => return Ok(()),
+    };
+    let quota = match get_quota(env, org_id) {
+        Some(quota) => quota,
+        None => return Ok(()),
+    };
+
+    let mut usage = get_usage(env, org_id);
+    let base_left = quota
+        .records_per_period
+        .saturating_sub(usage.records_written);
+    let from_purchased = count.saturating_sub(base_left);
+    if from_purchased > usage.purchased_remaining {
+        return Err(ContractError::QuotaExceeded);
+    }
+    usage.records_written = usage.records_written.saturating_add(count.min(base_left));
+    usage.purchased_remaining = usage.purchased_remaining.saturating_sub(from_purchased);
+    set_usage(env, &usage);
+    Ok(())
+}
+
+/// Adds purchased records to the organization's carry-over pool.
+pub fn add_purchased(env: &Env, org_id: u64, records: u32) -> OrgQuotaUsage {
+    let mut usage = get_usage(env, org_id);
+    usage.purchased_remaining = usage.purchased_remaining.saturating_add(records);
+    set_usage(env, &usage);
+    usage
+}
+
+pub fn set_billing_contract(env: &Env, billing: &Address) {
+    env.storage().instance().set(&QTA_BILL, billing);
+}
+
+pub fn get_billing_contract(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&QTA_BILL)
+}
@@ -0,0 +1,196 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::ttl_config;
+use crate::{residency, AccessLevel};
+
+// ── Storage keys ──────────────────────────────────────────────
+const ORG_CTR: Symbol = symbol_short!("ORG_CTR");
+const ORG: Symbol = symbol_short!("ORG");
+const ORG_MEM: Symbol = symbol_short!("ORG_MEM");
+const ORG_ACC: Symbol = symbol_short!("ORG_ACC");
+const ORG_GRT: Symbol = symbol_short!("ORG_GRT");
+
+/// Extends the time-to-live (TTL) for per-organization keys.
+fn extend_ttl_org_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-patient grant index keys.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for (patient, organization) grant keys.
+fn extend_ttl_grant_key(env: &Env, key: &(Symbol, Address, u64)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A clinic or practice. Its id is the same `org_id` residency policies
+/// and record quotas are keyed by.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Organization {
+    pub id: u64,
+    pub name: String,
+    /// May add and remove the organization's providers
+    pub admin: Address,
+    pub created_at: u64,
+}
+
+/// A patient's grant to every provider currently in an organization.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrgAccessGrant {
+    pub patient: Address,
+    pub org_id: u64,
+    pub level: AccessLevel,
+    pub granted_at: u64,
+    pub expires_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn next_id(env: &Env) -> u64 {
+    let id: u64 = env
+        .storage()
+        .instance()
+        .get(&ORG_CTR)
+        .unwrap_or(0u64)
+        .saturating_add(1);
+    env.storage().instance().set(&ORG_CTR, &id);
+    id
+}
+
+pub fn get(env: &Env, org_id: u64) -> Option<Organization> {
+    env.storage().persistent().get(&(ORG, org_id))
+}
+
+pub fn set(env: &Env, org: &Organization) {
+    let key = (ORG, org.id);
+    env.storage().persistent().set(&key, org);
+    extend_ttl_org_key(env, &key);
+}
+
+pub fn members(env: &Env, org_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&(ORG_MEM, org_id))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_members(env: &Env, org_id: u64, members: &Vec<Address>) {
+    let key = (ORG_MEM, org_id);
+    env.storage().persistent().set(&key, members);
+    extend_ttl_org_key(env, &key);
+}
+
+fn drop_member(env: &Env, org_id: u64, provider: &Address) {
+    let mut members = members(env, org_id);
+    if let Some(i) = members.first_index_of(provider) {
+        members.remove(i);
+        set_members(env, org_id, &members);
+    }
+}
+
+/// Moves `provider` into `org_id`, leaving any organization they were in.
+pub fn add_member(env: &Env, org_id: u64, provider: &Address) {
+    if let Some(current) = residency::get_provider_org(env, provider) {
+        if current == org_id {
+            return;
+        }
+        drop_member(env, current, provider);
+    }
+    residency::set_provider_org(env, provider, org_id);
+    let mut members = members(env, org_id);
+    members.push_back(provider.clone());
+    set_members(env, org_id, &members);
+}
+
+/// Takes `provider` out of `org_id`. Returns false if they were not in it.
+pub fn remove_member(env: &Env, org_id: u64, provider: &Address) -> bool {
+    if residency::get_provider_org(env, provider) != Some(org_id) {
+        return false;
+    }
+    residency::clear_provider_org(env, provider);
+    drop_member(env, org_id, provider);
+    true
+}
+
+pub fn get_grant(env: &Env, patient: &Address, org_id: u64) -> Option<OrgAccessGrant> {
+    env.storage()
+        .persistent()
+        .get(&(ORG_ACC, patient.clone(), org_id))
+}
+
+pub fn set_grant(env: &Env, grant: &OrgAccessGrant) {
+    let key = (ORG_ACC, grant.patient.clone(), grant.org_id);
+    env.storage().persistent().set(&key, grant);
+    extend_ttl_grant_key(env, &key);
+
+    let mut ids = granted_orgs(env, &grant.patient);
+    if !ids.contains(grant.org_id) {
+        ids.push_back(grant.org_id);
+        let index_key = (ORG_GRT, grant.patient.clone());
+        env.storage().persistent().set(&index_key, &ids);
+        extend_ttl_address_key(env, &index_key);
+    }
+}
+
+/// Removes the grant and its index entry. Returns false if there was none.
+pub fn remove_grant(env: &Env, patient: &Address, org_id: u64) -> bool {
+    let key = (ORG_ACC, patient.clone(), org_id);
+    if !env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage().persistent().remove(&key);
+
+    let mut ids = granted_orgs(env, patient);
+    if let Some(i) = ids.first_index_of(org_id) {
+        ids.remove(i);
+        let index_key = (ORG_GRT, patient.clone());
+        env.storage().persistent().set(&index_key, &ids);
+        extend_ttl_address_key(env, &index_key);
+    }
+    true
+}
+
+/// Re-points the live organization grants `from` made at `to`, for a
+/// patient merge, and removes `from`'s. Where `to` already granted the same
+/// organization, the grant that lasts longer is kept. Returns the number of
+/// organizations carried over.
+pub fn move_patient(env: &Env, from: &Address, to: &Address) -> u32 {
+    let now = env.ledger().timestamp();
+    let mut moved = 0u32;
+    for org_id in granted_orgs(env, from).iter() {
+        if let Some(mut grant) = get_grant(env, from, org_id) {
+            let superseded =
+                get_grant(env, to, org_id).is_some_and(|g| g.expires_at >= grant.expires_at);
+            if grant.expires_at > now && !superseded {
+                grant.patient = to.clone();
+                set_grant(env, &grant);
+            }
+            moved = moved.saturating_add(1);
+        }
+        remove_grant(env, from, org_id);
+    }
+    moved
+}
+
+/// Organizations the patient has granted access to, expired or not.
+pub fn granted_orgs(env: &Env, patient: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(ORG_GRT, patient.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// The level `provider` holds on `patient`'s records through their
+/// organization, if its grant is live.
+pub fn level_for(env: &Env, patient: &Address, provider: &Address) -> Option<AccessLevel> {
+    let org_id = residency::get_provider_org(env, provider)?;
+    get_grant(env, patient, org_id)
+        .filter(|g| g.expires_at > env.ledger().timestamp())
+        .map(|g| g.level)
+}
@@ -0,0 +1,127 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const PKEY: Symbol = symbol_short!("PKEY");
+const PKEY_LST: Symbol = symbol_short!("PKEY_LST");
+const PKEY_CUR: Symbol = symbol_short!("PKEY_CUR");
+const REC_PKEY: Symbol = symbol_short!("REC_PKEY");
+
+/// Extends the time-to-live (TTL) for (patient, key id) keys.
+fn extend_ttl_key_key(env: &Env, key: &(Symbol, Address, u32)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-patient key list and current
+/// key entries.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-record key id entries.
+fn extend_ttl_record_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A public key a patient's documents are encrypted to off-chain. Keys
+/// are never removed: readers of older records still need to know which
+/// key those were encrypted under.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PatientEncryptionKey {
+    pub patient: Address,
+    pub key_id: u32,
+    pub public_key: BytesN<32>,
+    pub registered_at: u64,
+}
+
+/// Which key a record's document is encrypted under. The record needs
+/// re-encrypting while `key_id` is not the patient's current key.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordKeyInfo {
+    pub record_id: u64,
+    pub key_id: u32,
+    pub public_key: BytesN<32>,
+    pub reencryption_pending: bool,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get(env: &Env, patient: &Address, key_id: u32) -> Option<PatientEncryptionKey> {
+    env.storage()
+        .persistent()
+        .get(&(PKEY, patient.clone(), key_id))
+}
+
+/// Ids of every key `patient` has registered, oldest first.
+pub fn list(env: &Env, patient: &Address) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&(PKEY_LST, patient.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores a newly registered key. The first key a patient registers
+/// becomes their current key.
+pub fn register(env: &Env, key: &PatientEncryptionKey) {
+    let entry_key = (PKEY, key.patient.clone(), key.key_id);
+    env.storage().persistent().set(&entry_key, key);
+    extend_ttl_key_key(env, &entry_key);
+
+    let list_key = (PKEY_LST, key.patient.clone());
+    let mut ids = list(env, &key.patient);
+    ids.push_back(key.key_id);
+    env.storage().persistent().set(&list_key, &ids);
+    extend_ttl_address_key(env, &list_key);
+
+    if current(env, &key.patient).is_none() {
+        set_current(env, &key.patient, key.key_id);
+    }
+}
+
+pub fn current(env: &Env, patient: &Address) -> Option<u32> {
+    env.storage().persistent().get(&(PKEY_CUR, patient.clone()))
+}
+
+pub fn set_current(env: &Env, patient: &Address, key_id: u32) {
+    let key = (PKEY_CUR, patient.clone());
+    env.storage().persistent().set(&key, &key_id);
+    extend_ttl_address_key(env, &key);
+}
+
+pub fn get_record_key(env: &Env, record_id: u64) -> Option<u32> {
+    env.storage().persistent().get(&(REC_PKEY, record_id))
+}
+
+pub fn set_record_key(env: &Env, record_id: u64, key_id: u32) {
+    let key = (REC_PKEY, record_id);
+    env.storage().persistent().set(&key, &key_id);
+    extend_ttl_record_key(env, &key);
+}
+
+pub fn remove_record_key(env: &Env, record_id: u64) {
+    env.storage().persistent().remove(&(REC_PKEY, record_id));
+}
+
+/// Tags a new record with the patient's current key, if they have one.
+pub fn attach_current(env: &Env, patient: &Address, record_id: u64) {
+    if let Some(key_id) = current(env, patient) {
+        set_record_key(env, record_id, key_id);
+    }
+}
+
+/// The key `record_id` of `patient` is encrypted under, if tagged.
+pub fn record_info(env: &Env, patient: &Address, record_id: u64) -> Option<RecordKeyInfo> {
+    let key_id = get_record_key(env, record_id)?;
+    let key = get(env, patient, key_id)?;
+    Some(RecordKeyInfo {
+        record_id,
+        key_id,
+        public_key: key.public_key,
+        reencryption_pending: current(env, patient) != Some(key_id),
+    })
+}
@@ -0,0 +1,75 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const PXF_CTR: Symbol = symbol_short!("PXF_CTR");
+const PXF: Symbol = symbol_short!("PXF");
+const PXF_OPT: Symbol = symbol_short!("PXF_OPT");
+
+/// How long patients have to opt out before a practice transfer can run
+pub const OPT_OUT_WINDOW_SECONDS: u64 = 2_592_000;
+
+/// Most patients one practice transfer may cover
+pub const MAX_TRANSFER_PATIENTS: u32 = 50;
+
+/// Extends the time-to-live (TTL) for transfer keys.
+fn extend_ttl_transfer_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for (transfer, patient) opt-out keys.
+fn extend_ttl_opt_out_key(env: &Env, key: &(Symbol, u64, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Hand-over of a closing practice's patients to another provider.
+///
+/// Scheduled by the closing provider; patients may opt out until
+/// `opt_out_deadline`, after which the receiving provider (or a
+/// SystemAdmin) carries it out.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PracticeTransfer {
+    pub id: u64,
+    pub closing_provider: Address,
+    pub receiving_provider: Address,
+    pub patients: Vec<Address>,
+    pub scheduled_at: u64,
+    pub opt_out_deadline: u64,
+    pub executed_at: Option<u64>,
+    pub records_moved: u32,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn next_id(env: &Env) -> u64 {
+    let current: u64 = env.storage().instance().get(&PXF_CTR).unwrap_or(0);
+    let next = current.saturating_add(1);
+    env.storage().instance().set(&PXF_CTR, &next);
+    next
+}
+
+pub fn get(env: &Env, id: u64) -> Option<PracticeTransfer> {
+    env.storage().persistent().get(&(PXF, id))
+}
+
+pub fn set(env: &Env, transfer: &PracticeTransfer) {
+    let key = (PXF, transfer.id);
+    env.storage().persistent().set(&key, transfer);
+    extend_ttl_transfer_key(env, &key);
+}
+
+pub fn is_opted_out(env: &Env, id: u64, patient: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&(PXF_OPT, id, patient.clone()))
+}
+
+pub fn set_opted_out(env: &Env, id: u64, patient: &Address) {
+    let key = (PXF_OPT, id, patient.clone());
+    env.storage().persistent().set(&key, &true);
+    extend_ttl_opt_out_key(env, &key);
+}
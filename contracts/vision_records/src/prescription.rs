@@ -1,21 +1,49 @@
-use soroban_sdk::{contracttype, Address, Env, String, Vec};
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, BytesN, Env, String, Symbol, Vec};
 use teye_common::concurrency::{self, FieldChange, UpdateOutcome, VersionStamp};
 
+use crate::ttl_config;
+use crate::ContractError;
+
+// ── Storage keys ──────────────────────────────────────────────
+const RX_CANON: Symbol = symbol_short!("RX_CANON");
+const RX_DUP_WINDOW: Symbol = symbol_short!("RX_DUPW");
+const RX_ACTIVE: Symbol = symbol_short!("RX_ACT");
+const RX_SWEEP: Symbol = symbol_short!("RX_SWEEP");
+
+/// Default window in which an identical prescription for the same patient is
+/// treated as a duplicate entry (30 days).
+pub const DEFAULT_DUPLICATE_WINDOW: u64 = 2_592_000;
+
+/// Longest field value accepted for canonicalisation.
+const MAX_FIELD_LEN: usize = 64;
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum LensType {
     Glasses,
     ContactLens,
+    /// Multifocal spectacles; both eyes require an add power.
+    Progressive,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PrescriptionStatus {
+    Active,
+    /// Past `expires_at`; set by the expiry sweeper or lazily on read.
+    Expired,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PrescriptionData {
-    pub sphere: String,   // SPH
-    pub cylinder: String, // CYL
-    pub axis: String,     // AXIS
-    pub add: String,      // ADD
-    pub pd: String,       // Pupillary Distance
+    pub sphere: String,     // SPH
+    pub cylinder: String,   // CYL
+    pub axis: String,       // AXIS
+    pub add: String,        // ADD
+    pub pd: String,         // Pupillary Distance
+    pub prism: String,      // Prism diopters, empty when none
+    pub prism_base: String, // IN, OUT, UP or DOWN
 }
 
 #[contracttype]
@@ -48,6 +76,10 @@ pub struct Prescription {
     pub expires_at: u64,
     pub verified: bool,
     pub metadata_hash: String,
+    /// Hash of the normalised clinical fields, identical for two entries of
+    /// the same paper prescription.
+    pub canonical_hash: BytesN<32>,
+    pub status: PrescriptionStatus,
 }
 
 pub fn save_prescription(env: &Env, prescription: &Prescription) {
@@ -66,6 +98,12 @@ pub fn save_prescription(env: &Env, prescription: &Prescription) {
         .unwrap_or(Vec::new(env));
     history.push_back(prescription.id);
     env.storage().persistent().set(&history_key, &history);
+
+    if prescription.status == PrescriptionStatus::Active {
+        let mut active = get_active(env, &prescription.patient);
+        active.push_back(prescription.id);
+        set_active(env, &prescription.patient, &active);
+    }
 }
 
 pub fn get_prescription(env: &Env, id: u64) -> Option<Prescription> {
@@ -81,6 +119,52 @@ pub fn get_patient_history(env: &Env, patient: Address) -> Vec<u64> {
         .unwrap_or(Vec::new(env))
 }
 
+/// Ids of the patient's prescriptions that have not been marked expired.
+pub fn get_active(env: &Env, patient: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(RX_ACTIVE, patient.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_active(env: &Env, patient: &Address, active: &Vec<u64>) {
+    let key = (RX_ACTIVE, patient.clone());
+    if active.is_empty() {
+        env.storage().persistent().remove(&key);
+        return;
+    }
+    env.storage().persistent().set(&key, active);
+    ttl_config::extend(env, &key);
+}
+
+/// Returns true if `rx` is still marked active but has reached `expires_at`.
+pub fn is_due_for_expiry(rx: &Prescription, now: u64) -> bool {
+    rx.status == PrescriptionStatus::Active && rx.expires_at <= now
+}
+
+/// Marks `rx` as expired and drops it from the patient's active index.
+pub fn mark_expired(env: &Env, rx: &mut Prescription) {
+    rx.status = PrescriptionStatus::Expired;
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("RX"), rx.id), rx);
+
+    let mut active = get_active(env, &rx.patient);
+    if let Some(pos) = active.first_index_of(rx.id) {
+        active.remove(pos);
+        set_active(env, &rx.patient, &active);
+    }
+}
+
+/// Returns the next prescription id the expiry sweeper will inspect.
+pub fn get_sweep_cursor(env: &Env) -> u64 {
+    env.storage().instance().get(&RX_SWEEP).unwrap_or(1)
+}
+
+pub fn set_sweep_cursor(env: &Env, cursor: u64) {
+    env.storage().instance().set(&RX_SWEEP, &cursor);
+}
+
 pub fn verify_prescription(env: &Env, id: u64, verifier: Address) -> bool {
     if let Some(mut rx) = get_prescription(env, id) {
         verifier.require_auth();
@@ -134,3 +218,340 @@ pub fn versioned_save_prescription(
 pub fn get_prescription_version(env: &Env, id: u64) -> VersionStamp {
     concurrency::get_version_stamp(env, id)
 }
+
+// ── Canonical hashing ────────────────────────────────────────
+
+/// Copies `value` into `buf` and returns it without surrounding whitespace.
+fn trimmed<'a>(
+    value: &String,
+    buf: &'a mut [u8; MAX_FIELD_LEN],
+) -> Result<&'a [u8], ContractError> {
+    let len = value.len() as usize;
+    if len > MAX_FIELD_LEN {
+        return Err(ContractError::InvalidInput);
+    }
+    value.copy_into_slice(&mut buf[..len]);
+
+    let mut start = 0;
+    let mut end = len;
+    while start < end && buf[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while end > start && buf[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    Ok(&buf[start..end])
+}
+
+/// Appends a normalised form of `value` to `out`.
+///
+/// Surrounding whitespace and a leading `+` are ignored. Decimal values are
+/// encoded as hundredths so that `-2.5`, `-2.50` and ` -2.500 ` agree; any
+/// other value is lowercased.
+fn push_field(out: &mut Bytes, value: &String) -> Result<(), ContractError> {
+    let mut buf = [0u8; MAX_FIELD_LEN];
+    let field = trimmed(value, &mut buf)?;
+
+    match parse_hundredths(field) {
+        Some(n) => {
+            out.push_back(0x01);
+            out.extend_from_array(&n.to_be_bytes());
+        }
+        None => {
+            out.push_back(0x02);
+            out.push_back(field.len() as u8);
+            for b in field {
+                out.push_back(b.to_ascii_lowercase());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Parses a signed decimal such as `-1.25` into hundredths, truncating any
+/// further precision. Returns `None` for anything that is not a plain number.
+fn parse_hundredths(field: &[u8]) -> Option<i64> {
+    let (negative, digits) = match field.first() {
+        Some(b'-') => (true, &field[1..]),
+        Some(b'+') => (false, &field[1..]),
+        _ => (false, field),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+
+    let mut whole: i64 = 0;
+    let mut frac: i64 = 0;
+    let mut frac_digits = 0u32;
+    let mut seen_dot = false;
+    let mut seen_digit = false;
+    for &b in digits {
+        match b {
+            b'0'..=b'9' => {
+                seen_digit = true;
+                let d = (b - b'0') as i64;
+                if !seen_dot {
+                    whole = whole.checked_mul(10)?.checked_add(d)?;
+                } else if frac_digits < 2 {
+                    frac = frac * 10 + d;
+                    frac_digits += 1;
+                }
+            }
+            b'.' if !seen_dot => seen_dot = true,
+            _ => return None,
+        }
+    }
+    if !seen_digit {
+        return None;
+    }
+    while frac_digits < 2 {
+        frac *= 10;
+        frac_digits += 1;
+    }
+
+    let value = whole.checked_mul(100)?.checked_add(frac)?;
+    Some(if negative { -value } else { value })
+}
+
+fn push_eye(out: &mut Bytes, eye: &PrescriptionData) -> Result<(), ContractError> {
+    push_field(out, &eye.sphere)?;
+    push_field(out, &eye.cylinder)?;
+    push_field(out, &eye.axis)?;
+    push_field(out, &eye.add)?;
+    push_field(out, &eye.pd)?;
+    push_field(out, &eye.prism)?;
+    push_field(out, &eye.prism_base)
+}
+
+/// Computes the canonical hash of the clinical content of a prescription.
+///
+/// Only the lens type, both eyes and any contact lens parameters contribute;
+/// the entering provider, metadata and validity period do not.
+pub fn canonical_hash(
+    env: &Env,
+    lens_type: &LensType,
+    left_eye: &PrescriptionData,
+    right_eye: &PrescriptionData,
+    contact_data: &OptionalContactLensData,
+) -> Result<BytesN<32>, ContractError> {
+    let mut out = Bytes::new(env);
+    out.push_back(match lens_type {
+        LensType::Glasses => 0,
+        LensType::ContactLens => 1,
+        LensType::Progressive => 2,
+    });
+    push_eye(&mut out, left_eye)?;
+    push_eye(&mut out, right_eye)?;
+    match contact_data {
+        OptionalContactLensData::None => out.push_back(0),
+        OptionalContactLensData::Some(data) => {
+            out.push_back(1);
+            push_field(&mut out, &data.base_curve)?;
+            push_field(&mut out, &data.diameter)?;
+            push_field(&mut out, &data.brand)?;
+        }
+    }
+    Ok(env.crypto().sha256(&out).into())
+}
+
+/// Records `rx_id` as the latest prescription for `patient` with `hash`.
+pub fn index_canonical_hash(env: &Env, patient: &Address, hash: &BytesN<32>, rx_id: u64) {
+    let key = (RX_CANON, patient.clone(), hash.clone());
+    env.storage().persistent().set(&key, &rx_id);
+    ttl_config::extend(env, &key);
+}
+
+pub fn find_by_canonical_hash(env: &Env, patient: &Address, hash: &BytesN<32>) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&(RX_CANON, patient.clone(), hash.clone()))
+}
+
+pub fn get_duplicate_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&RX_DUP_WINDOW)
+        .unwrap_or(DEFAULT_DUPLICATE_WINDOW)
+}
+
+pub fn set_duplicate_window(env: &Env, window_seconds: u64) {
+    env.storage()
+        .instance()
+        .set(&RX_DUP_WINDOW, &window_seconds);
+}
+
+/// Returns the existing prescription that `hash` duplicates, if one was
+/// issued for `patient` within the duplicate window.
+pub fn find_recent_duplicate(
+    env: &Env,
+    patient: &Address,
+    hash: &BytesN<32>,
+    now: u64,
+) -> Option<Prescription> {
+    let existing = get_prescription(env, find_by_canonical_hash(env, patient, hash)?)?;
+    if now < existing.issued_at.saturating_add(get_duplicate_window(env)) {
+        Some(existing)
+    } else {
+        None
+    }
+}
+
+// ── Lens-type validation ─────────────────────────────────────
+
+/// A prescription that failed validation, with the offending field.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FieldError {
+    pub error: ContractError,
+    pub field: &'static str,
+}
+
+impl FieldError {
+    fn missing(field: &'static str) -> Self {
+        Self {
+            error: ContractError::MissingRequiredField,
+            field,
+        }
+    }
+
+    fn invalid(field: &'static str) -> Self {
+        Self {
+            error: ContractError::InvalidInput,
+            field,
+        }
+    }
+}
+
+fn read<'a>(
+    value: &String,
+    buf: &'a mut [u8; MAX_FIELD_LEN],
+    field: &'static str,
+) -> Result<&'a [u8], FieldError> {
+    trimmed(value, buf).map_err(|_| FieldError::invalid(field))
+}
+
+fn require_present(value: &String, field: &'static str) -> Result<(), FieldError> {
+    let mut buf = [0u8; MAX_FIELD_LEN];
+    if read(value, &mut buf, field)?.is_empty() {
+        return Err(FieldError::missing(field));
+    }
+    Ok(())
+}
+
+/// A progressive lens needs a positive add power.
+fn require_add(value: &String, field: &'static str) -> Result<(), FieldError> {
+    let mut buf = [0u8; MAX_FIELD_LEN];
+    let add = read(value, &mut buf, field)?;
+    if add.is_empty() {
+        return Err(FieldError::missing(field));
+    }
+    match parse_hundredths(add) {
+        Some(n) if n > 0 => Ok(()),
+        Some(0) => Err(FieldError::missing(field)),
+        _ => Err(FieldError::invalid(field)),
+    }
+}
+
+/// Prism is optional, but when given it must be a positive amount with a
+/// base direction.
+fn validate_prism(
+    eye: &PrescriptionData,
+    prism_field: &'static str,
+    base_field: &'static str,
+) -> Result<(), FieldError> {
+    let mut prism_buf = [0u8; MAX_FIELD_LEN];
+    let mut base_buf = [0u8; MAX_FIELD_LEN];
+    let prism = read(&eye.prism, &mut prism_buf, prism_field)?;
+    let base = read(&eye.prism_base, &mut base_buf, base_field)?;
+
+    if prism.is_empty() {
+        return if base.is_empty() {
+            Ok(())
+        } else {
+            Err(FieldError::missing(prism_field))
+        };
+    }
+    match parse_hundredths(prism) {
+        Some(n) if n > 0 => {}
+        _ => return Err(FieldError::invalid(prism_field)),
+    }
+    if base.is_empty() {
+        return Err(FieldError::missing(base_field));
+    }
+    let directions: [&[u8]; 4] = [b"in", b"out", b"up", b"down"];
+    let valid_base = directions.iter().any(|dir| base.eq_ignore_ascii_case(dir));
+    if !valid_base {
+        return Err(FieldError::invalid(base_field));
+    }
+    Ok(())
+}
+
+/// Checks the fields each lens type depends on.
+///
+/// - Contact lenses need `ContactLensData` with a base curve and diameter.
+/// - Progressive lenses need an add power for both eyes.
+/// - Prism is only meaningful for spectacles and is ignored for contacts.
+pub fn validate_for_lens_type(
+    lens_type: &LensType,
+    left_eye: &PrescriptionData,
+    right_eye: &PrescriptionData,
+    contact_data: &OptionalContactLensData,
+) -> Result<(), FieldError> {
+    match lens_type {
+        LensType::ContactLens => match contact_data {
+            OptionalContactLensData::None => Err(FieldError::missing("contact_data")),
+            OptionalContactLensData::Some(data) => {
+                require_present(&data.base_curve, "contact_data.base_curve")?;
+                require_present(&data.diameter, "contact_data.diameter")
+            }
+        },
+        LensType::Glasses | LensType::Progressive => {
+            if *lens_type == LensType::Progressive {
+                require_add(&left_eye.add, "left_eye.add")?;
+                require_add(&right_eye.add, "right_eye.add")?;
+            }
+            validate_prism(left_eye, "left_eye.prism", "left_eye.prism_base")?;
+            validate_prism(right_eye, "right_eye.prism", "right_eye.prism_base")
+        }
+    }
+}
+
+/// Moves every prescription in `from`'s history to `to`, rewriting the
+/// patient on each prescription and its canonical hash entry. Returns the
+/// number of prescriptions moved.
+pub fn move_history(env: &Env, from: &Address, to: &Address) -> u32 {
+    let from_key = (symbol_short!("RX_HIST"), from.clone());
+    let moved = get_patient_history(env, from.clone());
+    if moved.is_empty() {
+        return 0;
+    }
+
+    let mut history = get_patient_history(env, to.clone());
+    for id in moved.iter() {
+        if let Some(mut rx) = get_prescription(env, id) {
+            env.storage()
+                .persistent()
+                .remove(&(RX_CANON, from.clone(), rx.canonical_hash.clone()));
+            rx.patient = to.clone();
+            env.storage()
+                .persistent()
+                .set(&(symbol_short!("RX"), id), &rx);
+            if find_by_canonical_hash(env, to, &rx.canonical_hash).is_none() {
+                index_canonical_hash(env, to, &rx.canonical_hash, id);
+            }
+        }
+        history.push_back(id);
+    }
+    env.storage()
+        .persistent()
+        .set(&(symbol_short!("RX_HIST"), to.clone()), &history);
+    env.storage().persistent().remove(&from_key);
+
+    let mut active = get_active(env, to);
+    for id in get_active(env, from).iter() {
+        active.push_back(id);
+    }
+    set_active(env, to, &active);
+    set_active(env, from, &Vec::new(env));
+
+    moved.len()
+}
@@ -29,6 +29,8 @@ fn test_prescription_workflow() {
         axis: String::from_str(&env, "180"),
         add: String::from_str(&env, "0.00"),
         pd: String::from_str(&env, "62"),
+        prism: String::from_str(&env, ""),
+        prism_base: String::from_str(&env, ""),
     };
 
     let right_eye = PrescriptionData {
@@ -37,6 +39,8 @@ fn test_prescription_workflow() {
         axis: String::from_str(&env, "175"),
         add: String::from_str(&env, "0.00"),
         pd: String::from_str(&env, "62"),
+        prism: String::from_str(&env, ""),
+        prism_base: String::from_str(&env, ""),
     };
 
     let rx_id = client.add_prescription(
@@ -103,6 +107,8 @@ fn test_contact_lens_workflow() {
         axis: String::from_str(&env, "0"),
         add: String::from_str(&env, "0.00"),
         pd: String::from_str(&env, "60"),
+        prism: String::from_str(&env, ""),
+        prism_base: String::from_str(&env, ""),
     };
 
     let contact_data = ContactLensData {
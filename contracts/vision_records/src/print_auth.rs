@@ -0,0 +1,105 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const PRT_CTR: Symbol = symbol_short!("PRT_CTR");
+const PRT_AUTH: Symbol = symbol_short!("PRT_AUTH");
+const PRT_CODE: Symbol = symbol_short!("PRT_CODE");
+const PRT_RX: Symbol = symbol_short!("PRT_RX");
+
+/// How long a print code can be redeemed after it is issued (15 minutes)
+pub const PRINT_CODE_TTL_SECONDS: u64 = 900;
+
+/// Codes are eight digits so they can be typed in at a kiosk
+const CODE_MIN: u32 = 10_000_000;
+const CODE_MAX: u32 = 99_999_999;
+
+/// Extends the time-to-live (TTL) for authorization and code keys.
+fn extend_ttl_u64_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Authorization to print one paper copy of a prescription. The code is
+/// written on the printout, tying it back to this entry.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrintAuthorization {
+    pub id: u64,
+    pub rx_id: u64,
+    pub patient: Address,
+    pub provider: Address,
+    pub code: u32,
+    pub issued_at: u64,
+    pub expires_at: u64,
+    pub redeemed_by: Option<Address>,
+    pub redeemed_at: Option<u64>,
+}
+
+impl PrintAuthorization {
+    pub fn is_redeemable(&self, now: u64) -> bool {
+        self.redeemed_at.is_none() && now < self.expires_at
+    }
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Increments and returns the next authorization ID
+pub fn increment_counter(env: &Env) -> u64 {
+    let current: u64 = env.storage().instance().get(&PRT_CTR).unwrap_or(0);
+    let next = current.saturating_add(1);
+    env.storage().instance().set(&PRT_CTR, &next);
+    next
+}
+
+pub fn get(env: &Env, id: u64) -> Option<PrintAuthorization> {
+    env.storage().persistent().get(&(PRT_AUTH, id))
+}
+
+pub fn save(env: &Env, auth: &PrintAuthorization) {
+    let key = (PRT_AUTH, auth.id);
+    env.storage().persistent().set(&key, auth);
+    extend_ttl_u64_key(env, &key);
+}
+
+/// The authorization `code` currently points at, if it is still redeemable
+pub fn find_by_code(env: &Env, code: u32) -> Option<PrintAuthorization> {
+    let id: u64 = env.storage().persistent().get(&(PRT_CODE, code as u64))?;
+    get(env, id).filter(|auth| auth.is_redeemable(env.ledger().timestamp()))
+}
+
+/// Draws a code no redeemable authorization is using and points it at `id`.
+pub fn assign_code(env: &Env, id: u64) -> u32 {
+    let code = loop {
+        let candidate: u64 = env.prng().gen_range(CODE_MIN as u64..=CODE_MAX as u64);
+        if find_by_code(env, candidate as u32).is_none() {
+            break candidate as u32;
+        }
+    };
+    let key = (PRT_CODE, code as u64);
+    env.storage().persistent().set(&key, &id);
+    extend_ttl_u64_key(env, &key);
+    code
+}
+
+/// Frees `code` once its authorization is consumed.
+pub fn release_code(env: &Env, code: u32) {
+    env.storage().persistent().remove(&(PRT_CODE, code as u64));
+}
+
+pub fn add_to_prescription(env: &Env, rx_id: u64, id: u64) {
+    let key = (PRT_RX, rx_id);
+    let mut ids = get_for_prescription(env, rx_id);
+    ids.push_back(id);
+    env.storage().persistent().set(&key, &ids);
+    extend_ttl_u64_key(env, &key);
+}
+
+pub fn get_for_prescription(env: &Env, rx_id: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(PRT_RX, rx_id))
+        .unwrap_or(Vec::new(env))
+}
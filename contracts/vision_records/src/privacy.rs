@@ -0,0 +1,44 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const PRIV_SET: Symbol = symbol_short!("PRIV_SET");
+
+/// Extends the time-to-live (TTL) for privacy settings keys.
+fn extend_ttl_privacy_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Patient-controlled privacy preferences
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrivacySettings {
+    pub patient: Address,
+    /// Covering (locum) providers may not read through the patient's
+    /// grants to an absent provider
+    pub locum_opt_out: bool,
+    pub updated_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn set_settings(env: &Env, settings: &PrivacySettings) {
+    let key = (PRIV_SET, settings.patient.clone());
+    env.storage().persistent().set(&key, settings);
+    extend_ttl_privacy_key(env, &key);
+}
+
+/// Returns the patient's settings, or the defaults if none have been saved
+pub fn get_settings(env: &Env, patient: &Address) -> PrivacySettings {
+    env.storage()
+        .persistent()
+        .get(&(PRIV_SET, patient.clone()))
+        .unwrap_or(PrivacySettings {
+            patient: patient.clone(),
+            locum_opt_out: false,
+            updated_at: 0,
+        })
+}
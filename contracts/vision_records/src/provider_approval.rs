@@ -0,0 +1,98 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+use crate::{
+    rbac::{self, Permission},
+    Role,
+};
+
+// ── Storage keys ──────────────────────────────────────────────
+const CRED_AUTH: Symbol = symbol_short!("CRED_AUTH");
+const PRV_PEND: Symbol = symbol_short!("PRV_PEND");
+const PRV_QUEUE: Symbol = symbol_short!("PRV_QUEUE");
+
+/// Extends the time-to-live (TTL) for per-user pending registration keys.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A provider registration waiting for an admin or the credentialing
+/// authority. The user exists but stays inactive, with no role assigned,
+/// until it is approved.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingRegistration {
+    pub user: Address,
+    pub role: Role,
+    pub requested_by: Address,
+    pub requested_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Roles that can write records and so need approval before activation
+pub fn is_provider_role(role: &Role) -> bool {
+    matches!(role, Role::Optometrist | Role::Ophthalmologist)
+}
+
+pub fn set_credentialing_authority(env: &Env, authority: &Option<Address>) {
+    match authority {
+        Some(authority) => env.storage().instance().set(&CRED_AUTH, authority),
+        None => env.storage().instance().remove(&CRED_AUTH),
+    }
+}
+
+pub fn get_credentialing_authority(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&CRED_AUTH)
+}
+
+/// A SystemAdmin or the configured credentialing authority
+pub fn can_approve(env: &Env, caller: &Address) -> bool {
+    rbac::has_permission(env, caller, &Permission::SystemAdmin)
+        || get_credentialing_authority(env).as_ref() == Some(caller)
+}
+
+pub fn enqueue(env: &Env, pending: &PendingRegistration) {
+    let key = (PRV_PEND, pending.user.clone());
+    env.storage().persistent().set(&key, pending);
+    extend_ttl_address_key(env, &key);
+
+    let mut queue = queue(env);
+    if !queue.contains(&pending.user) {
+        queue.push_back(pending.user.clone());
+        env.storage().instance().set(&PRV_QUEUE, &queue);
+    }
+}
+
+pub fn get(env: &Env, user: &Address) -> Option<PendingRegistration> {
+    env.storage().persistent().get(&(PRV_PEND, user.clone()))
+}
+
+pub fn remove(env: &Env, user: &Address) {
+    env.storage().persistent().remove(&(PRV_PEND, user.clone()));
+    let mut queue = queue(env);
+    if let Some(i) = queue.first_index_of(user) {
+        queue.remove(i);
+        env.storage().instance().set(&PRV_QUEUE, &queue);
+    }
+}
+
+fn queue(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&PRV_QUEUE)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Pending registrations, oldest first
+pub fn list(env: &Env) -> Vec<PendingRegistration> {
+    let mut pending = Vec::new(env);
+    for user in queue(env).iter() {
+        if let Some(entry) = get(env, &user) {
+            pending.push_back(entry);
+        }
+    }
+    pending
+}
@@ -0,0 +1,103 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+use crate::RecordType;
+
+// ── Storage keys ──────────────────────────────────────────────
+const PCN: Symbol = symbol_short!("PCN");
+const PCN_LST: Symbol = symbol_short!("PCN_LST");
+
+/// Extends the time-to-live (TTL) for (patient, purpose) consent keys.
+fn extend_ttl_consent_key(env: &Env, key: &(Symbol, Address, ConsentPurpose)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-patient purpose index keys.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Why data is being disclosed. Unlike an access grant, consent names a
+/// use rather than a recipient.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConsentPurpose {
+    Treatment,
+    Research,
+    Insurance,
+    PublicHealth,
+}
+
+/// Which of the patient's records a purpose consent covers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConsentScope {
+    AllRecords,
+    RecordType(RecordType),
+    Record(u64),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PurposeConsent {
+    pub patient: Address,
+    pub purpose: ConsentPurpose,
+    pub scope: ConsentScope,
+    pub recorded_at: u64,
+    pub expires_at: u64,
+    pub withdrawn_at: Option<u64>,
+}
+
+impl PurposeConsent {
+    pub fn is_active(&self, now: u64) -> bool {
+        self.withdrawn_at.is_none() && now < self.expires_at
+    }
+
+    /// True if the scope takes in a record with this id and type.
+    pub fn covers(&self, record_id: u64, record_type: &RecordType) -> bool {
+        match &self.scope {
+            ConsentScope::AllRecords => true,
+            ConsentScope::RecordType(t) => t == record_type,
+            ConsentScope::Record(id) => *id == record_id,
+        }
+    }
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get(env: &Env, patient: &Address, purpose: ConsentPurpose) -> Option<PurposeConsent> {
+    env.storage()
+        .persistent()
+        .get(&(PCN, patient.clone(), purpose))
+}
+
+/// Stores `consent`, replacing any earlier consent for the same purpose.
+pub fn set(env: &Env, consent: &PurposeConsent) {
+    let key = (PCN, consent.patient.clone(), consent.purpose);
+    env.storage().persistent().set(&key, consent);
+    extend_ttl_consent_key(env, &key);
+
+    let mut purposes = purposes(env, &consent.patient);
+    if !purposes.contains(consent.purpose) {
+        purposes.push_back(consent.purpose);
+        let index_key = (PCN_LST, consent.patient.clone());
+        env.storage().persistent().set(&index_key, &purposes);
+        extend_ttl_address_key(env, &index_key);
+    }
+}
+
+/// Every purpose the patient has recorded consent for, in first-recorded
+/// order.
+pub fn purposes(env: &Env, patient: &Address) -> Vec<ConsentPurpose> {
+    env.storage()
+        .persistent()
+        .get(&(PCN_LST, patient.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// The patient's live consent for `purpose`, if any.
+pub fn active(env: &Env, patient: &Address, purpose: ConsentPurpose) -> Option<PurposeConsent> {
+    get(env, patient, purpose).filter(|c| c.is_active(env.ledger().timestamp()))
+}
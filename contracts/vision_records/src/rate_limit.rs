@@ -1,5 +1,7 @@
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
 
+use crate::ttl_config;
+
 // ── Storage keys ──────────────────────────────────────────────
 pub(crate) const RATE_LIMIT_CONFIG: Symbol = symbol_short!("RL_CFG");
 pub(crate) const RATE_LIMIT_WINDOW: Symbol = symbol_short!("RL_WIN");
@@ -7,32 +9,21 @@ pub(crate) const RATE_LIMIT_COUNT: Symbol = symbol_short!("RL_CNT");
 pub(crate) const RATE_LIMIT_BYPASS: Symbol = symbol_short!("RL_BYP");
 
 
-const TTL_THRESHOLD: u32 = 5184000;
-const TTL_EXTEND_TO: u32 = 10368000;
-
 /// Extends the time-to-live (TTL) for rate limit storage keys.
 fn extend_ttl_config_key(env: &Env, key: &(Symbol, String)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 fn extend_ttl_window_key(env: &Env, key: &(Symbol, Address, String)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 fn extend_ttl_count_key(env: &Env, key: &(Symbol, Address, String)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 fn extend_ttl_bypass_key(env: &Env, key: &(Symbol, Address)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 // ── Types ─────────────────────────────────────────────────────
@@ -1,7 +1,6 @@
 use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
 
-const TTL_THRESHOLD: u32 = 5184000;
-const TTL_EXTEND_TO: u32 = 10368000;
+use crate::ttl_config;
 
 /// Time-based access restrictions
 #[contracttype]
@@ -60,15 +59,11 @@ pub struct AccessPolicy {
 }
 
 fn extend_ttl_address_key(env: &Env, key: &(soroban_sdk::Symbol, Address)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 fn extend_ttl_delegation_key(env: &Env, key: &(soroban_sdk::Symbol, Address, Address)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 #[contracttype]
@@ -91,6 +86,12 @@ pub enum Role {
     Optometrist = 3,
     Ophthalmologist = 4,
     Admin = 5,
+    /// Receives and acknowledges pharmacovigilance reports
+    Regulator = 6,
+    /// Records coverage hints on prescriptions after adjudicating a claim
+    Payer = 7,
+    /// Submits results for tests ordered by providers
+    Lab = 8,
 }
 
 pub fn get_base_permissions(env: &Env, role: &Role) -> Vec<Permission> {
@@ -247,6 +248,13 @@ pub fn assign_role(env: &Env, user: Address, role: Role, expires_at: u64) {
     extend_ttl_address_key(env, &key);
 }
 
+/// Remove a user's role assignment, including any custom grants on it
+pub fn clear_role(env: &Env, user: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&user_assignment_key(user));
+}
+
 /// Retrieve the active assignment for a user, or None if it doesn't exist or is expired
 pub fn get_active_assignment(env: &Env, user: &Address) -> Option<RoleAssignment> {
     if let Some(assignment) = env
@@ -261,6 +269,13 @@ pub fn get_active_assignment(env: &Env, user: &Address) -> Option<RoleAssignment
     None
 }
 
+/// Returns true if `user` currently holds `role`
+pub fn has_role(env: &Env, user: &Address, role: &Role) -> bool {
+    get_active_assignment(env, user)
+        .map(|assignment| assignment.role == *role)
+        .unwrap_or(false)
+}
+
 /// Set custom permissions for an existing assignment
 pub fn grant_custom_permission(env: &Env, user: Address, permission: Permission) -> Result<(), ()> {
     let mut assignment = get_active_assignment(env, &user).ok_or(())?;
@@ -624,7 +639,7 @@ fn satisfies_time_restriction(env: &Env, restriction: &TimeRestriction) -> bool
 }
 
 /// Get user's credential type from storage
-fn get_user_credential(env: &Env, user: &Address) -> CredentialType {
+pub fn get_user_credential(env: &Env, user: &Address) -> CredentialType {
     let key = user_credential_key(user);
     env.storage()
         .persistent()
@@ -633,7 +648,7 @@ fn get_user_credential(env: &Env, user: &Address) -> CredentialType {
 }
 
 /// Get record sensitivity level from storage
-fn get_record_sensitivity(env: &Env, record_id: &u64) -> SensitivityLevel {
+pub fn get_record_sensitivity(env: &Env, record_id: &u64) -> SensitivityLevel {
     let key = record_sensitivity_key(record_id);
     env.storage()
         .persistent()
@@ -784,9 +799,7 @@ pub fn create_access_policy(env: &Env, policy: AccessPolicy) {
 }
 
 fn extend_ttl_u64_key(env: &Env, key: &(soroban_sdk::Symbol, u64)) {
-    env.storage()
-        .persistent()
-        .extend_ttl(key, TTL_THRESHOLD, TTL_EXTEND_TO);
+    ttl_config::extend(env, key);
 }
 
 /// Consent grant structure for ABAC evaluation
@@ -824,6 +837,9 @@ pub fn build_eval_context(
             Role::Optometrist => "optometrist",
             Role::Ophthalmologist => "ophthalmologist",
             Role::Admin => "admin",
+            Role::Regulator => "regulator",
+            Role::Payer => "payer",
+            Role::Lab => "lab",
         };
         attr_vals.push_back(String::from_str(env, role_str));
     }
@@ -0,0 +1,78 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const RD_CNT: Symbol = symbol_short!("RD_CNT");
+const RD_LOG: Symbol = symbol_short!("RD_LOG");
+
+/// Entries per storage bucket, which is also the page size of
+/// `get_record_access_log`
+pub const READ_LOG_BUCKET_SIZE: u32 = 100;
+
+/// Extends the time-to-live (TTL) for per-record counter keys.
+fn extend_ttl_counter_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for (record, bucket) keys.
+fn extend_ttl_bucket_key(env: &Env, key: &(Symbol, u64, u32)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// One logged read of a record. Entries are only ever appended.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordReadEntry {
+    /// Position in the record's log, starting at 0
+    pub seq: u32,
+    pub record_id: u64,
+    pub reader: Address,
+    pub purpose: String,
+    pub read_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn count(env: &Env, record_id: u64) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&(RD_CNT, record_id))
+        .unwrap_or(0)
+}
+
+/// Appends a read of `record_id` by `reader` and returns the new entry.
+pub fn append(env: &Env, record_id: u64, reader: &Address, purpose: &String) -> RecordReadEntry {
+    let seq = count(env, record_id);
+    let entry = RecordReadEntry {
+        seq,
+        record_id,
+        reader: reader.clone(),
+        purpose: purpose.clone(),
+        read_at: env.ledger().timestamp(),
+    };
+
+    let bucket_key = (RD_LOG, record_id, seq / READ_LOG_BUCKET_SIZE);
+    let mut bucket = page(env, record_id, seq / READ_LOG_BUCKET_SIZE);
+    bucket.push_back(entry.clone());
+    env.storage().persistent().set(&bucket_key, &bucket);
+    extend_ttl_bucket_key(env, &bucket_key);
+
+    let count_key = (RD_CNT, record_id);
+    env.storage()
+        .persistent()
+        .set(&count_key, &seq.saturating_add(1));
+    extend_ttl_counter_key(env, &count_key);
+    entry
+}
+
+/// Entries `page * READ_LOG_BUCKET_SIZE` up to the next page boundary,
+/// oldest first. Empty past the end of the log.
+pub fn page(env: &Env, record_id: u64, page: u32) -> Vec<RecordReadEntry> {
+    env.storage()
+        .persistent()
+        .get(&(RD_LOG, record_id, page))
+        .unwrap_or(Vec::new(env))
+}
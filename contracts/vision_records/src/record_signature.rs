@@ -0,0 +1,90 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol};
+use teye_common::canonical::{self, Domain};
+
+use crate::ttl_config;
+use crate::{RecordType, VisionRecord};
+
+// ── Storage keys ──────────────────────────────────────────────
+const SIG_KEY: Symbol = symbol_short!("SIG_KEY");
+const REC_SIG: Symbol = symbol_short!("REC_SIG");
+
+/// Extends the time-to-live (TTL) for per-provider signing key entries.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-record signature entries.
+fn extend_ttl_record_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A provider's Ed25519 signature over a record's canonical hash. The
+/// public key is kept with the signature so it stays verifiable after the
+/// provider rotates keys.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordSignature {
+    pub record_id: u64,
+    pub signer: Address,
+    pub public_key: BytesN<32>,
+    pub canonical_hash: BytesN<32>,
+    pub signature: BytesN<64>,
+    pub signed_at: u64,
+}
+
+/// The record fields covered by a provider's signature.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct SignedRecord {
+    contract: Address,
+    record_id: u64,
+    patient: Address,
+    provider: Address,
+    record_type: RecordType,
+    data_hash: String,
+    created_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get_key(env: &Env, provider: &Address) -> Option<BytesN<32>> {
+    env.storage().persistent().get(&(SIG_KEY, provider.clone()))
+}
+
+pub fn set_key(env: &Env, provider: &Address, public_key: &BytesN<32>) {
+    let key = (SIG_KEY, provider.clone());
+    env.storage().persistent().set(&key, public_key);
+    extend_ttl_address_key(env, &key);
+}
+
+pub fn get(env: &Env, record_id: u64) -> Option<RecordSignature> {
+    env.storage().persistent().get(&(REC_SIG, record_id))
+}
+
+pub fn set(env: &Env, signature: &RecordSignature) {
+    let key = (REC_SIG, signature.record_id);
+    env.storage().persistent().set(&key, signature);
+    extend_ttl_record_key(env, &key);
+}
+
+pub fn remove(env: &Env, record_id: u64) {
+    env.storage().persistent().remove(&(REC_SIG, record_id));
+}
+
+/// Hash a provider signs to vouch for a record: the canonical encoding of
+/// this contract's address and the record's id, patient, provider, type,
+/// plaintext data hash and creation time. Amending the record changes it.
+pub fn canonical_hash(env: &Env, record: &VisionRecord, data_hash: &String) -> BytesN<32> {
+    let payload = SignedRecord {
+        contract: env.current_contract_address(),
+        record_id: record.id,
+        patient: record.patient.clone(),
+        provider: record.provider.clone(),
+        record_type: record.record_type.clone(),
+        data_hash: data_hash.clone(),
+        created_at: record.created_at,
+    };
+    canonical::hash(env, Domain::Signature, &payload)
+}
@@ -0,0 +1,87 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+use crate::ttl_config;
+use crate::RecordType;
+
+// ── Storage keys ──────────────────────────────────────────────
+const CUST_TYPE: Symbol = symbol_short!("CUST_TYPE");
+const CUST_LIST: Symbol = symbol_short!("CUST_LIST");
+
+/// Upper bound on registered custom types, keeping the code list small
+/// enough to live in instance storage.
+pub const MAX_CUSTOM_RECORD_TYPES: u32 = 64;
+
+/// Extends the time-to-live (TTL) for custom record type keys.
+fn extend_ttl_type_key(env: &Env, key: &(Symbol, u32)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Admin-defined record type used through [`RecordType::Custom`].
+///
+/// Only a hash of the label is stored on-chain; clinics resolve it to a
+/// display name such as "Orthokeratology fitting" off-chain.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomRecordType {
+    pub code: u32,
+    pub label_hash: BytesN<32>,
+    /// Retired types stay resolvable for existing records but cannot be
+    /// used for new ones.
+    pub active: bool,
+    pub registered_by: Address,
+    pub registered_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get(env: &Env, code: u32) -> Option<CustomRecordType> {
+    env.storage().persistent().get(&(CUST_TYPE, code))
+}
+
+pub fn set(env: &Env, custom: &CustomRecordType) {
+    let key = (CUST_TYPE, custom.code);
+    env.storage().persistent().set(&key, custom);
+    extend_ttl_type_key(env, &key);
+}
+
+pub fn get_codes(env: &Env) -> Vec<u32> {
+    env.storage()
+        .instance()
+        .get(&CUST_LIST)
+        .unwrap_or(Vec::new(env))
+}
+
+/// Adds `code` to the registry list. Returns false if the list is full.
+pub fn add_code(env: &Env, code: u32) -> bool {
+    let mut codes = get_codes(env);
+    if codes.contains(code) {
+        return true;
+    }
+    if codes.len() >= MAX_CUSTOM_RECORD_TYPES {
+        return false;
+    }
+    codes.push_back(code);
+    env.storage().instance().set(&CUST_LIST, &codes);
+    true
+}
+
+pub fn get_all(env: &Env) -> Vec<CustomRecordType> {
+    let mut all = Vec::new(env);
+    for code in get_codes(env).iter() {
+        if let Some(custom) = get(env, code) {
+            all.push_back(custom);
+        }
+    }
+    all
+}
+
+/// Returns true if new records and policies may use `record_type`.
+/// Built-in types always can; custom types must be registered and active.
+pub fn is_usable(env: &Env, record_type: &RecordType) -> bool {
+    match record_type {
+        RecordType::Custom(code) => get(env, *code).map(|c| c.active).unwrap_or(false),
+        _ => true,
+    }
+}
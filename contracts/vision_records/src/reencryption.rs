@@ -0,0 +1,95 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const REENC: Symbol = symbol_short!("REENC");
+const REENC_LST: Symbol = symbol_short!("REENC_LST");
+
+/// Extends the time-to-live (TTL) for (record, grantee) grant keys.
+fn extend_ttl_grant_key(env: &Env, key: &(Symbol, u64, Address)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-record grantee index keys.
+fn extend_ttl_record_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// The key material a grantee needs to decrypt one record's document: a
+/// pointer to a proxy re-encryption capsule or a copy of the document key
+/// wrapped to the grantee. `key_id` is the patient key the material was
+/// made from, when the record is tagged with one.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReencryptionGrant {
+    pub patient: Address,
+    pub grantee: Address,
+    pub record_id: u64,
+    pub wrapped_key_hash: String,
+    pub key_id: Option<u32>,
+    pub granted_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get(env: &Env, record_id: u64, grantee: &Address) -> Option<ReencryptionGrant> {
+    env.storage()
+        .persistent()
+        .get(&(REENC, record_id, grantee.clone()))
+}
+
+/// Grantees holding key material for `record_id`, in the order first
+/// granted.
+pub fn grantees(env: &Env, record_id: u64) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&(REENC_LST, record_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Stores `grant`, replacing the grantee's earlier one for the record.
+pub fn set(env: &Env, grant: &ReencryptionGrant) {
+    let key = (REENC, grant.record_id, grant.grantee.clone());
+    env.storage().persistent().set(&key, grant);
+    extend_ttl_grant_key(env, &key);
+
+    let mut list = grantees(env, grant.record_id);
+    if !list.contains(&grant.grantee) {
+        list.push_back(grant.grantee.clone());
+        let list_key = (REENC_LST, grant.record_id);
+        env.storage().persistent().set(&list_key, &list);
+        extend_ttl_record_key(env, &list_key);
+    }
+}
+
+/// Removes the grantee's key material for the record. Returns false if
+/// there was none.
+pub fn remove(env: &Env, record_id: u64, grantee: &Address) -> bool {
+    let key = (REENC, record_id, grantee.clone());
+    if !env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage().persistent().remove(&key);
+
+    let mut list = grantees(env, record_id);
+    if let Some(i) = list.first_index_of(grantee) {
+        list.remove(i);
+        env.storage()
+            .persistent()
+            .set(&(REENC_LST, record_id), &list);
+    }
+    true
+}
+
+/// Drops every grantee's key material for the record.
+pub fn remove_record(env: &Env, record_id: u64) {
+    for grantee in grantees(env, record_id).iter() {
+        env.storage()
+            .persistent()
+            .remove(&(REENC, record_id, grantee));
+    }
+    env.storage().persistent().remove(&(REENC_LST, record_id));
+}
@@ -0,0 +1,119 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const REF_CTR: Symbol = symbol_short!("REF_CTR");
+const REF: Symbol = symbol_short!("REF");
+const REF_PROV: Symbol = symbol_short!("REF_PROV");
+const REF_PAT: Symbol = symbol_short!("REF_PAT");
+
+/// How long the Read grant issued on an accepted referral lasts (90 days)
+pub const REFERRAL_GRANT_SECONDS: u64 = 7_776_000;
+
+/// Extends the time-to-live (TTL) for referral keys.
+fn extend_ttl_referral_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-address referral index keys.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReferralUrgency {
+    Routine,
+    Urgent,
+    Emergency,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReferralStatus {
+    Pending,
+    Accepted,
+    Declined,
+}
+
+/// A patient referral between providers.
+///
+/// `assigned_provider` differs from `requested_provider` when the request
+/// was rerouted to locum cover. `queued_until` is set when the requested
+/// provider was away without cover and the referral waits for their return.
+/// `reason_hash` commits to the clinical reason, which is kept off-chain.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Referral {
+    pub id: u64,
+    pub patient: Address,
+    pub referring_provider: Address,
+    pub requested_provider: Address,
+    pub assigned_provider: Address,
+    pub arrangement_id: Option<u64>,
+    pub queued_until: Option<u64>,
+    pub reason_hash: BytesN<32>,
+    pub urgency: ReferralUrgency,
+    pub status: ReferralStatus,
+    pub created_at: u64,
+    pub responded_at: Option<u64>,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Increments and returns the next referral ID
+pub fn increment_counter(env: &Env) -> u64 {
+    let current: u64 = env.storage().instance().get(&REF_CTR).unwrap_or(0);
+    let next = current.saturating_add(1);
+    env.storage().instance().set(&REF_CTR, &next);
+    next
+}
+
+pub fn get(env: &Env, id: u64) -> Option<Referral> {
+    env.storage().persistent().get(&(REF, id))
+}
+
+fn get_index(env: &Env, prefix: Symbol, address: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(prefix, address.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn push_index(env: &Env, prefix: Symbol, address: &Address, id: u64) {
+    let key = (prefix.clone(), address.clone());
+    let mut ids = get_index(env, prefix, address);
+    ids.push_back(id);
+    env.storage().persistent().set(&key, &ids);
+    extend_ttl_address_key(env, &key);
+}
+
+/// Stores a new referral and indexes it under the patient and the
+/// provider it was assigned to.
+pub fn create(env: &Env, referral: &Referral) {
+    let key = (REF, referral.id);
+    env.storage().persistent().set(&key, referral);
+    extend_ttl_referral_key(env, &key);
+    push_index(env, REF_PROV, &referral.assigned_provider, referral.id);
+    push_index(env, REF_PAT, &referral.patient, referral.id);
+}
+
+/// Overwrites an existing referral, e.g. after the assigned provider
+/// responds. Indexes are left as they are.
+pub fn update(env: &Env, referral: &Referral) {
+    let key = (REF, referral.id);
+    env.storage().persistent().set(&key, referral);
+    extend_ttl_referral_key(env, &key);
+}
+
+/// Referral ids assigned to `provider`
+pub fn get_provider_ids(env: &Env, provider: &Address) -> Vec<u64> {
+    get_index(env, REF_PROV, provider)
+}
+
+pub fn get_patient_ids(env: &Env, patient: &Address) -> Vec<u64> {
+    get_index(env, REF_PAT, patient)
+}
@@ -0,0 +1,106 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol};
+use teye_common::interface::{self, InterfaceRange};
+
+use crate::ttl_config;
+use crate::{ContractError, Role};
+
+// ── Storage keys ──────────────────────────────────────────────
+const ZK_VERIFIER: Symbol = symbol_short!("REG_ZK");
+const REG_PROOF: Symbol = symbol_short!("REG_PRF");
+
+/// Extends the time-to-live (TTL) for per-role registration proof keys.
+fn extend_ttl_role_key(env: &Env, key: &(Symbol, Role)) {
+    ttl_config::extend(env, key);
+}
+
+// ── ZK verifier interface ────────────────────────────────────
+
+/// zk_verifier interface versions this contract can call
+pub const ZK_VERIFIER_INTERFACE: InterfaceRange = InterfaceRange {
+    interface: interface::ZK_VERIFIER,
+    major: 1,
+    min_minor: 0,
+};
+
+/// Interface exposed by the zk_verifier contract for consuming receipts.
+#[soroban_sdk::contractclient(name = "ZkVerifierClient")]
+pub trait ZkVerifierInterface {
+    /// Marks the latest verification of `user` against `resource_id` as used
+    /// by `consumer`. Returns false if there is no fresh, unconsumed receipt.
+    fn consume_receipt(
+        env: Env,
+        consumer: Address,
+        user: Address,
+        resource_id: BytesN<32>,
+        max_age: u64,
+    ) -> bool;
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Proof a user must hold before registering themselves under `role`.
+///
+/// `resource_id` names the zk statement (e.g. "adult resident of the
+/// deployment's jurisdiction") the user must have proven to the verifier,
+/// no more than `max_proof_age` seconds before registering.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegistrationProofRequirement {
+    pub role: Role,
+    pub resource_id: BytesN<32>,
+    pub max_proof_age: u64,
+    pub updated_by: Address,
+    pub updated_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn set_zk_verifier(env: &Env, verifier: &Address) {
+    env.storage().instance().set(&ZK_VERIFIER, verifier);
+}
+
+pub fn get_zk_verifier(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&ZK_VERIFIER)
+}
+
+pub fn set_requirement(env: &Env, requirement: &RegistrationProofRequirement) {
+    let key = (REG_PROOF, requirement.role.clone());
+    env.storage().persistent().set(&key, requirement);
+    extend_ttl_role_key(env, &key);
+}
+
+pub fn remove_requirement(env: &Env, role: &Role) {
+    env.storage()
+        .persistent()
+        .remove(&(REG_PROOF, role.clone()));
+}
+
+pub fn get_requirement(env: &Env, role: &Role) -> Option<RegistrationProofRequirement> {
+    env.storage().persistent().get(&(REG_PROOF, role.clone()))
+}
+
+/// Checks that `user` may register themselves under `role` by consuming a
+/// fresh receipt from the zk verifier for the role's configured statement.
+///
+/// Nothing about the proof itself (birth date, residence) is stored here;
+/// the verifier only confirms that a matching proof was verified.
+pub fn verify_self_registration(
+    env: &Env,
+    user: &Address,
+    role: &Role,
+) -> Result<(), ContractError> {
+    let requirement = get_requirement(env, role).ok_or(ContractError::Unauthorized)?;
+    let verifier = get_zk_verifier(env).ok_or(ContractError::RegistrationProofRequired)?;
+
+    let client = ZkVerifierClient::new(env, &verifier);
+    if !client.consume_receipt(
+        &env.current_contract_address(),
+        user,
+        &requirement.resource_id,
+        &requirement.max_proof_age,
+    ) {
+        return Err(ContractError::RegistrationProofRequired);
+    }
+
+    Ok(())
+}
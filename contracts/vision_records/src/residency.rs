@@ -0,0 +1,149 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const RES_TAG: Symbol = symbol_short!("RES_TAG");
+const RES_ORG: Symbol = symbol_short!("RES_ORG");
+const RES_POL: Symbol = symbol_short!("RES_POL");
+const RES_RECS: Symbol = symbol_short!("RES_RECS");
+
+/// Most region codes a single organization policy may allow.
+pub const MAX_ALLOWED_REGIONS: u32 = 16;
+
+/// Extends the time-to-live (TTL) for record tag and org keys.
+fn extend_ttl_u64_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for provider membership keys.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Regions in which an organization may store off-chain record payloads.
+///
+/// Region codes are deployment-defined symbols such as `eu_west` or `us`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResidencyPolicy {
+    pub org_id: u64,
+    pub allowed_regions: Vec<Symbol>,
+    pub updated_by: Address,
+    pub updated_at: u64,
+}
+
+impl ResidencyPolicy {
+    pub fn allows(&self, region: &Symbol) -> bool {
+        self.allowed_regions.contains(region)
+    }
+}
+
+/// A record whose residency tag is missing or outside its organization's
+/// current policy.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResidencyViolation {
+    pub record_id: u64,
+    pub provider: Address,
+    pub region: Option<Symbol>,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get_provider_org(env: &Env, provider: &Address) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&(RES_ORG, provider.clone()))
+}
+
+pub fn set_provider_org(env: &Env, provider: &Address, org_id: u64) {
+    let key = (RES_ORG, provider.clone());
+    env.storage().persistent().set(&key, &org_id);
+    extend_ttl_address_key(env, &key);
+}
+
+pub fn clear_provider_org(env: &Env, provider: &Address) {
+    env.storage().persistent().remove(&(RES_ORG, provider.clone()));
+}
+
+pub fn get_policy(env: &Env, org_id: u64) -> Option<ResidencyPolicy> {
+    env.storage().persistent().get(&(RES_POL, org_id))
+}
+
+pub fn set_policy(env: &Env, policy: &ResidencyPolicy) {
+    let key = (RES_POL, policy.org_id);
+    env.storage().persistent().set(&key, policy);
+    extend_ttl_u64_key(env, &key);
+}
+
+pub fn get_tag(env: &Env, record_id: u64) -> Option<Symbol> {
+    env.storage().persistent().get(&(RES_TAG, record_id))
+}
+
+pub fn set_tag(env: &Env, record_id: u64, region: &Symbol) {
+    let key = (RES_TAG, record_id);
+    env.storage().persistent().set(&key, region);
+    extend_ttl_u64_key(env, &key);
+}
+
+/// Returns false if `provider` belongs to an organization whose policy
+/// does not allow `region`. Providers outside any organization, or in one
+/// without a policy, may use any region.
+pub fn is_allowed(env: &Env, provider: &Address, region: &Symbol) -> bool {
+    get_provider_org(env, provider)
+        .and_then(|org_id| get_policy(env, org_id))
+        .map(|policy| policy.allows(region))
+        .unwrap_or(true)
+}
+
+/// Adds a newly written record to its provider's organization index.
+pub fn index_record(env: &Env, provider: &Address, record_id: u64) {
+    if let Some(org_id) = get_provider_org(env, provider) {
+        let key = (RES_RECS, org_id);
+        let mut ids = get_org_records(env, org_id);
+        ids.push_back(record_id);
+        env.storage().persistent().set(&key, &ids);
+        extend_ttl_u64_key(env, &key);
+    }
+}
+
+/// Records written by the organization's providers since they joined it
+pub fn get_org_records(env: &Env, org_id: u64) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(RES_RECS, org_id))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Drops a record's region tag and removes it from its provider's
+/// organization index.
+pub fn remove_record(env: &Env, provider: &Address, record_id: u64) {
+    env.storage().persistent().remove(&(RES_TAG, record_id));
+    if let Some(org_id) = get_provider_org(env, provider) {
+        unindex_record(env, org_id, record_id);
+    }
+}
+
+/// Moves a record from its old provider's organization index to the new
+/// provider's when custody changes hands. The region tag stays.
+pub fn reindex_record(env: &Env, from_provider: &Address, to_provider: &Address, record_id: u64) {
+    let from_org = get_provider_org(env, from_provider);
+    if from_org == get_provider_org(env, to_provider) {
+        return;
+    }
+    if let Some(org_id) = from_org {
+        unindex_record(env, org_id, record_id);
+    }
+    index_record(env, to_provider, record_id);
+}
+
+fn unindex_record(env: &Env, org_id: u64, record_id: u64) {
+    let mut ids = get_org_records(env, org_id);
+    if let Some(i) = ids.first_index_of(record_id) {
+        ids.remove(i);
+        env.storage().persistent().set(&(RES_RECS, org_id), &ids);
+    }
+}
@@ -0,0 +1,93 @@
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const RSP_CTR: Symbol = symbol_short!("RSP_CTR");
+const RSP_ENT: Symbol = symbol_short!("RSP_ENT");
+const RSP_ADDR: Symbol = symbol_short!("RSP_ADDR");
+const RSP_LIST: Symbol = symbol_short!("RSP_LIST");
+
+/// Extends the time-to-live (TTL) for responder directory entries.
+fn extend_ttl_entry_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for responder address lookups.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// A pre-verified emergency responder.
+///
+/// Membership stands in for the per-request provider verification on
+/// break-glass grants, so an ER physician is not held up by registry
+/// lookups at the bedside.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResponderEntry {
+    pub id: u64,
+    pub responder: Address,
+    /// Hash of the facility (hospital, ER unit) the responder serves
+    pub facility_hash: BytesN<32>,
+    pub added_by: Address,
+    pub added_at: u64,
+    pub active: bool,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Increments and returns the next directory membership ID
+pub fn increment_counter(env: &Env) -> u64 {
+    let current: u64 = env.storage().instance().get(&RSP_CTR).unwrap_or(0);
+    let next = current.saturating_add(1);
+    env.storage().instance().set(&RSP_CTR, &next);
+    next
+}
+
+pub fn set_entry(env: &Env, entry: &ResponderEntry) {
+    let key = (RSP_ENT, entry.id);
+    env.storage().persistent().set(&key, entry);
+    extend_ttl_entry_key(env, &key);
+}
+
+pub fn get_entry(env: &Env, id: u64) -> Option<ResponderEntry> {
+    env.storage().persistent().get(&(RSP_ENT, id))
+}
+
+/// Stores a new membership and makes it the responder's current one.
+pub fn add_entry(env: &Env, entry: &ResponderEntry) {
+    set_entry(env, entry);
+
+    let addr_key = (RSP_ADDR, entry.responder.clone());
+    env.storage().persistent().set(&addr_key, &entry.id);
+    extend_ttl_address_key(env, &addr_key);
+
+    let mut ids = get_ids(env);
+    ids.push_back(entry.id);
+    env.storage().persistent().set(&RSP_LIST, &ids);
+    ttl_config::extend(env, &RSP_LIST);
+}
+
+/// The responder's current membership, active or not.
+pub fn get_by_address(env: &Env, responder: &Address) -> Option<ResponderEntry> {
+    let id: u64 = env
+        .storage()
+        .persistent()
+        .get(&(RSP_ADDR, responder.clone()))?;
+    get_entry(env, id)
+}
+
+/// The responder's membership if it is currently active.
+pub fn active_membership(env: &Env, responder: &Address) -> Option<ResponderEntry> {
+    get_by_address(env, responder).filter(|e| e.active)
+}
+
+pub fn get_ids(env: &Env) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&RSP_LIST)
+        .unwrap_or(Vec::new(env))
+}
@@ -0,0 +1,173 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+
+use crate::ttl_config;
+use crate::{RecordType, VisionRecord};
+
+// ── Storage keys ──────────────────────────────────────────────
+const RET_POLICY: Symbol = symbol_short!("RET_POL");
+const RET_OPT_OUT: Symbol = symbol_short!("RET_OPT");
+const RET_EPISODE: Symbol = symbol_short!("RET_EPI");
+const RET_CURSOR: Symbol = symbol_short!("RET_CUR");
+const ARCHIVE: Symbol = symbol_short!("ARCH_SUM");
+
+/// Extends the time-to-live (TTL) for retention policy keys.
+fn extend_ttl_policy_key(env: &Env, key: &(Symbol, RecordType)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-patient retention keys.
+fn extend_ttl_patient_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-record retention keys.
+fn extend_ttl_record_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// How many times the policy window an opted-out patient's records are
+/// kept before archival.
+pub const OPT_OUT_RETENTION_FACTOR: u64 = 2;
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Retention rule for a single record type.
+///
+/// Records of `record_type` older than `retain_seconds` become eligible for
+/// archival by the retention sweeper while the policy is `enabled`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetentionPolicy {
+    pub record_type: RecordType,
+    pub retain_seconds: u64,
+    pub enabled: bool,
+    pub updated_by: Address,
+    pub updated_at: u64,
+}
+
+/// Compact summary left behind once a record has been archived.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArchivalSummary {
+    pub record_id: u64,
+    pub patient: Address,
+    pub provider: Address,
+    pub record_type: RecordType,
+    pub data_hash: String,
+    pub created_at: u64,
+    pub archived_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn set_policy(env: &Env, policy: &RetentionPolicy) {
+    let key = (RET_POLICY, policy.record_type.clone());
+    env.storage().persistent().set(&key, policy);
+    extend_ttl_policy_key(env, &key);
+}
+
+pub fn get_policy(env: &Env, record_type: &RecordType) -> Option<RetentionPolicy> {
+    env.storage()
+        .persistent()
+        .get(&(RET_POLICY, record_type.clone()))
+}
+
+/// Records whether a patient has opted out of the default retention window.
+pub fn set_opt_out(env: &Env, patient: &Address, opted_out: bool) {
+    let key = (RET_OPT_OUT, patient.clone());
+    if opted_out {
+        env.storage().persistent().set(&key, &true);
+        extend_ttl_patient_key(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_opted_out(env: &Env, patient: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .get(&(RET_OPT_OUT, patient.clone()))
+        .unwrap_or(false)
+}
+
+/// Marks a record as belonging to an open episode of care. Such records are
+/// never archived until the episode is closed.
+pub fn set_open_episode(env: &Env, record_id: u64, open: bool) {
+    let key = (RET_EPISODE, record_id);
+    if open {
+        env.storage().persistent().set(&key, &true);
+        extend_ttl_record_key(env, &key);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn is_open_episode(env: &Env, record_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get(&(RET_EPISODE, record_id))
+        .unwrap_or(false)
+}
+
+pub fn get_archival_summary(env: &Env, record_id: u64) -> Option<ArchivalSummary> {
+    env.storage().persistent().get(&(ARCHIVE, record_id))
+}
+
+pub fn is_archived(env: &Env, record_id: u64) -> bool {
+    env.storage().persistent().has(&(ARCHIVE, record_id))
+}
+
+/// Returns the next record id the sweeper will inspect.
+pub fn get_cursor(env: &Env) -> u64 {
+    env.storage().instance().get(&RET_CURSOR).unwrap_or(1)
+}
+
+pub fn set_cursor(env: &Env, cursor: u64) {
+    env.storage().instance().set(&RET_CURSOR, &cursor);
+}
+
+/// Returns true if `record` is past its retention window, extended for
+/// patients who opted out, and nothing is holding it back (an open episode
+/// or a legal hold).
+pub fn is_eligible(env: &Env, record: &VisionRecord, now: u64) -> bool {
+    let policy = match get_policy(env, &record.record_type) {
+        Some(p) if p.enabled => p,
+        _ => return false,
+    };
+    let retain_seconds = if is_opted_out(env, &record.patient) {
+        policy
+            .retain_seconds
+            .saturating_mul(OPT_OUT_RETENTION_FACTOR)
+    } else {
+        policy.retain_seconds
+    };
+    if now < record.created_at.saturating_add(retain_seconds) {
+        return false;
+    }
+    !is_open_episode(env, record.id)
+        && !crate::legal_hold::is_record_held(env, record.id, &record.patient)
+}
+
+/// Replaces the full record with an archival summary.
+pub fn archive_record(env: &Env, record: &VisionRecord, now: u64) -> ArchivalSummary {
+    let summary = ArchivalSummary {
+        record_id: record.id,
+        patient: record.patient.clone(),
+        provider: record.provider.clone(),
+        record_type: record.record_type.clone(),
+        data_hash: record.data_hash.clone(),
+        created_at: record.created_at,
+        archived_at: now,
+    };
+
+    let key = (ARCHIVE, record.id);
+    env.storage().persistent().set(&key, &summary);
+    extend_ttl_record_key(env, &key);
+
+    env.storage()
+        .persistent()
+        .remove(&(symbol_short!("RECORD"), record.id));
+    env.storage().persistent().remove(&(RET_EPISODE, record.id));
+
+    summary
+}
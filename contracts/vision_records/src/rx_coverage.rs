@@ -0,0 +1,49 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const RX_COV: Symbol = symbol_short!("RX_COV");
+
+/// Longest plan reference accepted
+pub const MAX_PLAN_REF_LEN: u32 = 64;
+
+/// Extends the time-to-live (TTL) for per-prescription coverage keys.
+fn extend_ttl_coverage_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// What a payer has adjudicated a prescription to cover, for optical shops
+/// to read at point of sale. A hint only: the claim itself stays with the
+/// claims contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RxCoverage {
+    pub rx_id: u64,
+    pub payer: Address,
+    /// Payer's reference for the patient's plan
+    pub plan_ref: String,
+    /// Payer's coverage category, e.g. `LENSES` or `FRAMES`
+    pub category_code: Symbol,
+    /// Claim whose adjudication backs this hint
+    pub claim_id: u64,
+    pub recorded_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn set(env: &Env, coverage: &RxCoverage) {
+    let key = (RX_COV, coverage.rx_id);
+    env.storage().persistent().set(&key, coverage);
+    extend_ttl_coverage_key(env, &key);
+}
+
+pub fn get(env: &Env, rx_id: u64) -> Option<RxCoverage> {
+    env.storage().persistent().get(&(RX_COV, rx_id))
+}
+
+pub fn remove(env: &Env, rx_id: u64) {
+    env.storage().persistent().remove(&(RX_COV, rx_id));
+}
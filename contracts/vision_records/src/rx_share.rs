@@ -0,0 +1,108 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+use crate::ttl_config;
+use crate::ContractError;
+
+// ── Storage keys ──────────────────────────────────────────────
+const RX_SHARE_CODE: Symbol = symbol_short!("RX_CODE");
+const RX_RECEIPTS: Symbol = symbol_short!("RX_RCPT");
+
+/// How long a zk proof about a prescription stays usable by a retailer.
+pub const PROOF_MAX_AGE: u64 = 86_400;
+
+/// Extends the time-to-live (TTL) for per-prescription share code keys.
+fn extend_ttl_rx_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-patient receipt logs.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// What a retailer is allowed to learn about a shared prescription.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum PrescriptionShareScope {
+    /// Only that the prescription is valid and unexpired.
+    ValidityOnly = 0,
+    /// The full clinical values, e.g. to dispense lenses.
+    FullPrescription = 1,
+}
+
+/// How the retailer proved the patient agreed to the disclosure.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(u32)]
+pub enum ShareMethod {
+    ShareCode = 0,
+    ZkProof = 1,
+}
+
+/// One-off code a patient hands to a retailer. Only its hash is stored.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrescriptionShareCode {
+    pub rx_id: u64,
+    pub code_hash: BytesN<32>,
+    pub scope: PrescriptionShareScope,
+    pub expires_at: u64,
+}
+
+/// Record that a third party consumed a patient's prescription data.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrescriptionConsentReceipt {
+    pub patient: Address,
+    pub retailer_hash: BytesN<32>,
+    pub rx_id: u64,
+    pub scope: PrescriptionShareScope,
+    pub method: ShareMethod,
+    pub timestamp: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn set_share_code(env: &Env, code: &PrescriptionShareCode) {
+    let key = (RX_SHARE_CODE, code.rx_id);
+    env.storage().persistent().set(&key, code);
+    extend_ttl_rx_key(env, &key);
+}
+
+pub fn get_share_code(env: &Env, rx_id: u64) -> Option<PrescriptionShareCode> {
+    env.storage().persistent().get(&(RX_SHARE_CODE, rx_id))
+}
+
+/// Checks `code` against the stored share code for `rx_id` and, if it
+/// matches, burns it so it cannot be presented again.
+pub fn redeem_share_code(
+    env: &Env,
+    rx_id: u64,
+    code: &Bytes,
+) -> Result<PrescriptionShareScope, ContractError> {
+    let stored = get_share_code(env, rx_id).ok_or(ContractError::InvalidShareCredential)?;
+    let presented: BytesN<32> = env.crypto().sha256(code).into();
+    if presented != stored.code_hash || env.ledger().timestamp() >= stored.expires_at {
+        return Err(ContractError::InvalidShareCredential);
+    }
+    env.storage().persistent().remove(&(RX_SHARE_CODE, rx_id));
+    Ok(stored.scope)
+}
+
+pub fn add_receipt(env: &Env, receipt: &PrescriptionConsentReceipt) {
+    let key = (RX_RECEIPTS, receipt.patient.clone());
+    let mut log = get_receipts(env, &receipt.patient);
+    log.push_back(receipt.clone());
+    env.storage().persistent().set(&key, &log);
+    extend_ttl_address_key(env, &key);
+}
+
+pub fn get_receipts(env: &Env, patient: &Address) -> Vec<PrescriptionConsentReceipt> {
+    env.storage()
+        .persistent()
+        .get(&(RX_RECEIPTS, patient.clone()))
+        .unwrap_or(Vec::new(env))
+}
@@ -0,0 +1,211 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::ttl_config;
+use crate::{AccessGrant, AccessLevel, GrantStatus, RecordType, VisionRecord};
+
+// ── Storage keys ──────────────────────────────────────────────
+const SCP_GRT: Symbol = symbol_short!("SCP_GRT");
+const SCP_LST: Symbol = symbol_short!("SCP_LST");
+
+/// Most scoped grants one grantee may hold from one patient
+pub const MAX_SCOPED_GRANTS: u32 = 20;
+
+/// Extends the time-to-live (TTL) for (patient, grantee) scoped grant keys.
+fn extend_ttl_pair_key(env: &Env, key: &(Symbol, Address, Address)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-patient scoped grantee index keys.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// What part of a patient's records a scoped grant covers
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AccessScope {
+    Record(u64),
+    RecordType(RecordType),
+}
+
+/// A grant narrower than the patient-wide `AccessGrant`. A grantee holds at
+/// most one grant per scope; granting the same scope again replaces it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScopedAccessGrant {
+    pub patient: Address,
+    pub grantee: Address,
+    pub scope: AccessScope,
+    pub level: AccessLevel,
+    pub granted_at: u64,
+    pub expires_at: u64,
+    pub status: GrantStatus,
+    pub revoked_at: Option<u64>,
+}
+
+impl ScopedAccessGrant {
+    pub fn is_active(&self, now: u64) -> bool {
+        self.status == GrantStatus::Active && self.expires_at > now
+    }
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get_grants(env: &Env, patient: &Address, grantee: &Address) -> Vec<ScopedAccessGrant> {
+    env.storage()
+        .persistent()
+        .get(&(SCP_GRT, patient.clone(), grantee.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn set_grants(env: &Env, patient: &Address, grantee: &Address, grants: &Vec<ScopedAccessGrant>) {
+    let key = (SCP_GRT, patient.clone(), grantee.clone());
+    env.storage().persistent().set(&key, grants);
+    extend_ttl_pair_key(env, &key);
+}
+
+/// Stores `grant`, replacing any grant with the same scope. Returns false
+/// if the grantee already holds [`MAX_SCOPED_GRANTS`] other scopes.
+pub fn put(env: &Env, grant: &ScopedAccessGrant) -> bool {
+    let mut grants = get_grants(env, &grant.patient, &grant.grantee);
+    match grants.iter().position(|g| g.scope == grant.scope) {
+        Some(i) => grants.set(i as u32, grant.clone()),
+        None if grants.len() >= MAX_SCOPED_GRANTS => return false,
+        None => grants.push_back(grant.clone()),
+    }
+    set_grants(env, &grant.patient, &grant.grantee, &grants);
+
+    let mut grantees = grantees(env, &grant.patient);
+    if !grantees.contains(&grant.grantee) {
+        grantees.push_back(grant.grantee.clone());
+        let index_key = (SCP_LST, grant.patient.clone());
+        env.storage().persistent().set(&index_key, &grantees);
+        extend_ttl_address_key(env, &index_key);
+    }
+    true
+}
+
+/// Everyone `patient` has issued a scoped grant to, in first-granted order.
+pub fn grantees(env: &Env, patient: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&(SCP_LST, patient.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Re-points the active scoped grants `from` issued at `to`, for a patient
+/// merge, and drops `from`'s grants and index. Where `to` already holds a
+/// grant for the same grantee and scope, the one lasting longer is kept.
+/// Returns the number of grantees carried over.
+pub fn move_patient(env: &Env, from: &Address, to: &Address) -> u32 {
+    let now = env.ledger().timestamp();
+    let mut moved = 0u32;
+    for grantee in grantees(env, from).iter() {
+        for mut grant in get_grants(env, from, &grantee).iter() {
+            if !grant.is_active(now) {
+                continue;
+            }
+            let superseded = get_grants(env, to, &grantee).iter().any(|g| {
+                g.scope == grant.scope && g.is_active(now) && g.expires_at >= grant.expires_at
+            });
+            if !superseded {
+                grant.patient = to.clone();
+                put(env, &grant);
+            }
+        }
+        env.storage()
+            .persistent()
+            .remove(&(SCP_GRT, from.clone(), grantee.clone()));
+        moved = moved.saturating_add(1);
+    }
+    env.storage().persistent().remove(&(SCP_LST, from.clone()));
+    moved
+}
+
+/// Marks the grant for `scope` revoked. Returns false if there was no
+/// active grant for it.
+pub fn revoke(env: &Env, patient: &Address, grantee: &Address, scope: &AccessScope) -> bool {
+    let now = env.ledger().timestamp();
+    let mut grants = get_grants(env, patient, grantee);
+    let Some(i) = grants
+        .iter()
+        .position(|g| g.scope == *scope && g.status == GrantStatus::Active)
+    else {
+        return false;
+    };
+    let mut grant = grants.get_unchecked(i as u32);
+    grant.status = GrantStatus::Revoked;
+    grant.revoked_at = Some(now);
+    grants.set(i as u32, grant);
+    set_grants(env, patient, grantee, &grants);
+    true
+}
+
+/// Marks every active scoped grant `grantee` holds from `patient` revoked
+/// and returns how many there were.
+pub fn revoke_all(env: &Env, patient: &Address, grantee: &Address) -> u32 {
+    let now = env.ledger().timestamp();
+    let mut grants = get_grants(env, patient, grantee);
+    let mut revoked = 0u32;
+    for i in 0..grants.len() {
+        let mut grant = grants.get_unchecked(i);
+        if grant.status == GrantStatus::Active {
+            grant.status = GrantStatus::Revoked;
+            grant.revoked_at = Some(now);
+            grants.set(i, grant);
+            revoked = revoked.saturating_add(1);
+        }
+    }
+    if revoked > 0 {
+        set_grants(env, patient, grantee, &grants);
+    }
+    revoked
+}
+
+/// Level of the narrowest active grant covering `record_id` or
+/// `record_type`: a grant on the record itself (scoped, or from
+/// `grant_record_access`) before one on its type. `None` if no scoped
+/// grant applies, in which case the patient-wide grant decides. A
+/// `grant_record_access` grant only counts if the record is `patient`'s.
+pub fn narrowest_level(
+    env: &Env,
+    patient: &Address,
+    grantee: &Address,
+    record_id: Option<u64>,
+    record_type: Option<&RecordType>,
+) -> Option<AccessLevel> {
+    let now = env.ledger().timestamp();
+    let grants = get_grants(env, patient, grantee);
+    let active = |scope: &AccessScope| {
+        grants
+            .iter()
+            .find(|g| g.scope == *scope && g.is_active(now))
+            .map(|g| g.level)
+    };
+
+    if let Some(record_id) = record_id {
+        if let Some(level) = active(&AccessScope::Record(record_id)) {
+            return Some(level);
+        }
+        let owned = env
+            .storage()
+            .persistent()
+            .get::<_, VisionRecord>(&(symbol_short!("RECORD"), record_id))
+            .is_some_and(|record| record.patient == *patient);
+        let legacy: Option<AccessGrant> = if owned {
+            env.storage()
+                .persistent()
+                .get(&(symbol_short!("REC_ACC"), record_id, grantee.clone()))
+        } else {
+            None
+        };
+        if let Some(grant) = legacy {
+            if grant.status == GrantStatus::Active && grant.expires_at > now {
+                return Some(grant.level);
+            }
+        }
+    }
+    record_type.and_then(|t| active(&AccessScope::RecordType(t.clone())))
+}
@@ -0,0 +1,49 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol, Vec};
+
+use crate::rbac::{self, SensitivityLevel};
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const SENS_RCPT: Symbol = symbol_short!("SENS_RCPT");
+
+/// Extends the time-to-live (TTL) for per-record read receipt keys.
+fn extend_ttl_receipt_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Patient-visible proof that a sensitive record was read
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReadReceipt {
+    pub record_id: u64,
+    pub reader: Address,
+    pub read_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// True when `record_id` is held to the stricter handling given to
+/// mental-health-adjacent or genetic-test-linked findings: only explicit
+/// per-record grants open it, emergency grants reveal that it exists but not
+/// its contents, and every read by someone other than the patient leaves a
+/// [`ReadReceipt`].
+pub fn requires_record_grant(env: &Env, record_id: u64) -> bool {
+    rbac::get_record_sensitivity(env, &record_id) == SensitivityLevel::Restricted
+}
+
+pub fn add_receipt(env: &Env, receipt: &ReadReceipt) {
+    let key = (SENS_RCPT, receipt.record_id);
+    let mut receipts = get_receipts(env, receipt.record_id);
+    receipts.push_back(receipt.clone());
+    env.storage().persistent().set(&key, &receipts);
+    extend_ttl_receipt_key(env, &key);
+}
+
+pub fn get_receipts(env: &Env, record_id: u64) -> Vec<ReadReceipt> {
+    env.storage()
+        .persistent()
+        .get(&(SENS_RCPT, record_id))
+        .unwrap_or(Vec::new(env))
+}
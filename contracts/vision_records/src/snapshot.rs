@@ -0,0 +1,223 @@
+use soroban_sdk::{contracttype, symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+use crate::prescription::{self, Prescription};
+use crate::ttl_config;
+use crate::{AccessGrant, ConsentGrant, VisionRecord};
+
+// ── Storage keys ──────────────────────────────────────────────
+const SNAP_VER: Symbol = symbol_short!("SNAP_VER");
+const SNAP_LOG: Symbol = symbol_short!("SNAP_LOG");
+const SNAP_PTY: Symbol = symbol_short!("SNAP_PTY");
+
+/// Changes kept per patient. A mirror whose cursor has fallen further
+/// behind than this must resync from a full snapshot.
+pub const MAX_CHANGE_LOG: u32 = 100;
+
+/// Extends the time-to-live (TTL) for per-patient snapshot keys.
+fn extend_ttl_patient_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StateChangeKind {
+    RecordAdded,
+    RecordAmended,
+    RecordArchived,
+    RecordRestored,
+    PrescriptionAdded,
+    PrescriptionExpired,
+    AccessGranted,
+    AccessRevoked,
+    AccessRestored,
+    ConsentGranted,
+    ConsentRevoked,
+}
+
+/// One entry in a patient's change journal.
+///
+/// `ref_id` is the record or prescription id for record and prescription
+/// changes; `counterparty` is the grantee for grant and consent changes.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StateChange {
+    pub version: u64,
+    pub kind: StateChangeKind,
+    pub ref_id: Option<u64>,
+    pub counterparty: Option<Address>,
+    pub timestamp: u64,
+}
+
+/// Deterministic summary of a patient's on-chain footprint.
+///
+/// Two nodes holding the same records, prescriptions, grants and consents
+/// for a patient compute the same `digest`. `version` is the cursor to pass
+/// to `get_patient_changes_since` on the next incremental sync.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StateDigest {
+    pub patient: Address,
+    pub version: u64,
+    pub digest: BytesN<32>,
+    pub record_count: u32,
+    pub prescription_count: u32,
+    pub grant_count: u32,
+    pub consent_count: u32,
+    pub computed_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get_version(env: &Env, patient: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&(SNAP_VER, patient.clone()))
+        .unwrap_or(0)
+}
+
+pub fn get_log(env: &Env, patient: &Address) -> Vec<StateChange> {
+    env.storage()
+        .persistent()
+        .get(&(SNAP_LOG, patient.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Grantees that have ever held a grant or consent from the patient.
+pub fn get_counterparties(env: &Env, patient: &Address) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&(SNAP_PTY, patient.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Bumps the patient's version and appends a journal entry, dropping the
+/// oldest entry once the journal is full.
+pub fn record_change(
+    env: &Env,
+    patient: &Address,
+    kind: StateChangeKind,
+    ref_id: Option<u64>,
+    counterparty: Option<Address>,
+) {
+    let version = get_version(env, patient).saturating_add(1);
+    let ver_key = (SNAP_VER, patient.clone());
+    env.storage().persistent().set(&ver_key, &version);
+    extend_ttl_patient_key(env, &ver_key);
+
+    if let Some(grantee) = counterparty.clone() {
+        let mut parties = get_counterparties(env, patient);
+        if !parties.contains(&grantee) {
+            parties.push_back(grantee);
+            let pty_key = (SNAP_PTY, patient.clone());
+            env.storage().persistent().set(&pty_key, &parties);
+            extend_ttl_patient_key(env, &pty_key);
+        }
+    }
+
+    let mut log = get_log(env, patient);
+    if log.len() >= MAX_CHANGE_LOG {
+        log.pop_front();
+    }
+    log.push_back(StateChange {
+        version,
+        kind,
+        ref_id,
+        counterparty,
+        timestamp: env.ledger().timestamp(),
+    });
+    let log_key = (SNAP_LOG, patient.clone());
+    env.storage().persistent().set(&log_key, &log);
+    extend_ttl_patient_key(env, &log_key);
+}
+
+/// Journal entries after `cursor`, or `None` if some of them have already
+/// been dropped from the journal.
+pub fn changes_since(env: &Env, patient: &Address, cursor: u64) -> Option<Vec<StateChange>> {
+    let log = get_log(env, patient);
+    if let Some(oldest) = log.first() {
+        if cursor.saturating_add(1) < oldest.version {
+            return None;
+        }
+    }
+    let mut out = Vec::new(env);
+    for change in log.iter() {
+        if change.version > cursor {
+            out.push_back(change);
+        }
+    }
+    Some(out)
+}
+
+/// Hashes every record, prescription, grant and consent held for `patient`.
+///
+/// Each item is appended to the preimage with a one-byte tag and its XDR
+/// encoding, in storage order, so the result depends only on contract
+/// state. Missing grants and consents for known counterparties are skipped.
+pub fn compute(env: &Env, patient: &Address) -> StateDigest {
+    let mut payload = Bytes::new(env);
+    payload.append(&patient.clone().to_xdr(env));
+
+    let mut record_count = 0u32;
+    let record_ids: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&(symbol_short!("PAT_REC"), patient.clone()))
+        .unwrap_or(Vec::new(env));
+    for id in record_ids.iter() {
+        let record: Option<VisionRecord> =
+            env.storage().persistent().get(&(symbol_short!("RECORD"), id));
+        if let Some(record) = record {
+            payload.push_back(b'R');
+            payload.append(&record.to_xdr(env));
+            record_count = record_count.saturating_add(1);
+        }
+    }
+
+    let mut prescription_count = 0u32;
+    for id in prescription::get_patient_history(env, patient.clone()).iter() {
+        let rx: Option<Prescription> = prescription::get_prescription(env, id);
+        if let Some(rx) = rx {
+            payload.push_back(b'P');
+            payload.append(&rx.to_xdr(env));
+            prescription_count = prescription_count.saturating_add(1);
+        }
+    }
+
+    let mut grant_count = 0u32;
+    let mut consent_count = 0u32;
+    for grantee in get_counterparties(env, patient).iter() {
+        let grant: Option<AccessGrant> = env.storage().persistent().get(&(
+            symbol_short!("ACCESS"),
+            patient.clone(),
+            grantee.clone(),
+        ));
+        if let Some(grant) = grant {
+            payload.push_back(b'G');
+            payload.append(&grant.to_xdr(env));
+            grant_count = grant_count.saturating_add(1);
+        }
+        let consent: Option<ConsentGrant> = env.storage().persistent().get(&(
+            symbol_short!("CONSENT"),
+            patient.clone(),
+            grantee,
+        ));
+        if let Some(consent) = consent {
+            payload.push_back(b'C');
+            payload.append(&consent.to_xdr(env));
+            consent_count = consent_count.saturating_add(1);
+        }
+    }
+
+    StateDigest {
+        patient: patient.clone(),
+        version: get_version(env, patient),
+        digest: env.crypto().sha256(&payload).into(),
+        record_count,
+        prescription_count,
+        grant_count,
+        consent_count,
+        computed_at: env.ledger().timestamp(),
+    }
+}
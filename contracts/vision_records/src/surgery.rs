@@ -0,0 +1,145 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const SRG_CTR: Symbol = symbol_short!("SRG_CTR");
+const SRG: Symbol = symbol_short!("SRG");
+const SRG_SURG: Symbol = symbol_short!("SRG_SURG");
+const SRG_PAT: Symbol = symbol_short!("SRG_PAT");
+
+/// Extends the time-to-live (TTL) for surgical case keys.
+fn extend_ttl_case_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+/// Extends the time-to-live (TTL) for per-address case index keys.
+fn extend_ttl_address_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Where a surgical case is in its documentation. Each step can only
+/// follow the one before it.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum SurgeryStatus {
+    Scheduled,
+    PreOpCleared,
+    Performed,
+    FollowedUp,
+}
+
+impl SurgeryStatus {
+    /// The status a case moves to from this one, if any.
+    pub fn next(&self) -> Option<SurgeryStatus> {
+        match self {
+            SurgeryStatus::Scheduled => Some(SurgeryStatus::PreOpCleared),
+            SurgeryStatus::PreOpCleared => Some(SurgeryStatus::Performed),
+            SurgeryStatus::Performed => Some(SurgeryStatus::FollowedUp),
+            SurgeryStatus::FollowedUp => None,
+        }
+    }
+}
+
+/// One documented step of a surgical case. `document_hash` points at the
+/// off-chain note (clearance, operative report, follow-up exam).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SurgeryStep {
+    pub provider: Address,
+    pub document_hash: String,
+    pub recorded_at: u64,
+}
+
+/// A surgery built around a `RecordType::Surgery` record. A case that is
+/// past `scheduled_for` but not `FollowedUp` has incomplete documentation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SurgicalCase {
+    pub id: u64,
+    pub record_id: u64,
+    pub patient: Address,
+    pub surgeon: Address,
+    pub scheduled_for: u64,
+    pub status: SurgeryStatus,
+    pub preop_clearance: Option<SurgeryStep>,
+    pub outcome: Option<SurgeryStep>,
+    pub postop_followup: Option<SurgeryStep>,
+}
+
+impl SurgicalCase {
+    pub fn is_incomplete_at(&self, now: u64) -> bool {
+        self.status != SurgeryStatus::FollowedUp && self.scheduled_for <= now
+    }
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+/// Increments and returns the next surgical case ID
+pub fn increment_counter(env: &Env) -> u64 {
+    let current: u64 = env.storage().instance().get(&SRG_CTR).unwrap_or(0);
+    let next = current.saturating_add(1);
+    env.storage().instance().set(&SRG_CTR, &next);
+    next
+}
+
+pub fn get(env: &Env, id: u64) -> Option<SurgicalCase> {
+    env.storage().persistent().get(&(SRG, id))
+}
+
+fn get_index(env: &Env, prefix: Symbol, address: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(prefix, address.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+fn push_index(env: &Env, prefix: Symbol, address: &Address, id: u64) {
+    let key = (prefix.clone(), address.clone());
+    let mut ids = get_index(env, prefix, address);
+    ids.push_back(id);
+    env.storage().persistent().set(&key, &ids);
+    extend_ttl_address_key(env, &key);
+}
+
+/// Stores a new case and indexes it under its surgeon and patient.
+pub fn create(env: &Env, case: &SurgicalCase) {
+    update(env, case);
+    push_index(env, SRG_SURG, &case.surgeon, case.id);
+    push_index(env, SRG_PAT, &case.patient, case.id);
+}
+
+pub fn update(env: &Env, case: &SurgicalCase) {
+    let key = (SRG, case.id);
+    env.storage().persistent().set(&key, case);
+    extend_ttl_case_key(env, &key);
+}
+
+pub fn get_surgeon_ids(env: &Env, surgeon: &Address) -> Vec<u64> {
+    get_index(env, SRG_SURG, surgeon)
+}
+
+pub fn get_patient_ids(env: &Env, patient: &Address) -> Vec<u64> {
+    get_index(env, SRG_PAT, patient)
+}
+
+/// Drops every surgical case of `patient`, unlisting each from its surgeon.
+pub fn remove_patient(env: &Env, patient: &Address) {
+    for id in get_patient_ids(env, patient).iter() {
+        if let Some(case) = get(env, id) {
+            let mut ids = get_surgeon_ids(env, &case.surgeon);
+            if let Some(i) = ids.first_index_of(id) {
+                ids.remove(i);
+                env.storage()
+                    .persistent()
+                    .set(&(SRG_SURG, case.surgeon.clone()), &ids);
+            }
+        }
+        env.storage().persistent().remove(&(SRG, id));
+    }
+    env.storage()
+        .persistent()
+        .remove(&(SRG_PAT, patient.clone()));
+}
@@ -276,7 +276,7 @@ fn test_permission_without_consent_denied() {
     client.grant_access(&patient, &patient, &doctor, &AccessLevel::Read, &86400);
 
     // Access denied — no consent
-    assert_eq!(client.check_access(&patient, &doctor), AccessLevel::None);
+    assert_eq!(client.check_access(&patient, &doctor, &None, &None), AccessLevel::None);
 }
 
 #[test]
@@ -297,7 +297,7 @@ fn test_consent_and_permission_grants_access() {
     client.grant_consent(&patient, &doctor, &ConsentType::Treatment, &86400);
     client.grant_access(&patient, &patient, &doctor, &AccessLevel::Read, &86400);
 
-    assert_eq!(client.check_access(&patient, &doctor), AccessLevel::Read);
+    assert_eq!(client.check_access(&patient, &doctor, &None, &None), AccessLevel::Read);
 }
 
 #[test]
@@ -316,13 +316,13 @@ fn test_revoked_consent_blocks_access() {
 
     client.grant_consent(&patient, &doctor, &ConsentType::Sharing, &86400);
     client.grant_access(&patient, &patient, &doctor, &AccessLevel::Read, &86400);
-    assert_eq!(client.check_access(&patient, &doctor), AccessLevel::Read);
+    assert_eq!(client.check_access(&patient, &doctor, &None, &None), AccessLevel::Read);
 
     // Revoke consent
     client.revoke_consent(&patient, &doctor);
 
     // Access now denied despite active access grant
-    assert_eq!(client.check_access(&patient, &doctor), AccessLevel::None);
+    assert_eq!(client.check_access(&patient, &doctor, &None, &None), AccessLevel::None);
 }
 
 #[test]
@@ -343,13 +343,13 @@ fn test_expired_consent_blocks_access() {
     client.grant_consent(&patient, &doctor, &ConsentType::Research, &100);
     client.grant_access(&patient, &patient, &doctor, &AccessLevel::Read, &86400);
 
-    assert_eq!(client.check_access(&patient, &doctor), AccessLevel::Read);
+    assert_eq!(client.check_access(&patient, &doctor, &None, &None), AccessLevel::Read);
 
     // Advance time past consent expiry
     env.ledger().set_timestamp(200);
 
     // Consent expired — access denied
-    assert_eq!(client.check_access(&patient, &doctor), AccessLevel::None);
+    assert_eq!(client.check_access(&patient, &doctor, &None, &None), AccessLevel::None);
 }
 
 #[test]
@@ -0,0 +1,89 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::setup_test;
+use super::{AccessBasis, AccessLevel, AccessReasonCode, ConsentType};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address};
+
+const DAY: u64 = 86_400;
+
+#[test]
+fn test_granted_access_reports_basis_and_earliest_expiry() {
+    let (env, client, _admin) = setup_test();
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    client.grant_access(
+        &patient,
+        &patient,
+        &grantee,
+        &AccessLevel::Read,
+        &(10 * DAY),
+    );
+    client.grant_consent(&patient, &grantee, &ConsentType::Treatment, &(5 * DAY));
+
+    let decision = client.check_access_detailed(&patient, &grantee);
+    assert_eq!(decision.level, AccessLevel::Read);
+    assert_eq!(decision.basis, AccessBasis::Grant);
+    assert_eq!(decision.reason_code, AccessReasonCode::Granted);
+    assert_eq!(decision.expires_at, now + 5 * DAY);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::Read
+    );
+}
+
+#[test]
+fn test_never_granted_vs_revoked() {
+    let (env, client, _admin) = setup_test();
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+    let decision = client.check_access_detailed(&patient, &grantee);
+    assert_eq!(decision.level, AccessLevel::None);
+    assert_eq!(decision.basis, AccessBasis::None);
+    assert_eq!(decision.reason_code, AccessReasonCode::NeverGranted);
+
+    client.grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &DAY);
+    client.revoke_access(&patient, &grantee);
+    let decision = client.check_access_detailed(&patient, &grantee);
+    assert_eq!(decision.reason_code, AccessReasonCode::Revoked);
+}
+
+#[test]
+fn test_expired_grant_reports_lapse_time() {
+    let (env, client, _admin) = setup_test();
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    client.grant_access(&patient, &patient, &grantee, &AccessLevel::Write, &DAY);
+    client.grant_consent(&patient, &grantee, &ConsentType::Treatment, &(10 * DAY));
+
+    env.ledger().with_mut(|li| li.timestamp = now + DAY + 1);
+    let decision = client.check_access_detailed(&patient, &grantee);
+    assert_eq!(decision.level, AccessLevel::None);
+    assert_eq!(decision.reason_code, AccessReasonCode::Expired);
+    assert_eq!(decision.expires_at, now + DAY);
+}
+
+#[test]
+fn test_consent_state_is_reported() {
+    let (env, client, _admin) = setup_test();
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+    client.grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &DAY);
+    assert_eq!(
+        client.check_access_detailed(&patient, &grantee).reason_code,
+        AccessReasonCode::ConsentMissing
+    );
+
+    client.grant_consent(&patient, &grantee, &ConsentType::Sharing, &DAY);
+    client.revoke_consent(&patient, &grantee);
+    assert_eq!(
+        client.check_access_detailed(&patient, &grantee).reason_code,
+        AccessReasonCode::ConsentRevoked
+    );
+}
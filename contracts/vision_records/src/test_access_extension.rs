@@ -0,0 +1,128 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::setup_test;
+use super::{AccessLevel, ContractError, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env};
+
+const DAY: u64 = 86_400;
+
+/// `grantee` holds ten days of Read access, granted at t=1000.
+fn grant_read(env: &Env, client: &VisionRecordsContractClient) -> (Address, Address) {
+    env.ledger().set_timestamp(1_000);
+    let patient = Address::generate(env);
+    let grantee = Address::generate(env);
+    client.grant_access(
+        &patient,
+        &patient,
+        &grantee,
+        &AccessLevel::Read,
+        &(10 * DAY),
+    );
+    (patient, grantee)
+}
+
+#[test]
+fn test_extend_pushes_back_expiry() {
+    let (env, client, _admin) = setup_test();
+    let (patient, grantee) = grant_read(&env, &client);
+    let grant = client.extend_access(&patient, &grantee, &(5 * DAY));
+    assert_eq!(grant.expires_at, 1_000 + 15 * DAY);
+    assert_eq!(grant.granted_at, 1_000);
+
+    env.ledger().set_timestamp(1_000 + 12 * DAY);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::Read
+    );
+}
+
+#[test]
+fn test_extend_requires_live_grant() {
+    let (env, client, _admin) = setup_test();
+    let (patient, grantee) = grant_read(&env, &client);
+    let stranger = Address::generate(&env);
+    let res = client.try_extend_access(&patient, &stranger, &DAY);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::AccessGrantNotFound
+    );
+
+    env.ledger().set_timestamp(1_000 + 11 * DAY);
+    let res = client.try_extend_access(&patient, &grantee, &DAY);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::ExpiredAccess);
+
+    client.revoke_access(&patient, &grantee);
+    let res = client.try_renew_access(&patient, &grantee, &DAY);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::AccessGrantNotFound
+    );
+}
+
+#[test]
+fn test_renew_restarts_lapsed_grant() {
+    let (env, client, _admin) = setup_test();
+    let (patient, grantee) = grant_read(&env, &client);
+    let now = 1_000 + 20 * DAY;
+    env.ledger().set_timestamp(now);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::None
+    );
+
+    let grant = client.renew_access(&patient, &grantee, &(30 * DAY));
+    assert_eq!(grant.granted_at, 1_000);
+    assert_eq!(grant.expires_at, now + 30 * DAY);
+    assert_eq!(grant.level, AccessLevel::Read);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::Read
+    );
+}
+
+#[test]
+fn test_max_total_duration_is_enforced() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee) = grant_read(&env, &client);
+    client.set_max_grant_duration(&admin, &(30 * DAY));
+    assert_eq!(client.get_max_grant_duration(), 30 * DAY);
+
+    client.extend_access(&patient, &grantee, &(20 * DAY));
+    let res = client.try_extend_access(&patient, &grantee, &1);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::GrantDurationExceeded
+    );
+    let res = client.try_renew_access(&patient, &grantee, &(31 * DAY));
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::GrantDurationExceeded
+    );
+
+    let res = client.try_set_max_grant_duration(&patient, &(60 * DAY));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_repeated_renewals_stay_within_max_duration() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee) = grant_read(&env, &client);
+    client.set_max_grant_duration(&admin, &(30 * DAY));
+
+    env.ledger().set_timestamp(1_000 + 15 * DAY);
+    let grant = client.renew_access(&patient, &grantee, &(10 * DAY));
+    assert_eq!(grant.granted_at, 1_000);
+    assert_eq!(grant.expires_at, 1_000 + 25 * DAY);
+
+    env.ledger().set_timestamp(1_000 + 24 * DAY);
+    let res = client.try_renew_access(&patient, &grantee, &(10 * DAY));
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::GrantDurationExceeded
+    );
+}
@@ -0,0 +1,192 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_verified_provider, setup_test};
+use super::{
+    emergency::EmergencyStatus, AccessLevel, AccessScope, ConsentType, DisclosureTier,
+    EmergencyCondition, RecordType,
+};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, testutils::Events, testutils::Ledger as _, Address,
+    IntoVal, String, Vec,
+};
+
+const DAY: u64 = 86_400;
+
+#[test]
+fn test_revoke_all_access_ends_every_grant() {
+    let (env, client, admin) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Lockdown");
+    let patient = Address::generate(&env);
+    let family = Address::generate(&env);
+    let clinic = Address::generate(&env);
+    let researcher = Address::generate(&env);
+
+    for grantee in [&family, &clinic] {
+        client.grant_access(&patient, &patient, grantee, &AccessLevel::Read, &DAY);
+    }
+    client.grant_scoped_access(
+        &patient,
+        &researcher,
+        &AccessScope::RecordType(RecordType::Examination),
+        &AccessLevel::Read,
+        &DAY,
+    );
+    let access_id = client.grant_emergency_access(
+        &responder,
+        &patient,
+        &EmergencyCondition::Unconscious,
+        &String::from_str(&env, "Patient unconscious"),
+        &3600,
+        &Vec::new(&env),
+    );
+
+    let summary = client.revoke_all_access(&patient);
+    assert_eq!(summary.grants_revoked, 2);
+    assert_eq!(summary.scoped_grants_revoked, 1);
+    assert_eq!(summary.emergency_grants_revoked, 1);
+    assert_eq!(summary.locked_at, 1_000);
+
+    assert_eq!(client.get_access_grants(&patient).len(), 0);
+    assert_eq!(
+        client.check_access(&patient, &researcher, &None, &Some(RecordType::Examination)),
+        AccessLevel::None
+    );
+    assert_eq!(
+        client.get_emergency_access(&access_id).status,
+        EmergencyStatus::Revoked
+    );
+}
+
+#[test]
+fn test_revoke_all_access_is_idempotent() {
+    let (env, client, _admin) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let patient = Address::generate(&env);
+    client.grant_access(
+        &patient,
+        &patient,
+        &Address::generate(&env),
+        &AccessLevel::Read,
+        &DAY,
+    );
+    client.revoke_all_access(&patient);
+
+    let again = client.revoke_all_access(&patient);
+    assert_eq!(again.grants_revoked, 0);
+    assert_eq!(again.scoped_grants_revoked, 0);
+    assert_eq!(again.emergency_grants_revoked, 0);
+}
+
+#[test]
+fn test_revoke_all_access_leaves_no_tier_through_any_grant() {
+    let (env, client, admin) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Lockdown");
+    let patient = Address::generate(&env);
+    let record_id = add_record(&env, &client, &admin, &patient, RecordType::Examination);
+
+    let wide = Address::generate(&env);
+    let scoped = Address::generate(&env);
+    let record_level = Address::generate(&env);
+    let org_member = Address::generate(&env);
+    let consented = Address::generate(&env);
+
+    client.grant_access(&patient, &patient, &wide, &AccessLevel::Read, &DAY);
+    client.grant_scoped_access(
+        &patient,
+        &scoped,
+        &AccessScope::Record(record_id),
+        &AccessLevel::Read,
+        &DAY,
+    );
+    client.grant_record_access(
+        &patient,
+        &record_level,
+        &record_id,
+        &AccessLevel::Read,
+        &DAY,
+    );
+    let org_id = client.register_organization(
+        &admin,
+        &String::from_str(&env, "Lockdown Eye Group"),
+        &admin,
+    );
+    client.set_provider_org(&admin, &org_member, &org_id);
+    client.grant_org_access(&patient, &org_id, &AccessLevel::Read, &DAY);
+    client.grant_consent(&patient, &consented, &ConsentType::Treatment, &DAY);
+    client.grant_emergency_access(
+        &responder,
+        &patient,
+        &EmergencyCondition::Unconscious,
+        &String::from_str(&env, "Patient unconscious"),
+        &3600,
+        &Vec::new(&env),
+    );
+
+    let holders = [&scoped, &record_level, &org_member, &consented, &responder];
+    for holder in holders {
+        assert_ne!(
+            client.get_record_tier(holder, &record_id),
+            DisclosureTier::None
+        );
+    }
+
+    let summary = client.revoke_all_access(&patient);
+    assert_eq!(summary.grants_revoked, 1);
+    assert_eq!(summary.scoped_grants_revoked, 1);
+    assert_eq!(summary.record_grants_revoked, 1);
+    assert_eq!(summary.org_grants_revoked, 1);
+    assert_eq!(summary.consents_revoked, 1);
+    assert_eq!(summary.emergency_grants_revoked, 1);
+
+    // One summary event, however many grants ended
+    let events = env.events().all();
+    assert_eq!(events.len(), 1);
+    let event = events.get(0).unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("ALL_REVK"), patient.clone()).into_val(&env)
+    );
+
+    for holder in [&wide].into_iter().chain(holders) {
+        assert_eq!(
+            client.get_record_tier(holder, &record_id),
+            DisclosureTier::None
+        );
+    }
+}
+
+#[test]
+fn test_revoke_all_access_reaches_old_emergency_grants() {
+    let (env, client, admin) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Lockdown");
+    let patient = Address::generate(&env);
+    let first = client.grant_emergency_access(
+        &responder,
+        &patient,
+        &EmergencyCondition::Unconscious,
+        &String::from_str(&env, "Patient unconscious"),
+        &3600,
+        &Vec::new(&env),
+    );
+    // Push the first grant out of any recent-id window.
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&super::emergency::EMRG_CTR, &(first + 500));
+    });
+
+    let summary = client.revoke_all_access(&patient);
+    assert_eq!(summary.emergency_grants_revoked, 1);
+    assert_eq!(
+        client.get_emergency_access(&first).status,
+        EmergencyStatus::Revoked
+    );
+}
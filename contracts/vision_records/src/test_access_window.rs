@@ -0,0 +1,159 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{
+    AccessLevel, AccessReasonCode, AccessWindow, ConsentType, ContractError, RecordType, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env};
+
+const DAY: u64 = 86_400;
+const HOUR: u64 = 3_600;
+
+/// `grantee` holds thirty days of Read access and Treatment consent.
+fn consented_read_grant(env: &Env, client: &VisionRecordsContractClient) -> (Address, Address) {
+    let patient = Address::generate(env);
+    let grantee = Address::generate(env);
+    client.grant_access(
+        &patient,
+        &patient,
+        &grantee,
+        &AccessLevel::Read,
+        &(30 * DAY),
+    );
+    client.grant_consent(&patient, &grantee, &ConsentType::Treatment, &(30 * DAY));
+    (patient, grantee)
+}
+
+fn clinic_hours() -> AccessWindow {
+    AccessWindow {
+        start_minute: 9 * 60,
+        end_minute: 17 * 60,
+    }
+}
+
+fn set_time_of_day(env: &Env, hour: u64) {
+    env.ledger().with_mut(|li| li.timestamp = DAY + hour * HOUR);
+}
+
+#[test]
+fn test_window_limits_access_to_clinic_hours() {
+    let (env, client, _admin) = setup_test();
+    let (patient, grantee) = consented_read_grant(&env, &client);
+    client.set_access_window(&patient, &grantee, &Some(clinic_hours()));
+
+    set_time_of_day(&env, 10);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::Read
+    );
+
+    set_time_of_day(&env, 20);
+    let decision = client.check_access_detailed(&patient, &grantee);
+    assert_eq!(decision.level, AccessLevel::None);
+    assert_eq!(decision.reason_code, AccessReasonCode::OutsideWindow);
+
+    client.set_access_window(&patient, &grantee, &None);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::Read
+    );
+}
+
+#[test]
+fn test_window_uses_configured_timezone() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee) = consented_read_grant(&env, &client);
+    client.set_access_window(&patient, &grantee, &Some(clinic_hours()));
+
+    // 07:00 UTC is 10:00 at UTC+3.
+    set_time_of_day(&env, 7);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::None
+    );
+    client.set_access_timezone(&admin, &180);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::Read
+    );
+}
+
+#[test]
+fn test_overnight_window() {
+    let (env, client, _admin) = setup_test();
+    let (patient, grantee) = consented_read_grant(&env, &client);
+    client.set_access_window(
+        &patient,
+        &grantee,
+        &Some(AccessWindow {
+            start_minute: 22 * 60,
+            end_minute: 6 * 60,
+        }),
+    );
+
+    set_time_of_day(&env, 23);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::Read
+    );
+    set_time_of_day(&env, 3);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::Read
+    );
+    set_time_of_day(&env, 12);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::None
+    );
+}
+
+#[test]
+fn test_window_and_timezone_validation() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee) = consented_read_grant(&env, &client);
+    for window in [
+        AccessWindow {
+            start_minute: 600,
+            end_minute: 600,
+        },
+        AccessWindow {
+            start_minute: 1_440,
+            end_minute: 60,
+        },
+        AccessWindow {
+            start_minute: 60,
+            end_minute: 1_441,
+        },
+    ] {
+        let res = client.try_set_access_window(&patient, &grantee, &Some(window));
+        assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+    }
+
+    let res = client.try_set_access_timezone(&admin, &(15 * 60));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+    let res = client.try_set_access_timezone(&patient, &60);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_get_record_denied_outside_window() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee) = consented_read_grant(&env, &client);
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Window");
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    client.set_access_window(&patient, &grantee, &Some(clinic_hours()));
+
+    set_time_of_day(&env, 11);
+    assert_eq!(client.get_record(&grantee, &record_id).id, record_id);
+
+    set_time_of_day(&env, 19);
+    let res = client.try_get_record(&grantee, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
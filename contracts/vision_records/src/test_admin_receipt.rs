@@ -0,0 +1,72 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, setup_test};
+use super::{CredentialType, Permission, Role};
+use soroban_sdk::{symbol_short, testutils::Address as _, testutils::Ledger as _, Address};
+
+#[test]
+fn test_admin_mutation_returns_stored_receipt() {
+    let (env, client, admin) = setup_test();
+    let user = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Receipt");
+    env.ledger().with_mut(|li| li.sequence_number = 4_321);
+
+    let receipt = client.set_user_credential(&admin, &user, &CredentialType::MedicalLicense);
+    assert_eq!(receipt.admin, admin);
+    assert_eq!(receipt.action, symbol_short!("CRED_SET"));
+    assert_eq!(receipt.target, Some(user.clone()));
+    assert_eq!(receipt.ledger_sequence, 4_321);
+    assert_eq!(receipt.seq, client.get_audit_sequence());
+    assert_eq!(
+        client.get_admin_receipt(&receipt.seq),
+        Some(receipt.clone())
+    );
+    assert!(client.verify_admin_receipt(&receipt));
+}
+
+#[test]
+fn test_affected_user_lists_every_admin_action() {
+    let (env, client, admin) = setup_test();
+    let user = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Receipt");
+    client.grant_custom_permission(&admin, &user, &Permission::ManageAccess);
+    client.revoke_custom_permission(&admin, &user, &Permission::ManageAccess);
+
+    let receipts = client.get_admin_receipts(&user);
+    assert_eq!(receipts.len(), 3);
+    assert_eq!(receipts.get(0).unwrap().action, symbol_short!("REG_USR"));
+    assert_eq!(receipts.get(1).unwrap().action, symbol_short!("PERM_GRNT"));
+    assert_eq!(receipts.get(2).unwrap().action, symbol_short!("PERM_RVK"));
+    for receipt in receipts.iter() {
+        assert!(client.verify_admin_receipt(&receipt));
+    }
+}
+
+#[test]
+fn test_altered_receipt_fails_verification() {
+    let (env, client, admin) = setup_test();
+    let user = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Receipt");
+    let receipt = client.set_user_credential(&admin, &user, &CredentialType::MedicalLicense);
+
+    let mut forged = receipt.clone();
+    forged.timestamp += 1;
+    assert!(!client.verify_admin_receipt(&forged));
+
+    let mut forged = receipt.clone();
+    forged.target = Some(Address::generate(&env));
+    assert!(!client.verify_admin_receipt(&forged));
+
+    let mut forged = receipt;
+    forged.action = symbol_short!("PERM_GRNT");
+    assert!(!client.verify_admin_receipt(&forged));
+}
+
+#[test]
+fn test_unrelated_users_see_no_receipts() {
+    let (env, client, admin) = setup_test();
+    register_user(&env, &client, &admin, Role::Optometrist, "Dr. Receipt");
+    assert_eq!(client.get_admin_receipts(&Address::generate(&env)).len(), 0);
+}
@@ -0,0 +1,143 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, setup_test, DATA_HASH};
+use super::{AdminPermission, ContractError, RecordType, Role};
+use soroban_sdk::{testutils::Address as _, vec, Address, String};
+
+#[test]
+fn test_secondary_admin_acts_within_permissions() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Admin Set");
+    let user_admin = Address::generate(&env);
+    client.add_admin(
+        &admin,
+        &user_admin,
+        &vec![&env, AdminPermission::UserManagement],
+    );
+    assert_eq!(client.get_admins(), vec![&env, user_admin.clone()]);
+    assert!(client.has_admin_permission(&user_admin, &AdminPermission::UserManagement));
+    assert!(!client.has_admin_permission(&user_admin, &AdminPermission::Upgrade));
+    assert!(client.has_admin_permission(&admin, &AdminPermission::Upgrade));
+
+    client.deactivate_user(&user_admin, &provider);
+    assert!(!client.get_user(&provider).is_active);
+
+    let res = client.try_configure_multisig(
+        &user_admin,
+        &vec![&env, user_admin.clone(), admin.clone()],
+        &2,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    let res = client.try_remove_emergency_responder(&user_admin, &provider);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_remove_admin_withdraws_permissions() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Admin Set");
+    let user_admin = Address::generate(&env);
+    client.add_admin(
+        &admin,
+        &user_admin,
+        &vec![&env, AdminPermission::UserManagement],
+    );
+    client.remove_admin(&admin, &user_admin);
+
+    assert_eq!(client.get_admins().len(), 0);
+    assert_eq!(client.get_admin_permissions(&user_admin).len(), 0);
+    let res = client.try_deactivate_user(&user_admin, &provider);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_remove_admin(&admin, &user_admin);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::UserNotFound);
+}
+
+#[test]
+fn test_only_primary_admin_manages_the_set() {
+    let (env, client, admin) = setup_test();
+    let user_admin = Address::generate(&env);
+    let all = vec![
+        &env,
+        AdminPermission::UserManagement,
+        AdminPermission::EmergencyOversight,
+        AdminPermission::Upgrade,
+    ];
+    client.add_admin(&admin, &user_admin, &all);
+
+    // Secondary admins cannot grow the set, even with every permission
+    let res = client.try_add_admin(&user_admin, &Address::generate(&env), &all);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_add_admin(&admin, &Address::generate(&env), &vec![&env]);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
+
+#[test]
+fn test_resolve_conflict_requires_record_oversight() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Admin Set");
+    let record_id = client.add_record(
+        &admin,
+        &Address::generate(&env),
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&env, DATA_HASH),
+    );
+    let user_admin = Address::generate(&env);
+    client.add_admin(
+        &admin,
+        &user_admin,
+        &vec![&env, AdminPermission::UserManagement],
+    );
+    let record_admin = Address::generate(&env);
+    client.add_admin(
+        &admin,
+        &record_admin,
+        &vec![&env, AdminPermission::RecordOversight],
+    );
+
+    let res = client.try_resolve_conflict(&user_admin, &1, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    // Past the permission check, an unknown conflict is reported as such.
+    let res = client.try_resolve_conflict(&record_admin, &1, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+}
+
+#[test]
+fn test_secondary_admin_cannot_escalate_or_touch_admins() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Admin Set");
+    let other_admin = register_user(&env, &client, &admin, Role::Admin, "Second Admin");
+    let user_admin = Address::generate(&env);
+    client.add_admin(
+        &admin,
+        &user_admin,
+        &vec![&env, AdminPermission::UserManagement],
+    );
+
+    let res = client.try_update_user_role(&user_admin, &provider, &Role::Admin);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    let res = client.try_update_user_role(&user_admin, &other_admin, &Role::Optometrist);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    let res = client.try_deactivate_user(&user_admin, &other_admin);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    assert!(client.get_user(&other_admin).is_active);
+
+    // Other roles stay within UserManagement
+    client.update_user_role(&user_admin, &provider, &Role::Ophthalmologist);
+    assert_eq!(client.get_user(&provider).role, Role::Ophthalmologist);
+
+    // The primary admin may still act on admins
+    client.deactivate_user(&admin, &other_admin);
+    let res = client.try_reactivate_user(&user_admin, &other_admin);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    client.reactivate_user(&admin, &other_admin);
+    assert!(client.get_user(&other_admin).is_active);
+}
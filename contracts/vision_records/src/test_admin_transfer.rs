@@ -0,0 +1,105 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::setup_test;
+use super::{ContractError, Role};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, String};
+
+const DAY: u64 = 86_400;
+
+#[test]
+fn test_transfer_without_delay_is_immediate() {
+    let (env, client, admin) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let next = Address::generate(&env);
+
+    client.propose_admin(&admin, &next);
+    assert_eq!(client.get_admin_transfer_unlock(), Some(1_000));
+    client.accept_admin(&next);
+
+    assert_eq!(client.get_admin(), next);
+    assert_eq!(client.get_pending_admin(), None);
+    assert_eq!(client.get_admin_transfer_unlock(), None);
+}
+
+#[test]
+fn test_accept_waits_for_delay() {
+    let (env, client, admin) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let next = Address::generate(&env);
+    client.set_admin_transfer_delay(&admin, &(2 * DAY));
+    assert_eq!(client.get_admin_transfer_delay(), 2 * DAY);
+
+    client.propose_admin(&admin, &next);
+    assert_eq!(client.get_admin_transfer_unlock(), Some(1_000 + 2 * DAY));
+
+    env.ledger().set_timestamp(1_000 + DAY);
+    let res = client.try_accept_admin(&next);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::AdminTransferLocked
+    );
+    assert_eq!(client.get_admin(), admin);
+
+    env.ledger().set_timestamp(1_000 + 2 * DAY);
+    client.accept_admin(&next);
+    assert_eq!(client.get_admin(), next);
+}
+
+#[test]
+fn test_cancel_clears_pending_unlock() {
+    let (env, client, admin) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let next = Address::generate(&env);
+    client.set_admin_transfer_delay(&admin, &DAY);
+    client.propose_admin(&admin, &next);
+    client.cancel_admin_transfer(&admin);
+
+    assert_eq!(client.get_admin_transfer_unlock(), None);
+    env.ledger().set_timestamp(1_000 + DAY);
+    let res = client.try_accept_admin(&next);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
+
+#[test]
+fn test_delay_is_bounded_and_admin_only() {
+    let (env, client, admin) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let res = client.try_set_admin_transfer_delay(&admin, &(31 * DAY));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let other = Address::generate(&env);
+    let res = client.try_set_admin_transfer_delay(&other, &DAY);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_accept_moves_admin_role() {
+    let (env, client, admin) = setup_test();
+    let next = Address::generate(&env);
+    client.propose_admin(&admin, &next);
+    client.accept_admin(&next);
+
+    // The old admin can no longer act as one
+    let res = client.try_set_admin_transfer_delay(&admin, &DAY);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    let res = client.try_register_user(
+        &admin,
+        &Address::generate(&env),
+        &Role::Patient,
+        &String::from_str(&env, "Pat"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.set_admin_transfer_delay(&next, &DAY);
+    client.register_user(
+        &next,
+        &Address::generate(&env),
+        &Role::Patient,
+        &String::from_str(&env, "Pat"),
+    );
+}
@@ -0,0 +1,149 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{
+    AdverseEventSeverity, AdverseEventStatus, ContractError, RecordType, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+const NARRATIVE_HASH: &str = "QmAdverseEventNarrative000000000000000000000";
+const PRODUCT: &str = "LENS-ACUVUE-OASYS";
+
+fn report(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    reporter: &Address,
+    record_id: u64,
+    severity: AdverseEventSeverity,
+) -> u64 {
+    client.report_adverse_event(
+        reporter,
+        &record_id,
+        &String::from_str(env, PRODUCT),
+        &severity,
+        &String::from_str(env, NARRATIVE_HASH),
+    )
+}
+
+#[test]
+fn test_report_routes_to_regulator_and_is_acknowledged() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Lens");
+    let regulator = register_user(&env, &client, &admin, Role::Regulator, "Regulator");
+    let patient = Address::generate(&env);
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Prescription);
+
+    let report_id = report(
+        &env,
+        &client,
+        &patient,
+        record_id,
+        AdverseEventSeverity::Moderate,
+    );
+
+    let pending = client.get_pending_adverse_events(&regulator);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get(0).unwrap(), report_id);
+
+    client.acknowledge_adverse_event(&regulator, &report_id);
+
+    let stored = client.get_adverse_event(&regulator, &report_id);
+    assert_eq!(stored.status, AdverseEventStatus::Acknowledged);
+    assert_eq!(stored.acknowledged_by, Some(regulator.clone()));
+    assert_eq!(client.get_pending_adverse_events(&regulator).len(), 0);
+
+    let res = client.try_acknowledge_adverse_event(&regulator, &report_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
+
+#[test]
+fn test_aggregate_counts_are_public() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Lens");
+    let patient = Address::generate(&env);
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Prescription);
+
+    report(
+        &env,
+        &client,
+        &patient,
+        record_id,
+        AdverseEventSeverity::Mild,
+    );
+    report(
+        &env,
+        &client,
+        &provider,
+        record_id,
+        AdverseEventSeverity::Severe,
+    );
+
+    let counts = client.get_adverse_event_counts(&String::from_str(&env, PRODUCT));
+    assert_eq!(counts.total, 2);
+    assert_eq!(counts.mild, 1);
+    assert_eq!(counts.severe, 1);
+    assert_eq!(counts.moderate, 0);
+}
+
+#[test]
+fn test_only_record_parties_can_report() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Lens");
+    let patient = Address::generate(&env);
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Prescription);
+    let stranger = Address::generate(&env);
+
+    let res = client.try_report_adverse_event(
+        &stranger,
+        &record_id,
+        &String::from_str(&env, PRODUCT),
+        &AdverseEventSeverity::Mild,
+        &String::from_str(&env, NARRATIVE_HASH),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_report_requires_product_or_procedure_record() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Lens");
+    let patient = Address::generate(&env);
+    let exam = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+
+    let res = client.try_report_adverse_event(
+        &patient,
+        &exam,
+        &String::from_str(&env, PRODUCT),
+        &AdverseEventSeverity::Mild,
+        &String::from_str(&env, NARRATIVE_HASH),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidRecordType);
+}
+
+#[test]
+fn test_non_regulator_cannot_view_queue_or_reports() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Lens");
+    let patient = Address::generate(&env);
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Prescription);
+    let report_id = report(
+        &env,
+        &client,
+        &patient,
+        record_id,
+        AdverseEventSeverity::Mild,
+    );
+
+    let res = client.try_get_pending_adverse_events(&provider);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let stranger = Address::generate(&env);
+    let res = client.try_get_adverse_event(&stranger, &report_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AccessDenied);
+}
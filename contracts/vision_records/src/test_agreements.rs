@@ -0,0 +1,169 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{
+    AccessLevel, AgreementStatus, ConsentType, ContractError, GrantStatus, RecordType, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+
+const DAY: u64 = 86_400;
+
+/// An insurer with Treatment consent from a patient who has one exam on file.
+fn consented_insurer(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, u64) {
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Provider");
+    let patient = Address::generate(env);
+    let insurer = Address::generate(env);
+    let record_id = add_record(env, client, &provider, &patient, RecordType::Examination);
+    client.grant_consent(&patient, &insurer, &ConsentType::Treatment, &(90 * DAY));
+    (patient, insurer, record_id)
+}
+
+fn terms(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[42u8; 32])
+}
+
+fn signed_agreement(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    patient: &Address,
+    insurer: &Address,
+) -> u64 {
+    let id = client.propose_agreement(patient, insurer, &terms(env));
+    client.sign_agreement(insurer, &id);
+    id
+}
+
+#[test]
+fn test_agreement_needs_both_signatures() {
+    let (env, client, admin) = setup_test();
+    let (patient, insurer, _record_id) = consented_insurer(&env, &client, &admin);
+    let id = client.propose_agreement(&patient, &insurer, &terms(&env));
+    assert_eq!(client.get_agreement(&id).status, AgreementStatus::Proposed);
+
+    let res =
+        client.try_grant_under_agreement(&patient, &id, &None, &AccessLevel::Read, &(30 * DAY));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AgreementNotActive);
+
+    client.sign_agreement(&insurer, &id);
+    let agreement = client.get_agreement(&id);
+    assert_eq!(agreement.status, AgreementStatus::Active);
+    assert_eq!(agreement.terms_hash, terms(&env));
+    assert!(agreement.signed_at.is_some());
+}
+
+#[test]
+fn test_only_counterparty_can_sign() {
+    let (env, client, admin) = setup_test();
+    let (patient, insurer, _record_id) = consented_insurer(&env, &client, &admin);
+    let id = client.propose_agreement(&patient, &insurer, &terms(&env));
+    let res = client.try_sign_agreement(&patient, &id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_grants_reference_agreement() {
+    let (env, client, admin) = setup_test();
+    let (patient, insurer, record_id) = consented_insurer(&env, &client, &admin);
+    let id = signed_agreement(&env, &client, &patient, &insurer);
+    client.grant_under_agreement(&patient, &id, &None, &AccessLevel::Read, &(30 * DAY));
+    client.grant_under_agreement(
+        &patient,
+        &id,
+        &Some(record_id),
+        &AccessLevel::Read,
+        &(30 * DAY),
+    );
+
+    assert_eq!(
+        client.check_access(&patient, &insurer, &None, &None),
+        AccessLevel::Read
+    );
+    assert_eq!(
+        client.check_record_access(&record_id, &insurer),
+        AccessLevel::Read
+    );
+    let grant = client.list_access_grants(&patient).get(0).unwrap();
+    assert_eq!(grant.agreement_id, Some(id));
+    assert_eq!(client.get_agreement_grants(&id).len(), 2);
+}
+
+#[test]
+fn test_terminate_revokes_all_linked_grants() {
+    let (env, client, admin) = setup_test();
+    let (patient, insurer, record_id) = consented_insurer(&env, &client, &admin);
+    let id = signed_agreement(&env, &client, &patient, &insurer);
+    client.grant_under_agreement(&patient, &id, &None, &AccessLevel::Read, &(30 * DAY));
+    client.grant_under_agreement(
+        &patient,
+        &id,
+        &Some(record_id),
+        &AccessLevel::Read,
+        &(30 * DAY),
+    );
+
+    assert_eq!(client.terminate_agreement(&insurer, &id), 2);
+    assert_eq!(
+        client.check_access(&patient, &insurer, &None, &None),
+        AccessLevel::None
+    );
+    assert_eq!(
+        client.check_record_access(&record_id, &insurer),
+        AccessLevel::None
+    );
+    let grant = client.list_access_grants(&patient).get(0).unwrap();
+    assert_eq!(grant.status, GrantStatus::Revoked);
+
+    let agreement = client.get_agreement(&id);
+    assert_eq!(agreement.status, AgreementStatus::Terminated);
+    assert_eq!(agreement.terminated_by, Some(insurer.clone()));
+
+    // A terminated agreement cannot be ended twice or used to revive grants.
+    let res = client.try_terminate_agreement(&patient, &id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AgreementNotActive);
+    let res = client.try_restore_access(&patient, &insurer);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AgreementNotActive);
+}
+
+#[test]
+fn test_terminate_leaves_grants_outside_agreement() {
+    let (env, client, admin) = setup_test();
+    let (patient, insurer, _record_id) = consented_insurer(&env, &client, &admin);
+    let id = signed_agreement(&env, &client, &patient, &insurer);
+    client.grant_under_agreement(&patient, &id, &None, &AccessLevel::Read, &(30 * DAY));
+
+    // The patient replaces the agreement grant with a direct one.
+    client.grant_access(
+        &patient,
+        &patient,
+        &insurer,
+        &AccessLevel::Read,
+        &(30 * DAY),
+    );
+
+    assert_eq!(client.terminate_agreement(&patient, &id), 0);
+    assert_eq!(
+        client.check_access(&patient, &insurer, &None, &None),
+        AccessLevel::Read
+    );
+}
+
+#[test]
+fn test_outsider_cannot_terminate() {
+    let (env, client, admin) = setup_test();
+    let (patient, insurer, _record_id) = consented_insurer(&env, &client, &admin);
+    let id = signed_agreement(&env, &client, &patient, &insurer);
+    let outsider = Address::generate(&env);
+    let res = client.try_terminate_agreement(&outsider, &id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    assert_eq!(client.get_patient_agreements(&patient).len(), 1);
+}
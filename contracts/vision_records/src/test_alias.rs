@@ -0,0 +1,87 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{ContractError, LegalHoldTarget, RecordType, Role};
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN};
+
+#[test]
+fn test_merged_address_resolves_to_primary() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Alias");
+    let primary = Address::generate(&env);
+    let duplicate = Address::generate(&env);
+
+    let record_id = add_record(
+        &env,
+        &client,
+        &provider,
+        &duplicate,
+        RecordType::Examination,
+    );
+    client.merge_patients(&admin, &primary, &duplicate);
+
+    assert_eq!(client.resolve_address(&duplicate), primary);
+    assert_eq!(
+        client.get_patient_records(&duplicate),
+        vec![&env, record_id]
+    );
+    assert_eq!(client.get_aliases(&primary), vec![&env, duplicate]);
+}
+
+#[test]
+fn test_alias_chains_collapse_to_current() {
+    let (env, client, admin) = setup_test();
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+
+    client.register_address_alias(&admin, &first, &second);
+    client.register_address_alias(&admin, &second, &third);
+
+    assert_eq!(client.resolve_address(&first), third);
+    assert_eq!(client.resolve_address(&second), third);
+    assert_eq!(
+        client.get_aliases(&third),
+        vec![&env, second.clone(), first]
+    );
+    assert_eq!(client.get_aliases(&second).len(), 0);
+}
+
+#[test]
+fn test_alias_validation() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Alias");
+    let old = Address::generate(&env);
+    let current = Address::generate(&env);
+
+    let res = client.try_register_address_alias(&provider, &old, &current);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_register_address_alias(&admin, &old, &old);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    client.register_address_alias(&admin, &old, &current);
+    let res = client.try_register_address_alias(&admin, &current, &old);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
+
+#[test]
+fn test_held_address_cannot_be_migrated() {
+    let (env, client, admin) = setup_test();
+    let old = Address::generate(&env);
+    let current = Address::generate(&env);
+
+    client.place_legal_hold(
+        &admin,
+        &LegalHoldTarget::Patient(old.clone()),
+        &BytesN::from_array(&env, &[9u8; 32]),
+    );
+
+    let res = client.try_register_address_alias(&admin, &old, &current);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::LegalHoldActive);
+}
@@ -0,0 +1,114 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{ContractError, RecordType, Role, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, String};
+
+const AXIS_FIX_HASH: &str = "QmCorrectedAxisHash0000000000000000000000";
+const SPHERE_FIX_HASH: &str = "QmCorrectedSphereHash00000000000000000000";
+const TAMPERED_HASH: &str = "QmTamperedRecordHash000000000000000000000";
+
+/// An examination written at t=1000, returned with its provider.
+fn exam_on_file(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, u64) {
+    env.ledger().set_timestamp(1_000);
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Amend");
+    let patient = Address::generate(env);
+    let record_id = add_record(env, client, &provider, &patient, RecordType::Examination);
+    (provider, record_id)
+}
+
+fn amend(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    record_id: u64,
+    hash: &str,
+    reason: &str,
+) {
+    client.amend_record(
+        provider,
+        &record_id,
+        &String::from_str(env, hash),
+        &String::from_str(env, reason),
+    );
+}
+
+#[test]
+fn test_amendments_build_a_version_chain() {
+    let (env, client, admin) = setup_test();
+    let (provider, record_id) = exam_on_file(&env, &client, &admin);
+    let original = client.get_record(&provider, &record_id);
+    assert_eq!(client.get_record_versions(&record_id).len(), 0);
+
+    env.ledger().set_timestamp(2_000);
+    amend(
+        &env,
+        &client,
+        &provider,
+        record_id,
+        AXIS_FIX_HASH,
+        "axis transcribed wrongly",
+    );
+    env.ledger().set_timestamp(3_000);
+    amend(
+        &env,
+        &client,
+        &provider,
+        record_id,
+        SPHERE_FIX_HASH,
+        "sphere sign flipped",
+    );
+
+    let record = client.get_record(&provider, &record_id);
+    assert_eq!(record.data_hash, String::from_str(&env, SPHERE_FIX_HASH));
+    assert_eq!(record.created_at, original.created_at);
+    assert_eq!(record.updated_at, 3_000);
+
+    let history = client.get_record_versions(&record_id);
+    assert_eq!(history.len(), 2);
+    let first = history.get(0).unwrap();
+    let second = history.get(1).unwrap();
+    assert_eq!((first.version, second.version), (1, 2));
+    assert_eq!((first.amended_at, second.amended_at), (2_000, 3_000));
+    assert_eq!(first.amended_by, provider);
+    assert_eq!(
+        first.reason,
+        String::from_str(&env, "axis transcribed wrongly")
+    );
+    assert_ne!(first.previous_data_hash, second.previous_data_hash);
+}
+
+#[test]
+fn test_only_record_provider_amends() {
+    let (env, client, admin) = setup_test();
+    let (provider, record_id) = exam_on_file(&env, &client, &admin);
+    let other = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Other");
+
+    for caller in [other, admin.clone()] {
+        let res = client.try_amend_record(
+            &caller,
+            &record_id,
+            &String::from_str(&env, TAMPERED_HASH),
+            &String::from_str(&env, "correction"),
+        );
+        assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    }
+
+    let res = client.try_amend_record(
+        &provider,
+        &999,
+        &String::from_str(&env, TAMPERED_HASH),
+        &String::from_str(&env, "correction"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+    assert_eq!(client.get_record_versions(&record_id).len(), 0);
+}
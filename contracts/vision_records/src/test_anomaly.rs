@@ -0,0 +1,144 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, advance, register_verified_provider, setup_test};
+use super::{
+    AccessLevel, AnomalyKind, ContractError, EmergencyCondition, RecordType,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+
+const HOUR: u64 = 3600;
+
+fn grant(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    responder: &Address,
+    patient: &Address,
+) -> u64 {
+    client.grant_emergency_access(
+        responder,
+        patient,
+        &EmergencyCondition::Unconscious,
+        &String::from_str(env, "Patient unconscious"),
+        &3600,
+        &Vec::new(env),
+    )
+}
+
+#[test]
+fn test_read_burst_flags_grantee() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    let record_id = add_record(&env, &client, &responder, &patient, RecordType::Examination);
+    let grantee = Address::generate(&env);
+    client.grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &86_400);
+    client.set_anomaly_rule(&admin, &AnomalyKind::RecordReads, &2, &HOUR);
+
+    client.get_record(&grantee, &record_id);
+    client.get_record(&grantee, &record_id);
+    assert!(client.get_actor_flag(&grantee).is_none());
+
+    client.get_record(&grantee, &record_id);
+    let flag = client.get_actor_flag(&grantee).unwrap();
+    assert_eq!(flag.kind, AnomalyKind::RecordReads);
+    assert_eq!(flag.count, 3);
+    assert!(!flag.reviewed);
+
+    // The patient and the authoring provider are never counted.
+    client.get_record(&patient, &record_id);
+    client.get_record(&responder, &record_id);
+    assert!(client
+        .get_activity_window(&AnomalyKind::RecordReads, &patient)
+        .is_none());
+}
+
+#[test]
+fn test_read_window_resets() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    let record_id = add_record(&env, &client, &responder, &patient, RecordType::Examination);
+    let grantee = Address::generate(&env);
+    client.grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &86_400);
+    client.set_anomaly_rule(&admin, &AnomalyKind::RecordReads, &2, &HOUR);
+
+    client.get_record(&grantee, &record_id);
+    client.get_record(&grantee, &record_id);
+    advance(&env, HOUR);
+    client.get_record(&grantee, &record_id);
+
+    assert!(client.get_actor_flag(&grantee).is_none());
+    let window = client
+        .get_activity_window(&AnomalyKind::RecordReads, &grantee)
+        .unwrap();
+    assert_eq!(window.count, 1);
+}
+
+#[test]
+fn test_emergency_requests_count_distinct_patients() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    client.set_anomaly_rule(&admin, &AnomalyKind::EmergencyRequests, &2, &HOUR);
+
+    grant(&env, &client, &responder, &patient);
+    grant(&env, &client, &responder, &patient);
+    grant(&env, &client, &responder, &Address::generate(&env));
+    assert!(client.get_actor_flag(&responder).is_none());
+
+    grant(&env, &client, &responder, &Address::generate(&env));
+    let flag = client.get_actor_flag(&responder).unwrap();
+    assert_eq!(flag.kind, AnomalyKind::EmergencyRequests);
+    assert_eq!(flag.count, 3);
+}
+
+#[test]
+fn test_review_clears_flag_and_counters() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    client.set_anomaly_rule(&admin, &AnomalyKind::EmergencyRequests, &1, &HOUR);
+    grant(&env, &client, &responder, &Address::generate(&env));
+    grant(&env, &client, &responder, &Address::generate(&env));
+    assert!(client.get_actor_flag(&responder).is_some());
+
+    let flag = client.review_actor_flag(&admin, &responder);
+    assert!(flag.reviewed);
+    assert_eq!(flag.reviewed_by, Some(admin.clone()));
+    assert!(client
+        .get_activity_window(&AnomalyKind::EmergencyRequests, &responder)
+        .is_none());
+
+    let res = client.try_review_actor_flag(&admin, &Address::generate(&env));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::ActorFlagNotFound);
+}
+
+#[test]
+fn test_rules_are_admin_only_and_validated() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+
+    let res = client.try_set_anomaly_rule(&responder, &AnomalyKind::RecordReads, &5, &HOUR);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_set_anomaly_rule(&admin, &AnomalyKind::RecordReads, &0, &HOUR);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+    let res = client.try_set_anomaly_rule(&admin, &AnomalyKind::RecordReads, &5, &0);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    client.set_anomaly_rule(&admin, &AnomalyKind::RecordReads, &5, &HOUR);
+    assert_eq!(
+        client
+            .get_anomaly_rule(&AnomalyKind::RecordReads)
+            .unwrap()
+            .threshold,
+        5
+    );
+    client.remove_anomaly_rule(&admin, &AnomalyKind::RecordReads);
+    assert!(client.get_anomaly_rule(&AnomalyKind::RecordReads).is_none());
+}
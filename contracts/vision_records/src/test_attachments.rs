@@ -0,0 +1,87 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{
+    attachment::MAX_ATTACHMENTS_PER_RECORD, AttachmentKind, ContractError, RecordType, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, String};
+
+const OCT_HASH: &str = "QmAttachmentOctScanHash00000000000000000";
+const FUNDUS_HASH: &str = "QmAttachmentFundusHash000000000000000000";
+
+/// An examination written at t=1000.
+fn exam_on_file(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, u64) {
+    env.ledger().set_timestamp(1_000);
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Attach");
+    let patient = Address::generate(env);
+    let record_id = add_record(env, client, &provider, &patient, RecordType::Examination);
+    (provider, patient, record_id)
+}
+
+#[test]
+fn test_provider_attaches_artifacts() {
+    let (env, client, admin) = setup_test();
+    let (provider, patient, record_id) = exam_on_file(&env, &client, &admin);
+    let oct = client.add_attachment(
+        &provider,
+        &record_id,
+        &AttachmentKind::OctScan,
+        &String::from_str(&env, OCT_HASH),
+    );
+    assert_eq!(oct.index, 0);
+    let fundus = client.add_attachment(
+        &provider,
+        &record_id,
+        &AttachmentKind::FundusPhoto,
+        &String::from_str(&env, FUNDUS_HASH),
+    );
+    assert_eq!(fundus.index, 1);
+
+    let attachments = client.get_attachments(&patient, &record_id);
+    assert_eq!(attachments.len(), 2);
+    assert_eq!(attachments.get(0).unwrap(), oct);
+    assert_eq!(
+        attachments.get(1).unwrap().kind,
+        AttachmentKind::FundusPhoto
+    );
+}
+
+#[test]
+fn test_only_record_provider_attaches() {
+    let (env, client, admin) = setup_test();
+    let (_provider, patient, record_id) = exam_on_file(&env, &client, &admin);
+    let hash = String::from_str(&env, OCT_HASH);
+    for caller in [&patient, &admin] {
+        let res = client.try_add_attachment(caller, &record_id, &AttachmentKind::OctScan, &hash);
+        assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    }
+
+    let stranger = Address::generate(&env);
+    let res = client.try_get_attachments(&stranger, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_attachment_limit() {
+    let (env, client, admin) = setup_test();
+    let (provider, _patient, record_id) = exam_on_file(&env, &client, &admin);
+    let hash = String::from_str(&env, OCT_HASH);
+    for _ in 0..MAX_ATTACHMENTS_PER_RECORD {
+        client.add_attachment(&provider, &record_id, &AttachmentKind::Other, &hash);
+    }
+    let res = client.try_add_attachment(&provider, &record_id, &AttachmentKind::Other, &hash);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::QuotaExceeded);
+
+    let res = client.try_add_attachment(&provider, &(record_id + 1), &AttachmentKind::Other, &hash);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+}
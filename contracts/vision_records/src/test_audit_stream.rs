@@ -0,0 +1,42 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, setup_test};
+use super::{RecordType, Role};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address};
+
+#[test]
+fn test_privileged_actions_get_consecutive_sequence_numbers() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Audit");
+    client.set_retention_policy(&admin, &RecordType::Examination, &86_400, &true);
+
+    assert_eq!(client.get_audit_sequence(), 3);
+    let entries = client.get_audit_range(&1, &10);
+    assert_eq!(entries.len(), 3);
+    for (i, entry) in entries.iter().enumerate() {
+        assert_eq!(entry.seq, i as u64 + 1);
+        assert_eq!(entry.actor, admin);
+    }
+    assert_eq!(entries.get(0).unwrap().action, symbol_short!("INIT"));
+    let registration = entries.get(1).unwrap();
+    assert_eq!(registration.action, symbol_short!("REG_USR"));
+    assert_eq!(registration.subject, Some(provider));
+    assert_eq!(entries.get(2).unwrap().action, symbol_short!("RET_SET"));
+}
+
+#[test]
+fn test_rejected_actions_are_not_logged() {
+    let (env, client, _admin) = setup_test();
+    let outsider = Address::generate(&env);
+
+    let res = client.try_set_retention_policy(&outsider, &RecordType::Examination, &86_400, &true);
+    assert!(res.is_err());
+
+    assert_eq!(client.get_audit_sequence(), 1);
+    assert_eq!(client.get_audit_range(&2, &10).len(), 0);
+}
@@ -0,0 +1,200 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{advance, register_user, setup_test};
+use super::{
+    AccessLevel, AvailabilityStatus, ContractError, ReferralUrgency, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, testutils::Events as _, Address, BytesN, Env, Symbol,
+    TryFromVal,
+};
+
+const DAY: u64 = 86_400;
+
+/// A referrer with read access to the patient, a specialist to refer to and
+/// a locum who can cover for them.
+fn referral_desk(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, Address, Address) {
+    let referrer = register_user(env, client, admin, Role::Optometrist, "Dr. Referrer");
+    let specialist = register_user(env, client, admin, Role::Optometrist, "Dr. Specialist");
+    let locum = register_user(env, client, admin, Role::Optometrist, "Dr. Locum");
+    let patient = Address::generate(env);
+    client.grant_access(
+        &patient,
+        &patient,
+        &referrer,
+        &AccessLevel::Read,
+        &(30 * DAY),
+    );
+    (referrer, specialist, locum, patient)
+}
+
+fn go_away(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    specialist: &Address,
+    days: u64,
+) -> u64 {
+    let until = env.ledger().timestamp() + days * DAY;
+    client.set_availability(specialist, &AvailabilityStatus::OutOfOffice, &until);
+    until
+}
+
+fn designate(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    specialist: &Address,
+    locum: &Address,
+    days: u64,
+) -> u64 {
+    let now = env.ledger().timestamp();
+    client.designate_locum(specialist, locum, &now, &(now + days * DAY))
+}
+
+fn refer(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    referrer: &Address,
+    patient: &Address,
+    specialist: &Address,
+) -> u64 {
+    client.create_referral(
+        referrer,
+        patient,
+        specialist,
+        &BytesN::from_array(env, &[1u8; 32]),
+        &ReferralUrgency::Routine,
+    )
+}
+
+fn emitted(env: &Env, name: Symbol) -> bool {
+    env.events().all().iter().any(|(_, topics, _)| {
+        topics
+            .get(0)
+            .and_then(|t| Symbol::try_from_val(env, &t).ok())
+            == Some(name.clone())
+    })
+}
+
+#[test]
+fn test_referral_goes_direct_when_available() {
+    let (env, client, admin) = setup_test();
+    let (referrer, specialist, _locum, patient) = referral_desk(&env, &client, &admin);
+    assert_eq!(
+        client.get_availability(&specialist).status,
+        AvailabilityStatus::Available
+    );
+
+    let referral = client.get_referral(&refer(&env, &client, &referrer, &patient, &specialist));
+    assert_eq!(referral.assigned_provider, specialist);
+    assert_eq!(referral.arrangement_id, None);
+    assert_eq!(referral.queued_until, None);
+    assert_eq!(client.get_provider_referrals(&specialist).len(), 1);
+}
+
+#[test]
+fn test_referral_reroutes_to_locum_cover() {
+    let (env, client, admin) = setup_test();
+    let (referrer, specialist, locum, patient) = referral_desk(&env, &client, &admin);
+    go_away(&env, &client, &specialist, 7);
+    let arrangement_id = designate(&env, &client, &specialist, &locum, 7);
+
+    let referral = client.get_referral(&refer(&env, &client, &referrer, &patient, &specialist));
+    assert_eq!(referral.requested_provider, specialist);
+    assert_eq!(referral.assigned_provider, locum);
+    assert_eq!(referral.arrangement_id, Some(arrangement_id));
+    assert!(emitted(&env, symbol_short!("REF_RRT")));
+
+    assert_eq!(client.get_provider_referrals(&locum).len(), 1);
+    assert!(client.get_provider_referrals(&specialist).is_empty());
+}
+
+#[test]
+fn test_referral_queues_without_cover() {
+    let (env, client, admin) = setup_test();
+    let (referrer, specialist, _locum, patient) = referral_desk(&env, &client, &admin);
+    let until = go_away(&env, &client, &specialist, 3);
+
+    let referral = client.get_referral(&refer(&env, &client, &referrer, &patient, &specialist));
+    assert_eq!(referral.assigned_provider, specialist);
+    assert_eq!(referral.queued_until, Some(until));
+    assert!(emitted(&env, symbol_short!("REF_QUE")));
+}
+
+#[test]
+fn test_locum_opt_out_queues_instead_of_rerouting() {
+    let (env, client, admin) = setup_test();
+    let (referrer, specialist, locum, patient) = referral_desk(&env, &client, &admin);
+    go_away(&env, &client, &specialist, 7);
+    designate(&env, &client, &specialist, &locum, 7);
+    client.set_locum_opt_out(&patient, &true);
+
+    let referral = client.get_referral(&refer(&env, &client, &referrer, &patient, &specialist));
+    assert_eq!(referral.assigned_provider, specialist);
+    assert!(referral.queued_until.is_some());
+}
+
+#[test]
+fn test_out_of_office_lapses_on_return() {
+    let (env, client, admin) = setup_test();
+    let (referrer, specialist, _locum, patient) = referral_desk(&env, &client, &admin);
+    go_away(&env, &client, &specialist, 1);
+    advance(&env, DAY);
+
+    let referral = client.get_referral(&refer(&env, &client, &referrer, &patient, &specialist));
+    assert_eq!(referral.queued_until, None);
+
+    client.set_availability(&specialist, &AvailabilityStatus::Available, &0);
+    assert_eq!(client.get_availability(&specialist).until, 0);
+}
+
+#[test]
+fn test_grant_to_away_provider_emits_routing_event() {
+    let (env, client, admin) = setup_test();
+    let (_referrer, specialist, locum, patient) = referral_desk(&env, &client, &admin);
+    go_away(&env, &client, &specialist, 3);
+    client.grant_access(&patient, &patient, &specialist, &AccessLevel::Read, &DAY);
+    assert!(emitted(&env, symbol_short!("GRT_QUE")));
+
+    designate(&env, &client, &specialist, &locum, 3);
+    client.grant_access(&patient, &patient, &specialist, &AccessLevel::Read, &DAY);
+    assert!(emitted(&env, symbol_short!("GRT_RRT")));
+}
+
+#[test]
+fn test_set_availability_validation() {
+    let (env, client, admin) = setup_test();
+    let (_referrer, specialist, _locum, patient) = referral_desk(&env, &client, &admin);
+    let now = env.ledger().timestamp();
+    let res = client.try_set_availability(&specialist, &AvailabilityStatus::OutOfOffice, &now);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidTimestamp);
+
+    let res = client.try_set_availability(&patient, &AvailabilityStatus::OutOfOffice, &(now + DAY));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_referral_requires_patient_access() {
+    let (env, client, admin) = setup_test();
+    let (referrer, specialist, _locum, patient) = referral_desk(&env, &client, &admin);
+    let res = client.try_create_referral(
+        &specialist,
+        &patient,
+        &referrer,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &ReferralUrgency::Routine,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_get_referral(&99);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::ReferralNotFound);
+}
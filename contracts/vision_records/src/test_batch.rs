@@ -7,7 +7,7 @@
 
 use super::{
     AccessLevel, BatchGrantInput, BatchRecordInput, ContractError, RecordType, Role,
-    VisionRecordsContract, VisionRecordsContractClient,
+    VisionRecordsContract, VisionRecordsContractClient, MAX_RECORD_BATCH,
 };
 use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, String, Vec};
 
@@ -65,7 +65,7 @@ fn test_batch_add_records_single() {
     inputs.push_back(BatchRecordInput {
         patient: patient.clone(),
         record_type: RecordType::Examination,
-        data_hash: String::from_str(&env, "hash_a"),
+        data_hash: String::from_str(&env, "QmBatch_hash_a00000000000000000000000000000000"),
     });
 
     let ids = client.add_records(&provider, &inputs);
@@ -90,17 +90,17 @@ fn test_batch_add_records_multiple() {
     inputs.push_back(BatchRecordInput {
         patient: patient_a.clone(),
         record_type: RecordType::Examination,
-        data_hash: String::from_str(&env, "hash_1"),
+        data_hash: String::from_str(&env, "QmBatch_hash_100000000000000000000000000000000"),
     });
     inputs.push_back(BatchRecordInput {
         patient: patient_b.clone(),
         record_type: RecordType::Prescription,
-        data_hash: String::from_str(&env, "hash_2"),
+        data_hash: String::from_str(&env, "QmBatch_hash_200000000000000000000000000000000"),
     });
     inputs.push_back(BatchRecordInput {
         patient: patient_a.clone(),
         record_type: RecordType::LabResult,
-        data_hash: String::from_str(&env, "hash_3"),
+        data_hash: String::from_str(&env, "QmBatch_hash_300000000000000000000000000000000"),
     });
 
     let ids = client.add_records(&provider, &inputs);
@@ -172,7 +172,7 @@ fn test_batch_add_records_admin_can_create() {
     inputs.push_back(BatchRecordInput {
         patient: patient.clone(),
         record_type: RecordType::Surgery,
-        data_hash: String::from_str(&env, "surgery_hash"),
+        data_hash: String::from_str(&env, "QmBatch_surgery_hash00000000000000000000000000"),
     });
 
     let ids = client.add_records(&admin, &inputs);
@@ -202,12 +202,12 @@ fn test_batch_add_records_counter_continuity() {
     inputs.push_back(BatchRecordInput {
         patient: patient.clone(),
         record_type: RecordType::Diagnosis,
-        data_hash: String::from_str(&env, "batch_hash_1"),
+        data_hash: String::from_str(&env, "QmBatch_batch_hash_100000000000000000000000000"),
     });
     inputs.push_back(BatchRecordInput {
         patient: patient.clone(),
         record_type: RecordType::Treatment,
-        data_hash: String::from_str(&env, "batch_hash_2"),
+        data_hash: String::from_str(&env, "QmBatch_batch_hash_200000000000000000000000000"),
     });
 
     let ids = client.add_records(&provider, &inputs);
@@ -216,6 +216,59 @@ fn test_batch_add_records_counter_continuity() {
     assert_eq!(client.get_record_count(), 3);
 }
 
+#[test]
+fn test_add_records_batch_appends_to_existing_index() {
+    let (env, client, admin) = setup();
+    let provider = register_provider(&env, &client, &admin);
+    let patient = register_patient(&env, &client, &admin, "Alice");
+    let existing = client.add_record(
+        &provider,
+        &patient,
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+    );
+
+    let mut inputs = Vec::new(&env);
+    for record_type in [RecordType::Prescription, RecordType::LabResult] {
+        inputs.push_back(BatchRecordInput {
+            patient: patient.clone(),
+            record_type,
+            data_hash: String::from_str(&env, "QmBatch_import_hash000000000000000000000000000"),
+        });
+    }
+
+    let ids = client.add_records_batch(&provider, &inputs);
+    assert_eq!(ids.len(), 2);
+    assert_eq!(ids.get(0).unwrap(), existing + 1);
+    assert_eq!(ids.get(1).unwrap(), existing + 2);
+
+    let recs = client.get_patient_records(&patient);
+    assert_eq!(recs.len(), 3);
+    assert_eq!(recs.get(0).unwrap(), existing);
+    assert_eq!(recs.get(2).unwrap(), existing + 2);
+}
+
+#[test]
+fn test_add_records_batch_rejects_oversized_batch() {
+    let (env, client, admin) = setup();
+    let provider = register_provider(&env, &client, &admin);
+    let patient = register_patient(&env, &client, &admin, "Alice");
+
+    let mut inputs = Vec::new(&env);
+    for _ in 0..MAX_RECORD_BATCH + 1 {
+        inputs.push_back(BatchRecordInput {
+            patient: patient.clone(),
+            record_type: RecordType::Examination,
+            data_hash: String::from_str(&env, "QmBatch_import_hash000000000000000000000000000"),
+        });
+    }
+
+    let result = client.try_add_records_batch(&provider, &inputs);
+    assert_eq!(result.err().unwrap().unwrap(), ContractError::InvalidInput);
+    assert_eq!(client.get_record_count(), 0);
+}
+
 // ======================== Batch Record Retrieval ========================
 
 #[test]
@@ -225,10 +278,10 @@ fn test_batch_get_records() {
     let patient = register_patient(&env, &client, &admin, "Alice");
 
     let hashes = [
-        String::from_str(&env, "hash_0"),
-        String::from_str(&env, "hash_1"),
-        String::from_str(&env, "hash_2"),
-        String::from_str(&env, "hash_3"),
+        String::from_str(&env, "QmBatch_hash_000000000000000000000000000000000"),
+        String::from_str(&env, "QmBatch_hash_100000000000000000000000000000000"),
+        String::from_str(&env, "QmBatch_hash_200000000000000000000000000000000"),
+        String::from_str(&env, "QmBatch_hash_300000000000000000000000000000000"),
     ];
 
     let mut inputs = Vec::new(&env);
@@ -278,7 +331,7 @@ fn test_batch_get_records_partial_not_found() {
     inputs.push_back(BatchRecordInput {
         patient: patient.clone(),
         record_type: RecordType::Examination,
-        data_hash: String::from_str(&env, "hash_1"),
+        data_hash: String::from_str(&env, "QmBatch_hash_100000000000000000000000000000000"),
     });
     client.add_records(&provider, &inputs);
 
@@ -321,8 +374,14 @@ fn test_batch_grant_access_multiple() {
     client.grant_consent(&patient, &doc2, &super::ConsentType::Treatment, &7200);
     client.grant_access_batch(&patient, &grants);
 
-    assert_eq!(client.check_access(&patient, &doc1), AccessLevel::Read);
-    assert_eq!(client.check_access(&patient, &doc2), AccessLevel::Full);
+    assert_eq!(
+        client.check_access(&patient, &doc1, &None, &None),
+        AccessLevel::Read
+    );
+    assert_eq!(
+        client.check_access(&patient, &doc2, &None, &None),
+        AccessLevel::Full
+    );
 }
 
 #[test]
@@ -352,11 +411,17 @@ fn test_batch_grant_access_expiration() {
 
     client.grant_consent(&patient, &doc, &super::ConsentType::Treatment, &500);
     client.grant_access_batch(&patient, &grants);
-    assert_eq!(client.check_access(&patient, &doc), AccessLevel::Read);
+    assert_eq!(
+        client.check_access(&patient, &doc, &None, &None),
+        AccessLevel::Read
+    );
 
     // Advance time past expiration
     env.ledger().set_timestamp(1501);
-    assert_eq!(client.check_access(&patient, &doc), AccessLevel::None);
+    assert_eq!(
+        client.check_access(&patient, &doc, &None, &None),
+        AccessLevel::None
+    );
 }
 
 #[test]
@@ -376,7 +441,10 @@ fn test_batch_grant_access_overwrite() {
     });
     client.grant_consent(&patient, &doc, &super::ConsentType::Treatment, &7200);
     client.grant_access_batch(&patient, &grants1);
-    assert_eq!(client.check_access(&patient, &doc), AccessLevel::Read);
+    assert_eq!(
+        client.check_access(&patient, &doc, &None, &None),
+        AccessLevel::Read
+    );
 
     // Overwrite with Full access via batch
     let mut grants2 = Vec::new(&env);
@@ -386,7 +454,10 @@ fn test_batch_grant_access_overwrite() {
         duration_seconds: 7200,
     });
     client.grant_access_batch(&patient, &grants2);
-    assert_eq!(client.check_access(&patient, &doc), AccessLevel::Full);
+    assert_eq!(
+        client.check_access(&patient, &doc, &None, &None),
+        AccessLevel::Full
+    );
 }
 
 // ======================== Atomicity / Gas Optimization ========================
@@ -404,7 +475,7 @@ fn test_batch_records_atomic_counter() {
         inputs.push_back(BatchRecordInput {
             patient: patient.clone(),
             record_type: RecordType::Examination,
-            data_hash: String::from_str(&env, "h"),
+            data_hash: String::from_str(&env, "QmBatch_h0000000000000000000000000000000000000"),
         });
     }
 
@@ -431,12 +502,12 @@ fn test_batch_add_and_retrieve_round_trip() {
     inputs.push_back(BatchRecordInput {
         patient: patient.clone(),
         record_type: RecordType::Examination,
-        data_hash: String::from_str(&env, "exam_data"),
+        data_hash: String::from_str(&env, "QmBatch_exam_data00000000000000000000000000000"),
     });
     inputs.push_back(BatchRecordInput {
         patient: patient.clone(),
         record_type: RecordType::Prescription,
-        data_hash: String::from_str(&env, "rx_data"),
+        data_hash: String::from_str(&env, "QmBatch_rx_data0000000000000000000000000000000"),
     });
 
     let ids = client.add_records(&provider, &inputs);
@@ -453,3 +524,47 @@ fn test_batch_add_and_retrieve_round_trip() {
     assert_eq!(records.get(0).unwrap().provider, provider);
     assert_eq!(records.get(1).unwrap().provider, provider);
 }
+
+#[test]
+fn test_add_records_batch_validates_each_hash() {
+    let (env, client, admin) = setup();
+    let provider = register_provider(&env, &client, &admin);
+    let patient = register_patient(&env, &client, &admin, "Alice");
+
+    let mut inputs = Vec::new(&env);
+    inputs.push_back(BatchRecordInput {
+        patient: patient.clone(),
+        record_type: RecordType::Examination,
+        data_hash: String::from_str(&env, "QmBatch_valid00000000000000000000000000000000"),
+    });
+    inputs.push_back(BatchRecordInput {
+        patient: patient.clone(),
+        record_type: RecordType::Examination,
+        data_hash: String::from_str(&env, "not a hash"),
+    });
+
+    let result = client.try_add_records_batch(&provider, &inputs);
+    assert_eq!(result.err().unwrap().unwrap(), ContractError::InvalidInput);
+    assert_eq!(client.get_record_count(), 0);
+}
+
+#[test]
+fn test_add_records_batch_files_under_current_address() {
+    let (env, client, admin) = setup();
+    let provider = register_provider(&env, &client, &admin);
+    let current = register_patient(&env, &client, &admin, "Alice");
+    let old = Address::generate(&env);
+    client.register_address_alias(&admin, &old, &current);
+
+    let mut inputs = Vec::new(&env);
+    inputs.push_back(BatchRecordInput {
+        patient: old.clone(),
+        record_type: RecordType::Examination,
+        data_hash: String::from_str(&env, "QmBatch_alias00000000000000000000000000000000"),
+    });
+
+    let ids = client.add_records_batch(&provider, &inputs);
+    let record = client.get_record(&provider, &ids.get(0).unwrap());
+    assert_eq!(record.patient, current);
+    assert_eq!(client.get_patient_records(&current), ids);
+}
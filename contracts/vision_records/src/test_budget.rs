@@ -0,0 +1,71 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+//! Budget regression guards for record writes.
+//!
+//! `add_record` runs on every visit, so its CPU and memory cost is pinned
+//! here. The first write for a patient also creates their index entries;
+//! later writes only append, so both are measured.
+
+extern crate std;
+
+use super::test_utils::{register_user, setup_test, DATA_HASH};
+use super::{RecordType, Role, VisionRecordsContractClient};
+use soroban_sdk::{Address, Env, String};
+use std::println;
+
+const CPU_THRESHOLD_ADD_RECORD: u64 = 15_000_000;
+const MEM_THRESHOLD_ADD_RECORD: u64 = 5_000_000;
+
+fn measure_add_record(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    patient: &Address,
+    provider: &Address,
+) -> (u64, u64) {
+    let hash = String::from_str(env, DATA_HASH);
+    env.cost_estimate().budget().reset_default();
+    client.add_record(provider, patient, provider, &RecordType::Examination, &hash);
+    let budget = env.cost_estimate().budget();
+    (budget.cpu_instruction_cost(), budget.memory_bytes_cost())
+}
+
+fn assert_within(label: &str, cpu: u64, mem: u64) {
+    println!(
+        "[REGRESSION] {label}: cpu_instructions={cpu} (threshold={CPU_THRESHOLD_ADD_RECORD}), \
+         memory_bytes={mem} (threshold={MEM_THRESHOLD_ADD_RECORD})"
+    );
+    assert!(
+        cpu <= CPU_THRESHOLD_ADD_RECORD,
+        "CPU budget regression in {label}: measured {cpu} > threshold {CPU_THRESHOLD_ADD_RECORD}"
+    );
+    assert!(
+        mem <= MEM_THRESHOLD_ADD_RECORD,
+        "Memory budget regression in {label}: measured {mem} > threshold {MEM_THRESHOLD_ADD_RECORD}"
+    );
+}
+
+#[test]
+fn regression_add_first_record() {
+    let (env, client, admin) = setup_test();
+    let patient = register_user(&env, &client, &admin, Role::Patient, "Patient");
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Provider");
+    let (cpu, mem) = measure_add_record(&env, &client, &patient, &provider);
+    assert_within("add_record_first", cpu, mem);
+}
+
+#[test]
+fn regression_add_record_with_history() {
+    let (env, client, admin) = setup_test();
+    let patient = register_user(&env, &client, &admin, Role::Patient, "Patient");
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Provider");
+    for _ in 0..20 {
+        measure_add_record(&env, &client, &patient, &provider);
+    }
+    let (cpu, mem) = measure_add_record(&env, &client, &patient, &provider);
+    assert_within("add_record_after_20", cpu, mem);
+}
@@ -0,0 +1,175 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::setup_test;
+use super::{
+    AccessLevel, AccessReasonCode, ConsentType, ContractError, TemplateSlot,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Symbol};
+
+const DAY: u64 = 86_400;
+
+fn onboarding() -> Symbol {
+    symbol_short!("ONBOARD")
+}
+
+fn has_grant(client: &VisionRecordsContractClient, patient: &Address, grantee: &Address) -> bool {
+    client.check_access_detailed(patient, grantee).reason_code != AccessReasonCode::NeverGranted
+}
+
+#[test]
+fn test_bulk_grant_applies_every_level() {
+    let (env, client, _admin) = setup_test();
+    let patient = Address::generate(&env);
+    let clinic = Address::generate(&env);
+    let optometrist = Address::generate(&env);
+
+    client.grant_access_bulk(
+        &patient,
+        &vec![
+            &env,
+            (clinic.clone(), AccessLevel::Read),
+            (optometrist.clone(), AccessLevel::Write),
+        ],
+        &(30 * DAY),
+    );
+
+    client.grant_consent(&patient, &clinic, &ConsentType::Treatment, &(30 * DAY));
+    client.grant_consent(&patient, &optometrist, &ConsentType::Treatment, &(30 * DAY));
+    assert_eq!(
+        client.check_access(&patient, &clinic, &None, &None),
+        AccessLevel::Read
+    );
+    assert_eq!(
+        client.check_access(&patient, &optometrist, &None, &None),
+        AccessLevel::Write
+    );
+}
+
+#[test]
+fn test_bulk_grant_is_all_or_nothing() {
+    let (env, client, _admin) = setup_test();
+    let patient = Address::generate(&env);
+    let clinic = Address::generate(&env);
+
+    let res = client.try_grant_access_bulk(
+        &patient,
+        &vec![
+            &env,
+            (clinic.clone(), AccessLevel::Read),
+            (clinic.clone(), AccessLevel::Write),
+        ],
+        &DAY,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+    assert!(!has_grant(&client, &patient, &clinic));
+
+    let res = client.try_grant_access_bulk(
+        &patient,
+        &vec![
+            &env,
+            (clinic.clone(), AccessLevel::Read),
+            (patient.clone(), AccessLevel::Write),
+        ],
+        &DAY,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+    assert!(!has_grant(&client, &patient, &clinic));
+}
+
+#[test]
+fn test_apply_template_fills_slots_in_order() {
+    let (env, client, admin) = setup_test();
+    let patient = Address::generate(&env);
+    client.set_grant_template(
+        &admin,
+        &onboarding(),
+        &vec![
+            &env,
+            TemplateSlot {
+                label: symbol_short!("org"),
+                level: AccessLevel::Read,
+            },
+            TemplateSlot {
+                label: symbol_short!("primary"),
+                level: AccessLevel::Write,
+            },
+        ],
+        &(90 * DAY),
+    );
+    assert_eq!(
+        client
+            .get_grant_template(&onboarding())
+            .unwrap()
+            .slots
+            .len(),
+        2
+    );
+
+    let clinic = Address::generate(&env);
+    let optometrist = Address::generate(&env);
+    client.apply_grant_template(
+        &patient,
+        &onboarding(),
+        &vec![&env, clinic.clone(), optometrist.clone()],
+    );
+
+    client.grant_consent(&patient, &optometrist, &ConsentType::Treatment, &(90 * DAY));
+    assert!(has_grant(&client, &patient, &clinic));
+    assert_eq!(
+        client.check_access(&patient, &optometrist, &None, &None),
+        AccessLevel::Write
+    );
+}
+
+#[test]
+fn test_template_requires_matching_grantees() {
+    let (env, client, admin) = setup_test();
+    let patient = Address::generate(&env);
+    client.set_grant_template(
+        &admin,
+        &onboarding(),
+        &vec![
+            &env,
+            TemplateSlot {
+                label: symbol_short!("org"),
+                level: AccessLevel::Read,
+            },
+        ],
+        &DAY,
+    );
+
+    let res = client.try_apply_grant_template(&patient, &onboarding(), &vec![&env]);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let res = client.try_apply_grant_template(
+        &patient,
+        &symbol_short!("MISSING"),
+        &vec![&env, Address::generate(&env)],
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
+
+#[test]
+fn test_template_management_requires_admin() {
+    let (env, client, _admin) = setup_test();
+    let patient = Address::generate(&env);
+    let res = client.try_set_grant_template(
+        &patient,
+        &onboarding(),
+        &vec![
+            &env,
+            TemplateSlot {
+                label: symbol_short!("org"),
+                level: AccessLevel::Read,
+            },
+        ],
+        &DAY,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
@@ -0,0 +1,91 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{AccessLevel, ContractError, RecordType, Role};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, vec, Address};
+
+#[test]
+fn test_establish_and_end_care_relationship() {
+    let (env, client, admin) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Care");
+    let patient = Address::generate(&env);
+    let rel = client.establish_care_relationship(&patient, &provider);
+    assert_eq!(rel.established_at, 1_000);
+    assert_eq!(client.get_care_team(&patient), vec![&env, rel]);
+
+    let res = client.try_establish_care_relationship(&patient, &provider);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    env.ledger().set_timestamp(2_000);
+    let ended = client.end_care_relationship(&provider, &patient, &provider);
+    assert_eq!(ended.ended_at, Some(2_000));
+    assert_eq!(client.get_care_team(&patient).len(), 0);
+
+    let res = client.try_end_care_relationship(&patient, &patient, &provider);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::CareRelationshipNotFound
+    );
+
+    // Re-establishing keeps a single care team entry
+    client.establish_care_relationship(&patient, &provider);
+    assert_eq!(client.get_care_team(&patient).len(), 1);
+}
+
+#[test]
+fn test_care_team_requires_a_provider_and_a_party_to_end() {
+    let (env, client, admin) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Care");
+    let patient = Address::generate(&env);
+    let not_provider = Address::generate(&env);
+    let res = client.try_establish_care_relationship(&patient, &not_provider);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.establish_care_relationship(&patient, &provider);
+    let res = client.try_end_care_relationship(&admin, &patient, &provider);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_care_team_provider_reads_back_own_records() {
+    let (env, client, admin) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Care");
+    let patient = Address::generate(&env);
+    let other = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Care");
+    let own = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let others = add_record(&env, &client, &other, &patient, RecordType::Examination);
+
+    assert_eq!(
+        client.check_access(&patient, &provider, &Some(own), &None),
+        AccessLevel::None
+    );
+
+    client.establish_care_relationship(&patient, &provider);
+    assert_eq!(
+        client.check_access(&patient, &provider, &Some(own), &None),
+        AccessLevel::Read
+    );
+    // Only records the provider wrote
+    assert_eq!(
+        client.check_access(&patient, &provider, &Some(others), &None),
+        AccessLevel::None
+    );
+    assert_eq!(
+        client.check_access(&patient, &provider, &None, &None),
+        AccessLevel::None
+    );
+
+    client.end_care_relationship(&patient, &patient, &provider);
+    assert_eq!(
+        client.check_access(&patient, &provider, &Some(own), &None),
+        AccessLevel::None
+    );
+}
@@ -0,0 +1,203 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, advance, register_user, setup_test, DATA_HASH};
+use super::{ContractError, RecordType, Role, SensitivityLevel, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+const DAY: u64 = 86_400;
+
+/// Two residents and one attending each author a record for the same
+/// patient. The supervisor has no read access of their own.
+fn resident_records(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, Address, Address, u64, u64, u64) {
+    let supervisor = register_user(env, client, admin, Role::Staff, "Dr. Supervisor");
+    let resident_a = register_user(env, client, admin, Role::Optometrist, "Dr. Resident A");
+    let resident_b = register_user(env, client, admin, Role::Optometrist, "Dr. Resident B");
+    let attending = register_user(env, client, admin, Role::Optometrist, "Dr. Attending");
+    let patient = Address::generate(env);
+
+    let record_a = add_record(env, client, &resident_a, &patient, RecordType::Examination);
+    let record_b = add_record(env, client, &resident_b, &patient, RecordType::Examination);
+    let record_other = add_record(env, client, &attending, &patient, RecordType::Examination);
+    (
+        supervisor,
+        resident_a,
+        resident_b,
+        patient,
+        record_a,
+        record_b,
+        record_other,
+    )
+}
+
+fn grant(
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+    supervisor: &Address,
+    resident_a: &Address,
+    resident_b: &Address,
+    days: u64,
+) -> Vec<u64> {
+    client.grant_cohort_access(
+        admin,
+        supervisor,
+        &vec![&client.env, resident_a.clone(), resident_b.clone()],
+        &(days * DAY),
+    )
+}
+
+#[test]
+fn test_supervisor_reads_resident_records_only() {
+    let (env, client, admin) = setup_test();
+    let (supervisor, resident_a, resident_b, patient, record_a, record_b, record_other) =
+        resident_records(&env, &client, &admin);
+    assert!(client.try_get_record(&supervisor, &record_a).is_err());
+
+    let ids = grant(&client, &admin, &supervisor, &resident_a, &resident_b, 30);
+    assert_eq!(ids.len(), 2);
+
+    assert_eq!(client.get_record(&supervisor, &record_a).id, record_a);
+    assert_eq!(client.get_record(&supervisor, &record_b).id, record_b);
+
+    // Same patient, but not authored by a resident: still denied.
+    let res = client.try_get_record(&supervisor, &record_other);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_reads_are_disclosed_to_patient() {
+    let (env, client, admin) = setup_test();
+    let (supervisor, resident_a, resident_b, patient, record_a, _record_b, _record_other) =
+        resident_records(&env, &client, &admin);
+    let ids = grant(&client, &admin, &supervisor, &resident_a, &resident_b, 30);
+    client.get_record(&supervisor, &record_a);
+
+    let disclosures = client.get_cohort_disclosures(&patient);
+    assert_eq!(disclosures.len(), 1);
+    let disclosure = disclosures.get(0).unwrap();
+    assert_eq!(disclosure.grant_id, ids.get(0).unwrap());
+    assert_eq!(disclosure.supervisor, supervisor);
+    assert_eq!(disclosure.resident, resident_a);
+    assert_eq!(disclosure.record_id, record_a);
+}
+
+#[test]
+fn test_revoke_is_per_resident() {
+    let (env, client, admin) = setup_test();
+    let (supervisor, resident_a, resident_b, _patient, record_a, record_b, _record_other) =
+        resident_records(&env, &client, &admin);
+    let ids = grant(&client, &admin, &supervisor, &resident_a, &resident_b, 30);
+
+    client.revoke_cohort_resident(&admin, &supervisor, &resident_a);
+    assert!(client
+        .get_cohort_grant(&ids.get(0).unwrap())
+        .revoked_at
+        .is_some());
+
+    let res = client.try_get_record(&supervisor, &record_a);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    assert_eq!(client.get_record(&supervisor, &record_b).id, record_b);
+
+    let res = client.try_revoke_cohort_resident(&admin, &supervisor, &resident_a);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::CohortGrantNotFound
+    );
+}
+
+#[test]
+fn test_grant_expires() {
+    let (env, client, admin) = setup_test();
+    let (supervisor, resident_a, resident_b, _patient, record_a, _record_b, _record_other) =
+        resident_records(&env, &client, &admin);
+    grant(&client, &admin, &supervisor, &resident_a, &resident_b, 1);
+
+    advance(&env, DAY);
+    let res = client.try_get_record(&supervisor, &record_a);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_regrant_supersedes_previous() {
+    let (env, client, admin) = setup_test();
+    let (supervisor, resident_a, resident_b, _patient, _record_a, _record_b, _record_other) =
+        resident_records(&env, &client, &admin);
+    let first = grant(&client, &admin, &supervisor, &resident_a, &resident_b, 30);
+    let second = grant(&client, &admin, &supervisor, &resident_a, &resident_b, 60);
+
+    let old = client.get_cohort_grant(&first.get(0).unwrap());
+    assert!(old.revoked_at.is_some());
+    assert!(client
+        .get_cohort_grant(&second.get(0).unwrap())
+        .revoked_at
+        .is_none());
+    assert_eq!(client.get_supervisor_cohort(&supervisor).len(), 4);
+}
+
+#[test]
+fn test_grant_requires_system_admin() {
+    let (env, client, admin) = setup_test();
+    let (supervisor, resident_a, resident_b, _patient, _record_a, _record_b, _record_other) =
+        resident_records(&env, &client, &admin);
+    let res = client.try_grant_cohort_access(
+        &resident_a,
+        &supervisor,
+        &vec![&env, resident_b.clone()],
+        &(30 * DAY),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_grant_validates_residents() {
+    let (env, client, admin) = setup_test();
+    let (supervisor, _resident_a, _resident_b, patient, _record_a, _record_b, _record_other) =
+        resident_records(&env, &client, &admin);
+
+    let res = client.try_grant_cohort_access(&admin, &supervisor, &Vec::new(&env), &(30 * DAY));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let res = client.try_grant_cohort_access(
+        &admin,
+        &supervisor,
+        &vec![&env, supervisor.clone()],
+        &(30 * DAY),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    // The patient never authors records, so cannot be a resident.
+    let res = client.try_grant_cohort_access(
+        &admin,
+        &supervisor,
+        &vec![&env, patient.clone()],
+        &(30 * DAY),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidRole);
+}
+
+#[test]
+fn test_supervisor_sees_restricted_records_as_summary() {
+    let (env, client, admin) = setup_test();
+    let (supervisor, resident_a, resident_b, patient, record_a, record_b, _record_other) =
+        resident_records(&env, &client, &admin);
+    client.set_record_sensitivity(&patient, &record_a, &SensitivityLevel::Restricted);
+    grant(&client, &admin, &supervisor, &resident_a, &resident_b, 30);
+
+    let restricted = client.get_record(&supervisor, &record_a);
+    assert_eq!(restricted.data_hash, String::from_str(&env, ""));
+    let standard = client.get_record(&supervisor, &record_b);
+    assert_eq!(standard.data_hash, String::from_str(&env, DATA_HASH));
+
+    // Deactivated supervisors lose cover
+    client.deactivate_user(&admin, &supervisor);
+    let res = client.try_get_record(&supervisor, &record_b);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
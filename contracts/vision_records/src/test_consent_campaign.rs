@@ -0,0 +1,223 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{advance, setup_test};
+use super::{
+    AccessLevel, AccessReasonCode, ConsentType, ContractError, ReconsentStatus,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, vec, Address, BytesN, Env};
+
+const DAY: u64 = 86_400;
+
+/// A patient who granted Write access and Treatment consent under the old terms.
+fn granted_under_old_terms(env: &Env, client: &VisionRecordsContractClient) -> (Address, Address) {
+    env.ledger().set_timestamp(1_000);
+    let patient = Address::generate(env);
+    let grantee = Address::generate(env);
+    client.grant_access(
+        &patient,
+        &patient,
+        &grantee,
+        &AccessLevel::Write,
+        &(90 * DAY),
+    );
+    client.grant_consent(&patient, &grantee, &ConsentType::Treatment, &(90 * DAY));
+    advance(env, 60);
+    (patient, grantee)
+}
+
+fn terms(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[42u8; 32])
+}
+
+fn start_campaign(
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+    downgrade_to: AccessLevel,
+) -> u64 {
+    let env = &client.env;
+    let deadline = env.ledger().timestamp() + 30 * DAY;
+    client.create_consent_campaign(
+        admin,
+        &terms(env),
+        &vec![env, ConsentType::Treatment],
+        &deadline,
+        &downgrade_to,
+    )
+}
+
+#[test]
+fn test_pending_consent_downgrades_after_deadline() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee) = granted_under_old_terms(&env, &client);
+    let id = start_campaign(&client, &admin, AccessLevel::Read);
+    assert_eq!(
+        client.get_reconsent_status(&id, &patient),
+        ReconsentStatus::Pending
+    );
+
+    // Untouched until the deadline
+    advance(&env, 30 * DAY - 1);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::Write
+    );
+
+    advance(&env, 1);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::Read
+    );
+    assert!(client.get_consent_campaign_progress(&id).deadline_passed);
+
+    // Accepting restores the grant
+    client.respond_consent_campaign(&patient, &id, &true);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::Write
+    );
+}
+
+#[test]
+fn test_declining_lapses_consent_immediately() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee) = granted_under_old_terms(&env, &client);
+    let id = start_campaign(&client, &admin, AccessLevel::None);
+
+    client.respond_consent_campaign(&patient, &id, &false);
+    let decision = client.check_access_detailed(&patient, &grantee);
+    assert_eq!(decision.level, AccessLevel::None);
+    assert_eq!(decision.reason_code, AccessReasonCode::ConsentLapsed);
+
+    // Changing the answer moves the counts
+    client.respond_consent_campaign(&patient, &id, &true);
+    let progress = client.get_consent_campaign_progress(&id);
+    assert_eq!(progress.accepted, 1);
+    assert_eq!(progress.declined, 0);
+}
+
+#[test]
+fn test_unaffected_consents_keep_their_grants() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee) = granted_under_old_terms(&env, &client);
+    let other_patient = Address::generate(&env);
+    client.grant_access(
+        &other_patient,
+        &other_patient,
+        &grantee,
+        &AccessLevel::Write,
+        &(90 * DAY),
+    );
+    client.grant_consent(
+        &other_patient,
+        &grantee,
+        &ConsentType::Research,
+        &(90 * DAY),
+    );
+    advance(&env, 60);
+
+    start_campaign(&client, &admin, AccessLevel::None);
+
+    // Consent given under the new terms is not affected
+    let late_patient = Address::generate(&env);
+    client.grant_access(
+        &late_patient,
+        &late_patient,
+        &grantee,
+        &AccessLevel::Write,
+        &(90 * DAY),
+    );
+    client.grant_consent(
+        &late_patient,
+        &grantee,
+        &ConsentType::Treatment,
+        &(90 * DAY),
+    );
+
+    advance(&env, 31 * DAY);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::None
+    );
+    assert_eq!(
+        client.check_access(&other_patient, &grantee, &None, &None),
+        AccessLevel::Write
+    );
+    assert_eq!(
+        client.check_access(&late_patient, &grantee, &None, &None),
+        AccessLevel::Write
+    );
+}
+
+#[test]
+fn test_closed_campaign_stops_applying() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee) = granted_under_old_terms(&env, &client);
+    let id = start_campaign(&client, &admin, AccessLevel::None);
+    advance(&env, 31 * DAY);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::None
+    );
+
+    client.close_consent_campaign(&admin, &id);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::Write
+    );
+    assert_eq!(client.get_open_consent_campaigns().len(), 0);
+    assert!(client.get_consent_campaign_progress(&id).closed);
+
+    let res = client.try_respond_consent_campaign(&patient, &id, &true);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::ConsentCampaignClosed
+    );
+}
+
+#[test]
+fn test_campaign_validation() {
+    let (env, client, admin) = setup_test();
+    granted_under_old_terms(&env, &client);
+    let outsider = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + DAY;
+    let types = vec![&env, ConsentType::Treatment];
+
+    let res = client.try_create_consent_campaign(
+        &outsider,
+        &terms(&env),
+        &types,
+        &deadline,
+        &AccessLevel::Read,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_create_consent_campaign(
+        &admin,
+        &terms(&env),
+        &types,
+        &deadline,
+        &AccessLevel::Write,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let res = client.try_create_consent_campaign(
+        &admin,
+        &terms(&env),
+        &vec![&env],
+        &deadline,
+        &AccessLevel::Read,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let res = client.try_get_consent_campaign(&99);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::ConsentCampaignNotFound
+    );
+}
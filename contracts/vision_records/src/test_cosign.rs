@@ -0,0 +1,86 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{ContractError, RecordType, Role, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, vec, Address, Env};
+
+/// A resident whose records need an attending's co-signature, and their patient.
+fn supervised_resident(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, Address) {
+    env.ledger().set_timestamp(1_000);
+    let resident = register_user(env, client, admin, Role::Optometrist, "Dr. Resident");
+    let attending = register_user(env, client, admin, Role::Ophthalmologist, "Dr. Attending");
+    (resident, attending, Address::generate(env))
+}
+
+#[test]
+fn test_cosign_workflow() {
+    let (env, client, admin) = setup_test();
+    let (resident, attending, patient) = supervised_resident(&env, &client, &admin);
+    let first = add_record(&env, &client, &resident, &patient, RecordType::Examination);
+    let second = add_record(&env, &client, &resident, &patient, RecordType::Examination);
+    assert!(!client.is_pending_cosign(&first));
+
+    client.request_cosign(&resident, &first, &attending);
+    client.request_cosign(&resident, &second, &attending);
+    assert!(client.is_pending_cosign(&first));
+    assert_eq!(
+        client.get_pending_cosigns(&attending),
+        vec![&env, first, second]
+    );
+
+    env.ledger().set_timestamp(2_000);
+    let done = client.cosign_record(&attending, &first);
+    assert_eq!(done.cosigned_at, Some(2_000));
+    assert_eq!(done.requested_by, resident);
+    assert!(!client.is_pending_cosign(&first));
+    assert!(client.is_pending_cosign(&second));
+    assert_eq!(client.get_pending_cosigns(&attending), vec![&env, second]);
+
+    let res = client.try_cosign_record(&attending, &first);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::CosignNotPending);
+}
+
+#[test]
+fn test_rerequest_moves_record_to_new_supervisor() {
+    let (env, client, admin) = setup_test();
+    let (resident, attending, patient) = supervised_resident(&env, &client, &admin);
+    let record_id = add_record(&env, &client, &resident, &patient, RecordType::Examination);
+    let other = register_user(&env, &client, &admin, Role::Ophthalmologist, "Dr. Other");
+
+    client.request_cosign(&resident, &record_id, &attending);
+    client.request_cosign(&resident, &record_id, &other);
+    assert_eq!(client.get_pending_cosigns(&attending).len(), 0);
+    assert_eq!(client.get_pending_cosigns(&other), vec![&env, record_id]);
+
+    let res = client.try_cosign_record(&attending, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::CosignNotPending);
+    client.cosign_record(&other, &record_id);
+}
+
+#[test]
+fn test_request_cosign_validation() {
+    let (env, client, admin) = setup_test();
+    let (resident, attending, patient) = supervised_resident(&env, &client, &admin);
+    let record_id = add_record(&env, &client, &resident, &patient, RecordType::Examination);
+
+    let res = client.try_request_cosign(&attending, &record_id, &attending);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_request_cosign(&resident, &record_id, &resident);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let res = client.try_request_cosign(&resident, &record_id, &patient);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidRole);
+
+    let res = client.try_request_cosign(&resident, &(record_id + 1), &attending);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+}
@@ -0,0 +1,216 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test, DATA_HASH};
+use super::{
+    custody, ContractError, LegalHoldTarget, RecordType, Role, VisionRecordsContractClient,
+};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events},
+    vec, Address, BytesN, Env, IntoVal, String,
+};
+
+/// Three clinics; the first holds an examination record.
+fn three_clinics(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, Address, u64) {
+    let clinic_a = register_user(env, client, admin, Role::Optometrist, "Eastside Clinic");
+    let clinic_b = register_user(env, client, admin, Role::Optometrist, "Central Clinic");
+    let clinic_c = register_user(env, client, admin, Role::Optometrist, "Northgate Clinic");
+    let patient = Address::generate(env);
+    let record_id = add_record(env, client, &clinic_a, &patient, RecordType::Examination);
+    (clinic_a, clinic_b, clinic_c, record_id)
+}
+
+fn reason(env: &Env, text: &str) -> String {
+    String::from_str(env, text)
+}
+
+#[test]
+fn test_transfers_build_linked_chain() {
+    let (env, client, admin) = setup_test();
+    let (clinic_a, clinic_b, clinic_c, record_id) = three_clinics(&env, &client, &admin);
+    assert!(client.get_custody_chain(&record_id).is_empty());
+    assert!(client.verify_custody_chain(&record_id));
+
+    let first = client.transfer_record_custody(
+        &clinic_a,
+        &record_id,
+        &clinic_b,
+        &reason(&env, "Clinic merger"),
+    );
+    let second = client.transfer_record_custody(
+        &admin,
+        &record_id,
+        &clinic_c,
+        &reason(&env, "Central Clinic closure"),
+    );
+
+    assert_eq!(first.seq, 0);
+    assert_eq!(first.prev_hash, BytesN::from_array(&env, &[0u8; 32]));
+    assert_eq!(second.seq, 1);
+    assert_eq!(second.from_provider, clinic_b);
+    assert_eq!(second.authorizer, admin);
+    assert_eq!(second.prev_hash, first.entry_hash);
+
+    let chain = client.get_custody_chain(&record_id);
+    assert_eq!(chain.len(), 2);
+    assert_eq!(chain.get(1).unwrap(), second);
+    assert!(client.verify_custody_chain(&record_id));
+
+    let record = client.get_record(&clinic_c, &record_id);
+    assert_eq!(record.provider, clinic_c);
+}
+
+#[test]
+fn test_tampered_chain_fails_verification() {
+    let (env, client, admin) = setup_test();
+    let (clinic_a, clinic_b, clinic_c, record_id) = three_clinics(&env, &client, &admin);
+    client.transfer_record_custody(
+        &clinic_a,
+        &record_id,
+        &clinic_b,
+        &reason(&env, "Clinic merger"),
+    );
+    client.transfer_record_custody(&clinic_b, &record_id, &clinic_c, &reason(&env, "Referral"));
+
+    env.as_contract(&client.address, || {
+        let mut chain = custody::get_chain(&env, record_id);
+        let mut link = chain.get(0).unwrap();
+        link.reason = reason(&env, "Rewritten");
+        chain.set(0, link);
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("CUS_CHN"), record_id), &chain);
+    });
+    assert!(!client.verify_custody_chain(&record_id));
+}
+
+#[test]
+fn test_transfer_authorization_and_validation() {
+    let (env, client, admin) = setup_test();
+    let (clinic_a, clinic_b, clinic_c, record_id) = three_clinics(&env, &client, &admin);
+    let res = client.try_transfer_record_custody(
+        &clinic_b,
+        &record_id,
+        &clinic_c,
+        &reason(&env, "Not mine to give"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_transfer_record_custody(
+        &clinic_a,
+        &record_id,
+        &clinic_a,
+        &reason(&env, "Self transfer"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let res = client.try_transfer_record_custody(
+        &clinic_a,
+        &record_id,
+        &Address::generate(&env),
+        &reason(&env, "Unregistered"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidRole);
+
+    let res =
+        client.try_transfer_record_custody(&clinic_a, &record_id, &clinic_b, &reason(&env, ""));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let res = client.try_verify_custody_chain(&999);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+}
+
+#[test]
+fn test_transfer_refuses_archived_and_held_records() {
+    let (env, client, admin) = setup_test();
+    let (clinic_a, clinic_b, _clinic_c, record_id) = three_clinics(&env, &client, &admin);
+
+    client.archive_record(&admin, &record_id);
+    let res = client.try_transfer_record_custody(
+        &clinic_a,
+        &record_id,
+        &clinic_b,
+        &reason(&env, "Clinic merger"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordArchived);
+    client.restore_record(&admin, &record_id);
+
+    client.place_legal_hold(
+        &admin,
+        &LegalHoldTarget::Record(record_id),
+        &BytesN::from_array(&env, &[5u8; 32]),
+    );
+    let res = client.try_transfer_record_custody(
+        &clinic_a,
+        &record_id,
+        &clinic_b,
+        &reason(&env, "Clinic merger"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::LegalHoldActive);
+    assert!(client.get_custody_chain(&record_id).is_empty());
+}
+
+#[test]
+fn test_transfer_follows_residency_and_notifies_patient() {
+    const EAST: u64 = 1;
+    const CENTRAL: u64 = 2;
+    let (env, client, admin) = setup_test();
+    let clinic_a = register_user(&env, &client, &admin, Role::Optometrist, "Eastside Clinic");
+    let clinic_b = register_user(&env, &client, &admin, Role::Optometrist, "Central Clinic");
+    client.set_provider_org(&admin, &clinic_a, &EAST);
+    client.set_provider_org(&admin, &clinic_b, &CENTRAL);
+    let patient = Address::generate(&env);
+    let record_id = client.add_record_with_residency(
+        &clinic_a,
+        &patient,
+        &clinic_a,
+        &RecordType::Examination,
+        &String::from_str(&env, DATA_HASH),
+        &symbol_short!("us"),
+    );
+
+    // The receiving clinic may not hold US-resident payloads
+    client.set_residency_policy(&admin, &CENTRAL, &vec![&env, symbol_short!("eu_west")]);
+    let res = client.try_transfer_record_custody(
+        &clinic_a,
+        &record_id,
+        &clinic_b,
+        &reason(&env, "Clinic merger"),
+    );
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::ResidencyNotAllowed
+    );
+
+    client.set_residency_policy(&admin, &CENTRAL, &vec![&env, symbol_short!("us")]);
+    client.transfer_record_custody(
+        &clinic_a,
+        &record_id,
+        &clinic_b,
+        &reason(&env, "Clinic merger"),
+    );
+    let event = env.events().all().last().unwrap();
+    assert_eq!(
+        event.1,
+        (symbol_short!("CUS_PAT"), patient, record_id).into_val(&env)
+    );
+
+    // The record now answers to the receiving clinic's policy only
+    client.set_residency_policy(&admin, &EAST, &vec![&env, symbol_short!("eu_west")]);
+    assert!(client
+        .list_records_violating_policy(&admin, &EAST)
+        .is_empty());
+    client.set_residency_policy(&admin, &CENTRAL, &vec![&env, symbol_short!("eu_west")]);
+    let violations = client.list_records_violating_policy(&admin, &CENTRAL);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations.get(0).unwrap().provider, clinic_b);
+}
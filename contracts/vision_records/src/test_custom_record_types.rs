@@ -0,0 +1,219 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{advance, register_user, setup_test, DATA_HASH};
+use super::{ContractError, RecordType, Role, VisionRecordsContractClient};
+use soroban_sdk::{Address, BytesN, Env, String};
+
+const YEAR: u64 = 31_536_000;
+const ORTHO_K: u32 = 1001;
+const LOW_VISION: u32 = 1002;
+
+fn label(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+fn add(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    patient: &Address,
+    record_type: RecordType,
+) -> Result<u64, ContractError> {
+    client
+        .try_add_record(
+            provider,
+            patient,
+            provider,
+            &record_type,
+            &String::from_str(env, DATA_HASH),
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+// ======================== Registry ========================
+
+#[test]
+fn test_register_custom_record_type() {
+    let (env, client, admin) = setup_test();
+
+    let custom = client.register_custom_record_type(&admin, &ORTHO_K, &label(&env, 1));
+    assert_eq!(custom.code, ORTHO_K);
+    assert!(custom.active);
+    assert_eq!(client.get_custom_record_type(&ORTHO_K), Some(custom));
+    assert_eq!(client.get_custom_record_types().len(), 1);
+}
+
+#[test]
+fn test_register_requires_admin() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Ortho");
+
+    let res = client.try_register_custom_record_type(&provider, &ORTHO_K, &label(&env, 1));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    assert!(client.get_custom_record_type(&ORTHO_K).is_none());
+}
+
+#[test]
+fn test_codes_are_never_reassigned() {
+    let (env, client, admin) = setup_test();
+    client.register_custom_record_type(&admin, &ORTHO_K, &label(&env, 1));
+    client.retire_custom_record_type(&admin, &ORTHO_K);
+
+    let res = client.try_register_custom_record_type(&admin, &ORTHO_K, &label(&env, 2));
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::CustomRecordTypeExists
+    );
+    assert_eq!(
+        client.get_custom_record_type(&ORTHO_K).unwrap().label_hash,
+        label(&env, 1)
+    );
+}
+
+#[test]
+fn test_retire_unknown_type() {
+    let (_env, client, admin) = setup_test();
+
+    let res = client.try_retire_custom_record_type(&admin, &ORTHO_K);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::CustomRecordTypeNotFound
+    );
+}
+
+// ======================== Records ========================
+
+#[test]
+fn test_add_record_with_custom_type() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Ortho");
+    let patient = register_user(&env, &client, &admin, Role::Patient, "Patient");
+
+    let res = add(
+        &env,
+        &client,
+        &provider,
+        &patient,
+        RecordType::Custom(ORTHO_K),
+    );
+    assert_eq!(res, Err(ContractError::InvalidRecordType));
+
+    client.register_custom_record_type(&admin, &ORTHO_K, &label(&env, 1));
+    let record_id = add(
+        &env,
+        &client,
+        &provider,
+        &patient,
+        RecordType::Custom(ORTHO_K),
+    )
+    .unwrap();
+    assert_eq!(
+        client.get_record(&patient, &record_id).record_type,
+        RecordType::Custom(ORTHO_K)
+    );
+}
+
+#[test]
+fn test_retired_type_rejects_new_records_but_keeps_old() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Ortho");
+    let patient = register_user(&env, &client, &admin, Role::Patient, "Patient");
+    client.register_custom_record_type(&admin, &ORTHO_K, &label(&env, 1));
+    let record_id = add(
+        &env,
+        &client,
+        &provider,
+        &patient,
+        RecordType::Custom(ORTHO_K),
+    )
+    .unwrap();
+
+    client.retire_custom_record_type(&admin, &ORTHO_K);
+    let res = add(
+        &env,
+        &client,
+        &provider,
+        &patient,
+        RecordType::Custom(ORTHO_K),
+    );
+    assert_eq!(res, Err(ContractError::InvalidRecordType));
+
+    let by_type = client.get_patient_records_by_type(&patient, &RecordType::Custom(ORTHO_K));
+    assert_eq!(by_type.len(), 1);
+    assert_eq!(by_type.get(0).unwrap(), record_id);
+}
+
+#[test]
+fn test_records_filtered_by_type() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Ortho");
+    let patient = register_user(&env, &client, &admin, Role::Patient, "Patient");
+    client.register_custom_record_type(&admin, &ORTHO_K, &label(&env, 1));
+    client.register_custom_record_type(&admin, &LOW_VISION, &label(&env, 2));
+
+    add(&env, &client, &provider, &patient, RecordType::Examination).unwrap();
+    let ortho = add(
+        &env,
+        &client,
+        &provider,
+        &patient,
+        RecordType::Custom(ORTHO_K),
+    )
+    .unwrap();
+    add(
+        &env,
+        &client,
+        &provider,
+        &patient,
+        RecordType::Custom(LOW_VISION),
+    )
+    .unwrap();
+
+    let by_type = client.get_patient_records_by_type(&patient, &RecordType::Custom(ORTHO_K));
+    assert_eq!(by_type.len(), 1);
+    assert_eq!(by_type.get(0).unwrap(), ortho);
+    assert_eq!(
+        client
+            .get_patient_records_by_type(&patient, &RecordType::Examination)
+            .len(),
+        1
+    );
+}
+
+// ======================== Retention ========================
+
+#[test]
+fn test_retention_policy_for_custom_type() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Ortho");
+    let patient = register_user(&env, &client, &admin, Role::Patient, "Patient");
+
+    let res = client.try_set_retention_policy(&admin, &RecordType::Custom(ORTHO_K), &YEAR, &true);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidRecordType);
+
+    client.register_custom_record_type(&admin, &ORTHO_K, &label(&env, 1));
+    client.set_retention_policy(&admin, &RecordType::Custom(ORTHO_K), &YEAR, &true);
+
+    let ortho = add(
+        &env,
+        &client,
+        &provider,
+        &patient,
+        RecordType::Custom(ORTHO_K),
+    )
+    .unwrap();
+    let exam = add(&env, &client, &provider, &patient, RecordType::Examination).unwrap();
+
+    advance(&env, YEAR + 1);
+    assert_eq!(client.apply_retention(&10), 1);
+
+    let res = client.try_get_record(&patient, &ortho);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordArchived);
+    client.get_record(&patient, &exam);
+}
@@ -0,0 +1,229 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, advance, register_user, setup_test, DATA_HASH};
+use super::{
+    data_challenge::RESPONSE_WINDOW_SECONDS, ContractError, DataAvailability,
+    DataChallengeResponse, DataChallengeStatus, RecordType, Role, VisionRecordsContractClient,
+};
+use soroban_sdk::{
+    contract, contractimpl, testutils::Address as _, testutils::Ledger as _, Address, BytesN, Env,
+    String,
+};
+use teye_common::interface::{self, InterfaceVersion};
+
+/// Minimal zk verifier: receipts are issued per (user, resource) and consumed once.
+#[contract]
+struct MockZkVerifier;
+
+#[contractimpl]
+impl MockZkVerifier {
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        InterfaceVersion {
+            interface: interface::ZK_VERIFIER,
+            major: 1,
+            minor: 0,
+        }
+    }
+
+    pub fn issue(env: Env, user: Address, resource_id: BytesN<32>) {
+        env.storage().persistent().set(&(user, resource_id), &true);
+    }
+
+    pub fn consume_receipt(
+        env: Env,
+        _consumer: Address,
+        user: Address,
+        resource_id: BytesN<32>,
+        _max_age: u64,
+    ) -> bool {
+        let key = (user, resource_id);
+        let issued = env.storage().persistent().get(&key).unwrap_or(false);
+        env.storage().persistent().remove(&key);
+        issued
+    }
+}
+
+/// Two clinics; the first holds an examination record written at t=1000.
+fn two_clinics(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, u64) {
+    env.ledger().set_timestamp(1_000);
+    let clinic_a = register_user(env, client, admin, Role::Optometrist, "Eastside Clinic");
+    let clinic_b = register_user(env, client, admin, Role::Optometrist, "Central Clinic");
+    let patient = Address::generate(env);
+    let record_id = add_record(env, client, &clinic_a, &patient, RecordType::Examination);
+    (clinic_a, clinic_b, record_id)
+}
+
+fn digest(env: &Env, byte: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[byte; 32])
+}
+
+#[test]
+fn test_attestation_answers_challenge() {
+    let (env, client, admin) = setup_test();
+    let (clinic_a, _clinic_b, record_id) = two_clinics(&env, &client, &admin);
+    let challenger = Address::generate(&env);
+    assert_eq!(
+        client.get_record_availability(&record_id),
+        DataAvailability::Unchallenged
+    );
+
+    let id = client.open_data_challenge(&challenger, &record_id);
+    let challenge = client.get_data_challenge(&id);
+    assert_eq!(challenge.status, DataChallengeStatus::Open);
+    assert_eq!(challenge.data_hash, String::from_str(&env, DATA_HASH));
+    assert_eq!(challenge.respond_by, 1_000 + RESPONSE_WINDOW_SECONDS);
+    assert_eq!(
+        client.get_record_availability(&record_id),
+        DataAvailability::Challenged
+    );
+
+    // A second challenge while one is open returns the open one
+    assert_eq!(client.open_data_challenge(&challenger, &record_id), id);
+
+    let answered = client.respond_data_challenge(
+        &clinic_a,
+        &id,
+        &DataChallengeResponse::Attestation(digest(&env, 7)),
+    );
+    assert_eq!(answered.status, DataChallengeStatus::Attested);
+    assert_eq!(answered.attestation, Some(digest(&env, 7)));
+    assert_eq!(answered.custodian, Some(clinic_a.clone()));
+    assert_eq!(
+        client.get_record_availability(&record_id),
+        DataAvailability::Verified
+    );
+
+    let res = client.try_respond_data_challenge(
+        &clinic_a,
+        &id,
+        &DataChallengeResponse::Attestation(digest(&env, 8)),
+    );
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::DataChallengeClosed
+    );
+}
+
+#[test]
+fn test_only_current_custodian_may_respond() {
+    let (env, client, admin) = setup_test();
+    let (clinic_a, clinic_b, record_id) = two_clinics(&env, &client, &admin);
+    let challenger = Address::generate(&env);
+    let id = client.open_data_challenge(&challenger, &record_id);
+
+    let res = client.try_respond_data_challenge(
+        &clinic_b,
+        &id,
+        &DataChallengeResponse::Attestation(digest(&env, 1)),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.transfer_record_custody(
+        &clinic_a,
+        &record_id,
+        &clinic_b,
+        &String::from_str(&env, "Patient moved clinics"),
+    );
+    let res = client.try_respond_data_challenge(
+        &clinic_a,
+        &id,
+        &DataChallengeResponse::Attestation(digest(&env, 1)),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.respond_data_challenge(
+        &clinic_b,
+        &id,
+        &DataChallengeResponse::Attestation(digest(&env, 1)),
+    );
+}
+
+#[test]
+fn test_unanswered_challenge_flags_record_until_next_answer() {
+    let (env, client, admin) = setup_test();
+    let (clinic_a, _clinic_b, record_id) = two_clinics(&env, &client, &admin);
+    let challenger = Address::generate(&env);
+    let first = client.open_data_challenge(&challenger, &record_id);
+
+    advance(&env, RESPONSE_WINDOW_SECONDS + 1);
+    assert_eq!(
+        client.get_record_availability(&record_id),
+        DataAvailability::Unverified
+    );
+    let res = client.try_respond_data_challenge(
+        &clinic_a,
+        &first,
+        &DataChallengeResponse::Attestation(digest(&env, 2)),
+    );
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::DataChallengeClosed
+    );
+
+    // A fresh challenge does not clear the flag by itself
+    let second = client.open_data_challenge(&challenger, &record_id);
+    assert_ne!(first, second);
+    assert!(client.get_data_challenge(&second).after_lapse);
+    assert_eq!(
+        client.get_record_availability(&record_id),
+        DataAvailability::Unverified
+    );
+
+    client.respond_data_challenge(
+        &clinic_a,
+        &second,
+        &DataChallengeResponse::Attestation(digest(&env, 3)),
+    );
+    assert_eq!(
+        client.get_record_availability(&record_id),
+        DataAvailability::Verified
+    );
+    assert_eq!(client.get_record_data_challenges(&record_id).len(), 2);
+}
+
+#[test]
+fn test_zk_proof_of_possession() {
+    let (env, client, admin) = setup_test();
+    let (clinic_a, _clinic_b, record_id) = two_clinics(&env, &client, &admin);
+    let verifier_id = env.register(MockZkVerifier, ());
+    let verifier = MockZkVerifierClient::new(&env, &verifier_id);
+    client.set_registration_verifier(&admin, &verifier_id);
+
+    let challenger = Address::generate(&env);
+    let id = client.open_data_challenge(&challenger, &record_id);
+    let nonce = client.get_data_challenge(&id).nonce;
+
+    let res = client.try_respond_data_challenge(&clinic_a, &id, &DataChallengeResponse::ZkProof);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidAttestation);
+
+    verifier.issue(&clinic_a, &nonce);
+    let answered = client.respond_data_challenge(&clinic_a, &id, &DataChallengeResponse::ZkProof);
+    assert_eq!(answered.status, DataChallengeStatus::Proven);
+    assert_eq!(
+        client.get_record_availability(&record_id),
+        DataAvailability::Verified
+    );
+}
+
+#[test]
+fn test_challenge_unknown_record() {
+    let (env, client, admin) = setup_test();
+    two_clinics(&env, &client, &admin);
+    let challenger = Address::generate(&env);
+    let res = client.try_open_data_challenge(&challenger, &999);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+    let res = client.try_get_data_challenge(&1);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::DataChallengeNotFound
+    );
+}
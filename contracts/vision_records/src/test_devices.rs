@@ -0,0 +1,130 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, setup_test};
+use super::{
+    device, ContractError, RecordProvenance, RecordType, Role, VisionRecordsContractClient,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env, String};
+
+const OCT_HASH: &str = "QmDeviceOctScanHash000000000000000000000";
+
+fn device_key() -> SigningKey {
+    SigningKey::from_bytes(&[3u8; 32])
+}
+
+/// An OCT device signing with [`device_key`], registered to a new
+/// organization. Returns the clinic admin, organization and device ids.
+fn registered_device(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, u64, u64) {
+    let clinic_admin = Address::generate(env);
+    let org_id = client.register_organization(
+        admin,
+        &String::from_str(env, "Retina Imaging Centre"),
+        &clinic_admin,
+    );
+    let device = client.register_device(
+        &clinic_admin,
+        &org_id,
+        &BytesN::from_array(env, &device_key().verifying_key().to_bytes()),
+        &String::from_str(env, "OCT-3000"),
+    );
+    (clinic_admin, org_id, device.id)
+}
+
+fn sign_submission(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    device_id: u64,
+    seq: u64,
+    patient: &Address,
+    data_hash: &String,
+) -> BytesN<64> {
+    let hash = env.as_contract(&client.address, || {
+        device::submission_hash(env, device_id, seq, patient, data_hash)
+    });
+    BytesN::from_array(env, &device_key().sign(&hash.to_array()).to_bytes())
+}
+
+#[test]
+fn test_device_submits_record() {
+    let (env, client, admin) = setup_test();
+    let (_clinic_admin, org_id, device_id) = registered_device(&env, &client, &admin);
+    let patient = Address::generate(&env);
+    let data_hash = String::from_str(&env, OCT_HASH);
+    let signature = sign_submission(&env, &client, device_id, 0, &patient, &data_hash);
+
+    let record_id = client.submit_device_record(&device_id, &patient, &data_hash, &signature);
+    let record = client.get_record(&patient, &record_id);
+    assert_eq!(record.patient, patient);
+    assert_eq!(record.provider, client.address);
+    assert_eq!(record.record_type, RecordType::Examination);
+    assert_eq!(
+        client.get_record_provenance(&record_id),
+        RecordProvenance::Device(device_id)
+    );
+    assert_eq!(client.get_device(&device_id).next_seq, 1);
+    assert_eq!(client.get_org_devices(&org_id), vec![&env, device_id]);
+}
+
+#[test]
+#[should_panic]
+fn test_replayed_submission_rejected() {
+    let (env, client, admin) = setup_test();
+    let (_clinic_admin, _org_id, device_id) = registered_device(&env, &client, &admin);
+    let patient = Address::generate(&env);
+    let data_hash = String::from_str(&env, OCT_HASH);
+    let signature = sign_submission(&env, &client, device_id, 0, &patient, &data_hash);
+
+    client.submit_device_record(&device_id, &patient, &data_hash, &signature);
+    client.submit_device_record(&device_id, &patient, &data_hash, &signature);
+}
+
+#[test]
+fn test_provider_record_provenance() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Human");
+    let record_id = client.add_record(
+        &provider,
+        &Address::generate(&env),
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&env, OCT_HASH),
+    );
+    assert_eq!(
+        client.get_record_provenance(&record_id),
+        RecordProvenance::Provider(provider)
+    );
+}
+
+#[test]
+fn test_device_registration_and_deactivation() {
+    let (env, client, admin) = setup_test();
+    let (clinic_admin, org_id, device_id) = registered_device(&env, &client, &admin);
+    let stranger = Address::generate(&env);
+    let res = client.try_register_device(
+        &stranger,
+        &org_id,
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &String::from_str(&env, "Fundus-1"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.deactivate_device(&clinic_admin, &device_id);
+    let patient = Address::generate(&env);
+    let data_hash = String::from_str(&env, OCT_HASH);
+    let signature = sign_submission(&env, &client, device_id, 0, &patient, &data_hash);
+    let res = client.try_submit_device_record(&device_id, &patient, &data_hash, &signature);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::DeviceInactive);
+
+    let res = client.try_deactivate_device(&clinic_admin, &99);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::DeviceNotFound);
+}
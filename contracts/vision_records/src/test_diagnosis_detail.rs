@@ -0,0 +1,130 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{
+    diagnosis, AccessLevel, ConsentType, ContractError, DiagnosisSeverity, Laterality, RecordType,
+    Role,
+};
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+#[test]
+fn test_icd10_code_format() {
+    let env = Env::default();
+    for code in ["H40", "H40.1", "H40.1131", "C7A.01", "H25.9"] {
+        assert!(
+            diagnosis::is_valid_icd10(&String::from_str(&env, code)),
+            "{code}"
+        );
+    }
+    for code in [
+        "",
+        "H4",
+        "H40.",
+        "H40.11311",
+        "h40.11",
+        "4H0.1",
+        "HA0.1",
+        "H4011",
+        "H40-11",
+    ] {
+        assert!(
+            !diagnosis::is_valid_icd10(&String::from_str(&env, code)),
+            "{code}"
+        );
+    }
+}
+
+#[test]
+fn test_patient_diagnoses_are_structured() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Ophthalmologist, "Dr. Icd");
+    let patient = Address::generate(&env);
+    let first = add_record(&env, &client, &provider, &patient, RecordType::Diagnosis);
+    let second = add_record(&env, &client, &provider, &patient, RecordType::Diagnosis);
+
+    let glaucoma = String::from_str(&env, "H40.1131");
+    client.add_diagnosis_detail(
+        &provider,
+        &first,
+        &glaucoma,
+        &Laterality::Bilateral,
+        &DiagnosisSeverity::Mild,
+    );
+    client.add_diagnosis_detail(
+        &provider,
+        &second,
+        &String::from_str(&env, "H25.9"),
+        &Laterality::Right,
+        &DiagnosisSeverity::Moderate,
+    );
+    // Restating a code updates it in place.
+    client.add_diagnosis_detail(
+        &provider,
+        &first,
+        &glaucoma,
+        &Laterality::Bilateral,
+        &DiagnosisSeverity::Severe,
+    );
+
+    let member = Address::generate(&env);
+    client.grant_consent(&patient, &member, &ConsentType::Treatment, &86400);
+    client.grant_access(&patient, &patient, &member, &AccessLevel::Read, &86400);
+
+    let diagnoses = client.get_patient_diagnoses(&member, &patient);
+    assert_eq!(diagnoses.len(), 2);
+    let dx = diagnoses.get(0).unwrap();
+    assert_eq!(dx.record_id, first);
+    assert_eq!(dx.code, glaucoma);
+    assert_eq!(dx.severity, DiagnosisSeverity::Severe);
+    assert_eq!(diagnoses.get(1).unwrap().laterality, Laterality::Right);
+
+    // The code is searchable through the existing code index too.
+    let ids = client.find_records_by_code(&patient, &patient, &glaucoma, &0, &10);
+    assert_eq!(ids.len(), 1);
+}
+
+#[test]
+fn test_diagnosis_detail_validation() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Ophthalmologist, "Dr. Icd");
+    let patient = Address::generate(&env);
+    let id = add_record(&env, &client, &provider, &patient, RecordType::Diagnosis);
+
+    let res = client.try_add_diagnosis_detail(
+        &provider,
+        &id,
+        &String::from_str(&env, "glaucoma"),
+        &Laterality::Left,
+        &DiagnosisSeverity::Mild,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let exam = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let code = String::from_str(&env, "H40.11");
+    let res = client.try_add_diagnosis_detail(
+        &provider,
+        &exam,
+        &code,
+        &Laterality::Left,
+        &DiagnosisSeverity::Mild,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidRecordType);
+
+    let res = client.try_add_diagnosis_detail(
+        &patient,
+        &id,
+        &code,
+        &Laterality::Left,
+        &DiagnosisSeverity::Mild,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let stranger = Address::generate(&env);
+    let res = client.try_get_patient_diagnoses(&stranger, &patient);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AccessDenied);
+}
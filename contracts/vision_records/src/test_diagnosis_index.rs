@@ -0,0 +1,121 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{
+    AccessLevel, ConsentType, ContractError, RecordType, Role, VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+fn add_diagnosis(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    patient: &Address,
+    code: &str,
+) -> u64 {
+    let id = add_record(env, client, provider, patient, RecordType::Diagnosis);
+    client.add_diagnosis_code(provider, &id, &String::from_str(env, code));
+    id
+}
+
+fn grant_care_team_access(
+    client: &VisionRecordsContractClient,
+    patient: &Address,
+    member: &Address,
+) {
+    client.grant_consent(patient, member, &ConsentType::Treatment, &86400);
+    client.grant_access(patient, patient, member, &AccessLevel::Read, &86400);
+}
+
+#[test]
+fn test_find_records_by_code_with_active_grant() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Ophthalmologist, "Dr. Dx");
+    let patient = Address::generate(&env);
+    let glaucoma_1 = add_diagnosis(&env, &client, &provider, &patient, "H40.11");
+    add_diagnosis(&env, &client, &provider, &patient, "H25.9");
+    let glaucoma_2 = add_diagnosis(&env, &client, &provider, &patient, "H40.11");
+
+    let member = Address::generate(&env);
+    grant_care_team_access(&client, &patient, &member);
+
+    let ids = client.find_records_by_code(
+        &member,
+        &patient,
+        &String::from_str(&env, "H40.11"),
+        &0,
+        &10,
+    );
+    assert_eq!(ids.len(), 2);
+    assert_eq!(ids.get(0).unwrap(), glaucoma_1);
+    assert_eq!(ids.get(1).unwrap(), glaucoma_2);
+}
+
+#[test]
+fn test_find_records_by_code_paginates() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Ophthalmologist, "Dr. Dx");
+    let patient = Address::generate(&env);
+    for _ in 0..3 {
+        add_diagnosis(&env, &client, &provider, &patient, "H40.11");
+    }
+    let code = String::from_str(&env, "H40.11");
+
+    let page = client.find_records_by_code(&patient, &patient, &code, &2, &10);
+    assert_eq!(page.len(), 1);
+
+    let page = client.find_records_by_code(&patient, &patient, &code, &5, &10);
+    assert_eq!(page.len(), 0);
+}
+
+#[test]
+fn test_find_records_by_code_requires_grant() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Ophthalmologist, "Dr. Dx");
+    let patient = Address::generate(&env);
+    add_diagnosis(&env, &client, &provider, &patient, "H40.11");
+
+    let stranger = Address::generate(&env);
+    let res = client.try_find_records_by_code(
+        &stranger,
+        &patient,
+        &String::from_str(&env, "H40.11"),
+        &0,
+        &10,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AccessDenied);
+}
+
+#[test]
+fn test_diagnosis_code_only_on_diagnosis_records() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Ophthalmologist, "Dr. Dx");
+    let patient = Address::generate(&env);
+    let exam = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+
+    let res = client.try_add_diagnosis_code(&provider, &exam, &String::from_str(&env, "H40.11"));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidRecordType);
+}
+
+#[test]
+fn test_duplicate_code_is_indexed_once() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Ophthalmologist, "Dr. Dx");
+    let patient = Address::generate(&env);
+    let id = add_diagnosis(&env, &client, &provider, &patient, "H40.11");
+    client.add_diagnosis_code(&provider, &id, &String::from_str(&env, "H40.11"));
+
+    let ids = client.find_records_by_code(
+        &patient,
+        &patient,
+        &String::from_str(&env, "H40.11"),
+        &0,
+        &10,
+    );
+    assert_eq!(ids.len(), 1);
+}
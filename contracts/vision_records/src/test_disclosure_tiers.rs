@@ -0,0 +1,132 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{
+    add_record, register_user, register_verified_provider, setup_test, DATA_HASH,
+};
+use super::{
+    AccessLevel, ConsentType, ContractError, DisclosureTier, EmergencyCondition, RecordType, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+
+const DAY: u64 = 86_400;
+
+fn grantee_with(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+    patient: &Address,
+    level: AccessLevel,
+) -> Address {
+    let grantee = register_user(env, client, admin, Role::Optometrist, "Dr. Grantee");
+    client.grant_consent(patient, &grantee, &ConsentType::Treatment, &(30 * DAY));
+    client.grant_access(patient, patient, &grantee, &level, &(30 * DAY));
+    grantee
+}
+
+#[test]
+fn test_patient_and_provider_see_full_record() {
+    let (env, client, admin) = setup_test();
+    let author = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Author");
+    let patient = Address::generate(&env);
+    let id = add_record(&env, &client, &author, &patient, RecordType::Examination);
+
+    for caller in [&patient, &author] {
+        assert_eq!(client.get_record_tier(caller, &id), DisclosureTier::Full);
+        let record = client.get_record(caller, &id);
+        assert_eq!(record.data_hash, String::from_str(&env, DATA_HASH));
+    }
+}
+
+#[test]
+fn test_read_grantee_sees_summary_only() {
+    let (env, client, admin) = setup_test();
+    let author = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Author");
+    let patient = Address::generate(&env);
+    let id = add_record(&env, &client, &author, &patient, RecordType::Examination);
+    let grantee = grantee_with(&env, &client, &admin, &patient, AccessLevel::Read);
+
+    assert_eq!(
+        client.get_record_tier(&grantee, &id),
+        DisclosureTier::Summary
+    );
+    let record = client.get_record(&grantee, &id);
+    assert_eq!(record.id, id);
+    assert_eq!(record.record_type, RecordType::Examination);
+    assert_eq!(record.provider, author);
+    assert_eq!(record.data_hash, String::from_str(&env, ""));
+    assert_eq!(record.key_version, None);
+}
+
+#[test]
+fn test_write_grantee_sees_full_record() {
+    let (env, client, admin) = setup_test();
+    let author = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Author");
+    let patient = Address::generate(&env);
+    let id = add_record(&env, &client, &author, &patient, RecordType::Examination);
+    let grantee = grantee_with(&env, &client, &admin, &patient, AccessLevel::Write);
+
+    assert_eq!(client.get_record_tier(&grantee, &id), DisclosureTier::Full);
+    let record = client.get_record(&grantee, &id);
+    assert_eq!(record.data_hash, String::from_str(&env, DATA_HASH));
+}
+
+#[test]
+fn test_record_level_grant_upgrades_tier() {
+    let (env, client, admin) = setup_test();
+    let author = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Author");
+    let patient = Address::generate(&env);
+    let id = add_record(&env, &client, &author, &patient, RecordType::Examination);
+    let grantee = grantee_with(&env, &client, &admin, &patient, AccessLevel::Read);
+    client.grant_record_access(&patient, &grantee, &id, &AccessLevel::Write, &DAY);
+
+    assert_eq!(client.get_record_tier(&grantee, &id), DisclosureTier::Full);
+}
+
+#[test]
+fn test_stranger_sees_nothing() {
+    let (env, client, admin) = setup_test();
+    let author = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Author");
+    let patient = Address::generate(&env);
+    let id = add_record(&env, &client, &author, &patient, RecordType::Examination);
+    let stranger = Address::generate(&env);
+
+    assert_eq!(client.get_record_tier(&stranger, &id), DisclosureTier::None);
+    let res = client.try_get_record(&stranger, &id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_emergency_scope_maps_to_tiers() {
+    let (env, client, admin) = setup_test();
+    let author = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Author");
+    let patient = Address::generate(&env);
+    let exam = add_record(&env, &client, &author, &patient, RecordType::Examination);
+    let surgery = add_record(&env, &client, &author, &patient, RecordType::Surgery);
+
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    client.grant_emergency_access(
+        &responder,
+        &patient,
+        &EmergencyCondition::Unconscious,
+        &String::from_str(&env, "Patient unconscious"),
+        &3600,
+        &Vec::new(&env),
+    );
+
+    assert_eq!(
+        client.get_record_tier(&responder, &exam),
+        DisclosureTier::Full
+    );
+    assert_eq!(
+        client.get_record_tier(&responder, &surgery),
+        DisclosureTier::Existence
+    );
+    let res = client.try_get_record(&responder, &surgery);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
@@ -0,0 +1,128 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test, DATA_HASH};
+use super::{ContractError, RecordType, Role};
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env, String};
+
+/// Minimal claims contract: odd attestation ids are valid.
+#[contract]
+struct MockClaims;
+
+#[contractimpl]
+impl MockClaims {
+    pub fn verify_eligibility(
+        _env: Env,
+        attestation_id: u64,
+        _patient: Address,
+        _record_type: RecordType,
+    ) -> bool {
+        attestation_id % 2 == 1
+    }
+}
+
+#[test]
+fn test_gated_record_type_requires_attestation() {
+    let (env, client, admin) = setup_test();
+    client.set_claims_contract(&admin, &env.register(MockClaims, ()));
+    let provider = register_user(&env, &client, &admin, Role::Ophthalmologist, "Dr. Surgeon");
+    let patient = Address::generate(&env);
+    client.set_eligibility_required(&admin, &RecordType::Surgery, &true);
+    assert!(client.is_eligibility_required(&RecordType::Surgery));
+
+    let res = client.try_add_record(
+        &provider,
+        &patient,
+        &provider,
+        &RecordType::Surgery,
+        &String::from_str(&env, DATA_HASH),
+    );
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::EligibilityRequired
+    );
+
+    // Ungated types are unaffected.
+    add_record(&env, &client, &provider, &patient, RecordType::Examination);
+}
+
+#[test]
+fn test_valid_attestation_is_stored_on_record() {
+    let (env, client, admin) = setup_test();
+    client.set_claims_contract(&admin, &env.register(MockClaims, ()));
+    let provider = register_user(&env, &client, &admin, Role::Ophthalmologist, "Dr. Surgeon");
+    let patient = Address::generate(&env);
+    client.set_eligibility_required(&admin, &RecordType::Surgery, &true);
+
+    let record_id = client.add_record_with_eligibility(
+        &provider,
+        &patient,
+        &provider,
+        &RecordType::Surgery,
+        &String::from_str(&env, DATA_HASH),
+        &7,
+    );
+
+    let record = client.get_record(&provider, &record_id);
+    assert_eq!(record.eligibility_attestation, Some(7));
+}
+
+#[test]
+fn test_rejected_attestation() {
+    let (env, client, admin) = setup_test();
+    client.set_claims_contract(&admin, &env.register(MockClaims, ()));
+    let provider = register_user(&env, &client, &admin, Role::Ophthalmologist, "Dr. Surgeon");
+    let patient = Address::generate(&env);
+    client.set_eligibility_required(&admin, &RecordType::Surgery, &true);
+
+    let res = client.try_add_record_with_eligibility(
+        &provider,
+        &patient,
+        &provider,
+        &RecordType::Surgery,
+        &String::from_str(&env, DATA_HASH),
+        &8,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidEligibility);
+}
+
+#[test]
+fn test_attestation_cannot_be_reused() {
+    let (env, client, admin) = setup_test();
+    client.set_claims_contract(&admin, &env.register(MockClaims, ()));
+    let provider = register_user(&env, &client, &admin, Role::Ophthalmologist, "Dr. Surgeon");
+    let patient = Address::generate(&env);
+    client.set_eligibility_required(&admin, &RecordType::Surgery, &true);
+
+    client.add_record_with_eligibility(
+        &provider,
+        &patient,
+        &provider,
+        &RecordType::Surgery,
+        &String::from_str(&env, DATA_HASH),
+        &3,
+    );
+    let res = client.try_add_record_with_eligibility(
+        &provider,
+        &patient,
+        &provider,
+        &RecordType::Surgery,
+        &String::from_str(&env, DATA_HASH),
+        &3,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidEligibility);
+}
+
+#[test]
+fn test_only_admin_can_toggle_requirement() {
+    let (env, client, admin) = setup_test();
+    client.set_claims_contract(&admin, &env.register(MockClaims, ()));
+    let provider = register_user(&env, &client, &admin, Role::Ophthalmologist, "Dr. Surgeon");
+
+    let res = client.try_set_eligibility_required(&provider, &RecordType::Surgery, &true);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
@@ -0,0 +1,158 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{advance, register_verified_provider, setup_test};
+use super::{
+    emergency::EmergencyStatus, ContractError, EmergencyCondition, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, String, Vec};
+
+const HOUR: u64 = 3_600;
+
+fn grant(
+    client: &VisionRecordsContractClient,
+    responder: &Address,
+    patient: &Address,
+    condition: EmergencyCondition,
+) -> u64 {
+    client.grant_emergency_access(
+        responder,
+        patient,
+        &condition,
+        &String::from_str(&client.env, "Attested by responder"),
+        &(2 * HOUR),
+        &Vec::new(&client.env),
+    )
+}
+
+fn audit_actions(client: &VisionRecordsContractClient, access_id: u64) -> Vec<String> {
+    let mut actions = Vec::new(&client.env);
+    for entry in client.get_emergency_audit_trail(&access_id).iter() {
+        actions.push_back(entry.action);
+    }
+    actions
+}
+
+#[test]
+fn test_non_critical_grant_waits_for_acknowledgement() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    client.set_notification_preferences(&patient, &true, &HOUR);
+    let access_id = grant(&client, &responder, &patient, EmergencyCondition::Urgent);
+
+    let access = client.get_emergency_access(&access_id);
+    assert_eq!(access.status, EmergencyStatus::Pending);
+    assert!(client
+        .check_emergency_access(&patient, &responder)
+        .is_none());
+    assert!(client
+        .try_access_record_via_emergency(&responder, &patient, &None)
+        .is_err());
+
+    advance(&env, 600);
+    client.acknowledge_emergency_access(&patient, &access_id);
+
+    let access = client.get_emergency_access(&access_id);
+    assert_eq!(access.status, EmergencyStatus::Active);
+    // The requested window starts at acknowledgement
+    assert_eq!(access.expires_at, 600 + 2 * HOUR);
+    client.access_record_via_emergency(&responder, &patient, &None);
+
+    let actions = audit_actions(&client, access_id);
+    assert!(actions.contains(String::from_str(&env, "RULE_ACK_REQ")));
+    assert!(actions.contains(String::from_str(&env, "PENDING")));
+    assert!(actions.contains(String::from_str(&env, "ACKNOWLEDGED")));
+}
+
+#[test]
+fn test_override_window_activates_grant() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    client.set_notification_preferences(&patient, &true, &HOUR);
+    let access_id = grant(&client, &responder, &patient, EmergencyCondition::Urgent);
+
+    advance(&env, HOUR);
+    client.access_record_via_emergency(&responder, &patient, &None);
+
+    let access = client.get_emergency_access(&access_id);
+    assert_eq!(access.status, EmergencyStatus::Active);
+    assert_eq!(access.expires_at, 3 * HOUR);
+    assert!(audit_actions(&client, access_id).contains(String::from_str(&env, "OVERRIDDEN")));
+}
+
+#[test]
+fn test_critical_conditions_skip_acknowledgement() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    client.set_notification_preferences(&patient, &true, &HOUR);
+    let access_id = grant(
+        &client,
+        &responder,
+        &patient,
+        EmergencyCondition::Unconscious,
+    );
+
+    assert_eq!(
+        client.get_emergency_access(&access_id).status,
+        EmergencyStatus::Active
+    );
+    assert!(audit_actions(&client, access_id).contains(String::from_str(&env, "RULE_CRITICAL")));
+
+    let res = client.try_acknowledge_emergency_access(&patient, &access_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
+
+#[test]
+fn test_custodian_may_acknowledge() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    client.set_notification_preferences(&patient, &true, &HOUR);
+    let access_id = grant(&client, &responder, &patient, EmergencyCondition::Urgent);
+
+    let stranger = Address::generate(&env);
+    let res = client.try_acknowledge_emergency_access(&stranger, &access_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let custodian = Address::generate(&env);
+    client.delegate_role(&patient, &custodian, &Role::Optometrist, &(1_000 * HOUR));
+    client.acknowledge_emergency_access(&custodian, &access_id);
+    assert_eq!(
+        client.get_emergency_access(&access_id).status,
+        EmergencyStatus::Active
+    );
+}
+
+#[test]
+fn test_preferences_default_and_validation() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    client.set_notification_preferences(&patient, &true, &HOUR);
+    let other = Address::generate(&env);
+    assert!(
+        !client
+            .get_notification_preferences(&other)
+            .require_emergency_ack
+    );
+
+    let res = client.try_set_notification_preferences(&other, &true, &60);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    // Without the preference, non-critical grants are immediate
+    client.set_notification_preferences(&patient, &false, &HOUR);
+    let access_id = grant(&client, &responder, &patient, EmergencyCondition::Urgent);
+    assert_eq!(
+        client.get_emergency_access(&access_id).status,
+        EmergencyStatus::Active
+    );
+    assert!(audit_actions(&client, access_id).contains(String::from_str(&env, "RULE_NO_ACK")));
+}
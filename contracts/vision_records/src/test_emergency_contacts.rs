@@ -0,0 +1,163 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, register_verified_provider, setup_test};
+use super::{ContractError, EmergencyCondition, Role, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+fn try_grant(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    responder: &Address,
+    patient: &Address,
+    contacts: &Vec<Address>,
+) -> Result<u64, ContractError> {
+    client
+        .try_grant_emergency_access(
+            responder,
+            patient,
+            &EmergencyCondition::Unconscious,
+            &String::from_str(env, "Patient unconscious"),
+            &3600,
+            contacts,
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_contacts_are_deduplicated() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    let kin = register_user(&env, &client, &admin, Role::Patient, "Next of kin");
+    let friend = register_user(&env, &client, &admin, Role::Patient, "Next of kin");
+
+    let contacts = vec![&env, kin.clone(), friend.clone(), kin.clone()];
+    let access_id = try_grant(&env, &client, &responder, &patient, &contacts).unwrap();
+
+    let access = client.get_emergency_access(&access_id);
+    assert_eq!(access.notified_contacts, vec![&env, kin, friend]);
+}
+
+#[test]
+fn test_contact_list_is_capped() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    client.set_emergency_contact_limit(&admin, &2);
+    assert_eq!(client.get_emergency_contact_limit(), 2);
+
+    let mut contacts = Vec::new(&env);
+    for _ in 0..3 {
+        contacts.push_back(register_user(
+            &env,
+            &client,
+            &admin,
+            Role::Patient,
+            "Next of kin",
+        ));
+    }
+    let res = try_grant(&env, &client, &responder, &patient, &contacts);
+    assert_eq!(res, Err(ContractError::InvalidInput));
+}
+
+#[test]
+fn test_requester_cannot_list_self() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+
+    let contacts = vec![&env, responder.clone()];
+    let res = try_grant(&env, &client, &responder, &patient, &contacts);
+    assert_eq!(res, Err(ContractError::InvalidInput));
+}
+
+#[test]
+fn test_unregistered_contact_rejected() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+
+    let contacts = vec![&env, Address::generate(&env)];
+    let res = try_grant(&env, &client, &responder, &patient, &contacts);
+    assert_eq!(res, Err(ContractError::UserNotFound));
+}
+
+#[test]
+fn test_contact_limit_requires_admin() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+
+    let res = client.try_set_emergency_contact_limit(&responder, &10);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_grant_defaults_to_patient_contacts() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    let kin = register_user(&env, &client, &admin, Role::Patient, "Next of kin");
+    client.set_emergency_contacts(&patient, &vec![&env, kin.clone(), kin.clone()], &false);
+    assert_eq!(
+        client.get_emergency_contacts(&patient).unwrap().contacts,
+        vec![&env, kin.clone()]
+    );
+
+    let access_id = try_grant(&env, &client, &responder, &patient, &Vec::new(&env)).unwrap();
+    let access = client.get_emergency_access(&access_id);
+    assert_eq!(access.notified_contacts, vec![&env, kin]);
+
+    // Without `listed_only`, requesters may still name others
+    let friend = register_user(&env, &client, &admin, Role::Patient, "Next of kin");
+    let access_id = try_grant(
+        &env,
+        &client,
+        &responder,
+        &patient,
+        &vec![&env, friend.clone()],
+    )
+    .unwrap();
+    let access = client.get_emergency_access(&access_id);
+    assert_eq!(access.notified_contacts, vec![&env, friend]);
+}
+
+#[test]
+fn test_listed_only_rejects_unlisted_contacts() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    let kin = register_user(&env, &client, &admin, Role::Patient, "Next of kin");
+    let stranger = register_user(&env, &client, &admin, Role::Patient, "Next of kin");
+    client.set_emergency_contacts(&patient, &vec![&env, kin.clone()], &true);
+
+    let res = try_grant(
+        &env,
+        &client,
+        &responder,
+        &patient,
+        &vec![&env, kin.clone(), stranger],
+    );
+    assert_eq!(res, Err(ContractError::ContactNotListed));
+
+    assert!(try_grant(&env, &client, &responder, &patient, &vec![&env, kin]).is_ok());
+}
+
+#[test]
+fn test_patient_contacts_must_be_registered() {
+    let (env, client, _admin) = setup_test();
+    let patient = Address::generate(&env);
+
+    let res =
+        client.try_set_emergency_contacts(&patient, &vec![&env, Address::generate(&env)], &true);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::UserNotFound);
+
+    let res = client.try_set_emergency_contacts(&patient, &vec![&env, patient.clone()], &true);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+    assert_eq!(client.get_emergency_contacts(&patient), None);
+}
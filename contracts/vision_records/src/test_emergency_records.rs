@@ -0,0 +1,171 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{advance, register_verified_provider, setup_test, DATA_HASH};
+use super::{ContractError, EmergencyCondition, RecordType, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+
+fn add_emergency(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    patient: &Address,
+    record_type: RecordType,
+    access_id: u64,
+) -> Result<u64, ContractError> {
+    client
+        .try_add_emergency_record(
+            provider,
+            patient,
+            provider,
+            &record_type,
+            &String::from_str(env, DATA_HASH),
+            &access_id,
+        )
+        .map(|id| id.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+fn grant(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    responder: &Address,
+    patient: &Address,
+) -> u64 {
+    client.grant_emergency_access(
+        responder,
+        patient,
+        &EmergencyCondition::Unconscious,
+        &String::from_str(env, "Patient unconscious"),
+        &3600,
+        &Vec::new(env),
+    )
+}
+
+#[test]
+fn test_records_are_indexed_per_grant() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    let access_id = grant(&env, &client, &responder, &patient);
+
+    let first = add_emergency(
+        &env,
+        &client,
+        &responder,
+        &patient,
+        RecordType::Examination,
+        access_id,
+    )
+    .unwrap();
+    let second = add_emergency(
+        &env,
+        &client,
+        &responder,
+        &patient,
+        RecordType::Prescription,
+        access_id,
+    )
+    .unwrap();
+
+    let records = client.get_records_for_emergency(&access_id);
+    assert_eq!(records.len(), 2);
+    assert_eq!(records.get(0).unwrap(), first);
+    assert_eq!(records.get(1).unwrap(), second);
+
+    let record = client.get_record(&patient, &first);
+    assert_eq!(record.emergency_access_id, Some(access_id));
+
+    let trail = client.get_emergency_audit_trail(&access_id);
+    assert!(trail
+        .iter()
+        .any(|e| e.action == String::from_str(&env, "RECORDED")));
+}
+
+#[test]
+fn test_unknown_grant_rejected() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    let res = add_emergency(
+        &env,
+        &client,
+        &responder,
+        &patient,
+        RecordType::Examination,
+        42,
+    );
+    assert_eq!(res, Err(ContractError::EmergencyAccessNotFound));
+}
+
+#[test]
+fn test_grant_must_belong_to_requester_and_patient() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    let access_id = grant(&env, &client, &responder, &patient);
+
+    let other_patient = Address::generate(&env);
+    let res = add_emergency(
+        &env,
+        &client,
+        &responder,
+        &other_patient,
+        RecordType::Examination,
+        access_id,
+    );
+    assert_eq!(res, Err(ContractError::AccessDenied));
+    assert_eq!(client.get_records_for_emergency(&access_id).len(), 0);
+}
+
+#[test]
+fn test_grant_scope_and_expiry_enforced() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    let access_id = grant(&env, &client, &responder, &patient);
+
+    let res = add_emergency(
+        &env,
+        &client,
+        &responder,
+        &patient,
+        RecordType::Surgery,
+        access_id,
+    );
+    assert_eq!(res, Err(ContractError::AccessDenied));
+
+    advance(&env, 3600);
+    let res = add_emergency(
+        &env,
+        &client,
+        &responder,
+        &patient,
+        RecordType::Examination,
+        access_id,
+    );
+    assert_eq!(res, Err(ContractError::AccessDenied));
+}
+
+#[test]
+fn test_revoked_grant_rejected() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    let access_id = grant(&env, &client, &responder, &patient);
+    client.revoke_emergency_access(&patient, &access_id);
+
+    let res = add_emergency(
+        &env,
+        &client,
+        &responder,
+        &patient,
+        RecordType::Examination,
+        access_id,
+    );
+    assert_eq!(res, Err(ContractError::AccessDenied));
+}
@@ -0,0 +1,95 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_verified_provider, setup_test};
+use super::{ContractError, EmergencyCondition, RecordType, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, Address, Env, String, Vec};
+
+fn grant(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    responder: &Address,
+    patient: &Address,
+) -> u64 {
+    client.grant_emergency_access(
+        responder,
+        patient,
+        &EmergencyCondition::Unconscious,
+        &String::from_str(env, "Patient unconscious"),
+        &3600,
+        &Vec::new(env),
+    )
+}
+
+#[test]
+fn test_default_scope_excludes_surgery() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    let exam = add_record(&env, &client, &responder, &patient, RecordType::Examination);
+    let surgery = add_record(&env, &client, &responder, &patient, RecordType::Surgery);
+
+    let access_id = grant(&env, &client, &responder, &patient);
+    let access = client.get_emergency_access(&access_id);
+    assert_eq!(access.scope.len(), 2);
+    assert!(access.scope.contains(RecordType::Prescription));
+
+    client.access_record_via_emergency(&responder, &patient, &Some(exam));
+
+    let res = client.try_access_record_via_emergency(&responder, &patient, &Some(surgery));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AccessDenied);
+}
+
+#[test]
+fn test_patient_policy_defines_scope() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    let exam = add_record(&env, &client, &responder, &patient, RecordType::Examination);
+    let surgery = add_record(&env, &client, &responder, &patient, RecordType::Surgery);
+
+    let mut scope = Vec::new(&env);
+    scope.push_back(RecordType::Surgery);
+    client.set_emergency_policy(&patient, &scope);
+
+    grant(&env, &client, &responder, &patient);
+
+    client.access_record_via_emergency(&responder, &patient, &Some(surgery));
+    let res = client.try_access_record_via_emergency(&responder, &patient, &Some(exam));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AccessDenied);
+}
+
+#[test]
+fn test_policy_change_does_not_widen_existing_grant() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    let surgery = add_record(&env, &client, &responder, &patient, RecordType::Surgery);
+
+    grant(&env, &client, &responder, &patient);
+
+    let mut scope = Vec::new(&env);
+    scope.push_back(RecordType::Surgery);
+    client.set_emergency_policy(&patient, &scope);
+
+    let res = client.try_access_record_via_emergency(&responder, &patient, &Some(surgery));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AccessDenied);
+}
+
+#[test]
+fn test_scope_cannot_reach_other_patients_records() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    let patient = Address::generate(&env);
+    let other = Address::generate(&env);
+    let other_exam = add_record(&env, &client, &responder, &other, RecordType::Examination);
+
+    grant(&env, &client, &responder, &patient);
+
+    let res = client.try_access_record_via_emergency(&responder, &patient, &Some(other_exam));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AccessDenied);
+}
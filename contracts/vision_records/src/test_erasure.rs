@@ -0,0 +1,159 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{
+    ContractError, DiagnosisSeverity, ErasureStatus, Eye, Laterality, LegalHoldTarget, MeasureType,
+    MeasureUnit, RecordType, Role, VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Ledger as _, Address, Bytes, BytesN, Env, String};
+
+/// A registered patient with two examinations and one IOP reading, at
+/// timestamp 1_000.
+fn patient_on_file(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, [u64; 2]) {
+    env.ledger().set_timestamp(1_000);
+    let provider = register_user(env, client, admin, Role::Ophthalmologist, "Dr. Erase");
+    let patient = register_user(env, client, admin, Role::Patient, "Pat Forgotten");
+
+    let mut record_ids = [0u64; 2];
+    for id in record_ids.iter_mut() {
+        *id = add_record(env, client, &provider, &patient, RecordType::Examination);
+    }
+    client.record_measurement(
+        &patient,
+        &provider,
+        &MeasureType::IOP,
+        &Eye::Right,
+        &180,
+        &MeasureUnit::MmHg,
+    );
+
+    (provider, patient, record_ids)
+}
+
+#[test]
+fn test_erasure_lifecycle() {
+    let (env, client, admin) = setup_test();
+    let (_provider, patient, record_ids) = patient_on_file(&env, &client, &admin);
+    assert!(client.get_erasure_status(&patient).is_none());
+
+    let request = client.request_erasure(&patient);
+    assert_eq!(request.status, ErasureStatus::Requested);
+    assert_eq!(request.requested_at, 1_000);
+    assert_eq!(client.get_erasure_status(&patient), Some(request));
+
+    env.ledger().set_timestamp(2_000);
+    let done = client.execute_erasure(&admin, &patient);
+    assert_eq!(done.status, ErasureStatus::Completed);
+    assert_eq!(done.executed_by, Some(admin.clone()));
+    assert_eq!(done.executed_at, Some(2_000));
+    assert_eq!(done.record_hashes.len(), 2);
+
+    // An auditor holding a record ID can match it to the erasure.
+    let salt = done.salt.clone().unwrap();
+    for (i, id) in record_ids.iter().enumerate() {
+        let mut preimage = Bytes::from_array(&env, &salt.to_array());
+        preimage.extend_from_array(&id.to_be_bytes());
+        let expected: BytesN<32> = env.crypto().sha256(&preimage).into();
+        assert_eq!(done.record_hashes.get(i as u32).unwrap(), expected);
+    }
+    assert_eq!(done.measurements_removed, 1);
+    assert_eq!(client.get_erasure_status(&patient), Some(done));
+}
+
+#[test]
+fn test_erasure_removes_personal_data() {
+    let (env, client, admin) = setup_test();
+    let (_provider, patient, record_ids) = patient_on_file(&env, &client, &admin);
+    assert!(client.get_user_name(&patient).is_some());
+    client.request_erasure(&patient);
+    client.execute_erasure(&admin, &patient);
+
+    assert!(client.get_patient_records(&patient).is_empty());
+    for id in record_ids {
+        let res = client.try_get_record(&patient, &id);
+        assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+    }
+    assert!(client.get_user_name(&patient).is_none());
+    assert!(client
+        .get_measurement_series(
+            &patient,
+            &patient,
+            &MeasureType::IOP,
+            &Eye::Right,
+            &0,
+            &u64::MAX,
+        )
+        .is_empty());
+}
+
+#[test]
+fn test_erasure_removes_diagnoses() {
+    let (env, client, admin) = setup_test();
+    let (provider, patient, record_ids) = patient_on_file(&env, &client, &admin);
+    client.add_diagnosis_detail(
+        &provider,
+        &record_ids[0],
+        &String::from_str(&env, "H40.11"),
+        &Laterality::Bilateral,
+        &DiagnosisSeverity::Mild,
+    );
+    assert_eq!(client.get_patient_diagnoses(&patient, &patient).len(), 1);
+
+    client.request_erasure(&patient);
+    client.execute_erasure(&admin, &patient);
+    assert!(client.get_patient_diagnoses(&patient, &patient).is_empty());
+    assert!(client.get_diagnosis_details(&record_ids[0]).is_empty());
+}
+
+#[test]
+fn test_erasure_state_errors() {
+    let (env, client, admin) = setup_test();
+    let (_provider, patient, _record_ids) = patient_on_file(&env, &client, &admin);
+    let res = client.try_execute_erasure(&admin, &patient);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::ErasureNotPending);
+
+    client.request_erasure(&patient);
+    let res = client.try_request_erasure(&patient);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::ErasureAlreadyRequested
+    );
+
+    let res = client.try_execute_erasure(&patient, &patient);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.execute_erasure(&admin, &patient);
+    let res = client.try_execute_erasure(&admin, &patient);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::ErasureNotPending);
+}
+
+#[test]
+fn test_legal_hold_blocks_erasure() {
+    let (env, client, admin) = setup_test();
+    let (_provider, patient, record_ids) = patient_on_file(&env, &client, &admin);
+    client.request_erasure(&patient);
+    let hold_id = client.place_legal_hold(
+        &admin,
+        &LegalHoldTarget::Record(record_ids[1]),
+        &BytesN::from_array(&env, &[3u8; 32]),
+    );
+
+    let res = client.try_execute_erasure(&admin, &patient);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::LegalHoldActive);
+    assert_eq!(
+        client.get_erasure_status(&patient).unwrap().status,
+        ErasureStatus::Requested
+    );
+
+    client.lift_legal_hold(&admin, &hold_id);
+    client.execute_erasure(&admin, &patient);
+}
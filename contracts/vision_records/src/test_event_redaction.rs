@@ -0,0 +1,65 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::setup_test;
+use super::{ContractError, EventRedaction, VisionRecordsContractClient};
+use soroban_sdk::testutils::Events;
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, IntoVal, String};
+
+fn create_profile(env: &Env, client: &VisionRecordsContractClient, patient: &Address) {
+    client.create_profile(
+        patient,
+        patient,
+        &String::from_str(env, "hash_dob"),
+        &String::from_str(env, "hash_gender"),
+        &String::from_str(env, "hash_blood"),
+    );
+}
+
+#[test]
+fn test_default_is_full() {
+    let (env, client, _admin) = setup_test();
+    assert_eq!(client.get_event_redaction(), EventRedaction::Full);
+
+    let patient = Address::generate(&env);
+    create_profile(&env, &client, &patient);
+    let event = env.events().all().last().unwrap();
+    assert_eq!(event.1, (symbol_short!("PROF_CRT"), patient).into_val(&env));
+}
+
+#[test]
+fn test_minimal_strips_everything_but_the_name() {
+    let (env, client, admin) = setup_test();
+    client.set_event_redaction(&admin, &EventRedaction::Minimal);
+    assert_eq!(client.get_event_redaction(), EventRedaction::Minimal);
+
+    let patient = Address::generate(&env);
+    create_profile(&env, &client, &patient);
+    let event = env.events().all().last().unwrap();
+    assert_eq!(event.1, (symbol_short!("PROF_CRT"),).into_val(&env));
+}
+
+#[test]
+fn test_hashed_hides_patient_topic() {
+    let (env, client, admin) = setup_test();
+    client.set_event_redaction(&admin, &EventRedaction::Hashed);
+
+    let patient = Address::generate(&env);
+    create_profile(&env, &client, &patient);
+    let event = env.events().all().last().unwrap();
+    assert_eq!(event.1.len(), 2);
+    assert_ne!(event.1, (symbol_short!("PROF_CRT"), patient).into_val(&env));
+}
+
+#[test]
+fn test_only_system_admin_can_configure() {
+    let (env, client, _admin) = setup_test();
+    let outsider = Address::generate(&env);
+    let res = client.try_set_event_redaction(&outsider, &EventRedaction::Minimal);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    assert_eq!(client.get_event_redaction(), EventRedaction::Full);
+}
@@ -0,0 +1,79 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::setup_test;
+use super::{ContractError, EmergencyCondition, Feature};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, testutils::Events as _, vec, Address, BytesN, IntoVal,
+    String, Vec,
+};
+
+#[test]
+fn test_all_features_enabled_by_default() {
+    let (env, client, _admin) = setup_test();
+    assert_eq!(
+        client.get_enabled_features(),
+        vec![
+            &env,
+            Feature::EmergencyAccess,
+            Feature::DataSharingAgreements,
+            Feature::SponsoredCalls,
+        ]
+    );
+    assert!(client.is_feature_enabled(&Feature::EmergencyAccess));
+}
+
+#[test]
+fn test_disabled_feature_blocks_its_entrypoints() {
+    let (env, client, admin) = setup_test();
+    let patient = Address::generate(&env);
+    let counterparty = Address::generate(&env);
+    let terms = BytesN::from_array(&env, &[7u8; 32]);
+
+    let agreement_id = client.propose_agreement(&patient, &counterparty, &terms);
+
+    client.set_feature_flag(&admin, &Feature::DataSharingAgreements, &false);
+    let (_, topics, _) = env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (symbol_short!("FEAT_SET"), Feature::DataSharingAgreements).into_val(&env)
+    );
+    assert_eq!(
+        client.get_enabled_features(),
+        vec![&env, Feature::EmergencyAccess, Feature::SponsoredCalls]
+    );
+
+    let res = client.try_propose_agreement(&patient, &counterparty, &terms);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::FeatureDisabled);
+    let res = client.try_sign_agreement(&counterparty, &agreement_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::FeatureDisabled);
+
+    // Other subsystems are untouched, and re-enabling restores the feature
+    client.set_feature_flag(&admin, &Feature::EmergencyAccess, &false);
+    let res = client.try_grant_emergency_access(
+        &Address::generate(&env),
+        &patient,
+        &EmergencyCondition::Unconscious,
+        &String::from_str(&env, "attestation"),
+        &3600,
+        &Vec::new(&env),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::FeatureDisabled);
+
+    client.set_feature_flag(&admin, &Feature::DataSharingAgreements, &true);
+    client.sign_agreement(&counterparty, &agreement_id);
+}
+
+#[test]
+fn test_only_admin_sets_feature_flags() {
+    let (env, client, _admin) = setup_test();
+    let outsider = Address::generate(&env);
+
+    let res = client.try_set_feature_flag(&outsider, &Feature::SponsoredCalls, &false);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    assert!(client.is_feature_enabled(&Feature::SponsoredCalls));
+}
@@ -0,0 +1,110 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{ContractError, FhirVersion, RecordType, Role, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, String};
+
+fn anchor(
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    record_id: u64,
+    resource_type: &str,
+) {
+    client.anchor_fhir_resource(
+        provider,
+        &record_id,
+        &String::from_str(&client.env, resource_type),
+        &FhirVersion::R4,
+        &BytesN::from_array(&client.env, &[7u8; 32]),
+    );
+}
+
+#[test]
+fn test_records_listed_by_fhir_type() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Fhir");
+    let patient = Address::generate(&env);
+    let obs_1 = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let report = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let obs_2 = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    anchor(&client, &provider, obs_1, "Observation");
+    anchor(&client, &provider, report, "DiagnosticReport");
+    anchor(&client, &provider, obs_2, "Observation");
+
+    let observation = String::from_str(&env, "Observation");
+    assert_eq!(
+        client.get_records_by_fhir_type(&patient, &patient, &observation),
+        vec![&env, obs_1, obs_2]
+    );
+
+    let envelope = client.get_fhir_envelope(&patient, &report).unwrap();
+    assert_eq!(envelope.fhir_version, FhirVersion::R4);
+    assert_eq!(
+        envelope.resource_type,
+        String::from_str(&env, "DiagnosticReport")
+    );
+}
+
+#[test]
+fn test_reanchoring_moves_record_between_types() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Fhir");
+    let patient = Address::generate(&env);
+    let id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    anchor(&client, &provider, id, "Observation");
+    anchor(&client, &provider, id, "DiagnosticReport");
+
+    let observations =
+        client.get_records_by_fhir_type(&patient, &patient, &String::from_str(&env, "Observation"));
+    assert_eq!(observations.len(), 0);
+    let reports = client.get_records_by_fhir_type(
+        &patient,
+        &patient,
+        &String::from_str(&env, "DiagnosticReport"),
+    );
+    assert_eq!(reports, vec![&env, id]);
+}
+
+#[test]
+fn test_fhir_anchor_validation_and_access() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Fhir");
+    let patient = Address::generate(&env);
+    let id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let hash = BytesN::from_array(&env, &[7u8; 32]);
+
+    for bad in ["", "observation", "Observation/1"] {
+        let res = client.try_anchor_fhir_resource(
+            &provider,
+            &id,
+            &String::from_str(&env, bad),
+            &FhirVersion::R5,
+            &hash,
+        );
+        assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+    }
+
+    let res = client.try_anchor_fhir_resource(
+        &patient,
+        &id,
+        &String::from_str(&env, "Observation"),
+        &FhirVersion::R5,
+        &hash,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let stranger = Address::generate(&env);
+    let res = client.try_get_records_by_fhir_type(
+        &stranger,
+        &patient,
+        &String::from_str(&env, "Observation"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AccessDenied);
+    let res = client.try_get_fhir_envelope(&stranger, &id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
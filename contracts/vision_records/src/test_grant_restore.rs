@@ -0,0 +1,171 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::setup_test;
+use super::{
+    AccessLevel, AccessReasonCode, ConsentType, ContractError, GrantStatus,
+    VisionRecordsContractClient, ACCESS_RESTORE_WINDOW,
+};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env};
+
+const DAY: u64 = 86_400;
+
+/// A 30-day consented read grant issued at timestamp 1_000.
+fn consented_read_grant(env: &Env, client: &VisionRecordsContractClient) -> (Address, Address) {
+    env.ledger().set_timestamp(1_000);
+    let patient = Address::generate(env);
+    let grantee = Address::generate(env);
+    client.grant_access(
+        &patient,
+        &patient,
+        &grantee,
+        &AccessLevel::Read,
+        &(30 * DAY),
+    );
+    client.grant_consent(&patient, &grantee, &ConsentType::Treatment, &(30 * DAY));
+    (patient, grantee)
+}
+
+#[test]
+fn test_revoke_keeps_grant_but_denies_access() {
+    let (env, client, _admin) = setup_test();
+    let (patient, grantee) = consented_read_grant(&env, &client);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::Read
+    );
+
+    client.revoke_access(&patient, &grantee);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::None
+    );
+    assert_eq!(
+        client.check_access_detailed(&patient, &grantee).reason_code,
+        AccessReasonCode::Revoked
+    );
+
+    let grants = client.list_access_grants(&patient);
+    assert_eq!(grants.len(), 1);
+    let grant = grants.get(0).unwrap();
+    assert_eq!(grant.status, GrantStatus::Revoked);
+    assert_eq!(grant.revoked_at, Some(1_000));
+}
+
+#[test]
+fn test_restore_within_window() {
+    let (env, client, _admin) = setup_test();
+    let (patient, grantee) = consented_read_grant(&env, &client);
+    let original = client.list_access_grants(&patient).get(0).unwrap();
+    client.revoke_access(&patient, &grantee);
+
+    env.ledger().set_timestamp(1_000 + DAY);
+    client.restore_access(&patient, &grantee);
+
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::Read
+    );
+    let grant = client.list_access_grants(&patient).get(0).unwrap();
+    assert_eq!(grant.status, GrantStatus::Active);
+    assert_eq!(grant.revoked_at, None);
+    assert_eq!(grant.expires_at, original.expires_at);
+}
+
+#[test]
+fn test_restore_after_window_fails() {
+    let (env, client, _admin) = setup_test();
+    let (patient, grantee) = consented_read_grant(&env, &client);
+    client.revoke_access(&patient, &grantee);
+
+    env.ledger()
+        .set_timestamp(1_000 + ACCESS_RESTORE_WINDOW + 1);
+    let res = client.try_restore_access(&patient, &grantee);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::RestoreWindowClosed
+    );
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::None
+    );
+}
+
+#[test]
+fn test_restore_requires_revoked_grant() {
+    let (env, client, _admin) = setup_test();
+    let (patient, grantee) = consented_read_grant(&env, &client);
+    let res = client.try_restore_access(&patient, &grantee);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let stranger = Address::generate(&env);
+    let res = client.try_restore_access(&patient, &stranger);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::AccessGrantNotFound
+    );
+}
+
+#[test]
+fn test_regrant_after_revoke_is_active() {
+    let (env, client, _admin) = setup_test();
+    let (patient, grantee) = consented_read_grant(&env, &client);
+    client.revoke_access(&patient, &grantee);
+    client.grant_access(
+        &patient,
+        &patient,
+        &grantee,
+        &AccessLevel::Write,
+        &(10 * DAY),
+    );
+
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::Write
+    );
+    assert_eq!(client.list_access_grants(&patient).len(), 1);
+}
+
+#[test]
+fn test_get_access_grants_lists_only_live_grants() {
+    let (env, client, _admin) = setup_test();
+    let (patient, grantee) = consented_read_grant(&env, &client);
+    let short_lived = Address::generate(&env);
+    let revoked = Address::generate(&env);
+    client.grant_access(&patient, &patient, &short_lived, &AccessLevel::Read, &3_600);
+    client.grant_access(
+        &patient,
+        &patient,
+        &revoked,
+        &AccessLevel::Write,
+        &(30 * DAY),
+    );
+    client.revoke_access(&patient, &revoked);
+    assert_eq!(client.list_access_grants(&patient).len(), 3);
+
+    env.ledger().set_timestamp(1_000 + DAY);
+    let live = client.get_access_grants(&patient);
+    assert_eq!(live.len(), 1);
+    assert_eq!(live.get(0).unwrap().grantee, grantee);
+}
+
+#[test]
+fn test_revoke_prunes_grantees_past_restore_window() {
+    let (env, client, _admin) = setup_test();
+    let (patient, grantee) = consented_read_grant(&env, &client);
+    let other = Address::generate(&env);
+    client.grant_access(&patient, &patient, &other, &AccessLevel::Read, &(30 * DAY));
+    client.revoke_access(&patient, &grantee);
+
+    env.ledger()
+        .set_timestamp(1_000 + ACCESS_RESTORE_WINDOW + 1);
+    client.revoke_access(&patient, &other);
+
+    let grants = client.list_access_grants(&patient);
+    assert_eq!(grants.len(), 1);
+    assert_eq!(grants.get(0).unwrap().grantee, other);
+}
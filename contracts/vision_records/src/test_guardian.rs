@@ -0,0 +1,116 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test, DATA_HASH};
+use super::{AccessLevel, ContractError, RecordType, Role};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, String};
+
+const DAY: u64 = 86_400;
+const AGE_OUT: u64 = 1_000 + 365 * DAY;
+
+#[test]
+fn test_guardian_manages_dependent_access() {
+    let (env, client, admin) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Guardian");
+    let guardian = Address::generate(&env);
+    let dependent = Address::generate(&env);
+    client.register_dependent(&provider, &guardian, &dependent, &AGE_OUT);
+    let grantee = Address::generate(&env);
+
+    client.grant_access(&guardian, &dependent, &grantee, &AccessLevel::Read, &DAY);
+    assert_eq!(
+        client.check_access(&dependent, &grantee, &None, &None),
+        AccessLevel::Read
+    );
+
+    client.guardian_revoke_access(&guardian, &dependent, &grantee);
+    assert_eq!(
+        client.check_access(&dependent, &grantee, &None, &None),
+        AccessLevel::None
+    );
+}
+
+#[test]
+fn test_guardian_reads_dependent_records() {
+    let (env, client, admin) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Guardian");
+    let guardian = Address::generate(&env);
+    let dependent = Address::generate(&env);
+    let record_id = add_record(
+        &env,
+        &client,
+        &provider,
+        &dependent,
+        RecordType::Examination,
+    );
+    let res = client.try_get_record(&guardian, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.register_dependent(&provider, &guardian, &dependent, &AGE_OUT);
+    let record = client.get_record(&guardian, &record_id);
+    assert_eq!(record.data_hash, String::from_str(&env, DATA_HASH));
+}
+
+#[test]
+fn test_guardianship_ages_out() {
+    let (env, client, admin) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Guardian");
+    let guardian = Address::generate(&env);
+    let dependent = Address::generate(&env);
+    client.register_dependent(&provider, &guardian, &dependent, &AGE_OUT);
+    assert_eq!(client.get_guardians(&dependent).len(), 1);
+
+    env.ledger().set_timestamp(AGE_OUT);
+    assert_eq!(client.get_guardians(&dependent).len(), 0);
+    let res = client.try_grant_access(
+        &guardian,
+        &dependent,
+        &Address::generate(&env),
+        &AccessLevel::Read,
+        &DAY,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    // The dependent now manages their own access
+    client.grant_access(
+        &dependent,
+        &dependent,
+        &Address::generate(&env),
+        &AccessLevel::Read,
+        &DAY,
+    );
+}
+
+#[test]
+fn test_registration_needs_confirmation_and_can_end() {
+    let (env, client, admin) = setup_test();
+    env.ledger().set_timestamp(1_000);
+    let guardian = Address::generate(&env);
+    let dependent = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let res = client.try_register_dependent(&stranger, &guardian, &dependent, &AGE_OUT);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    let res = client.try_register_dependent(&admin, &guardian, &dependent, &1_000);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let guardianship = client.register_dependent(&admin, &guardian, &dependent, &AGE_OUT);
+    assert_eq!(guardianship.confirmed_by, admin);
+
+    let res = client.try_end_guardianship(&stranger, &guardian, &dependent);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let ended = client.end_guardianship(&guardian, &guardian, &dependent);
+    assert_eq!(ended.ended_at, Some(1_000));
+    let res = client.try_end_guardianship(&admin, &guardian, &dependent);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::GuardianshipNotFound
+    );
+}
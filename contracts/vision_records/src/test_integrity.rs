@@ -0,0 +1,104 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test, DATA_HASH};
+use super::{AccessLevel, ContractError, RecordType, Role, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, String};
+
+const SWAPPED_HASH: &str = "QmIntegritySwappedHash000000000000000000";
+
+/// A patient with one examination written at timestamp 1_000.
+fn examined_patient(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, u64) {
+    env.ledger().set_timestamp(1_000);
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Integrity");
+    let patient = Address::generate(env);
+    let record_id = add_record(env, client, &provider, &patient, RecordType::Examination);
+    (patient, record_id)
+}
+
+#[test]
+fn test_attestation_records_match_and_mismatch() {
+    let (env, client, admin) = setup_test();
+    let (patient, record_id) = examined_patient(&env, &client, &admin);
+
+    let ok =
+        client.attest_record_integrity(&patient, &record_id, &String::from_str(&env, DATA_HASH));
+    assert!(ok.matched);
+    assert_eq!(ok.seq, 0);
+    assert_eq!(ok.attester, patient);
+    assert_eq!(ok.attested_at, 1_000);
+
+    env.ledger().set_timestamp(2_000);
+    let swapped =
+        client.attest_record_integrity(&patient, &record_id, &String::from_str(&env, SWAPPED_HASH));
+    assert!(!swapped.matched);
+    assert_eq!(swapped.seq, 1);
+
+    assert_eq!(client.get_integrity_attestation_count(&record_id), 2);
+    let log = client.get_integrity_attestations(&patient, &record_id, &0);
+    assert_eq!(log.len(), 2);
+    assert_eq!(log.get(1).unwrap(), swapped);
+    assert_eq!(
+        client
+            .get_integrity_attestations(&patient, &record_id, &1)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_attestation_requires_full_disclosure() {
+    let (env, client, admin) = setup_test();
+    let (patient, record_id) = examined_patient(&env, &client, &admin);
+    let hash = String::from_str(&env, DATA_HASH);
+
+    let stranger = Address::generate(&env);
+    let res = client.try_attest_record_integrity(&stranger, &record_id, &hash);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    // A read grant only discloses a summary, without the data hash
+    client.grant_access(&patient, &patient, &stranger, &AccessLevel::Read, &86_400);
+    let res = client.try_attest_record_integrity(&stranger, &record_id, &hash);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.grant_access(&patient, &patient, &stranger, &AccessLevel::Write, &86_400);
+    assert!(
+        client
+            .attest_record_integrity(&stranger, &record_id, &hash)
+            .matched
+    );
+
+    let res = client.try_attest_record_integrity(&patient, &(record_id + 1), &hash);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+}
+
+#[test]
+fn test_attestation_log_requires_full_disclosure() {
+    let (env, client, admin) = setup_test();
+    let (patient, record_id) = examined_patient(&env, &client, &admin);
+    client.attest_record_integrity(&patient, &record_id, &String::from_str(&env, DATA_HASH));
+
+    let stranger = Address::generate(&env);
+    let res = client.try_get_integrity_attestations(&stranger, &record_id, &0);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.grant_access(&patient, &patient, &stranger, &AccessLevel::Read, &86_400);
+    let res = client.try_get_integrity_attestations(&stranger, &record_id, &0);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.grant_access(&patient, &patient, &stranger, &AccessLevel::Write, &86_400);
+    assert_eq!(
+        client
+            .get_integrity_attestations(&stranger, &record_id, &0)
+            .len(),
+        1
+    );
+}
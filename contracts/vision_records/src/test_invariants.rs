@@ -0,0 +1,115 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, advance, register_user, setup_test};
+use super::{AccessGrant, AccessLevel, RecordType, Role, VisionRecordsContractClient};
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, Env, Vec};
+use teye_common::invariant::InvariantSubject;
+
+/// Three records and one access grant for a single patient.
+fn patient_with_grant(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, Vec<u64>) {
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Check");
+    let patient = Address::generate(env);
+    let mut records = Vec::new(env);
+    for _ in 0..3 {
+        records.push_back(add_record(
+            env,
+            client,
+            &provider,
+            &patient,
+            RecordType::Examination,
+        ));
+    }
+    let grantee = Address::generate(env);
+    client.grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &3600);
+    (patient, grantee, records)
+}
+
+#[test]
+fn test_consistent_state_passes_in_slices() {
+    let (env, client, admin) = setup_test();
+    patient_with_grant(&env, &client, &admin);
+
+    let report = client.run_invariant_checks(&2);
+    assert_eq!((report.from, report.to), (1, 2));
+    assert!(!report.wrapped);
+    assert_eq!(report.violation_count, 0);
+
+    let report = client.run_invariant_checks(&2);
+    assert_eq!((report.from, report.to), (3, 3));
+    assert!(report.wrapped);
+    assert_eq!(report.violation_count, 0);
+}
+
+#[test]
+fn test_archived_records_stay_valid_index_entries() {
+    let (env, client, admin) = setup_test();
+    let (patient, _grantee, _records) = patient_with_grant(&env, &client, &admin);
+    client.set_retention_policy(&admin, &RecordType::Examination, &3600, &true);
+    advance(&env, 3601);
+
+    // A fresh record keeps the patient's index under inspection
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Later");
+    add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    assert_eq!(client.apply_retention(&10), 3);
+
+    let report = client.run_invariant_checks(&10);
+    assert_eq!(report.violation_count, 0);
+}
+
+#[test]
+fn test_index_and_grant_violations_are_reported() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee, records) = patient_with_grant(&env, &client, &admin);
+    let dropped = records.get(1).unwrap();
+
+    env.as_contract(&client.address, || {
+        // Drop one record from the patient index and point it at a ghost
+        let key = (symbol_short!("PAT_REC"), patient.clone());
+        let index = vec![
+            &env,
+            records.get(0).unwrap(),
+            records.get(2).unwrap(),
+            99u64,
+        ];
+        env.storage().persistent().set(&key, &index);
+
+        let grant_key = (symbol_short!("ACCESS"), patient.clone(), grantee.clone());
+        let mut grant: AccessGrant = env.storage().persistent().get(&grant_key).unwrap();
+        grant.expires_at = grant.granted_at;
+        env.storage().persistent().set(&grant_key, &grant);
+    });
+
+    let report = client.run_invariant_checks(&10);
+    assert_eq!(report.violation_count, 3);
+    let subject_of = |check| {
+        report
+            .violations
+            .iter()
+            .find(|v| v.check == check)
+            .map(|v| v.subject)
+    };
+    assert_eq!(
+        subject_of(symbol_short!("idx_miss")),
+        Some(InvariantSubject::Id(dropped))
+    );
+    assert_eq!(
+        subject_of(symbol_short!("idx_dang")),
+        Some(InvariantSubject::Id(99))
+    );
+    assert_eq!(
+        subject_of(symbol_short!("grt_exp")),
+        Some(InvariantSubject::AddressPair(
+            patient.clone(),
+            grantee.clone()
+        ))
+    );
+}
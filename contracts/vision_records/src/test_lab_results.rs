@@ -0,0 +1,114 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{ContractError, RecordType, Role, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+const RESULT_HASH: &str = "QmLabResultReportHash0000000000000000000";
+
+/// A registered lab and an examination it can report against.
+fn lab_order(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, Address, u64) {
+    let provider = register_user(env, client, admin, Role::Ophthalmologist, "Dr. Order");
+    let lab = register_user(env, client, admin, Role::Lab, "Ocular Pathology Lab");
+    let patient = Address::generate(env);
+    let record_id = add_record(env, client, &provider, &patient, RecordType::Examination);
+    (lab, provider, patient, record_id)
+}
+
+fn submit(
+    client: &VisionRecordsContractClient,
+    lab: &Address,
+    provider: &Address,
+    patient: &Address,
+    record_id: u64,
+) -> u64 {
+    client
+        .submit_lab_result(
+            lab,
+            patient,
+            provider,
+            &record_id,
+            &String::from_str(&client.env, "24331-1"),
+            &String::from_str(&client.env, RESULT_HASH),
+        )
+        .id
+}
+
+#[test]
+fn test_lab_result_links_to_ordering_record() {
+    let (env, client, admin) = setup_test();
+    let (lab, provider, patient, record_id) = lab_order(&env, &client, &admin);
+    let id = submit(&client, &lab, &provider, &patient, record_id);
+
+    assert_eq!(client.get_record_lab_results(&record_id), vec![&env, id]);
+    assert_eq!(client.get_ordered_lab_results(&provider), vec![&env, id]);
+
+    let result = client.get_lab_result(&provider, &id);
+    assert_eq!(result.lab, lab);
+    assert_eq!(result.ordering_record_id, record_id);
+    client.get_lab_result(&patient, &id);
+}
+
+#[test]
+fn test_only_labs_submit_results() {
+    let (env, client, admin) = setup_test();
+    let (_lab, provider, patient, record_id) = lab_order(&env, &client, &admin);
+    let res = client.try_submit_lab_result(
+        &provider,
+        &patient,
+        &provider,
+        &record_id,
+        &String::from_str(&env, "24331-1"),
+        &String::from_str(&env, RESULT_HASH),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_result_must_match_ordering_record() {
+    let (env, client, admin) = setup_test();
+    let (lab, provider, patient, record_id) = lab_order(&env, &client, &admin);
+    let other_patient = Address::generate(&env);
+    let res = client.try_submit_lab_result(
+        &lab,
+        &other_patient,
+        &provider,
+        &record_id,
+        &String::from_str(&env, "24331-1"),
+        &String::from_str(&env, RESULT_HASH),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let res = client.try_submit_lab_result(
+        &lab,
+        &patient,
+        &provider,
+        &record_id,
+        &String::from_str(&env, ""),
+        &String::from_str(&env, RESULT_HASH),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
+
+#[test]
+fn test_stranger_cannot_read_result() {
+    let (env, client, admin) = setup_test();
+    let (lab, provider, patient, record_id) = lab_order(&env, &client, &admin);
+    let id = submit(&client, &lab, &provider, &patient, record_id);
+
+    let stranger = Address::generate(&env);
+    let res = client.try_get_lab_result(&stranger, &id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_get_lab_result(&patient, &(id + 1));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::LabResultNotFound);
+}
@@ -0,0 +1,82 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, advance, register_user, setup_test};
+use super::{ContractError, LegalHoldTarget, RecordType, Role};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+
+const YEAR: u64 = 31_536_000;
+
+fn case_ref(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[7u8; 32])
+}
+
+#[test]
+fn test_place_and_lift_hold() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Hold");
+    let patient = Address::generate(&env);
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let target = LegalHoldTarget::Record(record_id);
+
+    let hold_id = client.place_legal_hold(&admin, &target, &case_ref(&env));
+    assert!(client.is_under_legal_hold(&target));
+    assert_eq!(client.list_legal_holds().len(), 1);
+
+    client.lift_legal_hold(&admin, &hold_id);
+    assert!(!client.is_under_legal_hold(&target));
+    assert_eq!(client.list_legal_holds().len(), 0);
+
+    let hold = client.get_legal_hold(&hold_id);
+    assert_eq!(hold.lifted_by, Some(admin.clone()));
+
+    let res = client.try_lift_legal_hold(&admin, &hold_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::LegalHoldNotFound);
+}
+
+#[test]
+fn test_hold_requires_admin() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Hold");
+    let patient = Address::generate(&env);
+
+    let res = client.try_place_legal_hold(
+        &provider,
+        &LegalHoldTarget::Patient(patient),
+        &case_ref(&env),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_hold_on_missing_record_rejected() {
+    let (env, client, admin) = setup_test();
+
+    let res = client.try_place_legal_hold(&admin, &LegalHoldTarget::Record(99), &case_ref(&env));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+}
+
+#[test]
+fn test_patient_hold_blocks_retention_archival() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Hold");
+    let patient = Address::generate(&env);
+    client.set_retention_policy(&admin, &RecordType::Examination, &YEAR, &true);
+    add_record(&env, &client, &provider, &patient, RecordType::Examination);
+
+    let hold_id = client.place_legal_hold(
+        &admin,
+        &LegalHoldTarget::Patient(patient.clone()),
+        &case_ref(&env),
+    );
+
+    advance(&env, YEAR + 1);
+    assert_eq!(client.apply_retention(&10), 0);
+
+    client.lift_legal_hold(&admin, &hold_id);
+    assert_eq!(client.apply_retention(&10), 1);
+}
@@ -0,0 +1,77 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{
+    events::{
+        AccessGrantedEvent, AccessRevokedEvent, RecordAddedEvent, RecordAmendedEvent,
+        UserRegisteredEvent,
+    },
+    AccessLevel, RecordType, Role,
+};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, testutils::Events as _, Address, Env, String, Symbol,
+    TryFromVal, Val,
+};
+
+const AMENDED_HASH: &str = "QmLifecycleEventAmendedHash00000000000000";
+
+/// Data of the event named `name` published by the last invocation
+fn event_data<T: TryFromVal<Env, Val>>(env: &Env, name: Symbol) -> T {
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(env, &t).ok())
+                == Some(name.clone())
+        })
+        .expect("event not published");
+    T::try_from_val(env, &data)
+        .ok()
+        .expect("unexpected event data")
+}
+
+#[test]
+fn test_record_lifecycle_is_published() {
+    let (env, client, admin) = setup_test();
+
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Timeline");
+    let registered: UserRegisteredEvent = event_data(&env, symbol_short!("USR_REG"));
+    assert_eq!(registered.user, provider);
+    assert_eq!(registered.role, Role::Optometrist);
+
+    let patient = Address::generate(&env);
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let added: RecordAddedEvent = event_data(&env, symbol_short!("REC_ADD"));
+    assert_eq!(added.record_id, record_id);
+    assert_eq!(added.patient, patient);
+    assert_eq!(added.record_type, RecordType::Examination);
+
+    client.amend_record(
+        &provider,
+        &record_id,
+        &String::from_str(&env, AMENDED_HASH),
+        &String::from_str(&env, "wrong eye recorded"),
+    );
+    let amended: RecordAmendedEvent = event_data(&env, symbol_short!("REC_AMND"));
+    assert_eq!(amended.record_id, record_id);
+    assert_eq!(amended.patient, patient);
+    assert_eq!(amended.version, 1);
+
+    let grantee = Address::generate(&env);
+    client.grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &3600);
+    let granted: AccessGrantedEvent = event_data(&env, symbol_short!("ACC_GRT"));
+    assert_eq!(granted.grantee, grantee);
+
+    client.revoke_access(&patient, &grantee);
+    let revoked: AccessRevokedEvent = event_data(&env, symbol_short!("ACC_REV"));
+    assert_eq!(revoked.patient, patient);
+    assert_eq!(revoked.grantee, grantee);
+}
@@ -0,0 +1,120 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, advance, register_user, setup_test};
+use super::{
+    AccessLevel, ConsentType, ContractError, RecordType, Role, VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+const DAY: u64 = 86_400;
+
+/// The absent provider holds a consented read grant on a record written by
+/// another clinician; the locum has no access of their own.
+fn absent_provider_grant(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, Address, u64) {
+    let author = register_user(env, client, admin, Role::Optometrist, "Dr. Author");
+    let absent = register_user(env, client, admin, Role::Optometrist, "Dr. Absent");
+    let locum = register_user(env, client, admin, Role::Optometrist, "Dr. Locum");
+    let patient = Address::generate(env);
+
+    let record_id = add_record(env, client, &author, &patient, RecordType::Examination);
+    client.grant_consent(&patient, &absent, &ConsentType::Treatment, &(30 * DAY));
+    client.grant_access(&patient, &patient, &absent, &AccessLevel::Read, &(30 * DAY));
+
+    (absent, locum, patient, record_id)
+}
+
+fn designate(
+    client: &VisionRecordsContractClient,
+    absent: &Address,
+    locum: &Address,
+    days: u64,
+) -> u64 {
+    let now = client.env.ledger().timestamp();
+    client.designate_locum(absent, locum, &now, &(now + days * DAY))
+}
+
+#[test]
+fn test_locum_reads_through_absent_grant_with_disclosure() {
+    let (env, client, admin) = setup_test();
+    let (absent, locum, patient, record_id) = absent_provider_grant(&env, &client, &admin);
+    assert!(client.try_get_record(&locum, &record_id).is_err());
+
+    let arrangement_id = designate(&client, &absent, &locum, 7);
+    let record = client.get_record(&locum, &record_id);
+    assert_eq!(record.id, record_id);
+
+    let disclosures = client.get_locum_disclosures(&patient);
+    assert_eq!(disclosures.len(), 1);
+    let disclosure = disclosures.get(0).unwrap();
+    assert_eq!(disclosure.arrangement_id, arrangement_id);
+    assert_eq!(disclosure.covering_provider, locum);
+    assert_eq!(disclosure.absent_provider, absent);
+}
+
+#[test]
+fn test_cover_expires_automatically() {
+    let (env, client, admin) = setup_test();
+    let (absent, locum, _patient, record_id) = absent_provider_grant(&env, &client, &admin);
+    designate(&client, &absent, &locum, 1);
+
+    advance(&env, DAY);
+    let res = client.try_get_record(&locum, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_patient_opt_out_blocks_cover() {
+    let (env, client, admin) = setup_test();
+    let (absent, locum, patient, record_id) = absent_provider_grant(&env, &client, &admin);
+    designate(&client, &absent, &locum, 7);
+
+    client.set_locum_opt_out(&patient, &true);
+    assert!(client.get_privacy_settings(&patient).locum_opt_out);
+
+    let res = client.try_get_record(&locum, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_cancelled_cover_grants_nothing() {
+    let (env, client, admin) = setup_test();
+    let (absent, locum, _patient, record_id) = absent_provider_grant(&env, &client, &admin);
+    let arrangement_id = designate(&client, &absent, &locum, 7);
+
+    client.cancel_locum(&absent, &arrangement_id);
+    assert!(client.get_locum_arrangement(&arrangement_id).cancelled);
+
+    let res = client.try_get_record(&locum, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_cancel_locum(&absent, &arrangement_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
+
+#[test]
+fn test_designation_validation() {
+    let (env, client, admin) = setup_test();
+    let (absent, locum, _patient, _record_id) = absent_provider_grant(&env, &client, &admin);
+    let now = env.ledger().timestamp();
+
+    let res = client.try_designate_locum(&absent, &absent, &now, &(now + DAY));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let res = client.try_designate_locum(&absent, &locum, &now, &(now + 91 * DAY));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidTimestamp);
+
+    let stranger = Address::generate(&env);
+    let res = client.try_designate_locum(&absent, &stranger, &now, &(now + DAY));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidRole);
+
+    assert_eq!(client.get_provider_locum_arrangements(&absent).len(), 0);
+}
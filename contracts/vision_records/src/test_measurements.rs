@@ -0,0 +1,205 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, setup_test};
+use super::{
+    measurement::MAX_SERIES_LEN, AccessLevel, ConsentType, ContractError, Eye, MeasureType,
+    MeasureUnit, Role, VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env};
+
+fn record_iop(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    patient: &Address,
+    at: u64,
+    tenths: i32,
+) {
+    env.ledger().set_timestamp(at);
+    client.record_measurement(
+        patient,
+        provider,
+        &MeasureType::IOP,
+        &Eye::Right,
+        &tenths,
+        &MeasureUnit::MmHg,
+    );
+}
+
+#[test]
+fn test_iop_series_by_time_range() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(
+        &env,
+        &client,
+        &admin,
+        Role::Ophthalmologist,
+        "Dr. Tonometry",
+    );
+    let patient = Address::generate(&env);
+    record_iop(&env, &client, &provider, &patient, 1_000, 180);
+    record_iop(&env, &client, &provider, &patient, 2_000, 215);
+    record_iop(&env, &client, &provider, &patient, 3_000, 240);
+    client.record_measurement(
+        &patient,
+        &provider,
+        &MeasureType::IOP,
+        &Eye::Left,
+        &170,
+        &MeasureUnit::MmHg,
+    );
+
+    let series = client.get_measurement_series(
+        &patient,
+        &patient,
+        &MeasureType::IOP,
+        &Eye::Right,
+        &1_500,
+        &3_000,
+    );
+    assert_eq!(series.len(), 2);
+    assert_eq!(series.get(0).unwrap().value, 215);
+    assert_eq!(series.get(1).unwrap().measured_at, 3_000);
+
+    let left = client.get_measurement_series(
+        &patient,
+        &patient,
+        &MeasureType::IOP,
+        &Eye::Left,
+        &0,
+        &u64::MAX,
+    );
+    assert_eq!(left.len(), 1);
+}
+
+#[test]
+fn test_series_spans_buckets_and_caps_length() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(
+        &env,
+        &client,
+        &admin,
+        Role::Ophthalmologist,
+        "Dr. Tonometry",
+    );
+    let patient = Address::generate(&env);
+    for i in 0..(MAX_SERIES_LEN as u64 + 20) {
+        record_iop(&env, &client, &provider, &patient, 1_000 + i, 150);
+    }
+
+    let all = client.get_measurement_series(
+        &patient,
+        &patient,
+        &MeasureType::IOP,
+        &Eye::Right,
+        &0,
+        &u64::MAX,
+    );
+    assert_eq!(all.len(), MAX_SERIES_LEN);
+
+    let tail = client.get_measurement_series(
+        &patient,
+        &patient,
+        &MeasureType::IOP,
+        &Eye::Right,
+        &(1_000 + MAX_SERIES_LEN as u64),
+        &u64::MAX,
+    );
+    assert_eq!(tail.len(), 20);
+}
+
+#[test]
+fn test_decimal_acuity_is_stored_as_logmar() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(
+        &env,
+        &client,
+        &admin,
+        Role::Ophthalmologist,
+        "Dr. Tonometry",
+    );
+    let patient = Address::generate(&env);
+    // 0.5 decimal acuity is 20/40, logMAR 0.301
+    let reading = client.record_measurement(
+        &patient,
+        &provider,
+        &MeasureType::VisualAcuity,
+        &Eye::Left,
+        &500,
+        &MeasureUnit::DecimalAcuity,
+    );
+    assert_eq!(reading.unit, MeasureUnit::LogMar);
+    assert_eq!(reading.value, 301);
+}
+
+#[test]
+fn test_measurement_validation_and_access() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(
+        &env,
+        &client,
+        &admin,
+        Role::Ophthalmologist,
+        "Dr. Tonometry",
+    );
+    let patient = Address::generate(&env);
+
+    let res = client.try_record_measurement(
+        &patient,
+        &provider,
+        &MeasureType::IOP,
+        &Eye::Right,
+        &150,
+        &MeasureUnit::Diopter,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+    let res = client.try_record_measurement(
+        &patient,
+        &provider,
+        &MeasureType::Refraction,
+        &Eye::Right,
+        &-4_000,
+        &MeasureUnit::Diopter,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let res = client.try_record_measurement(
+        &patient,
+        &patient,
+        &MeasureType::IOP,
+        &Eye::Right,
+        &150,
+        &MeasureUnit::MmHg,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let member = Address::generate(&env);
+    let res = client.try_get_measurement_series(
+        &member,
+        &patient,
+        &MeasureType::IOP,
+        &Eye::Right,
+        &0,
+        &10,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AccessDenied);
+
+    client.grant_consent(&patient, &member, &ConsentType::Treatment, &86400);
+    client.grant_access(&patient, &patient, &member, &AccessLevel::Read, &86400);
+    client.get_measurement_series(&member, &patient, &MeasureType::IOP, &Eye::Right, &0, &10);
+
+    let res = client.try_get_measurement_series(
+        &member,
+        &patient,
+        &MeasureType::IOP,
+        &Eye::Right,
+        &10,
+        &0,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidTimestamp);
+}
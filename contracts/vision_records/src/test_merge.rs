@@ -0,0 +1,245 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, register_verified_provider, setup_test};
+use super::{
+    emergency, AccessLevel, AccessScope, ConsentType, ContractError, EmergencyCondition,
+    LegalHoldTarget, LensType, OptionalContactLensData, PrescriptionData, RecordType, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String, Vec};
+
+const DAY: u64 = 86_400;
+
+fn add_rx(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    patient: &Address,
+) -> u64 {
+    let eye = PrescriptionData {
+        sphere: String::from_str(env, "-1.00"),
+        cylinder: String::from_str(env, "0.00"),
+        axis: String::from_str(env, "0"),
+        add: String::from_str(env, "0.00"),
+        pd: String::from_str(env, "62"),
+        prism: String::from_str(env, ""),
+        prism_base: String::from_str(env, ""),
+    };
+    client.add_prescription(
+        patient,
+        provider,
+        &LensType::Glasses,
+        &eye,
+        &eye,
+        &OptionalContactLensData::None,
+        &(365 * DAY),
+        &String::from_str(env, "metadata_hash"),
+    )
+}
+
+#[test]
+fn test_merge_moves_records_prescriptions_and_grants() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Merge");
+    let primary = Address::generate(&env);
+    let duplicate = Address::generate(&env);
+    let reader = Address::generate(&env);
+
+    add_record(&env, &client, &provider, &primary, RecordType::Examination);
+    let dup_record = add_record(
+        &env,
+        &client,
+        &provider,
+        &duplicate,
+        RecordType::Examination,
+    );
+    let dup_rx = add_rx(&env, &client, &provider, &duplicate);
+    client.grant_consent(&duplicate, &reader, &ConsentType::Treatment, &(30 * DAY));
+    client.grant_access(
+        &duplicate,
+        &duplicate,
+        &reader,
+        &AccessLevel::Read,
+        &(30 * DAY),
+    );
+
+    let merged = client.merge_patients(&admin, &primary, &duplicate);
+    assert_eq!(merged.records_moved, 1);
+    assert_eq!(merged.prescriptions_moved, 1);
+    assert_eq!(merged.grants_moved, 1);
+
+    assert_eq!(client.get_record(&primary, &dup_record).patient, primary);
+    assert_eq!(client.get_prescription(&dup_rx).patient, primary);
+    assert_eq!(client.get_prescription_history(&primary).len(), 1);
+    assert_eq!(
+        client.check_access(&primary, &reader, &None, &None),
+        AccessLevel::Read
+    );
+
+    let tombstone = client.get_patient_merge(&duplicate).unwrap();
+    assert_eq!(tombstone.primary, primary);
+    assert_eq!(tombstone.merged_by, admin);
+}
+
+#[test]
+fn test_merge_moves_consents_scoped_and_org_grants() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Merge");
+    let primary = Address::generate(&env);
+    let duplicate = Address::generate(&env);
+    add_record(
+        &env,
+        &client,
+        &provider,
+        &duplicate,
+        RecordType::Prescription,
+    );
+
+    // Consent on its own, with no grant yet
+    let consulted = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Consult");
+    client.grant_consent(&duplicate, &consulted, &ConsentType::Treatment, &(30 * DAY));
+
+    let scoped = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Scoped");
+    client.grant_consent(&duplicate, &scoped, &ConsentType::Treatment, &(30 * DAY));
+    client.grant_scoped_access(
+        &duplicate,
+        &scoped,
+        &AccessScope::RecordType(RecordType::Prescription),
+        &AccessLevel::Read,
+        &(30 * DAY),
+    );
+
+    let clinic_admin = Address::generate(&env);
+    let org_id = client.register_organization(
+        &admin,
+        &String::from_str(&env, "Merge Eye Clinic"),
+        &clinic_admin,
+    );
+    client.add_provider_to_org(&clinic_admin, &org_id, &provider);
+    client.grant_org_access(&duplicate, &org_id, &AccessLevel::Read, &(30 * DAY));
+
+    let merged = client.merge_patients(&admin, &primary, &duplicate);
+    assert_eq!(merged.grants_moved, 4);
+    assert_eq!(client.get_org_access_grants(&primary).len(), 1);
+    assert_eq!(client.get_org_access_grants(&duplicate).len(), 0);
+    assert_eq!(
+        client.check_access(&primary, &scoped, &None, &Some(RecordType::Prescription)),
+        AccessLevel::Read
+    );
+    assert_eq!(
+        client.check_access(&primary, &provider, &None, &None),
+        AccessLevel::Read
+    );
+
+    // A lockdown on the primary reaches everything carried over
+    let summary = client.revoke_all_access(&primary);
+    assert_eq!(summary.scoped_grants_revoked, 1);
+    assert_eq!(summary.org_grants_revoked, 1);
+    assert_eq!(summary.consents_revoked, 2);
+    for grantee in [&consulted, &scoped, &provider] {
+        assert_eq!(
+            client.check_access(&primary, grantee, &None, &Some(RecordType::Prescription)),
+            AccessLevel::None
+        );
+    }
+}
+
+#[test]
+fn test_merge_requires_admin_or_patient() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Merge");
+    let primary = Address::generate(&env);
+    let duplicate = Address::generate(&env);
+
+    let res = client.try_merge_patients(&provider, &primary, &duplicate);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    let stranger = Address::generate(&env);
+    let res = client.try_merge_patients(&stranger, &primary, &duplicate);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let merged = client.merge_patients(&primary, &primary, &duplicate);
+    assert_eq!(merged.merged_by, primary);
+}
+
+#[test]
+fn test_merge_requires_both_patients_auth() {
+    let (env, client, admin) = setup_test();
+    let primary = Address::generate(&env);
+    let duplicate = Address::generate(&env);
+
+    client.merge_patients(&admin, &primary, &duplicate);
+    let auths = env.auths();
+    assert!(auths.iter().any(|(addr, _)| *addr == primary));
+    assert!(auths.iter().any(|(addr, _)| *addr == duplicate));
+}
+
+#[test]
+fn test_merge_moves_emergency_history() {
+    let (env, client, admin) = setup_test();
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Merge");
+    let primary = Address::generate(&env);
+    let duplicate = Address::generate(&env);
+
+    let access_id = client.grant_emergency_access(
+        &responder,
+        &duplicate,
+        &EmergencyCondition::Unconscious,
+        &String::from_str(&env, "Patient unconscious"),
+        &3600,
+        &Vec::new(&env),
+    );
+    assert_eq!(client.get_patient_emergency_accesses(&duplicate).len(), 1);
+    // Older grants than any recent-id window still move.
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&emergency::EMRG_CTR, &(access_id + 500));
+    });
+
+    let merged = client.merge_patients(&admin, &primary, &duplicate);
+    assert_eq!(merged.emergency_moved, 1);
+    let accesses = client.get_patient_emergency_accesses(&primary);
+    assert_eq!(accesses.len(), 1);
+    assert_eq!(accesses.get(0).unwrap().id, access_id);
+    assert!(client.get_patient_emergency_accesses(&duplicate).is_empty());
+}
+
+#[test]
+fn test_merge_rejects_repeat_and_self() {
+    let (env, client, admin) = setup_test();
+    let primary = Address::generate(&env);
+    let duplicate = Address::generate(&env);
+
+    let res = client.try_merge_patients(&admin, &primary, &primary);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    client.merge_patients(&admin, &primary, &duplicate);
+    let res = client.try_merge_patients(&admin, &primary, &duplicate);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    // A tombstoned address cannot absorb another patient.
+    let other = Address::generate(&env);
+    let res = client.try_merge_patients(&admin, &duplicate, &other);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
+
+#[test]
+fn test_merge_blocked_by_legal_hold() {
+    let (env, client, admin) = setup_test();
+    let primary = Address::generate(&env);
+    let duplicate = Address::generate(&env);
+
+    client.place_legal_hold(
+        &admin,
+        &LegalHoldTarget::Patient(duplicate.clone()),
+        &BytesN::from_array(&env, &[3u8; 32]),
+    );
+
+    let res = client.try_merge_patients(&admin, &primary, &duplicate);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::LegalHoldActive);
+}
@@ -1,5 +1,14 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
 use ed25519_dalek::{Signer, SigningKey};
-use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+use soroban_sdk::{
+    testutils::Address as _, testutils::Ledger as _, xdr::ToXdr, Address, BytesN, Env,
+};
 
 use crate::*;
 
@@ -22,20 +31,26 @@ fn create_keypair(secret: &[u8; 32]) -> (SigningKey, [u8; 32]) {
     (signing_key, pubkey)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_message_bytes(
+    env: &Env,
     patient_pubkey: &[u8; 32],
     grantee_id: &[u8; 32],
     level: u32,
     expires_at: u64,
     nonce: u64,
-) -> Vec<u8> {
-    let mut msg = Vec::new();
+    patient: &Address,
+    grantee: &Address,
+) -> StdVec<u8> {
+    let mut msg = StdVec::new();
     msg.extend_from_slice(b"grant_access");
     msg.extend_from_slice(patient_pubkey);
     msg.extend_from_slice(grantee_id);
     msg.extend_from_slice(&level.to_be_bytes());
     msg.extend_from_slice(&expires_at.to_be_bytes());
     msg.extend_from_slice(&nonce.to_be_bytes());
+    msg.extend(patient.clone().to_xdr(env).iter());
+    msg.extend(grantee.clone().to_xdr(env).iter());
     msg
 }
 
@@ -49,6 +64,8 @@ fn test_valid_meta_grant() {
     let patient = Address::generate(&env);
     let grantee = Address::generate(&env);
     let relayer = Address::generate(&env);
+    client.register_meta_signer(&patient, &BytesN::from_array(&env, &patient_pub));
+    client.grant_consent(&patient, &grantee, &ConsentType::Treatment, &10_000_000);
 
     let level: u32 = 1; // Read
     let expires_at: u64 = 1_000_000;
@@ -58,7 +75,16 @@ fn test_valid_meta_grant() {
         li.timestamp = 500_000;
     });
 
-    let msg = build_message_bytes(&patient_pub, &grantee_pub, level, expires_at, nonce);
+    let msg = build_message_bytes(
+        &env,
+        &patient_pub,
+        &grantee_pub,
+        level,
+        expires_at,
+        nonce,
+        &patient,
+        &grantee,
+    );
     let sig = signing_key.sign(&msg);
 
     let signed_grant = SignedGrant {
@@ -74,8 +100,10 @@ fn test_valid_meta_grant() {
 
     client.grant_access_meta(&relayer, &signed_grant);
 
-    let access = client.check_access(&patient, &grantee);
+    let access = client.check_access(&patient, &grantee, &None, &None);
     assert_eq!(access, AccessLevel::Read);
+    assert!(client.get_consumed_nonce(&patient, &nonce).is_some());
+    assert!(client.get_consumed_nonce(&patient, &(nonce + 1)).is_none());
 }
 
 #[test]
@@ -89,6 +117,7 @@ fn test_invalid_signature_rejected() {
     let patient = Address::generate(&env);
     let grantee = Address::generate(&env);
     let relayer = Address::generate(&env);
+    client.register_meta_signer(&patient, &BytesN::from_array(&env, &patient_pub));
 
     let level: u32 = 1;
     let expires_at: u64 = 1_000_000;
@@ -100,7 +129,16 @@ fn test_invalid_signature_rejected() {
 
     // Sign with a different key to produce an invalid signature
     let (wrong_key, _) = create_keypair(&[99u8; 32]);
-    let msg = build_message_bytes(&patient_pub, &grantee_pub, level, expires_at, nonce);
+    let msg = build_message_bytes(
+        &env,
+        &patient_pub,
+        &grantee_pub,
+        level,
+        expires_at,
+        nonce,
+        &patient,
+        &grantee,
+    );
     let bad_sig = wrong_key.sign(&msg);
 
     let signed_grant = SignedGrant {
@@ -128,6 +166,7 @@ fn test_expired_meta_tx_rejected() {
     let patient = Address::generate(&env);
     let grantee = Address::generate(&env);
     let relayer = Address::generate(&env);
+    client.register_meta_signer(&patient, &BytesN::from_array(&env, &patient_pub));
 
     let level: u32 = 2; // Write
     let expires_at: u64 = 100;
@@ -138,7 +177,16 @@ fn test_expired_meta_tx_rejected() {
         li.timestamp = 500;
     });
 
-    let msg = build_message_bytes(&patient_pub, &grantee_pub, level, expires_at, nonce);
+    let msg = build_message_bytes(
+        &env,
+        &patient_pub,
+        &grantee_pub,
+        level,
+        expires_at,
+        nonce,
+        &patient,
+        &grantee,
+    );
     let sig = signing_key.sign(&msg);
 
     let signed_grant = SignedGrant {
@@ -167,6 +215,7 @@ fn test_replayed_nonce_rejected() {
     let patient = Address::generate(&env);
     let grantee = Address::generate(&env);
     let relayer = Address::generate(&env);
+    client.register_meta_signer(&patient, &BytesN::from_array(&env, &patient_pub));
 
     let level: u32 = 1;
     let expires_at: u64 = 1_000_000;
@@ -176,7 +225,16 @@ fn test_replayed_nonce_rejected() {
         li.timestamp = 500_000;
     });
 
-    let msg = build_message_bytes(&patient_pub, &grantee_pub, level, expires_at, nonce);
+    let msg = build_message_bytes(
+        &env,
+        &patient_pub,
+        &grantee_pub,
+        level,
+        expires_at,
+        nonce,
+        &patient,
+        &grantee,
+    );
     let sig = signing_key.sign(&msg);
 
     let signed_grant = SignedGrant {
@@ -207,8 +265,10 @@ fn test_meta_grant_full_access() {
     let patient = Address::generate(&env);
     let grantee = Address::generate(&env);
     let relayer = Address::generate(&env);
+    client.register_meta_signer(&patient, &BytesN::from_array(&env, &patient_pub));
+    client.grant_consent(&patient, &grantee, &ConsentType::Treatment, &10_000_000);
 
-    let level: u32 = 3; // Full
+    let level: u32 = 3; // Admin
     let expires_at: u64 = 2_000_000;
     let nonce: u64 = 1;
 
@@ -216,7 +276,16 @@ fn test_meta_grant_full_access() {
         li.timestamp = 100_000;
     });
 
-    let msg = build_message_bytes(&patient_pub, &grantee_pub, level, expires_at, nonce);
+    let msg = build_message_bytes(
+        &env,
+        &patient_pub,
+        &grantee_pub,
+        level,
+        expires_at,
+        nonce,
+        &patient,
+        &grantee,
+    );
     let sig = signing_key.sign(&msg);
 
     let signed_grant = SignedGrant {
@@ -232,8 +301,8 @@ fn test_meta_grant_full_access() {
 
     client.grant_access_meta(&relayer, &signed_grant);
 
-    let access = client.check_access(&patient, &grantee);
-    assert_eq!(access, AccessLevel::Full);
+    let access = client.check_access(&patient, &grantee, &None, &None);
+    assert_eq!(access, AccessLevel::Admin);
 }
 
 #[test]
@@ -246,13 +315,24 @@ fn test_meta_grant_different_nonces() {
     let patient = Address::generate(&env);
     let grantee = Address::generate(&env);
     let relayer = Address::generate(&env);
+    client.register_meta_signer(&patient, &BytesN::from_array(&env, &patient_pub));
+    client.grant_consent(&patient, &grantee, &ConsentType::Treatment, &10_000_000);
 
     env.ledger().with_mut(|li| {
         li.timestamp = 100_000;
     });
 
     // First grant with nonce 1
-    let msg1 = build_message_bytes(&patient_pub, &grantee_pub, 1, 2_000_000, 1);
+    let msg1 = build_message_bytes(
+        &env,
+        &patient_pub,
+        &grantee_pub,
+        1,
+        2_000_000,
+        1,
+        &patient,
+        &grantee,
+    );
     let sig1 = signing_key.sign(&msg1);
 
     let grant1 = SignedGrant {
@@ -268,7 +348,16 @@ fn test_meta_grant_different_nonces() {
     client.grant_access_meta(&relayer, &grant1);
 
     // Second grant with nonce 2 (upgrades to Write)
-    let msg2 = build_message_bytes(&patient_pub, &grantee_pub, 2, 2_000_000, 2);
+    let msg2 = build_message_bytes(
+        &env,
+        &patient_pub,
+        &grantee_pub,
+        2,
+        2_000_000,
+        2,
+        &patient,
+        &grantee,
+    );
     let sig2 = signing_key.sign(&msg2);
 
     let grant2 = SignedGrant {
@@ -283,6 +372,141 @@ fn test_meta_grant_different_nonces() {
     };
     client.grant_access_meta(&relayer, &grant2);
 
-    let access = client.check_access(&patient, &grantee);
+    let access = client.check_access(&patient, &grantee, &None, &None);
     assert_eq!(access, AccessLevel::Write);
 }
+
+#[test]
+fn test_relayer_supplied_key_rejected() {
+    let (env, client, _admin) = setup_env();
+
+    let (_signing_key, patient_pub) = create_keypair(&[1u8; 32]);
+    let (forged_key, forged_pub) = create_keypair(&[66u8; 32]);
+    let (_grantee_sk, grantee_pub) = create_keypair(&[2u8; 32]);
+
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    client.register_meta_signer(&patient, &BytesN::from_array(&env, &patient_pub));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 500_000;
+    });
+
+    // The relayer signs with its own key and claims it is the patient's.
+    let msg = build_message_bytes(
+        &env,
+        &forged_pub,
+        &grantee_pub,
+        3,
+        1_000_000,
+        1,
+        &patient,
+        &grantee,
+    );
+    let sig = forged_key.sign(&msg);
+
+    let signed_grant = SignedGrant {
+        patient: patient.clone(),
+        patient_pubkey: BytesN::from_array(&env, &forged_pub),
+        grantee: grantee.clone(),
+        grantee_id: BytesN::from_array(&env, &grantee_pub),
+        level: 3,
+        expires_at: 1_000_000,
+        nonce: 1,
+        signature: BytesN::from_array(&env, &sig.to_bytes()),
+    };
+
+    let result = client.try_grant_access_meta(&relayer, &signed_grant);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::None
+    );
+}
+
+#[test]
+fn test_meta_grant_requires_registered_signer() {
+    let (env, client, _admin) = setup_env();
+
+    let (signing_key, patient_pub) = create_keypair(&[1u8; 32]);
+    let (_grantee_sk, grantee_pub) = create_keypair(&[2u8; 32]);
+
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+    let relayer = Address::generate(&env);
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 500_000;
+    });
+
+    let msg = build_message_bytes(
+        &env,
+        &patient_pub,
+        &grantee_pub,
+        1,
+        1_000_000,
+        1,
+        &patient,
+        &grantee,
+    );
+    let sig = signing_key.sign(&msg);
+
+    let signed_grant = SignedGrant {
+        patient: patient.clone(),
+        patient_pubkey: BytesN::from_array(&env, &patient_pub),
+        grantee: grantee.clone(),
+        grantee_id: BytesN::from_array(&env, &grantee_pub),
+        level: 1,
+        expires_at: 1_000_000,
+        nonce: 1,
+        signature: BytesN::from_array(&env, &sig.to_bytes()),
+    };
+
+    let result = client.try_grant_access_meta(&relayer, &signed_grant);
+    assert_eq!(result, Err(Ok(ContractError::Unauthorized)));
+}
+
+#[test]
+#[should_panic(expected = "HostError")]
+fn test_meta_grant_redirected_grantee_rejected() {
+    let (env, client, _admin) = setup_env();
+
+    let (signing_key, patient_pub) = create_keypair(&[1u8; 32]);
+    let (_grantee_sk, grantee_pub) = create_keypair(&[2u8; 32]);
+
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+    let relayer = Address::generate(&env);
+    client.register_meta_signer(&patient, &BytesN::from_array(&env, &patient_pub));
+
+    env.ledger().with_mut(|li| {
+        li.timestamp = 500_000;
+    });
+
+    let msg = build_message_bytes(
+        &env,
+        &patient_pub,
+        &grantee_pub,
+        1,
+        1_000_000,
+        1,
+        &patient,
+        &grantee,
+    );
+    let sig = signing_key.sign(&msg);
+
+    // Same signature, but the relayer swaps in a different grantee address.
+    let signed_grant = SignedGrant {
+        patient: patient.clone(),
+        patient_pubkey: BytesN::from_array(&env, &patient_pub),
+        grantee: relayer.clone(),
+        grantee_id: BytesN::from_array(&env, &grantee_pub),
+        level: 1,
+        expires_at: 1_000_000,
+        nonce: 1,
+        signature: BytesN::from_array(&env, &sig.to_bytes()),
+    };
+
+    client.grant_access_meta(&relayer, &signed_grant);
+}
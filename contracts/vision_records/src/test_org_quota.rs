@@ -0,0 +1,122 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{advance, register_user, setup_test, DATA_HASH};
+use super::{BatchRecordInput, ContractError, RecordType, Role, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+const DAY: u64 = 86_400;
+const ORG: u64 = 3;
+
+/// A provider in [`ORG`], which may write two records per 30 days.
+fn metered_provider(env: &Env, client: &VisionRecordsContractClient, admin: &Address) -> Address {
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Busy");
+    client.set_provider_org(admin, &provider, &ORG);
+    client.set_org_quota(admin, &ORG, &2, &(30 * DAY));
+    provider
+}
+
+fn try_add(
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    patient: &Address,
+) -> Result<u64, ContractError> {
+    client
+        .try_add_record(
+            provider,
+            patient,
+            provider,
+            &RecordType::Examination,
+            &String::from_str(&client.env, DATA_HASH),
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_quota_blocks_writes_past_limit() {
+    let (env, client, admin) = setup_test();
+    let provider = metered_provider(&env, &client, &admin);
+    let patient = Address::generate(&env);
+    try_add(&client, &provider, &patient).unwrap();
+    try_add(&client, &provider, &patient).unwrap();
+    assert_eq!(
+        try_add(&client, &provider, &patient),
+        Err(ContractError::QuotaExceeded)
+    );
+
+    let usage = client.get_org_quota_usage(&provider, &ORG);
+    assert_eq!(usage.records_written, 2);
+}
+
+#[test]
+fn test_quota_resets_each_period() {
+    let (env, client, admin) = setup_test();
+    let provider = metered_provider(&env, &client, &admin);
+    let patient = Address::generate(&env);
+    try_add(&client, &provider, &patient).unwrap();
+    try_add(&client, &provider, &patient).unwrap();
+
+    advance(&env, 30 * DAY);
+    assert_eq!(client.get_org_quota_usage(&admin, &ORG).records_written, 0);
+    try_add(&client, &provider, &patient).unwrap();
+}
+
+#[test]
+fn test_batch_counts_every_record() {
+    let (env, client, admin) = setup_test();
+    let provider = metered_provider(&env, &client, &admin);
+    let patient = Address::generate(&env);
+    let input = BatchRecordInput {
+        patient: patient.clone(),
+        record_type: RecordType::Examination,
+        data_hash: String::from_str(&env, DATA_HASH),
+    };
+    let res = client.try_add_records(&provider, &vec![&env, input.clone(), input.clone(), input]);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::QuotaExceeded);
+    assert_eq!(client.get_org_quota_usage(&admin, &ORG).records_written, 0);
+}
+
+#[test]
+fn test_purchased_records_extend_quota() {
+    let (env, client, admin) = setup_test();
+    let provider = metered_provider(&env, &client, &admin);
+    let patient = Address::generate(&env);
+    let billing = Address::generate(&env);
+    client.set_billing_contract(&admin, &billing);
+
+    try_add(&client, &provider, &patient).unwrap();
+    try_add(&client, &provider, &patient).unwrap();
+    let usage = client.purchase_org_quota(&billing, &ORG, &1);
+    assert_eq!(usage.purchased_remaining, 1);
+
+    try_add(&client, &provider, &patient).unwrap();
+    assert_eq!(
+        try_add(&client, &provider, &patient),
+        Err(ContractError::QuotaExceeded)
+    );
+    assert_eq!(
+        client.get_org_quota_usage(&admin, &ORG).purchased_remaining,
+        0
+    );
+}
+
+#[test]
+fn test_quota_access_control() {
+    let (env, client, admin) = setup_test();
+    let provider = metered_provider(&env, &client, &admin);
+    let stranger = Address::generate(&env);
+
+    let res = client.try_purchase_org_quota(&stranger, &ORG, &10);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_set_org_quota(&provider, &ORG, &100, &DAY);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_get_org_quota_usage(&stranger, &ORG);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
@@ -0,0 +1,139 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, setup_test};
+use super::{AccessLevel, AccessReasonCode, ContractError, Role, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, vec, Address, Env, String};
+
+const DAY: u64 = 86_400;
+
+/// An organization registered at timestamp 1_000, with its own admin.
+fn clinic(env: &Env, client: &VisionRecordsContractClient, admin: &Address) -> (Address, u64) {
+    env.ledger().set_timestamp(1_000);
+    let clinic_admin = Address::generate(env);
+    let org_id = client.register_organization(
+        admin,
+        &String::from_str(env, "Eastside Eye Clinic"),
+        &clinic_admin,
+    );
+    (clinic_admin, org_id)
+}
+
+#[test]
+fn test_register_and_manage_members() {
+    let (env, client, admin) = setup_test();
+    let (clinic_admin, org_id) = clinic(&env, &client, &admin);
+    let org = client.get_organization(&org_id);
+    assert_eq!(org.admin, clinic_admin);
+
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Clinic");
+    client.add_provider_to_org(&clinic_admin, &org_id, &provider);
+    assert_eq!(
+        client.get_org_providers(&org_id),
+        vec![&env, provider.clone()]
+    );
+    assert_eq!(client.get_provider_org(&provider), Some(org_id));
+
+    client.remove_provider_from_org(&clinic_admin, &org_id, &provider);
+    assert_eq!(client.get_org_providers(&org_id).len(), 0);
+    assert_eq!(client.get_provider_org(&provider), None);
+
+    let res = client.try_remove_provider_from_org(&clinic_admin, &org_id, &provider);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::UserNotFound);
+}
+
+#[test]
+fn test_membership_needs_org_admin_and_provider() {
+    let (env, client, admin) = setup_test();
+    let (clinic_admin, org_id) = clinic(&env, &client, &admin);
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Clinic");
+    let outsider = Address::generate(&env);
+
+    let res = client.try_add_provider_to_org(&outsider, &org_id, &provider);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    let res = client.try_add_provider_to_org(&clinic_admin, &org_id, &outsider);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+    let res = client.try_add_provider_to_org(&admin, &(org_id + 1), &provider);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::OrganizationNotFound
+    );
+}
+
+#[test]
+fn test_org_grant_covers_current_members() {
+    let (env, client, admin) = setup_test();
+    let (clinic_admin, org_id) = clinic(&env, &client, &admin);
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Clinic");
+    client.add_provider_to_org(&clinic_admin, &org_id, &provider);
+
+    let patient = Address::generate(&env);
+    client.grant_org_access(&patient, &org_id, &AccessLevel::Write, &DAY);
+    assert_eq!(client.get_org_access_grants(&patient).len(), 1);
+    assert_eq!(
+        client.check_access(&patient, &provider, &None, &None),
+        AccessLevel::Write
+    );
+
+    // Leaving the clinic ends the access
+    client.remove_provider_from_org(&clinic_admin, &org_id, &provider);
+    assert_eq!(
+        client.check_access(&patient, &provider, &None, &None),
+        AccessLevel::None
+    );
+}
+
+#[test]
+fn test_org_grant_expires_and_revokes() {
+    let (env, client, admin) = setup_test();
+    let (clinic_admin, org_id) = clinic(&env, &client, &admin);
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Clinic");
+    client.add_provider_to_org(&clinic_admin, &org_id, &provider);
+    let patient = Address::generate(&env);
+    client.grant_org_access(&patient, &org_id, &AccessLevel::Read, &DAY);
+
+    env.ledger().set_timestamp(1_000 + DAY);
+    assert_eq!(
+        client.check_access(&patient, &provider, &None, &None),
+        AccessLevel::None
+    );
+    assert_eq!(client.get_org_access_grants(&patient).len(), 0);
+
+    client.revoke_org_access(&patient, &org_id);
+    let res = client.try_revoke_org_access(&patient, &org_id);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::AccessGrantNotFound
+    );
+}
+
+#[test]
+fn test_org_grant_does_not_cover_deactivated_members() {
+    let (env, client, admin) = setup_test();
+    let (clinic_admin, org_id) = clinic(&env, &client, &admin);
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Clinic");
+    client.add_provider_to_org(&clinic_admin, &org_id, &provider);
+
+    let patient = Address::generate(&env);
+    client.grant_org_access(&patient, &org_id, &AccessLevel::Write, &DAY);
+    assert_eq!(
+        client.check_access(&patient, &provider, &None, &None),
+        AccessLevel::Write
+    );
+
+    client.deactivate_user(&admin, &provider);
+    assert_eq!(
+        client.check_access(&patient, &provider, &None, &None),
+        AccessLevel::None
+    );
+    assert_eq!(
+        client
+            .check_access_detailed(&patient, &provider)
+            .reason_code,
+        AccessReasonCode::UserInactive
+    );
+}
@@ -0,0 +1,93 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{ContractError, RecordType, Role};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env};
+
+fn pubkey(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
+
+#[test]
+fn test_records_tagged_with_current_key() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Cipher");
+    let patient = Address::generate(&env);
+    let untagged = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    assert_eq!(client.get_record_encryption_key(&untagged), None);
+
+    client.register_encryption_key(&patient, &1, &pubkey(&env, 1));
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let info = client.get_record_encryption_key(&record_id).unwrap();
+    assert_eq!(info.key_id, 1);
+    assert_eq!(info.public_key, pubkey(&env, 1));
+    assert!(!info.reencryption_pending);
+}
+
+#[test]
+fn test_rotation_flags_records_until_reencrypted() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Cipher");
+    let patient = Address::generate(&env);
+    client.register_encryption_key(&patient, &1, &pubkey(&env, 1));
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+
+    client.register_encryption_key(&patient, &2, &pubkey(&env, 2));
+    assert_eq!(
+        client.get_current_encryption_key(&patient).unwrap().key_id,
+        1
+    );
+    client.rotate_key(&patient, &2);
+
+    let info = client.get_record_encryption_key(&record_id).unwrap();
+    assert_eq!(info.key_id, 1);
+    assert!(info.reencryption_pending);
+    assert!(
+        !client
+            .get_record_encryption_key(&add_record(
+                &env,
+                &client,
+                &provider,
+                &patient,
+                RecordType::Examination
+            ))
+            .unwrap()
+            .reencryption_pending
+    );
+
+    let info = client.mark_record_reencrypted(&provider, &record_id);
+    assert_eq!(info.key_id, 2);
+    assert!(!info.reencryption_pending);
+}
+
+#[test]
+fn test_key_registry_validation() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Cipher");
+    let patient = Address::generate(&env);
+    client.register_encryption_key(&patient, &1, &pubkey(&env, 1));
+
+    let res = client.try_register_encryption_key(&patient, &1, &pubkey(&env, 9));
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::EncryptionKeyExists
+    );
+
+    let res = client.try_rotate_key(&patient, &7);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::EncryptionKeyNotFound
+    );
+    let res = client.try_rotate_key(&patient, &1);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let stranger = Address::generate(&env);
+    let res = client.try_mark_record_reencrypted(&stranger, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
@@ -2,7 +2,7 @@ use crate::{
     circuit_breaker::PauseScope, rbac::Role, ContractError, RecordType, VisionRecordsContract,
     VisionRecordsContractClient,
 };
-use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String};
+use soroban_sdk::{symbol_short, testutils::Address as _, Address, Env, String, Vec};
 
 fn setup_test() -> (Env, VisionRecordsContractClient<'static>, Address) {
     let env = Env::default();
@@ -108,3 +108,121 @@ fn test_unauthorized_pause() {
     let res = client.try_pause_contract(&staff, &PauseScope::Global);
     assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
 }
+
+#[test]
+fn test_pause_and_unpause() {
+    let (env, client, admin) = setup_test();
+    let patient = Address::generate(&env);
+    assert!(!client.is_paused());
+
+    client.pause(&admin);
+    assert!(client.is_paused());
+
+    let res = client.try_set_notification_preferences(&patient, &true, &3600);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Paused);
+    let res = client.try_set_emergency_contacts(&patient, &Vec::new(&env), &false);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Paused);
+
+    // Only admins can lift the pause
+    let outsider = Address::generate(&env);
+    let res = client.try_unpause(&outsider);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.unpause(&admin);
+    assert!(!client.is_paused());
+    client.set_notification_preferences(&patient, &true, &3600);
+}
+
+#[test]
+fn test_pause_blocks_settings_and_admin_mutations() {
+    let (env, client, admin) = setup_test();
+    let patient = Address::generate(&env);
+    let other = Address::generate(&env);
+    client.pause(&admin);
+
+    let res = client.try_set_locum_opt_out(&patient, &true);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Paused);
+    let res = client.try_register_address_alias(&admin, &patient, &other);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Paused);
+    let res = client.try_revoke_cohort_resident(&admin, &patient, &other);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Paused);
+    let res = client.try_set_provider_org(&admin, &other, &1);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Paused);
+    let res = client.try_set_record_residency(&admin, &1, &symbol_short!("EU"));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Paused);
+    let res = client.try_purchase_org_quota(&admin, &1, &10);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Paused);
+}
+
+#[test]
+fn test_degraded_mode_keeps_only_emergency_path() {
+    let (env, client, admin) = setup_test();
+    let contract_id = client.address.clone();
+
+    let doctor = Address::generate(&env);
+    client.register_user(
+        &admin,
+        &doctor,
+        &Role::Ophthalmologist,
+        &String::from_str(&env, "Doctor"),
+    );
+    env.as_contract(&contract_id, || {
+        crate::provider::set_provider(
+            &env,
+            &crate::provider::Provider {
+                address: doctor.clone(),
+                name: String::from_str(&env, "Doctor"),
+                licenses: soroban_sdk::Vec::new(&env),
+                specialties: soroban_sdk::Vec::new(&env),
+                certifications: soroban_sdk::Vec::new(&env),
+                locations: soroban_sdk::Vec::new(&env),
+                verification_status: crate::VerificationStatus::Verified,
+                registered_at: 0,
+                verified_at: Some(0),
+                verified_by: Some(admin.clone()),
+                is_active: true,
+            },
+        );
+    });
+    let patient = Address::generate(&env);
+    let hash = String::from_str(&env, "QmDegradedModeRecordHash00000000000000000000");
+    let record_id = client.add_record(&doctor, &patient, &doctor, &RecordType::Examination, &hash);
+
+    client.set_degraded_mode(&admin, &true);
+    assert!(client.is_degraded_mode());
+
+    // Bulk writes are shed
+    let res = client.try_add_record(&doctor, &patient, &doctor, &RecordType::Examination, &hash);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Paused);
+    let res = client.try_register_user(
+        &admin,
+        &Address::generate(&env),
+        &Role::Patient,
+        &String::from_str(&env, "Test"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Paused);
+
+    // The clinically critical path stays open
+    client.grant_emergency_access(
+        &doctor,
+        &patient,
+        &crate::EmergencyCondition::Unconscious,
+        &String::from_str(&env, "Patient unconscious"),
+        &3600,
+        &soroban_sdk::Vec::new(&env),
+    );
+    client.access_record_via_emergency(&doctor, &patient, &Some(record_id));
+    client.get_record(&doctor, &record_id);
+    client.check_access(&patient, &doctor, &None, &None);
+
+    client.set_degraded_mode(&admin, &false);
+    client.add_record(&doctor, &patient, &doctor, &RecordType::Examination, &hash);
+}
+
+#[test]
+fn test_unauthorized_degraded_mode() {
+    let (env, client, _admin) = setup_test();
+    let stranger = Address::generate(&env);
+    let res = client.try_set_degraded_mode(&stranger, &true);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
@@ -0,0 +1,178 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{
+    practice_transfer::OPT_OUT_WINDOW_SECONDS, ConsentType, ContractError, RecordType, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, vec, Address, Env};
+
+const CONSENT_SECONDS: u64 = 2 * OPT_OUT_WINDOW_SECONDS;
+
+/// A closing and a receiving practice at timestamp 1_000, plus two patients
+/// who have not yet seen either.
+fn closing_practice(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, Address, Address) {
+    env.ledger().set_timestamp(1_000);
+    let closing = register_user(env, client, admin, Role::Optometrist, "Dr. Closing");
+    let receiving = register_user(env, client, admin, Role::Optometrist, "Dr. Receiving");
+    (
+        closing,
+        receiving,
+        Address::generate(env),
+        Address::generate(env),
+    )
+}
+
+#[test]
+fn test_transfer_moves_records_after_opt_out_window() {
+    let (env, client, admin) = setup_test();
+    let (closing, receiving, staying, leaving) = closing_practice(&env, &client, &admin);
+    let moved = add_record(&env, &client, &closing, &staying, RecordType::Examination);
+    let other = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Other");
+    let untouched = add_record(&env, &client, &other, &staying, RecordType::Examination);
+    let kept = add_record(&env, &client, &closing, &leaving, RecordType::Examination);
+    client.establish_care_relationship(&staying, &closing);
+    client.grant_consent(
+        &staying,
+        &receiving,
+        &ConsentType::Treatment,
+        &CONSENT_SECONDS,
+    );
+
+    let transfer = client.transfer_records(
+        &closing,
+        &receiving,
+        &vec![&env, staying.clone(), leaving.clone()],
+    );
+    assert_eq!(transfer.opt_out_deadline, 1_000 + OPT_OUT_WINDOW_SECONDS);
+    client.opt_out_of_transfer(&leaving, &transfer.id);
+    assert!(client.is_transfer_opted_out(&transfer.id, &leaving));
+
+    let res = client.try_execute_records_transfer(&receiving, &transfer.id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::TransferOptOutOpen);
+
+    env.ledger().set_timestamp(transfer.opt_out_deadline);
+    let done = client.execute_records_transfer(&receiving, &transfer.id);
+    assert_eq!(done.records_moved, 1);
+    assert_eq!(done.executed_at, Some(transfer.opt_out_deadline));
+
+    assert_eq!(client.get_record(&admin, &moved).provider, receiving);
+    assert_eq!(client.get_record(&admin, &untouched).provider, other);
+    assert_eq!(client.get_record(&admin, &kept).provider, closing);
+    assert!(client.verify_custody_chain(&moved));
+
+    let team = client.get_care_team(&staying);
+    assert_eq!(team.len(), 1);
+    assert_eq!(team.get(0).unwrap().provider, receiving);
+    assert!(client.get_care_team(&leaving).is_empty());
+
+    let res = client.try_execute_records_transfer(&receiving, &transfer.id);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::PracticeTransferCompleted
+    );
+}
+
+#[test]
+fn test_opt_out_closes_at_deadline() {
+    let (env, client, admin) = setup_test();
+    let (closing, receiving, staying, leaving) = closing_practice(&env, &client, &admin);
+    add_record(&env, &client, &closing, &staying, RecordType::Examination);
+    let transfer = client.transfer_records(&closing, &receiving, &vec![&env, staying.clone()]);
+
+    let res = client.try_opt_out_of_transfer(&leaving, &transfer.id);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::PracticeTransferNotFound
+    );
+
+    env.ledger().set_timestamp(transfer.opt_out_deadline);
+    let res = client.try_opt_out_of_transfer(&staying, &transfer.id);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::TransferOptOutClosed
+    );
+}
+
+#[test]
+fn test_transfer_authorization_and_validation() {
+    let (env, client, admin) = setup_test();
+    let (closing, receiving, staying, leaving) = closing_practice(&env, &client, &admin);
+    add_record(&env, &client, &closing, &staying, RecordType::Examination);
+    let patients = vec![&env, staying.clone()];
+
+    let res = client.try_transfer_records(&staying, &receiving, &patients);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_transfer_records(&closing, &leaving, &patients);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidRole);
+
+    let res = client.try_transfer_records(&closing, &closing, &patients);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let res = client.try_transfer_records(&closing, &receiving, &vec![&env]);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let transfer = client.transfer_records(&closing, &receiving, &patients);
+    env.ledger().set_timestamp(transfer.opt_out_deadline);
+    let res = client.try_execute_records_transfer(&closing, &transfer.id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    client.execute_records_transfer(&admin, &transfer.id);
+}
+
+#[test]
+fn test_transfer_rejects_patients_without_closing_provider_records() {
+    let (env, client, admin) = setup_test();
+    let (closing, receiving, staying, leaving) = closing_practice(&env, &client, &admin);
+    add_record(&env, &client, &closing, &staying, RecordType::Examination);
+    let other = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Other");
+    add_record(&env, &client, &other, &leaving, RecordType::Examination);
+
+    let res = client.try_transfer_records(
+        &closing,
+        &receiving,
+        &vec![&env, staying.clone(), leaving.clone()],
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
+
+#[test]
+fn test_transfer_links_receiving_provider_only_with_consent() {
+    let (env, client, admin) = setup_test();
+    let (closing, receiving, staying, leaving) = closing_practice(&env, &client, &admin);
+    add_record(&env, &client, &closing, &staying, RecordType::Examination);
+    add_record(&env, &client, &closing, &leaving, RecordType::Examination);
+    client.establish_care_relationship(&leaving, &closing);
+
+    let transfer = client.transfer_records(
+        &closing,
+        &receiving,
+        &vec![&env, staying.clone(), leaving.clone()],
+    );
+    client.grant_consent(
+        &staying,
+        &receiving,
+        &ConsentType::Treatment,
+        &CONSENT_SECONDS,
+    );
+    env.ledger().set_timestamp(transfer.opt_out_deadline);
+    let done = client.execute_records_transfer(&receiving, &transfer.id);
+    assert_eq!(done.records_moved, 2);
+
+    let team = client.get_care_team(&staying);
+    assert_eq!(team.len(), 1);
+    assert_eq!(team.get(0).unwrap().provider, receiving);
+    assert!(client.get_care_team(&leaving).is_empty());
+
+    client.establish_care_relationship(&leaving, &receiving);
+    assert_eq!(client.get_care_team(&leaving).len(), 1);
+}
@@ -0,0 +1,161 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{advance, register_user, setup_test};
+use super::{
+    ContractError, LensType, OptionalContactLensData, PrescriptionData, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+
+const YEAR: u64 = 31_536_000;
+
+fn eye(env: &Env, sphere: &str, cylinder: &str, axis: &str) -> PrescriptionData {
+    PrescriptionData {
+        sphere: String::from_str(env, sphere),
+        cylinder: String::from_str(env, cylinder),
+        axis: String::from_str(env, axis),
+        add: String::from_str(env, "0.00"),
+        pd: String::from_str(env, "62"),
+        prism: String::from_str(env, ""),
+        prism_base: String::from_str(env, ""),
+    }
+}
+
+fn add(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    patient: &Address,
+    provider: &Address,
+    left: &PrescriptionData,
+    right: &PrescriptionData,
+) -> u64 {
+    client.add_prescription(
+        patient,
+        provider,
+        &LensType::Glasses,
+        left,
+        right,
+        &OptionalContactLensData::None,
+        &YEAR,
+        &String::from_str(env, "metadata_hash"),
+    )
+}
+
+#[test]
+fn test_same_paper_prescription_converges() {
+    let (env, client, admin) = setup_test();
+    let first = register_user(&env, &client, &admin, Role::Optometrist, "Dr. First");
+    let second = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Second");
+    let patient = Address::generate(&env);
+
+    let rx1 = add(
+        &env,
+        &client,
+        &patient,
+        &first,
+        &eye(&env, "-2.50", "-1.25", "180"),
+        &eye(&env, "-2.75", "-1.00", "175"),
+    );
+    // Same values, different formatting.
+    let rx2 = add(
+        &env,
+        &client,
+        &patient,
+        &second,
+        &eye(&env, " -2.5", "-1.25 ", "180.0"),
+        &eye(&env, "-2.75", "-1", "175"),
+    );
+
+    assert_eq!(rx1, rx2);
+    assert_eq!(client.get_prescription_history(&patient).len(), 1);
+
+    let rx = client.get_prescription(&rx1);
+    let found = client.find_prescription_by_canonical_hash(&patient, &rx.canonical_hash);
+    assert_eq!(found.id, rx1);
+    assert_eq!(found.provider, first);
+}
+
+#[test]
+fn test_different_values_are_not_duplicates() {
+    let (env, client, admin) = setup_test();
+    let first = register_user(&env, &client, &admin, Role::Optometrist, "Dr. First");
+    let patient = Address::generate(&env);
+
+    let rx1 = add(
+        &env,
+        &client,
+        &patient,
+        &first,
+        &eye(&env, "-2.50", "-1.25", "180"),
+        &eye(&env, "-2.75", "-1.00", "175"),
+    );
+    let rx2 = add(
+        &env,
+        &client,
+        &patient,
+        &first,
+        &eye(&env, "-2.25", "-1.25", "180"),
+        &eye(&env, "-2.75", "-1.00", "175"),
+    );
+
+    assert_ne!(rx1, rx2);
+    assert_ne!(
+        client.get_prescription(&rx1).canonical_hash,
+        client.get_prescription(&rx2).canonical_hash
+    );
+}
+
+#[test]
+fn test_duplicate_outside_window_is_new_prescription() {
+    let (env, client, admin) = setup_test();
+    let first = register_user(&env, &client, &admin, Role::Optometrist, "Dr. First");
+    let second = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Second");
+    let patient = Address::generate(&env);
+    client.set_prescription_duplicate_window(&admin, &3600);
+
+    let left = eye(&env, "-1.00", "0.00", "0");
+    let right = eye(&env, "-1.00", "0.00", "0");
+    let rx1 = add(&env, &client, &patient, &first, &left, &right);
+
+    advance(&env, 3601);
+    let rx2 = add(&env, &client, &patient, &second, &left, &right);
+
+    assert_ne!(rx1, rx2);
+    let hash = client.get_prescription(&rx2).canonical_hash;
+    assert_eq!(
+        client
+            .find_prescription_by_canonical_hash(&patient, &hash)
+            .id,
+        rx2
+    );
+}
+
+#[test]
+fn test_same_prescription_for_other_patient_is_not_duplicate() {
+    let (env, client, admin) = setup_test();
+    let first = register_user(&env, &client, &admin, Role::Optometrist, "Dr. First");
+    let patient = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let left = eye(&env, "-1.00", "0.00", "0");
+    let right = eye(&env, "-1.00", "0.00", "0");
+    let rx1 = add(&env, &client, &patient, &first, &left, &right);
+    let rx2 = add(&env, &client, &other, &first, &left, &right);
+
+    assert_ne!(rx1, rx2);
+}
+
+#[test]
+fn test_unknown_canonical_hash() {
+    let (env, client, _admin) = setup_test();
+    let patient = Address::generate(&env);
+
+    let res = client
+        .try_find_prescription_by_canonical_hash(&patient, &BytesN::from_array(&env, &[1u8; 32]));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+}
@@ -0,0 +1,129 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{advance, register_user, setup_test};
+use super::{
+    ContractError, LensType, OptionalContactLensData, PrescriptionData, PrescriptionStatus, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    Address, Env, String,
+};
+
+const DAY: u64 = 86_400;
+const YEAR: u64 = 31_536_000;
+
+/// Adds a glasses prescription; `sphere` keeps entries from being
+/// folded together as duplicates.
+fn add(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    patient: &Address,
+    sphere: &str,
+    duration: u64,
+) -> u64 {
+    let eye = PrescriptionData {
+        sphere: String::from_str(env, sphere),
+        cylinder: String::from_str(env, "-0.50"),
+        axis: String::from_str(env, "90"),
+        add: String::from_str(env, "0.00"),
+        pd: String::from_str(env, "62"),
+        prism: String::from_str(env, ""),
+        prism_base: String::from_str(env, ""),
+    };
+    client.add_prescription(
+        patient,
+        provider,
+        &LensType::Glasses,
+        &eye,
+        &eye,
+        &OptionalContactLensData::None,
+        &duration,
+        &String::from_str(env, "metadata_hash"),
+    )
+}
+
+#[test]
+fn test_sweeper_expires_due_prescriptions() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Expiry");
+    let patient = Address::generate(&env);
+    let short = add(&env, &client, &provider, &patient, "-1.00", DAY);
+    let long = add(&env, &client, &provider, &patient, "-2.00", YEAR);
+    assert_eq!(client.get_active_prescriptions(&patient).len(), 2);
+
+    advance(&env, DAY);
+    assert_eq!(client.expire_prescriptions(&10), 1);
+    assert!(!env.events().all().is_empty());
+
+    assert_eq!(
+        client.get_prescription(&short).status,
+        PrescriptionStatus::Expired
+    );
+    assert_eq!(
+        client.get_prescription(&long).status,
+        PrescriptionStatus::Active
+    );
+    let active = client.get_active_prescriptions(&patient);
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get(0).unwrap(), long);
+
+    // Already expired prescriptions are not counted again.
+    assert_eq!(client.expire_prescriptions(&10), 0);
+}
+
+#[test]
+fn test_sweeper_resumes_from_cursor() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Expiry");
+    let patient = Address::generate(&env);
+    for sphere in ["-1.00", "-1.25", "-1.50"] {
+        add(&env, &client, &provider, &patient, sphere, DAY);
+    }
+    advance(&env, DAY + 1);
+
+    assert_eq!(client.expire_prescriptions(&2), 2);
+    assert_eq!(client.expire_prescriptions(&2), 1);
+    assert_eq!(client.get_active_prescriptions(&patient).len(), 0);
+}
+
+#[test]
+fn test_expiry_is_applied_lazily_on_read() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Expiry");
+    let patient = Address::generate(&env);
+    let rx_id = add(&env, &client, &provider, &patient, "-1.00", DAY);
+
+    advance(&env, DAY);
+    assert_eq!(
+        client.get_prescription(&rx_id).status,
+        PrescriptionStatus::Expired
+    );
+    assert_eq!(client.get_active_prescriptions(&patient).len(), 0);
+    // Nothing left for the sweeper to do.
+    assert_eq!(client.expire_prescriptions(&10), 0);
+}
+
+#[test]
+fn test_active_index_drops_unswept_expired_entries() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Expiry");
+    let patient = Address::generate(&env);
+    add(&env, &client, &provider, &patient, "-1.00", DAY);
+
+    advance(&env, DAY);
+    assert_eq!(client.get_active_prescriptions(&patient).len(), 0);
+}
+
+#[test]
+fn test_sweeper_rejects_zero_limit() {
+    let (_env, client, _admin) = setup_test();
+    let res = client.try_expire_prescriptions(&0);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
@@ -0,0 +1,189 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, setup_test};
+use super::{
+    ContactLensData, ContractError, LensType, OptionalContactLensData, PrescriptionData, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+const YEAR: u64 = 31_536_000;
+
+fn eye(env: &Env, add: &str, prism: &str, prism_base: &str) -> PrescriptionData {
+    PrescriptionData {
+        sphere: String::from_str(env, "-1.50"),
+        cylinder: String::from_str(env, "-0.50"),
+        axis: String::from_str(env, "90"),
+        add: String::from_str(env, add),
+        pd: String::from_str(env, "63"),
+        prism: String::from_str(env, prism),
+        prism_base: String::from_str(env, prism_base),
+    }
+}
+
+fn try_add(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    patient: &Address,
+    lens_type: LensType,
+    eye_data: &PrescriptionData,
+    contact_data: OptionalContactLensData,
+) -> Result<u64, ContractError> {
+    client
+        .try_add_prescription(
+            patient,
+            provider,
+            &lens_type,
+            eye_data,
+            eye_data,
+            &contact_data,
+            &YEAR,
+            &String::from_str(env, "metadata_hash"),
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+fn contact(env: &Env, base_curve: &str, diameter: &str) -> OptionalContactLensData {
+    OptionalContactLensData::Some(ContactLensData {
+        base_curve: String::from_str(env, base_curve),
+        diameter: String::from_str(env, diameter),
+        brand: String::from_str(env, "Acuvue"),
+    })
+}
+
+#[test]
+fn test_contact_requires_lens_data() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Lens");
+    let patient = Address::generate(&env);
+    let data = eye(&env, "0.00", "", "");
+
+    let res = try_add(
+        &env,
+        &client,
+        &provider,
+        &patient,
+        LensType::ContactLens,
+        &data,
+        OptionalContactLensData::None,
+    );
+    assert_eq!(res, Err(ContractError::MissingRequiredField));
+
+    let res = try_add(
+        &env,
+        &client,
+        &provider,
+        &patient,
+        LensType::ContactLens,
+        &data,
+        contact(&env, "8.6", " "),
+    );
+    assert_eq!(res, Err(ContractError::MissingRequiredField));
+
+    let res = try_add(
+        &env,
+        &client,
+        &provider,
+        &patient,
+        LensType::ContactLens,
+        &data,
+        contact(&env, "8.6", "14.2"),
+    );
+    assert!(res.is_ok());
+}
+
+#[test]
+fn test_progressive_requires_add_power() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Lens");
+    let patient = Address::generate(&env);
+
+    for add in ["", "0.00"] {
+        let res = try_add(
+            &env,
+            &client,
+            &provider,
+            &patient,
+            LensType::Progressive,
+            &eye(&env, add, "", ""),
+            OptionalContactLensData::None,
+        );
+        assert_eq!(res, Err(ContractError::MissingRequiredField));
+    }
+
+    let res = try_add(
+        &env,
+        &client,
+        &provider,
+        &patient,
+        LensType::Progressive,
+        &eye(&env, "+2.00", "", ""),
+        OptionalContactLensData::None,
+    );
+    assert!(res.is_ok());
+}
+
+#[test]
+fn test_prism_validated_for_spectacles() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Lens");
+    let patient = Address::generate(&env);
+
+    let res = try_add(
+        &env,
+        &client,
+        &provider,
+        &patient,
+        LensType::Glasses,
+        &eye(&env, "0.00", "1.5", ""),
+        OptionalContactLensData::None,
+    );
+    assert_eq!(res, Err(ContractError::MissingRequiredField));
+
+    let res = try_add(
+        &env,
+        &client,
+        &provider,
+        &patient,
+        LensType::Glasses,
+        &eye(&env, "0.00", "1.5", "SIDEWAYS"),
+        OptionalContactLensData::None,
+    );
+    assert_eq!(res, Err(ContractError::InvalidInput));
+
+    let res = try_add(
+        &env,
+        &client,
+        &provider,
+        &patient,
+        LensType::Glasses,
+        &eye(&env, "0.00", "1.5", "Out"),
+        OptionalContactLensData::None,
+    );
+    assert!(res.is_ok());
+}
+
+#[test]
+fn test_prism_ignored_for_contacts() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Lens");
+    let patient = Address::generate(&env);
+
+    let res = try_add(
+        &env,
+        &client,
+        &provider,
+        &patient,
+        LensType::ContactLens,
+        &eye(&env, "0.00", "garbage", ""),
+        contact(&env, "8.6", "14.2"),
+    );
+    assert!(res.is_ok());
+}
@@ -0,0 +1,103 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, setup_test};
+use super::{
+    print_auth, ContractError, LensType, OptionalContactLensData, PrescriptionData, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, String};
+
+const YEAR: u64 = 31_536_000;
+
+/// A year-long glasses prescription written at timestamp 1_000.
+fn glasses_prescription(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, u64) {
+    env.ledger().set_timestamp(1_000);
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Paper");
+
+    let patient = Address::generate(env);
+    let eye = PrescriptionData {
+        sphere: String::from_str(env, "-1.50"),
+        cylinder: String::from_str(env, "-0.50"),
+        axis: String::from_str(env, "90"),
+        add: String::from_str(env, "0.00"),
+        pd: String::from_str(env, "62"),
+        prism: String::from_str(env, ""),
+        prism_base: String::from_str(env, ""),
+    };
+    let rx_id = client.add_prescription(
+        &patient,
+        &provider,
+        &LensType::Glasses,
+        &eye,
+        &eye,
+        &OptionalContactLensData::None,
+        &YEAR,
+        &String::from_str(env, "metadata_hash"),
+    );
+
+    (provider, rx_id)
+}
+
+#[test]
+fn test_print_code_is_single_use() {
+    let (env, client, admin) = setup_test();
+    let (provider, rx_id) = glasses_prescription(&env, &client, &admin);
+    let kiosk = Address::generate(&env);
+    let code = client.issue_print_authorization(&provider, &rx_id);
+    assert!((10_000_000..=99_999_999).contains(&code));
+
+    let auth = client.redeem_print_authorization(&kiosk, &code);
+    assert_eq!(auth.rx_id, rx_id);
+    assert_eq!(auth.redeemed_by, Some(kiosk.clone()));
+    assert_eq!(auth.redeemed_at, Some(1_000));
+
+    let res = client.try_redeem_print_authorization(&kiosk, &code);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::InvalidPrintAuthorization
+    );
+
+    // The paper copy stays traceable
+    let history = client.get_print_authorizations(&rx_id);
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap(), auth);
+}
+
+#[test]
+fn test_print_code_expires() {
+    let (env, client, admin) = setup_test();
+    let (provider, rx_id) = glasses_prescription(&env, &client, &admin);
+    let kiosk = Address::generate(&env);
+    let code = client.issue_print_authorization(&provider, &rx_id);
+
+    env.ledger()
+        .set_timestamp(1_000 + print_auth::PRINT_CODE_TTL_SECONDS);
+    let res = client.try_redeem_print_authorization(&kiosk, &code);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::InvalidPrintAuthorization
+    );
+    assert_eq!(client.get_print_authorization(&1).redeemed_at, None);
+}
+
+#[test]
+fn test_only_prescriber_issues_print_codes() {
+    let (env, client, admin) = setup_test();
+    let (provider, rx_id) = glasses_prescription(&env, &client, &admin);
+    let other = Address::generate(&env);
+
+    let res = client.try_issue_print_authorization(&other, &rx_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_issue_print_authorization(&provider, &999);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+}
@@ -0,0 +1,226 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+//! Property tests for prescription field validation and access decisions.
+//!
+//! Invariants tested:
+//! - Progressive lenses accept any positive add power and reject zero
+//! - Prism needs a positive amount and an IN/OUT/UP/DOWN base, in any case
+//! - Contact lenses ignore spectacle-only fields
+//! - A grant yields its level until it expires and `None` from then on
+
+extern crate std;
+
+use super::test_utils::setup_test;
+use super::{
+    prescription::{
+        validate_for_lens_type, ContactLensData, LensType, OptionalContactLensData,
+        PrescriptionData,
+    },
+    AccessLevel, ConsentType, ContractError,
+};
+use proptest::prelude::*;
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, String};
+use std::{format, vec};
+
+/// Formats hundredths of a dioptre the way clinicians write them, e.g. `+1.25`.
+fn dioptres(hundredths: i64) -> std::string::String {
+    let sign = if hundredths < 0 { "-" } else { "+" };
+    let abs = hundredths.abs();
+    format!("{sign}{}.{:02}", abs / 100, abs % 100)
+}
+
+fn eye(env: &Env, add: &str, prism: &str, prism_base: &str) -> PrescriptionData {
+    PrescriptionData {
+        sphere: String::from_str(env, "-2.00"),
+        cylinder: String::from_str(env, "-0.50"),
+        axis: String::from_str(env, "180"),
+        add: String::from_str(env, add),
+        pd: String::from_str(env, "32"),
+        prism: String::from_str(env, prism),
+        prism_base: String::from_str(env, prism_base),
+    }
+}
+
+fn mixed_case(word: &str, mask: u8) -> std::string::String {
+    word.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if mask & (1 << (i % 8)) != 0 {
+                c.to_ascii_uppercase()
+            } else {
+                c
+            }
+        })
+        .collect()
+}
+
+fn access_level_from_u8(n: u8) -> AccessLevel {
+    match n % 3 {
+        0 => AccessLevel::Read,
+        1 => AccessLevel::Write,
+        _ => AccessLevel::Admin,
+    }
+}
+
+// ── Prescription validation ──────────────────────────────────────
+
+proptest! {
+    #[test]
+    fn prop_progressive_accepts_any_positive_add(
+        left in 1i64..=400,
+        right in 1i64..=400,
+    ) {
+        let env = Env::default();
+        let result = validate_for_lens_type(
+            &LensType::Progressive,
+            &eye(&env, &dioptres(left), "", ""),
+            &eye(&env, &dioptres(right), "", ""),
+            &OptionalContactLensData::None,
+        );
+        prop_assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn prop_progressive_rejects_zero_or_negative_add(
+        bad in -400i64..=0,
+        good in 1i64..=400,
+        bad_left in any::<bool>(),
+    ) {
+        let env = Env::default();
+        let (left, right) = if bad_left { (bad, good) } else { (good, bad) };
+        let err = validate_for_lens_type(
+            &LensType::Progressive,
+            &eye(&env, &dioptres(left), "", ""),
+            &eye(&env, &dioptres(right), "", ""),
+            &OptionalContactLensData::None,
+        )
+        .unwrap_err();
+
+        let field = if bad_left { "left_eye.add" } else { "right_eye.add" };
+        prop_assert_eq!(err.field, field);
+        let expected = if bad == 0 {
+            ContractError::MissingRequiredField
+        } else {
+            ContractError::InvalidInput
+        };
+        prop_assert_eq!(err.error, expected);
+    }
+
+    #[test]
+    fn prop_prism_base_is_case_insensitive(
+        amount in 1i64..=1000,
+        base in prop::sample::select(vec!["in", "out", "up", "down"]),
+        mask in any::<u8>(),
+    ) {
+        let env = Env::default();
+        let prism = dioptres(amount);
+        let base = mixed_case(base, mask);
+        let result = validate_for_lens_type(
+            &LensType::Glasses,
+            &eye(&env, "", &prism, &base),
+            &eye(&env, "", "", ""),
+            &OptionalContactLensData::None,
+        );
+        prop_assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn prop_prism_rejects_unknown_base(
+        amount in 1i64..=1000,
+        base in "[a-z]{1,8}",
+    ) {
+        prop_assume!(!["in", "out", "up", "down"].contains(&base.as_str()));
+        let env = Env::default();
+        let err = validate_for_lens_type(
+            &LensType::Glasses,
+            &eye(&env, "", &dioptres(amount), &base),
+            &eye(&env, "", "", ""),
+            &OptionalContactLensData::None,
+        )
+        .unwrap_err();
+        prop_assert_eq!(err.field, "left_eye.prism_base");
+        prop_assert_eq!(err.error, ContractError::InvalidInput);
+    }
+
+    #[test]
+    fn prop_contacts_ignore_spectacle_fields(
+        add in -400i64..=400,
+        prism in -1000i64..=1000,
+        base in "[a-z]{0,8}",
+    ) {
+        let env = Env::default();
+        let junk = eye(&env, &dioptres(add), &dioptres(prism), &base);
+        let contact = OptionalContactLensData::Some(ContactLensData {
+            base_curve: String::from_str(&env, "8.6"),
+            diameter: String::from_str(&env, "14.2"),
+            brand: String::from_str(&env, ""),
+        });
+        let result = validate_for_lens_type(&LensType::ContactLens, &junk, &junk, &contact);
+        prop_assert_eq!(result, Ok(()));
+    }
+}
+
+// ── Access decisions ─────────────────────────────────────────────
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(32))]
+
+    /// A grant is honoured for its whole lifetime and never after.
+    #[test]
+    fn prop_expired_grants_never_yield_access(
+        level_seed in any::<u8>(),
+        duration in 3600u64..=31_536_000,
+        before in 0u64..3600,
+        after in 0u64..=31_536_000,
+    ) {
+        let (env, client, _admin) = setup_test();
+        let patient = Address::generate(&env);
+        let grantee = Address::generate(&env);
+        let level = access_level_from_u8(level_seed);
+        env.ledger().set_timestamp(1_000);
+
+        // Consent outlives the grant so only the grant's expiry is exercised
+        client.grant_consent(&patient, &grantee, &ConsentType::Treatment, &(duration + 86_400));
+        client.grant_access(&patient, &patient, &grantee, &level, &duration);
+
+        env.ledger().set_timestamp(1_000 + duration - 1 - before.min(duration - 1));
+        prop_assert_eq!(client.check_access(&patient, &grantee, &None, &None), level);
+
+        env.ledger().set_timestamp(1_000 + duration + after);
+        prop_assert_eq!(client.check_access(&patient, &grantee, &None, &None), AccessLevel::None);
+        prop_assert_eq!(
+            client.check_access_detailed(&patient, &grantee).level,
+            AccessLevel::None
+        );
+    }
+
+    /// Revocation ends access regardless of how much of the grant was left.
+    #[test]
+    fn prop_revoked_grants_never_yield_access(
+        level_seed in any::<u8>(),
+        duration in 3600u64..=31_536_000,
+        elapsed in 0u64..3600,
+    ) {
+        let (env, client, _admin) = setup_test();
+        let patient = Address::generate(&env);
+        let grantee = Address::generate(&env);
+        env.ledger().set_timestamp(1_000);
+
+        client.grant_consent(&patient, &grantee, &ConsentType::Treatment, &(duration + 86_400));
+        client.grant_access(
+            &patient,
+            &patient,
+            &grantee,
+            &access_level_from_u8(level_seed),
+            &duration,
+        );
+        env.ledger().set_timestamp(1_000 + elapsed);
+        client.revoke_access(&patient, &grantee);
+        prop_assert_eq!(client.check_access(&patient, &grantee, &None, &None), AccessLevel::None);
+    }
+}
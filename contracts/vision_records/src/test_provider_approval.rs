@@ -0,0 +1,115 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, setup_test, DATA_HASH};
+use super::{ContractError, RecordType, Role, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+fn try_write(
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+) -> Result<u64, ContractError> {
+    let patient = Address::generate(&client.env);
+    client
+        .try_add_record(
+            provider,
+            &patient,
+            provider,
+            &RecordType::Examination,
+            &String::from_str(&client.env, DATA_HASH),
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_provider_registration_waits_for_approval() {
+    let (env, client, admin) = setup_test();
+    let staff = register_user(&env, &client, &admin, Role::Staff, "Front Desk");
+    let provider = register_user(&env, &client, &staff, Role::Optometrist, "Dr. Pending");
+
+    assert!(!client.get_user(&provider).is_active);
+    let pending = client.list_pending_registrations();
+    assert_eq!(pending.len(), 1);
+    let entry = pending.get(0).unwrap();
+    assert_eq!(entry.user, provider);
+    assert_eq!(entry.role, Role::Optometrist);
+    assert_eq!(entry.requested_by, staff);
+    assert_eq!(
+        try_write(&client, &provider),
+        Err(ContractError::Unauthorized)
+    );
+
+    client.approve_provider(&admin, &provider);
+    assert!(client.get_user(&provider).is_active);
+    assert_eq!(client.list_pending_registrations().len(), 0);
+    assert!(try_write(&client, &provider).is_ok());
+}
+
+#[test]
+fn test_admin_registered_providers_are_active() {
+    let (env, client, admin) = setup_test();
+    let staff = register_user(&env, &client, &admin, Role::Staff, "Front Desk");
+    let provider = register_user(&env, &client, &admin, Role::Ophthalmologist, "Dr. Direct");
+    assert!(client.get_user(&provider).is_active);
+    assert_eq!(client.list_pending_registrations().len(), 0);
+
+    // Non-provider roles never queue
+    let patient = register_user(&env, &client, &staff, Role::Patient, "Pat");
+    assert!(client.get_user(&patient).is_active);
+}
+
+#[test]
+fn test_credentialing_authority_approves_and_rejects() {
+    let (env, client, admin) = setup_test();
+    let staff = register_user(&env, &client, &admin, Role::Staff, "Front Desk");
+    let authority = Address::generate(&env);
+    client.set_credentialing_authority(&admin, &Some(authority.clone()));
+
+    let approved = register_user(&env, &client, &staff, Role::Optometrist, "Dr. Pending");
+    let rejected = register_user(&env, &client, &staff, Role::Optometrist, "Dr. Pending");
+
+    let res = client.try_approve_provider(&staff, &approved);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.approve_provider(&authority, &approved);
+    client.reject_provider(&authority, &rejected);
+
+    assert!(client.get_user(&approved).is_active);
+    assert_eq!(
+        client.try_get_user(&rejected).unwrap_err().unwrap(),
+        ContractError::UserNotFound
+    );
+    assert_eq!(
+        try_write(&client, &rejected),
+        Err(ContractError::Unauthorized)
+    );
+
+    let res = client.try_reject_provider(&authority, &rejected);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::RegistrationNotPending
+    );
+}
+
+#[test]
+fn test_two_phase_registration_waits_for_approval() {
+    let (env, client, admin) = setup_test();
+    let staff = register_user(&env, &client, &admin, Role::Staff, "Front Desk");
+    let provider = Address::generate(&env);
+    let name = String::from_str(&env, "Dr. Two Phase");
+
+    client.prepare_register_user(&staff, &provider, &Role::Optometrist, &name);
+    client.commit_register_user(&staff, &provider, &Role::Optometrist, &name);
+
+    assert!(!client.get_user(&provider).is_active);
+    assert_eq!(client.list_pending_registrations().len(), 1);
+    assert_eq!(
+        try_write(&client, &provider),
+        Err(ContractError::Unauthorized)
+    );
+}
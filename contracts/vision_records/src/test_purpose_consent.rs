@@ -0,0 +1,113 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{
+    ConsentPurpose, ConsentScope, ContractError, RecordType, Role, VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env};
+
+const YEAR: u64 = 31_536_000;
+
+/// An examination and a prescription record for one patient, written at
+/// timestamp 1_000.
+fn exam_and_prescription(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, u64, u64) {
+    env.ledger().set_timestamp(1_000);
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Consent");
+    let patient = Address::generate(env);
+    let exam_id = add_record(env, client, &provider, &patient, RecordType::Examination);
+    let rx_id = add_record(env, client, &provider, &patient, RecordType::Prescription);
+    (patient, exam_id, rx_id)
+}
+
+#[test]
+fn test_consent_is_per_purpose() {
+    let (env, client, admin) = setup_test();
+    let (patient, _exam_id, _rx_id) = exam_and_prescription(&env, &client, &admin);
+    assert!(!client.check_consent(&patient, &ConsentPurpose::Research));
+
+    client.record_consent(
+        &patient,
+        &ConsentPurpose::Research,
+        &ConsentScope::AllRecords,
+        &(1_000 + YEAR),
+    );
+    assert!(client.check_consent(&patient, &ConsentPurpose::Research));
+    assert!(!client.check_consent(&patient, &ConsentPurpose::Insurance));
+
+    env.ledger().set_timestamp(1_000 + YEAR);
+    assert!(!client.check_consent(&patient, &ConsentPurpose::Research));
+}
+
+#[test]
+fn test_record_consent_respects_scope() {
+    let (env, client, admin) = setup_test();
+    let (patient, exam_id, rx_id) = exam_and_prescription(&env, &client, &admin);
+    client.record_consent(
+        &patient,
+        &ConsentPurpose::Insurance,
+        &ConsentScope::RecordType(RecordType::Prescription),
+        &(1_000 + YEAR),
+    );
+    assert!(client.check_record_consent(&patient, &ConsentPurpose::Insurance, &rx_id));
+    assert!(!client.check_record_consent(&patient, &ConsentPurpose::Insurance, &exam_id));
+
+    // Re-recording replaces the earlier scope
+    client.record_consent(
+        &patient,
+        &ConsentPurpose::Insurance,
+        &ConsentScope::Record(exam_id),
+        &(1_000 + YEAR),
+    );
+    assert!(client.check_record_consent(&patient, &ConsentPurpose::Insurance, &exam_id));
+    assert!(!client.check_record_consent(&patient, &ConsentPurpose::Insurance, &rx_id));
+    assert_eq!(client.get_purpose_consents(&patient).len(), 1);
+}
+
+#[test]
+fn test_withdraw_consent() {
+    let (env, client, admin) = setup_test();
+    let (patient, _exam_id, _rx_id) = exam_and_prescription(&env, &client, &admin);
+    client.record_consent(
+        &patient,
+        &ConsentPurpose::Treatment,
+        &ConsentScope::AllRecords,
+        &(1_000 + YEAR),
+    );
+    let withdrawn = client.withdraw_consent(&patient, &ConsentPurpose::Treatment);
+    assert_eq!(withdrawn.withdrawn_at, Some(1_000));
+    assert!(!client.check_consent(&patient, &ConsentPurpose::Treatment));
+
+    let res = client.try_withdraw_consent(&patient, &ConsentPurpose::Treatment);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
+
+#[test]
+fn test_record_consent_validation() {
+    let (env, client, admin) = setup_test();
+    let (patient, exam_id, _rx_id) = exam_and_prescription(&env, &client, &admin);
+    let res = client.try_record_consent(
+        &patient,
+        &ConsentPurpose::Research,
+        &ConsentScope::AllRecords,
+        &1_000,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let other = Address::generate(&env);
+    let res = client.try_record_consent(
+        &other,
+        &ConsentPurpose::Research,
+        &ConsentScope::Record(exam_id),
+        &(1_000 + YEAR),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
@@ -155,7 +155,10 @@ fn test_role_delegation() {
     // (caller: pt2, patient: pt1, grantee: doctor)
     client.grant_access(&pt2, &pt1, &doctor, &super::AccessLevel::Read, &3600);
 
-    assert_eq!(client.check_access(&pt1, &doctor), super::AccessLevel::Read);
+    assert_eq!(
+        client.check_access(&pt1, &doctor, &None, &None),
+        super::AccessLevel::Read
+    );
 }
 
 #[test]
@@ -0,0 +1,107 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{
+    read_log::READ_LOG_BUCKET_SIZE, AccessLevel, ContractError, RecordType, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, String};
+
+/// An examination written at timestamp 1_000 that `reader` holds a read
+/// grant on.
+fn shared_exam(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, u64) {
+    env.ledger().set_timestamp(1_000);
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Log");
+    let patient = Address::generate(env);
+    let record_id = add_record(env, client, &provider, &patient, RecordType::Examination);
+    let reader = Address::generate(env);
+    client.grant_access(&patient, &patient, &reader, &AccessLevel::Read, &86_400);
+    (patient, reader, record_id)
+}
+
+fn purpose(env: &Env, text: &str) -> String {
+    String::from_str(env, text)
+}
+
+#[test]
+fn test_reads_are_logged_in_order() {
+    let (env, client, admin) = setup_test();
+    let (patient, reader, record_id) = shared_exam(&env, &client, &admin);
+    client.log_record_access(&reader, &record_id, &purpose(&env, "Follow-up"));
+    env.ledger().set_timestamp(2_000);
+    let entry = client.log_record_access(&patient, &record_id, &purpose(&env, "Own copy"));
+    assert_eq!(entry.seq, 1);
+
+    let log = client.get_record_access_log(&patient, &record_id, &0);
+    assert_eq!(log.len(), 2);
+    let first = log.get(0).unwrap();
+    assert_eq!(first.reader, reader);
+    assert_eq!(first.purpose, purpose(&env, "Follow-up"));
+    assert_eq!(first.read_at, 1_000);
+    assert_eq!(log.get(1).unwrap().reader, patient);
+    assert_eq!(client.get_record_access_count(&patient, &record_id), 2);
+}
+
+#[test]
+fn test_log_is_paged_by_bucket() {
+    let (env, client, admin) = setup_test();
+    let (patient, reader, record_id) = shared_exam(&env, &client, &admin);
+    for _ in 0..READ_LOG_BUCKET_SIZE + 1 {
+        client.log_record_access(&reader, &record_id, &purpose(&env, "Audit"));
+    }
+
+    assert_eq!(
+        client.get_record_access_log(&patient, &record_id, &0).len(),
+        READ_LOG_BUCKET_SIZE
+    );
+    let second = client.get_record_access_log(&patient, &record_id, &1);
+    assert_eq!(second.len(), 1);
+    assert_eq!(second.get(0).unwrap().seq, READ_LOG_BUCKET_SIZE);
+    assert_eq!(
+        client.get_record_access_log(&patient, &record_id, &2).len(),
+        0
+    );
+}
+
+#[test]
+fn test_only_permitted_readers_can_log() {
+    let (env, client, admin) = setup_test();
+    let (patient, reader, record_id) = shared_exam(&env, &client, &admin);
+    let stranger = Address::generate(&env);
+    let res = client.try_log_record_access(&stranger, &record_id, &purpose(&env, "Curious"));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AccessDenied);
+
+    let res = client.try_log_record_access(&reader, &999, &purpose(&env, "Follow-up"));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+
+    let res = client.try_log_record_access(&reader, &record_id, &purpose(&env, ""));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+    assert_eq!(client.get_record_access_count(&patient, &record_id), 0);
+}
+
+#[test]
+fn test_only_patient_or_admin_reads_the_log() {
+    let (env, client, admin) = setup_test();
+    let (_patient, reader, record_id) = shared_exam(&env, &client, &admin);
+    client.log_record_access(&reader, &record_id, &purpose(&env, "Follow-up"));
+
+    let res = client.try_get_record_access_log(&reader, &record_id, &0);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    let res = client.try_get_record_access_count(&reader, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    assert_eq!(
+        client.get_record_access_log(&admin, &record_id, &0).len(),
+        1
+    );
+    assert_eq!(client.get_record_access_count(&admin, &record_id), 1);
+}
@@ -0,0 +1,51 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test, DATA_HASH};
+use super::{AccessLevel, ContractError, RecordType, Role};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+#[test]
+fn test_patient_and_author_read_record() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Author");
+    let patient = Address::generate(&env);
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    for reader in [patient.clone(), provider.clone()] {
+        let record = client.read_record(&reader, &record_id);
+        assert_eq!(record.data_hash, String::from_str(&env, DATA_HASH));
+    }
+}
+
+#[test]
+fn test_grant_holder_reads_until_revoked() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Author");
+    let patient = Address::generate(&env);
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let grantee = Address::generate(&env);
+
+    let res = client.try_read_record(&grantee, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AccessDenied);
+
+    client.grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &3600);
+    assert_eq!(client.read_record(&grantee, &record_id).id, record_id);
+
+    client.revoke_access(&patient, &grantee);
+    let res = client.try_read_record(&grantee, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AccessDenied);
+}
+
+#[test]
+fn test_missing_record_is_not_found() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Author");
+    let patient = Address::generate(&env);
+    add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let res = client.try_read_record(&patient, &999);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+}
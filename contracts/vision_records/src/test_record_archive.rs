@@ -0,0 +1,111 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{
+    AccessLevel, ContractError, LegalHoldTarget, RecordType, Role, StateChangeKind,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, Env};
+
+/// Two examinations for one patient; tests archive the second.
+fn two_exams(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, u64, u64) {
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Archive");
+    let patient = Address::generate(env);
+    let kept = add_record(env, client, &provider, &patient, RecordType::Examination);
+    let archived = add_record(env, client, &provider, &patient, RecordType::Examination);
+    (provider, patient, kept, archived)
+}
+
+fn listed(
+    client: &VisionRecordsContractClient,
+    patient: &Address,
+    include_archived: bool,
+) -> soroban_sdk::Vec<u64> {
+    client.get_patient_records_filtered(patient, &None, &0, &u64::MAX, &include_archived, &0, &50)
+}
+
+#[test]
+fn test_archive_hides_record_but_keeps_it() {
+    let (env, client, admin) = setup_test();
+    let (provider, patient, kept, archived) = two_exams(&env, &client, &admin);
+    let tombstone = client.archive_record(&patient, &archived);
+    assert_eq!(tombstone.archived_by, patient);
+    assert_eq!(client.get_record_tombstone(&archived), Some(tombstone));
+
+    assert_eq!(listed(&client, &patient, false), vec![&env, kept]);
+    assert_eq!(listed(&client, &patient, true), vec![&env, kept, archived]);
+
+    // Only the patient and admins can still open it
+    let res = client.try_get_record(&provider, &archived);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordArchived);
+    assert_eq!(client.get_record(&patient, &archived).id, archived);
+
+    let changes = client.get_patient_changes_since(&patient, &0);
+    let last = changes.get(changes.len() - 1).unwrap();
+    assert_eq!(last.kind, StateChangeKind::RecordArchived);
+    assert_eq!(last.ref_id, Some(archived));
+}
+
+#[test]
+fn test_restore_brings_record_back() {
+    let (env, client, admin) = setup_test();
+    let (provider, patient, kept, archived) = two_exams(&env, &client, &admin);
+    client.archive_record(&admin, &archived);
+    let res = client.try_archive_record(&patient, &archived);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordArchived);
+
+    client.restore_record(&patient, &archived);
+    assert_eq!(client.get_record_tombstone(&archived), None);
+    assert_eq!(listed(&client, &patient, false), vec![&env, kept, archived]);
+    assert_eq!(client.get_record(&provider, &archived).id, archived);
+
+    let res = client.try_restore_record(&patient, &archived);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
+
+#[test]
+fn test_only_patient_or_admin_can_archive() {
+    let (env, client, admin) = setup_test();
+    let (provider, patient, _kept, archived) = two_exams(&env, &client, &admin);
+    let grantee = Address::generate(&env);
+    client.grant_access(&patient, &patient, &grantee, &AccessLevel::Admin, &86_400);
+
+    for caller in [&provider, &grantee] {
+        let res = client.try_archive_record(caller, &archived);
+        assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    }
+    let res = client.try_archive_record(&patient, &999);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+}
+
+#[test]
+fn test_archive_refused_under_legal_hold() {
+    let (env, client, admin) = setup_test();
+    let (_provider, patient, kept, archived) = two_exams(&env, &client, &admin);
+    client.place_legal_hold(
+        &admin,
+        &LegalHoldTarget::Record(archived),
+        &BytesN::from_array(&env, &[7u8; 32]),
+    );
+    let res = client.try_archive_record(&patient, &archived);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::LegalHoldActive);
+
+    // A hold on the patient covers every record
+    client.place_legal_hold(
+        &admin,
+        &LegalHoldTarget::Patient(patient.clone()),
+        &BytesN::from_array(&env, &[8u8; 32]),
+    );
+    let res = client.try_archive_record(&admin, &kept);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::LegalHoldActive);
+    assert_eq!(client.get_record_tombstone(&kept), None);
+}
@@ -0,0 +1,88 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{RecordType, Role, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, vec, Address, Env};
+
+/// Five records for one patient, created at t = 1000, 2000, ... 5000 and
+/// alternating Examination / Prescription.
+fn five_records(env: &Env, client: &VisionRecordsContractClient, admin: &Address) -> Address {
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Filter");
+    let patient = Address::generate(env);
+    for i in 1..=5u64 {
+        env.ledger().set_timestamp(i * 1_000);
+        let record_type = if i % 2 == 1 {
+            RecordType::Examination
+        } else {
+            RecordType::Prescription
+        };
+        add_record(env, client, &provider, &patient, record_type);
+    }
+    patient
+}
+
+#[test]
+fn test_filter_by_type_and_date_range() {
+    let (env, client, admin) = setup_test();
+    let patient = five_records(&env, &client, &admin);
+
+    let all = client.get_patient_records_filtered(&patient, &None, &0, &u64::MAX, &false, &0, &50);
+    assert_eq!(all, vec![&env, 1u64, 2, 3, 4, 5]);
+
+    let exams = client.get_patient_records_filtered(
+        &patient,
+        &Some(RecordType::Examination),
+        &0,
+        &u64::MAX,
+        &false,
+        &0,
+        &50,
+    );
+    assert_eq!(exams, vec![&env, 1u64, 3, 5]);
+
+    // Bounds are inclusive
+    let recent_exams = client.get_patient_records_filtered(
+        &patient,
+        &Some(RecordType::Examination),
+        &3_000,
+        &5_000,
+        &false,
+        &0,
+        &50,
+    );
+    assert_eq!(recent_exams, vec![&env, 3u64, 5]);
+
+    let window =
+        client.get_patient_records_filtered(&patient, &None, &2_000, &4_000, &false, &0, &50);
+    assert_eq!(window, vec![&env, 2u64, 3, 4]);
+}
+
+#[test]
+fn test_filter_pages_over_matches() {
+    let (env, client, admin) = setup_test();
+    let patient = five_records(&env, &client, &admin);
+
+    let first = client.get_patient_records_filtered(&patient, &None, &0, &u64::MAX, &false, &0, &2);
+    let second =
+        client.get_patient_records_filtered(&patient, &None, &0, &u64::MAX, &false, &2, &2);
+    let last = client.get_patient_records_filtered(&patient, &None, &0, &u64::MAX, &false, &4, &2);
+    assert_eq!(first, vec![&env, 1u64, 2]);
+    assert_eq!(second, vec![&env, 3u64, 4]);
+    assert_eq!(last, vec![&env, 5u64]);
+
+    let none = client.get_patient_records_filtered(
+        &Address::generate(&env),
+        &None,
+        &0,
+        &u64::MAX,
+        &false,
+        &0,
+        &50,
+    );
+    assert_eq!(none.len(), 0);
+}
@@ -0,0 +1,131 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{ContractError, RecordType, Role, VisionRecordsContractClient};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, BytesN, Env, String};
+
+const AMENDED_HASH: &str = "QmSignatureAmendedHash000000000000000000";
+
+/// An examination written at timestamp 1_000 by a provider who has
+/// registered the returned signing key.
+fn keyed_exam(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, u64, SigningKey) {
+    env.ledger().set_timestamp(1_000);
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Sign");
+    let patient = Address::generate(env);
+    let record_id = add_record(env, client, &provider, &patient, RecordType::Examination);
+
+    let key = SigningKey::from_bytes(&[7u8; 32]);
+    client.register_signing_key(
+        &provider,
+        &BytesN::from_array(env, &key.verifying_key().to_bytes()),
+    );
+
+    (provider, patient, record_id, key)
+}
+
+fn sign(
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    record_id: u64,
+    key: &SigningKey,
+) -> BytesN<64> {
+    let hash = client.get_record_signing_hash(provider, &record_id);
+    BytesN::from_array(&client.env, &key.sign(&hash.to_array()).to_bytes())
+}
+
+#[test]
+fn test_provider_signs_record() {
+    let (env, client, admin) = setup_test();
+    let (provider, patient, record_id, key) = keyed_exam(&env, &client, &admin);
+    assert!(!client.is_record_signature_current(&patient, &record_id));
+
+    let signature = sign(&client, &provider, record_id, &key);
+    let stored = client.sign_record(&provider, &record_id, &signature);
+    assert_eq!(stored.signer, provider);
+    assert_eq!(stored.signature, signature);
+    assert_eq!(
+        stored.public_key,
+        client.get_signing_key(&provider).unwrap()
+    );
+    assert_eq!(stored.signed_at, 1_000);
+    assert_eq!(client.get_record_signature(&record_id).unwrap(), stored);
+    assert!(client.is_record_signature_current(&patient, &record_id));
+}
+
+#[test]
+#[should_panic]
+fn test_signature_from_other_key_rejected() {
+    let (env, client, admin) = setup_test();
+    let (provider, _patient, record_id, _key) = keyed_exam(&env, &client, &admin);
+    let other = SigningKey::from_bytes(&[9u8; 32]);
+    let signature = sign(&client, &provider, record_id, &other);
+    client.sign_record(&provider, &record_id, &signature);
+}
+
+#[test]
+fn test_amendment_makes_signature_stale() {
+    let (env, client, admin) = setup_test();
+    let (provider, patient, record_id, key) = keyed_exam(&env, &client, &admin);
+    client.sign_record(
+        &provider,
+        &record_id,
+        &sign(&client, &provider, record_id, &key),
+    );
+    client.amend_record(
+        &provider,
+        &record_id,
+        &String::from_str(&env, AMENDED_HASH),
+        &String::from_str(&env, "Corrected axis"),
+    );
+    assert!(!client.is_record_signature_current(&patient, &record_id));
+
+    client.sign_record(
+        &provider,
+        &record_id,
+        &sign(&client, &provider, record_id, &key),
+    );
+    assert!(client.is_record_signature_current(&patient, &record_id));
+}
+
+#[test]
+fn test_sign_record_requires_record_provider_and_key() {
+    let (env, client, admin) = setup_test();
+    let (provider, patient, record_id, key) = keyed_exam(&env, &client, &admin);
+    let signature = sign(&client, &provider, record_id, &key);
+    let res = client.try_sign_record(&patient, &record_id, &signature);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_sign_record(&provider, &(record_id + 1), &signature);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+
+    let stranger = Address::generate(&env);
+    let res = client.try_get_record_signing_hash(&stranger, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_sign_record_without_registered_key() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Unkeyed");
+    let patient = Address::generate(&env);
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+
+    let res = client.try_sign_record(&provider, &record_id, &BytesN::from_array(&env, &[0u8; 64]));
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::SigningKeyNotRegistered
+    );
+
+    let res = client.try_register_signing_key(&patient, &BytesN::from_array(&env, &[1u8; 32]));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
@@ -0,0 +1,143 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{AccessLevel, ContractError, RecordType, Role, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, BytesN, Env, String};
+
+const CAPSULE: &str = "QmReencryptCapsuleHash000000000000000000";
+const NEW_CAPSULE: &str = "QmReencryptCapsuleHashRotated00000000000";
+
+/// An examination for a patient with a registered encryption key, on which
+/// `grantee` holds a write grant, at timestamp 1_000.
+fn granted_encrypted_exam(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, u64) {
+    env.ledger().set_timestamp(1_000);
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Author");
+    let grantee = register_user(env, client, admin, Role::Ophthalmologist, "Dr. Consult");
+
+    let patient = Address::generate(env);
+    client.register_encryption_key(&patient, &1, &BytesN::from_array(env, &[1u8; 32]));
+    let record_id = add_record(env, client, &provider, &patient, RecordType::Examination);
+    client.grant_access(&patient, &patient, &grantee, &AccessLevel::Write, &86_400);
+
+    (patient, grantee, record_id)
+}
+
+#[test]
+fn test_grantee_receives_key_material() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee, record_id) = granted_encrypted_exam(&env, &client, &admin);
+    let grant = client.grant_reencryption(
+        &patient,
+        &grantee,
+        &record_id,
+        &String::from_str(&env, CAPSULE),
+    );
+    assert_eq!(grant.patient, patient);
+    assert_eq!(grant.key_id, Some(1));
+    assert_eq!(grant.granted_at, 1_000);
+    assert_eq!(client.get_reencryption_grant(&grantee, &record_id), grant);
+    assert_eq!(
+        client.get_reencryption_grantees(&patient, &record_id).len(),
+        1
+    );
+
+    client.grant_reencryption(
+        &patient,
+        &grantee,
+        &record_id,
+        &String::from_str(&env, NEW_CAPSULE),
+    );
+    assert_eq!(
+        client
+            .get_reencryption_grant(&grantee, &record_id)
+            .wrapped_key_hash,
+        String::from_str(&env, NEW_CAPSULE)
+    );
+    assert_eq!(
+        client.get_reencryption_grantees(&patient, &record_id).len(),
+        1
+    );
+}
+
+#[test]
+fn test_grant_requires_patient_and_full_access() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee, record_id) = granted_encrypted_exam(&env, &client, &admin);
+    let res = client.try_grant_reencryption(
+        &grantee,
+        &grantee,
+        &record_id,
+        &String::from_str(&env, CAPSULE),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let stranger = Address::generate(&env);
+    let res = client.try_grant_reencryption(
+        &patient,
+        &stranger,
+        &record_id,
+        &String::from_str(&env, CAPSULE),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AccessDenied);
+
+    let res = client.try_grant_reencryption(
+        &patient,
+        &grantee,
+        &record_id,
+        &String::from_str(&env, "short"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
+
+#[test]
+fn test_material_withheld_after_access_revoked() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee, record_id) = granted_encrypted_exam(&env, &client, &admin);
+    client.grant_reencryption(
+        &patient,
+        &grantee,
+        &record_id,
+        &String::from_str(&env, CAPSULE),
+    );
+    client.revoke_access(&patient, &grantee);
+
+    let res = client.try_get_reencryption_grant(&grantee, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AccessDenied);
+}
+
+#[test]
+fn test_revoke_reencryption() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee, record_id) = granted_encrypted_exam(&env, &client, &admin);
+    client.grant_reencryption(
+        &patient,
+        &grantee,
+        &record_id,
+        &String::from_str(&env, CAPSULE),
+    );
+    client.revoke_reencryption(&patient, &grantee, &record_id);
+
+    let res = client.try_get_reencryption_grant(&grantee, &record_id);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::ReencryptionGrantNotFound
+    );
+    assert!(client
+        .get_reencryption_grantees(&patient, &record_id)
+        .is_empty());
+
+    let res = client.try_revoke_reencryption(&patient, &grantee, &record_id);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::ReencryptionGrantNotFound
+    );
+}
@@ -0,0 +1,157 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, setup_test};
+use super::{
+    referral::REFERRAL_GRANT_SECONDS, AccessLevel, ContractError, ReferralStatus, ReferralUrgency,
+    Role, VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, BytesN, Env};
+
+const DAY: u64 = 86_400;
+
+/// A referrer holding a 30-day read grant from the patient, and a specialist
+/// with no access yet, at timestamp 1_000.
+fn referring_practice(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, Address) {
+    env.ledger().set_timestamp(1_000);
+    let referrer = register_user(env, client, admin, Role::Optometrist, "Dr. Referrer");
+    let specialist = register_user(env, client, admin, Role::Optometrist, "Dr. Specialist");
+
+    let patient = Address::generate(env);
+    client.grant_access(
+        &patient,
+        &patient,
+        &referrer,
+        &AccessLevel::Read,
+        &(30 * DAY),
+    );
+
+    (referrer, specialist, patient)
+}
+
+fn refer(
+    client: &VisionRecordsContractClient,
+    referrer: &Address,
+    patient: &Address,
+    specialist: &Address,
+    urgency: ReferralUrgency,
+) -> u64 {
+    client.create_referral(
+        referrer,
+        patient,
+        specialist,
+        &BytesN::from_array(&client.env, &[9u8; 32]),
+        &urgency,
+    )
+}
+
+#[test]
+fn test_accepted_referral_grants_time_boxed_read() {
+    let (env, client, admin) = setup_test();
+    let (referrer, specialist, patient) = referring_practice(&env, &client, &admin);
+    let id = refer(
+        &client,
+        &referrer,
+        &patient,
+        &specialist,
+        ReferralUrgency::Urgent,
+    );
+
+    let referral = client.get_referral(&id);
+    assert_eq!(referral.status, ReferralStatus::Pending);
+    assert_eq!(referral.urgency, ReferralUrgency::Urgent);
+    assert_eq!(referral.reason_hash, BytesN::from_array(&env, &[9u8; 32]));
+    assert_eq!(
+        client.check_access(&patient, &specialist, &None, &None),
+        AccessLevel::None
+    );
+
+    let accepted = client.accept_referral(&specialist, &id);
+    assert_eq!(accepted.status, ReferralStatus::Accepted);
+    assert_eq!(accepted.responded_at, Some(1_000));
+    assert_eq!(
+        client.check_access(&patient, &specialist, &None, &None),
+        AccessLevel::Read
+    );
+    let grant = client.get_access_grants(&patient);
+    let grant = grant.iter().find(|g| g.grantee == specialist).unwrap();
+    assert_eq!(grant.expires_at, 1_000 + REFERRAL_GRANT_SECONDS);
+
+    env.ledger()
+        .set_timestamp(1_000 + REFERRAL_GRANT_SECONDS + 1);
+    assert_eq!(
+        client.check_access(&patient, &specialist, &None, &None),
+        AccessLevel::None
+    );
+}
+
+#[test]
+fn test_acceptance_keeps_existing_stronger_grant() {
+    let (env, client, admin) = setup_test();
+    let (referrer, specialist, patient) = referring_practice(&env, &client, &admin);
+    client.grant_access(
+        &patient,
+        &patient,
+        &specialist,
+        &AccessLevel::Write,
+        &(10 * DAY),
+    );
+    let id = refer(
+        &client,
+        &referrer,
+        &patient,
+        &specialist,
+        ReferralUrgency::Routine,
+    );
+    client.accept_referral(&specialist, &id);
+
+    assert_eq!(
+        client.check_access(&patient, &specialist, &None, &None),
+        AccessLevel::Write
+    );
+}
+
+#[test]
+fn test_decline_and_response_rules() {
+    let (env, client, admin) = setup_test();
+    let (referrer, specialist, patient) = referring_practice(&env, &client, &admin);
+    let declined = refer(
+        &client,
+        &referrer,
+        &patient,
+        &specialist,
+        ReferralUrgency::Routine,
+    );
+    let pending = refer(
+        &client,
+        &referrer,
+        &patient,
+        &specialist,
+        ReferralUrgency::Emergency,
+    );
+
+    let res = client.try_decline_referral(&referrer, &declined);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.decline_referral(&specialist, &declined);
+    let res = client.try_accept_referral(&specialist, &declined);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::ReferralNotPending);
+    assert_eq!(
+        client.check_access(&patient, &specialist, &None, &None),
+        AccessLevel::None
+    );
+
+    let open = client.get_referrals_for_provider(&specialist, &Some(ReferralStatus::Pending));
+    assert_eq!(open.len(), 1);
+    assert_eq!(open.get(0).unwrap().id, pending);
+    let all = client.get_referrals_for_provider(&specialist, &None);
+    assert_eq!(all.len(), 2);
+}
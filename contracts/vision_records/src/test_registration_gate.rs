@@ -0,0 +1,232 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::setup_test;
+use super::{ContractError, Role, VisionRecordsContractClient};
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, BytesN, Env, String};
+use teye_common::interface::{self, InterfaceVersion};
+
+const DAY: u64 = 86_400;
+
+/// Minimal zk verifier: receipts are issued per user and consumed once.
+#[contract]
+struct MockZkVerifier;
+
+#[contractimpl]
+impl MockZkVerifier {
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        InterfaceVersion {
+            interface: interface::ZK_VERIFIER,
+            major: 1,
+            minor: 0,
+        }
+    }
+
+    pub fn issue(env: Env, user: Address) {
+        env.storage().persistent().set(&user, &true);
+    }
+
+    pub fn consume_receipt(
+        env: Env,
+        _consumer: Address,
+        user: Address,
+        _resource_id: BytesN<32>,
+        _max_age: u64,
+    ) -> bool {
+        let issued = env.storage().persistent().get(&user).unwrap_or(false);
+        env.storage().persistent().remove(&user);
+        issued
+    }
+}
+
+/// A verifier from a later, breaking release of the zk_verifier interface.
+#[contract]
+struct FutureZkVerifier;
+
+#[contractimpl]
+impl FutureZkVerifier {
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        InterfaceVersion {
+            interface: interface::ZK_VERIFIER,
+            major: 2,
+            minor: 0,
+        }
+    }
+}
+
+/// A contract that does not report an interface version at all.
+#[contract]
+struct UnversionedContract;
+
+#[contractimpl]
+impl UnversionedContract {
+    pub fn ping(_env: Env) {}
+}
+
+/// Installs a [`MockZkVerifier`] as the registration verifier.
+fn install_verifier(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> MockZkVerifierClient<'static> {
+    let verifier_id = env.register(MockZkVerifier, ());
+    client.set_registration_verifier(admin, &verifier_id);
+    MockZkVerifierClient::new(env, &verifier_id)
+}
+
+fn adult_statement(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[18u8; 32])
+}
+
+fn gate_patients(env: &Env, client: &VisionRecordsContractClient, admin: &Address) {
+    client.set_registration_proof_requirement(
+        admin,
+        &Role::Patient,
+        &Some(adult_statement(env)),
+        &DAY,
+    );
+}
+
+#[test]
+fn test_self_registration_with_receipt() {
+    let (env, client, admin) = setup_test();
+    let verifier = install_verifier(&env, &client, &admin);
+    gate_patients(&env, &client, &admin);
+
+    let patient = Address::generate(&env);
+    verifier.issue(&patient);
+    client.register_user(
+        &patient,
+        &patient,
+        &Role::Patient,
+        &String::from_str(&env, "Adult Patient"),
+    );
+    assert_eq!(client.get_user(&patient).role, Role::Patient);
+}
+
+#[test]
+fn test_self_registration_without_receipt_rejected() {
+    let (env, client, admin) = setup_test();
+    install_verifier(&env, &client, &admin);
+    gate_patients(&env, &client, &admin);
+
+    let patient = Address::generate(&env);
+    let res = client.try_register_user(
+        &patient,
+        &patient,
+        &Role::Patient,
+        &String::from_str(&env, "No Proof"),
+    );
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::RegistrationProofRequired
+    );
+}
+
+#[test]
+fn test_ungated_role_cannot_self_register() {
+    let (env, client, admin) = setup_test();
+    let verifier = install_verifier(&env, &client, &admin);
+    gate_patients(&env, &client, &admin);
+
+    let provider = Address::generate(&env);
+    verifier.issue(&provider);
+    let res = client.try_register_user(
+        &provider,
+        &provider,
+        &Role::Optometrist,
+        &String::from_str(&env, "Dr. Self"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_custodian_registers_minor_without_proof() {
+    let (env, client, admin) = setup_test();
+    install_verifier(&env, &client, &admin);
+    gate_patients(&env, &client, &admin);
+
+    let minor = Address::generate(&env);
+    client.register_user(
+        &admin,
+        &minor,
+        &Role::Patient,
+        &String::from_str(&env, "Minor Patient"),
+    );
+    assert_eq!(client.get_user(&minor).role, Role::Patient);
+}
+
+#[test]
+fn test_requirement_can_be_cleared() {
+    let (env, client, admin) = setup_test();
+    let verifier = install_verifier(&env, &client, &admin);
+    gate_patients(&env, &client, &admin);
+    assert!(client
+        .get_registration_proof_requirement(&Role::Patient)
+        .is_some());
+
+    client.set_registration_proof_requirement(&admin, &Role::Patient, &None, &0);
+    assert!(client
+        .get_registration_proof_requirement(&Role::Patient)
+        .is_none());
+
+    let patient = Address::generate(&env);
+    verifier.issue(&patient);
+    let res = client.try_register_user(
+        &patient,
+        &patient,
+        &Role::Patient,
+        &String::from_str(&env, "Too Late"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_requirement_needs_verifier_and_admin() {
+    let (env, client, admin) = setup_test();
+
+    let res = client.try_set_registration_proof_requirement(
+        &admin,
+        &Role::Patient,
+        &Some(adult_statement(&env)),
+        &DAY,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let outsider = Address::generate(&env);
+    let res = client.try_set_registration_proof_requirement(&outsider, &Role::Patient, &None, &0);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_incompatible_verifier_rejected() {
+    let (env, client, admin) = setup_test();
+    install_verifier(&env, &client, &admin);
+    let original = client.get_registration_verifier();
+
+    let future_id = env.register(FutureZkVerifier, ());
+    let res = client.try_set_registration_verifier(&admin, &future_id);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::IncompatibleInterface
+    );
+
+    let unversioned_id = env.register(UnversionedContract, ());
+    let res = client.try_set_registration_verifier(&admin, &unversioned_id);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::IncompatibleInterface
+    );
+
+    assert_eq!(client.get_registration_verifier(), original);
+}
+
+#[test]
+fn test_reports_interface_version() {
+    let (_env, client, _admin) = setup_test();
+    assert_eq!(client.get_interface_version(), super::INTERFACE_VERSION);
+}
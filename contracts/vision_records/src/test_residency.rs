@@ -0,0 +1,116 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test, DATA_HASH};
+use super::{ContractError, RecordType, Role};
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, Address, String};
+
+const ORG: u64 = 7;
+
+#[test]
+fn test_tagged_write_allowed_without_policy() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Region");
+    client.set_provider_org(&admin, &provider, &ORG);
+    let patient = Address::generate(&env);
+    let id = client.add_record_with_residency(
+        &provider,
+        &patient,
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&env, DATA_HASH),
+        &symbol_short!("us"),
+    );
+    assert_eq!(client.get_record_residency(&id), Some(symbol_short!("us")));
+    assert_eq!(client.get_provider_org(&provider), Some(ORG));
+}
+
+#[test]
+fn test_policy_rejects_disallowed_region_at_write() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Region");
+    client.set_provider_org(&admin, &provider, &ORG);
+    let patient = Address::generate(&env);
+    client.set_residency_policy(&admin, &ORG, &vec![&env, symbol_short!("eu_west")]);
+
+    let res = client.try_add_record_with_residency(
+        &provider,
+        &patient,
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&env, DATA_HASH),
+        &symbol_short!("us"),
+    );
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::ResidencyNotAllowed
+    );
+
+    let id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let res = client.try_set_record_residency(&provider, &id, &symbol_short!("us"));
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::ResidencyNotAllowed
+    );
+    client.set_record_residency(&provider, &id, &symbol_short!("eu_west"));
+}
+
+#[test]
+fn test_policy_change_surfaces_violations() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Region");
+    client.set_provider_org(&admin, &provider, &ORG);
+    let patient = Address::generate(&env);
+    let us_id = client.add_record_with_residency(
+        &provider,
+        &patient,
+        &provider,
+        &RecordType::Examination,
+        &String::from_str(&env, DATA_HASH),
+        &symbol_short!("us"),
+    );
+    let untagged_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    assert!(client
+        .list_records_violating_policy(&admin, &ORG)
+        .is_empty());
+
+    client.set_residency_policy(&admin, &ORG, &vec![&env, symbol_short!("eu_west")]);
+    let violations = client.list_records_violating_policy(&admin, &ORG);
+    assert_eq!(violations.len(), 2);
+    let first = violations.get(0).unwrap();
+    assert_eq!(first.record_id, us_id);
+    assert_eq!(first.region, Some(symbol_short!("us")));
+    assert_eq!(violations.get(1).unwrap().record_id, untagged_id);
+    assert_eq!(violations.get(1).unwrap().region, None);
+
+    client.set_record_residency(&admin, &us_id, &symbol_short!("eu_west"));
+    client.set_record_residency(&provider, &untagged_id, &symbol_short!("eu_west"));
+    assert!(client
+        .list_records_violating_policy(&admin, &ORG)
+        .is_empty());
+}
+
+#[test]
+fn test_residency_admin_checks() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Region");
+    client.set_provider_org(&admin, &provider, &ORG);
+    let patient = Address::generate(&env);
+    let res = client.try_set_residency_policy(&provider, &ORG, &vec![&env, symbol_short!("us")]);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_set_residency_policy(&admin, &ORG, &vec![&env]);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let res = client.try_list_records_violating_policy(&provider, &ORG);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let stranger = Address::generate(&env);
+    let res = client.try_set_record_residency(&stranger, &id, &symbol_short!("us"));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
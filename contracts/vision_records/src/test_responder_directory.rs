@@ -0,0 +1,127 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, setup_test};
+use super::{ContractError, CredentialType, EmergencyCondition, Role, VisionRecordsContractClient};
+use soroban_sdk::{
+    testutils::Address as _, testutils::Ledger as _, Address, BytesN, Env, String, Vec,
+};
+
+const DAY: u64 = 86_400;
+
+/// An ER physician holding emergency credentials but absent from the
+/// provider registry, so break-glass only works through the directory.
+/// Returns the physician and a patient at timestamp 1_000.
+fn er_physician(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address) {
+    env.ledger().set_timestamp(1_000);
+    let physician = register_user(env, client, admin, Role::Ophthalmologist, "Dr. Emergency");
+    client.set_user_credential(admin, &physician, &CredentialType::EmergencyCredentials);
+
+    (physician, Address::generate(env))
+}
+
+fn facility(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[7u8; 32])
+}
+
+fn break_glass(
+    client: &VisionRecordsContractClient,
+    physician: &Address,
+    patient: &Address,
+    duration: u64,
+) -> Result<u64, ContractError> {
+    client
+        .try_grant_emergency_access(
+            physician,
+            patient,
+            &EmergencyCondition::Unconscious,
+            &String::from_str(&client.env, "Patient unconscious on arrival"),
+            &duration,
+            &Vec::new(&client.env),
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_unlisted_unverified_physician_is_refused() {
+    let (env, client, admin) = setup_test();
+    let (physician, patient) = er_physician(&env, &client, &admin);
+    assert_eq!(
+        break_glass(&client, &physician, &patient, 3600),
+        Err(ContractError::Unauthorized)
+    );
+}
+
+#[test]
+fn test_member_gets_full_window_and_membership_on_grant() {
+    let (env, client, admin) = setup_test();
+    let (physician, patient) = er_physician(&env, &client, &admin);
+    let membership = client.add_emergency_responder(&admin, &physician, &facility(&env));
+
+    let access_id = break_glass(&client, &physician, &patient, 3600).unwrap();
+    let access = client.get_emergency_access(&access_id);
+    assert_eq!(access.responder_id, Some(membership));
+    assert_eq!(access.expires_at, 1_000 + DAY);
+}
+
+#[test]
+fn test_removed_member_loses_fast_path() {
+    let (env, client, admin) = setup_test();
+    let (physician, patient) = er_physician(&env, &client, &admin);
+    client.add_emergency_responder(&admin, &physician, &facility(&env));
+    let access_id = break_glass(&client, &physician, &patient, 3600).unwrap();
+
+    client.remove_emergency_responder(&admin, &physician);
+    assert!(!client.get_emergency_responder(&physician).active);
+    assert!(client.get_emergency_responders().is_empty());
+    assert_eq!(
+        break_glass(&client, &physician, &patient, 3600),
+        Err(ContractError::Unauthorized)
+    );
+
+    // Grants issued while listed are untouched.
+    let access = client.get_emergency_access(&access_id);
+    assert!(access.responder_id.is_some());
+
+    let res = client.try_remove_emergency_responder(&admin, &physician);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::ResponderNotFound);
+}
+
+#[test]
+fn test_listing_requires_emergency_credentials() {
+    let (env, client, admin) = setup_test();
+    er_physician(&env, &client, &admin);
+    let other = Address::generate(&env);
+    let res = client.try_add_emergency_responder(&admin, &other, &facility(&env));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidRole);
+}
+
+#[test]
+fn test_duplicate_listing_rejected() {
+    let (env, client, admin) = setup_test();
+    let (physician, _patient) = er_physician(&env, &client, &admin);
+    client.add_emergency_responder(&admin, &physician, &facility(&env));
+    let res = client.try_add_emergency_responder(&admin, &physician, &facility(&env));
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::ResponderAlreadyListed
+    );
+    assert_eq!(client.get_emergency_responders().len(), 1);
+}
+
+#[test]
+fn test_only_system_admin_maintains_directory() {
+    let (env, client, admin) = setup_test();
+    let (physician, _patient) = er_physician(&env, &client, &admin);
+    let res = client.try_add_emergency_responder(&physician, &physician, &facility(&env));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
@@ -0,0 +1,146 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, advance, register_user, setup_test};
+use super::{ContractError, RecordType, Role};
+
+const YEAR: u64 = 31_536_000;
+
+// ======================== Policy Configuration ========================
+
+#[test]
+fn test_set_retention_policy_requires_admin() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Retention");
+
+    let res = client.try_set_retention_policy(&provider, &RecordType::Examination, &YEAR, &true);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    assert!(client
+        .get_retention_policy(&RecordType::Examination)
+        .is_none());
+}
+
+#[test]
+fn test_set_retention_policy_rejects_zero_window() {
+    let (_env, client, admin) = setup_test();
+
+    let res = client.try_set_retention_policy(&admin, &RecordType::Examination, &0, &true);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
+
+// ======================== Sweeper ========================
+
+#[test]
+fn test_apply_retention_archives_expired_records() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Retention");
+    let patient = register_user(&env, &client, &admin, Role::Patient, "Patient");
+    client.set_retention_policy(&admin, &RecordType::Examination, &(5 * YEAR), &true);
+
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+
+    // Not yet eligible.
+    assert_eq!(client.apply_retention(&10), 0);
+    client.get_record(&patient, &record_id);
+
+    advance(&env, 5 * YEAR + 1);
+    assert_eq!(client.apply_retention(&10), 1);
+
+    let summary = client.get_archival_summary(&patient, &record_id);
+    assert_eq!(summary.patient, patient);
+    assert_eq!(summary.record_type, RecordType::Examination);
+
+    let res = client.try_get_record(&patient, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordArchived);
+}
+
+#[test]
+fn test_apply_retention_respects_limit() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Retention");
+    let patient = register_user(&env, &client, &admin, Role::Patient, "Patient");
+    client.set_retention_policy(&admin, &RecordType::Examination, &YEAR, &true);
+
+    for _ in 0..3 {
+        add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    }
+    advance(&env, YEAR + 1);
+
+    assert_eq!(client.apply_retention(&2), 2);
+    assert_eq!(client.apply_retention(&2), 1);
+}
+
+#[test]
+fn test_disabled_policy_does_not_archive() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Retention");
+    let patient = register_user(&env, &client, &admin, Role::Patient, "Patient");
+    client.set_retention_policy(&admin, &RecordType::Examination, &YEAR, &false);
+
+    add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    advance(&env, YEAR + 1);
+
+    assert_eq!(client.apply_retention(&10), 0);
+}
+
+#[test]
+fn test_patient_opt_out_extends_retention() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Retention");
+    let patient = register_user(&env, &client, &admin, Role::Patient, "Patient");
+    client.set_retention_policy(&admin, &RecordType::Examination, &YEAR, &true);
+
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    client.set_retention_opt_out(&patient, &true);
+    assert!(client.is_retention_opted_out(&patient));
+
+    advance(&env, YEAR + 1);
+    assert_eq!(client.apply_retention(&10), 0);
+    client.get_record(&patient, &record_id);
+
+    // Opting out lengthens the window rather than exempting the record
+    advance(&env, YEAR);
+    assert_eq!(client.apply_retention(&10), 1);
+    assert_eq!(
+        client.get_archival_summary(&patient, &record_id).record_id,
+        record_id
+    );
+}
+
+#[test]
+fn test_archival_summary_needs_record_access() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Retention");
+    let patient = register_user(&env, &client, &admin, Role::Patient, "Patient");
+    let stranger = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Stranger");
+    client.set_retention_policy(&admin, &RecordType::Examination, &YEAR, &true);
+
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    advance(&env, YEAR + 1);
+    assert_eq!(client.apply_retention(&10), 1);
+
+    client.get_archival_summary(&provider, &record_id);
+    let res = client.try_get_archival_summary(&stranger, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_open_episode_blocks_archival() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Retention");
+    let patient = register_user(&env, &client, &admin, Role::Patient, "Patient");
+    client.set_retention_policy(&admin, &RecordType::Examination, &YEAR, &true);
+
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    client.set_record_episode_open(&provider, &record_id, &true);
+
+    advance(&env, YEAR + 1);
+    assert_eq!(client.apply_retention(&10), 0);
+
+    client.set_record_episode_open(&provider, &record_id, &false);
+    assert_eq!(client.apply_retention(&10), 1);
+}
@@ -0,0 +1,151 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, setup_test};
+use super::{
+    ContractError, LensType, OptionalContactLensData, PrescriptionData, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, Address, Env, String,
+};
+
+const YEAR: u64 = 31_536_000;
+
+/// Claims contract stand-in: claims count as adjudicated once recorded.
+#[contract]
+struct MockClaims;
+
+#[contractimpl]
+impl MockClaims {
+    pub fn adjudicate(env: Env, claim_id: u64, payer: Address, patient: Address, rx_id: u64) {
+        env.storage()
+            .persistent()
+            .set(&(claim_id, payer, patient, rx_id), &true);
+    }
+
+    pub fn is_rx_claim_adjudicated(
+        env: Env,
+        claim_id: u64,
+        payer: Address,
+        patient: Address,
+        rx_id: u64,
+    ) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(claim_id, payer, patient, rx_id))
+            .unwrap_or(false)
+    }
+}
+
+/// Installs the mock claims contract, registers a payer and writes a glasses
+/// prescription. Returns the claims client, payer, patient and prescription id.
+fn glasses_prescription(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (MockClaimsClient<'static>, Address, Address, u64) {
+    let claims_id = env.register(MockClaims, ());
+    client.set_claims_contract(admin, &claims_id);
+
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Lens");
+    let payer = register_user(env, client, admin, Role::Payer, "Vision Benefits Co");
+
+    let patient = Address::generate(env);
+    let eye = PrescriptionData {
+        sphere: String::from_str(env, "-2.00"),
+        cylinder: String::from_str(env, "-0.25"),
+        axis: String::from_str(env, "180"),
+        add: String::from_str(env, "0.00"),
+        pd: String::from_str(env, "63"),
+        prism: String::from_str(env, ""),
+        prism_base: String::from_str(env, ""),
+    };
+    let rx_id = client.add_prescription(
+        &patient,
+        &provider,
+        &LensType::Glasses,
+        &eye,
+        &eye,
+        &OptionalContactLensData::None,
+        &YEAR,
+        &String::from_str(env, "metadata_hash"),
+    );
+
+    (
+        MockClaimsClient::new(env, &claims_id),
+        payer,
+        patient,
+        rx_id,
+    )
+}
+
+fn plan(env: &Env) -> String {
+    String::from_str(env, "PLAN-GOLD-2291")
+}
+
+#[test]
+fn test_payer_records_coverage_after_adjudication() {
+    let (env, client, admin) = setup_test();
+    let (claims, payer, patient, rx_id) = glasses_prescription(&env, &client, &admin);
+    assert_eq!(client.get_rx_coverage(&rx_id), None);
+
+    let res =
+        client.try_set_rx_coverage(&payer, &rx_id, &501, &plan(&env), &symbol_short!("LENSES"));
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::ClaimNotAdjudicated
+    );
+
+    claims.adjudicate(&501, &payer, &patient, &rx_id);
+    let coverage =
+        client.set_rx_coverage(&payer, &rx_id, &501, &plan(&env), &symbol_short!("LENSES"));
+    assert_eq!(coverage.category_code, symbol_short!("LENSES"));
+    assert_eq!(client.get_rx_coverage(&rx_id), Some(coverage));
+}
+
+#[test]
+fn test_only_payers_record_coverage() {
+    let (env, client, admin) = setup_test();
+    let (claims, _payer, patient, rx_id) = glasses_prescription(&env, &client, &admin);
+    let shop = Address::generate(&env);
+    claims.adjudicate(&7, &shop, &patient, &rx_id);
+
+    let res = client.try_set_rx_coverage(&shop, &rx_id, &7, &plan(&env), &symbol_short!("FRAMES"));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_claim_for_another_prescription_is_refused() {
+    let (env, client, admin) = setup_test();
+    let (claims, payer, patient, rx_id) = glasses_prescription(&env, &client, &admin);
+    claims.adjudicate(&9, &payer, &patient, &(rx_id + 1));
+
+    let res = client.try_set_rx_coverage(&payer, &rx_id, &9, &plan(&env), &symbol_short!("FRAMES"));
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::ClaimNotAdjudicated
+    );
+
+    let res = client.try_set_rx_coverage(&payer, &999, &9, &plan(&env), &symbol_short!("FRAMES"));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+}
+
+#[test]
+fn test_only_recording_payer_clears_coverage() {
+    let (env, client, admin) = setup_test();
+    let (claims, payer, patient, rx_id) = glasses_prescription(&env, &client, &admin);
+    claims.adjudicate(&11, &payer, &patient, &rx_id);
+    client.set_rx_coverage(&payer, &rx_id, &11, &plan(&env), &symbol_short!("LENSES"));
+
+    let other = Address::generate(&env);
+    let res = client.try_clear_rx_coverage(&other, &rx_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.clear_rx_coverage(&payer, &rx_id);
+    assert_eq!(client.get_rx_coverage(&rx_id), None);
+}
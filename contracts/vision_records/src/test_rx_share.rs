@@ -0,0 +1,228 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, setup_test};
+use super::{
+    ContractError, LensType, OptionalContactLensData, PrescriptionData, PrescriptionShareScope,
+    Role, ShareMethod, VisionRecordsContractClient,
+};
+use soroban_sdk::{
+    contract, contractimpl, testutils::Address as _, testutils::Ledger as _, Address, Bytes,
+    BytesN, Env, String,
+};
+use teye_common::interface::{self, InterfaceVersion};
+
+const DAY: u64 = 86_400;
+const YEAR: u64 = 31_536_000;
+
+/// Minimal zk verifier: receipts are issued per (user, resource) and consumed once.
+#[contract]
+struct MockZkVerifier;
+
+#[contractimpl]
+impl MockZkVerifier {
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        InterfaceVersion {
+            interface: interface::ZK_VERIFIER,
+            major: 1,
+            minor: 0,
+        }
+    }
+
+    pub fn issue(env: Env, user: Address, resource_id: BytesN<32>) {
+        env.storage().persistent().set(&(user, resource_id), &true);
+    }
+
+    pub fn consume_receipt(
+        env: Env,
+        _consumer: Address,
+        user: Address,
+        resource_id: BytesN<32>,
+        _max_age: u64,
+    ) -> bool {
+        let key = (user, resource_id);
+        let issued = env.storage().persistent().get(&key).unwrap_or(false);
+        env.storage().persistent().remove(&key);
+        issued
+    }
+}
+
+/// Writes a one-year glasses prescription and returns the patient and its id.
+fn glasses_prescription(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, u64) {
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Lens");
+    let patient = Address::generate(env);
+    let eye = PrescriptionData {
+        sphere: String::from_str(env, "-1.25"),
+        cylinder: String::from_str(env, "-0.50"),
+        axis: String::from_str(env, "90"),
+        add: String::from_str(env, "0.00"),
+        pd: String::from_str(env, "62"),
+        prism: String::from_str(env, ""),
+        prism_base: String::from_str(env, ""),
+    };
+    let rx_id = client.add_prescription(
+        &patient,
+        &provider,
+        &LensType::Glasses,
+        &eye,
+        &eye,
+        &OptionalContactLensData::None,
+        &YEAR,
+        &String::from_str(env, "metadata_hash"),
+    );
+
+    (patient, rx_id)
+}
+
+fn retailer_hash(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[0xAB; 32])
+}
+
+fn share_code(env: &Env) -> (Bytes, BytesN<32>) {
+    let code = Bytes::from_slice(env, b"RX-7731-CODE");
+    let hash: BytesN<32> = env.crypto().sha256(&code).into();
+    (code, hash)
+}
+
+#[test]
+fn test_share_code_produces_receipt_once() {
+    let (&env, client, admin) = setup_test();
+    let (patient, rx_id) = glasses_prescription(&env, &client, &admin);
+    let retailer = Address::generate(&env);
+    let (code, hash) = share_code(&env);
+    client.issue_prescription_share_code(
+        &patient,
+        &rx_id,
+        &hash,
+        &PrescriptionShareScope::FullPrescription,
+        &DAY,
+    );
+
+    let receipt =
+        client.verify_prescription_with_code(&retailer, &retailer_hash(&env), &rx_id, &code);
+    assert_eq!(receipt.patient, patient);
+    assert_eq!(receipt.scope, PrescriptionShareScope::FullPrescription);
+    assert_eq!(receipt.method, ShareMethod::ShareCode);
+
+    let receipts = client.get_prescription_receipts(&patient);
+    assert_eq!(receipts.len(), 1);
+    assert_eq!(receipts.get(0).unwrap().retailer_hash, retailer_hash(&env));
+
+    let res =
+        client.try_verify_prescription_with_code(&retailer, &retailer_hash(&env), &rx_id, &code);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::InvalidShareCredential
+    );
+}
+
+#[test]
+fn test_wrong_or_expired_code_rejected() {
+    let (&env, client, admin) = setup_test();
+    let (patient, rx_id) = glasses_prescription(&env, &client, &admin);
+    let retailer = Address::generate(&env);
+    let (_code, hash) = share_code(&env);
+    client.issue_prescription_share_code(
+        &patient,
+        &rx_id,
+        &hash,
+        &PrescriptionShareScope::ValidityOnly,
+        &DAY,
+    );
+
+    let res = client.try_verify_prescription_with_code(
+        &retailer,
+        &retailer_hash(&env),
+        &rx_id,
+        &Bytes::from_slice(&env, b"guess"),
+    );
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::InvalidShareCredential
+    );
+
+    env.ledger().with_mut(|li| li.timestamp = DAY + 1);
+    let (code, _) = share_code(&env);
+    let res =
+        client.try_verify_prescription_with_code(&retailer, &retailer_hash(&env), &rx_id, &code);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::InvalidShareCredential
+    );
+    assert_eq!(client.get_prescription_receipts(&patient).len(), 0);
+}
+
+#[test]
+fn test_only_owner_issues_codes() {
+    let (&env, client, admin) = setup_test();
+    let (_patient, rx_id) = glasses_prescription(&env, &client, &admin);
+    let retailer = Address::generate(&env);
+    let (_code, hash) = share_code(&env);
+    let res = client.try_issue_prescription_share_code(
+        &retailer,
+        &rx_id,
+        &hash,
+        &PrescriptionShareScope::FullPrescription,
+        &DAY,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_proof_path_consumes_verifier_receipt() {
+    let (&env, client, admin) = setup_test();
+    let (patient, rx_id) = glasses_prescription(&env, &client, &admin);
+    let retailer = Address::generate(&env);
+    let verifier_id = env.register(MockZkVerifier, ());
+    let verifier = MockZkVerifierClient::new(&env, &verifier_id);
+    client.set_registration_verifier(&admin, &verifier_id);
+
+    let rx = client.get_prescription(&rx_id);
+    let res = client.try_verify_prescription_with_proof(
+        &retailer,
+        &retailer_hash(&env),
+        &rx_id,
+        &PrescriptionShareScope::ValidityOnly,
+    );
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::InvalidShareCredential
+    );
+
+    verifier.issue(&patient, &rx.canonical_hash);
+    let receipt = client.verify_prescription_with_proof(
+        &retailer,
+        &retailer_hash(&env),
+        &rx_id,
+        &PrescriptionShareScope::ValidityOnly,
+    );
+    assert_eq!(receipt.method, ShareMethod::ZkProof);
+    assert_eq!(client.get_prescription_receipts(&patient).len(), 1);
+}
+
+#[test]
+fn test_expired_prescription_cannot_be_shared() {
+    let (&env, client, admin) = setup_test();
+    let (patient, rx_id) = glasses_prescription(&env, &client, &admin);
+    let (_code, hash) = share_code(&env);
+    env.ledger().with_mut(|li| li.timestamp = YEAR + 1);
+    let res = client.try_issue_prescription_share_code(
+        &patient,
+        &rx_id,
+        &hash,
+        &PrescriptionShareScope::FullPrescription,
+        &(YEAR + DAY),
+    );
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::PrescriptionExpired
+    );
+}
@@ -0,0 +1,236 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{
+    AccessLevel, AccessScope, ConsentType, ContractError, DisclosureTier, GrantStatus, RecordType,
+    Role, VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+const DAY: u64 = 86_400;
+
+/// A patient with one examination and one prescription record, and a
+/// registered grantee holding the patient's consent but no grant yet.
+/// Returns the patient, grantee and both record ids.
+fn exam_and_prescription(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, u64, u64) {
+    let provider = register_user(env, client, admin, Role::Optometrist, "Dr. Scope");
+    let patient = Address::generate(env);
+    let exam_id = add_record(env, client, &provider, &patient, RecordType::Examination);
+    let rx_id = add_record(env, client, &provider, &patient, RecordType::Prescription);
+
+    let grantee = register_user(env, client, admin, Role::Optometrist, "Dr. Grantee");
+    client.grant_consent(&patient, &grantee, &ConsentType::Treatment, &(30 * DAY));
+    (patient, grantee, exam_id, rx_id)
+}
+
+#[test]
+fn test_type_scoped_grant_covers_only_that_type() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee, exam_id, rx_id) = exam_and_prescription(&env, &client, &admin);
+    client.grant_scoped_access(
+        &patient,
+        &grantee,
+        &AccessScope::RecordType(RecordType::Prescription),
+        &AccessLevel::Read,
+        &DAY,
+    );
+
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &Some(RecordType::Prescription)),
+        AccessLevel::Read
+    );
+    assert_eq!(
+        client.check_access(&patient, &grantee, &Some(rx_id), &None),
+        AccessLevel::Read
+    );
+    assert_eq!(
+        client.check_access(&patient, &grantee, &Some(exam_id), &None),
+        AccessLevel::None
+    );
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &None),
+        AccessLevel::None
+    );
+
+    // Reads follow the same scope
+    assert_eq!(client.read_record(&grantee, &rx_id).id, rx_id);
+    let res = client.try_read_record(&grantee, &exam_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::AccessDenied);
+}
+
+#[test]
+fn test_narrowest_grant_decides() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee, exam_id, rx_id) = exam_and_prescription(&env, &client, &admin);
+    client.grant_access(&patient, &patient, &grantee, &AccessLevel::Write, &DAY);
+    client.grant_scoped_access(
+        &patient,
+        &grantee,
+        &AccessScope::RecordType(RecordType::Prescription),
+        &AccessLevel::Read,
+        &DAY,
+    );
+    client.grant_scoped_access(
+        &patient,
+        &grantee,
+        &AccessScope::Record(rx_id),
+        &AccessLevel::Admin,
+        &DAY,
+    );
+
+    assert_eq!(
+        client.check_access(&patient, &grantee, &Some(rx_id), &None),
+        AccessLevel::Admin
+    );
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &Some(RecordType::Prescription)),
+        AccessLevel::Read
+    );
+    // No scoped grant on examinations: the patient-wide grant applies
+    assert_eq!(
+        client.check_access(&patient, &grantee, &Some(exam_id), &None),
+        AccessLevel::Write
+    );
+}
+
+#[test]
+fn test_revoked_scope_stops_applying() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee, _exam_id, rx_id) = exam_and_prescription(&env, &client, &admin);
+    let scope = AccessScope::RecordType(RecordType::Prescription);
+    client.grant_scoped_access(&patient, &grantee, &scope, &AccessLevel::Read, &DAY);
+
+    client.revoke_scoped_access(&patient, &grantee, &scope);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &Some(rx_id), &None),
+        AccessLevel::None
+    );
+
+    let grants = client.get_scoped_grants(&patient, &grantee);
+    assert_eq!(grants.len(), 1);
+    assert_eq!(grants.get(0).unwrap().status, GrantStatus::Revoked);
+
+    let res = client.try_revoke_scoped_access(&patient, &grantee, &scope);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::AccessGrantNotFound
+    );
+}
+
+#[test]
+fn test_record_scope_requires_patients_record() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee, _exam_id, rx_id) = exam_and_prescription(&env, &client, &admin);
+    let stranger = Address::generate(&env);
+    let res = client.try_grant_scoped_access(
+        &stranger,
+        &grantee,
+        &AccessScope::Record(rx_id),
+        &AccessLevel::Read,
+        &DAY,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_grant_scoped_access(
+        &patient,
+        &grantee,
+        &AccessScope::Record(999),
+        &AccessLevel::Read,
+        &DAY,
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+}
+
+#[test]
+fn test_record_tier_follows_narrowest_grant() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee, exam_id, rx_id) = exam_and_prescription(&env, &client, &admin);
+    client.grant_access(&patient, &patient, &grantee, &AccessLevel::Write, &DAY);
+    client.grant_scoped_access(
+        &patient,
+        &grantee,
+        &AccessScope::RecordType(RecordType::Prescription),
+        &AccessLevel::Read,
+        &DAY,
+    );
+
+    assert_eq!(
+        client.check_access(&patient, &grantee, &Some(rx_id), &None),
+        AccessLevel::Read
+    );
+    assert_eq!(
+        client.get_record_tier(&grantee, &rx_id),
+        DisclosureTier::Summary
+    );
+    assert_eq!(
+        client.get_record_tier(&grantee, &exam_id),
+        DisclosureTier::Full
+    );
+}
+
+#[test]
+fn test_record_grant_only_counts_for_its_patient() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee, _exam_id, rx_id) = exam_and_prescription(&env, &client, &admin);
+    client.grant_record_access(&patient, &grantee, &rx_id, &AccessLevel::Write, &DAY);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &Some(rx_id), &None),
+        AccessLevel::Write
+    );
+
+    let other_patient = Address::generate(&env);
+    assert_eq!(
+        client.check_access(&other_patient, &grantee, &Some(rx_id), &None),
+        AccessLevel::None
+    );
+}
+
+#[test]
+fn test_scoped_grants_follow_consent_and_user_status() {
+    let (env, client, admin) = setup_test();
+    let (patient, grantee, _exam_id, rx_id) = exam_and_prescription(&env, &client, &admin);
+    client.grant_scoped_access(
+        &patient,
+        &grantee,
+        &AccessScope::RecordType(RecordType::Prescription),
+        &AccessLevel::Read,
+        &DAY,
+    );
+    client.grant_record_access(&patient, &grantee, &rx_id, &AccessLevel::Write, &DAY);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &Some(rx_id), &None),
+        AccessLevel::Write
+    );
+
+    // Without consent no record-level grant applies
+    client.revoke_consent(&patient, &grantee);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &Some(rx_id), &None),
+        AccessLevel::None
+    );
+    assert_eq!(
+        client.get_record_tier(&grantee, &rx_id),
+        DisclosureTier::None
+    );
+
+    // Nor for a deactivated grantee, even with consent restored
+    client.grant_consent(&patient, &grantee, &ConsentType::Treatment, &DAY);
+    client.deactivate_user(&admin, &grantee);
+    assert_eq!(
+        client.check_access(&patient, &grantee, &None, &Some(RecordType::Prescription)),
+        AccessLevel::None
+    );
+    assert_eq!(
+        client.check_access(&patient, &grantee, &Some(rx_id), &None),
+        AccessLevel::None
+    );
+}
@@ -0,0 +1,128 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{
+    add_record, register_user, register_verified_provider, setup_test, DATA_HASH,
+};
+use super::{
+    AccessLevel, ConsentType, ContractError, DisclosureTier, EmergencyCondition, RecordType, Role,
+    SensitivityLevel, VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, String, Vec};
+
+const DAY: u64 = 86_400;
+
+/// A Restricted examination record written at timestamp 1_000. Returns the
+/// author, patient and record id.
+fn restricted_exam(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, u64) {
+    env.ledger().set_timestamp(1_000);
+    let author = register_user(env, client, admin, Role::Optometrist, "Dr. Author");
+    let patient = Address::generate(env);
+
+    let record_id = add_record(env, client, &author, &patient, RecordType::Examination);
+    client.set_record_sensitivity(&patient, &record_id, &SensitivityLevel::Restricted);
+
+    (author, patient, record_id)
+}
+
+fn patient_wide_grantee(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+    patient: &Address,
+) -> Address {
+    let grantee = register_user(env, client, admin, Role::Optometrist, "Dr. Grantee");
+    client.grant_consent(patient, &grantee, &ConsentType::Treatment, &(30 * DAY));
+    client.grant_access(patient, patient, &grantee, &AccessLevel::Write, &(30 * DAY));
+    grantee
+}
+
+#[test]
+fn test_patient_wide_grants_do_not_open_restricted_records() {
+    let (env, client, admin) = setup_test();
+    let (author, patient, record_id) = restricted_exam(&env, &client, &admin);
+    let grantee = patient_wide_grantee(&env, &client, &admin, &patient);
+
+    assert_eq!(
+        client.get_record_tier(&grantee, &record_id),
+        DisclosureTier::None
+    );
+    let res = client.try_get_record(&grantee, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    // Standard records of the same patient are unaffected
+    let other = add_record(&env, &client, &author, &patient, RecordType::Examination);
+    assert_eq!(
+        client.get_record_tier(&grantee, &other),
+        DisclosureTier::Full
+    );
+}
+
+#[test]
+fn test_record_grant_opens_restricted_record_with_receipt() {
+    let (env, client, admin) = setup_test();
+    let (_author, patient, record_id) = restricted_exam(&env, &client, &admin);
+    let grantee = patient_wide_grantee(&env, &client, &admin, &patient);
+    client.grant_record_access(&patient, &grantee, &record_id, &AccessLevel::Write, &DAY);
+
+    let record = client.get_record(&grantee, &record_id);
+    assert_eq!(record.data_hash, String::from_str(&env, DATA_HASH));
+
+    // The patient's own reads leave no receipt
+    client.get_record(&patient, &record_id);
+
+    let receipts = client.get_read_receipts(&patient, &record_id);
+    assert_eq!(receipts.len(), 1);
+    let receipt = receipts.get(0).unwrap();
+    assert_eq!(receipt.reader, grantee);
+    assert_eq!(receipt.read_at, 1_000);
+
+    let res = client.try_get_read_receipts(&grantee, &record_id);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+}
+
+#[test]
+fn test_emergency_grants_see_existence_only() {
+    let (env, client, admin) = setup_test();
+    let (_author, patient, record_id) = restricted_exam(&env, &client, &admin);
+    let responder = register_verified_provider(&env, &client, &admin, "Dr. Responder");
+    client.grant_emergency_access(
+        &responder,
+        &patient,
+        &EmergencyCondition::Unconscious,
+        &String::from_str(&env, "Patient unconscious"),
+        &3600,
+        &Vec::new(&env),
+    );
+
+    // Examinations are in the default emergency scope
+    assert_eq!(
+        client.get_record_tier(&responder, &record_id),
+        DisclosureTier::Existence
+    );
+}
+
+#[test]
+fn test_only_patient_provider_or_admin_set_sensitivity() {
+    let (env, client, admin) = setup_test();
+    let (author, patient, record_id) = restricted_exam(&env, &client, &admin);
+    let stranger = Address::generate(&env);
+
+    let res = client.try_set_record_sensitivity(&stranger, &record_id, &SensitivityLevel::Standard);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.set_record_sensitivity(&author, &record_id, &SensitivityLevel::Standard);
+    let grantee = patient_wide_grantee(&env, &client, &admin, &patient);
+    assert_eq!(
+        client.get_record_tier(&grantee, &record_id),
+        DisclosureTier::Full
+    );
+}
@@ -0,0 +1,163 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{
+    AccessLevel, ConsentType, ContractError, LensType, OptionalContactLensData, PrescriptionData,
+    RecordType, Role, StateChangeKind,
+};
+use crate::snapshot::MAX_CHANGE_LOG;
+use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+const DAY: u64 = 86_400;
+
+fn eye(env: &Env) -> PrescriptionData {
+    PrescriptionData {
+        sphere: String::from_str(env, "-1.75"),
+        cylinder: String::from_str(env, "-0.50"),
+        axis: String::from_str(env, "90"),
+        add: String::from_str(env, "0.00"),
+        pd: String::from_str(env, "62"),
+        prism: String::from_str(env, ""),
+        prism_base: String::from_str(env, ""),
+    }
+}
+
+#[test]
+fn test_snapshot_counts_full_footprint() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Snapshot");
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+
+    add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    client.add_prescription(
+        &patient,
+        &provider,
+        &LensType::Glasses,
+        &eye(&env),
+        &eye(&env),
+        &OptionalContactLensData::None,
+        &(365 * DAY),
+        &String::from_str(&env, "metadata_hash"),
+    );
+    client.grant_access(
+        &patient,
+        &patient,
+        &grantee,
+        &AccessLevel::Read,
+        &(30 * DAY),
+    );
+    client.grant_consent(&patient, &grantee, &ConsentType::Treatment, &(30 * DAY));
+
+    let digest = client.snapshot_patient_state(&patient);
+    assert_eq!(digest.record_count, 2);
+    assert_eq!(digest.prescription_count, 1);
+    assert_eq!(digest.grant_count, 1);
+    assert_eq!(digest.consent_count, 1);
+    assert_eq!(digest.version, 5);
+    assert_eq!(client.get_patient_state_version(&patient), 5);
+}
+
+#[test]
+fn test_digest_is_stable_until_state_changes() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Snapshot");
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+    add_record(&env, &client, &provider, &patient, RecordType::Examination);
+
+    let first = client.snapshot_patient_state(&patient);
+    let again = client.snapshot_patient_state(&patient);
+    assert_eq!(first.digest, again.digest);
+
+    client.grant_access(
+        &patient,
+        &patient,
+        &grantee,
+        &AccessLevel::Read,
+        &(30 * DAY),
+    );
+    let granted = client.snapshot_patient_state(&patient);
+    assert_ne!(first.digest, granted.digest);
+
+    // Revoked grants stay on-chain for restore, so they still count.
+    client.revoke_access(&patient, &grantee);
+    let revoked = client.snapshot_patient_state(&patient);
+    assert_eq!(revoked.grant_count, 1);
+    assert_ne!(revoked.digest, granted.digest);
+    assert!(revoked.version > granted.version);
+}
+
+#[test]
+fn test_changes_since_cursor() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Snapshot");
+    let patient = Address::generate(&env);
+    let grantee = Address::generate(&env);
+
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let cursor = client.get_patient_state_version(&patient);
+    client.grant_consent(&patient, &grantee, &ConsentType::Treatment, &(30 * DAY));
+    client.revoke_consent(&patient, &grantee);
+
+    let all = client.get_patient_changes_since(&patient, &0);
+    assert_eq!(all.len(), 3);
+    let first = all.get(0).unwrap();
+    assert_eq!(first.kind, StateChangeKind::RecordAdded);
+    assert_eq!(first.ref_id, Some(record_id));
+
+    let changes = client.get_patient_changes_since(&patient, &cursor);
+    assert_eq!(changes.len(), 2);
+    assert_eq!(
+        changes.get(0).unwrap().kind,
+        StateChangeKind::ConsentGranted
+    );
+    assert_eq!(
+        changes.get(1).unwrap().kind,
+        StateChangeKind::ConsentRevoked
+    );
+    assert_eq!(changes.get(1).unwrap().counterparty, Some(grantee));
+
+    let latest = client.get_patient_state_version(&patient);
+    assert!(client
+        .get_patient_changes_since(&patient, &latest)
+        .is_empty());
+}
+
+#[test]
+fn test_stale_cursor_requires_full_resync() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Snapshot");
+    let patient = Address::generate(&env);
+    for _ in 0..(MAX_CHANGE_LOG + 2) {
+        add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    }
+
+    let res = client.try_get_patient_changes_since(&patient, &0);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::StateCursorExpired);
+
+    let digest = client.snapshot_patient_state(&patient);
+    assert_eq!(digest.record_count, MAX_CHANGE_LOG + 2);
+    let changes = client.get_patient_changes_since(&patient, &(digest.version - 10));
+    assert_eq!(changes.len(), 10);
+}
+
+#[test]
+fn test_unrelated_patient_unaffected() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Snapshot");
+    let patient = Address::generate(&env);
+    let other = Address::generate(&env);
+    let before = client.snapshot_patient_state(&other);
+
+    add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let after = client.snapshot_patient_state(&other);
+    assert_eq!(before.digest, after.digest);
+    assert_eq!(after.version, 0);
+}
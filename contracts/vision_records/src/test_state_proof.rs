@@ -0,0 +1,36 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{RecordType, Role};
+use soroban_sdk::{symbol_short, testutils::Address as _, vec, xdr::ToXdr, Address, BytesN, Vec};
+use teye_common::storage_ttl::StorageKeySpec;
+
+#[test]
+fn test_patient_index_proof_matches_stored_ids() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Mirror");
+    let patient = Address::generate(&env);
+    let first = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let second = add_record(&env, &client, &provider, &patient, RecordType::Prescription);
+
+    let spec = StorageKeySpec::Address(symbol_short!("PAT_REC"), patient.clone());
+    let proof = client.prove_entry(&spec).unwrap();
+
+    let ids: Vec<u64> = vec![&env, first, second];
+    assert_eq!(proof.contract, client.address);
+    assert_eq!(proof.value_xdr, ids.to_xdr(&env));
+    assert_eq!(
+        proof.key_xdr,
+        (symbol_short!("PAT_REC"), patient).to_xdr(&env)
+    );
+    let expected: BytesN<32> = env.crypto().sha256(&proof.value_xdr).into();
+    assert_eq!(proof.entry_hash, expected);
+
+    let unknown = StorageKeySpec::Address(symbol_short!("PAT_REC"), Address::generate(&env));
+    assert_eq!(client.prove_entry(&unknown), None);
+}
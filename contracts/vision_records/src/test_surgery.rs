@@ -0,0 +1,124 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{ContractError, RecordType, Role, SurgeryStatus, VisionRecordsContractClient};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, String};
+
+const DOC_HASH: &str = "QmSurgeryStepDocumentHash000000000000000";
+
+/// A surgeon, a co-managing optometrist and a patient with one surgery
+/// record, at timestamp 1_000. Returns all three and the record id.
+fn surgery_record(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+) -> (Address, Address, Address, u64) {
+    env.ledger().set_timestamp(1_000);
+    let surgeon = register_user(env, client, admin, Role::Ophthalmologist, "Dr. Surgeon");
+    let optometrist = register_user(env, client, admin, Role::Optometrist, "Dr. Comanage");
+
+    let patient = Address::generate(env);
+    let record_id = add_record(env, client, &surgeon, &patient, RecordType::Surgery);
+
+    (surgeon, optometrist, patient, record_id)
+}
+
+#[test]
+fn test_surgery_workflow_in_order() {
+    let (env, client, admin) = setup_test();
+    let (surgeon, optometrist, patient, record_id) = surgery_record(&env, &client, &admin);
+    let doc = String::from_str(&env, DOC_HASH);
+    let case = client.schedule_surgery(&surgeon, &record_id, &5_000);
+    assert_eq!(case.status, SurgeryStatus::Scheduled);
+    assert_eq!(case.patient, patient);
+
+    let case = client.record_preop_clearance(&optometrist, &case.id, &doc);
+    assert_eq!(case.status, SurgeryStatus::PreOpCleared);
+    assert_eq!(case.preop_clearance.unwrap().provider, optometrist);
+
+    env.ledger().set_timestamp(5_000);
+    let case = client.record_surgery_outcome(&surgeon, &case.id, &doc);
+    assert_eq!(case.status, SurgeryStatus::Performed);
+
+    let case = client.record_postop_followup(&optometrist, &case.id, &doc);
+    assert_eq!(case.status, SurgeryStatus::FollowedUp);
+    assert!(case.postop_followup.is_some());
+    assert_eq!(client.get_surgery(&case.id).unwrap(), case);
+    assert_eq!(client.get_patient_surgeries(&patient).len(), 1);
+}
+
+#[test]
+fn test_steps_cannot_be_skipped() {
+    let (env, client, admin) = setup_test();
+    let (surgeon, optometrist, _patient, record_id) = surgery_record(&env, &client, &admin);
+    let doc = String::from_str(&env, DOC_HASH);
+    let case = client.schedule_surgery(&surgeon, &record_id, &5_000);
+
+    let res = client.try_record_surgery_outcome(&surgeon, &case.id, &doc);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::SurgeryStepOutOfOrder
+    );
+    let res = client.try_record_postop_followup(&optometrist, &case.id, &doc);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::SurgeryStepOutOfOrder
+    );
+
+    client.record_preop_clearance(&optometrist, &case.id, &doc);
+    let res = client.try_record_preop_clearance(&optometrist, &case.id, &doc);
+    assert_eq!(
+        res.unwrap_err().unwrap(),
+        ContractError::SurgeryStepOutOfOrder
+    );
+}
+
+#[test]
+fn test_surgery_role_gates() {
+    let (env, client, admin) = setup_test();
+    let (surgeon, optometrist, patient, record_id) = surgery_record(&env, &client, &admin);
+    let doc = String::from_str(&env, DOC_HASH);
+
+    let opt_record = add_record(&env, &client, &optometrist, &patient, RecordType::Surgery);
+    let res = client.try_schedule_surgery(&optometrist, &opt_record, &5_000);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let exam = add_record(&env, &client, &surgeon, &patient, RecordType::Examination);
+    let res = client.try_schedule_surgery(&surgeon, &exam, &5_000);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidRecordType);
+
+    let case = client.schedule_surgery(&surgeon, &record_id, &5_000);
+    let res = client.try_record_preop_clearance(&patient, &case.id, &doc);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.record_preop_clearance(&optometrist, &case.id, &doc);
+    let res = client.try_record_surgery_outcome(&optometrist, &case.id, &doc);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_record_surgery_outcome(&surgeon, &99, &doc);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::SurgeryNotFound);
+}
+
+#[test]
+fn test_incomplete_surgeries_are_listed() {
+    let (env, client, admin) = setup_test();
+    let (surgeon, optometrist, _patient, record_id) = surgery_record(&env, &client, &admin);
+    let doc = String::from_str(&env, DOC_HASH);
+    let done = client.schedule_surgery(&surgeon, &record_id, &2_000);
+    let open = client.schedule_surgery(&surgeon, &record_id, &3_000);
+    assert_eq!(client.get_incomplete_surgeries(&surgeon).len(), 0);
+
+    client.record_preop_clearance(&optometrist, &done.id, &doc);
+    client.record_surgery_outcome(&surgeon, &done.id, &doc);
+    client.record_postop_followup(&optometrist, &done.id, &doc);
+
+    env.ledger().set_timestamp(4_000);
+    let incomplete = client.get_incomplete_surgeries(&surgeon);
+    assert_eq!(incomplete.len(), 1);
+    assert_eq!(incomplete.get(0).unwrap().id, open.id);
+}
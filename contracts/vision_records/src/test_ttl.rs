@@ -0,0 +1,116 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{add_record, register_user, setup_test};
+use super::{AccessLevel, ContractError, RecordType, Role, TtlConfig, VisionRecordsContractClient};
+use soroban_sdk::{symbol_short, testutils::Address as _, testutils::Ledger as _, Address, Env};
+
+fn record_ttl(client: &VisionRecordsContractClient, record_id: u64) -> u32 {
+    client.env.as_contract(&client.address, || {
+        client
+            .env
+            .storage()
+            .persistent()
+            .get_ttl(&(symbol_short!("RECORD"), record_id))
+    })
+}
+
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    env.ledger().with_mut(|l| l.sequence_number += ledgers);
+}
+
+#[test]
+fn test_ttl_config_defaults_and_validation() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Ttl");
+    assert_eq!(
+        client.get_ttl_config(),
+        TtlConfig {
+            threshold: 5_184_000,
+            extend_to: 10_368_000,
+        }
+    );
+
+    let res = client.try_set_ttl_config(&admin, &3_000, &3_000);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+    let res = client.try_set_ttl_config(&admin, &0, &3_000);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+
+    let res = client.try_set_ttl_config(&provider, &500, &3_000);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    client.set_ttl_config(&admin, &500, &3_000);
+    assert_eq!(client.get_ttl_config().extend_to, 3_000);
+}
+
+#[test]
+fn test_extend_record_ttl_uses_configured_thresholds() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Ttl");
+    client.set_ttl_config(&admin, &500, &3_000);
+    let patient = Address::generate(&env);
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+
+    // Still above the threshold: left alone
+    let before = record_ttl(&client, record_id);
+    client.extend_record_ttl(&record_id);
+    assert_eq!(record_ttl(&client, record_id), before);
+
+    advance_ledgers(&env, before - 100);
+    client.extend_record_ttl(&record_id);
+    assert_eq!(record_ttl(&client, record_id), 3_000);
+
+    let res = client.try_extend_record_ttl(&(record_id + 1));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::RecordNotFound);
+}
+
+#[test]
+fn test_reading_a_record_extends_it() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Ttl");
+    client.set_ttl_config(&admin, &500, &3_000);
+    let patient = Address::generate(&env);
+    let record_id = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+
+    advance_ledgers(&env, record_ttl(&client, record_id) - 100);
+    client.get_record(&patient, &record_id);
+    assert_eq!(record_ttl(&client, record_id), 3_000);
+}
+
+#[test]
+fn test_extend_patient_data_ttl_covers_indexes_grants_and_records() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Ttl");
+    client.set_ttl_config(&admin, &500, &3_000);
+    let patient = Address::generate(&env);
+    let first = add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    add_record(&env, &client, &provider, &patient, RecordType::Examination);
+    let grantee = Address::generate(&env);
+    client.grant_access(&patient, &patient, &grantee, &AccessLevel::Read, &86_400);
+
+    advance_ledgers(&env, record_ttl(&client, first) - 100);
+    // Record index, grantee index, one grant and two records
+    assert_eq!(client.extend_patient_data_ttl(&patient), 5);
+    assert_eq!(record_ttl(&client, first), 3_000);
+
+    // An unknown patient has nothing to extend
+    assert_eq!(client.extend_patient_data_ttl(&Address::generate(&env)), 0);
+}
+
+#[test]
+fn test_module_storage_uses_configured_thresholds() {
+    let (env, client, admin) = setup_test();
+    client.set_ttl_config(&admin, &10_000, &20_000);
+    let user = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Configured");
+
+    let role_ttl = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&(symbol_short!("ROLE_ASN"), user.clone()))
+    });
+    assert_eq!(role_ttl, 20_000);
+}
@@ -0,0 +1,134 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, setup_test};
+use super::{
+    user_name, ContractError, PrepareUserRegistration, Role, User, VisionRecordsContractClient,
+};
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, vec, xdr::ToXdr, Address, Bytes, BytesN, Env, String,
+};
+
+fn salted(env: &Env, salt: &Bytes, name: &str) -> BytesN<32> {
+    let mut preimage = salt.clone();
+    preimage.append(&Bytes::from_slice(env, name.as_bytes()));
+    env.crypto().sha256(&preimage).into()
+}
+
+#[test]
+fn test_hashed_registration_keeps_name_off_chain() {
+    let (env, client, admin) = setup_test();
+    let user = Address::generate(&env);
+    let salt = Bytes::from_slice(&env, b"kyc-salt-1f9a");
+    let name = "Zoë Ŝmith 山田";
+    let display_ref = String::from_str(&env, "QmEncryptedDisplayNameBlob00000000000000");
+
+    client.register_user_hashed(
+        &admin,
+        &user,
+        &Role::Patient,
+        &salted(&env, &salt, name),
+        &Some(display_ref.clone()),
+    );
+
+    assert!(client.get_user(&user).name.is_empty());
+    assert_eq!(
+        client.get_user_name(&user).unwrap().display_ref,
+        Some(display_ref)
+    );
+    assert!(client.verify_name(&user, &String::from_str(&env, name), &salt));
+    assert!(!client.verify_name(&user, &String::from_str(&env, "Zoe Smith"), &salt));
+    assert!(!client.verify_name(
+        &user,
+        &String::from_str(&env, name),
+        &Bytes::from_slice(&env, b"other")
+    ));
+}
+
+fn plaintext_salt(env: &Env, client: &VisionRecordsContractClient, user: &Address) -> Bytes {
+    env.as_contract(&client.address, || user_name::plaintext_salt(env, user))
+}
+
+#[test]
+fn test_plaintext_registration_is_not_salted_by_address_alone() {
+    let (env, client, admin) = setup_test();
+    let user = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Plain");
+    let name = String::from_str(&env, "Dr. Plain");
+
+    assert!(client.get_user(&user).name.is_empty());
+    assert!(!client.verify_name(&user, &name, &user.clone().to_xdr(&env)));
+    let salt = plaintext_salt(&env, &client, &user);
+    assert!(client.verify_name(&user, &name, &salt));
+}
+
+#[test]
+fn test_prepared_registration_keeps_only_the_hash() {
+    let (env, client, admin) = setup_test();
+    let user = Address::generate(&env);
+    let name = String::from_str(&env, "Pat Prepared");
+    client.prepare_register_user(&admin, &user, &Role::Patient, &name);
+
+    let prepared: PrepareUserRegistration = env.as_contract(&client.address, || {
+        env.storage()
+            .temporary()
+            .get(&(symbol_short!("PREP_REG_USER"), user.clone()))
+            .unwrap()
+    });
+    let salt = plaintext_salt(&env, &client, &user);
+    assert_eq!(prepared.name_hash, salted(&env, &salt, "Pat Prepared"));
+
+    let res = client.try_commit_register_user(
+        &admin,
+        &user,
+        &Role::Patient,
+        &String::from_str(&env, "Someone Else"),
+    );
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidPhase);
+    client.commit_register_user(&admin, &user, &Role::Patient, &name);
+    assert!(client.verify_name(&user, &name, &salt));
+}
+
+#[test]
+fn test_migration_scrubs_plaintext_names() {
+    let (env, client, admin) = setup_test();
+    let legacy = Address::generate(&env);
+
+    // A user stored before names were hashed
+    env.as_contract(&client.address, || {
+        env.storage().persistent().set(
+            &(symbol_short!("USER"), legacy.clone()),
+            &User {
+                address: legacy.clone(),
+                role: Role::Patient,
+                name: String::from_str(&env, "Jane Legacy"),
+                registered_at: 0,
+                is_active: true,
+            },
+        );
+    });
+    assert_eq!(client.get_user_name(&legacy), None);
+
+    let outsider = Address::generate(&env);
+    let res = client.try_migrate_user_names(&outsider, &vec![&env, legacy.clone()]);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let users = vec![&env, legacy.clone(), Address::generate(&env)];
+    assert_eq!(client.migrate_user_names(&admin, &users), 1);
+    assert!(client.get_user(&legacy).name.is_empty());
+    let salt = plaintext_salt(&env, &client, &legacy);
+    assert!(client.verify_name(&legacy, &String::from_str(&env, "Jane Legacy"), &salt));
+
+    // Already scrubbed
+    assert_eq!(client.migrate_user_names(&admin, &users), 0);
+
+    let mut too_many = vec![&env];
+    for _ in 0..=user_name::MAX_MIGRATION_BATCH {
+        too_many.push_back(Address::generate(&env));
+    }
+    let res = client.try_migrate_user_names(&admin, &too_many);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
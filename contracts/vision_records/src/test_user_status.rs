@@ -0,0 +1,124 @@
+#![allow(
+    clippy::unwrap_used,
+    clippy::expect_used,
+    clippy::panic,
+    clippy::arithmetic_side_effects
+)]
+
+use super::test_utils::{register_user, setup_test, DATA_HASH};
+use super::{
+    ContractError, LensType, OptionalContactLensData, PrescriptionData, RecordType, Role,
+    VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+fn try_add_record(
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    patient: &Address,
+) -> Result<u64, ContractError> {
+    client
+        .try_add_record(
+            provider,
+            patient,
+            provider,
+            &RecordType::Examination,
+            &String::from_str(&client.env, DATA_HASH),
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+fn try_add_prescription(
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    patient: &Address,
+) -> Result<u64, ContractError> {
+    let env = &client.env;
+    let eye = PrescriptionData {
+        sphere: String::from_str(env, "-1.25"),
+        cylinder: String::from_str(env, "-0.50"),
+        axis: String::from_str(env, "90"),
+        add: String::from_str(env, "0.00"),
+        pd: String::from_str(env, "62"),
+        prism: String::from_str(env, ""),
+        prism_base: String::from_str(env, ""),
+    };
+    client
+        .try_add_prescription(
+            patient,
+            provider,
+            &LensType::Glasses,
+            &eye,
+            &eye,
+            &OptionalContactLensData::None,
+            &31_536_000,
+            &String::from_str(env, "metadata_hash"),
+        )
+        .map(|r| r.unwrap())
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_deactivated_provider_cannot_write() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Status");
+    let patient = Address::generate(&env);
+    assert!(try_add_record(&client, &provider, &patient).is_ok());
+
+    client.deactivate_user(&admin, &provider);
+    assert!(!client.get_user(&provider).is_active);
+    assert_eq!(
+        try_add_record(&client, &provider, &patient),
+        Err(ContractError::Unauthorized)
+    );
+    assert_eq!(
+        try_add_prescription(&client, &provider, &patient),
+        Err(ContractError::Unauthorized)
+    );
+
+    client.reactivate_user(&admin, &provider);
+    assert!(client.get_user(&provider).is_active);
+    assert!(try_add_record(&client, &provider, &patient).is_ok());
+    assert!(try_add_prescription(&client, &provider, &patient).is_ok());
+}
+
+#[test]
+fn test_update_user_role() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Status");
+    let patient = Address::generate(&env);
+    client.update_user_role(&admin, &provider, &Role::Staff);
+    assert_eq!(client.get_user(&provider).role, Role::Staff);
+    assert_eq!(
+        try_add_record(&client, &provider, &patient),
+        Err(ContractError::Unauthorized)
+    );
+
+    // A deactivated user's new role applies once they are reactivated
+    client.deactivate_user(&admin, &provider);
+    client.update_user_role(&admin, &provider, &Role::Ophthalmologist);
+    assert_eq!(
+        try_add_record(&client, &provider, &patient),
+        Err(ContractError::Unauthorized)
+    );
+    client.reactivate_user(&admin, &provider);
+    assert!(try_add_record(&client, &provider, &patient).is_ok());
+}
+
+#[test]
+fn test_user_status_changes_require_admin() {
+    let (env, client, admin) = setup_test();
+    let provider = register_user(&env, &client, &admin, Role::Optometrist, "Dr. Status");
+    let res = client.try_deactivate_user(&provider, &admin);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+    let res = client.try_update_user_role(&provider, &provider, &Role::Admin);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::Unauthorized);
+
+    let res = client.try_deactivate_user(&admin, &admin);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+    let res = client.try_reactivate_user(&admin, &provider);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+    let res = client.try_deactivate_user(&admin, &Address::generate(&env));
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::UserNotFound);
+}
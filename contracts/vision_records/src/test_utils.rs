@@ -0,0 +1,91 @@
+//! Setup shared by the unit-test modules.
+
+#![allow(clippy::arithmetic_side_effects)]
+
+use super::{
+    provider::{self, Provider},
+    RecordType, Role, VerificationStatus, VisionRecordsContract, VisionRecordsContractClient,
+};
+use soroban_sdk::{testutils::Address as _, testutils::Ledger as _, Address, Env, String, Vec};
+
+/// Content hash for records whose payload the test does not inspect.
+pub const DATA_HASH: &str = "QmVisionRecordsTestHash00000000000000000000000";
+
+/// Registers and initializes the contract with all auths mocked.
+pub fn setup_test() -> (Env, VisionRecordsContractClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = env.register(VisionRecordsContract, ());
+    let client = VisionRecordsContractClient::new(&env, &contract_id);
+
+    let admin = Address::generate(&env);
+    client.initialize(&admin);
+
+    (env, client, admin)
+}
+
+/// Registers a fresh address under `role` and returns it.
+pub fn register_user(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+    role: Role,
+    name: &str,
+) -> Address {
+    let user = Address::generate(env);
+    client.register_user(admin, &user, &role, &String::from_str(env, name));
+    user
+}
+
+/// Registers an ophthalmologist whose provider profile is already verified,
+/// as emergency access requires.
+pub fn register_verified_provider(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    admin: &Address,
+    name: &str,
+) -> Address {
+    let user = register_user(env, client, admin, Role::Ophthalmologist, name);
+    env.as_contract(&client.address, || {
+        provider::set_provider(
+            env,
+            &Provider {
+                address: user.clone(),
+                name: String::from_str(env, name),
+                licenses: Vec::new(env),
+                specialties: Vec::new(env),
+                certifications: Vec::new(env),
+                locations: Vec::new(env),
+                verification_status: VerificationStatus::Verified,
+                registered_at: 0,
+                verified_at: Some(0),
+                verified_by: Some(admin.clone()),
+                is_active: true,
+            },
+        );
+    });
+    user
+}
+
+/// Adds a record of `record_type` written by `provider` with [`DATA_HASH`].
+pub fn add_record(
+    env: &Env,
+    client: &VisionRecordsContractClient,
+    provider: &Address,
+    patient: &Address,
+    record_type: RecordType,
+) -> u64 {
+    client.add_record(
+        provider,
+        patient,
+        provider,
+        &record_type,
+        &String::from_str(env, DATA_HASH),
+    )
+}
+
+/// Moves the ledger clock forward by `seconds`.
+pub fn advance(env: &Env, seconds: u64) {
+    env.ledger().with_mut(|li| li.timestamp += seconds);
+}
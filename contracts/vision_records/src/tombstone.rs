@@ -0,0 +1,43 @@
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const REC_TOMB: Symbol = symbol_short!("REC_TOMB");
+
+/// Extends the time-to-live (TTL) for per-record tombstone keys.
+fn extend_ttl_record_key(env: &Env, key: &(Symbol, u64)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Marks a record as archived by its patient or an admin. Unlike retention
+/// archival the record itself stays in storage, so it can be restored.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecordTombstone {
+    pub record_id: u64,
+    pub archived_by: Address,
+    pub archived_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn set(env: &Env, tombstone: &RecordTombstone) {
+    let key = (REC_TOMB, tombstone.record_id);
+    env.storage().persistent().set(&key, tombstone);
+    extend_ttl_record_key(env, &key);
+}
+
+pub fn get(env: &Env, record_id: u64) -> Option<RecordTombstone> {
+    env.storage().persistent().get(&(REC_TOMB, record_id))
+}
+
+pub fn is_archived(env: &Env, record_id: u64) -> bool {
+    env.storage().persistent().has(&(REC_TOMB, record_id))
+}
+
+pub fn remove(env: &Env, record_id: u64) {
+    env.storage().persistent().remove(&(REC_TOMB, record_id));
+}
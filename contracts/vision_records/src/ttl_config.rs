@@ -0,0 +1,62 @@
+use soroban_sdk::{contracttype, symbol_short, Env, IntoVal, Symbol, Val};
+
+// ── Storage keys ──────────────────────────────────────────────
+const TTL_CFG: Symbol = symbol_short!("TTL_CFG");
+
+/// Roughly 60 days of ledgers; entries below this are bumped on access.
+pub const DEFAULT_TTL_THRESHOLD: u32 = 5_184_000;
+/// Roughly 120 days of ledgers; the TTL bumped entries are extended to.
+pub const DEFAULT_TTL_EXTEND_TO: u32 = 10_368_000;
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// Ledger counts used whenever the contract extends a persistent entry or
+/// its instance storage: entries whose TTL has fallen below `threshold`
+/// are extended to `extend_to`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TtlConfig {
+    pub threshold: u32,
+    pub extend_to: u32,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn get(env: &Env) -> TtlConfig {
+    env.storage().instance().get(&TTL_CFG).unwrap_or(TtlConfig {
+        threshold: DEFAULT_TTL_THRESHOLD,
+        extend_to: DEFAULT_TTL_EXTEND_TO,
+    })
+}
+
+pub fn set(env: &Env, config: &TtlConfig) {
+    env.storage().instance().set(&TTL_CFG, config);
+}
+
+/// Extends the time-to-live (TTL) for a persistent key using the
+/// configured thresholds. The key must exist.
+pub fn extend<K: IntoVal<Env, Val>>(env: &Env, key: &K) {
+    let config = get(env);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, config.threshold, config.extend_to);
+}
+
+/// Extends the time-to-live (TTL) for instance storage using the
+/// configured thresholds.
+pub fn extend_instance(env: &Env) {
+    let config = get(env);
+    env.storage()
+        .instance()
+        .extend_ttl(config.threshold, config.extend_to);
+}
+
+/// Like `extend`, but skips keys that are not in storage. Returns whether
+/// the key was present.
+pub fn extend_if_present<K: IntoVal<Env, Val>>(env: &Env, key: &K) -> bool {
+    if !env.storage().persistent().has(key) {
+        return false;
+    }
+    extend(env, key);
+    true
+}
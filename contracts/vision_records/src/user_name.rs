@@ -0,0 +1,88 @@
+use soroban_sdk::{
+    contracttype, symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, String, Symbol,
+};
+
+use crate::ttl_config;
+
+// ── Storage keys ──────────────────────────────────────────────
+const USR_NAME: Symbol = symbol_short!("USR_NAME");
+const NAME_SEC: Symbol = symbol_short!("NAME_SEC");
+
+/// Longest name, in UTF-8 bytes, `verify_name` will hash. Names in any
+/// script are accepted here; only legacy plaintext registration is limited
+/// to printable ASCII.
+pub const MAX_NAME_BYTES: u32 = 256;
+
+/// Most users scrubbed by one `migrate_user_names` call
+pub const MAX_MIGRATION_BATCH: u32 = 50;
+
+/// Extends the time-to-live (TTL) for per-user name keys.
+fn extend_ttl_name_key(env: &Env, key: &(Symbol, Address)) {
+    ttl_config::extend(env, key);
+}
+
+// ── Types ─────────────────────────────────────────────────────
+
+/// What the contract keeps of a user's name: a salted SHA-256 of it and,
+/// optionally, a reference to an encrypted display-name blob held off-chain
+/// (which may carry the name in several languages).
+///
+/// Users registered with `register_user_hashed` choose their own salt and
+/// keep it, which is the only way to keep the name private. Names given in
+/// plaintext to `register_user`, or scrubbed by `migrate_user_names`, are
+/// salted by [`plaintext_salt`], which anyone able to read contract storage
+/// can recompute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UserName {
+    pub name_hash: BytesN<32>,
+    pub display_ref: Option<String>,
+    pub updated_at: u64,
+}
+
+// ── Storage Functions ────────────────────────────────────────
+
+pub fn set(env: &Env, user: &Address, name: &UserName) {
+    let key = (USR_NAME, user.clone());
+    env.storage().persistent().set(&key, name);
+    extend_ttl_name_key(env, &key);
+}
+
+pub fn get(env: &Env, user: &Address) -> Option<UserName> {
+    env.storage().persistent().get(&(USR_NAME, user.clone()))
+}
+
+pub fn remove(env: &Env, user: &Address) {
+    env.storage().persistent().remove(&(USR_NAME, user.clone()));
+}
+
+/// SHA-256 over `salt || name`, or `None` if `name` is too long
+pub fn salted_hash(env: &Env, name: &String, salt: &Bytes) -> Option<BytesN<32>> {
+    let len = name.len();
+    if len > MAX_NAME_BYTES {
+        return None;
+    }
+    let mut buf = [0u8; MAX_NAME_BYTES as usize];
+    name.copy_into_slice(&mut buf[..len as usize]);
+    let mut preimage = salt.clone();
+    preimage.extend_from_slice(&buf[..len as usize]);
+    Some(env.crypto().sha256(&preimage).into())
+}
+
+/// Salt for names the contract received in plaintext: SHA-256 of a random
+/// per-deployment secret and the XDR of `user`. The secret stops a hash
+/// being checked against the address alone, but it is kept in contract
+/// storage, so these hashes only keep the name out of casual view.
+pub fn plaintext_salt(env: &Env, user: &Address) -> Bytes {
+    let secret: BytesN<32> = match env.storage().instance().get(&NAME_SEC) {
+        Some(secret) => secret,
+        None => {
+            let secret: BytesN<32> = env.prng().gen();
+            env.storage().instance().set(&NAME_SEC, &secret);
+            secret
+        }
+    };
+    let mut preimage = Bytes::from_array(env, &secret.to_array());
+    preimage.append(&user.clone().to_xdr(env));
+    Bytes::from_array(env, &env.crypto().sha256(&preimage).to_array())
+}
@@ -8,6 +8,8 @@ const MAX_NAME_LEN: u32 = 64;
 const MIN_HASH_LEN: u32 = 32;
 const MAX_HASH_LEN: u32 = 64;
 
+const MAX_REASON_LEN: u32 = 128;
+
 const MIN_DURATION_SECONDS: u64 = 3600; // 1 hour
 const MAX_DURATION_SECONDS: u64 = 157_680_000; // 5 years
 
@@ -71,6 +73,23 @@ pub fn validate_data_hash(hash: &String) -> Result<(), ContractError> {
     Ok(())
 }
 
+/// Validate a free-text reason kept in an audit trail.
+/// Reasons must be non-empty printable ASCII of at most MAX_REASON_LEN bytes.
+pub fn validate_reason(reason: &String) -> Result<(), ContractError> {
+    let len = reason.len();
+    if len == 0 || len > MAX_REASON_LEN {
+        return Err(ContractError::InvalidInput);
+    }
+
+    let mut buf = [0u8; MAX_REASON_LEN as usize];
+    reason.copy_into_slice(&mut buf[..len as usize]);
+    if buf[..len as usize].iter().any(|b| !(32..=126).contains(b)) {
+        return Err(ContractError::InvalidInput);
+    }
+
+    Ok(())
+}
+
 /// Validate a grant access duration.
 /// Prevent extremely short durations (e.g., 0) or extremely long ones (overflow risk).
 pub fn validate_duration(duration_seconds: u64) -> Result<(), ContractError> {
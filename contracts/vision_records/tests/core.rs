@@ -64,7 +64,7 @@ fn test_access_control() {
     let doctor = Address::generate(&ctx.env);
 
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor),
+        ctx.client.check_access(&patient, &doctor, &None, &None),
         AccessLevel::None
     );
 
@@ -74,27 +74,27 @@ fn test_access_control() {
     ctx.client
         .grant_access(&patient, &patient, &doctor, &AccessLevel::Read, &86400);
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor),
+        ctx.client.check_access(&patient, &doctor, &None, &None),
         AccessLevel::Read
     );
 
     // EXACT boundary check: at the exact second of expiration
     ctx.env.ledger().set_timestamp(current_time + 86400);
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor),
+        ctx.client.check_access(&patient, &doctor, &None, &None),
         AccessLevel::None
     );
 
     ctx.env.ledger().set_timestamp(current_time + 86401);
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor),
+        ctx.client.check_access(&patient, &doctor, &None, &None),
         AccessLevel::None
     );
 
     ctx.env.ledger().set_timestamp(current_time);
     ctx.client.revoke_access(&patient, &patient, &doctor);
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor),
+        ctx.client.check_access(&patient, &doctor, &None, &None),
         AccessLevel::None
     );
 }
@@ -324,14 +324,14 @@ fn test_check_access_returns_none_when_expired() {
     // Still active just before expiry
     ctx.env.ledger().set_timestamp(4_599);
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor),
+        ctx.client.check_access(&patient, &doctor, &None, &None),
         AccessLevel::Read
     );
 
     // Expired at exact boundary
     ctx.env.ledger().set_timestamp(4_600);
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor),
+        ctx.client.check_access(&patient, &doctor, &None, &None),
         AccessLevel::None
     );
 }
@@ -356,11 +356,11 @@ fn test_purge_expired_grants_removes_expired() {
 
     // doctor1 is expired, doctor2 is still active
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor1),
+        ctx.client.check_access(&patient, &doctor1, &None, &None),
         AccessLevel::None
     );
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor2),
+        ctx.client.check_access(&patient, &doctor2, &None, &None),
         AccessLevel::Write
     );
 
@@ -370,7 +370,7 @@ fn test_purge_expired_grants_removes_expired() {
 
     // doctor2 still accessible
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor2),
+        ctx.client.check_access(&patient, &doctor2, &None, &None),
         AccessLevel::Write
     );
 }
@@ -393,7 +393,7 @@ fn test_purge_expired_grants_all_expired() {
 
     // Grant storage is gone
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor),
+        ctx.client.check_access(&patient, &doctor, &None, &None),
         AccessLevel::None
     );
 }
@@ -416,7 +416,7 @@ fn test_purge_expired_grants_none_expired() {
 
     // Access unchanged
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor),
+        ctx.client.check_access(&patient, &doctor, &None, &None),
         AccessLevel::Read
     );
 }
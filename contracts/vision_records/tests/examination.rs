@@ -1,9 +1,9 @@
 mod common;
 
-use common::{create_test_record, create_test_user, setup_test_env};
-use soroban_sdk::String;
+use common::{create_test_record, create_test_user, setup_test_env, TestContext};
+use soroban_sdk::{Address, String};
 use vision_records::{
-    AccessLevel, IntraocularPressure, OptFundusPhotography, OptPhysicalMeasurement,
+    AccessLevel, ContractError, IntraocularPressure, OptFundusPhotography, OptPhysicalMeasurement,
     OptRetinalImaging, OptVisualField, PhysicalMeasurement, RecordType, Role, SlitLampFindings,
     VisualAcuity,
 };
@@ -148,3 +148,185 @@ fn test_eye_examination_access_control() {
         .try_get_eye_examination(&other_provider, &record_id);
     assert!(exam_res_other_after.is_ok());
 }
+
+fn add_exam_with_acuity(
+    ctx: &TestContext,
+    provider: &Address,
+    patient: &Address,
+    data_hash: &str,
+    uncorrected: (&str, &str),
+    corrected: Option<(&str, &str)>,
+) -> Result<u64, ContractError> {
+    let record_id = create_test_record(
+        ctx,
+        provider,
+        patient,
+        provider,
+        RecordType::Examination,
+        data_hash,
+    );
+    let measurement = |(left, right): (&str, &str)| PhysicalMeasurement {
+        left_eye: String::from_str(&ctx.env, left),
+        right_eye: String::from_str(&ctx.env, right),
+    };
+    let visual_acuity = VisualAcuity {
+        uncorrected: measurement(uncorrected),
+        corrected: match corrected {
+            Some(c) => OptPhysicalMeasurement::Some(measurement(c)),
+            None => OptPhysicalMeasurement::None,
+        },
+    };
+    let iop = IntraocularPressure {
+        left_eye: 15,
+        right_eye: 15,
+        method: String::from_str(&ctx.env, "Goldmann"),
+        timestamp: ctx.env.ledger().timestamp(),
+    };
+    let slit_lamp = SlitLampFindings {
+        cornea: String::from_str(&ctx.env, "Clear"),
+        anterior_chamber: String::from_str(&ctx.env, "Quiet"),
+        iris: String::from_str(&ctx.env, "Normal"),
+        lens: String::from_str(&ctx.env, "Clear"),
+    };
+    ctx.client
+        .try_add_eye_examination(
+            provider,
+            &record_id,
+            &visual_acuity,
+            &iop,
+            &slit_lamp,
+            &OptVisualField::None,
+            &OptRetinalImaging::None,
+            &OptFundusPhotography::None,
+            &String::from_str(&ctx.env, "Notes"),
+        )
+        .map(|_| record_id)
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_eye_examination_rejects_unparseable_acuity() {
+    let ctx = setup_test_env();
+    let patient = create_test_user(&ctx, Role::Patient, "Patient4");
+    let provider = create_test_user(&ctx, Role::Optometrist, "Provider4");
+
+    let res = add_exam_with_acuity(
+        &ctx,
+        &provider,
+        &patient,
+        "bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb",
+        ("20/20", "20/40 -2"),
+        None,
+    );
+    assert_eq!(res, Err(ContractError::InvalidInput));
+}
+
+#[test]
+fn test_categorical_acuity_is_stored_but_not_compared() {
+    let ctx = setup_test_env();
+    let patient = create_test_user(&ctx, Role::Patient, "Patient8");
+    let provider = create_test_user(&ctx, Role::Optometrist, "Provider8");
+
+    let baseline = add_exam_with_acuity(
+        &ctx,
+        &provider,
+        &patient,
+        "gggggggggggggggggggggggggggggggg",
+        ("20/200", "20/40"),
+        None,
+    )
+    .unwrap();
+    let followup = add_exam_with_acuity(
+        &ctx,
+        &provider,
+        &patient,
+        "hhhhhhhhhhhhhhhhhhhhhhhhhhhhhhhh",
+        ("counting fingers", "20/40"),
+        Some(("HM", "NLP")),
+    )
+    .unwrap();
+
+    let exam = ctx.client.get_eye_examination(&provider, &followup);
+    assert_eq!(
+        exam.visual_acuity.uncorrected.left_eye,
+        String::from_str(&ctx.env, "counting fingers")
+    );
+    match exam.visual_acuity.corrected {
+        OptPhysicalMeasurement::Some(m) => {
+            assert_eq!(m.left_eye, String::from_str(&ctx.env, "HM"));
+            assert_eq!(m.right_eye, String::from_str(&ctx.env, "NLP"));
+        }
+        OptPhysicalMeasurement::None => panic!("corrected acuity was dropped"),
+    }
+
+    // There is no logMAR value to compare against
+    let res = ctx
+        .client
+        .try_get_acuity_change(&provider, &baseline, &followup);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
+
+#[test]
+fn test_acuity_change_compares_across_notations() {
+    let ctx = setup_test_env();
+    let patient = create_test_user(&ctx, Role::Patient, "Patient5");
+    let provider = create_test_user(&ctx, Role::Optometrist, "Provider5");
+
+    let baseline = add_exam_with_acuity(
+        &ctx,
+        &provider,
+        &patient,
+        "cccccccccccccccccccccccccccccccc",
+        ("20/20", "6/6"),
+        None,
+    )
+    .unwrap();
+    // Follow-up recorded in decimal; the corrected values take precedence.
+    let followup = add_exam_with_acuity(
+        &ctx,
+        &provider,
+        &patient,
+        "dddddddddddddddddddddddddddddddd",
+        ("0.1", "0.1"),
+        Some(("0.5", "1.0")),
+    )
+    .unwrap();
+
+    let change = ctx
+        .client
+        .get_acuity_change(&provider, &baseline, &followup);
+    assert_eq!(change.left_eye, 301);
+    assert_eq!(change.right_eye, 0);
+    assert_eq!(change.left_eye_lines, 3);
+    assert_eq!(change.right_eye_lines, 0);
+}
+
+#[test]
+fn test_acuity_change_requires_same_patient() {
+    let ctx = setup_test_env();
+    let patient = create_test_user(&ctx, Role::Patient, "Patient6");
+    let other_patient = create_test_user(&ctx, Role::Patient, "Patient7");
+    let provider = create_test_user(&ctx, Role::Optometrist, "Provider6");
+
+    let first = add_exam_with_acuity(
+        &ctx,
+        &provider,
+        &patient,
+        "eeeeeeeeeeeeeeeeeeeeeeeeeeeeeeee",
+        ("20/20", "20/20"),
+        None,
+    )
+    .unwrap();
+    let second = add_exam_with_acuity(
+        &ctx,
+        &provider,
+        &other_patient,
+        "ffffffffffffffffffffffffffffffff",
+        ("20/40", "20/40"),
+        None,
+    )
+    .unwrap();
+
+    let res = ctx.client.try_get_acuity_change(&provider, &first, &second);
+    assert_eq!(res.unwrap_err().unwrap(), ContractError::InvalidInput);
+}
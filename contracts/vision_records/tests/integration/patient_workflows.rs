@@ -3,6 +3,7 @@
 use super::{create_test_user, setup_test_env};
 use soroban_sdk::{
     testutils::{Address as _, Ledger},
+    xdr::ToXdr,
     Address, String,
 };
 use vision_records::{AccessLevel, RecordType};
@@ -24,7 +25,14 @@ fn test_patient_registration_workflow() {
     // Step 2: Verify patient is registered
     let user_data = ctx.client.get_user(&patient);
     assert_eq!(user_data.role, vision_records::Role::Patient);
-    assert_eq!(user_data.name, String::from_str(&ctx.env, "John Doe"));
+    // Only a salted hash of the name is kept
+    assert!(user_data.name.is_empty());
+    let salt = patient.clone().to_xdr(&ctx.env);
+    assert!(ctx.client.verify_name(
+        &patient,
+        &String::from_str(&ctx.env, "John Doe"),
+        &salt
+    ));
     assert!(user_data.is_active);
 
     // Step 3: Patient can view their own profile
@@ -49,7 +57,7 @@ fn test_patient_grant_access_workflow() {
     );
 
     // Verify access was granted
-    let access_level = ctx.client.check_access(&patient, &family_member);
+    let access_level = ctx.client.check_access(&patient, &family_member, &None, &None);
     assert_eq!(access_level, AccessLevel::Read);
 
     // Family member should be able to read patient's records
@@ -74,7 +82,7 @@ fn test_patient_revoke_access_workflow() {
 
     // Verify access
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor),
+        ctx.client.check_access(&patient, &doctor, &None, &None),
         AccessLevel::Full
     );
 
@@ -83,7 +91,7 @@ fn test_patient_revoke_access_workflow() {
 
     // Verify access is revoked
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor),
+        ctx.client.check_access(&patient, &doctor, &None, &None),
         AccessLevel::None
     );
 }
@@ -112,15 +120,15 @@ fn test_patient_multiple_access_grants() {
 
     // Verify all grants
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor1),
+        ctx.client.check_access(&patient, &doctor1, &None, &None),
         AccessLevel::Full
     );
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor2),
+        ctx.client.check_access(&patient, &doctor2, &None, &None),
         AccessLevel::Read
     );
     assert_eq!(
-        ctx.client.check_access(&patient, &family),
+        ctx.client.check_access(&patient, &family, &None, &None),
         AccessLevel::Read
     );
 
@@ -129,15 +137,15 @@ fn test_patient_multiple_access_grants() {
 
     // Verify revoked grant is gone, others remain
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor1),
+        ctx.client.check_access(&patient, &doctor1, &None, &None),
         AccessLevel::Full
     );
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor2),
+        ctx.client.check_access(&patient, &doctor2, &None, &None),
         AccessLevel::None
     );
     assert_eq!(
-        ctx.client.check_access(&patient, &family),
+        ctx.client.check_access(&patient, &family, &None, &None),
         AccessLevel::Read
     );
 }
@@ -162,7 +170,7 @@ fn test_patient_access_expiration() {
 
     // Verify access is granted
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor),
+        ctx.client.check_access(&patient, &doctor, &None, &None),
         AccessLevel::Read
     );
 
@@ -171,7 +179,7 @@ fn test_patient_access_expiration() {
 
     // Access should be expired
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor),
+        ctx.client.check_access(&patient, &doctor, &None, &None),
         AccessLevel::None
     );
 }
@@ -229,7 +237,7 @@ fn test_patient_cannot_grant_self_access() {
 
     // Verify access
     assert_eq!(
-        ctx.client.check_access(&patient, &patient),
+        ctx.client.check_access(&patient, &patient, &None, &None),
         AccessLevel::Full
     );
 }
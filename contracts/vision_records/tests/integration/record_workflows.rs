@@ -230,15 +230,16 @@ fn test_record_access_levels_workflow() {
 
     // Verify access levels
     assert_eq!(
-        ctx.client.check_access(&patient, &reader),
+        ctx.client.check_access(&patient, &reader, &None, &None),
         AccessLevel::Read
     );
     assert_eq!(
-        ctx.client.check_access(&patient, &writer),
+        ctx.client.check_access(&patient, &writer, &None, &None),
         AccessLevel::Write
     );
     assert_eq!(
-        ctx.client.check_access(&patient, &full_access),
+        ctx.client
+            .check_access(&patient, &full_access, &None, &None),
         AccessLevel::Full
     );
 }
@@ -50,7 +50,7 @@ proptest! {
         let patient = Address::generate(&env);
         let grantee = Address::generate(&env);
 
-        prop_assert_eq!(client.check_access(&patient, &grantee), AccessLevel::None);
+        prop_assert_eq!(client.check_access(&patient, &grantee, &None, &None), AccessLevel::None);
     }
 
     /// Granting an access level and then checking must return exactly that level.
@@ -65,7 +65,7 @@ proptest! {
         let level = access_level_from_u8(level_seed);
 
         client.grant_access(&patient, &patient, &grantee, &level, &duration);
-        prop_assert_eq!(client.check_access(&patient, &grantee), level);
+        prop_assert_eq!(client.check_access(&patient, &grantee, &None, &None), level);
     }
 
     /// Grant followed immediately by revoke must always result in `None`.
@@ -80,10 +80,10 @@ proptest! {
         let level = access_level_from_u8(level_seed);
 
         client.grant_access(&patient, &patient, &grantee, &level, &duration);
-        prop_assert_ne!(client.check_access(&patient, &grantee), AccessLevel::None);
+        prop_assert_ne!(client.check_access(&patient, &grantee, &None, &None), AccessLevel::None);
 
         client.revoke_access(&patient, &patient, &grantee);
-        prop_assert_eq!(client.check_access(&patient, &grantee), AccessLevel::None);
+        prop_assert_eq!(client.check_access(&patient, &grantee, &None, &None), AccessLevel::None);
     }
 
     /// Revoking access that was never granted must not panic (returns Ok).
@@ -95,7 +95,7 @@ proptest! {
 
         // No grant was ever issued — revoke should be a no-op
         client.revoke_access(&patient, &patient, &grantee);
-        prop_assert_eq!(client.check_access(&patient, &grantee), AccessLevel::None);
+        prop_assert_eq!(client.check_access(&patient, &grantee, &None, &None), AccessLevel::None);
     }
 
     /// A re-grant with a different level must always overwrite the previous one.
@@ -113,11 +113,11 @@ proptest! {
         let second_level = access_level_from_u8(second_seed);
 
         client.grant_access(&patient, &patient, &grantee, &first_level, &duration);
-        prop_assert_eq!(client.check_access(&patient, &grantee), first_level.clone());
+        prop_assert_eq!(client.check_access(&patient, &grantee, &None, &None), first_level.clone());
 
         // Overwrite with second level
         client.grant_access(&patient, &patient, &grantee, &second_level, &duration);
-        prop_assert_eq!(client.check_access(&patient, &grantee), second_level);
+        prop_assert_eq!(client.check_access(&patient, &grantee, &None, &None), second_level);
     }
 
     /// Granting access to multiple grantees must not interfere with each other.
@@ -138,13 +138,13 @@ proptest! {
         client.grant_access(&patient, &patient, &grantee_a, &level_a, &duration);
         client.grant_access(&patient, &patient, &grantee_b, &level_b, &duration);
 
-        prop_assert_eq!(client.check_access(&patient, &grantee_a), level_a);
-        prop_assert_eq!(client.check_access(&patient, &grantee_b), level_b.clone());
+        prop_assert_eq!(client.check_access(&patient, &grantee_a, &None, &None), level_a);
+        prop_assert_eq!(client.check_access(&patient, &grantee_b, &None, &None), level_b.clone());
 
         // Revoking grantee_a must not affect grantee_b
         client.revoke_access(&patient, &patient, &grantee_a);
-        prop_assert_eq!(client.check_access(&patient, &grantee_a), AccessLevel::None);
-        prop_assert_eq!(client.check_access(&patient, &grantee_b), level_b);
+        prop_assert_eq!(client.check_access(&patient, &grantee_a, &None, &None), AccessLevel::None);
+        prop_assert_eq!(client.check_access(&patient, &grantee_b, &None, &None), level_b);
     }
 
     /// Time-restricted access: policies should only allow access during specified hours
@@ -177,7 +177,7 @@ proptest! {
         );
 
         prop_assert_eq!(
-            client.check_access(&delegator, &doctor),
+            client.check_access(&delegator, &doctor, &None, &None),
             vision_records::AccessLevel::Read
         );
     }
@@ -121,11 +121,11 @@ proptest! {
         // Patient grants access to a doctor
         let doctor = Address::generate(&env);
         client.grant_access(&patient, &patient, &doctor, &AccessLevel::Read, &3600u64);
-        prop_assert_eq!(client.check_access(&patient, &doctor), AccessLevel::Read);
+        prop_assert_eq!(client.check_access(&patient, &doctor, &None, &None), AccessLevel::Read);
 
         // Patient revokes access
         client.revoke_access(&patient, &patient, &doctor);
-        prop_assert_eq!(client.check_access(&patient, &doctor), AccessLevel::None);
+        prop_assert_eq!(client.check_access(&patient, &doctor, &None, &None), AccessLevel::None);
     }
 
     /// Registering the same user twice must overwrite the record (not panic),
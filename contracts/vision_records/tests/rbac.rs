@@ -132,7 +132,7 @@ fn test_role_delegation() {
     ctx.client
         .grant_access(&pt2, &pt1, &doctor, &AccessLevel::Read, &3600);
 
-    assert_eq!(ctx.client.check_access(&pt1, &doctor), AccessLevel::Read);
+    assert_eq!(ctx.client.check_access(&pt1, &doctor, &None, &None), AccessLevel::Read);
 }
 
 #[test]
@@ -234,7 +234,7 @@ fn test_access_control_with_generated_addresses() {
     let patient = Address::generate(&ctx.env);
     let grantee = Address::generate(&ctx.env);
     assert_eq!(
-        ctx.client.check_access(&patient, &grantee),
+        ctx.client.check_access(&patient, &grantee, &None, &None),
         AccessLevel::None
     );
 }
@@ -260,7 +260,7 @@ fn test_scoped_delegation_only_grants_specified_permissions() {
     ctx.client
         .grant_access(&delegatee, &patient, &doctor, &AccessLevel::Read, &3600);
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor),
+        ctx.client.check_access(&patient, &doctor, &None, &None),
         AccessLevel::Read
     );
 
@@ -293,7 +293,7 @@ fn test_full_role_delegation_still_works() {
     ctx.client
         .grant_access(&pt2, &pt1, &doctor, &AccessLevel::Read, &3600);
 
-    assert_eq!(ctx.client.check_access(&pt1, &doctor), AccessLevel::Read);
+    assert_eq!(ctx.client.check_access(&pt1, &doctor, &None, &None), AccessLevel::Read);
 }
 
 /// Scoped delegations respect expiry: after expires_at, delegatee loses delegated permissions.
@@ -323,7 +323,7 @@ fn test_scoped_delegation_expiry() {
     ctx.client
         .grant_access(&delegatee, &patient, &doctor, &AccessLevel::Read, &3600);
     assert_eq!(
-        ctx.client.check_access(&patient, &doctor),
+        ctx.client.check_access(&patient, &doctor, &None, &None),
         AccessLevel::Read
     );
 }
@@ -350,7 +350,7 @@ fn test_revoke_access_cascades_delegations_and_emits_event() {
     // Delegation is active: delegatee can manage grantee's access.
     ctx.client
         .grant_access(&delegatee, &grantee, &doctor, &AccessLevel::Read, &3600);
-    assert_eq!(ctx.client.check_access(&grantee, &doctor), AccessLevel::Read);
+    assert_eq!(ctx.client.check_access(&grantee, &doctor, &None, &None), AccessLevel::Read);
 
     let events_before = ctx.env.events().all().len();
     ctx.client.revoke_access(&patient, &patient, &grantee);
@@ -1,4 +1,6 @@
-use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Vec};
+use soroban_sdk::{contracttype, symbol_short, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+const RECEIPT: Symbol = symbol_short!("RCPT_USE");
 
 /// Record of a successful ZK verification event.
 #[contracttype]
@@ -121,6 +123,38 @@ impl AuditTrail {
         true
     }
 
+    /// Consumes the most recent audit record for `user` and `resource_id` on
+    /// behalf of `consumer`.
+    ///
+    /// A record can back a single consumption per consumer, and is only
+    /// accepted if it was logged within `max_age` seconds. Returns `true` if a
+    /// fresh, unconsumed record was found and is now marked as used.
+    pub fn consume_latest(
+        env: &Env,
+        consumer: &Address,
+        user: &Address,
+        resource_id: &BytesN<32>,
+        max_age: u64,
+    ) -> bool {
+        let record = match Self::get_record(env, user.clone(), resource_id.clone()) {
+            Some(record) => record,
+            None => return false,
+        };
+        if env.ledger().timestamp() > record.timestamp.saturating_add(max_age) {
+            return false;
+        }
+
+        let key = (RECEIPT, consumer.clone(), user.clone(), resource_id.clone());
+        let record_hash = hash_record(env, &record);
+        let consumed: Option<BytesN<32>> = env.storage().persistent().get(&key);
+        if consumed == Some(record_hash.clone()) {
+            return false;
+        }
+
+        env.storage().persistent().set(&key, &record_hash);
+        true
+    }
+
     pub fn log_verification(env: &Env, submitter: &Address, proof_id: u64, verified: bool) {
         let record = VerificationRecord {
             submitter: submitter.clone(),
@@ -0,0 +1,244 @@
+//! Reference proof vectors for integrators.
+//!
+//! Each [`ProofFixture`] pairs proof bytes and public inputs with the
+//! outcome the on-chain verifier produces for them. Teams wiring up a
+//! prover can feed their own output through [`evaluate`], or check that
+//! their integration agrees with this crate via [`verify_fixture`].
+//!
+//! Only built for tests and with the `testutils` feature.
+
+use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, Vec};
+
+use crate::verifier::{Bn254Verifier, G1Point, G2Point, Proof};
+use crate::{
+    map_proof_validation_error, validate_level4_attributes, validate_request, AccessRequest,
+    ContractError,
+};
+
+/// Verification path a fixture targets.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Circuit {
+    /// `verify_access` and auth levels 1–3
+    Access,
+    /// Auth level 4, which also needs an attribute commitment input
+    Level4Attributes,
+}
+
+/// What the verifier does with a fixture.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FixtureOutcome {
+    /// Well-formed and verifies
+    Accepted,
+    /// Rejected before verification with this error
+    Rejected(ContractError),
+    /// Well-formed but the proof does not verify
+    Unverified,
+}
+
+pub struct ProofFixture {
+    pub name: &'static str,
+    pub circuit: Circuit,
+    pub a: [[u8; 32]; 2],
+    pub b: [[u8; 32]; 4],
+    pub c: [[u8; 32]; 2],
+    pub public_inputs: &'static [[u8; 32]],
+    pub expected: FixtureOutcome,
+}
+
+/// A field element with the given first and last bytes and zeros between.
+const fn word(first: u8, last: u8) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    out[0] = first;
+    out[31] = last;
+    out
+}
+
+const ZERO: [u8; 32] = [0u8; 32];
+const FULL: [u8; 32] = [0xFF; 32];
+
+const A: [[u8; 32]; 2] = [word(1, 1), word(0, 2)];
+const B: [[u8; 32]; 4] = [word(0x18, 1), word(0x19, 2), word(0x12, 3), word(0x12, 4)];
+const C: [[u8; 32]; 2] = [word(1, 3), word(0, 4)];
+
+const INPUT: [u8; 32] = word(1, 7);
+const COMMITMENT: [u8; 32] = word(9, 9);
+
+const fn access(
+    name: &'static str,
+    a: [[u8; 32]; 2],
+    b: [[u8; 32]; 4],
+    c: [[u8; 32]; 2],
+    public_inputs: &'static [[u8; 32]],
+    expected: FixtureOutcome,
+) -> ProofFixture {
+    ProofFixture {
+        name,
+        circuit: Circuit::Access,
+        a,
+        b,
+        c,
+        public_inputs,
+        expected,
+    }
+}
+
+pub const FIXTURES: &[ProofFixture] = &[
+    access("access_valid", A, B, C, &[INPUT], FixtureOutcome::Accepted),
+    access(
+        "access_wrong_input",
+        A,
+        B,
+        C,
+        &[word(2, 7)],
+        FixtureOutcome::Unverified,
+    ),
+    access(
+        "access_wrong_a",
+        [word(2, 1), word(0, 2)],
+        B,
+        C,
+        &[INPUT],
+        FixtureOutcome::Unverified,
+    ),
+    access(
+        "access_zero_a",
+        [ZERO, ZERO],
+        B,
+        C,
+        &[INPUT],
+        FixtureOutcome::Rejected(ContractError::DegenerateProof),
+    ),
+    access(
+        "access_saturated_c",
+        A,
+        B,
+        [FULL, FULL],
+        &[INPUT],
+        FixtureOutcome::Rejected(ContractError::OversizedProofComponent),
+    ),
+    access(
+        "access_malformed_a",
+        [ZERO, word(0, 2)],
+        B,
+        C,
+        &[INPUT],
+        FixtureOutcome::Rejected(ContractError::MalformedG1Point),
+    ),
+    access(
+        "access_malformed_b",
+        A,
+        [word(0x18, 1), ZERO, word(0x12, 3), word(0x12, 4)],
+        C,
+        &[INPUT],
+        FixtureOutcome::Rejected(ContractError::MalformedG2Point),
+    ),
+    access(
+        "access_zero_input",
+        A,
+        B,
+        C,
+        &[INPUT, ZERO],
+        FixtureOutcome::Rejected(ContractError::ZeroedPublicInput),
+    ),
+    access(
+        "access_no_inputs",
+        A,
+        B,
+        C,
+        &[],
+        FixtureOutcome::Rejected(ContractError::EmptyPublicInputs),
+    ),
+    ProofFixture {
+        name: "level4_valid",
+        circuit: Circuit::Level4Attributes,
+        a: A,
+        b: B,
+        c: C,
+        public_inputs: &[INPUT, COMMITMENT],
+        expected: FixtureOutcome::Accepted,
+    },
+    ProofFixture {
+        name: "level4_missing_commitment",
+        circuit: Circuit::Level4Attributes,
+        a: A,
+        b: B,
+        c: C,
+        public_inputs: &[INPUT],
+        expected: FixtureOutcome::Rejected(ContractError::ProofRequiredForAuthLevel),
+    },
+];
+
+impl ProofFixture {
+    pub fn proof(&self, env: &Env) -> Proof {
+        let n = |bytes: &[u8; 32]| BytesN::from_array(env, bytes);
+        Proof {
+            a: G1Point {
+                x: n(&self.a[0]),
+                y: n(&self.a[1]),
+            },
+            b: G2Point {
+                x: (n(&self.b[0]), n(&self.b[1])),
+                y: (n(&self.b[2]), n(&self.b[3])),
+            },
+            c: G1Point {
+                x: n(&self.c[0]),
+                y: n(&self.c[1]),
+            },
+        }
+    }
+
+    pub fn public_inputs(&self, env: &Env) -> Vec<BytesN<32>> {
+        let mut out = Vec::new(env);
+        for input in self.public_inputs {
+            out.push_back(BytesN::from_array(env, input));
+        }
+        out
+    }
+
+    /// An [`AccessRequest`] carrying this fixture for a fresh user.
+    pub fn request(&self, env: &Env, nonce: u64) -> AccessRequest {
+        AccessRequest {
+            user: Address::generate(env),
+            resource_id: BytesN::from_array(env, &[7u8; 32]),
+            proof: self.proof(env),
+            public_inputs: self.public_inputs(env),
+            nonce,
+        }
+    }
+}
+
+pub fn find(name: &str) -> Option<&'static ProofFixture> {
+    FIXTURES.iter().find(|f| f.name == name)
+}
+
+/// Runs `request` through the same checks the contract applies for
+/// `circuit`, without touching nonces, whitelists or rate limits.
+pub fn evaluate(env: &Env, circuit: Circuit, request: &AccessRequest) -> FixtureOutcome {
+    if circuit == Circuit::Level4Attributes {
+        if let Err(err) = validate_level4_attributes(request) {
+            return FixtureOutcome::Rejected(err);
+        }
+    }
+    if let Err(err) = validate_request(request) {
+        return FixtureOutcome::Rejected(err);
+    }
+    if let Err(err) =
+        Bn254Verifier::validate_proof_components(&request.proof, &request.public_inputs)
+    {
+        return FixtureOutcome::Rejected(map_proof_validation_error(err));
+    }
+    if Bn254Verifier::verify_proof(env, &request.proof, &request.public_inputs) {
+        FixtureOutcome::Accepted
+    } else {
+        FixtureOutcome::Unverified
+    }
+}
+
+/// True if the fixture called `name` still produces its recorded outcome.
+/// Unknown names return false.
+pub fn verify_fixture(env: &Env, name: &str) -> bool {
+    match find(name) {
+        Some(fixture) => evaluate(env, fixture.circuit, &fixture.request(env, 0)) == fixture.expected,
+        None => false,
+    }
+}
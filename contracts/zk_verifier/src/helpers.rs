@@ -1,5 +1,6 @@
 use crate::verifier::{G1Point, G2Point};
 use crate::{AccessRequest, Proof};
+use common::optometry;
 use soroban_sdk::{BytesN, Env, Vec};
 
 /// Helper utility for creating ZK access requests.
@@ -59,4 +60,14 @@ impl ZkAccessHelper {
             nonce: 0, // Default nonce; caller should set appropriately for replay protection
         }
     }
+
+    /// Encodes a visual acuity in logMAR thousandths as a public input.
+    ///
+    /// Circuits that constrain acuity must use this encoding (see
+    /// [`optometry::logmar_to_field_bytes`]) so that proofs built from Snellen,
+    /// decimal or logMAR charts agree on the same value. Returns `None` for
+    /// acuity outside the supported logMAR range.
+    pub fn encode_logmar(env: &Env, logmar: i32) -> Option<BytesN<32>> {
+        optometry::logmar_to_field_bytes(logmar).map(|bytes| BytesN::from_array(env, &bytes))
+    }
 }
@@ -18,6 +18,8 @@
 mod audit;
 pub mod credentials;
 pub mod events;
+#[cfg(any(test, feature = "testutils"))]
+pub mod fixtures;
 mod helpers;
 pub mod revocation;
 pub mod selective_disclosure;
@@ -32,11 +34,13 @@ pub use crate::verifier::{Bn254Verifier, PoseidonHasher, Proof, ProofValidationE
 pub use crate::verifier::{Bn254Verifier, PoseidonHasher, Proof, ProofValidationError};
 pub use crate::vk::VerificationKey;
 
+use common::interface::{self, InterfaceVersion};
 use common::{nonce, whitelist};
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env,
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env,
     String, Symbol, Vec,
 };
+use common::storage_ttl::{self, StorageKeySpec};
 // use verifier::ProofValidationError;
 
 const ADMIN: Symbol = symbol_short!("ADMIN");
@@ -48,6 +52,15 @@ const RATE_TRACK: Symbol = symbol_short!("RLTRK");
 /// Maximum number of public inputs accepted per proof verification.
 const MAX_PUBLIC_INPUTS: u32 = 16;
 
+/// Cross-contract interface version reported by `get_interface_version`.
+/// Bump the major version when `consume_receipt` or other functions called
+/// by vision_records and identity change incompatibly.
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: interface::ZK_VERIFIER,
+    major: 1,
+    minor: 0,
+};
+
 /// Request structure for ZK access verification.
 // TODO: post-quantum migration - This struct currently hardcodes a Groth16 `Proof`.
 // Future PQ systems (like STARKs) will require an `enum ProofType` or dynamically sized bytes
@@ -63,7 +76,8 @@ pub struct AccessRequest {
     pub proof: Proof,
     /// Public inputs associated with the proof.
     pub public_inputs: Vec<BytesN<32>>,
-    pub timestamp: u64,
+    /// The user's replay-protection nonce for this request.
+    pub nonce: u64,
 }
 
 /// Storage keys (all ≤9 chars for symbol_short!)
@@ -199,6 +213,11 @@ impl ZkVerifierContract {
         env.storage().instance().set(&INITIALIZED, &true);
         env.storage().instance().set(&PROOF_CTR, &0u64);
 
+    /// Version of the interface this contract exposes to other contracts.
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
     /// Store the Groth16 verification key used by `verify_access`.
     ///
     /// Only the admin may call this.  The key can be updated at any time
@@ -490,6 +509,16 @@ impl ZkVerifierContract {
         Ok(proof_ids)
     }
 
+    fn relay_payload_hash(env: &Env, request: &AccessRequest) -> BytesN<32> {
+        let mut buf = Bytes::new(env);
+        buf.extend_from_array(&request.resource_id.to_array());
+        for input in request.public_inputs.iter() {
+            buf.extend_from_array(&input.to_array());
+        }
+        buf.extend_from_array(&request.nonce.to_be_bytes());
+        env.crypto().keccak256(&buf).into()
+    }
+
     fn validate_and_increment_nonce(
         env: &Env,
         user: &Address,
@@ -623,8 +652,19 @@ impl ZkVerifierContract {
         request: AccessRequest,
     ) -> Result<bool, ContractError> {
         executor.require_auth();
-        // Additional checks for authorized executors can be added here
+        // Bind the user's nonce to this exact request so a relayed proof can
+        // only ever be submitted once, whichever executor carries it.
+        let payload_hash = Self::relay_payload_hash(&env, &request);
+        nonce::consume_nonce(&env, &request.user, request.nonce, &payload_hash)
+            .map_err(|_| ContractError::InvalidNonce)?;
         Self::verify_access(env, request)
+    }
+
+    /// Returns the payload hash a relayed request consumed `nonce` with.
+    pub fn get_relayed_payload(env: Env, user: Address, nonce: u64) -> Option<BytesN<32>> {
+        nonce::consumed_payload(&env, &user, nonce)
+    }
+
     /// Verifies the integrity of the audit chain for a given user and resource.
     ///
     /// Returns `true` if all hash links are valid, or if the chain is empty.
@@ -632,6 +672,23 @@ impl ZkVerifierContract {
         AuditTrail::verify_chain(&env, user, resource_id)
     }
 
+    /// Consumes the caller's receipt for the latest successful verification
+    /// of `user` against `resource_id`.
+    ///
+    /// Lets another contract gate an action on a proof without learning its
+    /// contents: each verification can be consumed once per `consumer`, and
+    /// only within `max_age` seconds of being logged.
+    pub fn consume_receipt(
+        env: Env,
+        consumer: Address,
+        user: Address,
+        resource_id: BytesN<32>,
+        max_age: u64,
+    ) -> bool {
+        consumer.require_auth();
+        AuditTrail::consume_latest(&env, &consumer, &user, &resource_id, max_age)
+    }
+
     // ── Credential schema management ─────────────────────────────────────────
 
     /// Register a new credential schema. Only admin can register schemas.
@@ -878,4 +935,16 @@ impl ZkVerifierContract {
     ) -> bool {
         RevocationRegistryManager::is_revoked(&env, &registry_id, index)
     }
+
+    /// Extends the TTL of the entries described by `keys_spec`, inspecting at
+    /// most `limit` keys. Permissionless: it only keeps existing state alive.
+    pub fn extend_storage(env: Env, keys_spec: Vec<StorageKeySpec>, limit: u32) -> u32 {
+        storage_ttl::extend_storage(
+            &env,
+            &keys_spec,
+            limit,
+            storage_ttl::DEFAULT_TTL_THRESHOLD,
+            storage_ttl::DEFAULT_TTL_EXTEND_TO,
+        )
+    }
 }
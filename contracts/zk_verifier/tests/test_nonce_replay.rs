@@ -202,3 +202,22 @@ fn failed_validation_does_not_advance_nonce() {
     // Nonce must not have advanced because validate_request fires before nonce check.
     assert_eq!(client.get_nonce(&user), 0u64);
 }
+
+#[test]
+fn relayed_request_cannot_be_resubmitted_by_another_executor() {
+    let env = Env::default();
+    let (client, _, user) = setup(&env);
+    let executor_a = Address::generate(&env);
+    let executor_b = Address::generate(&env);
+    let (proof, inputs) = valid_proof_and_inputs(&env);
+    let req = make_request(&env, user.clone(), 0, proof, inputs);
+
+    client
+        .try_verify_delegated_access(&executor_a, &req)
+        .unwrap()
+        .unwrap();
+    assert!(client.get_relayed_payload(&user, &0).is_some());
+
+    let replay = client.try_verify_delegated_access(&executor_b, &req);
+    assert_eq!(replay.unwrap_err(), Ok(ContractError::InvalidNonce));
+}
@@ -1049,3 +1049,12 @@ fn test_audit_chain_empty_is_valid() {
         "Empty audit chain should be valid"
     );
 }
+
+#[test]
+fn test_encode_logmar_matches_shared_encoding() {
+    let env = Env::default();
+    let encoded = ZkAccessHelper::encode_logmar(&env, 301).unwrap();
+    let expected = common::optometry::logmar_to_field_bytes(301).unwrap();
+    assert_eq!(encoded, BytesN::from_array(&env, &expected));
+    assert!(ZkAccessHelper::encode_logmar(&env, 5_000).is_none());
+}
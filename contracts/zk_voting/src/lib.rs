@@ -12,6 +12,8 @@ use ballot::{DataKey, OptionIndex, VoteError};
 use soroban_sdk::{
     contract, contractimpl, contracttype, panic_with_error, Address, BytesN, Env, Vec,
 };
+use common::interface::{self, InterfaceVersion};
+use common::storage_ttl::{self, StorageKeySpec};
 use zk_verifier::{Bn254Verifier, Proof};
 
 #[contracttype]
@@ -22,6 +24,13 @@ pub struct BallotResults {
     pub closed: bool,
 }
 
+/// Cross-contract interface version reported by `get_interface_version`
+pub const INTERFACE_VERSION: InterfaceVersion = InterfaceVersion {
+    interface: interface::ZK_VOTING,
+    major: 1,
+    minor: 0,
+};
+
 #[contract]
 pub struct ZkVoting;
 
@@ -49,6 +58,11 @@ impl ZkVoting {
         }
     }
 
+    /// Version of the interface this contract exposes to other contracts.
+    pub fn get_interface_version(_env: Env) -> InterfaceVersion {
+        INTERFACE_VERSION
+    }
+
     /// Set the Merkle root that defines eligible voters. Admin only.
     pub fn set_merkle_root(env: Env, caller: Address, root: BytesN<32>) {
         caller.require_auth();
@@ -199,4 +213,16 @@ impl ZkVoting {
             panic_with_error!(env, VoteError::BallotNotOpen);
         }
     }
+
+    /// Extends the TTL of the entries described by `keys_spec`, inspecting at
+    /// most `limit` keys. Permissionless: it only keeps existing state alive.
+    pub fn extend_storage(env: Env, keys_spec: Vec<StorageKeySpec>, limit: u32) -> u32 {
+        storage_ttl::extend_storage(
+            &env,
+            &keys_spec,
+            limit,
+            storage_ttl::DEFAULT_TTL_THRESHOLD,
+            storage_ttl::DEFAULT_TTL_EXTEND_TO,
+        )
+    }
 }